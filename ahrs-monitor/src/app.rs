@@ -5,43 +5,142 @@
 
 use crate::{
     config,
-    config::AppConfig,
-    core::StandardPayload,
-    logger::{LogRecord, Logger, ToLog},
-    model::{AppEvent, FrameContext},
-    ui::{AppTab, DashboardTab, InspectorTab, TabViewer},
+    config::{
+        AngleUnit, AppConfig, LayoutPreset, MinSpecMode, ModelConfig, Panel,
+        ShortcutAction, TabKind,
+    },
+    core::{
+        alert::Alert,
+        attitude::FusionAlgorithm,
+        calibration::AccCalibration,
+        housekeeping,
+        mag_calibration::MagCalibration,
+        mesh::Mesh,
+        motion::MotionState,
+        shock::ShockEvent,
+        source::{Link, RedundancyStats},
+        warm_start,
+    },
+    journal::JournalWriter,
+    logger::{self, LogRecord, Logger},
+    model::{
+        AppEvent, FrameContext, IngesterCommand, RejectedFrame,
+        channel::{CommandSender, EventReceiver},
+        session::ArchivedSession,
+        stats::SharedStats,
+    },
+    report::{self, DiagnosticReport, ReportCounters},
+    ui::{
+        AlertPanel, AnalysisTab, AppTab, AppliedCalibration, CalibrationTab,
+        ConnectionAction, ConnectionPanel, DashboardTab, DockAction,
+        DockLayout, FrameBacklog, HiddenTraces, HousekeepingPanel,
+        InspectorTab, PerfOverlay, Playback, SettingsAction, SpectrumTab,
+        TabViewer, TelemetryTab, TrajectoryTab, utils,
+    },
 };
+use chrono::{DateTime, Local};
 use eframe::Frame;
 use egui::{
-    Align, CentralPanel, Color32, Context, Layout, RichText, TopBottomPanel,
+    Align, CentralPanel, Color32, ColorImage, ComboBox, Context, Event, Id,
+    Layout, Modal, RichText, SidePanel, TopBottomPanel, UserData,
+    ViewportCommand, Window,
 };
-use std::{collections::VecDeque, sync::Arc};
-use tokio::sync::mpsc::Receiver;
+use std::{
+    collections::VecDeque,
+    fs, mem,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+/// Number of UI frames to wait for the screenshot of the problem report.
+const REPORT_SCREENSHOT_TIMEOUT: usize = 30;
+
+/// Tag of the viewport screenshot requested by the user.
+const SCREENSHOT_TAG: &str = "screenshot";
+
+/// Number of UI frames to wait for the screenshot requested by the user.
+const SCREENSHOT_TIMEOUT: usize = 30;
+
+/// Number of packets per second samples shown in the status bar sparkline.
+const PPS_SPARKLINE_SIZE: usize = 60;
 
 /// Application handler.
 pub struct App {
     /// Given global config.
     config: AppConfig,
-    /// MPSC receiver handle.
-    rx: Receiver<AppEvent>,
+    /// Events channel receiver handle.
+    rx: EventReceiver,
     /// List of application tabs.
     tabs: Vec<AppTab>,
-    /// Current selected tab index.
-    current_tab_idx: usize,
+    /// Docking layout of the tabs.
+    dock: DockLayout,
     /// Current smoothed number of frames per second.
     fps: f64,
+    /// Time since frame rate is below the min-spec threshold (`None` - frame
+    /// rate is not low).
+    low_fps_since: Option<Instant>,
     /// Current number of frames from the start.
     frame_counter: usize,
     /// IMU connection status.
     connection_status: bool,
+    /// Indicator whether recorded log is replayed instead of the live stream.
+    is_replaying: bool,
     /// History of the last N frame contexts.
     history: VecDeque<Arc<FrameContext>>,
     /// Current frame context.
     current_frame: Option<Arc<FrameContext>>,
     /// Indicator whether UI is paused.
     is_paused: bool,
+    /// Plotted frames scrubbed while UI is paused.
+    playback: Playback,
+    /// Plotted frames not fed into the hidden tabs yet.
+    backlog: FrameBacklog,
     /// IMU data logger.
     logger: Option<Logger>,
+    /// Path to the log file of the last stopped recording.
+    last_recording: Option<PathBuf>,
+    /// Triggered alert to show in a modal window.
+    alert_modal: Option<Box<Alert>>,
+    /// Application events journal writer.
+    journal: Option<JournalWriter>,
+    /// Stream statistics accumulated over the whole session by the stats
+    /// worker.
+    stats: SharedStats,
+    /// Ingester commands channel sender handle.
+    commands: Option<CommandSender>,
+    /// Connection settings window.
+    connection_panel: ConnectionPanel,
+    /// Device identifier accepted by the ingester (`None` accepts all).
+    device_filter: Option<u8>,
+    /// Log directory housekeeping window.
+    housekeeping: HousekeepingPanel,
+    /// Active and past alerts window.
+    alerts: AlertPanel,
+    /// UI and ingestion performance overlay.
+    perf: PerfOverlay,
+    /// Plot traces hidden from the legend.
+    hidden_traces: HiddenTraces,
+    /// Path to the config file to save calibration to.
+    config_path: String,
+    /// Current device session start time.
+    session_start: DateTime<Local>,
+    /// Active layout preset.
+    layout: LayoutPreset,
+    /// Recently rejected frames.
+    quarantine: VecDeque<RejectedFrame>,
+    /// UI frame number of the pending problem report request (`None` - no
+    /// pending request).
+    report_requested_at: Option<usize>,
+    /// Result of the last problem report.
+    report_status: Option<String>,
+    /// Name of the tab and number of UI frames left to wait for the pending
+    /// screenshot request (`None` - no pending request).
+    screenshot_requested: Option<(String, usize)>,
+    /// Result of the last screenshot.
+    screenshot_status: Option<String>,
+    /// Vehicle 3D model of the attitude widget (`None` - wireframe cube).
+    model: Option<Arc<Mesh>>,
 }
 
 impl eframe::App for App {
@@ -49,18 +148,58 @@ impl eframe::App for App {
     ///
     /// # Parameters
     /// - `ctx` - given egui context to handle.
-    fn update(&mut self, ctx: &Context, _: &mut Frame) {
+    fn update(&mut self, ctx: &Context, frame: &mut Frame) {
+        self.perf.record_frame(
+            ctx.input(|i| i.unstable_dt),
+            frame.info().cpu_usage,
+            self.rx.queued(),
+        );
+
+        let fps = self.fps(ctx);
+        self.update_min_spec(fps);
+        self.hidden_traces.sync(ctx);
+        self.handle_shortcuts(ctx);
+
         TopBottomPanel::top("top_panel")
             .show(ctx, |ui| self.display_top_panel(ui));
 
         CentralPanel::default().show(ctx, |ui| self.display_central_panel(ui));
+        self.display_tab_windows(ctx);
+
+        if self.layout.has_panel(Panel::StatusBar) {
+            TopBottomPanel::bottom("bottom_panel")
+                .show(ctx, |ui| self.display_bottom_panel(ui, fps));
+        }
+
+        if self.is_paused && !self.playback.is_empty() {
+            TopBottomPanel::bottom("playback_panel")
+                .show(ctx, |ui| self.display_playback_panel(ui));
+        }
 
-        TopBottomPanel::bottom("bottom_panel")
-            .show(ctx, |ui| self.display_bottom_panel(ui, ctx));
+        self.display_alert_modal(ctx);
+        self.alerts.show(ctx);
+        self.perf.show(ctx);
+        self.display_connection_panel(ctx);
+        self.check_housekeeping(false);
+        self.housekeeping.show(ctx, &self.config.log.retention);
+        self.handle_report_request(ctx);
+        self.handle_screenshot_request(ctx);
 
+        self.handle_replay(ctx);
         self.handle_events();
         self.frame_counter += 1;
     }
+
+    /// Store last known attitude for the warm-start of the next session and
+    /// docking layout of the tabs.
+    ///
+    /// # Parameters
+    /// - `gl` - given OpenGL context (unused).
+    fn on_exit(&mut self, _: Option<&eframe::glow::Context>) {
+        self.save_warm_start();
+        self.save_dock_layout();
+        self.save_hidden_traces();
+    }
 }
 
 impl App {
@@ -68,29 +207,135 @@ impl App {
     ///
     /// # Parameters
     /// - `config` - given global config.
-    /// - `rx` - given MPSC receiver handle.
+    /// - `rx` - given events channel receiver handle.
     ///
     /// # Returns
     /// - New `App` object.
     #[must_use]
-    pub fn new(config: AppConfig, rx: Receiver<AppEvent>) -> Self {
-        Self {
+    pub fn new(config: AppConfig, rx: EventReceiver) -> Self {
+        let layout = config
+            .display
+            .startup_layout()
+            .unwrap_or_else(LayoutPreset::full);
+        let model = load_model(&config.display.model);
+        let perf = PerfOverlay::new(config.display.perf_overlay);
+        let hidden_traces = HiddenTraces::load(config::HIDDEN_TRACES_FILE_PATH)
+            .unwrap_or_else(|e| {
+                log::error!("Failed to load hidden plot traces: {e}");
+                HiddenTraces::default()
+            });
+        let dock = DockLayout::load(config::DOCK_LAYOUT_FILE_PATH)
+            .unwrap_or_else(|e| {
+                log::error!("Failed to load dock layout: {e}");
+                DockLayout::default()
+            });
+
+        let mut app = Self {
             config,
             rx,
             fps: 0.0,
+            low_fps_since: None,
             frame_counter: 0,
             connection_status: false,
+            is_replaying: false,
             history: VecDeque::with_capacity(config::HISTORY_MAX_SIZE),
             is_paused: false,
+            playback: Playback::default(),
+            backlog: FrameBacklog::default(),
             current_frame: None,
             tabs: vec![
-                AppTab::Dashboard(DashboardTab::default()),
+                AppTab::Dashboard(Box::new(
+                    DashboardTab::default().with_model(model.clone()),
+                )),
                 AppTab::Telemetry(Box::default()),
-                AppTab::Inspector(InspectorTab),
+                AppTab::Inspector(Box::default()),
+                AppTab::Compare(Box::default()),
+                AppTab::Calibration(Box::default()),
+                AppTab::Sessions(Box::default()),
+                AppTab::Replay(Box::default()),
+                AppTab::Trajectory(Box::default()),
+                AppTab::Analysis(Box::default()),
+                AppTab::Spectrum(Box::default()),
+                AppTab::Settings(Box::default()),
             ],
-            current_tab_idx: 0,
+            dock,
             logger: None,
-        }
+            last_recording: None,
+            alert_modal: None,
+            journal: None,
+            stats: SharedStats::default(),
+            commands: None,
+            connection_panel: ConnectionPanel::default(),
+            device_filter: None,
+            housekeeping: HousekeepingPanel::default(),
+            alerts: AlertPanel::default(),
+            perf,
+            hidden_traces,
+            config_path: config::CONFIG_FILE_PATH.to_string(),
+            session_start: Local::now(),
+            layout: LayoutPreset::full(),
+            quarantine: VecDeque::with_capacity(config::QUARANTINE_MAX_SIZE),
+            report_requested_at: None,
+            report_status: None,
+            screenshot_requested: None,
+            screenshot_status: None,
+            model,
+        };
+
+        app.apply_layout(layout);
+        app
+    }
+
+    /// Set application events journal writer.
+    ///
+    /// # Parameters
+    /// - `journal` - given journal writer recording every received event.
+    ///
+    /// # Returns
+    /// - Updated `App` object.
+    #[must_use]
+    pub fn with_journal(mut self, journal: JournalWriter) -> Self {
+        self.journal = Some(journal);
+        self
+    }
+
+    /// Set session statistics accumulated by the stats worker.
+    ///
+    /// # Parameters
+    /// - `stats` - given shared session statistics handle.
+    ///
+    /// # Returns
+    /// - Updated `App` object.
+    #[must_use]
+    pub fn with_stats(mut self, stats: SharedStats) -> Self {
+        self.stats = stats;
+        self
+    }
+
+    /// Set ingester commands channel for runtime connection changes.
+    ///
+    /// # Parameters
+    /// - `commands` - given commands channel sender handle.
+    ///
+    /// # Returns
+    /// - Updated `App` object.
+    #[must_use]
+    pub fn with_commands(mut self, commands: CommandSender) -> Self {
+        self.commands = Some(commands);
+        self
+    }
+
+    /// Set path to the config file to save calibration to.
+    ///
+    /// # Parameters
+    /// - `path` - given config file path.
+    ///
+    /// # Returns
+    /// - Updated `App` object.
+    #[must_use]
+    pub fn with_config_path(mut self, path: String) -> Self {
+        self.config_path = path;
+        self
     }
 
     /// Get smoothed number of frames per second.
@@ -120,41 +365,204 @@ impl App {
         }
     }
 
+    /// Enable reduced-graphics mode if frame rate stays low.
+    ///
+    /// # Parameters
+    /// - `fps` - given smoothed number of frames per second.
+    fn update_min_spec(&mut self, fps: usize) {
+        let min_spec = self.config.display.min_spec;
+        let display = &mut self.config.display;
+
+        match min_spec.mode {
+            MinSpecMode::On => display.is_min_spec = true,
+            MinSpecMode::Off => display.is_min_spec = false,
+            // Paused UI is not repainted continuously.
+            MinSpecMode::Auto if !display.is_min_spec && !self.is_paused => {
+                #[allow(clippy::cast_precision_loss)]
+                let is_low = (fps as f32) < min_spec.fps_threshold;
+
+                if !is_low {
+                    self.low_fps_since = None;
+                    return;
+                }
+
+                let since =
+                    *self.low_fps_since.get_or_insert_with(Instant::now);
+
+                if since.elapsed().as_secs_f32() >= min_spec.duration {
+                    log::warn!(
+                        "Frame rate is below {} FPS, enabling min-spec mode",
+                        min_spec.fps_threshold
+                    );
+                    display.is_min_spec = true;
+                }
+            }
+            MinSpecMode::Auto => self.low_fps_since = None,
+        }
+    }
+
     /// Enable/disable IMU data logging.
     #[inline]
     pub fn toggle_logging(&mut self) {
         if self.logger.is_some() {
-            self.logger = None;
+            self.stop_logging();
         } else {
             self.logger = Logger::new(&self.config).ok();
+            self.check_housekeeping(true);
+        }
+    }
+
+    /// Stop IMU data logging and remember the closed log file.
+    fn stop_logging(&mut self) {
+        if let Some(logger) = self.logger.take() {
+            self.last_recording = Some(PathBuf::from(logger.path()));
         }
     }
 
+    /// Check retention policy of the log directory.
+    ///
+    /// # Parameters
+    /// - `force` - given flag whether to check before the next interval.
+    fn check_housekeeping(&mut self, force: bool) {
+        let active =
+            self.logger.as_ref().map(|logger| Path::new(logger.path()));
+
+        self.housekeeping.check(
+            &self.config.log.directory,
+            &self.config.log.retention,
+            active,
+            force,
+        );
+    }
+
+    /// Apply layout preset.
+    ///
+    /// Tabs hidden by the preset are removed from the panes and windows.
+    ///
+    /// # Parameters
+    /// - `layout` - given layout preset to apply.
+    fn apply_layout(&mut self, layout: LayoutPreset) {
+        log::info!("Applying layout preset: {}", layout.name);
+
+        self.layout = layout;
+        let available = self.available_tabs();
+        self.dock.retain(&available);
+    }
+
+    /// Get tabs allowed by the active layout preset.
+    ///
+    /// # Returns
+    /// - Allowed tabs in tab bar order.
+    fn available_tabs(&self) -> Vec<TabKind> {
+        self.tabs
+            .iter()
+            .map(AppTab::kind)
+            .filter(|&kind| self.layout.has_tab(kind))
+            .collect()
+    }
+
+    /// Get tab index.
+    ///
+    /// # Parameters
+    /// - `kind` - given tab to find.
+    ///
+    /// # Returns
+    /// - Index of the tab - if found.
+    /// - `None` - otherwise.
+    fn tab_index(&self, kind: TabKind) -> Option<usize> {
+        self.tabs.iter().position(|tab| tab.kind() == kind)
+    }
+
     /// Display top panel.
     ///
     /// # Parameters
     /// - `ui` - given screen UI handler.
     fn display_top_panel(&mut self, ui: &mut egui::Ui) {
         ui.horizontal(|ui| {
-            for (index, tab) in self.tabs.iter().enumerate() {
-                let (icon, title) = match tab {
-                    AppTab::Dashboard(tab) => (tab.icon(), tab.title()),
-                    AppTab::Telemetry(tab) => (tab.icon(), tab.title()),
-                    AppTab::Inspector(tab) => (tab.icon(), tab.title()),
-                };
+            for tab in &self.tabs {
+                let kind = tab.kind();
+
+                if !self.layout.has_tab(kind) {
+                    continue;
+                }
 
-                let tab_label = format!("{icon} {title}");
-                let checked = self.current_tab_idx == index;
+                let checked = self.dock.contains(kind);
 
-                if ui.selectable_label(checked, tab_label).clicked() {
-                    self.current_tab_idx = index;
+                if ui
+                    .selectable_label(checked, tab.label())
+                    .on_hover_text("Show in the focused pane")
+                    .clicked()
+                {
+                    self.dock.select(kind);
                 }
             }
+
+            ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                self.display_layout_selector(ui);
+                self.alerts.display_indicator(ui);
+                self.display_angle_unit_toggle(ui);
+            });
         });
+
+        if !self.layout.has_panel(Panel::Toolbar) {
+            return;
+        }
+
         ui.separator();
         ui.horizontal(|ui| {
             self.display_pause_button(ui);
             self.display_record_button(ui);
+            self.display_recording_status(ui);
+
+            if ui
+                .button("↺ Reset Counters")
+                .on_hover_text("Reset session packet counters and statistics")
+                .clicked()
+            {
+                self.stats.lock().reset();
+            }
+
+            self.display_report_button(ui);
+            self.display_screenshot_button(ui);
+
+            ui.toggle_value(&mut self.perf.is_open, "⏱ Performance")
+                .on_hover_text(
+                    "Show UI frame times, event queue depth and ingester \
+                     processing time",
+                );
+
+            if ui
+                .button("🗑 Housekeeping")
+                .on_hover_text(
+                    "Preview recordings to prune by retention policy",
+                )
+                .clicked()
+            {
+                let active =
+                    self.logger.as_ref().map(|logger| Path::new(logger.path()));
+
+                self.housekeeping.open(
+                    &self.config.log.directory,
+                    &self.config.log.retention,
+                    active,
+                );
+            }
+
+            let connection_btn = ui
+                .add_enabled(
+                    self.commands.is_some(),
+                    egui::Button::new("🔌 Connection"),
+                )
+                .on_hover_text("Change frame source settings")
+                .on_disabled_hover_text("Ingester is not running");
+
+            if connection_btn.clicked() {
+                self.connection_panel
+                    .open(&self.config.net, self.device_filter);
+            }
+
+            self.display_fusion_selector(ui);
+            self.display_fusion_inputs(ui);
 
             if self.logger.is_some() && self.is_paused {
                 ui.label("⚠ Warning: Interface paused, but logging is ACTIVE");
@@ -162,226 +570,1748 @@ impl App {
         });
     }
 
-    /// Display pause button.
+    /// Display problem report button.
     ///
     /// # Parameters
     /// - `ui` - given screen UI handler.
-    fn display_pause_button(&mut self, ui: &mut egui::Ui) {
-        let pause_color = if self.is_paused {
-            Color32::from_rgb(255, 165, 0)
-        } else {
-            Color32::from_gray(60)
-        };
+    fn display_report_button(&mut self, ui: &mut egui::Ui) {
+        let report_btn = ui
+            .add_enabled(
+                self.report_requested_at.is_none(),
+                egui::Button::new("🐞 Report a Problem"),
+            )
+            .on_hover_text(
+                "Save config, recent logs, counters, rejected frames and \
+                 screenshot into a zip file to attach to an issue",
+            );
 
-        let text = if self.is_paused {
-            "▶ Resume Stream"
-        } else {
-            "⏸ Pause Stream"
+        if report_btn.clicked() {
+            ui.ctx().send_viewport_cmd(ViewportCommand::Screenshot(
+                UserData::default(),
+            ));
+            self.report_requested_at = Some(self.frame_counter);
+        }
+
+        if let Some(status) = &self.report_status {
+            ui.label(status);
+        }
+    }
+
+    /// Save requested problem report once the screenshot is taken.
+    ///
+    /// # Parameters
+    /// - `ctx` - given egui context to handle.
+    fn handle_report_request(&mut self, ctx: &Context) {
+        let Some(requested_at) = self.report_requested_at else {
+            return;
         };
 
-        let btn =
-            egui::Button::new(RichText::new(text).strong()).fill(pause_color);
+        // Tagged screenshots are requested by plot exports and user.
+        let screenshot = ctx.input(|i| {
+            i.events.iter().find_map(|event| match event {
+                Event::Screenshot {
+                    image, user_data, ..
+                } if user_data.data.is_none() => Some(Arc::clone(image)),
+                _ => None,
+            })
+        });
 
-        if ui.add(btn).clicked() {
-            self.is_paused = !self.is_paused;
-        }
+        // Report is saved without screenshot if backend does not support it.
+        let elapsed = self.frame_counter.saturating_sub(requested_at);
 
-        if self.is_paused {
-            ui.label(
-                RichText::new("(DISPLAY FROZEN)")
-                    .color(Color32::YELLOW)
-                    .small(),
-            );
+        if screenshot.is_none() && elapsed < REPORT_SCREENSHOT_TIMEOUT {
+            return;
         }
+
+        self.report_requested_at = None;
+        self.save_report(screenshot.as_deref());
     }
 
-    /// Display record button.
+    /// Save diagnostic bundle into the logs directory.
     ///
     /// # Parameters
-    /// - `ui` - given screen UI handler.
-    fn display_record_button(&mut self, ui: &mut egui::Ui) {
-        let is_logging = self.logger.is_some();
+    /// - `screenshot` - given screenshot of the interface.
+    fn save_report(&mut self, screenshot: Option<&ColorImage>) {
+        let config = fs::read_to_string(&self.config_path)
+            .map_err(anyhow::Error::from)
+            .and_then(|content| report::redact_config(&content))
+            .unwrap_or_else(|e| format!("# Config file is unavailable: {e}\n"));
 
-        let (btn_label, btn_color) = {
-            self.logger.as_ref().map_or_else(
-                || ("⏺ Record".to_string(), Color32::from_gray(40)),
-                |logger| {
-                    (format!("⏹ {}", logger.timestamp_str()), Color32::DARK_RED)
-                },
-            )
+        let last_frame = self.history.back();
+        let stats = self.stats.lock();
+        let counters = ReportCounters {
+            connected: self.connection_status,
+            total_packets: stats.total_packets,
+            bad_packets: stats.bad_packets,
+            lost_packets: stats.lost_packets,
+            pps: last_frame.map_or(0, |ctx| ctx.pps),
+            dropped_events: last_frame.map_or(0, |ctx| ctx.dropped_events),
         };
+        drop(stats);
 
-        let response = ui.add(egui::Button::new(btn_label).fill(btn_color));
+        let report = DiagnosticReport {
+            config,
+            counters,
+            quarantine: self.quarantine.iter().collect(),
+            screenshot,
+        };
 
-        if response.clicked() {
-            self.toggle_logging();
-        }
+        let mut path = PathBuf::from(&self.config.log.directory);
+        path.push(format!(
+            "report_{}.zip",
+            Local::now().format("%d-%m-%Y_%H-%M-%S")
+        ));
 
-        let on_hover_text = if is_logging {
-            "Stop Recording"
-        } else {
-            "Start Recording"
+        self.report_status = match report.write(&path) {
+            Ok(()) => {
+                log::info!("Problem report saved to: {}", path.display());
+                Some(format!("Report saved to: {}", path.display()))
+            }
+            Err(e) => {
+                log::error!("Error to save problem report: {e}");
+                Some(format!("Report saving failed: {e}"))
+            }
         };
-
-        response.on_hover_text(on_hover_text);
     }
 
-    /// Display central panel.
+    /// Display screenshot button.
     ///
     /// # Parameters
     /// - `ui` - given screen UI handler.
-    #[inline]
-    fn display_central_panel(&mut self, ui: &mut egui::Ui) {
-        self.render_active_tab(ui);
+    fn display_screenshot_button(&mut self, ui: &mut egui::Ui) {
+        let screenshot_btn = ui
+            .add_enabled(
+                self.screenshot_requested.is_none(),
+                egui::Button::new("📷 Screenshot"),
+            )
+            .on_hover_text(format!(
+                "Save screenshot of the current view into {}",
+                self.config.log.directory
+            ));
 
-        if !self.is_paused {
-            ui.ctx().request_repaint();
+        if screenshot_btn.clicked() {
+            self.request_screenshot(ui.ctx());
+        }
+
+        if let Some(status) = &self.screenshot_status {
+            ui.label(status);
         }
     }
 
-    /// Display bottom panel.
+    /// Request screenshot of the current view.
     ///
     /// # Parameters
-    /// - `ui` - given screen UI handler.
     /// - `ctx` - given egui context to handle.
-    fn display_bottom_panel(&mut self, ui: &mut egui::Ui, ctx: &Context) {
-        ui.horizontal(|ui| {
-            // Connection status label.
-            let connection_label = if self.connection_status {
-                RichText::new("CONNECTED").color(Color32::GREEN)
-            } else {
-                RichText::new("DISCONNECTED").color(Color32::RED)
-            };
-
-            ui.label(connection_label);
-            ui.separator();
+    fn request_screenshot(&mut self, ctx: &Context) {
+        if self.screenshot_requested.is_some() {
+            return;
+        }
 
-            // Encryption status label.
-            let encryption_label = if self.config.net.use_encryption {
-                RichText::new("🔒 ENCRYPTED").color(Color32::LIGHT_GREEN)
-            } else {
-                RichText::new("🔓 UNENCRYPTED").color(Color32::YELLOW)
-            };
+        let name = self
+            .dock
+            .panes
+            .get(self.dock.focused)
+            .and_then(|&kind| self.tabs.iter().find(|tab| tab.kind() == kind))
+            .map_or_else(
+                || SCREENSHOT_TAG.to_string(),
+                |tab| tab.title().to_lowercase().replace(' ', "_"),
+            );
 
-            ui.label(encryption_label);
-            ui.separator();
+        ctx.send_viewport_cmd(ViewportCommand::Screenshot(UserData::new(
+            SCREENSHOT_TAG.to_string(),
+        )));
+        self.screenshot_requested = Some((name, SCREENSHOT_TIMEOUT));
+    }
 
-            // Received packets info label.
-            if let Some(frame_ctx) = &self.current_frame {
-                ui.label(format!("Total packets: {}", frame_ctx.total_packets));
-                ui.separator();
-                ui.label(format!("Bad packets: {}", frame_ctx.bad_packets));
-                ui.separator();
-                ui.label(format!("Stream: {} packets/sec", frame_ctx.pps));
-                ui.separator();
-            }
-
-            // Colored FPS indicator.
-            let current_fps = self.fps(ctx);
-
-            let fps_color = match current_fps {
-                f if f >= 60 => Color32::from_rgb(0, 200, 0),
-                f if f >= 45 => Color32::from_rgb(120, 200, 0),
-                f if f >= 30 => Color32::from_rgb(255, 165, 0),
-                f if f > 0 => Color32::from_rgb(220, 30, 30),
-                _ => Color32::GRAY,
-            };
+    /// Save requested screenshot once it is taken.
+    ///
+    /// # Parameters
+    /// - `ctx` - given egui context to handle.
+    fn handle_screenshot_request(&mut self, ctx: &Context) {
+        let Some((_, frames_left)) = &mut self.screenshot_requested else {
+            return;
+        };
 
-            let fps_label =
-                RichText::new(format!("FPS: {current_fps}")).color(fps_color);
-            ui.label(fps_label);
-            ui.separator();
+        *frames_left = frames_left.saturating_sub(1);
+        let is_expired = *frames_left == 0;
 
-            ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
-                ui.label(format!("AHRS Monitor - v{}", config::VERSION));
-            });
+        let screenshot = ctx.input(|i| {
+            i.events.iter().find_map(|event| match event {
+                Event::Screenshot {
+                    image, user_data, ..
+                } if user_data
+                    .data
+                    .as_ref()
+                    .and_then(|data| data.downcast_ref::<String>())
+                    .is_some_and(|tag| tag == SCREENSHOT_TAG) =>
+                {
+                    Some(Arc::clone(image))
+                }
+                _ => None,
+            })
         });
+
+        let Some(screenshot) = screenshot else {
+            // Backend may not support screenshots or drop the request.
+            if is_expired {
+                log::error!(
+                    "Screenshot was not taken in {SCREENSHOT_TIMEOUT} frames"
+                );
+                self.screenshot_requested = None;
+                self.screenshot_status = Some(
+                    "Screenshot saving failed: no image received".to_string(),
+                );
+            }
+
+            return;
+        };
+
+        let (name, _) = self.screenshot_requested.take().unwrap_or_default();
+
+        self.screenshot_status = match self.save_screenshot(&screenshot, &name)
+        {
+            Ok(path) => {
+                log::info!("Screenshot saved to: {}", path.display());
+                Some(format!("Screenshot saved to: {}", path.display()))
+            }
+            Err(e) => {
+                log::error!("Error to save screenshot: {e}");
+                Some(format!("Screenshot saving failed: {e}"))
+            }
+        };
+    }
+
+    /// Save screenshot into the logs directory.
+    ///
+    /// # Parameters
+    /// - `image` - given screenshot of the interface.
+    /// - `name` - given name of the captured tab.
+    ///
+    /// # Returns
+    /// - Path to the saved screenshot - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - Directory creation, image encoding or file writing errors.
+    fn save_screenshot(
+        &self,
+        image: &ColorImage,
+        name: &str,
+    ) -> anyhow::Result<PathBuf> {
+        fs::create_dir_all(&self.config.log.directory)?;
+
+        let mut path = PathBuf::from(&self.config.log.directory);
+        path.push(format!(
+            "{name}_{}.png",
+            Local::now().format("%d-%m-%Y_%H-%M-%S")
+        ));
+
+        fs::write(&path, report::encode_png(image)?)?;
+        Ok(path)
     }
 
-    /// Render active tab.
+    /// Display layout preset selector.
     ///
     /// # Parameters
     /// - `ui` - given screen UI handler.
-    fn render_active_tab(&mut self, ui: &mut egui::Ui) {
-        if let Some(tab) = self.tabs.get_mut(self.current_tab_idx)
-            && let Some(frame_ctx) = &self.current_frame
-            && self.connection_status
-        {
-            if self.config.imu.is_correct() {
-                match tab {
-                    AppTab::Dashboard(tab) => {
-                        tab.ui(ui, frame_ctx, &self.config);
-                    }
-                    AppTab::Telemetry(tab) => {
-                        tab.ui(ui, frame_ctx, &self.config);
-                    }
-                    AppTab::Inspector(tab) => {
-                        tab.ui(ui, frame_ctx, &self.config);
+    fn display_layout_selector(&mut self, ui: &mut egui::Ui) {
+        let mut selected = None;
+
+        ComboBox::from_id_salt("layout_preset")
+            .selected_text(self.layout.name.as_str())
+            .show_ui(ui, |ui| {
+                for preset in self.config.display.layout_presets() {
+                    let checked = preset.name == self.layout.name;
+
+                    if ui
+                        .selectable_label(checked, preset.name.as_str())
+                        .clicked()
+                    {
+                        selected = Some(preset);
                     }
                 }
-            } else {
-                ui.vertical_centered(|ui| {
-                    ui.add_space(ui.available_height() / 2.0 - 50.0);
-                    ui.add_space(10.0);
+            })
+            .response
+            .on_hover_text("Layout preset (visible tabs, plots and panels)");
 
-                    let label_text = "UNSUPPORTED IMU PAYLOAD TYPE";
-                    ui.label(RichText::new(label_text).size(18.0));
-                });
-            }
-        } else {
-            ui.vertical_centered(|ui| {
-                ui.add_space(ui.available_height() / 2.0 - 50.0);
-                ui.add(egui::Spinner::new().size(40.0));
-                ui.add_space(10.0);
-
-                let label_text = if self.connection_status {
-                    "WAITING FOR IMU DATA..."
-                } else {
-                    "WAITING FOR CONNECTION..."
-                };
+        ui.label("Layout:");
 
-                ui.label(RichText::new(label_text).size(18.0));
-            });
+        if let Some(preset) = selected {
+            self.apply_layout(preset);
         }
     }
 
-    /// Handle events from ingester.
-    fn handle_events(&mut self) {
-        while let Ok(event) = self.rx.try_recv() {
-            match event {
-                AppEvent::UpdateConnectionStatus(status) => {
-                    self.handle_update_connection_status(status);
-                }
-                AppEvent::FrameReceived(frame_ctx) => {
-                    self.handle_received_frame(*frame_ctx);
-                }
-            }
+    /// Display unit toggle of the displayed angles and angular rates.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    fn display_angle_unit_toggle(&mut self, ui: &mut egui::Ui) {
+        let selected = &mut self.config.display.angle_unit;
+
+        // Units are laid out right to left.
+        for unit in AngleUnit::ALL.into_iter().rev() {
+            ui.selectable_value(selected, unit, unit.angle_symbol())
+                .on_hover_text(
+                    "Unit of the displayed angles and angular rates",
+                );
         }
     }
 
-    /// Handle updating connection status event.
+    /// Display attitude fusion algorithm selector.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    fn display_fusion_selector(&mut self, ui: &mut egui::Ui) {
+        let current = self.config.imu.fusion;
+        let mut selected = current;
+
+        ui.add_enabled_ui(self.commands.is_some(), |ui| {
+            ComboBox::from_label("Fusion")
+                .selected_text(selected.name())
+                .show_ui(ui, |ui| {
+                    for value in FusionAlgorithm::ALL {
+                        ui.selectable_value(&mut selected, value, value.name());
+                    }
+                })
+                .response
+                .on_hover_text("Attitude fusion algorithm")
+                .on_disabled_hover_text("Ingester is not running");
+        });
+
+        if selected == current {
+            return;
+        }
+
+        let command = IngesterCommand::SetFusion(selected);
+
+        match &self.commands {
+            Some(commands) if commands.send(command).is_ok() => {
+                self.config.imu.fusion = selected;
+            }
+            _ => log::error!("Ingester is not running, fusion is not changed"),
+        }
+    }
+
+    /// Display toggles of the sensors fused into the attitude.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    fn display_fusion_inputs(&mut self, ui: &mut egui::Ui) {
+        let current = self.config.imu.inputs;
+        let mut selected = current;
+
+        ui.add_enabled_ui(self.commands.is_some(), |ui| {
+            for (is_enabled, label, hint) in [
+                (&mut selected.acc, "ACC", "Fuse accelerometer readings"),
+                (&mut selected.gyr, "GYR", "Fuse gyroscope readings"),
+                (&mut selected.mag, "MAG", "Fuse magnetometer readings"),
+            ] {
+                ui.toggle_value(is_enabled, label)
+                    .on_hover_text(hint)
+                    .on_disabled_hover_text("Ingester is not running");
+            }
+        });
+
+        if selected == current {
+            return;
+        }
+
+        let command = IngesterCommand::SetFusionInputs(selected);
+
+        match &self.commands {
+            Some(commands) if commands.send(command).is_ok() => {
+                self.config.imu.inputs = selected;
+            }
+            _ => log::error!("Ingester is not running, inputs are not changed"),
+        }
+    }
+
+    /// Display pause button.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    fn display_pause_button(&mut self, ui: &mut egui::Ui) {
+        let pause_color = if self.is_paused {
+            Color32::from_rgb(255, 165, 0)
+        } else {
+            Color32::from_gray(60)
+        };
+
+        let text = if self.is_paused {
+            "▶ Resume Stream"
+        } else {
+            "⏸ Pause Stream"
+        };
+
+        let btn =
+            egui::Button::new(RichText::new(text).strong()).fill(pause_color);
+
+        if ui.add(btn).clicked() {
+            self.toggle_pause();
+        }
+
+        if self.is_paused {
+            ui.label(
+                RichText::new("(DISPLAY FROZEN)")
+                    .color(Color32::YELLOW)
+                    .small(),
+            );
+        }
+    }
+
+    /// Pause or resume the stream display.
+    fn toggle_pause(&mut self) {
+        self.is_paused = !self.is_paused;
+
+        if !self.is_paused {
+            self.stop_playback();
+        }
+    }
+
+    /// Handle keyboard shortcuts.
+    ///
+    /// Shortcuts are ignored while a text field has keyboard focus.
+    ///
+    /// # Parameters
+    /// - `ctx` - given egui context to handle.
+    fn handle_shortcuts(&mut self, ctx: &Context) {
+        if ctx.wants_keyboard_input() {
+            return;
+        }
+
+        for (action, shortcut) in self.config.shortcuts.shortcuts() {
+            if !ctx.input_mut(|i| i.consume_shortcut(&shortcut)) {
+                continue;
+            }
+
+            match action {
+                ShortcutAction::Pause => self.toggle_pause(),
+                ShortcutAction::Record => self.toggle_logging(),
+                ShortcutAction::NextTab => self.switch_tab(1),
+                ShortcutAction::PreviousTab => self.switch_tab(-1),
+                ShortcutAction::ResetAttitude => self.reset_attitude(),
+                ShortcutAction::Screenshot => self.request_screenshot(ctx),
+            }
+        }
+    }
+
+    /// Show neighbouring tab of the tab bar in the focused pane.
+    ///
+    /// # Parameters
+    /// - `step` - given offset of the tab in the tab bar (wraps around).
+    fn switch_tab(&mut self, step: isize) {
+        // Popped out tabs stay in their windows.
+        let mut available = self.available_tabs();
+        available.retain(|kind| !self.dock.windows.contains(kind));
+
+        let current = self.dock.panes.get(self.dock.focused).copied();
+        let Some(index) =
+            current.and_then(|kind| available.iter().position(|&k| k == kind))
+        else {
+            return;
+        };
+
+        let next = index
+            .checked_add_signed(step)
+            .map_or(available.len().saturating_sub(1), |next| {
+                next % available.len()
+            });
+
+        // Tab shown in another pane is focused instead of being moved.
+        if let Some(&kind) = available.get(next) {
+            self.dock.select(kind);
+        }
+    }
+
+    /// Display playback scrubber and sync tabs to the selected frame.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    fn display_playback_panel(&mut self, ui: &mut egui::Ui) {
+        let Some(frame_ctx) = self.playback.show(ui) else {
+            return;
+        };
+
+        for tab in &mut self.tabs {
+            match tab {
+                AppTab::Dashboard(tab) => {
+                    tab.set_cursor(Some(frame_ctx.timestamp));
+                }
+                AppTab::Telemetry(tab) => tab.set_cursor(Some(&frame_ctx)),
+                _ => {}
+            }
+        }
+
+        self.current_frame = Some(frame_ctx);
+    }
+
+    /// Return tabs from the scrubbed frame to the latest one.
+    fn stop_playback(&mut self) {
+        self.playback.stop();
+
+        for tab in &mut self.tabs {
+            match tab {
+                AppTab::Dashboard(tab) => tab.set_cursor(None),
+                AppTab::Telemetry(tab) => tab.set_cursor(None),
+                _ => {}
+            }
+        }
+
+        if let Some(frame_ctx) = self.history.back() {
+            self.current_frame = Some(Arc::clone(frame_ctx));
+        }
+    }
+
+    /// Display record button.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    fn display_record_button(&mut self, ui: &mut egui::Ui) {
+        let is_logging = self.logger.is_some();
+
+        let (btn_label, btn_color) = {
+            self.logger.as_ref().map_or_else(
+                || ("⏺ Record".to_string(), Color32::from_gray(40)),
+                |logger| {
+                    let label = match logger.segment() {
+                        1 => format!("⏹ {}", logger.timestamp_str()),
+                        n => format!("⏹ {} (seg {n})", logger.timestamp_str()),
+                    };
+
+                    (label, Color32::DARK_RED)
+                },
+            )
+        };
+
+        let response = ui.add(egui::Button::new(btn_label).fill(btn_color));
+
+        if response.clicked() {
+            self.toggle_logging();
+        }
+
+        let on_hover_text = if is_logging {
+            "Stop Recording"
+        } else {
+            "Start Recording"
+        };
+
+        response.on_hover_text(on_hover_text);
+    }
+
+    /// Display active log file info or reveal action of the last one.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    fn display_recording_status(&self, ui: &mut egui::Ui) {
+        if let Some(logger) = &self.logger {
+            let path = Path::new(logger.path());
+            let name = path.file_name().map_or_else(
+                || logger.path().clone(),
+                |name| name.to_string_lossy().into_owned(),
+            );
+
+            ui.label(format!(
+                "📄 {name} · {:.1} MB · {}",
+                housekeeping::megabytes(logger.size()),
+                logger.timestamp_str()
+            ))
+            .on_hover_text(logger.path());
+        } else if let Some(path) = &self.last_recording {
+            let response = ui
+                .link("📂 Show recording")
+                .on_hover_text(path.display().to_string());
+
+            if response.clicked()
+                && let Err(e) = logger::reveal_in_file_manager(path)
+            {
+                log::error!("Failed to open file manager: {e}");
+            }
+        }
+    }
+
+    /// Display modal window with triggered alert info.
+    ///
+    /// # Parameters
+    /// - `ctx` - given egui context to handle.
+    fn display_alert_modal(&mut self, ctx: &Context) {
+        let Some(alert) = &self.alert_modal else {
+            return;
+        };
+
+        let mut is_closed = false;
+        let modal = Modal::new(Id::new("alert_modal")).show(ctx, |ui| {
+            ui.heading(RichText::new("⚠ Alert").color(Color32::RED));
+            ui.separator();
+            ui.label(RichText::new(&alert.rule).strong());
+            ui.label(format!(
+                "{:?}: {} (threshold {})",
+                alert.metric, alert.value, alert.threshold
+            ));
+            ui.label(format!("Time: {}", alert.time));
+            ui.add_space(8.0);
+
+            if ui.button("OK").clicked() {
+                is_closed = true;
+            }
+        });
+
+        if is_closed || modal.should_close() {
+            self.alert_modal = None;
+        }
+    }
+
+    /// Display connection settings window and apply changed settings.
+    ///
+    /// # Parameters
+    /// - `ctx` - given egui context to handle.
+    fn display_connection_panel(&mut self, ctx: &Context) {
+        let Some(action) = self.connection_panel.show(ctx, &self.config.net)
+        else {
+            return;
+        };
+
+        // Filter is changed first to be applied to the reopened source.
+        let commands = match &action {
+            ConnectionAction::Connect(net, device_id) => vec![
+                IngesterCommand::SetFrameFilter {
+                    device_id: *device_id,
+                    use_encryption: net.use_encryption,
+                },
+                IngesterCommand::Reconnect(net.clone()),
+            ],
+            ConnectionAction::Disconnect => vec![IngesterCommand::Disconnect],
+        };
+
+        match &self.commands {
+            Some(sender)
+                if commands
+                    .into_iter()
+                    .all(|command| sender.send(command).is_ok()) =>
+            {
+                self.archive_session();
+
+                if let ConnectionAction::Connect(net, device_id) = action {
+                    self.config.net = *net;
+                    self.device_filter = device_id;
+                }
+            }
+            _ => log::error!("Ingester is not running, settings are ignored"),
+        }
+    }
+
+    /// Archive current device session into the session browser.
+    ///
+    /// History and counters start from scratch for the next session, and
+    /// logging of the closed session is stopped.
+    fn archive_session(&mut self) {
+        let now = Local::now();
+        let session = ArchivedSession {
+            source: self.config.net.endpoint(),
+            started: mem::replace(&mut self.session_start, now),
+            ended: now,
+            stats: mem::take(&mut *self.stats.lock()),
+            history: self.history.drain(..).collect(),
+        };
+
+        self.current_frame = None;
+        self.stop_logging();
+        self.reset_live_tabs();
+
+        if session.is_empty() {
+            return;
+        }
+
+        if let Some(AppTab::Sessions(tab)) = self
+            .tabs
+            .iter_mut()
+            .find(|tab| matches!(tab, AppTab::Sessions(_)))
+        {
+            tab.archive(session);
+        }
+    }
+
+    /// Display central panel.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    #[inline]
+    fn display_central_panel(&mut self, ui: &mut egui::Ui) {
+        self.display_panes(ui);
+
+        if self.is_paused {
+            return;
+        }
+
+        if self.config.display.is_min_spec {
+            let period = 1.0 / self.config.display.min_spec.fps_cap;
+            ui.ctx()
+                .request_repaint_after(Duration::from_secs_f32(period));
+        } else {
+            ui.ctx().request_repaint();
+        }
+    }
+
+    /// Display bottom panel.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `fps` - given smoothed number of frames per second.
+    fn display_bottom_panel(&mut self, ui: &mut egui::Ui, fps: usize) {
+        ui.horizontal(|ui| {
+            self.display_connection_chip(ui);
+            self.display_g_load_chip(ui);
+            self.display_stale_data_chip(ui);
+            self.display_encryption_chip(ui);
+            self.display_total_packets_chip(ui);
+            self.display_bad_packets_chip(ui);
+            self.display_lost_packets_chip(ui);
+            self.display_stream_chip(ui);
+            self.display_throughput_chip(ui);
+            self.display_dropped_chip(ui);
+            self.display_link_chip(ui);
+            self.display_motion_chip(ui);
+            display_fps_chip(ui, fps);
+            self.display_min_spec_chip(ui);
+
+            ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                ui.label(format!("AHRS Monitor - v{}", config::VERSION));
+            });
+        });
+    }
+
+    /// Display connection status chip.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    fn display_connection_chip(&self, ui: &mut egui::Ui) {
+        let connection_label = if self.connection_status {
+            RichText::new("CONNECTED").color(Color32::GREEN)
+        } else {
+            RichText::new("DISCONNECTED").color(Color32::RED)
+        };
+
+        ui.label(connection_label);
+        ui.separator();
+    }
+
+    /// Display G-load alert chip.
+    ///
+    /// G-load alert is held until max G-load is reset.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    fn display_g_load_chip(&self, ui: &mut egui::Ui) {
+        let limit = self.config.imu.g_load.limit;
+
+        if let Some(g_load) = self.current_frame.as_ref().and_then(|f| f.g_load)
+            && g_load.max > limit
+        {
+            ui.label(
+                RichText::new(format!("⚠ G-LOAD {:.1} g", g_load.max))
+                    .color(Color32::RED),
+            )
+            .on_hover_text(format!(
+                "Max G-load exceeds limit of {limit} g, reset it in the \
+                 Dashboard"
+            ));
+            ui.separator();
+        }
+    }
+
+    /// Display stale data alarm chip.
+    ///
+    /// Stale data alarm is separate from connection loss: frames keep
+    /// arriving, but their readings do not change.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    fn display_stale_data_chip(&self, ui: &mut egui::Ui) {
+        if let Some(frame_ctx) = &self.current_frame
+            && frame_ctx.is_stale_data
+        {
+            ui.label(RichText::new("⚠ STALE DATA").color(Color32::ORANGE))
+                .on_hover_text(format!(
+                    "Payload is unchanged for {} frames while sequence \
+                     numbers advance: sensor is frozen or firmware resends \
+                     a stale buffer",
+                    frame_ctx.repeated_frames
+                ));
+            ui.separator();
+        }
+    }
+
+    /// Display encryption status chip.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    fn display_encryption_chip(&self, ui: &mut egui::Ui) {
+        let encryption_label = if self.config.net.use_encryption {
+            RichText::new("🔒 ENCRYPTED").color(Color32::LIGHT_GREEN)
+        } else {
+            RichText::new("🔓 UNENCRYPTED").color(Color32::YELLOW)
+        };
+
+        ui.label(encryption_label);
+        ui.separator();
+    }
+
+    /// Display session total packets chip.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    fn display_total_packets_chip(&self, ui: &mut egui::Ui) {
+        let total = self.stats.lock().total_packets;
+
+        ui.label(format!("Total packets: {total}"));
+        ui.separator();
+    }
+
+    /// Display session bad packets chip.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    fn display_bad_packets_chip(&self, ui: &mut egui::Ui) {
+        let bad = self.stats.lock().bad_packets;
+
+        ui.label(format!("Bad packets: {}", bad.total()))
+            .on_hover_text(format!(
+                "Header: {}\nLength: {}\nCRC/MAC: {}\nDecryption: {}\n\
+                 Payload: {}\nDuplicate: {}\nStale: {}",
+                bad.header,
+                bad.length,
+                bad.integrity,
+                bad.decryption,
+                bad.payload,
+                bad.duplicate,
+                bad.stale
+            ));
+        ui.separator();
+    }
+
+    /// Display session lost packets chip.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    fn display_lost_packets_chip(&self, ui: &mut egui::Ui) {
+        let stats = self.stats.lock();
+        let (lost, loss_ratio) = (stats.lost_packets, stats.loss_ratio());
+        drop(stats);
+
+        ui.label(format!("Lost: {lost} ({:.1}%)", loss_ratio * 100.0))
+            .on_hover_text("Packets missing according to sequence numbers");
+        ui.separator();
+    }
+
+    /// Display packet rate chip with its sparkline.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    fn display_stream_chip(&self, ui: &mut egui::Ui) {
+        let Some(frame_ctx) = &self.current_frame else {
+            return;
+        };
+
+        // Sampling the latest statistics of the stats worker.
+        let stats = self.stats.lock();
+        let pps_summary = stats.pps_summary();
+        let recent_pps: Vec<usize> =
+            stats.recent_pps(PPS_SPARKLINE_SIZE).collect();
+        drop(stats);
+
+        let pps_hover = pps_summary.map_or_else(
+            || "No history yet".to_string(),
+            |pps| {
+                format!(
+                    "Session min/avg/max: {}/{:.1}/{} packets/sec",
+                    pps.min, pps.avg, pps.max
+                )
+            },
+        );
+
+        ui.label(format!("Stream: {} packets/sec", frame_ctx.pps))
+            .on_hover_text(&pps_hover);
+        utils::display_sparkline(
+            ui,
+            &recent_pps,
+            PPS_SPARKLINE_SIZE,
+            Color32::LIGHT_BLUE,
+        )
+        .on_hover_text(format!(
+            "Packets/sec over the last {PPS_SPARKLINE_SIZE} seconds\n\
+             {pps_hover}"
+        ));
+        ui.separator();
+    }
+
+    /// Display data throughput chip.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    fn display_throughput_chip(&self, ui: &mut egui::Ui) {
+        let Some(frame_ctx) = &self.current_frame else {
+            return;
+        };
+
+        ui.label(format!(
+            "Throughput: {}",
+            utils::format_throughput(frame_ctx.bytes_per_second)
+        ))
+        .on_hover_text(format!(
+            "{} kbit/s received over the last second, including rejected \
+             and duplicated datagrams",
+            frame_ctx.bytes_per_second * 8 / 1000
+        ));
+        ui.separator();
+    }
+
+    /// Display chip of the frames dropped by the events channel.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    fn display_dropped_chip(&self, ui: &mut egui::Ui) {
+        if let Some(frame_ctx) = &self.current_frame
+            && frame_ctx.dropped_events > 0
+        {
+            let dropped_label =
+                RichText::new(format!("Dropped: {}", frame_ctx.dropped_events))
+                    .color(Color32::YELLOW);
+
+            ui.label(dropped_label)
+                .on_hover_text("Frames dropped by the events channel");
+            ui.separator();
+        }
+    }
+
+    /// Display active link chip of redundant frame sources.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    fn display_link_chip(&self, ui: &mut egui::Ui) {
+        let Some(frame_ctx) = &self.current_frame else {
+            return;
+        };
+
+        let Some(link) = frame_ctx.active_link else {
+            return;
+        };
+
+        let link_label = match link {
+            Link::Primary => RichText::new("Link: Primary"),
+            Link::Secondary => {
+                RichText::new("Link: Secondary").color(Color32::YELLOW)
+            }
+        };
+
+        let link_hover = frame_ctx.link_stats.map_or_else(
+            || "Active link of redundant frame sources".to_string(),
+            |stats| link_stats_summary(&stats),
+        );
+
+        ui.label(link_label).on_hover_text(link_hover);
+        ui.separator();
+    }
+
+    /// Display motion state chip.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    fn display_motion_chip(&self, ui: &mut egui::Ui) {
+        let Some(motion) = self.current_frame.as_ref().and_then(|f| f.motion)
+        else {
+            return;
+        };
+
+        let color = match motion {
+            MotionState::Static => Color32::LIGHT_GREEN,
+            MotionState::SlowMotion => Color32::LIGHT_BLUE,
+            MotionState::Dynamic => Color32::ORANGE,
+            MotionState::Freefall => Color32::RED,
+        };
+
+        let motion_label =
+            RichText::new(motion.name().to_uppercase()).color(color);
+
+        ui.label(motion_label)
+            .on_hover_text("Motion state classified from readings");
+        ui.separator();
+    }
+
+    /// Display reduced-graphics mode chip.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    fn display_min_spec_chip(&mut self, ui: &mut egui::Ui) {
+        if !self.config.display.is_min_spec {
+            return;
+        }
+
+        let label = RichText::new("MIN-SPEC").color(Color32::ORANGE);
+
+        if ui
+            .small_button(label)
+            .on_hover_text("Reduced-graphics mode, click to disable")
+            .clicked()
+        {
+            self.config.display.min_spec.mode = MinSpecMode::Off;
+            self.config.display.is_min_spec = false;
+        }
+        ui.separator();
+    }
+
+    /// Display docked tabs side by side.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    #[allow(clippy::cast_precision_loss)]
+    fn display_panes(&mut self, ui: &mut egui::Ui) {
+        let panes = self.dock.panes.clone();
+        let count = panes.len();
+        let mut action = None;
+
+        let Some((&last, rest)) = panes.split_last() else {
+            ui.centered_and_justified(|ui| {
+                ui.label("All tabs are shown in separate windows");
+            });
+            return;
+        };
+
+        if rest.is_empty() {
+            action = self.display_pane(ui, 0, last, count);
+        } else {
+            for (pane, &kind) in rest.iter().enumerate() {
+                // Free space is shared equally by the remaining panes.
+                let width = ui.available_width() / (count - pane) as f32;
+
+                SidePanel::left(Id::new(("dock_pane", pane)))
+                    .resizable(true)
+                    .default_width(width)
+                    .show_inside(ui, |ui| {
+                        let requested =
+                            self.display_pane(ui, pane, kind, count);
+                        action = action.or(requested);
+                    });
+            }
+
+            CentralPanel::default().show_inside(ui, |ui| {
+                let requested = self.display_pane(ui, count - 1, last, count);
+                action = action.or(requested);
+            });
+        }
+
+        if let Some(action) = action {
+            let available = self.available_tabs();
+            self.dock.apply(action, &available);
+        }
+    }
+
+    /// Display docked tab with its pane controls.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `pane` - given pane index.
+    /// - `kind` - given tab shown in the pane.
+    /// - `count` - given number of panes.
+    ///
+    /// # Returns
+    /// - Docking layout change requested by user - if any.
+    fn display_pane(
+        &mut self,
+        ui: &mut egui::Ui,
+        pane: usize,
+        kind: TabKind,
+        count: usize,
+    ) -> Option<DockAction> {
+        let index = self.tab_index(kind)?;
+        let label = self.tabs.get(index).map(AppTab::label)?;
+        let mut action = None;
+
+        ui.horizontal(|ui| {
+            let is_focused = self.dock.focused == pane;
+
+            if ui
+                .selectable_label(is_focused, label)
+                .on_hover_text("Tab bar switches the tab of the focused pane")
+                .clicked()
+            {
+                action = Some(DockAction::Focus(pane));
+            }
+
+            ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                if count > 1
+                    && ui
+                        .small_button("✖")
+                        .on_hover_text("Close pane")
+                        .clicked()
+                {
+                    action = Some(DockAction::Close(pane));
+                }
+
+                if ui
+                    .small_button("⧉")
+                    .on_hover_text("Pop out into a separate window")
+                    .clicked()
+                {
+                    action = Some(DockAction::PopOut(pane));
+                }
+
+                if ui
+                    .small_button("◫")
+                    .on_hover_text("Show another tab side by side")
+                    .clicked()
+                {
+                    action = Some(DockAction::Split(pane));
+                }
+            });
+        });
+        ui.separator();
+
+        self.render_tab(ui, index);
+        action
+    }
+
+    /// Display tabs popped out into separate windows.
+    ///
+    /// # Parameters
+    /// - `ctx` - given egui context to handle.
+    fn display_tab_windows(&mut self, ctx: &Context) {
+        let mut action = None;
+
+        for kind in self.dock.windows.clone() {
+            let Some(index) = self.tab_index(kind) else {
+                continue;
+            };
+            let Some(label) = self.tabs.get(index).map(AppTab::label) else {
+                continue;
+            };
+            let mut is_open = true;
+
+            Window::new(label)
+                .id(Id::new(("dock_window", index)))
+                .open(&mut is_open)
+                .default_size([640.0, 480.0])
+                .show(ctx, |ui| {
+                    if ui
+                        .small_button("⇲ Dock")
+                        .on_hover_text("Move back into the main window")
+                        .clicked()
+                    {
+                        action = Some(DockAction::Dock(kind));
+                    }
+                    ui.separator();
+
+                    self.render_tab(ui, index);
+                });
+
+            if !is_open {
+                action = Some(DockAction::CloseWindow(kind));
+            }
+        }
+
+        if let Some(action) = action {
+            let available = self.available_tabs();
+            self.dock.apply(action, &available);
+        }
+    }
+
+    /// Render tab.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `index` - given tab index.
+    fn render_tab(&mut self, ui: &mut egui::Ui, index: usize) {
+        self.feed_tab(index);
+
+        if self.render_offline_tab(ui, index) {
+            return;
+        }
+
+        let mut requests = TabRequests::default();
+
+        if let Some(tab) = self.tabs.get_mut(index)
+            && let Some(frame_ctx) = &self.current_frame
+            && (self.connection_status || self.is_replaying)
+        {
+            // Vendor-specific payloads are supported if custom decoder is set.
+            let is_decoded = frame_ctx
+                .frame
+                .as_ref()
+                .is_some_and(|frame| frame.payload.is_some());
+
+            if self.config.imu.is_correct() || is_decoded {
+                requests = show_live_tab(
+                    tab,
+                    ui,
+                    frame_ctx,
+                    &self.config,
+                    &mut self.layout,
+                );
+            } else {
+                ui.vertical_centered(|ui| {
+                    ui.add_space(ui.available_height() / 2.0 - 50.0);
+                    ui.add_space(10.0);
+
+                    let label_text = "UNSUPPORTED IMU PAYLOAD TYPE";
+                    ui.label(RichText::new(label_text).size(18.0));
+                });
+            }
+        } else {
+            ui.vertical_centered(|ui| {
+                ui.add_space(ui.available_height() / 2.0 - 50.0);
+                ui.add(egui::Spinner::new().size(40.0));
+                ui.add_space(10.0);
+
+                let label_text = if self.connection_status {
+                    "WAITING FOR IMU DATA..."
+                } else {
+                    "WAITING FOR CONNECTION..."
+                };
+
+                ui.label(RichText::new(label_text).size(18.0));
+            });
+        }
+
+        self.handle_tab_requests(requests);
+    }
+
+    /// Render tab which does not require IMU connection.
+    ///
+    /// Recorded and archived sessions do not require IMU connection.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `index` - given tab index.
+    ///
+    /// # Returns
+    /// - `true` - if tab is rendered.
+    /// - `false` - if tab shows live data.
+    fn render_offline_tab(&mut self, ui: &mut egui::Ui, index: usize) -> bool {
+        match self.tabs.get_mut(index) {
+            Some(AppTab::Compare(tab)) => tab.show(ui, &self.config),
+            Some(AppTab::Sessions(tab)) => tab.show(ui),
+            Some(AppTab::Analysis(tab)) => tab.show(ui, &self.config),
+            Some(AppTab::Replay(tab)) => {
+                if tab.show(ui) {
+                    self.is_replaying = tab.is_active();
+                    self.reset_shown_frames();
+                }
+            }
+            Some(AppTab::Settings(tab)) => {
+                if let Some(action) = tab.show(ui, &self.config) {
+                    let result = self.apply_settings(action);
+
+                    if let Some(AppTab::Settings(tab)) =
+                        self.tabs.get_mut(index)
+                    {
+                        tab.set_status(result);
+                    }
+                }
+            }
+            _ => return false,
+        }
+
+        true
+    }
+
+    /// Handle requests of the rendered tab.
+    ///
+    /// # Parameters
+    /// - `requests` - given requests to handle.
+    fn handle_tab_requests(&mut self, requests: TabRequests) {
+        match requests.calibration {
+            Some(AppliedCalibration::Acc(calibration)) => {
+                self.apply_acc_calibration(calibration);
+            }
+            Some(AppliedCalibration::Mag(calibration)) => {
+                self.apply_mag_calibration(calibration);
+            }
+            None => {}
+        }
+
+        if requests.is_g_load_reset {
+            self.reset_g_load();
+        }
+
+        if requests.is_layout_saved {
+            self.save_layout();
+        }
+
+        if requests.is_nav_reset {
+            self.reset_navigation();
+        }
+    }
+
+    /// Apply accelerometer calibration and save it to the config file.
+    ///
+    /// # Parameters
+    /// - `calibration` - given calibration of the raw readings to apply.
+    fn apply_acc_calibration(&mut self, calibration: AccCalibration) {
+        let command = IngesterCommand::SetAccCalibration(calibration);
+
+        match &self.commands {
+            Some(commands) if commands.send(command).is_ok() => {
+                self.config.imu.acc_calibration = calibration;
+            }
+            _ => {
+                log::error!(
+                    "Ingester is not running, calibration is not applied"
+                );
+                return;
+            }
+        }
+
+        match config::save_acc_calibration(&self.config_path, &calibration) {
+            Ok(()) => log::info!("Calibration saved to: {}", self.config_path),
+            Err(e) => log::error!("Failed to save calibration: {e}"),
+        }
+    }
+
+    /// Apply magnetometer calibration and save it to the config file.
+    ///
+    /// # Parameters
+    /// - `calibration` - given calibration of the raw readings to apply.
+    fn apply_mag_calibration(&mut self, calibration: MagCalibration) {
+        let command = IngesterCommand::SetMagCalibration(calibration);
+
+        match &self.commands {
+            Some(commands) if commands.send(command).is_ok() => {
+                self.config.imu.mag_calibration = calibration;
+            }
+            _ => {
+                log::error!(
+                    "Ingester is not running, calibration is not applied"
+                );
+                return;
+            }
+        }
+
+        match config::save_mag_calibration(&self.config_path, &calibration) {
+            Ok(()) => log::info!("Calibration saved to: {}", self.config_path),
+            Err(e) => log::error!("Failed to save calibration: {e}"),
+        }
+    }
+
+    /// Apply configurations edited in the Settings tab.
+    ///
+    /// Network changes reconnect the ingester, fusion algorithm and inputs
+    /// are switched on the fly, display settings take effect at the next
+    /// frame. Other IMU settings are used by the ingester after restart.
+    ///
+    /// # Parameters
+    /// - `action` - given settings change requested by user.
+    ///
+    /// # Returns
+    /// - Status message - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - Invalid configuration values.
+    /// - Ingester is not running while network or fusion settings changed.
+    /// - Config file writing errors.
+    fn apply_settings(
+        &mut self,
+        action: SettingsAction,
+    ) -> anyhow::Result<String> {
+        let (mut edited, is_saved) = match action {
+            SettingsAction::Apply(edited) => (edited, false),
+            SettingsAction::Save(edited) => (edited, true),
+        };
+
+        // Payload type is not editable, so it is known to be decoded.
+        edited.validate(&[edited.imu.payload_type])?;
+
+        let current = &self.config;
+        let mut commands = Vec::new();
+
+        if config::is_modified(&current.net, &edited.net) {
+            commands
+                .push(IngesterCommand::Reconnect(Box::new(edited.net.clone())));
+        }
+
+        if edited.imu.fusion != current.imu.fusion {
+            commands.push(IngesterCommand::SetFusion(edited.imu.fusion));
+        }
+
+        if edited.imu.inputs != current.imu.inputs {
+            commands.push(IngesterCommand::SetFusionInputs(edited.imu.inputs));
+        }
+
+        let mut restart_imu = edited.imu.clone();
+        restart_imu.fusion = current.imu.fusion;
+        restart_imu.inputs = current.imu.inputs;
+        let is_restart_required =
+            config::is_modified(&current.imu, &restart_imu);
+        let is_reconnected =
+            matches!(commands.first(), Some(IngesterCommand::Reconnect(_)));
+
+        if !commands.is_empty() {
+            let Some(sender) = &self.commands else {
+                anyhow::bail!("ingester is not running, settings are ignored");
+            };
+
+            for command in commands {
+                sender
+                    .send(command)
+                    .map_err(|_| anyhow::anyhow!("ingester is not running"))?;
+            }
+        }
+
+        if is_reconnected {
+            self.archive_session();
+        }
+
+        edited.channels =
+            self.config.channels.clone().with_full_scale(&edited.imu);
+        self.config = *edited;
+
+        let mut status = String::from("Settings applied");
+
+        if is_saved {
+            config::save_config(&self.config_path, &self.config)?;
+            status = format!("Settings saved to: {}", self.config_path);
+        }
+
+        if is_restart_required {
+            status.push_str(" (IMU settings take effect after restart)");
+        }
+
+        log::info!("{status}");
+        Ok(status)
+    }
+
+    /// Save active layout preset to the config file.
+    fn save_layout(&mut self) {
+        let layouts = &mut self.config.display.layouts;
+
+        match layouts.iter_mut().find(|p| p.name == self.layout.name) {
+            Some(preset) => preset.clone_from(&self.layout),
+            None => layouts.push(self.layout.clone()),
+        }
+
+        match config::save_layout_preset(&self.config_path, &self.layout) {
+            Ok(()) => log::info!(
+                "Layout preset {} saved to: {}",
+                self.layout.name,
+                self.config_path
+            ),
+            Err(e) => log::error!("Failed to save layout preset: {e}"),
+        }
+    }
+
+    /// Store last known attitude and gyroscope bias of the devices.
+    fn save_warm_start(&self) {
+        if !self.config.imu.warm_start {
+            return;
+        }
+
+        let path = config::WARM_START_FILE_PATH;
+        let frames = self.history.iter().map(AsRef::as_ref);
+
+        match warm_start::save_last_states(path, frames) {
+            Ok(()) => log::info!("Warm-start state saved to: {path}"),
+            Err(e) => log::error!("Failed to save warm-start state: {e}"),
+        }
+    }
+
+    /// Store docking layout of the tabs.
+    fn save_dock_layout(&self) {
+        let path = config::DOCK_LAYOUT_FILE_PATH;
+
+        match self.dock.save(path) {
+            Ok(()) => log::info!("Dock layout saved to: {path}"),
+            Err(e) => log::error!("Failed to save dock layout: {e}"),
+        }
+    }
+
+    /// Store plot traces hidden from the legend.
+    fn save_hidden_traces(&self) {
+        let path = config::HIDDEN_TRACES_FILE_PATH;
+
+        match self.hidden_traces.save(path) {
+            Ok(()) => log::info!("Hidden plot traces saved to: {path}"),
+            Err(e) => log::error!("Failed to save hidden plot traces: {e}"),
+        }
+    }
+
+    /// Restart attitude estimation of all devices.
+    fn reset_attitude(&self) {
+        let command = IngesterCommand::ResetAttitude;
+
+        match &self.commands {
+            Some(commands) if commands.send(command).is_ok() => {}
+            _ => log::error!("Ingester is not running, attitude is not reset"),
+        }
+    }
+
+    /// Reset dead-reckoning velocity and position.
+    fn reset_navigation(&self) {
+        let command = IngesterCommand::ResetNavigation;
+
+        match &self.commands {
+            Some(commands) if commands.send(command).is_ok() => {}
+            _ => {
+                log::error!("Ingester is not running, navigation is not reset")
+            }
+        }
+    }
+
+    /// Reset max G-load.
+    fn reset_g_load(&self) {
+        let command = IngesterCommand::ResetGLoad;
+
+        match &self.commands {
+            Some(commands) if commands.send(command).is_ok() => {}
+            _ => log::error!("Ingester is not running, G-load is not reset"),
+        }
+    }
+
+    /// Handle events from ingester.
+    fn handle_events(&mut self) {
+        while let Some(event) = self.rx.try_recv() {
+            self.record_event(&event);
+
+            match event {
+                AppEvent::UpdateConnectionStatus(status) => {
+                    self.handle_update_connection_status(status);
+                }
+                // Live frames are not mixed into the replayed log.
+                AppEvent::FrameReceived(_) if self.is_replaying => {}
+                AppEvent::FrameReceived(frame_ctx) => {
+                    self.handle_received_frame(*frame_ctx);
+                }
+                AppEvent::AlertTriggered(alert) => {
+                    self.alerts.add(alert.as_ref().clone());
+
+                    if alert.show_modal {
+                        self.alert_modal = Some(alert);
+                    }
+                }
+                AppEvent::ShockDetected(shock) => {
+                    self.handle_shock(&shock);
+                }
+            }
+        }
+    }
+
+    /// Feed frames released by the recorded log replay.
+    ///
+    /// # Parameters
+    /// - `ctx` - given egui context to handle.
+    fn handle_replay(&mut self, ctx: &Context) {
+        let dt = ctx.input(|i| i.stable_dt);
+
+        let Some(AppTab::Replay(tab)) = self
+            .tabs
+            .iter_mut()
+            .find(|tab| matches!(tab, AppTab::Replay(_)))
+        else {
+            return;
+        };
+
+        let frames = tab.advance(dt);
+
+        for frame_ctx in frames {
+            self.handle_received_frame(frame_ctx);
+        }
+
+        if self.is_replaying {
+            ctx.request_repaint();
+        }
+    }
+
+    /// Discard frames shown by the tabs, e.g. when switching between the
+    /// live stream and the replayed log.
+    fn reset_shown_frames(&mut self) {
+        self.current_frame = None;
+        self.history.clear();
+        self.is_paused = false;
+        self.reset_live_tabs();
+    }
+
+    /// Handle detected shock event.
+    ///
+    /// Shock is marked on the Telemetry plots and written to the log as an
+    /// event row.
+    ///
+    /// # Parameters
+    /// - `shock` - given detected shock event.
+    fn handle_shock(&mut self, shock: &ShockEvent) {
+        if let Some(logger) = &mut self.logger {
+            logger.write(&LogRecord::from_shock(shock)).ok();
+        }
+
+        if self.is_paused {
+            return;
+        }
+
+        // Event is marked at the latest plotted frame, so that hidden tab
+        // is caught up first.
+        if let Some(index) = self
+            .tabs
+            .iter()
+            .position(|tab| matches!(tab, AppTab::Telemetry(_)))
+        {
+            self.feed_tab(index);
+
+            if let Some(AppTab::Telemetry(tab)) = self.tabs.get_mut(index) {
+                tab.add_event(shock.to_string());
+            }
+        }
+    }
+
+    /// Feed frames missed by the tab while it was hidden.
+    ///
+    /// # Parameters
+    /// - `index` - given tab index.
+    fn feed_tab(&mut self, index: usize) {
+        let Some(tab) = self.tabs.get_mut(index) else {
+            return;
+        };
+
+        for frame_ctx in self.backlog.take(index) {
+            let Some(frame) = &frame_ctx.frame else {
+                continue;
+            };
+
+            match tab {
+                AppTab::Telemetry(tab) => {
+                    tab.add_data(
+                        frame,
+                        frame_ctx.timestamp,
+                        frame_ctx.host_time_us,
+                        frame_ctx.clipping,
+                        frame_ctx.linear_acc,
+                        frame_ctx.altitude,
+                        frame_ctx.vibration,
+                        &frame_ctx.anomalies,
+                        frame_ctx.mode_transition,
+                        frame_ctx.motion_transition,
+                    );
+
+                    if let Some(gap) = frame_ctx.resumed_after {
+                        tab.add_event(format!("resumed after {gap:.0} s"));
+                    }
+                }
+                AppTab::Dashboard(tab) => {
+                    tab.add_device_frame(frame_ctx);
+                    tab.add_data(
+                        &frame_ctx.quaternion,
+                        frame_ctx.attitude_error,
+                        frame_ctx.attitude_uncertainty,
+                        frame_ctx.timestamp,
+                    );
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Append event to the events journal.
+    ///
+    /// # Parameters
+    /// - `event` - given application event to journal.
+    fn record_event(&mut self, event: &AppEvent) {
+        if let Some(journal) = &mut self.journal
+            && let Err(e) = journal.record(event)
+        {
+            log::error!("Events journal writing failed, stopping: {e}");
+            self.journal = None;
+        }
+    }
+
+    /// Handle updating connection status event.
     ///
     /// # Parameters
     /// - `status` - given new connection status between AHRS monitor and IMU.
     fn handle_update_connection_status(&mut self, status: bool) {
+        // Session of the device ends, its history is discarded.
+        self.save_warm_start();
         self.connection_status = status;
         self.current_frame = None;
-        self.logger = None;
+        self.stop_logging();
         self.history.clear();
         self.frame_counter = 0;
         self.is_paused = false;
-        self.tabs = vec![
-            AppTab::Dashboard(DashboardTab::default()),
-            AppTab::Telemetry(Box::default()),
-            AppTab::Inspector(InspectorTab),
-        ];
+        self.reset_live_tabs();
+    }
+
+    /// Start new session segment after device reboot.
+    ///
+    /// History of the rebooted device is discarded and logging continues in
+    /// the new log file, so that restarted sensor clock does not produce time
+    /// discontinuity. Data of other devices is kept.
+    ///
+    /// # Parameters
+    /// - `device_id` - given rebooted device identifier.
+    fn handle_device_reboot(&mut self, device_id: u8) {
+        self.history.retain(|frame_ctx| {
+            frame_ctx
+                .frame
+                .as_ref()
+                .is_none_or(|frame| frame.header.device_id != device_id)
+        });
+
+        if !self.is_replaying
+            && let Some(logger) = &mut self.logger
+        {
+            match logger.next_segment() {
+                Ok(()) => log::warn!(
+                    "Device rebooted, logging continues in: {}",
+                    logger.path()
+                ),
+                Err(e) => {
+                    log::error!("Failed to create log file: {e}");
+                    self.stop_logging();
+                }
+            }
+        }
+    }
+
+    /// Reset live data tabs.
+    fn reset_live_tabs(&mut self) {
+        self.playback.clear();
+        self.backlog.clear();
+
+        for tab in &mut self.tabs {
+            match tab {
+                AppTab::Dashboard(tab) => {
+                    **tab =
+                        DashboardTab::default().with_model(self.model.clone());
+                }
+                AppTab::Telemetry(tab) => **tab = TelemetryTab::default(),
+                AppTab::Calibration(tab) => **tab = CalibrationTab::default(),
+                AppTab::Trajectory(tab) => **tab = TrajectoryTab::default(),
+                AppTab::Spectrum(tab) => **tab = SpectrumTab::default(),
+                AppTab::Inspector(tab) => **tab = InspectorTab::default(),
+                AppTab::Compare(_)
+                | AppTab::Sessions(_)
+                | AppTab::Replay(_)
+                | AppTab::Analysis(_)
+                | AppTab::Settings(_) => {}
+            }
+        }
     }
 
     /// Handle received frame event.
     ///
     /// # Parameters
     /// - `frame_ctx` - given new frame context info.
-    fn handle_received_frame(&mut self, frame_ctx: FrameContext) {
+    fn handle_received_frame(&mut self, mut frame_ctx: FrameContext) {
+        self.perf.record_processing(frame_ctx.processing_time_us);
+
+        if frame_ctx.is_reboot
+            && let Some(frame) = &frame_ctx.frame
+        {
+            self.handle_device_reboot(frame.header.device_id);
+        }
+
+        if let Some(rejected) = frame_ctx.rejected.take() {
+            self.quarantine.push_back(rejected);
+
+            if self.quarantine.len() > config::QUARANTINE_MAX_SIZE {
+                self.quarantine.pop_front();
+            }
+        }
+
         let shared_ctx = Arc::new(frame_ctx);
 
         self.history.push_back(Arc::clone(&shared_ctx));
@@ -392,25 +2322,68 @@ impl App {
 
         if !self.is_paused {
             if let Some(ref frame) = shared_ctx.frame {
-                if let Some(AppTab::Telemetry(tab)) = self
+                let plot_history = self.config.display.plot_history();
+
+                self.playback.push(Arc::clone(&shared_ctx), plot_history);
+
+                // Dashboard and Telemetry plots are fed when the tab is
+                // rendered, hidden tabs are backfilled once they are selected.
+                self.backlog.push(Arc::clone(&shared_ctx), plot_history);
+
+                if let Some(AppTab::Inspector(tab)) = self
+                    .tabs
+                    .iter_mut()
+                    .find(|tab| matches!(tab, AppTab::Inspector(_)))
+                {
+                    tab.add_frame(Arc::clone(&shared_ctx));
+                }
+
+                // Replayed log must not be sampled as a new calibration run.
+                if !self.is_replaying
+                    && let Some(AppTab::Calibration(tab)) = self
+                        .tabs
+                        .iter_mut()
+                        .find(|tab| matches!(tab, AppTab::Calibration(_)))
+                {
+                    tab.add_data(
+                        frame,
+                        &self.config.imu.mounting,
+                        shared_ctx.motion,
+                    );
+                }
+
+                if let Some(AppTab::Analysis(tab)) = self
                     .tabs
                     .iter_mut()
-                    .find(|tab| matches!(tab, AppTab::Telemetry(_)))
+                    .find(|tab| matches!(tab, AppTab::Analysis(_)))
                 {
                     tab.add_data(frame, shared_ctx.timestamp);
                 }
 
-                if let Some(AppTab::Dashboard(tab)) = self
+                if let Some(AppTab::Spectrum(tab)) = self
                     .tabs
                     .iter_mut()
-                    .find(|tab| matches!(tab, AppTab::Dashboard(_)))
+                    .find(|tab| matches!(tab, AppTab::Spectrum(_)))
+                {
+                    tab.add_data(frame, shared_ctx.timestamp);
+                }
+
+                if let Some(navigation) = shared_ctx.navigation
+                    && let Some(AppTab::Trajectory(tab)) = self
+                        .tabs
+                        .iter_mut()
+                        .find(|tab| matches!(tab, AppTab::Trajectory(_)))
                 {
-                    tab.add_data(&shared_ctx.quaternion, shared_ctx.timestamp);
+                    tab.add_data(navigation);
                 }
             }
 
             self.current_frame = Some(Arc::clone(&shared_ctx));
-            self.write_record(&shared_ctx);
+
+            // Replayed frames are already recorded in the replayed log.
+            if !self.is_replaying {
+                self.write_record(&shared_ctx);
+            }
         }
     }
 
@@ -419,44 +2392,147 @@ impl App {
     /// # Parameters
     /// - `frame_ctx` - given current frame context info.
     fn write_record(&mut self, frame_ctx: &FrameContext) {
-        if let Some(frame) = &frame_ctx.frame {
-            let header = frame.header;
-
-            let (q_w, q_x, q_y, q_z, roll, pitch, yaw) = frame_ctx
-                .quaternion
-                .map_or((1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0), |quat| {
-                    let e = quat.euler_angles();
-                    (quat.w, quat.i, quat.j, quat.k, e.0, e.1, e.2)
-                });
+        if let Some(logger) = &mut self.logger
+            && let Some(record) = LogRecord::from_frame_context(frame_ctx)
+        {
+            logger.write(&record).ok();
+        }
+    }
+}
 
-            let mut record = LogRecord {
-                timestamp: frame_ctx.timestamp,
-                device_id: header.device_id,
-                q_w,
-                q_x,
-                q_y,
-                q_z,
-                roll,
-                pitch,
-                yaw,
-                ..LogRecord::default()
-            };
+/// Requests of the rendered tab handled by the application.
+#[derive(Debug, Default)]
+struct TabRequests {
+    /// Calibration applied by the user.
+    calibration: Option<AppliedCalibration>,
+    /// Flag whether max G-load reset is requested.
+    is_g_load_reset: bool,
+    /// Flag whether layout saving is requested.
+    is_layout_saved: bool,
+    /// Flag whether dead-reckoning reset is requested.
+    is_nav_reset: bool,
+}
 
-            if let Some(payload) = &frame.payload {
-                match payload {
-                    StandardPayload::Imu3Acc(p) => p.fill_record(&mut record),
-                    StandardPayload::Imu3Gyr(p) => p.fill_record(&mut record),
-                    StandardPayload::Imu3Mag(p) => p.fill_record(&mut record),
-                    StandardPayload::Imu6(p) => p.fill_record(&mut record),
-                    StandardPayload::Imu9(p) => p.fill_record(&mut record),
-                    StandardPayload::Imu10(p) => p.fill_record(&mut record),
-                    StandardPayload::ImuQuat(p) => p.fill_record(&mut record),
-                }
-            }
+/// Show tab displaying live data.
+///
+/// # Parameters
+/// - `tab` - given tab to show.
+/// - `ui` - given screen UI handler.
+/// - `frame_ctx` - given current frame context to handle.
+/// - `config` - given global config to handle.
+/// - `layout` - given current layout preset to update.
+///
+/// # Returns
+/// - Requests of the tab.
+fn show_live_tab(
+    tab: &mut AppTab,
+    ui: &mut egui::Ui,
+    frame_ctx: &FrameContext,
+    config: &AppConfig,
+    layout: &mut LayoutPreset,
+) -> TabRequests {
+    let mut requests = TabRequests::default();
 
-            if let Some(logger) = &mut self.logger {
-                logger.write(&record).ok();
+    match tab {
+        AppTab::Dashboard(tab) => {
+            tab.set_instruments(&layout.instruments);
+            tab.ui(ui, frame_ctx, config);
+            requests.is_g_load_reset = tab.take_g_load_reset();
+            requests.is_layout_saved = tab.take_layout_save();
+
+            if let Some(instruments) = tab.take_instruments() {
+                layout.instruments = instruments;
             }
         }
+        AppTab::Telemetry(tab) => {
+            tab.set_visible_plots(&layout.plots);
+            tab.ui(ui, frame_ctx, config);
+        }
+        AppTab::Calibration(tab) => {
+            requests.calibration = tab.show(ui, config);
+        }
+        AppTab::Trajectory(tab) => {
+            requests.is_nav_reset = tab.show(ui, config);
+        }
+        AppTab::Inspector(tab) => tab.ui(ui, frame_ctx, config),
+        AppTab::Compare(tab) => tab.ui(ui, frame_ctx, config),
+        AppTab::Sessions(tab) => tab.ui(ui, frame_ctx, config),
+        AppTab::Replay(tab) => tab.ui(ui, frame_ctx, config),
+        AppTab::Analysis(tab) => tab.ui(ui, frame_ctx, config),
+        AppTab::Spectrum(tab) => tab.ui(ui, frame_ctx, config),
+        AppTab::Settings(tab) => tab.ui(ui, frame_ctx, config),
     }
+
+    requests
+}
+
+/// Load vehicle 3D model of the attitude widget.
+///
+/// # Parameters
+/// - `cfg` - given vehicle 3D model configurations.
+///
+/// # Returns
+/// - Loaded model - in case of success.
+/// - `None` - if model is not configured or failed to load.
+fn load_model(cfg: &ModelConfig) -> Option<Arc<Mesh>> {
+    if cfg.path.is_empty() {
+        return None;
+    }
+
+    match Mesh::load(cfg) {
+        Ok(mesh) => {
+            log::info!(
+                "Vehicle model loaded: {} ({} triangles)",
+                cfg.path,
+                mesh.triangles.len()
+            );
+            Some(Arc::new(mesh))
+        }
+        Err(e) => {
+            log::error!("Failed to load vehicle model, showing cube: {e}");
+            None
+        }
+    }
+}
+
+/// Display colored FPS indicator chip.
+///
+/// # Parameters
+/// - `ui` - given screen UI handler.
+/// - `fps` - given smoothed number of frames per second.
+fn display_fps_chip(ui: &mut egui::Ui, fps: usize) {
+    let fps_color = match fps {
+        f if f >= 60 => Color32::from_rgb(0, 200, 0),
+        f if f >= 45 => Color32::from_rgb(120, 200, 0),
+        f if f >= 30 => Color32::from_rgb(255, 165, 0),
+        f if f > 0 => Color32::from_rgb(220, 30, 30),
+        _ => Color32::GRAY,
+    };
+
+    ui.label(RichText::new(format!("FPS: {fps}")).color(fps_color));
+    ui.separator();
+}
+
+/// Get summary of the redundant links statistics.
+///
+/// # Parameters
+/// - `stats` - given frame statistics of redundant links.
+///
+/// # Returns
+/// - Multiline statistics of each link.
+fn link_stats_summary(stats: &RedundancyStats) -> String {
+    [(Link::Primary, "Primary"), (Link::Secondary, "Secondary")]
+        .map(|(link, name)| {
+            let link_stats = stats.get(link);
+
+            format!(
+                "{name}: {} packets/sec, {} received, {} delivered first, \
+                 {} duplicates",
+                link_stats.pps,
+                link_stats.received,
+                link_stats.unique(),
+                link_stats.duplicates
+            )
+        })
+        .join("\n")
 }
@@ -0,0 +1,375 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Command line tools for AHRS Monitor recordings and configurations.
+
+use ahrs_monitor::{
+    check_conformance,
+    config::{
+        AppConfig, CONFIG_FILE_PATH, ImportMode, Transport, export_rules,
+        import_rules,
+    },
+    core::{
+        compare::{Session, SessionComparison, alignment_tolerance_us},
+        decoder::DecoderRegistry,
+        engine::EngineRegistry,
+        layout::{StructLanguage, export_structs},
+        reprocess::{read_log, reprocess_log},
+        smoothing::DEFAULT_CUTOFF,
+        summary::LogSummary,
+    },
+    init_logging, load_app_config,
+};
+use log::LevelFilter;
+use std::{env, path::Path, process::ExitCode, time::Duration};
+
+/// Command line usage.
+const USAGE: &str = "\
+Usage: ahrs-monitor-cli <command> [options]
+
+Commands:
+  stats <log> [--json]  Print summary statistics of the CSV log or events
+                        journal (duration, per-channel min/max/mean/std,
+                        lost samples, attitude range)
+  reprocess <input> <output> [--config <path>] [--smooth] [--cutoff <hz>]
+                        Re-run attitude estimation of the CSV log or events
+                        journal with the current fusion settings and write
+                        a new CSV log; --smooth applies zero-phase attitude
+                        smoothing (--cutoff, default 5 Hz, implies it)
+  compare <log_a> <log_b> [--config <path>]
+                        Print Markdown comparison report of two recorded
+                        sessions (per-channel deltas, attitude error)
+  export-structs <c|python> [--config <path>]
+                        Print struct definitions of the decoded payloads
+  conformance <seconds> [--config <path>] [--stdin|--generator]
+                        Observe the device stream and print Markdown
+                        report of its conformance to the IDTP
+                        specification (fails if any check fails)
+  rules export <file> [--config <path>]
+                        Export alerting rules of the config file into the
+                        shareable rule set file
+  rules import <file> [--config <path>] [--replace] [--trust]
+                        Import rule set file into the config file (rules
+                        with the same name are replaced, others appended;
+                        --replace removes all configured rules first).
+                        Webhook and command actions are stripped unless
+                        --trust is given";
+
+/// Print summary statistics of the recording.
+///
+/// # Parameters
+/// - `args` - given command arguments.
+///
+/// # Returns
+/// - `Ok` - in case of success.
+/// - `Err` - otherwise.
+fn stats(args: &[String]) -> anyhow::Result<()> {
+    let is_json = args.iter().any(|arg| arg == "--json");
+    let Some(path) = args.iter().find(|arg| !arg.starts_with("--")) else {
+        anyhow::bail!("path to the log is not specified\n\n{USAGE}");
+    };
+
+    let records = read_log(path)?;
+    let path = Path::new(path);
+    let name = path.file_name().map_or_else(
+        || path.to_string_lossy().into_owned(),
+        |name| name.to_string_lossy().into_owned(),
+    );
+    let summary = LogSummary::new(&name, &records);
+
+    if is_json {
+        println!("{}", serde_json::to_string_pretty(&summary)?);
+    } else {
+        print!("{}", summary.to_text());
+    }
+
+    Ok(())
+}
+
+/// Re-run attitude estimation of the recording with current settings.
+///
+/// # Parameters
+/// - `args` - given command arguments.
+///
+/// # Returns
+/// - `Ok` - in case of success.
+/// - `Err` - otherwise.
+fn reprocess(args: &[String]) -> anyhow::Result<()> {
+    let (config_path, args) = split_config(args)?;
+    let mut smoothing = None;
+    let mut paths = Vec::new();
+    let mut iter = args.into_iter();
+
+    while let Some(arg) = iter.next() {
+        match arg {
+            "--smooth" => {
+                smoothing.get_or_insert(DEFAULT_CUTOFF);
+            }
+            "--cutoff" => {
+                let Some(cutoff) = iter
+                    .next()
+                    .and_then(|hz| hz.parse::<f32>().ok())
+                    .filter(|hz| *hz > 0.0)
+                else {
+                    anyhow::bail!(
+                        "--cutoff requires a positive frequency in Hz\n\n\
+                         {USAGE}"
+                    );
+                };
+                smoothing = Some(cutoff);
+            }
+            _ => paths.push(arg),
+        }
+    }
+
+    let [input, output] = paths.as_slice() else {
+        anyhow::bail!("expected reprocess <input> <output>\n\n{USAGE}");
+    };
+
+    let config = load_config(config_path, None)?;
+    let estimated = reprocess_log(input, output, &config, smoothing)?;
+
+    println!(
+        "Re-estimated attitude of {estimated} records ({}) written to \
+         {output}",
+        config.imu.fusion.name()
+    );
+
+    if let Some(cutoff) = smoothing {
+        println!("Attitude smoothed with {cutoff} Hz cutoff");
+    }
+
+    Ok(())
+}
+
+/// Export or import shareable rule set.
+///
+/// # Parameters
+/// - `args` - given command arguments.
+///
+/// # Returns
+/// - `Ok` - in case of success.
+/// - `Err` - otherwise.
+fn rules(args: &[String]) -> anyhow::Result<()> {
+    let (config_path, args) = split_config(args)?;
+    let mut mode = ImportMode::Merge;
+    let mut is_trusted = false;
+    let mut paths = Vec::new();
+
+    for arg in args {
+        match arg {
+            "--replace" => mode = ImportMode::Replace,
+            "--trust" => is_trusted = true,
+            _ => paths.push(arg),
+        }
+    }
+
+    match paths.as_slice() {
+        ["export", rules_path] => {
+            let count = export_rules(config_path, rules_path)?;
+            println!("Exported {count} rules into {rules_path}");
+        }
+        ["import", rules_path] => {
+            let summary =
+                import_rules(config_path, rules_path, mode, is_trusted)?;
+            println!(
+                "Imported rules into {config_path}: {} added, {} replaced, \
+                 {} removed",
+                summary.added, summary.replaced, summary.removed
+            );
+
+            if !summary.stripped.is_empty() {
+                println!(
+                    "Stripped {} untrusted actions (re-import with --trust \
+                     to keep them):",
+                    summary.stripped.len()
+                );
+
+                for action in &summary.stripped {
+                    println!("  {action}");
+                }
+            }
+        }
+        _ => anyhow::bail!("expected rules export|import <file>\n\n{USAGE}"),
+    }
+
+    Ok(())
+}
+
+/// Print comparison report of two recorded sessions.
+///
+/// # Parameters
+/// - `args` - given command arguments.
+///
+/// # Returns
+/// - `Ok` - in case of success.
+/// - `Err` - otherwise.
+fn compare(args: &[String]) -> anyhow::Result<()> {
+    let (config_path, args) = split_config(args)?;
+    let [a_path, b_path] = args.as_slice() else {
+        anyhow::bail!("expected compare <log_a> <log_b>\n\n{USAGE}");
+    };
+
+    let config = load_config(config_path, None)?;
+    let a = Session::load(a_path)?;
+    let b = Session::load(b_path)?;
+    let tolerance = alignment_tolerance_us(config.imu.sample_rate);
+
+    print!(
+        "{}",
+        SessionComparison::new(&a, &b, tolerance).to_markdown()
+    );
+    Ok(())
+}
+
+/// Print struct definitions of the decoded payloads.
+///
+/// # Parameters
+/// - `args` - given command arguments.
+///
+/// # Returns
+/// - `Ok` - in case of success.
+/// - `Err` - otherwise.
+fn export(args: &[String]) -> anyhow::Result<()> {
+    let (config_path, args) = split_config(args)?;
+    let Some(language) = args
+        .first()
+        .and_then(|name| StructLanguage::from_name(name))
+    else {
+        anyhow::bail!("expected export-structs <c|python>\n\n{USAGE}");
+    };
+
+    let config = load_config(config_path, None)?;
+    let types = DecoderRegistry::default().payload_types();
+
+    print!("{}", export_structs(language, &types, &config.channels));
+    Ok(())
+}
+
+/// Check conformance of the device stream to the IDTP specification.
+///
+/// # Parameters
+/// - `args` - given command arguments.
+///
+/// # Returns
+/// - `Ok` - if stream conforms to the specification.
+/// - `Err` - otherwise.
+fn conformance(args: &[String]) -> anyhow::Result<()> {
+    let (config_path, args) = split_config(args)?;
+    let mut transport = None;
+    let mut duration = None;
+
+    for arg in args {
+        match arg {
+            "--stdin" => transport = Some(Transport::Stdin),
+            "--generator" => transport = Some(Transport::Generator),
+            _ => duration = arg.parse::<u64>().ok().map(Duration::from_secs),
+        }
+    }
+
+    let Some(duration) = duration else {
+        anyhow::bail!("expected conformance <seconds>\n\n{USAGE}");
+    };
+
+    // Report is printed to stdout, logs go to stderr.
+    init_logging(LevelFilter::Info);
+
+    let config = load_config(config_path, transport)?;
+    let runtime = tokio::runtime::Runtime::new()?;
+    let report = runtime.block_on(check_conformance(
+        config,
+        DecoderRegistry::default(),
+        EngineRegistry::default(),
+        duration,
+    ));
+
+    // Ingester is still blocked on reading frames, it is not waited for.
+    runtime.shutdown_background();
+    let report = report?;
+
+    print!("{}", report.to_markdown());
+
+    if !report.passed() {
+        anyhow::bail!("device stream does not conform to IDTP specification");
+    }
+
+    Ok(())
+}
+
+/// Split `--config` option out of the command arguments.
+///
+/// # Parameters
+/// - `args` - given command arguments.
+///
+/// # Returns
+/// - Config file path and other arguments - in case of success.
+/// - `Err` - otherwise.
+fn split_config(args: &[String]) -> anyhow::Result<(&str, Vec<&str>)> {
+    let mut config_path = CONFIG_FILE_PATH;
+    let mut rest = Vec::new();
+    let mut iter = args.iter();
+
+    while let Some(arg) = iter.next() {
+        if arg == "--config" {
+            let Some(path) = iter.next() else {
+                anyhow::bail!("--config requires a path\n\n{USAGE}");
+            };
+            config_path = path.as_str();
+        } else {
+            rest.push(arg.as_str());
+        }
+    }
+
+    Ok((config_path, rest))
+}
+
+/// Load and validate configurations with the built-in decoders.
+///
+/// # Parameters
+/// - `config_path` - given config file path.
+/// - `transport` - given transport overriding the configured one.
+///
+/// # Returns
+/// - Application's configurations - in case of success.
+/// - `Err` - otherwise.
+fn load_config(
+    config_path: &str,
+    transport: Option<Transport>,
+) -> anyhow::Result<AppConfig> {
+    load_app_config(config_path, transport, &DecoderRegistry::default())
+}
+
+/// Run command line tool.
+///
+/// # Returns
+/// - `Ok` - in case of success.
+/// - `Err` - otherwise.
+fn run() -> anyhow::Result<()> {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.split_first() {
+        Some((command, args)) if command == "stats" => stats(args),
+        Some((command, args)) if command == "reprocess" => reprocess(args),
+        Some((command, args)) if command == "rules" => rules(args),
+        Some((command, args)) if command == "compare" => compare(args),
+        Some((command, args)) if command == "export-structs" => export(args),
+        Some((command, args)) if command == "conformance" => conformance(args),
+        Some((command, _)) if command == "--help" || command == "-h" => {
+            println!("{USAGE}");
+            Ok(())
+        }
+        Some((command, _)) => {
+            anyhow::bail!("unknown command \"{command}\"\n\n{USAGE}")
+        }
+        None => anyhow::bail!("command is not specified\n\n{USAGE}"),
+    }
+}
+
+fn main() -> ExitCode {
+    if let Err(e) = run() {
+        eprintln!("Error: {e}");
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
+}
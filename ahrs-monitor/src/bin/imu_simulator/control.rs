@@ -0,0 +1,196 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Simulator control endpoint.
+//!
+//! Control commands are JSON objects sent in UDP datagrams, e.g.
+//! `{"rate": 50.0, "motion": "spin", "faults": {"drop_rate": 0.1}}`.
+//! Omitted fields are left unchanged, so `{}` just queries the current
+//! settings. Each command is answered with a JSON status datagram.
+
+use indtp::payload::PayloadType;
+use serde::{Deserialize, Serialize};
+use std::io;
+use tokio::net::UdpSocket;
+
+/// Max simulated sample rate in Hz.
+const MAX_RATE: f32 = 10_000.0;
+
+/// Max size of the control command in bytes.
+const MAX_COMMAND_SIZE: usize = 1024;
+
+/// Simulated motion profiles.
+#[derive(
+    Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum MotionProfile {
+    /// Periodic swinging around all axes.
+    #[default]
+    Swing,
+    /// Motionless IMU (sensor noise only).
+    Static,
+    /// Constant rotation around Z-axis.
+    Spin,
+}
+
+/// Fault injection settings.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Faults {
+    /// Probability of dropping the frame (causes sequence gaps).
+    pub drop_rate: f64,
+    /// Probability of corrupting a random byte of the frame.
+    pub corrupt_rate: f64,
+    /// Probability of sending the frame twice.
+    pub duplicate_rate: f64,
+}
+
+/// Control command (omitted fields are left unchanged).
+#[derive(Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+struct ControlCommand {
+    /// Sample rate in Hz.
+    rate: Option<f32>,
+    /// Motion profile.
+    motion: Option<MotionProfile>,
+    /// Fault injection settings.
+    faults: Option<Faults>,
+    /// IDTP payload type.
+    payload_type: Option<u8>,
+}
+
+/// Current simulator settings.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+pub struct SimSettings {
+    /// Sample rate in Hz.
+    pub rate: f32,
+    /// Motion profile.
+    pub motion: MotionProfile,
+    /// Fault injection settings.
+    pub faults: Faults,
+    /// IDTP payload type.
+    pub payload_type: u8,
+}
+
+impl SimSettings {
+    /// Apply control command.
+    ///
+    /// # Parameters
+    /// - `cmd` - given control command to apply.
+    ///
+    /// # Returns
+    /// - `Ok` - in case of success.
+    /// - `Err` - if command contains invalid values (nothing is applied).
+    fn apply(&mut self, cmd: ControlCommand) -> anyhow::Result<()> {
+        if let Some(rate) = cmd.rate
+            && !(rate > 0.0 && rate <= MAX_RATE)
+        {
+            anyhow::bail!("rate must be in range (0, {MAX_RATE}] Hz");
+        }
+
+        if let Some(faults) = cmd.faults {
+            let rates =
+                [faults.drop_rate, faults.corrupt_rate, faults.duplicate_rate];
+
+            if rates.iter().any(|p| !(0.0..=1.0).contains(p)) {
+                anyhow::bail!("fault rates must be in range [0, 1]");
+            }
+        }
+
+        if let Some(payload_type) = cmd.payload_type
+            && !PayloadType::from(payload_type).is_standard()
+        {
+            anyhow::bail!("unsupported payload type: {payload_type:#04X}");
+        }
+
+        self.rate = cmd.rate.unwrap_or(self.rate);
+        self.motion = cmd.motion.unwrap_or(self.motion);
+        self.faults = cmd.faults.unwrap_or(self.faults);
+        self.payload_type = cmd.payload_type.unwrap_or(self.payload_type);
+
+        Ok(())
+    }
+}
+
+/// Control command response.
+#[derive(Serialize, Debug)]
+struct ControlResponse<'a> {
+    /// Flag whether command is applied.
+    ok: bool,
+    /// Error message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    /// Current simulator settings.
+    settings: &'a SimSettings,
+}
+
+/// UDP endpoint receiving control commands.
+pub struct ControlEndpoint {
+    /// Control socket.
+    socket: UdpSocket,
+}
+
+impl ControlEndpoint {
+    /// Bind control endpoint.
+    ///
+    /// # Parameters
+    /// - `addr` - given address to listen on.
+    ///
+    /// # Returns
+    /// - New control endpoint - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - Socket binding errors.
+    pub async fn bind(addr: &str) -> io::Result<Self> {
+        let socket = UdpSocket::bind(addr).await?;
+
+        log::info!("Listening for control commands on {addr} (UDP)");
+        Ok(Self { socket })
+    }
+
+    /// Receive control command, apply it and send response.
+    ///
+    /// # Parameters
+    /// - `settings` - given simulator settings to update.
+    ///
+    /// # Returns
+    /// - `Ok` - in case of success (including invalid commands).
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - Socket I/O errors.
+    pub async fn handle(&self, settings: &mut SimSettings) -> io::Result<()> {
+        let mut buffer = [0u8; MAX_COMMAND_SIZE];
+        let (len, peer) = self.socket.recv_from(&mut buffer).await?;
+
+        let result = buffer
+            .get(..len)
+            .ok_or_else(|| anyhow::anyhow!("invalid command length"))
+            .and_then(|bytes| Ok(serde_json::from_slice(bytes)?))
+            .and_then(|cmd| settings.apply(cmd));
+
+        let error = match result {
+            Ok(()) => {
+                log::info!("Control command from {peer}: {settings:?}");
+                None
+            }
+            Err(e) => {
+                log::warn!("Invalid control command from {peer}: {e}");
+                Some(e.to_string())
+            }
+        };
+
+        let response = ControlResponse {
+            ok: error.is_none(),
+            error,
+            settings,
+        };
+
+        let reply = serde_json::to_vec(&response).unwrap_or_default();
+        self.socket.send_to(&reply, peer).await?;
+
+        Ok(())
+    }
+}
@@ -3,6 +3,7 @@
 
 //! IMU simulator entry point.
 
+mod control;
 mod simulator;
 mod utils;
 
@@ -35,6 +36,7 @@ async fn main() -> anyhow::Result<()> {
 /// - I/O errors.
 async fn run_simulator() -> anyhow::Result<()> {
     let app_config = load_config(config::CONFIG_FILE_PATH)?;
+    app_config.validate(&[])?;
 
     log::info!("Setting simulator...");
     let mut sim = Simulator::new(app_config)?;
@@ -1,17 +1,21 @@
 // SPDX-License-Identifier: Apache-2.0.
 // Copyright (C) 2026-present ahrs-monitor project and contributors.
 
-use crate::utils::ImuSimulator;
+use crate::{
+    control::{ControlEndpoint, Faults, MotionProfile, SimSettings},
+    utils::ImuSimulator,
+};
 /// IMU data transmission simulation implementation.
-use ahrs_monitor::config::{self, AppConfig};
+use ahrs_monitor::config::{self, AppConfig, ImuMetrics};
 use indtp::{
     Frame, Mode,
     engines::{SwCryptoEngine, SwIntegrityEngine},
+    payload::PayloadType,
     types::CryptoKeys,
 };
 use tokio::{
     net::UdpSocket,
-    time::{Duration, Instant},
+    time::{Duration, Instant, interval},
 };
 
 /// IMU data transmission simulator.
@@ -72,58 +76,168 @@ impl Simulator {
         log::info!("Listening on {} (UDP)", self.simulator_addr);
         log::info!("Sending to AHRS Monitor: {} (UDP)", self.monitor_addr);
 
-        let mut buffer = vec![0u8; 256];
+        let control = match self.cfg.net.simulator_control_port {
+            0 => None,
+            port => {
+                let addr =
+                    format!("{}:{port}", self.cfg.net.simulator_ip_address);
+                Some(ControlEndpoint::bind(&addr).await?)
+            }
+        };
 
+        let mut settings = SimSettings {
+            rate: self.cfg.imu.sample_rate,
+            motion: MotionProfile::default(),
+            faults: Faults::default(),
+            payload_type: self.cfg.imu.payload_type,
+        };
+
+        let mut buffer = vec![0u8; 256];
         let mut sequence = 0u16;
+        let mut ticker = interval(sample_period(settings.rate));
+        let start_time = Instant::now();
+
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {
+                    let timestamp = start_time.elapsed().as_micros() as u32;
+                    self.send_frame(
+                        &socket,
+                        &mut buffer,
+                        sequence,
+                        timestamp,
+                        &settings,
+                    )
+                    .await?;
+
+                    sequence = sequence.wrapping_add(1);
+
+                    if sequence.is_multiple_of(1000) {
+                        println!("Sequence: {sequence} Sent 1000 packets over UDP");
+                    }
+                }
+                result = handle_control(control.as_ref(), &mut settings) => {
+                    result?;
+
+                    if ticker.period() != sample_period(settings.rate) {
+                        ticker = interval(sample_period(settings.rate));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Generate and send single IDTP frame.
+    ///
+    /// # Parameters
+    /// - `socket` - given UDP socket to send frame with.
+    /// - `buffer` - given buffer to build frame in.
+    /// - `sequence` - given frame sequence number.
+    /// - `timestamp` - given sensor-local time in microseconds.
+    /// - `settings` - given current simulator settings.
+    ///
+    /// # Returns
+    /// - `Ok` - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - IDTP frame building errors.
+    /// - I/O errors.
+    async fn send_frame(
+        &mut self,
+        socket: &UdpSocket,
+        buffer: &mut [u8],
+        sequence: u16,
+        timestamp: u32,
+        settings: &SimSettings,
+    ) -> anyhow::Result<()> {
         let device_id = 0xAA;
-        let payload_type = self.cfg.imu.payload_type();
+        let payload_type = PayloadType::from(settings.payload_type);
+        let metrics = ImuMetrics::from(payload_type);
         let mode =
             Mode::try_from(self.cfg.imu.protocol_mode).unwrap_or(Mode::Lite);
 
         let mut frame = match mode {
             Mode::Lite => {
-                Frame::new_lite(&mut buffer, device_id, payload_type.as_u8())
+                Frame::new_lite(buffer, device_id, payload_type.as_u8())
+            }
+            Mode::Verified => {
+                Frame::new_verified(buffer, device_id, payload_type.as_u8())
             }
-            Mode::Verified => Frame::new_verified(
-                &mut buffer,
-                device_id,
-                payload_type.as_u8(),
-            ),
             Mode::Trusted => {
-                Frame::new_trusted(&mut buffer, device_id, payload_type.as_u8())
+                Frame::new_trusted(buffer, device_id, payload_type.as_u8())
+            }
+            Mode::Critical => {
+                Frame::new_critical(buffer, device_id, payload_type.as_u8())
             }
-            Mode::Critical => Frame::new_critical(
-                &mut buffer,
-                device_id,
-                payload_type.as_u8(),
-            ),
         }?;
 
-        let metrics = self.cfg.imu.metrics;
-        let delay = 1000.0 / self.cfg.imu.sample_rate;
-        let dt = delay / 1000.0;
-        let delay_time = Duration::from_millis(delay as u64);
-        let start_time = Instant::now();
+        let dt = 1.0 / settings.rate;
+        let payload =
+            self.sim
+                .next_payload(dt, &payload_type, &metrics, settings.motion);
 
-        loop {
-            let payload = self.sim.next_payload(dt, &payload_type, &metrics);
+        frame.set_sequence(sequence);
+        frame.push_single_sample(timestamp, payload.to_bytes())?;
 
-            frame.set_sequence(sequence);
-            let timestamp = start_time.elapsed().as_micros() as u32;
-            frame.push_single_sample(timestamp, payload.to_bytes())?;
+        let _ = frame
+            .pack::<SwIntegrityEngine, SwCryptoEngine>(Some(&self.keys))?;
+        let mut raw_frame = frame.frame()?.to_vec();
 
-            let _ = frame
-                .pack::<SwIntegrityEngine, SwCryptoEngine>(Some(&self.keys))?;
-            let raw_frame = frame.frame()?;
-            socket.send_to(raw_frame, &self.monitor_addr).await?;
+        // Injecting faults.
+        let faults = settings.faults;
+
+        if rand::random_bool(faults.drop_rate) {
+            return Ok(());
+        }
 
-            sequence = sequence.wrapping_add(1);
+        if rand::random_bool(faults.corrupt_rate) && !raw_frame.is_empty() {
+            let pos = rand::random_range(0..raw_frame.len());
 
-            if sequence.is_multiple_of(1000) {
-                println!("Sequence: {sequence} Sent 1000 packets over UDP");
+            if let Some(byte) = raw_frame.get_mut(pos) {
+                *byte ^= 1 << rand::random_range(0..8);
             }
+        }
+
+        socket.send_to(&raw_frame, &self.monitor_addr).await?;
 
-            tokio::time::sleep(delay_time).await;
+        if rand::random_bool(faults.duplicate_rate) {
+            socket.send_to(&raw_frame, &self.monitor_addr).await?;
         }
+
+        Ok(())
+    }
+}
+
+/// Handle control command if control endpoint is enabled.
+///
+/// # Parameters
+/// - `control` - given control endpoint to handle.
+/// - `settings` - given simulator settings to update.
+///
+/// # Returns
+/// - `Ok` - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - Socket I/O errors.
+async fn handle_control(
+    control: Option<&ControlEndpoint>,
+    settings: &mut SimSettings,
+) -> std::io::Result<()> {
+    match control {
+        Some(control) => control.handle(settings).await,
+        None => std::future::pending().await,
     }
 }
+
+/// Get sample period for the sample rate.
+///
+/// # Parameters
+/// - `rate` - given sample rate in Hz.
+///
+/// # Returns
+/// - Sample period.
+fn sample_period(rate: f32) -> Duration {
+    Duration::from_secs_f32(1.0 / rate)
+}
@@ -3,6 +3,7 @@
 
 //! Utils for IMU simulator.
 
+use crate::control::MotionProfile;
 use ahrs_monitor::{config::ImuMetrics, core::StandardPayload};
 use indtp::payload::{
     Imu3Acc, Imu3Gyr, Imu3Mag, Imu6, Imu9, Imu10, ImuQuat, PayloadType,
@@ -13,6 +14,9 @@ use rand_distr::{Distribution, Normal};
 /// Earth's standard gravity in meters per second squared.
 const GRAVITY: f32 = 9.80665;
 
+/// Angular velocity around Z-axis for the spinning motion profile.
+const SPIN_RATE: f32 = 90.0;
+
 /// IMU readings simulator.
 pub struct ImuSimulator {
     /// Internal clock for periodic wave generation.
@@ -64,6 +68,7 @@ impl ImuSimulator {
     /// - `dt` - given delta time in seconds.
     /// - `payload_type` - given standard payload type to handle.
     /// - `metrics` - given IMU metrics to handle.
+    /// - `motion` - given simulated motion profile.
     ///
     /// # Returns
     /// - Next generated set of IMU readings.
@@ -72,15 +77,24 @@ impl ImuSimulator {
         dt: f32,
         payload_type: &PayloadType,
         metrics: &ImuMetrics,
+        motion: MotionProfile,
     ) -> StandardPayload {
         self.time += dt;
 
         // Generating gyroscope readings.
         for i in 0..3 {
             let t = self.time;
-            let swing = (t * (1.2 + i as f32)).sin() * 100.0;
-            let jitter = (t * 25.0).sin() * 1.15;
-            self.gyr[i] = if metrics.gyr { swing + jitter } else { 0.0 };
+            let rate = match motion {
+                MotionProfile::Swing => {
+                    let swing = (t * (1.2 + i as f32)).sin() * 100.0;
+                    let jitter = (t * 25.0).sin() * 1.15;
+                    swing + jitter
+                }
+                MotionProfile::Static => 0.0,
+                MotionProfile::Spin if i == 2 => SPIN_RATE,
+                MotionProfile::Spin => 0.0,
+            };
+            self.gyr[i] = if metrics.gyr { rate } else { 0.0 };
         }
 
         // Generating quaternion.
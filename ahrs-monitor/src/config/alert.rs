@@ -0,0 +1,88 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Alerting rules related configurations.
+
+use crate::config::{Deserialize, Serialize};
+
+app_config! {
+    /// Alerting rule configurations.
+    pub struct AlertRule {
+        /// Human-readable rule name.
+        pub name: String,
+        /// Monitored metric.
+        pub metric: AlertMetric,
+        /// Condition comparing metric value with the threshold.
+        pub condition: AlertCondition,
+        /// Metric threshold value.
+        pub threshold: f32,
+        /// Actions to perform when rule is triggered.
+        #[serde(default)]
+        pub actions: Vec<AlertAction>,
+    }
+}
+
+/// Metrics monitored by alerting rules.
+#[derive(
+    Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertMetric {
+    /// Accelerometer readings vector magnitude.
+    #[default]
+    AccNorm,
+    /// Gyroscope readings vector magnitude.
+    GyrNorm,
+    /// Magnetometer readings vector magnitude.
+    MagNorm,
+    /// Number of packets per second.
+    Pps,
+    /// Number of broken packets.
+    BadPackets,
+    /// Number of packets failed CRC or MAC verification.
+    CrcErrors,
+    /// Percentage of packets lost over the latest 1000 valid frames.
+    PacketLoss,
+    /// Number of consecutive frames repeating the previous payload.
+    RepeatedFrames,
+}
+
+/// Conditions comparing metric value with the threshold.
+#[derive(
+    Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertCondition {
+    /// Metric value is greater than threshold.
+    #[default]
+    Above,
+    /// Metric value is less than threshold.
+    Below,
+}
+
+/// Actions performed when alerting rule is triggered.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum AlertAction {
+    /// Play a sound (system sound if no sound file is specified).
+    Sound {
+        /// Path to the sound file to play.
+        #[serde(default)]
+        file: Option<String>,
+    },
+    /// Show a modal window in the user interface.
+    Modal,
+    /// Send HTTP POST request with alert info in JSON to the URL.
+    Webhook {
+        /// Webhook URL (plain `http://` only).
+        url: String,
+    },
+    /// Run a local command.
+    Command {
+        /// Command to run.
+        command: String,
+        /// Command arguments.
+        #[serde(default)]
+        args: Vec<String>,
+    },
+}
@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Application events channel related configurations.
+
+use crate::config::{Deserialize, Serialize};
+
+app_config! {
+    /// Events channel configurations.
+    pub struct ChannelConfig {
+        /// Policy applied when the user interface does not keep up.
+        #[serde(default)]
+        pub policy: BackpressurePolicy,
+    }
+}
+
+/// Policies applied when the events channel is full.
+#[derive(
+    Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum BackpressurePolicy {
+    /// Wait until the user interface frees a slot (stalls the ingester).
+    Block,
+    /// Drop the oldest queued frame in favour of the new one.
+    #[default]
+    DropOldest,
+    /// Drop the new frame.
+    DropNewest,
+}
@@ -0,0 +1,117 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Debugging related configurations.
+
+use crate::config::{Deserialize, Serialize};
+
+app_config! {
+    /// Debug configurations (fault injection for resilience testing).
+    pub struct DebugConfig {
+        /// Probability of dropping received frame before processing.
+        #[serde(default)]
+        pub drop_rate: f64,
+        /// Probability of delaying received frame before processing.
+        #[serde(default)]
+        pub delay_rate: f64,
+        /// Delay of the delayed frames in milliseconds.
+        #[serde(default)]
+        pub delay_ms: u64,
+        /// Signals of the test-signal generator (`"generator"` transport).
+        #[serde(default)]
+        pub generator: Vec<SignalConfig>,
+    }
+}
+
+/// Test-signal generator signal configurations.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct SignalConfig {
+    /// Generated sensor readings channel.
+    pub channel: GeneratorChannel,
+    /// Signal waveform.
+    pub waveform: Waveform,
+    /// Constant value added to the waveform.
+    #[serde(default)]
+    pub offset: f32,
+}
+
+/// Sensor readings channels of the test-signal generator.
+#[derive(
+    Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum GeneratorChannel {
+    /// Accelerometer X axis.
+    #[default]
+    AccX,
+    /// Accelerometer Y axis.
+    AccY,
+    /// Accelerometer Z axis.
+    AccZ,
+    /// Gyroscope X axis.
+    GyrX,
+    /// Gyroscope Y axis.
+    GyrY,
+    /// Gyroscope Z axis.
+    GyrZ,
+    /// Magnetometer X axis.
+    MagX,
+    /// Magnetometer Y axis.
+    MagY,
+    /// Magnetometer Z axis.
+    MagZ,
+    /// Barometer.
+    Baro,
+    /// Quaternion W component.
+    QuatW,
+    /// Quaternion X component.
+    QuatX,
+    /// Quaternion Y component.
+    QuatY,
+    /// Quaternion Z component.
+    QuatZ,
+}
+
+/// Waveforms of the test-signal generator.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum Waveform {
+    /// Sine wave.
+    Sine {
+        /// Peak amplitude.
+        amplitude: f32,
+        /// Frequency in Hz.
+        frequency: f32,
+    },
+    /// Square wave alternating between 0 and amplitude.
+    Step {
+        /// Step amplitude.
+        amplitude: f32,
+        /// Time between steps in seconds.
+        period: f32,
+    },
+    /// Sine wave with linear frequency sweep, repeated after the sweep ends.
+    Chirp {
+        /// Peak amplitude.
+        amplitude: f32,
+        /// Frequency at the start of the sweep in Hz.
+        start_frequency: f32,
+        /// Frequency at the end of the sweep in Hz.
+        end_frequency: f32,
+        /// Sweep duration in seconds.
+        duration: f32,
+    },
+}
+
+impl DebugConfig {
+    /// Check whether fault injection is enabled.
+    ///
+    /// # Returns
+    /// - `true` - if any received frames are dropped or delayed.
+    /// - `false` - otherwise.
+    #[must_use]
+    pub fn is_fault_injection_enabled(&self) -> bool {
+        self.drop_rate > 0.0 || self.delay_rate > 0.0
+    }
+}
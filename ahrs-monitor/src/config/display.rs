@@ -0,0 +1,467 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! User interface display related configurations.
+
+use crate::config::{
+    DEFAULT_LAYOUT, Deserialize, LayoutPreset, Serialize, builtin_layouts,
+};
+
+app_config! {
+    /// Display configurations.
+    pub struct DisplayConfig {
+        /// Smoothing of the attitude widget and numeric readouts.
+        #[serde(default)]
+        pub smoothing: Smoothing,
+        /// Indicator whether attitude is interpolated between received
+        /// samples for rendering.
+        #[serde(default)]
+        pub interpolation: bool,
+        /// Formats of the numeric readouts.
+        #[serde(default)]
+        pub format: MetricFormats,
+        /// Plots configurations.
+        #[serde(default)]
+        pub plot: PlotsConfig,
+        /// Reduced-graphics mode for low-end hardware.
+        #[serde(default)]
+        pub min_spec: MinSpecConfig,
+        /// Vehicle 3D model of the attitude widget.
+        #[serde(default)]
+        pub model: ModelConfig,
+        /// Heading indicator of the Dashboard.
+        #[serde(default)]
+        pub compass: CompassConfig,
+        /// Unit of the displayed angles and angular rates.
+        #[serde(default)]
+        pub angle_unit: AngleUnit,
+        /// Indicator whether performance overlay is shown at startup.
+        #[serde(default)]
+        pub perf_overlay: bool,
+        /// Indicator whether reduced-graphics mode is active.
+        #[serde(skip)]
+        pub is_min_spec: bool,
+        /// Name of the layout preset applied at startup (empty - default
+        /// layout showing everything).
+        #[serde(default)]
+        pub layout: String,
+        /// User-defined layout presets (override built-in presets with the
+        /// same name).
+        #[serde(default)]
+        pub layouts: Vec<LayoutPreset>,
+    }
+}
+
+impl DisplayConfig {
+    /// Get available layout presets.
+    ///
+    /// # Returns
+    /// - Built-in layout presets followed by the user-defined ones.
+    #[must_use]
+    pub fn layout_presets(&self) -> Vec<LayoutPreset> {
+        let mut presets = builtin_layouts();
+
+        for preset in &self.layouts {
+            match presets.iter_mut().find(|p| p.name == preset.name) {
+                Some(builtin) => builtin.clone_from(preset),
+                None => presets.push(preset.clone()),
+            }
+        }
+
+        presets
+    }
+
+    /// Get layout preset applied at startup.
+    ///
+    /// # Returns
+    /// - Layout preset - if preset with configured name exists.
+    /// - `None` - otherwise.
+    #[must_use]
+    pub fn startup_layout(&self) -> Option<LayoutPreset> {
+        let name = if self.layout.is_empty() {
+            DEFAULT_LAYOUT
+        } else {
+            &self.layout
+        };
+
+        self.layout_presets().into_iter().find(|p| p.name == name)
+    }
+
+    /// Get number of plotted points per metric.
+    ///
+    /// # Returns
+    /// - Configured plot history reduced in min-spec mode.
+    #[must_use]
+    pub fn plot_history(&self) -> usize {
+        if self.is_min_spec {
+            (self.plot.history / MIN_SPEC_HISTORY_DIVISOR).max(2)
+        } else {
+            self.plot.history
+        }
+    }
+}
+
+/// Divisor of the plot history in min-spec mode.
+const MIN_SPEC_HISTORY_DIVISOR: usize = 4;
+
+/// Vehicle 3D model configurations.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ModelConfig {
+    /// Path to the STL or OBJ model file (empty - wireframe cube).
+    pub path: String,
+    /// Model color (RGB).
+    pub color: [u8; 3],
+    /// Rotation of the model into the body frame as roll, pitch and yaw in
+    /// degrees.
+    pub orientation: [f32; 3],
+}
+
+impl Default for ModelConfig {
+    /// Get default vehicle 3D model configurations.
+    ///
+    /// # Returns
+    /// - Wireframe cube instead of the model.
+    fn default() -> Self {
+        Self {
+            path: String::new(),
+            color: [180, 180, 190],
+            orientation: [0.0; 3],
+        }
+    }
+}
+
+/// Heading indicator configurations (magnetic heading without declination by
+/// default).
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct CompassConfig {
+    /// Magnetic declination in degrees (east positive), added to the
+    /// magnetic heading to get the true one.
+    pub declination: f32,
+    /// Indicator whether true heading is shown instead of the magnetic one.
+    pub true_heading: bool,
+}
+
+impl CompassConfig {
+    /// Get displayed heading.
+    ///
+    /// # Parameters
+    /// - `yaw` - given estimated yaw angle (magnetic heading) in radians.
+    /// - `is_true` - given indicator whether true heading is requested.
+    ///
+    /// # Returns
+    /// - Heading in degrees in range [0, 360).
+    #[must_use]
+    pub fn heading(&self, yaw: f32, is_true: bool) -> f32 {
+        let declination = if is_true { self.declination } else { 0.0 };
+        (yaw.to_degrees() + declination).rem_euclid(360.0)
+    }
+}
+
+/// Unit of the displayed angles and angular rates.
+#[derive(
+    Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum AngleUnit {
+    /// Degrees and degrees per second.
+    #[default]
+    Degrees,
+    /// Radians and radians per second.
+    Radians,
+}
+
+impl AngleUnit {
+    /// All angle units.
+    pub const ALL: [Self; 2] = [Self::Degrees, Self::Radians];
+
+    /// Get factor converting angles in radians into the unit.
+    ///
+    /// # Returns
+    /// - Conversion factor.
+    #[must_use]
+    pub const fn angle_factor(self) -> f32 {
+        match self {
+            Self::Degrees => 180.0 / std::f32::consts::PI,
+            Self::Radians => 1.0,
+        }
+    }
+
+    /// Get factor converting angular rates in deg/s into the unit.
+    ///
+    /// # Returns
+    /// - Conversion factor.
+    #[must_use]
+    pub const fn rate_factor(self) -> f32 {
+        match self {
+            Self::Degrees => 1.0,
+            Self::Radians => std::f32::consts::PI / 180.0,
+        }
+    }
+
+    /// Get symbol of the angles.
+    ///
+    /// # Returns
+    /// - Angle unit symbol string slice.
+    #[must_use]
+    pub const fn angle_symbol(self) -> &'static str {
+        match self {
+            Self::Degrees => "deg",
+            Self::Radians => "rad",
+        }
+    }
+
+    /// Get symbol of the angular rates.
+    ///
+    /// # Returns
+    /// - Angular rate unit symbol string slice.
+    #[must_use]
+    pub const fn rate_symbol(self) -> &'static str {
+        match self {
+            Self::Degrees => "deg/s",
+            Self::Radians => "rad/s",
+        }
+    }
+}
+
+/// Reduced-graphics mode activation.
+#[derive(
+    Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum MinSpecMode {
+    /// Enabled once frame rate stays low.
+    #[default]
+    Auto,
+    /// Always enabled.
+    On,
+    /// Always disabled.
+    Off,
+}
+
+/// Reduced-graphics mode configurations.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct MinSpecConfig {
+    /// Mode activation.
+    pub mode: MinSpecMode,
+    /// Frame rate below which the mode is enabled automatically.
+    pub fps_threshold: f32,
+    /// Duration of the low frame rate enabling the mode in seconds.
+    pub duration: f32,
+    /// Max frame rate in the mode.
+    pub fps_cap: f32,
+}
+
+impl Default for MinSpecConfig {
+    /// Get default reduced-graphics mode configurations.
+    ///
+    /// # Returns
+    /// - Mode enabled after 5 seconds below 20 FPS, capped at 15 FPS.
+    fn default() -> Self {
+        Self {
+            mode: MinSpecMode::Auto,
+            fps_threshold: 20.0,
+            duration: 5.0,
+            fps_cap: 15.0,
+        }
+    }
+}
+
+/// Default number of decimal places of the sensor readings.
+const DEFAULT_PRECISION: usize = 6;
+
+/// Default number of decimal places of the Euler angles.
+const DEFAULT_EULER_PRECISION: usize = 2;
+
+/// Format of the numeric readout.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct MetricFormat {
+    /// Number of decimal places.
+    pub precision: usize,
+    /// Values with lower absolute value are displayed as zero.
+    pub dead_band: f32,
+}
+
+impl Default for MetricFormat {
+    /// Get default numeric readout format.
+    ///
+    /// # Returns
+    /// - Sensor readings format without dead-band.
+    fn default() -> Self {
+        Self::with_precision(DEFAULT_PRECISION)
+    }
+}
+
+impl MetricFormat {
+    /// Construct format without dead-band.
+    ///
+    /// # Parameters
+    /// - `precision` - given number of decimal places.
+    ///
+    /// # Returns
+    /// - New `MetricFormat` object.
+    #[must_use]
+    pub const fn with_precision(precision: usize) -> Self {
+        Self {
+            precision,
+            dead_band: 0.0,
+        }
+    }
+
+    /// Format numeric readout.
+    ///
+    /// # Parameters
+    /// - `value` - given value to format.
+    ///
+    /// # Returns
+    /// - Value rounded to the precision (zero within dead-band).
+    #[must_use]
+    pub fn format(&self, value: f32) -> String {
+        let value = if value.abs() < self.dead_band {
+            0.0
+        } else {
+            value
+        };
+
+        let text = format!("{value:.*}", self.precision);
+
+        // Hiding sign of the values rounded to zero (e.g. "-0.00").
+        match text.strip_prefix('-') {
+            Some(abs) if abs.chars().all(|c| c == '0' || c == '.') => {
+                abs.to_string()
+            }
+            _ => text,
+        }
+    }
+}
+
+/// Formats of the numeric readouts by metric.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct MetricFormats {
+    /// Accelerometer readings format.
+    pub acc: MetricFormat,
+    /// Gyroscope readings format.
+    pub gyr: MetricFormat,
+    /// Magnetometer readings format.
+    pub mag: MetricFormat,
+    /// Barometer readings format.
+    pub baro: MetricFormat,
+    /// Attitude quaternion format.
+    pub quat: MetricFormat,
+    /// Euler angles format.
+    pub euler: MetricFormat,
+}
+
+impl Default for MetricFormats {
+    /// Get default numeric readouts formats.
+    ///
+    /// # Returns
+    /// - Formats used before display formats became configurable.
+    fn default() -> Self {
+        Self {
+            acc: MetricFormat::default(),
+            gyr: MetricFormat::default(),
+            mag: MetricFormat::default(),
+            baro: MetricFormat::default(),
+            quat: MetricFormat::default(),
+            euler: MetricFormat::with_precision(DEFAULT_EULER_PRECISION),
+        }
+    }
+}
+
+/// Attitude smoothing amount.
+#[derive(
+    Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Smoothing {
+    /// Raw attitude (max responsiveness).
+    #[default]
+    None,
+    /// Slight smoothing hiding sensor jitter.
+    Light,
+    /// Strong smoothing for presentations.
+    Heavy,
+}
+
+impl Smoothing {
+    /// All smoothing amounts.
+    pub const ALL: [Self; 3] = [Self::None, Self::Light, Self::Heavy];
+
+    /// Get smoothing time constant.
+    ///
+    /// Time constant does not depend on the frame rate, so that smoothing
+    /// looks the same regardless of rendering performance.
+    ///
+    /// # Returns
+    /// - Time constant in seconds.
+    #[must_use]
+    pub const fn time_constant(self) -> f32 {
+        match self {
+            Self::None => 0.0,
+            Self::Light => 0.05,
+            Self::Heavy => 0.25,
+        }
+    }
+
+    /// Get smoothing amount name.
+    ///
+    /// # Returns
+    /// - Smoothing amount name string slice.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::Light => "Light",
+            Self::Heavy => "Heavy",
+        }
+    }
+}
+
+/// Default number of plotted points per metric.
+const DEFAULT_PLOT_HISTORY: usize = 1000;
+
+/// Plots configurations.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct PlotsConfig {
+    /// Rendering backend of the plots.
+    pub renderer: PlotRenderer,
+    /// Number of plotted points per metric.
+    pub history: usize,
+    /// Indicator whether vector magnitudes of the sensor readings are
+    /// plotted next to their axes.
+    pub magnitudes: bool,
+}
+
+impl Default for PlotsConfig {
+    /// Get default plots configurations.
+    ///
+    /// # Returns
+    /// - CPU rendered plots of the most recent 1000 points.
+    fn default() -> Self {
+        Self {
+            renderer: PlotRenderer::default(),
+            history: DEFAULT_PLOT_HISTORY,
+            magnitudes: false,
+        }
+    }
+}
+
+/// Rendering backend of the plots.
+#[derive(
+    Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum PlotRenderer {
+    /// Plots tessellated by the CPU.
+    #[default]
+    Cpu,
+    /// Decimated line strips drawn by the GPU (requires the `gpu-plots`
+    /// feature).
+    Gpu,
+}
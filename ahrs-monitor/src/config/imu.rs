@@ -6,6 +6,11 @@
 use crate::{
     app_config,
     config::{Deserialize, Serialize},
+    core::{
+        altitude::STANDARD_PRESSURE, attitude::FusionAlgorithm,
+        calibration::AccCalibration, engine::SOFTWARE_ENGINE,
+        mag_calibration::MagCalibration, mounting::Mounting, units::Units,
+    },
 };
 use indtp::payload::PayloadType;
 
@@ -23,6 +28,408 @@ app_config! {
         pub device_id: u8,
         /// INDTP protocol mode.
         pub protocol_mode: u8,
+        /// Name of the frame integrity and crypto engine.
+        #[serde(default = "default_engine")]
+        pub engine: String,
+        /// Units and per-axis scale of the received readings.
+        #[serde(default)]
+        pub units: Units,
+        /// Accelerometer full-scale range (0 - clipping detection disabled).
+        #[serde(default)]
+        pub acc_range: f32,
+        /// Gyroscope full-scale range (0 - clipping detection disabled).
+        #[serde(default)]
+        pub gyr_range: f32,
+        /// Magnetometer full-scale range (0 - clipping detection disabled).
+        #[serde(default)]
+        pub mag_range: f32,
+        /// Fraction of the full-scale range treated as clipping.
+        #[serde(default = "default_clipping_threshold")]
+        pub clipping_threshold: f32,
+        /// Number of recent sequence numbers tracked to reject duplicates
+        /// (0 - duplicate rejection disabled).
+        #[serde(default = "default_replay_window")]
+        pub replay_window: u16,
+        /// Pressure at zero barometric altitude in Pa (QNH for altitude
+        /// above mean sea level, QFE for height above the take-off point).
+        #[serde(default = "default_reference_pressure")]
+        pub reference_pressure: f32,
+        /// Attitude fusion algorithm.
+        #[serde(default)]
+        pub fusion: FusionAlgorithm,
+        /// Sensors fused into the attitude.
+        #[serde(default)]
+        pub inputs: FusionInputs,
+        /// Online gyroscope bias estimation.
+        #[serde(default)]
+        pub gyro_bias: GyroBiasConfig,
+        /// Accelerometer calibration applied to received readings.
+        #[serde(default)]
+        pub acc_calibration: AccCalibration,
+        /// Magnetometer hard/soft-iron calibration applied to received
+        /// readings.
+        #[serde(default)]
+        pub mag_calibration: MagCalibration,
+        /// Sensor mounting orientation relative to the body axes.
+        #[serde(default)]
+        pub mounting: Mounting,
+        /// Dead-reckoning velocity and position estimation.
+        #[serde(default)]
+        pub dead_reckoning: DeadReckoningConfig,
+        /// Online anomaly detection on sensor readings channels.
+        #[serde(default)]
+        pub anomaly: AnomalyConfig,
+        /// Motion state classification.
+        #[serde(default)]
+        pub motion: MotionConfig,
+        /// Vibration metrics of the accelerometer readings.
+        #[serde(default)]
+        pub vibration: VibrationConfig,
+        /// Shock (impact) detection.
+        #[serde(default)]
+        pub shock: ShockConfig,
+        /// Baro-aided altitude and climb rate estimation.
+        #[serde(default)]
+        pub vertical: VerticalConfig,
+        /// Stale data (frozen sensor) detection.
+        #[serde(default)]
+        pub stale_data: StaleDataConfig,
+        /// G-load monitoring limits.
+        #[serde(default)]
+        pub g_load: GLoadConfig,
+        /// Whether attitude estimation starts from the last known attitude
+        /// and gyroscope bias of the same device.
+        #[serde(default)]
+        pub warm_start: bool,
+    }
+}
+
+/// Get default fraction of the full-scale range treated as clipping.
+///
+/// # Returns
+/// - Default clipping threshold.
+const fn default_clipping_threshold() -> f32 {
+    0.98
+}
+
+/// Get default pressure at zero barometric altitude.
+///
+/// # Returns
+/// - ISA sea level standard pressure in Pa.
+const fn default_reference_pressure() -> f32 {
+    STANDARD_PRESSURE
+}
+
+/// Get default number of recent sequence numbers tracked per device.
+///
+/// # Returns
+/// - Default replay window size.
+const fn default_replay_window() -> u16 {
+    64
+}
+
+/// Get default frame integrity and crypto engine name.
+///
+/// # Returns
+/// - Software engine name.
+fn default_engine() -> String {
+    SOFTWARE_ENGINE.to_string()
+}
+
+/// Online gyroscope bias estimation configurations.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct GyroBiasConfig {
+    /// Whether bias is estimated while the device is stationary.
+    pub enabled: bool,
+    /// Max standard deviation of accelerometer readings of the stationary
+    /// device.
+    pub acc_std: f32,
+    /// Max standard deviation of gyroscope readings of the stationary device
+    /// in degrees per second.
+    pub gyr_std: f32,
+    /// Max bias magnitude in degrees per second (slow constant rotation is
+    /// not mistaken for bias).
+    pub max_bias: f32,
+}
+
+impl Default for GyroBiasConfig {
+    /// Get default bias estimation configurations.
+    ///
+    /// # Returns
+    /// - Bias estimation tolerating noise of consumer-grade MEMS sensors.
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            acc_std: 0.2,
+            gyr_std: 0.5,
+            max_bias: 5.0,
+        }
+    }
+}
+
+/// Dead-reckoning velocity and position estimation configurations.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct DeadReckoningConfig {
+    /// Whether linear acceleration is integrated into velocity and position.
+    pub enabled: bool,
+    /// Max linear acceleration magnitude of the still device in m/s².
+    pub acc_threshold: f32,
+    /// Max angular rate magnitude of the still device in degrees per second.
+    pub gyr_threshold: f32,
+    /// Min duration of stillness resetting velocity in seconds.
+    pub still_time: f32,
+}
+
+impl Default for DeadReckoningConfig {
+    /// Get default dead-reckoning configurations.
+    ///
+    /// # Returns
+    /// - Disabled dead-reckoning with stillness thresholds tolerating noise
+    ///   of consumer-grade MEMS sensors.
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            acc_threshold: 0.3,
+            gyr_threshold: 3.0,
+            still_time: 0.2,
+        }
+    }
+}
+
+/// Motion state classification configurations.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct MotionConfig {
+    /// Whether motion state is classified.
+    pub enabled: bool,
+    /// Averaging time constant of the readings statistics in seconds.
+    pub window: f32,
+    /// Min duration of the new state before it is reported in seconds.
+    pub hold_time: f32,
+    /// Max mean angular rate of the static device in degrees per second.
+    pub static_gyr: f32,
+    /// Max standard deviation of acceleration magnitude of the static
+    /// device in m/s².
+    pub static_acc: f32,
+    /// Max mean angular rate in slow motion in degrees per second.
+    pub slow_gyr: f32,
+    /// Max standard deviation of acceleration magnitude in slow motion
+    /// in m/s².
+    pub slow_acc: f32,
+    /// Max acceleration magnitude in freefall in m/s².
+    pub freefall_acc: f32,
+    /// Whether motion transitions are written to the `annotation` log
+    /// column.
+    pub annotate_log: bool,
+}
+
+impl Default for MotionConfig {
+    /// Get default motion classification configurations.
+    ///
+    /// # Returns
+    /// - Motion classification tolerating noise of consumer-grade MEMS
+    ///   sensors.
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            window: 0.5,
+            hold_time: 0.2,
+            static_gyr: 3.0,
+            static_acc: 0.15,
+            slow_gyr: 45.0,
+            slow_acc: 1.5,
+            freefall_acc: 3.0,
+            annotate_log: true,
+        }
+    }
+}
+
+/// Vibration metrics configurations.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct VibrationConfig {
+    /// Whether vibration metrics are computed.
+    pub enabled: bool,
+    /// Length of the sliding window in seconds.
+    pub window: f32,
+}
+
+impl Default for VibrationConfig {
+    /// Get default vibration metrics configurations.
+    ///
+    /// # Returns
+    /// - Vibration metrics over the last second of readings.
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            window: 1.0,
+        }
+    }
+}
+
+/// Shock (impact) detection configurations.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ShockConfig {
+    /// Whether shocks are detected.
+    pub enabled: bool,
+    /// Acceleration magnitude treated as shock in g.
+    pub threshold: f32,
+    /// Time after the shock during which new shocks are suppressed in
+    /// seconds.
+    pub hold_off: f32,
+}
+
+impl Default for ShockConfig {
+    /// Get default shock detection configurations.
+    ///
+    /// # Returns
+    /// - Shock detection above 4 g with 0.5 s hold-off.
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            threshold: 4.0,
+            hold_off: 0.5,
+        }
+    }
+}
+
+/// Baro-aided altitude and climb rate estimation configurations.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct VerticalConfig {
+    /// Whether altitude and climb rate are estimated.
+    pub enabled: bool,
+    /// Standard deviation of the vertical acceleration in m/s² (greater -
+    /// faster response, noisier climb rate).
+    pub acc_noise: f32,
+    /// Standard deviation of the barometric altitude in meters (greater -
+    /// smoother altitude, slower response).
+    pub baro_noise: f32,
+}
+
+impl Default for VerticalConfig {
+    /// Get default vertical channel estimation configurations.
+    ///
+    /// # Returns
+    /// - Estimation tuned for consumer-grade MEMS sensors.
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            acc_noise: 0.5,
+            baro_noise: 0.5,
+        }
+    }
+}
+
+/// Stale data (frozen sensor) detection configurations.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct StaleDataConfig {
+    /// Whether repeated payloads are detected.
+    pub enabled: bool,
+    /// Number of consecutive frames repeating the payload treated as stale
+    /// data.
+    pub frames: usize,
+}
+
+impl Default for StaleDataConfig {
+    /// Get default stale data detection configurations.
+    ///
+    /// # Returns
+    /// - Stale data after 50 repeated payloads.
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            frames: 50,
+        }
+    }
+}
+
+/// Sensors fused into the attitude.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct FusionInputs {
+    /// Whether accelerometer readings are fused.
+    pub acc: bool,
+    /// Whether gyroscope readings are fused.
+    pub gyr: bool,
+    /// Whether magnetometer readings are fused.
+    pub mag: bool,
+}
+
+impl Default for FusionInputs {
+    /// Get default fusion inputs.
+    ///
+    /// # Returns
+    /// - All sensors fused.
+    fn default() -> Self {
+        Self {
+            acc: true,
+            gyr: true,
+            mag: true,
+        }
+    }
+}
+
+/// G-load monitoring configurations.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct GLoadConfig {
+    /// Whether G-load is monitored.
+    pub enabled: bool,
+    /// G-load shown as warning in g.
+    pub warning: f32,
+    /// G-load limit raising visual alert in g.
+    pub limit: f32,
+}
+
+impl Default for GLoadConfig {
+    /// Get default G-load monitoring configurations.
+    ///
+    /// # Returns
+    /// - Warning above 2 g and alert above 3 g.
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            warning: 2.0,
+            limit: 3.0,
+        }
+    }
+}
+
+/// Online anomaly detection configurations.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct AnomalyConfig {
+    /// Whether sensor readings channels are checked for anomalies.
+    pub enabled: bool,
+    /// Number of recent readings averaged by the band of regular readings.
+    pub window: u32,
+    /// Number of standard deviations from the average treated as anomaly.
+    pub threshold: f32,
+    /// Number of consecutive anomalous readings treated as level shift.
+    pub shift_samples: u32,
+    /// Whether anomalies are written to the `annotation` log column.
+    pub annotate_log: bool,
+}
+
+impl Default for AnomalyConfig {
+    /// Get default anomaly detection configurations.
+    ///
+    /// # Returns
+    /// - Disabled anomaly detection flagging readings beyond 6 standard
+    ///   deviations of the last 200 readings.
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            window: 200,
+            threshold: 6.0,
+            shift_samples: 20,
+            annotate_log: false,
+        }
     }
 }
 
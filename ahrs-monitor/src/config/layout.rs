@@ -0,0 +1,404 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! User interface layout presets related configurations.
+
+use crate::config::{Deserialize, Serialize};
+
+/// Name of the layout preset showing everything.
+pub const DEFAULT_LAYOUT: &str = "Default";
+
+/// Number of columns of the Dashboard instrument grid.
+pub const GRID_COLUMNS: u8 = 4;
+
+/// Max number of rows spanned by the Dashboard instrument.
+pub const MAX_TILE_ROWS: u8 = 6;
+
+app_config! {
+    /// Layout preset selecting visible tabs, plot groups and panels.
+    pub struct LayoutPreset {
+        /// Preset name.
+        pub name: String,
+        /// Visible tabs.
+        pub tabs: Vec<TabKind>,
+        /// Visible plot groups of the Telemetry tab.
+        #[serde(default = "PlotGroup::all")]
+        pub plots: Vec<PlotGroup>,
+        /// Visible panels.
+        #[serde(default = "Panel::all")]
+        pub panels: Vec<Panel>,
+        /// Instrument grid of the Dashboard tab.
+        #[serde(default = "InstrumentTile::defaults")]
+        pub instruments: Vec<InstrumentTile>,
+    }
+}
+
+impl LayoutPreset {
+    /// Construct new `LayoutPreset` object.
+    ///
+    /// # Parameters
+    /// - `name` - given preset name.
+    /// - `tabs` - given visible tabs.
+    /// - `plots` - given visible plot groups.
+    /// - `panels` - given visible panels.
+    ///
+    /// # Returns
+    /// - New `LayoutPreset` object.
+    #[must_use]
+    pub fn new(
+        name: &str,
+        tabs: &[TabKind],
+        plots: &[PlotGroup],
+        panels: &[Panel],
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            tabs: tabs.to_vec(),
+            plots: plots.to_vec(),
+            panels: panels.to_vec(),
+            instruments: InstrumentTile::defaults(),
+        }
+    }
+
+    /// Set instrument grid of the Dashboard tab.
+    ///
+    /// # Parameters
+    /// - `instruments` - given instrument tiles in placement order.
+    ///
+    /// # Returns
+    /// - Updated `LayoutPreset` object.
+    #[must_use]
+    pub fn with_instruments(mut self, instruments: &[InstrumentTile]) -> Self {
+        self.instruments = instruments.to_vec();
+        self
+    }
+
+    /// Construct default layout preset showing everything.
+    ///
+    /// # Returns
+    /// - Layout preset with all tabs, plot groups and panels.
+    #[must_use]
+    pub fn full() -> Self {
+        Self::new(DEFAULT_LAYOUT, &TabKind::ALL, &PlotGroup::ALL, &Panel::ALL)
+    }
+
+    /// Check whether tab is visible.
+    ///
+    /// # Parameters
+    /// - `tab` - given tab to check.
+    ///
+    /// # Returns
+    /// - `true` - if tab is visible.
+    /// - `false` - otherwise.
+    #[must_use]
+    pub fn has_tab(&self, tab: TabKind) -> bool {
+        self.tabs.contains(&tab)
+    }
+
+    /// Check whether panel is visible.
+    ///
+    /// # Parameters
+    /// - `panel` - given panel to check.
+    ///
+    /// # Returns
+    /// - `true` - if panel is visible.
+    /// - `false` - otherwise.
+    #[must_use]
+    pub fn has_panel(&self, panel: Panel) -> bool {
+        self.panels.contains(&panel)
+    }
+}
+
+/// Application tabs.
+#[derive(
+    Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum TabKind {
+    /// Attitude dashboard.
+    #[default]
+    Dashboard,
+    /// Sensor readings plots.
+    Telemetry,
+    /// Raw packet inspector.
+    Inspector,
+    /// Recorded sessions comparison.
+    Compare,
+    /// Accelerometer calibration wizard.
+    Calibration,
+    /// Archived device sessions browser.
+    Sessions,
+    /// Recorded log replay.
+    Replay,
+    /// Dead-reckoning trajectory.
+    Trajectory,
+    /// Sensor noise analysis.
+    Analysis,
+    /// Frequency spectrum of sensor readings.
+    Spectrum,
+    /// Live configurations editor.
+    Settings,
+}
+
+impl TabKind {
+    /// All application tabs.
+    pub const ALL: [Self; 11] = [
+        Self::Dashboard,
+        Self::Telemetry,
+        Self::Inspector,
+        Self::Compare,
+        Self::Calibration,
+        Self::Sessions,
+        Self::Replay,
+        Self::Trajectory,
+        Self::Analysis,
+        Self::Spectrum,
+        Self::Settings,
+    ];
+}
+
+/// Plot groups of the Telemetry tab.
+#[derive(
+    Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum PlotGroup {
+    /// Accelerometer readings.
+    #[default]
+    Acc,
+    /// Linear (gravity-free) acceleration.
+    LinAcc,
+    /// Vibration metrics of the accelerometer readings.
+    Vibration,
+    /// Gyroscope readings.
+    Gyr,
+    /// Magnetometer readings.
+    Mag,
+    /// Barometer readings.
+    Baro,
+    /// Attitude quaternion components.
+    Quat,
+}
+
+impl PlotGroup {
+    /// All plot groups.
+    pub const ALL: [Self; 7] = [
+        Self::Acc,
+        Self::LinAcc,
+        Self::Vibration,
+        Self::Gyr,
+        Self::Mag,
+        Self::Baro,
+        Self::Quat,
+    ];
+
+    /// Get all plot groups.
+    ///
+    /// # Returns
+    /// - List of all plot groups.
+    #[must_use]
+    pub fn all() -> Vec<Self> {
+        Self::ALL.to_vec()
+    }
+}
+
+/// Application panels.
+#[derive(
+    Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Panel {
+    /// Toolbar under the tabs (pause, record, connection, fusion).
+    #[default]
+    Toolbar,
+    /// Status bar with connection status and packet counters.
+    StatusBar,
+}
+
+impl Panel {
+    /// All panels.
+    pub const ALL: [Self; 2] = [Self::Toolbar, Self::StatusBar];
+
+    /// Get all panels.
+    ///
+    /// # Returns
+    /// - List of all panels.
+    #[must_use]
+    pub fn all() -> Vec<Self> {
+        Self::ALL.to_vec()
+    }
+}
+
+/// Instrument widgets of the Dashboard tab.
+#[derive(
+    Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Instrument {
+    /// 3D attitude view.
+    #[default]
+    Attitude,
+    /// Artificial horizon.
+    Horizon,
+    /// Heading indicator.
+    Compass,
+    /// Euler angles readouts.
+    Euler,
+    /// Quaternion components readouts.
+    Quaternion,
+    /// Estimated gyroscope bias readouts.
+    GyroBias,
+    /// Baro-aided altitude and climb rate readouts.
+    Vertical,
+    /// Current and max G-load readouts.
+    GLoad,
+    /// Onboard vs local attitude error readout.
+    OnboardFusion,
+    /// Plot of the Euler angles.
+    AttitudePlot,
+    /// Plot of the onboard vs local attitude error.
+    ErrorPlot,
+}
+
+impl Instrument {
+    /// All instruments.
+    pub const ALL: [Self; 11] = [
+        Self::Attitude,
+        Self::Horizon,
+        Self::Compass,
+        Self::Euler,
+        Self::Quaternion,
+        Self::GyroBias,
+        Self::Vertical,
+        Self::GLoad,
+        Self::OnboardFusion,
+        Self::AttitudePlot,
+        Self::ErrorPlot,
+    ];
+
+    /// Get instrument name.
+    ///
+    /// # Returns
+    /// - Human-readable instrument name.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Attitude => "3D view",
+            Self::Horizon => "Horizon",
+            Self::Compass => "Compass",
+            Self::Euler => "Euler angles",
+            Self::Quaternion => "Quaternion",
+            Self::GyroBias => "Gyro bias",
+            Self::Vertical => "Vertical",
+            Self::GLoad => "G-load",
+            Self::OnboardFusion => "Onboard fusion",
+            Self::AttitudePlot => "Attitude plot",
+            Self::ErrorPlot => "Error plot",
+        }
+    }
+}
+
+/// Instrument tile of the Dashboard grid.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct InstrumentTile {
+    /// Instrument shown in the tile.
+    pub instrument: Instrument,
+    /// Number of grid columns spanned by the tile.
+    pub columns: u8,
+    /// Number of grid rows spanned by the tile.
+    pub rows: u8,
+}
+
+impl InstrumentTile {
+    /// Construct new `InstrumentTile` object.
+    ///
+    /// # Parameters
+    /// - `instrument` - given instrument shown in the tile.
+    /// - `columns` - given number of grid columns spanned by the tile.
+    /// - `rows` - given number of grid rows spanned by the tile.
+    ///
+    /// # Returns
+    /// - New `InstrumentTile` object.
+    #[must_use]
+    pub const fn new(instrument: Instrument, columns: u8, rows: u8) -> Self {
+        Self {
+            instrument,
+            columns,
+            rows,
+        }
+    }
+
+    /// Get default instrument grid.
+    ///
+    /// # Returns
+    /// - Attitude plot above the 3D view, compass and the readouts.
+    #[must_use]
+    pub fn defaults() -> Vec<Self> {
+        vec![
+            Self::new(Instrument::AttitudePlot, 4, 2),
+            Self::new(Instrument::Attitude, 2, 3),
+            Self::new(Instrument::Compass, 2, 3),
+            Self::new(Instrument::Euler, 2, 1),
+            Self::new(Instrument::Quaternion, 2, 1),
+            Self::new(Instrument::GyroBias, 2, 1),
+            Self::new(Instrument::GLoad, 1, 1),
+            Self::new(Instrument::Vertical, 1, 1),
+        ]
+    }
+}
+
+/// Get layout presets shipped with the monitor.
+///
+/// # Returns
+/// - List of built-in layout presets.
+#[must_use]
+pub fn builtin_layouts() -> Vec<LayoutPreset> {
+    vec![
+        LayoutPreset::full(),
+        LayoutPreset::new(
+            "Flight test",
+            &[
+                TabKind::Dashboard,
+                TabKind::Telemetry,
+                TabKind::Spectrum,
+                TabKind::Trajectory,
+                TabKind::Sessions,
+            ],
+            &[
+                PlotGroup::Acc,
+                PlotGroup::LinAcc,
+                PlotGroup::Vibration,
+                PlotGroup::Gyr,
+                PlotGroup::Mag,
+                PlotGroup::Baro,
+            ],
+            &Panel::ALL,
+        )
+        .with_instruments(&[
+            InstrumentTile::new(Instrument::Horizon, 2, 3),
+            InstrumentTile::new(Instrument::Compass, 2, 3),
+            InstrumentTile::new(Instrument::GLoad, 1, 1),
+            InstrumentTile::new(Instrument::Vertical, 1, 1),
+            InstrumentTile::new(Instrument::Euler, 2, 1),
+            InstrumentTile::new(Instrument::AttitudePlot, 4, 2),
+        ]),
+        LayoutPreset::new(
+            "Bench calibration",
+            &[
+                TabKind::Calibration,
+                TabKind::Analysis,
+                TabKind::Telemetry,
+                TabKind::Dashboard,
+            ],
+            &[PlotGroup::Acc, PlotGroup::Gyr, PlotGroup::Mag],
+            &Panel::ALL,
+        ),
+        LayoutPreset::new(
+            "Protocol debug",
+            &[TabKind::Inspector, TabKind::Telemetry, TabKind::Sessions],
+            &PlotGroup::ALL,
+            &Panel::ALL,
+        ),
+    ]
+}
@@ -10,5 +10,36 @@ app_config! {
     pub struct LoggingConfig {
         /// Directory where logs are stored.
         pub directory: String,
+        /// Retention policy of the recordings in the directory.
+        #[serde(default)]
+        pub retention: RetentionConfig,
+    }
+}
+
+/// Retention policy of the recordings configurations.
+#[derive(
+    Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(default, deny_unknown_fields)]
+pub struct RetentionConfig {
+    /// Max total size of the recordings in megabytes (0 - unlimited).
+    pub max_total_mb: u64,
+    /// Max age of the recordings in days (0 - unlimited).
+    pub max_age_days: u64,
+    /// Min free disk space in megabytes (0 - not checked, Linux only).
+    pub min_free_mb: u64,
+    /// Whether recordings are pruned without confirmation.
+    pub auto_prune: bool,
+}
+
+impl RetentionConfig {
+    /// Check whether any retention limit is set.
+    ///
+    /// # Returns
+    /// - `true` - if recordings are pruned by any limit.
+    /// - `false` - otherwise.
+    #[must_use]
+    pub const fn is_enabled(&self) -> bool {
+        self.max_total_mb > 0 || self.max_age_days > 0 || self.min_free_mb > 0
     }
 }
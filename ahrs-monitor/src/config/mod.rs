@@ -3,17 +3,45 @@
 
 //! Application's configurations.
 
+mod alert;
+mod channel;
+mod debug;
+mod display;
 mod imu;
+mod layout;
 mod logging;
 mod net;
+mod rules;
+mod shortcuts;
+mod validate;
 
-use crate::{app_config, config::logging::LoggingConfig};
+use crate::{
+    app_config,
+    config::logging::LoggingConfig,
+    core::{
+        calibration::AccCalibration, channel::ChannelRegistry,
+        mag_calibration::MagCalibration,
+    },
+};
+pub use alert::*;
+pub use channel::*;
+pub use debug::*;
+pub use display::*;
 pub use imu::*;
 use indtp::payload::PayloadType;
+use indtp::types::{AesKey, HmacKey};
+pub use layout::*;
+pub use logging::RetentionConfig;
 pub use net::*;
+pub use rules::*;
 use serde::{Deserialize, Serialize};
+pub use shortcuts::*;
 use std::fs;
-use indtp::types::{AesKey, HmacKey};
+use toml_edit::{
+    Array, ArrayOfTables, DocumentMut, InlineTable, Item, Table, TableLike,
+    value,
+};
+pub use validate::*;
 
 /// Window width in pixels.
 pub const APP_WINDOW_WIDTH: f32 = 1024.0;
@@ -33,12 +61,42 @@ pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 /// Max number of frame contexts in history.
 pub const HISTORY_MAX_SIZE: usize = 32;
 
-/// MPSC channel max number of messages in the buffer.
+/// Max number of frames in the packet inspector list.
+pub const INSPECTOR_MAX_FRAMES: usize = 256;
+
+/// Max number of rejected frames kept for problem reports.
+pub const QUARANTINE_MAX_SIZE: usize = 32;
+
+/// Max number of alerts in the alerts panel history.
+pub const ALERT_HISTORY_MAX_SIZE: usize = 100;
+
+/// Max number of sessions in the session browser.
+pub const MAX_ARCHIVED_SESSIONS: usize = 16;
+
+/// Interval between log directory retention checks in seconds.
+pub const HOUSEKEEPING_INTERVAL: u64 = 600;
+
+/// Events channel max number of queued events.
 pub const MPSC_CHANNEL_BUFFER_SIZE: usize = 128;
 
+/// Stats worker events channel max number of queued events.
+pub const WORKER_QUEUE_SIZE: usize = 4096;
+
 /// AHRS Monitor configuration file path.
 pub const CONFIG_FILE_PATH: &str = "configs/config.toml";
 
+/// Last known attitude estimation states file path.
+pub const WARM_START_FILE_PATH: &str = "configs/warm_start.json";
+
+/// Directory of the exported plot images.
+pub const PLOT_EXPORT_DIRECTORY: &str = "exports";
+
+/// Docking layout of the tabs file path.
+pub const DOCK_LAYOUT_FILE_PATH: &str = "configs/dock_layout.json";
+
+/// Plot traces hidden from the legend file path.
+pub const HIDDEN_TRACES_FILE_PATH: &str = "configs/hidden_traces.json";
+
 /// AES-128 encryption key.
 pub const AES_KEY: &AesKey =
     include_bytes!("../../configs/firmware/secrets/aes.key");
@@ -56,6 +114,24 @@ app_config! {
         pub net: NetConfig,
         /// Logging configurations.
         pub log: LoggingConfig,
+        /// Display configurations.
+        #[serde(default)]
+        pub display: DisplayConfig,
+        /// Alerting rules.
+        #[serde(default)]
+        pub alerts: Vec<AlertRule>,
+        /// Keyboard shortcuts.
+        #[serde(default)]
+        pub shortcuts: ShortcutsConfig,
+        /// Events channel configurations.
+        #[serde(default)]
+        pub channel: ChannelConfig,
+        /// Debug configurations.
+        #[serde(default)]
+        pub debug: DebugConfig,
+        /// Decoded channels info (set at startup, not configurable).
+        #[serde(skip)]
+        pub channels: ChannelRegistry,
     }
 }
 
@@ -76,3 +152,334 @@ pub fn load_config(path: &str) -> anyhow::Result<AppConfig> {
 
     Ok(config)
 }
+
+/// Check whether configurations differ.
+///
+/// # Parameters
+/// - `old` - given original configurations.
+/// - `new` - given edited configurations.
+///
+/// # Returns
+/// - `true` - if any configuration value is changed.
+/// - `false` - otherwise.
+pub fn is_modified<T: Serialize>(old: &T, new: &T) -> bool {
+    serde_json::to_value(old).ok() != serde_json::to_value(new).ok()
+}
+
+/// Save application's configurations into configurations file.
+///
+/// Only values which differ from the ones stored in the file are written,
+/// other contents of the file (including comments) are preserved. Arrays of
+/// tables (alerting rules, layout presets, generator signals) are saved by
+/// their own editors and are left intact.
+///
+/// # Parameters
+/// - `path` - given config file path.
+/// - `config` - given configurations to save.
+///
+/// # Returns
+/// - `Ok` - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - Config file reading, parsing or writing errors.
+/// - Values which are not representable in TOML.
+pub fn save_config(path: &str, config: &AppConfig) -> anyhow::Result<()> {
+    let saved = serde_json::to_value(load_config(path)?)?;
+    let edited = serde_json::to_value(config)?;
+
+    let content = fs::read_to_string(path)?;
+    let mut document: DocumentMut = content.parse()?;
+
+    if let (Some(saved), Some(edited)) = (saved.as_object(), edited.as_object())
+    {
+        update_table(document.as_table_mut(), saved, edited)?;
+    }
+
+    fs::write(path, document.to_string())?;
+    Ok(())
+}
+
+/// Write changed configuration values into TOML table.
+///
+/// # Parameters
+/// - `table` - given TOML table to update.
+/// - `saved` - given configuration values stored in the table.
+/// - `edited` - given configuration values to write.
+///
+/// # Returns
+/// - `Ok` - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - Values which are not representable in TOML.
+fn update_table(
+    table: &mut dyn TableLike,
+    saved: &serde_json::Map<String, serde_json::Value>,
+    edited: &serde_json::Map<String, serde_json::Value>,
+) -> anyhow::Result<()> {
+    let empty = serde_json::Map::new();
+
+    for (key, field) in edited {
+        let old = saved.get(key);
+
+        if old == Some(field) {
+            continue;
+        }
+
+        if let Some(fields) = field.as_object() {
+            let old = old.and_then(serde_json::Value::as_object);
+
+            match table.get_mut(key) {
+                Some(Item::ArrayOfTables(_)) => {}
+                Some(item) => {
+                    if let Some(nested) = item.as_table_like_mut() {
+                        update_table(nested, old.unwrap_or(&empty), fields)?;
+                    }
+                }
+                None => {
+                    let mut nested = Table::new();
+                    nested.set_implicit(true);
+                    update_table(&mut nested, &empty, fields)?;
+                    table.insert(key, Item::Table(nested));
+                }
+            }
+
+            continue;
+        }
+
+        // Omitted optional value.
+        let Some(mut new) = rules::toml_value(field)? else {
+            table.remove(key);
+            continue;
+        };
+
+        match table.get_mut(key) {
+            Some(Item::ArrayOfTables(_)) => {}
+            Some(Item::Value(current)) => {
+                // Keeping comments and whitespaces around the value.
+                *new.decor_mut() = current.decor().clone();
+                *current = new;
+            }
+            _ => {
+                table.insert(key, value(new));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Save accelerometer calibration into configurations file.
+///
+/// Other contents of the file (including comments) are preserved.
+///
+/// # Parameters
+/// - `path` - given config file path.
+/// - `calibration` - given accelerometer calibration to save.
+///
+/// # Returns
+/// - `Ok` - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - Config file reading, parsing or writing errors.
+/// - Missing `[imu]` section.
+pub fn save_acc_calibration(
+    path: &str,
+    calibration: &AccCalibration,
+) -> anyhow::Result<()> {
+    let content = fs::read_to_string(path)?;
+    let mut document: DocumentMut = content.parse()?;
+    let section = imu_subsection(&mut document, "acc_calibration")?;
+
+    section.insert("offset", value(float_array(calibration.offset)));
+    section.insert("scale", value(float_array(calibration.scale)));
+
+    fs::write(path, document.to_string())?;
+    Ok(())
+}
+
+/// Save magnetometer calibration into configurations file.
+///
+/// Other contents of the file (including comments) are preserved.
+///
+/// # Parameters
+/// - `path` - given config file path.
+/// - `calibration` - given magnetometer calibration to save.
+///
+/// # Returns
+/// - `Ok` - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - Config file reading, parsing or writing errors.
+/// - Missing `[imu]` section.
+pub fn save_mag_calibration(
+    path: &str,
+    calibration: &MagCalibration,
+) -> anyhow::Result<()> {
+    let content = fs::read_to_string(path)?;
+    let mut document: DocumentMut = content.parse()?;
+    let section = imu_subsection(&mut document, "mag_calibration")?;
+
+    let matrix: Array = calibration
+        .matrix
+        .iter()
+        .map(|row| toml_edit::Value::from(float_array(*row)))
+        .collect();
+
+    section.insert("offset", value(float_array(calibration.offset)));
+    section.insert("matrix", value(matrix));
+
+    fs::write(path, document.to_string())?;
+    Ok(())
+}
+
+/// Get subsection of the `[imu]` section, creating it if missing.
+///
+/// # Parameters
+/// - `document` - given configurations document.
+/// - `key` - given subsection key (e.g. `acc_calibration`).
+///
+/// # Returns
+/// - Subsection table - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - Missing `[imu]` section.
+/// - Subsection is not a table.
+fn imu_subsection<'a>(
+    document: &'a mut DocumentMut,
+    key: &str,
+) -> anyhow::Result<&'a mut dyn TableLike> {
+    let imu = document
+        .get_mut("imu")
+        .and_then(Item::as_table_mut)
+        .ok_or_else(|| anyhow::anyhow!("missing [imu] section"))?;
+
+    imu.entry(key)
+        .or_insert_with(toml_edit::table)
+        .as_table_like_mut()
+        .ok_or_else(|| anyhow::anyhow!("imu.{key} is not a table"))
+}
+
+/// Convert values into TOML array.
+///
+/// # Parameters
+/// - `values` - given values to convert.
+///
+/// # Returns
+/// - Array of the shortest representation of values (e.g. 0.1 instead of
+///   0.10000000149011612).
+fn float_array(values: [f32; 3]) -> Array {
+    values
+        .iter()
+        .map(|v| v.to_string().parse().unwrap_or_else(|_| f64::from(*v)))
+        .collect()
+}
+
+/// Save layout preset into configurations file.
+///
+/// User-defined preset with the same name is updated, otherwise new one is
+/// appended to `[[display.layouts]]`, so that built-in presets are overridden
+/// by the saved copy. Other contents of the file (including comments) are
+/// preserved.
+///
+/// # Parameters
+/// - `path` - given config file path.
+/// - `preset` - given layout preset to save.
+///
+/// # Returns
+/// - `Ok` - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - Config file reading, parsing or writing errors.
+/// - `display` or `display.layouts` of unexpected type.
+pub fn save_layout_preset(
+    path: &str,
+    preset: &LayoutPreset,
+) -> anyhow::Result<()> {
+    let content = fs::read_to_string(path)?;
+    let mut document: DocumentMut = content.parse()?;
+
+    let layouts = document
+        .entry("display")
+        .or_insert_with(toml_edit::table)
+        .as_table_mut()
+        .ok_or_else(|| anyhow::anyhow!("display is not a table"))?
+        .entry("layouts")
+        .or_insert_with(|| Item::ArrayOfTables(ArrayOfTables::new()))
+        .as_array_of_tables_mut()
+        .ok_or_else(|| anyhow::anyhow!("display.layouts is not an array"))?;
+
+    let is_saved = |table: &Table| {
+        table.get("name").and_then(Item::as_str) == Some(preset.name.as_str())
+    };
+
+    let index = if let Some(index) = layouts.iter().position(is_saved) {
+        index
+    } else {
+        let mut table = Table::new();
+        table.insert("name", value(preset.name.as_str()));
+        table.insert("tabs", value(names(&preset.tabs)?));
+        table.insert("plots", value(names(&preset.plots)?));
+        table.insert("panels", value(names(&preset.panels)?));
+
+        layouts.push(table);
+        layouts.len() - 1
+    };
+
+    let table = layouts
+        .get_mut(index)
+        .ok_or_else(|| anyhow::anyhow!("layout preset is not found"))?;
+
+    let mut instruments = Array::new();
+
+    for tile in &preset.instruments {
+        let mut entry = InlineTable::new();
+        entry.insert("instrument", name(&tile.instrument)?.into());
+        entry.insert("columns", i64::from(tile.columns).into());
+        entry.insert("rows", i64::from(tile.rows).into());
+        instruments.push(entry);
+    }
+
+    table.insert("instruments", value(instruments));
+
+    fs::write(path, document.to_string())?;
+    Ok(())
+}
+
+/// Get configuration name of the enumeration value.
+///
+/// # Parameters
+/// - `variant` - given unit enumeration value to handle.
+///
+/// # Returns
+/// - Name of the value as written in the config file - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - Value is not serialized as a string.
+fn name<T: Serialize>(variant: &T) -> anyhow::Result<String> {
+    match serde_json::to_value(variant)? {
+        serde_json::Value::String(name) => Ok(name),
+        other => anyhow::bail!("{other} is not a unit enumeration value"),
+    }
+}
+
+/// Get configuration names of the enumeration values.
+///
+/// # Parameters
+/// - `variants` - given unit enumeration values to handle.
+///
+/// # Returns
+/// - Array of the value names - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - Value is not serialized as a string.
+fn names<T: Serialize>(variants: &[T]) -> anyhow::Result<Array> {
+    variants.iter().map(name).collect()
+}
@@ -16,7 +16,141 @@ app_config! {
         pub simulator_ip_address: String,
         /// Simulator's UDP port.
         pub simulator_udp_port: u16,
+        /// Simulator's UDP control port (0 - control is disabled).
+        #[serde(default)]
+        pub simulator_control_port: u16,
         /// Flag whether to use encryption for IMU data transmission.
         pub use_encryption: bool,
+        /// Transport delivering INDTP frames to the ingester.
+        #[serde(default)]
+        pub transport: Transport,
+        /// Ingester's Unix domain socket path.
+        #[serde(default)]
+        pub unix_socket_path: String,
+        /// Ingester's `SocketCAN` network interface name.
+        #[serde(default)]
+        pub can_interface: String,
+        /// CAN identifier of frames carrying IDTP frame segments.
+        #[serde(default)]
+        pub can_id: u32,
+        /// Downstream UDP endpoints to forward valid frames to.
+        #[serde(default)]
+        pub forward_endpoints: Vec<String>,
+        /// Device UDP endpoint to send keepalive datagrams to.
+        #[serde(default)]
+        pub device_address: String,
+        /// Keepalive datagrams interval in milliseconds (0 - disabled).
+        #[serde(default)]
+        pub keepalive_interval_ms: u64,
+        /// UDP socket receive buffer size in bytes (0 - OS default).
+        #[serde(default)]
+        pub recv_buffer_size: usize,
+        /// UDP socket busy polling time in microseconds (0 - disabled,
+        /// Linux only).
+        #[serde(default)]
+        pub busy_poll_us: u32,
+        /// DSCP value of the UDP socket datagrams (0 - best effort).
+        #[serde(default)]
+        pub dscp: u8,
+        /// Secondary (redundant) link delivering the same frames.
+        #[serde(default)]
+        pub secondary: Option<LinkConfig>,
     }
+
+    /// Secondary (redundant) link configurations.
+    pub struct LinkConfig {
+        /// Transport delivering INDTP frames over the secondary link.
+        #[serde(default)]
+        pub transport: Transport,
+        /// Secondary link IP address.
+        #[serde(default)]
+        pub ip_address: String,
+        /// Secondary link UDP port.
+        #[serde(default)]
+        pub udp_port: u16,
+        /// Secondary link Unix domain socket path.
+        #[serde(default)]
+        pub unix_socket_path: String,
+        /// Secondary link `SocketCAN` network interface name.
+        #[serde(default)]
+        pub can_interface: String,
+        /// CAN identifier of frames carrying IDTP frame segments.
+        #[serde(default)]
+        pub can_id: u32,
+        /// Time without primary link frames before failover in milliseconds.
+        #[serde(default = "default_failover_timeout_ms")]
+        pub failover_timeout_ms: u64,
+    }
+}
+
+/// Get default time without primary link frames before failover.
+///
+/// # Returns
+/// - Default failover timeout in milliseconds.
+const fn default_failover_timeout_ms() -> u64 {
+    500
+}
+
+impl NetConfig {
+    /// Get frame source description.
+    ///
+    /// # Returns
+    /// - Configured transport endpoint (e.g. `udp://127.0.0.1:10000`).
+    #[must_use]
+    pub fn endpoint(&self) -> String {
+        match self.transport {
+            Transport::Udp => {
+                format!("udp://{}:{}", self.ip_address, self.udp_port)
+            }
+            Transport::Unix => format!("unix://{}", self.unix_socket_path),
+            Transport::Stdin => "stdin".to_string(),
+            Transport::Can => {
+                format!("can://{}#{:X}", self.can_interface, self.can_id)
+            }
+            Transport::Generator => "generator".to_string(),
+        }
+    }
+
+    /// Get networks configurations of the secondary link.
+    ///
+    /// # Returns
+    /// - Networks configurations with secondary link transport settings -
+    ///   if secondary link is configured.
+    /// - `None` - otherwise.
+    #[must_use]
+    pub fn secondary_net(&self) -> Option<Self> {
+        let link = self.secondary.as_ref()?;
+
+        Some(Self {
+            transport: link.transport,
+            ip_address: link.ip_address.clone(),
+            udp_port: link.udp_port,
+            unix_socket_path: link.unix_socket_path.clone(),
+            can_interface: link.can_interface.clone(),
+            can_id: link.can_id,
+            // Keepalive datagrams are sent over the primary link only.
+            keepalive_interval_ms: 0,
+            secondary: None,
+            ..self.clone()
+        })
+    }
+}
+
+/// Transports delivering INDTP frames to the ingester.
+#[derive(
+    Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Transport {
+    /// UDP datagrams (one frame per datagram).
+    #[default]
+    Udp,
+    /// Unix domain datagram socket (one frame per datagram).
+    Unix,
+    /// Standard input stream (length-prefixed frames).
+    Stdin,
+    /// `SocketCAN` interface (frames segmented over CAN-FD, Linux only).
+    Can,
+    /// Built-in test-signal generator (no network, `debug.generator`).
+    Generator,
 }
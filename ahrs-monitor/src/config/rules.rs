@@ -0,0 +1,375 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Shareable rule sets.
+//!
+//! Rule set is a standalone TOML file with the alerting rules of the
+//! configurations file, so that monitoring setups can be moved between
+//! machines and shared between teammates. Rule sets are versioned, so that
+//! files written by newer versions with unknown sections are rejected
+//! instead of being imported partially.
+//!
+//! Webhook and command actions of imported rules send data or run programs
+//! on the importing machine, so that they are stripped unless the user
+//! explicitly trusts the rule set. Recording triggers and derived channels
+//! are not configurable yet, so that rule sets carry alerting rules only.
+
+use crate::{
+    app_config,
+    config::{AlertAction, AlertRule, load_config},
+};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use toml_edit::{Array, ArrayOfTables, DocumentMut, InlineTable, Item, Table};
+
+/// Current rule set file format version.
+pub const RULES_FORMAT_VERSION: u32 = 1;
+
+/// Alerting rule fields in the order of declaration.
+const RULE_FIELDS: [&str; 5] =
+    ["name", "metric", "condition", "threshold", "actions"];
+
+/// Header comment of the exported rule set files.
+const RULES_FILE_HEADER: &str = "\
+# AHRS Monitor rule set.
+# Import with: ahrs-monitor-cli rules import <file>
+";
+
+app_config! {
+    /// Shareable set of rules.
+    pub struct RuleSet {
+        /// Rule set file format version.
+        pub version: u32,
+        /// Alerting rules.
+        #[serde(default)]
+        pub alerts: Vec<AlertRule>,
+    }
+}
+
+/// How imported rules are combined with the configured ones.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ImportMode {
+    /// Rules with the same name are replaced, other rules are appended.
+    #[default]
+    Merge,
+    /// All configured rules are replaced with the imported ones.
+    Replace,
+}
+
+/// Result of the rule set import.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ImportSummary {
+    /// Number of appended rules.
+    pub added: usize,
+    /// Number of replaced rules with the same name.
+    pub replaced: usize,
+    /// Number of removed rules (only in `ImportMode::Replace`).
+    pub removed: usize,
+    /// Descriptions of the stripped webhook and command actions.
+    pub stripped: Vec<String>,
+}
+
+impl RuleSet {
+    /// Construct rule set of the current format version.
+    ///
+    /// # Parameters
+    /// - `alerts` - given alerting rules.
+    ///
+    /// # Returns
+    /// - New `RuleSet` object.
+    #[must_use]
+    pub const fn new(alerts: Vec<AlertRule>) -> Self {
+        Self {
+            version: RULES_FORMAT_VERSION,
+            alerts,
+        }
+    }
+
+    /// Load rule set from file.
+    ///
+    /// # Parameters
+    /// - `path` - given rule set file path.
+    ///
+    /// # Returns
+    /// - Loaded rule set - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - File reading or parsing errors.
+    /// - Unsupported format version.
+    /// - Invalid rules.
+    pub fn load(path: &str) -> anyhow::Result<Self> {
+        let content = fs::read_to_string(path)?;
+        let rules: Self = toml::from_str(&content)?;
+
+        anyhow::ensure!(
+            (1..=RULES_FORMAT_VERSION).contains(&rules.version),
+            "{path}: unsupported rule set version {}, max is \
+             {RULES_FORMAT_VERSION}",
+            rules.version
+        );
+
+        rules.validate()?;
+        Ok(rules)
+    }
+
+    /// Save rule set into file.
+    ///
+    /// # Parameters
+    /// - `path` - given rule set file path.
+    ///
+    /// # Returns
+    /// - `Ok` - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - Serialization or file writing errors.
+    pub fn save(&self, path: &str) -> anyhow::Result<()> {
+        let content = toml::to_string_pretty(self)?;
+        fs::write(path, format!("{RULES_FILE_HEADER}\n{content}"))?;
+        Ok(())
+    }
+}
+
+/// Export rules of the configurations file into rule set file.
+///
+/// # Parameters
+/// - `config_path` - given config file path.
+/// - `rules_path` - given rule set file path to write.
+///
+/// # Returns
+/// - Number of exported rules - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - Config file reading or parsing errors.
+/// - Rule set file writing errors.
+pub fn export_rules(
+    config_path: &str,
+    rules_path: &str,
+) -> anyhow::Result<usize> {
+    let config = load_config(config_path)?;
+    let rules = RuleSet::new(config.alerts);
+
+    rules.save(rules_path)?;
+    Ok(rules.alerts.len())
+}
+
+/// Import rule set file into configurations file.
+///
+/// Other contents of the configurations file (including comments) are
+/// preserved.
+///
+/// # Parameters
+/// - `config_path` - given config file path.
+/// - `rules_path` - given rule set file path to read.
+/// - `mode` - given way of combining imported and configured rules.
+/// - `is_trusted` - given flag whether to keep webhook and command actions
+///   of the imported rules.
+///
+/// # Returns
+/// - Summary of the changed rules - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - Rule set loading errors.
+/// - Config file reading, parsing or writing errors.
+/// - `alerts` of unexpected type.
+pub fn import_rules(
+    config_path: &str,
+    rules_path: &str,
+    mode: ImportMode,
+    is_trusted: bool,
+) -> anyhow::Result<ImportSummary> {
+    let mut imported = RuleSet::load(rules_path)?;
+    let mut alerts = load_config(config_path)?.alerts;
+    let mut summary = ImportSummary::default();
+
+    if !is_trusted {
+        for rule in &mut imported.alerts {
+            rule.actions.retain(|action| match external_action(action) {
+                Some(description) => {
+                    summary
+                        .stripped
+                        .push(format!("{}: {description}", rule.name));
+                    false
+                }
+                None => true,
+            });
+        }
+    }
+
+    if mode == ImportMode::Replace {
+        summary.replaced = imported
+            .alerts
+            .iter()
+            .filter(|rule| alerts.iter().any(|r| r.name == rule.name))
+            .count();
+        summary.added = imported.alerts.len() - summary.replaced;
+        summary.removed = alerts.len().saturating_sub(summary.replaced);
+        alerts = imported.alerts;
+    } else {
+        for rule in imported.alerts {
+            if let Some(existing) =
+                alerts.iter_mut().find(|r| r.name == rule.name)
+            {
+                *existing = rule;
+                summary.replaced += 1;
+            } else {
+                alerts.push(rule);
+                summary.added += 1;
+            }
+        }
+    }
+
+    let content = fs::read_to_string(config_path)?;
+    let mut document: DocumentMut = content.parse()?;
+
+    let tables = document
+        .entry("alerts")
+        .or_insert_with(|| Item::ArrayOfTables(ArrayOfTables::new()))
+        .as_array_of_tables_mut()
+        .ok_or_else(|| anyhow::anyhow!("alerts is not an array of tables"))?;
+
+    // Existing tables are updated in place, so that their comments are kept.
+    for (i, rule) in alerts.iter().enumerate() {
+        if let Some(table) = tables.get_mut(i) {
+            set_rule_fields(table, rule)?;
+        } else {
+            let mut table = Table::new();
+            set_rule_fields(&mut table, rule)?;
+            tables.push(table);
+        }
+    }
+
+    while tables.len() > alerts.len() {
+        tables.remove(tables.len() - 1);
+    }
+
+    fs::write(config_path, document.to_string())?;
+    Ok(summary)
+}
+
+/// Describe action acting outside of the monitor.
+///
+/// # Parameters
+/// - `action` - given alerting rule action.
+///
+/// # Returns
+/// - Action description - for webhook and command actions.
+/// - `None` - otherwise.
+fn external_action(action: &AlertAction) -> Option<String> {
+    match action {
+        AlertAction::Webhook { url } => Some(format!("webhook {url}")),
+        AlertAction::Command { command, args } => {
+            Some(format!("command {command} {args:?}"))
+        }
+        AlertAction::Sound { .. } | AlertAction::Modal => None,
+    }
+}
+
+/// Write alerting rule into configurations file table.
+///
+/// # Parameters
+/// - `table` - given table of the rule to update.
+/// - `rule` - given alerting rule to write.
+///
+/// # Returns
+/// - `Ok` - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - Rule is not serialized as a map.
+fn set_rule_fields(table: &mut Table, rule: &AlertRule) -> anyhow::Result<()> {
+    let serde_json::Value::Object(fields) = serde_json::to_value(rule)? else {
+        anyhow::bail!("alerting rule is not serialized as a map");
+    };
+
+    table.retain(|key, _| fields.get(key).is_some_and(|f| !f.is_null()));
+
+    // Keys of JSON maps are sorted, so that new keys are written in the order
+    // of the rule declaration.
+    for key in RULE_FIELDS {
+        let Some(field) =
+            fields.get(key).map(toml_value).transpose()?.flatten()
+        else {
+            continue;
+        };
+
+        if let Some(item) = table.get_mut(key) {
+            *item = toml_edit::value(field);
+        } else {
+            table.insert(key, toml_edit::value(field));
+        }
+    }
+
+    Ok(())
+}
+
+/// Convert JSON value into TOML value.
+///
+/// # Parameters
+/// - `json` - given JSON value to convert.
+///
+/// # Returns
+/// - TOML value (`None` for null, which is an omitted optional value).
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - Number which is not representable in TOML.
+#[allow(clippy::cast_possible_truncation)]
+pub(super) fn toml_value(
+    json: &serde_json::Value,
+) -> anyhow::Result<Option<toml_edit::Value>> {
+    use serde_json::Value;
+
+    let converted = match json {
+        Value::Null => return Ok(None),
+        Value::Bool(flag) => (*flag).into(),
+        Value::String(text) => text.as_str().into(),
+        Value::Number(number) => {
+            if let Some(integer) = number.as_i64() {
+                integer.into()
+            } else if let Some(float) = number.as_f64() {
+                // Rule values are f32, shortest representation of them is
+                // written (e.g. 0.1 instead of 0.10000000149011612).
+                let float = float as f32;
+                float
+                    .to_string()
+                    .parse()
+                    .unwrap_or_else(|_| f64::from(float))
+                    .into()
+            } else {
+                anyhow::bail!("{number} is not representable in TOML");
+            }
+        }
+        Value::Array(items) => {
+            let mut array = Array::new();
+
+            for item in items {
+                if let Some(item) = toml_value(item)? {
+                    array.push(item);
+                }
+            }
+
+            array.into()
+        }
+        Value::Object(fields) => {
+            let mut table = InlineTable::new();
+
+            // Tag of the action goes first (e.g. `{ type = "modal" }`).
+            let tag = fields.get_key_value("type");
+            let rest = fields.iter().filter(|(key, _)| *key != "type");
+
+            for (key, field) in tag.into_iter().chain(rest) {
+                if let Some(field) = toml_value(field)? {
+                    table.insert(key, field);
+                }
+            }
+
+            table.into()
+        }
+    };
+
+    Ok(Some(converted))
+}
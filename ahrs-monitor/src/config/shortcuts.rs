@@ -0,0 +1,186 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Keyboard shortcuts related configurations.
+
+use crate::config::{Deserialize, Serialize};
+use egui::{Key, KeyboardShortcut, Modifiers};
+
+/// Keyboard shortcuts configurations.
+///
+/// Bindings are key names optionally prefixed with `Ctrl`, `Shift` and `Alt`
+/// modifiers separated by `+` (e.g. `"Ctrl+R"`), empty binding disables the
+/// shortcut.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ShortcutsConfig {
+    /// Pause or resume the stream display.
+    pub pause: String,
+    /// Start or stop recording.
+    pub record: String,
+    /// Show the next tab in the focused pane.
+    pub next_tab: String,
+    /// Show the previous tab in the focused pane.
+    pub previous_tab: String,
+    /// Restart attitude estimation of all devices.
+    pub reset_attitude: String,
+    /// Save screenshot of the current view.
+    pub screenshot: String,
+}
+
+impl Default for ShortcutsConfig {
+    /// Get default keyboard shortcuts.
+    ///
+    /// # Returns
+    /// - Default keyboard shortcuts.
+    fn default() -> Self {
+        Self {
+            pause: "Space".to_string(),
+            record: "Ctrl+R".to_string(),
+            next_tab: "Ctrl+Tab".to_string(),
+            previous_tab: "Ctrl+Shift+Tab".to_string(),
+            reset_attitude: "Ctrl+Shift+A".to_string(),
+            screenshot: "F12".to_string(),
+        }
+    }
+}
+
+/// Actions triggered by keyboard shortcuts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShortcutAction {
+    /// Pause or resume the stream display.
+    Pause,
+    /// Start or stop recording.
+    Record,
+    /// Show the next tab in the focused pane.
+    NextTab,
+    /// Show the previous tab in the focused pane.
+    PreviousTab,
+    /// Restart attitude estimation of all devices.
+    ResetAttitude,
+    /// Save screenshot of the current view.
+    Screenshot,
+}
+
+impl ShortcutAction {
+    /// All shortcut actions.
+    pub const ALL: [Self; 6] = [
+        Self::Pause,
+        Self::Record,
+        Self::NextTab,
+        Self::PreviousTab,
+        Self::ResetAttitude,
+        Self::Screenshot,
+    ];
+
+    /// Get configuration key of the action.
+    ///
+    /// # Returns
+    /// - Key of the action binding in the `[shortcuts]` section.
+    #[must_use]
+    pub const fn key(self) -> &'static str {
+        match self {
+            Self::Pause => "pause",
+            Self::Record => "record",
+            Self::NextTab => "next_tab",
+            Self::PreviousTab => "previous_tab",
+            Self::ResetAttitude => "reset_attitude",
+            Self::Screenshot => "screenshot",
+        }
+    }
+}
+
+impl ShortcutsConfig {
+    /// Get binding of the action.
+    ///
+    /// # Parameters
+    /// - `action` - given shortcut action.
+    ///
+    /// # Returns
+    /// - Configured binding string slice.
+    #[must_use]
+    pub fn binding(&self, action: ShortcutAction) -> &str {
+        match action {
+            ShortcutAction::Pause => &self.pause,
+            ShortcutAction::Record => &self.record,
+            ShortcutAction::NextTab => &self.next_tab,
+            ShortcutAction::PreviousTab => &self.previous_tab,
+            ShortcutAction::ResetAttitude => &self.reset_attitude,
+            ShortcutAction::Screenshot => &self.screenshot,
+        }
+    }
+
+    /// Get enabled keyboard shortcuts.
+    ///
+    /// Invalid bindings are skipped, they are reported by the config
+    /// validation.
+    ///
+    /// # Returns
+    /// - List of actions with their keyboard shortcuts, the ones with more
+    ///   modifiers first.
+    #[must_use]
+    pub fn shortcuts(&self) -> Vec<(ShortcutAction, KeyboardShortcut)> {
+        let mut shortcuts: Vec<_> = ShortcutAction::ALL
+            .into_iter()
+            .filter_map(|action| {
+                let shortcut = parse_shortcut(self.binding(action)).ok()??;
+                Some((action, shortcut))
+            })
+            .collect();
+
+        // Shortcuts ignore extra Shift and Alt modifiers, so that
+        // "Ctrl+Shift+Tab" must be checked before "Ctrl+Tab".
+        shortcuts.sort_by_key(|(_, shortcut)| {
+            let modifiers = shortcut.modifiers;
+            std::cmp::Reverse(
+                [modifiers.command, modifiers.shift, modifiers.alt]
+                    .into_iter()
+                    .filter(|&is_set| is_set)
+                    .count(),
+            )
+        });
+
+        shortcuts
+    }
+}
+
+/// Parse keyboard shortcut binding.
+///
+/// # Parameters
+/// - `binding` - given binding (e.g. `"Ctrl+Shift+Tab"`).
+///
+/// # Returns
+/// - Keyboard shortcut (`None` for empty binding) - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - Unknown modifier or key name.
+pub fn parse_shortcut(
+    binding: &str,
+) -> Result<Option<KeyboardShortcut>, String> {
+    let binding = binding.trim();
+
+    if binding.is_empty() {
+        return Ok(None);
+    }
+
+    let mut parts: Vec<&str> = binding.split('+').map(str::trim).collect();
+    let name = parts.pop().unwrap_or_default();
+    let mut modifiers = Modifiers::NONE;
+
+    for part in parts {
+        // Ctrl is mapped to Cmd on macOS.
+        modifiers = modifiers
+            | match part.to_ascii_lowercase().as_str() {
+                "ctrl" | "cmd" => Modifiers::COMMAND,
+                "shift" => Modifiers::SHIFT,
+                "alt" => Modifiers::ALT,
+                _ => return Err(format!("unknown modifier \"{part}\"")),
+            };
+    }
+
+    let key = Key::from_name(name)
+        .ok_or_else(|| format!("unknown key \"{name}\""))?;
+
+    Ok(Some(KeyboardShortcut::new(modifiers, key)))
+}
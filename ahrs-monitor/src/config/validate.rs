@@ -0,0 +1,984 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Semantic validation of application's configurations.
+
+use crate::config::{
+    AlertAction, AlertRule, AppConfig, DebugConfig, DisplayConfig,
+    GRID_COLUMNS, ImuConfig, LayoutPreset, LinkConfig, MAX_TILE_ROWS,
+    NetConfig, PlotRenderer, RuleSet, ShortcutAction, ShortcutsConfig,
+    SignalConfig, Transport, Waveform, parse_shortcut,
+};
+use crate::core::replay::MAX_REPLAY_WINDOW;
+use egui::KeyboardShortcut;
+use indtp::{Mode, payload::PayloadType};
+use std::{fmt, path::Path};
+
+/// Max supported IMU sample rate in Hz.
+const MAX_SAMPLE_RATE: f32 = 10_000.0;
+
+/// Max number of decimal places of the numeric readouts.
+const MAX_PRECISION: usize = 9;
+
+/// Max number of plotted points per metric.
+pub const MAX_PLOT_HISTORY: usize = 1_000_000;
+
+/// Max extended (29-bit) CAN identifier.
+const MAX_CAN_ID: u32 = 0x1FFF_FFFF;
+
+/// Max Differentiated Services Code Point (6-bit field).
+const MAX_DSCP: u8 = 63;
+
+/// Check whether endpoint is in `host:port` format.
+///
+/// # Parameters
+/// - `endpoint` - given endpoint to check.
+///
+/// # Returns
+/// - `true` - if endpoint has non-empty host and non-zero port.
+/// - `false` - otherwise.
+fn is_host_port(endpoint: &str) -> bool {
+    endpoint.rsplit_once(':').is_some_and(|(host, port)| {
+        !host.is_empty() && port.parse::<u16>().is_ok_and(|p| p != 0)
+    })
+}
+
+/// Single invalid configuration value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigIssue {
+    /// Configuration key path (e.g. `imu.sample_rate`).
+    pub key: String,
+    /// Human-readable description of the problem.
+    pub message: String,
+}
+
+/// Aggregated configuration validation errors.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfigErrors {
+    /// All found invalid configuration values.
+    pub issues: Vec<ConfigIssue>,
+}
+
+impl fmt::Display for ConfigErrors {
+    /// Format list of invalid configuration values.
+    ///
+    /// # Parameters
+    /// - `f` - given formatter to write into.
+    ///
+    /// # Returns
+    /// - Formatting result.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid configurations ({} issues):", self.issues.len())?;
+
+        for issue in &self.issues {
+            write!(f, "\n  - {}: {}", issue.key, issue.message)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ConfigErrors {}
+
+/// Collector of invalid configuration values.
+#[derive(Debug, Default)]
+struct Validator {
+    /// Found invalid configuration values.
+    issues: Vec<ConfigIssue>,
+}
+
+impl Validator {
+    /// Record issue if condition does not hold.
+    ///
+    /// # Parameters
+    /// - `is_valid` - given result of the value check.
+    /// - `key` - given configuration key path.
+    /// - `message` - given issue description builder.
+    fn check(
+        &mut self,
+        is_valid: bool,
+        key: impl Into<String>,
+        message: impl FnOnce() -> String,
+    ) {
+        if !is_valid {
+            self.issues.push(ConfigIssue {
+                key: key.into(),
+                message: message(),
+            });
+        }
+    }
+
+    /// Validate IMU configurations.
+    ///
+    /// # Parameters
+    /// - `imu` - given IMU configurations to validate.
+    /// - `custom_payload_types` - given payload types with custom decoders.
+    fn imu(&mut self, imu: &ImuConfig, custom_payload_types: &[u8]) {
+        let rate = imu.sample_rate;
+
+        self.check(
+            rate.is_finite() && rate > 0.0 && rate <= MAX_SAMPLE_RATE,
+            "imu.sample_rate",
+            || {
+                format!(
+                    "must be in range (0, {MAX_SAMPLE_RATE}] Hz, got {rate}"
+                )
+            },
+        );
+
+        let payload_type = imu.payload_type;
+
+        self.check(
+            PayloadType::from(payload_type).is_standard()
+                || custom_payload_types.contains(&payload_type),
+            "imu.payload_type",
+            || {
+                format!(
+                    "{payload_type:#04X} is neither a standard payload type \
+                     nor has a custom decoder"
+                )
+            },
+        );
+
+        let mode = imu.protocol_mode;
+
+        self.check(Mode::try_from(mode).is_ok(), "imu.protocol_mode", || {
+            format!("{mode:#04X} is not a valid protocol mode")
+        });
+
+        let ranges = [
+            ("imu.acc_range", imu.acc_range),
+            ("imu.gyr_range", imu.gyr_range),
+            ("imu.mag_range", imu.mag_range),
+        ];
+
+        for (key, range) in ranges {
+            self.check(range.is_finite() && range >= 0.0, key, || {
+                format!("must be >= 0 (0 disables clipping), got {range}")
+            });
+        }
+
+        let threshold = imu.clipping_threshold;
+
+        self.check(
+            threshold > 0.0 && threshold <= 1.0,
+            "imu.clipping_threshold",
+            || format!("must be in range (0, 1], got {threshold}"),
+        );
+
+        let pressure = imu.reference_pressure;
+
+        self.check(
+            pressure.is_finite() && pressure > 0.0,
+            "imu.reference_pressure",
+            || format!("must be a positive number, got {pressure}"),
+        );
+
+        let window = imu.replay_window;
+
+        self.check(window <= MAX_REPLAY_WINDOW, "imu.replay_window", || {
+            format!("must be in range [0, {MAX_REPLAY_WINDOW}], got {window}")
+        });
+
+        let gyro_bias = &imu.gyro_bias;
+
+        for (key, value) in [
+            ("imu.gyro_bias.acc_std", gyro_bias.acc_std),
+            ("imu.gyro_bias.gyr_std", gyro_bias.gyr_std),
+            ("imu.gyro_bias.max_bias", gyro_bias.max_bias),
+        ] {
+            self.check(value.is_finite() && value > 0.0, key, || {
+                format!("must be a positive number, got {value}")
+            });
+        }
+
+        let nav = &imu.dead_reckoning;
+
+        for (key, value) in [
+            ("imu.dead_reckoning.acc_threshold", nav.acc_threshold),
+            ("imu.dead_reckoning.gyr_threshold", nav.gyr_threshold),
+            ("imu.dead_reckoning.still_time", nav.still_time),
+        ] {
+            self.check(value.is_finite() && value > 0.0, key, || {
+                format!("must be a positive number, got {value}")
+            });
+        }
+
+        let vertical = &imu.vertical;
+
+        for (key, value) in [
+            ("imu.vertical.acc_noise", vertical.acc_noise),
+            ("imu.vertical.baro_noise", vertical.baro_noise),
+        ] {
+            self.check(value.is_finite() && value > 0.0, key, || {
+                format!("must be a positive number, got {value}")
+            });
+        }
+
+        self.detectors(imu);
+        self.calibration(imu);
+        self.units(imu);
+        self.mounting(imu);
+    }
+
+    /// Validate motion, vibration, shock, G-load, stale data and anomaly
+    /// detectors configurations.
+    ///
+    /// # Parameters
+    /// - `imu` - given IMU configurations to validate.
+    fn detectors(&mut self, imu: &ImuConfig) {
+        let motion = &imu.motion;
+
+        for (key, value) in [
+            ("imu.motion.window", motion.window),
+            ("imu.motion.hold_time", motion.hold_time),
+            ("imu.motion.static_gyr", motion.static_gyr),
+            ("imu.motion.static_acc", motion.static_acc),
+            ("imu.motion.slow_gyr", motion.slow_gyr),
+            ("imu.motion.slow_acc", motion.slow_acc),
+            ("imu.motion.freefall_acc", motion.freefall_acc),
+        ] {
+            self.check(value.is_finite() && value > 0.0, key, || {
+                format!("must be a positive number, got {value}")
+            });
+        }
+
+        self.check(
+            motion.static_gyr <= motion.slow_gyr
+                && motion.static_acc <= motion.slow_acc,
+            "imu.motion",
+            || "static thresholds must not exceed slow motion ones".to_string(),
+        );
+
+        let window = imu.vibration.window;
+
+        self.check(
+            window.is_finite() && window > 0.0,
+            "imu.vibration.window",
+            || format!("must be a positive number, got {window}"),
+        );
+
+        let shock = &imu.shock;
+
+        self.check(
+            shock.threshold.is_finite() && shock.threshold > 1.0,
+            "imu.shock.threshold",
+            || format!("must be greater than 1 g, got {}", shock.threshold),
+        );
+        self.check(
+            shock.hold_off.is_finite() && shock.hold_off >= 0.0,
+            "imu.shock.hold_off",
+            || format!("must be a non-negative number, got {}", shock.hold_off),
+        );
+
+        let g_load = &imu.g_load;
+
+        self.check(
+            g_load.warning.is_finite() && g_load.warning > 0.0,
+            "imu.g_load.warning",
+            || format!("must be a positive number, got {}", g_load.warning),
+        );
+        self.check(
+            g_load.limit.is_finite() && g_load.limit >= g_load.warning,
+            "imu.g_load.limit",
+            || {
+                format!(
+                    "must not be less than warning {}, got {}",
+                    g_load.warning, g_load.limit
+                )
+            },
+        );
+
+        let frames = imu.stale_data.frames;
+
+        self.check(frames >= 2, "imu.stale_data.frames", || {
+            format!("must be >= 2, got {frames}")
+        });
+
+        let anomaly = &imu.anomaly;
+
+        self.check(anomaly.window >= 2, "imu.anomaly.window", || {
+            format!("must be >= 2, got {}", anomaly.window)
+        });
+        self.check(
+            anomaly.threshold.is_finite() && anomaly.threshold > 0.0,
+            "imu.anomaly.threshold",
+            || format!("must be a positive number, got {}", anomaly.threshold),
+        );
+        self.check(
+            anomaly.shift_samples >= 2,
+            "imu.anomaly.shift_samples",
+            || format!("must be >= 2, got {}", anomaly.shift_samples),
+        );
+    }
+
+    /// Validate accelerometer and magnetometer calibration configurations.
+    ///
+    /// # Parameters
+    /// - `imu` - given IMU configurations to validate.
+    fn calibration(&mut self, imu: &ImuConfig) {
+        let calibration = &imu.acc_calibration;
+
+        self.check(
+            calibration.offset.iter().all(|v| v.is_finite()),
+            "imu.acc_calibration.offset",
+            || format!("must be finite, got {:?}", calibration.offset),
+        );
+        self.check(
+            calibration.scale.iter().all(|&v| v.is_finite() && v > 0.0),
+            "imu.acc_calibration.scale",
+            || format!("must be positive, got {:?}", calibration.scale),
+        );
+
+        let calibration = &imu.mag_calibration;
+
+        self.check(
+            calibration.offset.iter().all(|v| v.is_finite()),
+            "imu.mag_calibration.offset",
+            || format!("must be finite, got {:?}", calibration.offset),
+        );
+        self.check(
+            calibration.matrix.iter().flatten().all(|v| v.is_finite()),
+            "imu.mag_calibration.matrix",
+            || format!("must be finite, got {:?}", calibration.matrix),
+        );
+    }
+
+    /// Validate sensor units configurations.
+    ///
+    /// # Parameters
+    /// - `imu` - given IMU configurations to validate.
+    fn units(&mut self, imu: &ImuConfig) {
+        let units = &imu.units;
+        let scales = [
+            ("imu.units.acc_scale", units.acc_scale),
+            ("imu.units.gyr_scale", units.gyr_scale),
+            ("imu.units.mag_scale", units.mag_scale),
+        ];
+
+        for (key, scale) in scales {
+            self.check(
+                scale.iter().all(|&v| v.is_finite() && v != 0.0),
+                key,
+                || format!("must be finite and non-zero, got {scale:?}"),
+            );
+        }
+    }
+
+    /// Validate sensor mounting configurations.
+    ///
+    /// # Parameters
+    /// - `imu` - given IMU configurations to validate.
+    fn mounting(&mut self, imu: &ImuConfig) {
+        let mounting = &imu.mounting;
+
+        self.check(mounting.is_permutation(), "imu.mounting.axes", || {
+            format!(
+                "must map each of sensor axes x, y, z once, got {:?}",
+                mounting.axes
+            )
+        });
+
+        if let Some(q) = mounting.quaternion {
+            let norm = q.iter().map(|v| v * v).sum::<f32>().sqrt();
+
+            self.check(
+                norm.is_finite() && norm > f32::EPSILON,
+                "imu.mounting.quaternion",
+                || format!("must be a non-zero quaternion, got {q:?}"),
+            );
+        }
+    }
+
+    /// Validate networks configurations.
+    ///
+    /// # Parameters
+    /// - `net` - given networks configurations to validate.
+    fn net(&mut self, net: &NetConfig) {
+        self.check(
+            net.transport != Transport::Udp || net.udp_port != 0,
+            "net.udp_port",
+            || "must be in range [1, 65535] for \"udp\" transport".to_string(),
+        );
+
+        self.check(
+            net.simulator_udp_port != 0,
+            "net.simulator_udp_port",
+            || "must be in range [1, 65535]".to_string(),
+        );
+
+        let is_same_host = net.ip_address == net.simulator_ip_address;
+
+        self.check(
+            !is_same_host || net.udp_port != net.simulator_udp_port,
+            "net.simulator_udp_port",
+            || format!("conflicts with net.udp_port ({})", net.udp_port),
+        );
+
+        self.check(
+            net.simulator_control_port != net.simulator_udp_port,
+            "net.simulator_control_port",
+            || {
+                format!(
+                    "conflicts with net.simulator_udp_port ({})",
+                    net.simulator_udp_port
+                )
+            },
+        );
+
+        match net.transport {
+            Transport::Unix => self.check(
+                !net.unix_socket_path.is_empty(),
+                "net.unix_socket_path",
+                || "must be set for \"unix\" transport".to_string(),
+            ),
+            Transport::Can => {
+                self.check(
+                    !net.can_interface.is_empty(),
+                    "net.can_interface",
+                    || "must be set for \"can\" transport".to_string(),
+                );
+                self.check(net.can_id <= MAX_CAN_ID, "net.can_id", || {
+                    format!(
+                        "must be in range [0, {MAX_CAN_ID:#X}], got {:#X}",
+                        net.can_id
+                    )
+                });
+            }
+            Transport::Udp | Transport::Stdin | Transport::Generator => {}
+        }
+
+        self.check(net.dscp <= MAX_DSCP, "net.dscp", || {
+            format!("must be in range [0, {MAX_DSCP}], got {}", net.dscp)
+        });
+
+        self.check(
+            net.busy_poll_us == 0 || cfg!(target_os = "linux"),
+            "net.busy_poll_us",
+            || "busy polling is supported on Linux only".to_string(),
+        );
+
+        for (i, endpoint) in net.forward_endpoints.iter().enumerate() {
+            self.check(
+                is_host_port(endpoint),
+                format!("net.forward_endpoints[{i}]"),
+                || format!("\"{endpoint}\" must be in \"host:port\" format"),
+            );
+        }
+
+        if net.keepalive_interval_ms > 0 {
+            self.keepalive(net);
+        }
+
+        if let Some(secondary) = &net.secondary {
+            self.secondary(net, secondary);
+        }
+    }
+
+    /// Validate keepalive (client-initiated mode) configurations.
+    ///
+    /// # Parameters
+    /// - `net` - given networks configurations to validate.
+    fn keepalive(&mut self, net: &NetConfig) {
+        self.check(
+            net.transport == Transport::Udp,
+            "net.keepalive_interval_ms",
+            || "requires \"udp\" transport".to_string(),
+        );
+
+        self.check(
+            is_host_port(&net.device_address),
+            "net.device_address",
+            || {
+                format!(
+                    "\"{}\" must be in \"host:port\" format when keepalive is \
+                 enabled",
+                    net.device_address
+                )
+            },
+        );
+    }
+
+    /// Validate secondary link configurations.
+    ///
+    /// # Parameters
+    /// - `net` - given networks configurations to validate.
+    /// - `link` - given secondary link configurations to validate.
+    fn secondary(&mut self, net: &NetConfig, link: &LinkConfig) {
+        match link.transport {
+            Transport::Udp => {
+                self.check(
+                    link.udp_port != 0,
+                    "net.secondary.udp_port",
+                    || {
+                        "must be in range [1, 65535] for \"udp\" transport"
+                            .to_string()
+                    },
+                );
+
+                let is_same_socket = net.transport == Transport::Udp
+                    && net.ip_address == link.ip_address
+                    && net.udp_port == link.udp_port;
+
+                self.check(!is_same_socket, "net.secondary.udp_port", || {
+                    format!("conflicts with net.udp_port ({})", net.udp_port)
+                });
+            }
+            Transport::Unix => {
+                self.check(
+                    !link.unix_socket_path.is_empty(),
+                    "net.secondary.unix_socket_path",
+                    || "must be set for \"unix\" transport".to_string(),
+                );
+
+                let is_same_socket = net.transport == Transport::Unix
+                    && net.unix_socket_path == link.unix_socket_path;
+
+                self.check(
+                    !is_same_socket,
+                    "net.secondary.unix_socket_path",
+                    || "conflicts with net.unix_socket_path".to_string(),
+                );
+            }
+            Transport::Can => {
+                self.check(
+                    !link.can_interface.is_empty(),
+                    "net.secondary.can_interface",
+                    || "must be set for \"can\" transport".to_string(),
+                );
+                self.check(
+                    link.can_id <= MAX_CAN_ID,
+                    "net.secondary.can_id",
+                    || {
+                        format!(
+                            "must be in range [0, {MAX_CAN_ID:#X}], got {:#X}",
+                            link.can_id
+                        )
+                    },
+                );
+            }
+            // Standard input is owned by the primary link reader.
+            Transport::Stdin => self.check(
+                net.transport != Transport::Stdin,
+                "net.secondary.transport",
+                || "conflicts with net.transport (\"stdin\")".to_string(),
+            ),
+            // Both generators would produce the same sequence numbers.
+            Transport::Generator => self.check(
+                net.transport != Transport::Generator,
+                "net.secondary.transport",
+                || "conflicts with net.transport (\"generator\")".to_string(),
+            ),
+        }
+
+        self.check(
+            link.failover_timeout_ms != 0,
+            "net.secondary.failover_timeout_ms",
+            || "must be greater than 0".to_string(),
+        );
+    }
+
+    /// Validate keyboard shortcuts configurations.
+    ///
+    /// # Parameters
+    /// - `shortcuts` - given keyboard shortcuts configurations to validate.
+    fn shortcuts(&mut self, shortcuts: &ShortcutsConfig) {
+        let mut bound: Vec<(ShortcutAction, KeyboardShortcut)> = Vec::new();
+
+        for action in ShortcutAction::ALL {
+            let key = format!("shortcuts.{}", action.key());
+
+            match parse_shortcut(shortcuts.binding(action)) {
+                Ok(Some(shortcut)) => {
+                    let other = bound
+                        .iter()
+                        .find(|(_, bound)| *bound == shortcut)
+                        .map(|(other, _)| other.key());
+
+                    self.check(other.is_none(), key, || {
+                        format!(
+                            "conflicts with shortcuts.{}",
+                            other.unwrap_or_default()
+                        )
+                    });
+                    bound.push((action, shortcut));
+                }
+                Ok(None) => {}
+                Err(e) => self.check(false, key, || e),
+            }
+        }
+    }
+
+    /// Validate display configurations.
+    ///
+    /// # Parameters
+    /// - `display` - given display configurations to validate.
+    fn display(&mut self, display: &DisplayConfig) {
+        let formats = &display.format;
+
+        for (name, format) in [
+            ("acc", formats.acc),
+            ("gyr", formats.gyr),
+            ("mag", formats.mag),
+            ("baro", formats.baro),
+            ("quat", formats.quat),
+            ("euler", formats.euler),
+        ] {
+            let precision = format.precision;
+            let dead_band = format.dead_band;
+
+            self.check(
+                precision <= MAX_PRECISION,
+                format!("display.format.{name}.precision"),
+                || {
+                    format!(
+                        "must be in range [0, {MAX_PRECISION}], got {precision}"
+                    )
+                },
+            );
+            self.check(
+                dead_band.is_finite() && dead_band >= 0.0,
+                format!("display.format.{name}.dead_band"),
+                || format!("must be a non-negative number, got {dead_band}"),
+            );
+        }
+
+        let model = &display.model;
+
+        if !model.path.is_empty() {
+            let path = Path::new(&model.path);
+            let is_supported = path.extension().is_some_and(|ext| {
+                ext.eq_ignore_ascii_case("stl")
+                    || ext.eq_ignore_ascii_case("obj")
+            });
+
+            self.check(is_supported, "display.model.path", || {
+                format!("must be .stl or .obj file, got {}", model.path)
+            });
+        }
+
+        for (axis, angle) in
+            ["roll", "pitch", "yaw"].iter().zip(model.orientation)
+        {
+            self.check(
+                angle.is_finite(),
+                format!("display.model.orientation.{axis}"),
+                || format!("must be a finite number, got {angle}"),
+            );
+        }
+
+        let declination = display.compass.declination;
+
+        self.check(
+            declination.is_finite() && declination.abs() <= 180.0,
+            "display.compass.declination",
+            || format!("must be in range [-180, 180], got {declination}"),
+        );
+
+        let min_spec = &display.min_spec;
+
+        for (key, value) in [
+            ("display.min_spec.fps_threshold", min_spec.fps_threshold),
+            ("display.min_spec.duration", min_spec.duration),
+            ("display.min_spec.fps_cap", min_spec.fps_cap),
+        ] {
+            self.check(value.is_finite() && value > 0.0, key, || {
+                format!("must be a positive number, got {value}")
+            });
+        }
+
+        let history = display.plot.history;
+
+        self.check(
+            (2..=MAX_PLOT_HISTORY).contains(&history),
+            "display.plot.history",
+            || {
+                format!(
+                    "must be in range [2, {MAX_PLOT_HISTORY}], got {history}"
+                )
+            },
+        );
+        self.check(
+            display.plot.renderer == PlotRenderer::Cpu
+                || cfg!(feature = "gpu-plots"),
+            "display.plot.renderer",
+            || {
+                "\"gpu\" requires building with the `gpu-plots` feature"
+                    .to_string()
+            },
+        );
+
+        for (i, preset) in display.layouts.iter().enumerate() {
+            self.layout(i, preset);
+        }
+
+        self.check(
+            display.startup_layout().is_some(),
+            "display.layout",
+            || format!("\"{}\" is not a layout preset name", display.layout),
+        );
+    }
+
+    /// Validate user-defined layout preset.
+    ///
+    /// # Parameters
+    /// - `i` - given layout preset index.
+    /// - `preset` - given layout preset to validate.
+    fn layout(&mut self, i: usize, preset: &LayoutPreset) {
+        self.check(
+            !preset.name.is_empty(),
+            format!("display.layouts[{i}].name"),
+            || "must not be empty".to_string(),
+        );
+
+        self.check(
+            !preset.tabs.is_empty(),
+            format!("display.layouts[{i}].tabs"),
+            || "must contain at least one tab".to_string(),
+        );
+
+        for (j, tile) in preset.instruments.iter().enumerate() {
+            let key = format!("display.layouts[{i}].instruments[{j}]");
+
+            self.check(
+                (1..=GRID_COLUMNS).contains(&tile.columns),
+                format!("{key}.columns"),
+                || {
+                    format!(
+                        "must be in range [1, {GRID_COLUMNS}], got {}",
+                        tile.columns
+                    )
+                },
+            );
+            self.check(
+                (1..=MAX_TILE_ROWS).contains(&tile.rows),
+                format!("{key}.rows"),
+                || {
+                    format!(
+                        "must be in range [1, {MAX_TILE_ROWS}], got {}",
+                        tile.rows
+                    )
+                },
+            );
+
+            let is_repeated = preset
+                .instruments
+                .iter()
+                .take(j)
+                .any(|other| other.instrument == tile.instrument);
+
+            self.check(!is_repeated, format!("{key}.instrument"), || {
+                "must not repeat instrument of another tile".to_string()
+            });
+        }
+    }
+
+    /// Validate debug configurations.
+    ///
+    /// # Parameters
+    /// - `debug` - given debug configurations to validate.
+    fn debug(&mut self, debug: &DebugConfig) {
+        for (key, rate) in [
+            ("debug.drop_rate", debug.drop_rate),
+            ("debug.delay_rate", debug.delay_rate),
+        ] {
+            self.check((0.0..=1.0).contains(&rate), key, || {
+                format!("must be in range [0, 1], got {rate}")
+            });
+        }
+
+        self.check(
+            debug.delay_rate <= 0.0 || debug.delay_ms != 0,
+            "debug.delay_ms",
+            || "must be greater than 0 if debug.delay_rate is set".to_string(),
+        );
+
+        for (i, signal) in debug.generator.iter().enumerate() {
+            self.signal(i, signal);
+        }
+    }
+
+    /// Validate test-signal generator signal.
+    ///
+    /// # Parameters
+    /// - `i` - given signal index.
+    /// - `signal` - given signal configurations to validate.
+    fn signal(&mut self, i: usize, signal: &SignalConfig) {
+        let key = |name: &str| format!("debug.generator[{i}].{name}");
+
+        let (amplitude, frequencies, durations) = match signal.waveform {
+            Waveform::Sine {
+                amplitude,
+                frequency,
+            } => (amplitude, vec![("frequency", frequency)], vec![]),
+            Waveform::Step { amplitude, period } => {
+                (amplitude, vec![], vec![("period", period)])
+            }
+            Waveform::Chirp {
+                amplitude,
+                start_frequency,
+                end_frequency,
+                duration,
+            } => (
+                amplitude,
+                vec![
+                    ("start_frequency", start_frequency),
+                    ("end_frequency", end_frequency),
+                ],
+                vec![("duration", duration)],
+            ),
+        };
+
+        for (name, value) in
+            [("offset", signal.offset), ("amplitude", amplitude)]
+        {
+            self.check(value.is_finite(), key(name), || {
+                format!("must be a finite number, got {value}")
+            });
+        }
+
+        for (name, frequency) in frequencies {
+            self.check(
+                frequency.is_finite() && frequency >= 0.0,
+                key(name),
+                || format!("must be a non-negative number, got {frequency}"),
+            );
+        }
+
+        for (name, duration) in durations {
+            self.check(
+                duration.is_finite() && duration > 0.0,
+                key(name),
+                || format!("must be greater than 0, got {duration}"),
+            );
+        }
+    }
+
+    /// Validate alerting rule.
+    ///
+    /// # Parameters
+    /// - `i` - given alerting rule index.
+    /// - `rule` - given alerting rule to validate.
+    fn alert(&mut self, i: usize, rule: &AlertRule) {
+        self.check(!rule.name.is_empty(), format!("alerts[{i}].name"), || {
+            "must not be empty".to_string()
+        });
+
+        let threshold = rule.threshold;
+
+        self.check(
+            threshold.is_finite(),
+            format!("alerts[{i}].threshold"),
+            || format!("must be a finite number, got {threshold}"),
+        );
+
+        for (j, action) in rule.actions.iter().enumerate() {
+            if let AlertAction::Webhook { url } = action {
+                let host = url.strip_prefix("http://").unwrap_or_default();
+
+                self.check(
+                    !host.is_empty() && !host.starts_with('/'),
+                    format!("alerts[{i}].actions[{j}].url"),
+                    || {
+                        format!(
+                            "must be a plain http:// URL (https:// is not \
+                             supported), got '{url}'"
+                        )
+                    },
+                );
+            }
+        }
+    }
+}
+
+impl RuleSet {
+    /// Validate rule set values.
+    ///
+    /// # Returns
+    /// - `Ok` - if all rules are valid.
+    /// - `Err` - with all invalid values otherwise.
+    ///
+    /// # Errors
+    /// - At least one rule is invalid.
+    pub fn validate(&self) -> Result<(), ConfigErrors> {
+        let mut validator = Validator::default();
+
+        for (i, rule) in self.alerts.iter().enumerate() {
+            validator.alert(i, rule);
+        }
+
+        if validator.issues.is_empty() {
+            Ok(())
+        } else {
+            Err(ConfigErrors {
+                issues: validator.issues,
+            })
+        }
+    }
+}
+
+impl AppConfig {
+    /// Validate configuration values.
+    ///
+    /// # Parameters
+    /// - `custom_payload_types` - given payload types with custom decoders.
+    ///
+    /// # Returns
+    /// - `Ok` - if all values are valid.
+    /// - `Err` - with all invalid values otherwise.
+    ///
+    /// # Errors
+    /// - At least one configuration value is invalid.
+    pub fn validate(
+        &self,
+        custom_payload_types: &[u8],
+    ) -> Result<(), ConfigErrors> {
+        let mut validator = Validator::default();
+
+        validator.imu(&self.imu, custom_payload_types);
+        validator.net(&self.net);
+
+        // Frames received over both links are deduplicated by replay guard.
+        validator.check(
+            self.net.secondary.is_none() || self.imu.replay_window != 0,
+            "imu.replay_window",
+            || "must be greater than 0 if net.secondary is set".to_string(),
+        );
+
+        // Generated payloads are built from the standard readings.
+        validator.check(
+            self.net.transport != Transport::Generator
+                || PayloadType::from(self.imu.payload_type).is_standard(),
+            "imu.payload_type",
+            || "must be standard for \"generator\" transport".to_string(),
+        );
+
+        validator.check(
+            !self.log.directory.is_empty(),
+            "log.directory",
+            || "must not be empty".to_string(),
+        );
+
+        validator.check(
+            self.log.retention.min_free_mb == 0 || cfg!(target_os = "linux"),
+            "log.retention.min_free_mb",
+            || "free disk space is checked on Linux only".to_string(),
+        );
+
+        for (i, rule) in self.alerts.iter().enumerate() {
+            validator.alert(i, rule);
+        }
+
+        validator.display(&self.display);
+        validator.shortcuts(&self.shortcuts);
+        validator.debug(&self.debug);
+
+        if validator.issues.is_empty() {
+            Ok(())
+        } else {
+            Err(ConfigErrors {
+                issues: validator.issues,
+            })
+        }
+    }
+}
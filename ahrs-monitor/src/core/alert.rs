@@ -0,0 +1,431 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Alerting rules evaluation and alert actions.
+
+use crate::{
+    config::{AlertAction, AlertCondition, AlertMetric, AlertRule},
+    core::StandardPayload,
+    model::FrameContext,
+};
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, VecDeque},
+    time::Duration,
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+    process::Command,
+};
+
+/// Max time to wait for the webhook response.
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Number of the latest valid frames the packet loss is computed over.
+const LOSS_WINDOW_SIZE: usize = 1000;
+
+/// System sound played when no sound file is specified.
+#[cfg(target_os = "windows")]
+const DEFAULT_SOUND_FILE: &str = r"C:\Windows\Media\Windows Exclamation.wav";
+
+/// System sound played when no sound file is specified.
+#[cfg(target_os = "macos")]
+const DEFAULT_SOUND_FILE: &str = "/System/Library/Sounds/Ping.aiff";
+
+/// System sound played when no sound file is specified.
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+const DEFAULT_SOUND_FILE: &str =
+    "/usr/share/sounds/freedesktop/stereo/bell.oga";
+
+/// Triggered alert info.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Alert {
+    /// Name of the triggered rule.
+    pub rule: String,
+    /// Monitored metric.
+    pub metric: AlertMetric,
+    /// Metric value that triggered the rule.
+    pub value: f32,
+    /// Rule threshold value.
+    pub threshold: f32,
+    /// Sensor-local time in microseconds.
+    pub timestamp: u32,
+    /// Host local time in string representation.
+    pub time: String,
+    /// Flag whether to show a modal window in the user interface.
+    #[serde(skip)]
+    pub show_modal: bool,
+    /// Flag whether metric returned within the threshold (rule is no longer
+    /// breached).
+    #[serde(default)]
+    pub is_cleared: bool,
+}
+
+/// Alerting rules evaluator.
+#[derive(Debug, Default)]
+pub struct AlertEngine {
+    /// Alerting rules.
+    rules: Vec<AlertRule>,
+    /// Flags that show whether each rule is currently active.
+    active: Vec<bool>,
+    /// Packet loss over the latest valid frames.
+    loss: LossWindow,
+}
+
+/// Packet loss tracker over the latest valid frames.
+#[derive(Debug, Default)]
+struct LossWindow {
+    /// Number of packets lost before each of the latest valid frames.
+    gaps: VecDeque<u16>,
+    /// Total number of lost packets in the window.
+    lost: usize,
+    /// Sequence number of the last valid frame by device identifier.
+    last_sequences: HashMap<u8, u16>,
+}
+
+impl LossWindow {
+    /// Count packets lost before the valid frame.
+    ///
+    /// # Parameters
+    /// - `device_id` - given identifier of the device that sent the frame.
+    /// - `sequence` - given sequence number of the valid frame.
+    fn update(&mut self, device_id: u8, sequence: u16) {
+        let last = self.last_sequences.insert(device_id, sequence);
+        let gap = last.map_or(0, |prev| {
+            let gap = sequence.wrapping_sub(prev).wrapping_sub(1);
+
+            // Huge gaps are caused by reordering or IMU restart, not loss.
+            if gap < u16::MAX / 2 { gap } else { 0 }
+        });
+
+        self.gaps.push_back(gap);
+        self.lost += usize::from(gap);
+
+        if self.gaps.len() > LOSS_WINDOW_SIZE
+            && let Some(gap) = self.gaps.pop_front()
+        {
+            self.lost -= usize::from(gap);
+        }
+    }
+
+    /// Forget sequence number of the device, so that its next frame does not
+    /// count packets lost while it was restarted.
+    ///
+    /// # Parameters
+    /// - `device_id` - given device identifier.
+    fn forget(&mut self, device_id: u8) {
+        self.last_sequences.remove(&device_id);
+    }
+
+    /// Get percentage of lost packets.
+    ///
+    /// # Returns
+    /// - Lost packets to expected ones in percent (0.0 - 100.0).
+    #[allow(clippy::cast_precision_loss)]
+    fn percent(&self) -> f32 {
+        let expected = self.gaps.len() + self.lost;
+
+        if expected == 0 {
+            0.0
+        } else {
+            self.lost as f32 / expected as f32 * 100.0
+        }
+    }
+}
+
+impl AlertEngine {
+    /// Construct new `AlertEngine` object.
+    ///
+    /// # Parameters
+    /// - `rules` - given alerting rules to evaluate.
+    ///
+    /// # Returns
+    /// - New `AlertEngine` object.
+    #[must_use]
+    pub fn new(rules: &[AlertRule]) -> Self {
+        Self {
+            rules: rules.to_vec(),
+            active: vec![false; rules.len()],
+            loss: LossWindow::default(),
+        }
+    }
+
+    /// Reset packet loss tracking after connection loss.
+    pub fn reset_loss(&mut self) {
+        self.loss = LossWindow::default();
+    }
+
+    /// Reset packet loss tracking of the rebooted device.
+    ///
+    /// # Parameters
+    /// - `device_id` - given identifier of the rebooted device.
+    pub fn reset_device(&mut self, device_id: u8) {
+        self.loss.forget(device_id);
+    }
+
+    /// Evaluate alerting rules and perform actions of triggered ones.
+    ///
+    /// # Parameters
+    /// - `frame_ctx` - given current frame context to handle.
+    ///
+    /// # Returns
+    /// - List of newly triggered and cleared alerts.
+    pub fn evaluate(&mut self, frame_ctx: &FrameContext) -> Vec<Alert> {
+        let mut alerts = Vec::new();
+
+        if let Some(frame) = &frame_ctx.frame
+            && frame_ctx.is_valid
+        {
+            self.loss
+                .update(frame.header.device_id, frame.header.sequence.get());
+        }
+
+        let loss = self.loss.percent();
+
+        for (rule, active) in self.rules.iter().zip(self.active.iter_mut()) {
+            let Some(value) = metric_value(rule.metric, frame_ctx, loss) else {
+                continue;
+            };
+
+            let is_breached = match rule.condition {
+                AlertCondition::Above => value > rule.threshold,
+                AlertCondition::Below => value < rule.threshold,
+            };
+
+            // Triggering rule only once per threshold breach.
+            if is_breached && !*active {
+                let alert = Alert {
+                    rule: rule.name.clone(),
+                    metric: rule.metric,
+                    value,
+                    threshold: rule.threshold,
+                    timestamp: frame_ctx.timestamp,
+                    time: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+                    show_modal: rule.actions.contains(&AlertAction::Modal),
+                    is_cleared: false,
+                };
+
+                log::warn!(
+                    "Alert '{}': {:?} = {} (threshold {})",
+                    alert.rule,
+                    alert.metric,
+                    alert.value,
+                    alert.threshold
+                );
+
+                for action in &rule.actions {
+                    perform_action(&alert, action);
+                }
+
+                alerts.push(alert);
+            } else if !is_breached && *active {
+                log::info!(
+                    "Alert '{}' cleared: {:?} = {value}",
+                    rule.name,
+                    rule.metric
+                );
+
+                alerts.push(Alert {
+                    rule: rule.name.clone(),
+                    metric: rule.metric,
+                    value,
+                    threshold: rule.threshold,
+                    timestamp: frame_ctx.timestamp,
+                    time: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+                    show_modal: false,
+                    is_cleared: true,
+                });
+            }
+
+            *active = is_breached;
+        }
+
+        alerts
+    }
+}
+
+/// Get metric value from frame context.
+///
+/// # Parameters
+/// - `metric` - given metric to get.
+/// - `frame_ctx` - given current frame context to handle.
+/// - `loss` - given packet loss over the latest valid frames in percent.
+///
+/// # Returns
+/// - Metric value - in case of success.
+/// - `None` - if frame context does not contain metric.
+#[allow(clippy::cast_precision_loss)]
+fn metric_value(
+    metric: AlertMetric,
+    frame_ctx: &FrameContext,
+    loss: f32,
+) -> Option<f32> {
+    let payload = frame_ctx.frame.as_ref().and_then(|f| f.payload.as_ref());
+
+    match metric {
+        AlertMetric::AccNorm => {
+            payload.and_then(StandardPayload::acc).map(|v| v.norm())
+        }
+        AlertMetric::GyrNorm => {
+            payload.and_then(StandardPayload::gyr).map(|v| v.norm())
+        }
+        AlertMetric::MagNorm => {
+            payload.and_then(StandardPayload::mag).map(|v| v.norm())
+        }
+        AlertMetric::Pps => Some(frame_ctx.pps as f32),
+        AlertMetric::BadPackets => Some(frame_ctx.bad_packets.total() as f32),
+        AlertMetric::CrcErrors => Some(frame_ctx.bad_packets.integrity as f32),
+        AlertMetric::PacketLoss => Some(loss),
+        AlertMetric::RepeatedFrames => Some(frame_ctx.repeated_frames as f32),
+    }
+}
+
+/// Perform alert action in the background.
+///
+/// # Parameters
+/// - `alert` - given triggered alert to handle.
+/// - `action` - given action to perform.
+fn perform_action(alert: &Alert, action: &AlertAction) {
+    match action {
+        AlertAction::Sound { file } => play_sound(file.as_deref()),
+        // Modal window is shown by the user interface.
+        AlertAction::Modal => {}
+        AlertAction::Webhook { url } => {
+            let url = url.clone();
+            let body = serde_json::to_string(alert).unwrap_or_default();
+
+            tokio::spawn(async move {
+                let result = tokio::time::timeout(
+                    WEBHOOK_TIMEOUT,
+                    post_json(&url, &body),
+                )
+                .await;
+
+                match result {
+                    Ok(Ok(())) => log::info!("Alert webhook called: {url}"),
+                    Ok(Err(e)) => log::error!("Alert webhook failed: {e}"),
+                    Err(_) => log::error!("Alert webhook timed out: {url}"),
+                }
+            });
+        }
+        AlertAction::Command { command, args } => {
+            let mut cmd = Command::new(command);
+
+            cmd.args(args)
+                .env("AHRS_ALERT_RULE", &alert.rule)
+                .env("AHRS_ALERT_VALUE", alert.value.to_string())
+                .env("AHRS_ALERT_THRESHOLD", alert.threshold.to_string())
+                .env("AHRS_ALERT_TIMESTAMP", alert.timestamp.to_string());
+
+            run_command(cmd, command.clone());
+        }
+    }
+}
+
+/// Play alert sound in the background.
+///
+/// # Parameters
+/// - `file` - given path to the sound file to play (`None` - system sound).
+pub fn play_sound(file: Option<&str>) {
+    let file = file.unwrap_or(DEFAULT_SOUND_FILE);
+
+    let cmd = if cfg!(target_os = "windows") {
+        let mut cmd = Command::new("powershell");
+        // Quote is escaped by doubling inside single-quoted string, so that
+        // path can not terminate it.
+        let path = file.replace('\'', "''");
+        let script =
+            format!("(New-Object Media.SoundPlayer '{path}').PlaySync()");
+        cmd.args(["-NoProfile", "-Command", &script]);
+        cmd
+    } else if cfg!(target_os = "macos") {
+        let mut cmd = Command::new("afplay");
+        cmd.arg(file);
+        cmd
+    } else {
+        let mut cmd = Command::new("paplay");
+        cmd.arg(file);
+        cmd
+    };
+
+    run_command(cmd, format!("play {file}"));
+}
+
+/// Run command in the background and log its exit status.
+///
+/// # Parameters
+/// - `cmd` - given command to run.
+/// - `label` - given command label for logging.
+fn run_command(mut cmd: Command, label: String) {
+    tokio::spawn(async move {
+        match cmd.status().await {
+            Ok(status) if status.success() => {
+                log::info!("Alert command finished: {label}");
+            }
+            Ok(status) => {
+                log::error!("Alert command '{label}' failed: {status}");
+            }
+            Err(e) => log::error!("Alert command '{label}' failed: {e}"),
+        }
+    });
+}
+
+/// Send HTTP POST request with JSON body.
+///
+/// # Parameters
+/// - `url` - given plain `http://` URL.
+/// - `body` - given JSON body to send.
+///
+/// # Returns
+/// - `Ok` - in case of success (2xx response status).
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - Unsupported URL scheme.
+/// - I/O errors.
+/// - Non-successful response status.
+async fn post_json(url: &str, body: &str) -> anyhow::Result<()> {
+    let Some(rest) = url.strip_prefix("http://") else {
+        anyhow::bail!("unsupported webhook URL (only http:// is supported)");
+    };
+
+    let (authority, path) =
+        rest.find('/').map_or((rest, "/"), |pos| rest.split_at(pos));
+
+    let addr = if authority.contains(':') {
+        authority.to_string()
+    } else {
+        format!("{authority}:80")
+    };
+
+    let mut stream = TcpStream::connect(addr).await?;
+    let request = format!(
+        "POST {path} HTTP/1.1\r\n\
+         Host: {authority}\r\n\
+         Content-Type: application/json\r\n\
+         Content-Length: {}\r\n\
+         Connection: close\r\n\r\n\
+         {body}",
+        body.len()
+    );
+
+    stream.write_all(request.as_bytes()).await?;
+
+    let mut response = Vec::new();
+    stream.read_to_end(&mut response).await?;
+
+    let response = String::from_utf8_lossy(&response);
+    let status_line = response.lines().next().unwrap_or_default();
+    let is_success = status_line
+        .split_whitespace()
+        .nth(1)
+        .is_some_and(|code| code.starts_with('2'));
+
+    if !is_success {
+        anyhow::bail!("unexpected response: {status_line}");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Barometric altitude.
+//!
+//! Pressure altitude is derived from the International Standard Atmosphere
+//! (ISA) troposphere model. Reference pressure is the sea level pressure
+//! (QNH) for altitude above mean sea level, or the pressure at the take-off
+//! point (QFE) for height above it.
+
+/// ISA sea level standard pressure in Pa.
+pub const STANDARD_PRESSURE: f32 = 101_325.0;
+
+/// ISA sea level standard temperature divided by the temperature lapse rate
+/// in meters.
+const TEMPERATURE_SCALE_HEIGHT: f32 = 44_330.8;
+
+/// Exponent of the ISA troposphere pressure model (`R * L / (g * M)`).
+const PRESSURE_EXPONENT: f32 = 0.190_263;
+
+/// Get pressure altitude.
+///
+/// # Parameters
+/// - `pressure` - given static pressure in Pa.
+/// - `reference_pressure` - given pressure at zero altitude in Pa.
+///
+/// # Returns
+/// - Altitude above the reference pressure level in meters - if pressures
+///   are positive.
+/// - `None` - otherwise.
+#[must_use]
+pub fn pressure_altitude(
+    pressure: f32,
+    reference_pressure: f32,
+) -> Option<f32> {
+    (pressure > 0.0 && reference_pressure > 0.0).then(|| {
+        let ratio = pressure / reference_pressure;
+        TEMPERATURE_SCALE_HEIGHT * (1.0 - ratio.powf(PRESSURE_EXPONENT))
+    })
+}
@@ -0,0 +1,216 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Sensor noise analysis.
+//!
+//! Overlapping Allan deviation of the readings recorded while the device is
+//! still characterizes sensor noise: white noise (angle/velocity random
+//! walk) dominates short averaging times with slope -1/2, while the flat
+//! bottom of the curve gives bias instability.
+
+use crate::core::{
+    channel,
+    compare::{Channel, Session},
+};
+
+/// Inertial channels characterized by noise analysis.
+pub const CHANNELS: [Channel; 6] = [
+    Channel {
+        info: channel::ACC_X,
+        value: |r| r.acc_x,
+    },
+    Channel {
+        info: channel::ACC_Y,
+        value: |r| r.acc_y,
+    },
+    Channel {
+        info: channel::ACC_Z,
+        value: |r| r.acc_z,
+    },
+    Channel {
+        info: channel::GYR_X,
+        value: |r| r.gyr_x,
+    },
+    Channel {
+        info: channel::GYR_Y,
+        value: |r| r.gyr_y,
+    },
+    Channel {
+        info: channel::GYR_Z,
+        value: |r| r.gyr_z,
+    },
+];
+
+/// Max number of averaging times of the Allan deviation curve.
+const MAX_CLUSTER_SIZES: usize = 100;
+
+/// Ratio of the flat bottom of the Allan deviation curve to the bias
+/// instability coefficient (`sqrt(2 * ln(2) / π)`).
+const BIAS_INSTABILITY_FACTOR: f64 = 0.664;
+
+/// Single point of the Allan deviation curve.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct AllanPoint {
+    /// Averaging time in seconds.
+    pub tau: f64,
+    /// Allan deviation in units of the readings.
+    pub deviation: f64,
+}
+
+/// Allan deviation curve of a single channel.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct AllanDeviation {
+    /// Curve points in ascending order of averaging time.
+    pub points: Vec<AllanPoint>,
+}
+
+impl AllanDeviation {
+    /// Compute overlapping Allan deviation of the readings.
+    ///
+    /// # Parameters
+    /// - `samples` - given readings sampled with constant period.
+    /// - `sample_period` - given sample period in seconds.
+    ///
+    /// # Returns
+    /// - Allan deviation at logarithmically spaced averaging times.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn compute(samples: &[f64], sample_period: f64) -> Self {
+        if sample_period <= 0.0 {
+            return Self::default();
+        }
+
+        // Integrated readings, so that cluster averages are differences.
+        let theta: Vec<f64> = std::iter::once(0.0)
+            .chain(samples.iter().scan(0.0, |sum, v| {
+                *sum += v * sample_period;
+                Some(*sum)
+            }))
+            .collect();
+
+        let points = cluster_sizes(samples.len())
+            .into_iter()
+            .filter_map(|m| {
+                let count = theta.len().checked_sub(2 * m)?;
+
+                if count == 0 {
+                    return None;
+                }
+
+                let tau = m as f64 * sample_period;
+                let sum: f64 = theta
+                    .iter()
+                    .zip(theta.iter().skip(m))
+                    .zip(theta.iter().skip(2 * m))
+                    .map(|((a, b), c)| (c - 2.0 * b + a).powi(2))
+                    .sum();
+
+                let variance = sum / (2.0 * tau * tau * count as f64);
+
+                Some(AllanPoint {
+                    tau,
+                    deviation: variance.sqrt(),
+                })
+            })
+            .collect();
+
+        Self { points }
+    }
+
+    /// Compute Allan deviation of the recorded session channel.
+    ///
+    /// # Parameters
+    /// - `session` - given recorded session.
+    /// - `channel` - given channel to analyze.
+    ///
+    /// # Returns
+    /// - Allan deviation curve - if channel has enough readings.
+    /// - `None` - otherwise.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn from_session(session: &Session, channel: &Channel) -> Option<Self> {
+        let samples: Vec<f64> = session
+            .records
+            .iter()
+            .filter_map(|r| (channel.value)(r).map(f64::from))
+            .collect();
+
+        let intervals = samples.len().checked_sub(1).filter(|n| *n > 0)?;
+        let sample_period = session.duration() / intervals as f64;
+
+        Some(Self::compute(&samples, sample_period))
+    }
+
+    /// Get white noise coefficient (angle or velocity random walk).
+    ///
+    /// # Returns
+    /// - Allan deviation at averaging time of 1 second in units of the
+    ///   readings multiplied by `√s` - if curve covers 1 second.
+    /// - `None` - otherwise.
+    #[must_use]
+    pub fn random_walk(&self) -> Option<f64> {
+        self.points.windows(2).find_map(|window| {
+            let [a, b] = window else {
+                return None;
+            };
+
+            if !(a.tau..=b.tau).contains(&1.0) {
+                return None;
+            }
+
+            // Linear interpolation on the log-log scale.
+            let (tau_a, tau_b) = (a.tau.log10(), b.tau.log10());
+            let (dev_a, dev_b) = (a.deviation.log10(), b.deviation.log10());
+            let t = -tau_a / (tau_b - tau_a);
+
+            Some(10.0_f64.powf(dev_a + t * (dev_b - dev_a)))
+        })
+    }
+
+    /// Get bias instability.
+    ///
+    /// # Returns
+    /// - Averaging time of the curve minimum and bias instability in units
+    ///   of the readings - if curve is not empty.
+    /// - `None` - otherwise.
+    #[must_use]
+    pub fn bias_instability(&self) -> Option<AllanPoint> {
+        self.points
+            .iter()
+            .min_by(|a, b| a.deviation.total_cmp(&b.deviation))
+            .map(|min| AllanPoint {
+                tau: min.tau,
+                deviation: min.deviation / BIAS_INSTABILITY_FACTOR,
+            })
+    }
+}
+
+/// Get logarithmically spaced cluster sizes.
+///
+/// # Parameters
+/// - `len` - given number of readings.
+///
+/// # Returns
+/// - Ascending list of distinct cluster sizes in samples.
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+fn cluster_sizes(len: usize) -> Vec<usize> {
+    let max = len / 2;
+
+    if max == 0 {
+        return Vec::new();
+    }
+
+    let log_max = (max as f64).log10();
+    let steps = (MAX_CLUSTER_SIZES - 1) as f64;
+
+    let mut sizes: Vec<usize> = (0..MAX_CLUSTER_SIZES)
+        .map(|i| 10.0_f64.powf(log_max * i as f64 / steps).round() as usize)
+        .collect();
+
+    sizes.dedup();
+    sizes
+}
@@ -0,0 +1,235 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Online anomaly detection on sensor readings channels.
+//!
+//! Each channel tracks exponentially weighted moving average (EWMA) and
+//! variance of its readings. Reading outside of the band of the configured
+//! number of standard deviations is a spike. If readings stay outside of the
+//! band for a while, the level has shifted and the band is moved to the new
+//! level.
+
+use crate::{config::AnomalyConfig, core::StandardPayload};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Keys of the monitored channels.
+pub const CHANNEL_KEYS: [&str; 9] = [
+    "acc_x", "acc_y", "acc_z", "gyr_x", "gyr_y", "gyr_z", "mag_x", "mag_y",
+    "mag_z",
+];
+
+/// Min standard deviation of the band, so that noise-free (e.g. simulated)
+/// readings do not flag every change.
+const MIN_STD_DEV: f32 = 1e-3;
+
+/// Kind of the detected anomaly.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum AnomalyKind {
+    /// Reading outside of the band of the recent readings.
+    Spike,
+    /// Readings outside of the band for a while.
+    LevelShift,
+}
+
+impl AnomalyKind {
+    /// Get anomaly kind name.
+    ///
+    /// # Returns
+    /// - Anomaly kind name string slice.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Spike => "spike",
+            Self::LevelShift => "level shift",
+        }
+    }
+}
+
+/// Anomaly detected on a single channel.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct Anomaly {
+    /// Index of the channel in [`CHANNEL_KEYS`].
+    pub channel: usize,
+    /// Kind of the anomaly.
+    pub kind: AnomalyKind,
+    /// Anomalous reading.
+    pub value: f32,
+    /// Number of standard deviations from the recent readings average.
+    pub z_score: f32,
+}
+
+impl Anomaly {
+    /// Get key of the anomalous channel.
+    ///
+    /// # Returns
+    /// - Channel key string slice (e.g. `acc_x`).
+    #[must_use]
+    pub fn key(&self) -> &'static str {
+        CHANNEL_KEYS.get(self.channel).copied().unwrap_or_default()
+    }
+}
+
+impl fmt::Display for Anomaly {
+    /// Format anomaly as log annotation.
+    ///
+    /// # Parameters
+    /// - `f` - given formatter to write into.
+    ///
+    /// # Returns
+    /// - Formatting result.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} ({:.1}σ)",
+            self.key(),
+            self.kind.name(),
+            self.z_score
+        )
+    }
+}
+
+/// Exponentially weighted band of a single channel.
+#[derive(Debug, Default, Clone, Copy)]
+struct ChannelBand {
+    /// Number of readings in the band.
+    count: u32,
+    /// Moving average of the readings.
+    mean: f32,
+    /// Moving variance of the readings.
+    variance: f32,
+    /// Number of consecutive readings outside of the band.
+    outside: u32,
+}
+
+impl ChannelBand {
+    /// Update band with new reading.
+    ///
+    /// # Parameters
+    /// - `value` - given reading to handle.
+    /// - `config` - given anomaly detection configurations.
+    ///
+    /// # Returns
+    /// - Kind of the anomaly and z-score - if reading is anomalous.
+    /// - `None` - otherwise.
+    #[allow(clippy::cast_precision_loss)]
+    fn update(
+        &mut self,
+        value: f32,
+        config: &AnomalyConfig,
+    ) -> Option<(AnomalyKind, f32)> {
+        let alpha = 2.0 / (config.window as f32 + 1.0);
+
+        // Band is not reliable until it covers the window.
+        if self.count < config.window {
+            self.count += 1;
+            self.add(value, alpha.max(1.0 / self.count as f32));
+            return None;
+        }
+
+        let z_score =
+            (value - self.mean) / self.variance.sqrt().max(MIN_STD_DEV);
+
+        if z_score.abs() <= config.threshold {
+            self.outside = 0;
+            self.add(value, alpha);
+            return None;
+        }
+
+        // Outliers are kept out of the band statistics.
+        self.outside += 1;
+
+        if self.outside >= config.shift_samples {
+            self.mean = value;
+            self.outside = 0;
+            return Some((AnomalyKind::LevelShift, z_score));
+        }
+
+        (self.outside == 1).then_some((AnomalyKind::Spike, z_score))
+    }
+
+    /// Add reading to the moving statistics.
+    ///
+    /// # Parameters
+    /// - `value` - given reading to add.
+    /// - `alpha` - given weight of the reading.
+    fn add(&mut self, value: f32, alpha: f32) {
+        let delta = value - self.mean;
+
+        self.mean += alpha * delta;
+        self.variance = (1.0 - alpha) * (self.variance + alpha * delta * delta);
+    }
+}
+
+/// Online anomaly detector of the sensor readings channels.
+#[derive(Debug, Default)]
+pub struct AnomalyDetector {
+    /// Anomaly detection configurations.
+    config: AnomalyConfig,
+    /// Bands by channel of [`CHANNEL_KEYS`].
+    bands: [ChannelBand; CHANNEL_KEYS.len()],
+}
+
+impl AnomalyDetector {
+    /// Construct new `AnomalyDetector` object.
+    ///
+    /// # Parameters
+    /// - `config` - given anomaly detection configurations.
+    ///
+    /// # Returns
+    /// - New `AnomalyDetector` object.
+    #[must_use]
+    pub fn new(config: AnomalyConfig) -> Self {
+        Self {
+            config,
+            bands: Default::default(),
+        }
+    }
+
+    /// Detect anomalous readings.
+    ///
+    /// # Parameters
+    /// - `payload` - given frame payload to handle.
+    ///
+    /// # Returns
+    /// - List of anomalies (empty if all readings are regular).
+    pub fn detect(
+        &mut self,
+        payload: Option<&StandardPayload>,
+    ) -> Vec<Anomaly> {
+        let Some(payload) = payload else {
+            return Vec::new();
+        };
+
+        let readings = [payload.acc(), payload.gyr(), payload.mag()];
+        let mut anomalies = Vec::new();
+
+        for ((offset, bands), readings) in
+            (0..).step_by(3).zip(self.bands.chunks_mut(3)).zip(readings)
+        {
+            let Some(readings) = readings else {
+                continue;
+            };
+
+            for (axis, (band, value)) in
+                bands.iter_mut().zip(readings.iter()).enumerate()
+            {
+                if let Some((kind, z_score)) = band.update(*value, &self.config)
+                {
+                    let anomaly = Anomaly {
+                        channel: offset + axis,
+                        kind,
+                        value: *value,
+                        z_score,
+                    };
+
+                    log::warn!("Anomaly detected: {anomaly}");
+                    anomalies.push(anomaly);
+                }
+            }
+        }
+
+        anomalies
+    }
+}
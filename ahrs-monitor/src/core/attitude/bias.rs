@@ -0,0 +1,192 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Online gyroscope bias estimation.
+//!
+//! Readings are grouped into windows of fixed duration. If acceleration and
+//! angular rate barely vary within the window, the device is considered
+//! stationary and the mean angular rate of the window is its gyroscope bias.
+
+use crate::config::GyroBiasConfig;
+use serde::{Deserialize, Serialize};
+use tsilna_nav::math::na::Vector3;
+
+/// Duration of the stillness detection window in seconds.
+const WINDOW_DURATION: f32 = 1.0;
+
+/// Min number of readings in the stillness detection window.
+const MIN_WINDOW_READINGS: usize = 10;
+
+/// Weight of the bias estimated in the latest stationary window. Bias
+/// follows slow drift (e.g. with temperature), while the noise of single
+/// windows is averaged out.
+const BIAS_UPDATE_GAIN: f32 = 0.25;
+
+/// Gyroscope bias estimation state.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq)]
+pub struct GyroBias {
+    /// Estimated bias (x, y, z) in degrees per second.
+    pub bias: [f32; 3],
+    /// Indicator whether device was stationary during the latest window.
+    pub is_still: bool,
+}
+
+/// Running statistics of the readings (Welford's algorithm).
+#[derive(Debug, Default, Clone, Copy)]
+struct Statistics {
+    /// Number of readings.
+    count: usize,
+    /// Mean of the readings.
+    mean: Vector3<f32>,
+    /// Sum of squared differences from the mean.
+    m2: Vector3<f32>,
+}
+
+impl Statistics {
+    /// Add reading to the statistics.
+    ///
+    /// # Parameters
+    /// - `value` - given reading to add.
+    #[allow(clippy::cast_precision_loss)]
+    fn add(&mut self, value: Vector3<f32>) {
+        self.count += 1;
+
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f32;
+        self.m2 += delta.component_mul(&(value - self.mean));
+    }
+
+    /// Get standard deviation of the readings magnitude.
+    ///
+    /// # Returns
+    /// - Square root of the total variance of all axes.
+    #[allow(clippy::cast_precision_loss)]
+    fn std_dev(&self) -> f32 {
+        if self.count == 0 {
+            return 0.0;
+        }
+
+        (self.m2.sum() / self.count as f32).sqrt()
+    }
+}
+
+/// Online gyroscope bias estimator.
+#[derive(Debug, Clone)]
+pub struct GyroBiasEstimator {
+    /// Bias estimation configurations.
+    config: GyroBiasConfig,
+    /// Accelerometer readings statistics of the current window.
+    acc: Statistics,
+    /// Gyroscope readings statistics of the current window.
+    gyr: Statistics,
+    /// Duration of the current window in seconds.
+    elapsed: f32,
+    /// Estimated bias (`None` - device was not stationary yet).
+    bias: Option<Vector3<f32>>,
+    /// Indicator whether device was stationary during the latest window.
+    is_still: bool,
+}
+
+impl Default for GyroBiasEstimator {
+    /// Construct bias estimator with default configurations.
+    ///
+    /// # Returns
+    /// - New `GyroBiasEstimator` object.
+    fn default() -> Self {
+        Self::new(GyroBiasConfig::default())
+    }
+}
+
+impl GyroBiasEstimator {
+    /// Construct new `GyroBiasEstimator` object.
+    ///
+    /// # Parameters
+    /// - `config` - given bias estimation configurations.
+    ///
+    /// # Returns
+    /// - New `GyroBiasEstimator` object.
+    #[must_use]
+    pub fn new(config: GyroBiasConfig) -> Self {
+        Self {
+            config,
+            acc: Statistics::default(),
+            gyr: Statistics::default(),
+            elapsed: 0.0,
+            bias: None,
+            is_still: false,
+        }
+    }
+
+    /// Update bias estimation with raw inertial sensors readings.
+    ///
+    /// # Parameters
+    /// - `acc` - given vector of accelerometer readings.
+    /// - `gyr` - given vector of gyroscope readings in degrees per second.
+    /// - `dt` - given time step since last update in seconds.
+    pub fn update(&mut self, acc: Vector3<f32>, gyr: Vector3<f32>, dt: f32) {
+        if !self.config.enabled {
+            return;
+        }
+
+        self.acc.add(acc);
+        self.gyr.add(gyr);
+        self.elapsed += dt;
+
+        if self.elapsed < WINDOW_DURATION {
+            return;
+        }
+
+        // Constant rotation has low variance as well, so that mean angular
+        // rate must be within the plausible bias magnitude.
+        self.is_still = self.gyr.count >= MIN_WINDOW_READINGS
+            && self.acc.std_dev() <= self.config.acc_std
+            && self.gyr.std_dev() <= self.config.gyr_std
+            && self.gyr.mean.norm() <= self.config.max_bias;
+
+        if self.is_still {
+            let mean = self.gyr.mean;
+
+            self.bias = Some(
+                self.bias
+                    .map_or(mean, |bias| bias.lerp(&mean, BIAS_UPDATE_GAIN)),
+            );
+        }
+
+        self.acc = Statistics::default();
+        self.gyr = Statistics::default();
+        self.elapsed = 0.0;
+    }
+
+    /// Set initial bias, refined by the following stationary windows.
+    ///
+    /// # Parameters
+    /// - `bias` - given gyroscope bias in degrees per second.
+    pub const fn set_bias(&mut self, bias: Vector3<f32>) {
+        self.bias = Some(bias);
+    }
+
+    /// Subtract estimated bias from gyroscope readings.
+    ///
+    /// # Parameters
+    /// - `gyr` - given vector of gyroscope readings in degrees per second.
+    ///
+    /// # Returns
+    /// - Corrected gyroscope readings.
+    #[must_use]
+    pub fn correct(&self, gyr: Vector3<f32>) -> Vector3<f32> {
+        self.bias.map_or(gyr, |bias| gyr - bias)
+    }
+
+    /// Get bias estimation state.
+    ///
+    /// # Returns
+    /// - Bias estimation state - if bias was estimated.
+    /// - `None` - otherwise.
+    #[must_use]
+    pub fn state(&self) -> Option<GyroBias> {
+        self.bias.map(|bias| GyroBias {
+            bias: bias.into(),
+            is_still: self.is_still,
+        })
+    }
+}
@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Tilt-compensated compass.
+//!
+//! Heading-only estimation for low-power payloads without gyroscope readings.
+//! Tilt is taken directly from the gravity direction measured by the
+//! accelerometer, and heading from the magnetic field rotated into the level
+//! frame. Gyroscope readings are ignored, so that the attitude is as noisy as
+//! the readings, but it does not drift. Magnetometer-only frames keep the
+//! last known tilt (level until the first accelerometer readings).
+
+use crate::core::attitude::AttitudeFilter;
+use tsilna_nav::math::{Quat32, na::Vector3};
+
+/// Tilt-compensated compass.
+#[derive(Default)]
+pub struct CompassFilter {
+    /// Rotation levelling the sensor frame.
+    tilt: Quat32,
+    /// Rotation around the vertical axis pointing X axis to magnetic north.
+    heading: Quat32,
+}
+
+impl AttitudeFilter for CompassFilter {
+    /// Update tilt with accelerometer readings, ignoring gyroscope ones.
+    ///
+    /// # Parameters
+    /// - `acc` - given vector of accelerometer readings.
+    /// - `_gyr` - given vector of gyroscope readings in degrees per second.
+    /// - `_dt` - given time step since last update in seconds.
+    fn update_imu(&mut self, acc: Vector3<f32>, _gyr: Vector3<f32>, _dt: f32) {
+        self.update_acc(acc);
+    }
+
+    /// Update tilt and heading, ignoring gyroscope readings.
+    ///
+    /// # Parameters
+    /// - `acc` - given vector of accelerometer readings.
+    /// - `_gyr` - given vector of gyroscope readings in degrees per second.
+    /// - `mag` - given vector of magnetometer readings.
+    /// - `_dt` - given time step since last update in seconds.
+    fn update_marg(
+        &mut self,
+        acc: Vector3<f32>,
+        _gyr: Vector3<f32>,
+        mag: Vector3<f32>,
+        _dt: f32,
+    ) {
+        self.update_acc(acc);
+        self.update_mag(mag);
+    }
+
+    /// Update tilt with accelerometer readings.
+    ///
+    /// # Parameters
+    /// - `acc` - given vector of accelerometer readings.
+    fn update_acc(&mut self, acc: Vector3<f32>) {
+        // Measured gravity direction should point up in Earth frame. Tilt is
+        // kept when device is upside down, since it is ambiguous then.
+        if let Some(tilt) = Quat32::rotation_between(&acc, &Vector3::z()) {
+            self.tilt = tilt;
+        }
+    }
+
+    /// Update heading with magnetometer readings.
+    ///
+    /// # Parameters
+    /// - `mag` - given vector of magnetometer readings.
+    fn update_mag(&mut self, mag: Vector3<f32>) {
+        // Horizontal magnetic field should point north (X axis).
+        let field = self.tilt * mag;
+
+        if field.xy().norm() > f32::EPSILON {
+            let heading_error = field.y.atan2(field.x);
+            self.heading =
+                Quat32::from_scaled_axis(Vector3::z() * -heading_error);
+        }
+    }
+
+    /// Get estimated attitude.
+    ///
+    /// # Returns
+    /// - Estimated attitude in quaternion representation.
+    fn quaternion(&self) -> Quat32 {
+        self.heading * self.tilt
+    }
+
+    /// Continue estimation from the given attitude.
+    ///
+    /// # Parameters
+    /// - `q` - given initial attitude to set.
+    fn set_quaternion(&mut self, q: Quat32) {
+        self.tilt = q;
+        self.heading = Quat32::identity();
+    }
+}
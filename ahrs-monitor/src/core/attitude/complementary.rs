@@ -0,0 +1,108 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Complementary attitude filter.
+//!
+//! Gyroscope readings are integrated for short-term attitude, which is
+//! pulled towards accelerometer tilt and magnetometer heading with the time
+//! constant of the filter.
+
+use crate::core::attitude::AttitudeFilter;
+use tsilna_nav::math::{Quat32, na::Vector3};
+
+/// Time constant of the accelerometer and magnetometer correction in seconds.
+const TIME_CONSTANT: f32 = 1.0;
+
+/// Complementary attitude filter.
+#[derive(Default)]
+pub struct ComplementaryFilter {
+    /// Estimated attitude (sensor frame relative to Earth frame).
+    q: Quat32,
+}
+
+impl ComplementaryFilter {
+    /// Integrate gyroscope readings and correct tilt by gravity direction.
+    ///
+    /// # Parameters
+    /// - `acc` - given vector of accelerometer readings.
+    /// - `gyr` - given vector of gyroscope readings in degrees per second.
+    /// - `dt` - given time step since last update in seconds.
+    ///
+    /// # Returns
+    /// - Correction weight of the update.
+    fn predict(
+        &mut self,
+        acc: Vector3<f32>,
+        gyr: Vector3<f32>,
+        dt: f32,
+    ) -> f32 {
+        let weight = dt / (TIME_CONSTANT + dt);
+        self.q *= Quat32::from_scaled_axis(gyr.map(f32::to_radians) * dt);
+
+        // Measured gravity direction should point up in Earth frame.
+        let up = self.q * acc;
+
+        if let Some(tilt) = Quat32::rotation_between(&up, &Vector3::z()) {
+            self.q = tilt.powf(weight) * self.q;
+        }
+
+        weight
+    }
+}
+
+impl AttitudeFilter for ComplementaryFilter {
+    /// Update attitude with inertial sensors readings.
+    ///
+    /// # Parameters
+    /// - `acc` - given vector of accelerometer readings.
+    /// - `gyr` - given vector of gyroscope readings in degrees per second.
+    /// - `dt` - given time step since last update in seconds.
+    fn update_imu(&mut self, acc: Vector3<f32>, gyr: Vector3<f32>, dt: f32) {
+        self.predict(acc, gyr, dt);
+    }
+
+    /// Update attitude with inertial and magnetic sensors readings.
+    ///
+    /// # Parameters
+    /// - `acc` - given vector of accelerometer readings.
+    /// - `gyr` - given vector of gyroscope readings in degrees per second.
+    /// - `mag` - given vector of magnetometer readings.
+    /// - `dt` - given time step since last update in seconds.
+    fn update_marg(
+        &mut self,
+        acc: Vector3<f32>,
+        gyr: Vector3<f32>,
+        mag: Vector3<f32>,
+        dt: f32,
+    ) {
+        let weight = self.predict(acc, gyr, dt);
+
+        // Horizontal magnetic field should point north (X axis).
+        let field = self.q * mag;
+
+        if field.xy().norm() > f32::EPSILON {
+            let heading_error = field.y.atan2(field.x);
+            let heading = Quat32::from_scaled_axis(
+                Vector3::z() * (-heading_error * weight),
+            );
+
+            self.q = heading * self.q;
+        }
+    }
+
+    /// Get estimated attitude.
+    ///
+    /// # Returns
+    /// - Estimated attitude in quaternion representation.
+    fn quaternion(&self) -> Quat32 {
+        self.q
+    }
+
+    /// Continue estimation from the given attitude.
+    ///
+    /// # Parameters
+    /// - `q` - given initial attitude to set.
+    fn set_quaternion(&mut self, q: Quat32) {
+        self.q = q;
+    }
+}
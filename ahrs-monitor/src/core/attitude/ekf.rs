@@ -0,0 +1,219 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Simple extended Kalman filter (EKF) of attitude.
+//!
+//! State is the attitude quaternion propagated by gyroscope readings.
+//! Normalized accelerometer and magnetometer readings are observations of
+//! the gravity and magnetic field directions rotated into the sensor frame.
+
+use crate::core::attitude::{AttitudeFilter, magnetic_reference};
+use tsilna_nav::math::{
+    Quat32,
+    na::{Matrix3, Matrix3x4, Matrix4, Quaternion, Vector3, Vector4},
+};
+
+/// Process noise spectral density of the quaternion state.
+const PROCESS_NOISE: f32 = 1e-3;
+
+/// Accelerometer direction observation noise variance.
+const ACC_NOISE: f32 = 1e-2;
+
+/// Magnetometer direction observation noise variance.
+const MAG_NOISE: f32 = 5e-2;
+
+/// Initial variance of the quaternion state.
+const INITIAL_VARIANCE: f32 = 1.0;
+
+/// Extended Kalman filter of attitude.
+pub struct EkfFilter {
+    /// Quaternion state (w, x, y, z).
+    x: Vector4<f32>,
+    /// State covariance.
+    p: Matrix4<f32>,
+}
+
+impl Default for EkfFilter {
+    /// Construct filter with identity attitude and high uncertainty.
+    ///
+    /// # Returns
+    /// - New `EkfFilter` object.
+    fn default() -> Self {
+        Self {
+            x: Vector4::new(1.0, 0.0, 0.0, 0.0),
+            p: Matrix4::identity() * INITIAL_VARIANCE,
+        }
+    }
+}
+
+impl EkfFilter {
+    /// Get current state as unit quaternion.
+    ///
+    /// # Returns
+    /// - Estimated attitude (sensor frame relative to Earth frame).
+    fn attitude(&self) -> Quat32 {
+        Quat32::from_quaternion(Quaternion::new(
+            self.x.x, self.x.y, self.x.z, self.x.w,
+        ))
+    }
+
+    /// Propagate state by gyroscope readings.
+    ///
+    /// # Parameters
+    /// - `gyr` - given vector of gyroscope readings in degrees per second.
+    /// - `dt` - given time step since last update in seconds.
+    fn predict(&mut self, gyr: Vector3<f32>, dt: f32) {
+        let w = gyr.map(f32::to_radians) * (0.5 * dt);
+
+        // Right multiplication by the pure quaternion of the rates.
+        #[rustfmt::skip]
+        let omega = Matrix4::new(
+            0.0, -w.x, -w.y, -w.z,
+            w.x,  0.0,  w.z, -w.y,
+            w.y, -w.z,  0.0,  w.x,
+            w.z,  w.y, -w.x,  0.0,
+        );
+
+        let f = Matrix4::identity() + omega;
+
+        self.x = f * self.x;
+        self.p = f * self.p * f.transpose()
+            + Matrix4::identity() * (PROCESS_NOISE * dt);
+        self.normalize();
+    }
+
+    /// Correct state by direction observation.
+    ///
+    /// # Parameters
+    /// - `reference` - given observed direction in Earth frame (`y` = 0).
+    /// - `measured` - given normalized measured direction in sensor frame.
+    /// - `noise` - given observation noise variance.
+    fn correct(
+        &mut self,
+        reference: Vector3<f32>,
+        measured: Vector3<f32>,
+        noise: f32,
+    ) {
+        let (predicted, h) = observe(&self.x, &reference);
+
+        let s = h * self.p * h.transpose() + Matrix3::identity() * noise;
+
+        let Some(s_inv) = s.try_inverse() else {
+            return;
+        };
+
+        let k = self.p * h.transpose() * s_inv;
+
+        self.x += k * (measured - predicted);
+        self.p = (Matrix4::identity() - k * h) * self.p;
+        self.normalize();
+    }
+
+    /// Normalize quaternion state.
+    fn normalize(&mut self) {
+        if let Some(x) = self.x.try_normalize(f32::EPSILON) {
+            self.x = x;
+        } else {
+            *self = Self::default();
+        }
+    }
+}
+
+/// Get direction in sensor frame and its Jacobian by the quaternion state.
+///
+/// # Parameters
+/// - `x` - given normalized quaternion state (w, x, y, z).
+/// - `r` - given direction in Earth frame (`y` component is ignored).
+///
+/// # Returns
+/// - Direction rotated into sensor frame.
+/// - Jacobian of the direction by the quaternion state.
+fn observe(
+    x: &Vector4<f32>,
+    r: &Vector3<f32>,
+) -> (Vector3<f32>, Matrix3x4<f32>) {
+    let (w, qx, qy, qz) = (x.x, x.y, x.z, x.w);
+    let q = Quat32::new_unchecked(Quaternion::new(w, qx, qy, qz));
+    let r = Vector3::new(r.x, 0.0, r.z);
+
+    // Derivatives of the first and third rows of the rotation matrix.
+    #[rustfmt::skip]
+    let h_x = Matrix3x4::new(
+         w,   qx, -qy, -qz,
+        -qz,  qy,  qx, -w,
+         qy,  qz,  w,   qx,
+    ) * 2.0;
+
+    #[rustfmt::skip]
+    let h_z = Matrix3x4::new(
+        -qy, qz, -w,  qx,
+         qx, w,   qz, qy,
+         w, -qx, -qy, qz,
+    ) * 2.0;
+
+    (q.inverse_transform_vector(&r), h_x * r.x + h_z * r.z)
+}
+
+impl AttitudeFilter for EkfFilter {
+    /// Update attitude with inertial sensors readings.
+    ///
+    /// # Parameters
+    /// - `acc` - given vector of accelerometer readings.
+    /// - `gyr` - given vector of gyroscope readings in degrees per second.
+    /// - `dt` - given time step since last update in seconds.
+    fn update_imu(&mut self, acc: Vector3<f32>, gyr: Vector3<f32>, dt: f32) {
+        self.predict(gyr, dt);
+
+        if let Some(acc) = acc.try_normalize(f32::EPSILON) {
+            self.correct(Vector3::z(), acc, ACC_NOISE);
+        }
+    }
+
+    /// Update attitude with inertial and magnetic sensors readings.
+    ///
+    /// # Parameters
+    /// - `acc` - given vector of accelerometer readings.
+    /// - `gyr` - given vector of gyroscope readings in degrees per second.
+    /// - `mag` - given vector of magnetometer readings.
+    /// - `dt` - given time step since last update in seconds.
+    fn update_marg(
+        &mut self,
+        acc: Vector3<f32>,
+        gyr: Vector3<f32>,
+        mag: Vector3<f32>,
+        dt: f32,
+    ) {
+        self.update_imu(acc, gyr, dt);
+
+        if let Some(mag) = mag.try_normalize(f32::EPSILON) {
+            let north = magnetic_reference(&self.attitude(), &mag);
+            self.correct(north, mag, MAG_NOISE);
+        }
+    }
+
+    /// Get estimated attitude.
+    ///
+    /// # Returns
+    /// - Estimated attitude in quaternion representation.
+    fn quaternion(&self) -> Quat32 {
+        self.attitude()
+    }
+
+    /// Continue estimation from the given attitude.
+    ///
+    /// # Parameters
+    /// - `q` - given initial attitude to set.
+    fn set_quaternion(&mut self, q: Quat32) {
+        self.x = Vector4::new(q.w, q.i, q.j, q.k);
+    }
+
+    /// Get attitude uncertainty derived from the state covariance.
+    ///
+    /// # Returns
+    /// - Standard deviation of the attitude error in degrees.
+    fn uncertainty(&self) -> Option<f32> {
+        // Small rotation angle is twice the vector part of the quaternion.
+        let variance = self.p.fixed_view::<3, 3>(1, 1).trace();
+        Some((2.0 * variance.max(0.0).sqrt()).to_degrees())
+    }
+}
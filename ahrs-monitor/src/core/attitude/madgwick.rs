@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Madgwick attitude filter backed by the `fusion-ahrs` crate.
+
+use crate::core::attitude::AttitudeFilter;
+use fusion_ahrs::Ahrs;
+use tsilna_nav::math::{Quat32, na::Vector3};
+
+/// Madgwick (Fusion revised AHRS) attitude filter.
+#[derive(Default)]
+pub struct MadgwickFilter {
+    /// Fusion AHRS algorithm handler.
+    ahrs: Ahrs,
+}
+
+impl AttitudeFilter for MadgwickFilter {
+    /// Update attitude with inertial sensors readings.
+    ///
+    /// # Parameters
+    /// - `acc` - given vector of accelerometer readings.
+    /// - `gyr` - given vector of gyroscope readings in degrees per second.
+    /// - `dt` - given time step since last update in seconds.
+    fn update_imu(&mut self, acc: Vector3<f32>, gyr: Vector3<f32>, dt: f32) {
+        self.ahrs.update_no_magnetometer(gyr, acc, dt);
+    }
+
+    /// Update attitude with inertial and magnetic sensors readings.
+    ///
+    /// # Parameters
+    /// - `acc` - given vector of accelerometer readings.
+    /// - `gyr` - given vector of gyroscope readings in degrees per second.
+    /// - `mag` - given vector of magnetometer readings.
+    /// - `dt` - given time step since last update in seconds.
+    fn update_marg(
+        &mut self,
+        acc: Vector3<f32>,
+        gyr: Vector3<f32>,
+        mag: Vector3<f32>,
+        dt: f32,
+    ) {
+        self.ahrs.update(gyr, acc, mag, dt);
+    }
+
+    /// Get estimated attitude.
+    ///
+    /// # Returns
+    /// - Estimated attitude in quaternion representation.
+    fn quaternion(&self) -> Quat32 {
+        self.ahrs.quaternion()
+    }
+
+    /// Continue estimation from the given attitude.
+    ///
+    /// Fusion AHRS does not expose its state, attitude converges with the
+    /// initialisation ramp of the algorithm instead.
+    ///
+    /// # Parameters
+    /// - `q` - given initial attitude to set (ignored).
+    fn set_quaternion(&mut self, _: Quat32) {}
+}
@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Mahony nonlinear complementary attitude filter.
+
+use crate::core::attitude::{AttitudeFilter, magnetic_reference};
+use tsilna_nav::math::{Quat32, na::Vector3};
+
+/// Proportional gain of the attitude error feedback.
+const KP: f32 = 2.0;
+
+/// Integral gain of the attitude error feedback (gyroscope bias).
+const KI: f32 = 0.01;
+
+/// Mahony attitude filter with gyroscope bias estimation.
+#[derive(Default)]
+pub struct MahonyFilter {
+    /// Estimated attitude (sensor frame relative to Earth frame).
+    q: Quat32,
+    /// Integral of the attitude error in radians per second.
+    integral: Vector3<f32>,
+}
+
+impl MahonyFilter {
+    /// Correct gyroscope readings by attitude error and integrate them.
+    ///
+    /// # Parameters
+    /// - `gyr` - given vector of gyroscope readings in degrees per second.
+    /// - `error` - given attitude error in sensor frame.
+    /// - `dt` - given time step since last update in seconds.
+    fn integrate(&mut self, gyr: Vector3<f32>, error: Vector3<f32>, dt: f32) {
+        self.integral += error * (KI * dt);
+
+        let omega = gyr.map(f32::to_radians) + error * KP + self.integral;
+        self.q *= Quat32::from_scaled_axis(omega * dt);
+    }
+
+    /// Get attitude error against gravity direction.
+    ///
+    /// # Parameters
+    /// - `acc` - given vector of accelerometer readings.
+    ///
+    /// # Returns
+    /// - Attitude error in sensor frame (zero for free fall).
+    fn gravity_error(&self, acc: Vector3<f32>) -> Vector3<f32> {
+        acc.try_normalize(f32::EPSILON)
+            .map_or_else(Vector3::zeros, |acc| {
+                let up = self.q.inverse_transform_vector(&Vector3::z());
+                acc.cross(&up)
+            })
+    }
+}
+
+impl AttitudeFilter for MahonyFilter {
+    /// Update attitude with inertial sensors readings.
+    ///
+    /// # Parameters
+    /// - `acc` - given vector of accelerometer readings.
+    /// - `gyr` - given vector of gyroscope readings in degrees per second.
+    /// - `dt` - given time step since last update in seconds.
+    fn update_imu(&mut self, acc: Vector3<f32>, gyr: Vector3<f32>, dt: f32) {
+        let error = self.gravity_error(acc);
+        self.integrate(gyr, error, dt);
+    }
+
+    /// Update attitude with inertial and magnetic sensors readings.
+    ///
+    /// # Parameters
+    /// - `acc` - given vector of accelerometer readings.
+    /// - `gyr` - given vector of gyroscope readings in degrees per second.
+    /// - `mag` - given vector of magnetometer readings.
+    /// - `dt` - given time step since last update in seconds.
+    fn update_marg(
+        &mut self,
+        acc: Vector3<f32>,
+        gyr: Vector3<f32>,
+        mag: Vector3<f32>,
+        dt: f32,
+    ) {
+        let mut error = self.gravity_error(acc);
+
+        if let Some(mag) = mag.try_normalize(f32::EPSILON) {
+            let north = magnetic_reference(&self.q, &mag);
+            error += mag.cross(&self.q.inverse_transform_vector(&north));
+        }
+
+        self.integrate(gyr, error, dt);
+    }
+
+    /// Get estimated attitude.
+    ///
+    /// # Returns
+    /// - Estimated attitude in quaternion representation.
+    fn quaternion(&self) -> Quat32 {
+        self.q
+    }
+
+    /// Continue estimation from the given attitude.
+    ///
+    /// # Parameters
+    /// - `q` - given initial attitude to set.
+    fn set_quaternion(&mut self, q: Quat32) {
+        self.q = q;
+    }
+}
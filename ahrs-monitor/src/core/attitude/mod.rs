@@ -0,0 +1,601 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Attitude estimation related declarations.
+
+mod bias;
+mod compass;
+mod complementary;
+mod ekf;
+mod madgwick;
+mod mahony;
+mod onboard;
+pub mod representation;
+
+use crate::{
+    config::FusionInputs,
+    core::{
+        StandardPayload, calibration::STANDARD_GRAVITY, warm_start::WarmStart,
+    },
+};
+pub use bias::{GyroBias, GyroBiasEstimator};
+pub use compass::CompassFilter;
+pub use complementary::ComplementaryFilter;
+pub use ekf::EkfFilter;
+pub use madgwick::MadgwickFilter;
+pub use mahony::MahonyFilter;
+pub use onboard::AttitudeComparison;
+use serde::{Deserialize, Serialize};
+use tsilna_nav::math::{
+    Quat32,
+    na::{Quaternion, Vector3},
+};
+
+/// Weight of the new accelerometer innovation in its moving average.
+const INNOVATION_WEIGHT: f32 = 0.05;
+
+/// Attitude fusion algorithm.
+#[derive(
+    Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum FusionAlgorithm {
+    /// Madgwick (Fusion revised AHRS) filter.
+    #[default]
+    Madgwick,
+    /// Mahony nonlinear complementary filter.
+    Mahony,
+    /// Complementary filter.
+    Complementary,
+    /// Simple extended Kalman filter.
+    Ekf,
+    /// Tilt-compensated compass without gyroscope readings.
+    Compass,
+}
+
+impl FusionAlgorithm {
+    /// All fusion algorithms.
+    pub const ALL: [Self; 5] = [
+        Self::Madgwick,
+        Self::Mahony,
+        Self::Complementary,
+        Self::Ekf,
+        Self::Compass,
+    ];
+
+    /// Get fusion algorithm name.
+    ///
+    /// # Returns
+    /// - Fusion algorithm name string slice.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Madgwick => "Madgwick",
+            Self::Mahony => "Mahony",
+            Self::Complementary => "Complementary",
+            Self::Ekf => "EKF",
+            Self::Compass => "Compass",
+        }
+    }
+}
+
+/// Attitude fusion filter.
+pub trait AttitudeFilter: Send {
+    /// Update attitude with inertial sensors readings.
+    ///
+    /// # Parameters
+    /// - `acc` - given vector of accelerometer readings.
+    /// - `gyr` - given vector of gyroscope readings in degrees per second.
+    /// - `dt` - given time step since last update in seconds.
+    fn update_imu(&mut self, acc: Vector3<f32>, gyr: Vector3<f32>, dt: f32);
+
+    /// Update attitude with inertial and magnetic sensors readings.
+    ///
+    /// # Parameters
+    /// - `acc` - given vector of accelerometer readings.
+    /// - `gyr` - given vector of gyroscope readings in degrees per second.
+    /// - `mag` - given vector of magnetometer readings.
+    /// - `dt` - given time step since last update in seconds.
+    fn update_marg(
+        &mut self,
+        acc: Vector3<f32>,
+        gyr: Vector3<f32>,
+        mag: Vector3<f32>,
+        dt: f32,
+    );
+
+    /// Update attitude with gyroscope readings only.
+    ///
+    /// Angular rate is integrated without any correction, so that attitude
+    /// drifts with the gyroscope bias error.
+    ///
+    /// # Parameters
+    /// - `gyr` - given vector of gyroscope readings in degrees per second.
+    /// - `dt` - given time step since last update in seconds.
+    fn update_gyr(&mut self, gyr: Vector3<f32>, dt: f32) {
+        let rotation = Quat32::from_scaled_axis(gyr.map(f32::to_radians) * dt);
+        self.set_quaternion(self.quaternion() * rotation);
+    }
+
+    /// Update attitude with accelerometer readings only.
+    ///
+    /// Filters integrating gyroscope readings keep their attitude.
+    ///
+    /// # Parameters
+    /// - `_acc` - given vector of accelerometer readings.
+    fn update_acc(&mut self, _acc: Vector3<f32>) {}
+
+    /// Update attitude with magnetometer readings only.
+    ///
+    /// Filters integrating gyroscope readings keep their attitude.
+    ///
+    /// # Parameters
+    /// - `_mag` - given vector of magnetometer readings.
+    fn update_mag(&mut self, _mag: Vector3<f32>) {}
+
+    /// Get estimated attitude.
+    ///
+    /// # Returns
+    /// - Estimated attitude in quaternion representation.
+    fn quaternion(&self) -> Quat32;
+
+    /// Continue estimation from the given attitude.
+    ///
+    /// # Parameters
+    /// - `q` - given initial attitude to set.
+    fn set_quaternion(&mut self, q: Quat32);
+
+    /// Get attitude uncertainty derived from the filter state.
+    ///
+    /// # Returns
+    /// - Standard deviation of the attitude error in degrees - if filter
+    ///   tracks its covariance.
+    /// - `None` - otherwise.
+    fn uncertainty(&self) -> Option<f32> {
+        None
+    }
+}
+
+/// AHRS attitude estimator wrapper.
+pub struct AttitudeEstimator {
+    /// Selected fusion algorithm.
+    algorithm: FusionAlgorithm,
+    /// Fusion filter handler.
+    filter: Box<dyn AttitudeFilter>,
+    /// Gyroscope bias estimator.
+    bias: GyroBiasEstimator,
+    /// Moving average of the angle between measured and estimated gravity
+    /// directions in degrees.
+    innovation: Option<f32>,
+    /// Sensors fused into the attitude.
+    inputs: FusionInputs,
+}
+
+impl Default for AttitudeEstimator {
+    /// Construct estimator with default fusion algorithm.
+    ///
+    /// # Returns
+    /// - New `AttitudeEstimator` object.
+    fn default() -> Self {
+        Self::new(FusionAlgorithm::default())
+    }
+}
+
+impl AttitudeEstimator {
+    /// Construct new `AttitudeEstimator` object.
+    ///
+    /// # Parameters
+    /// - `algorithm` - given fusion algorithm.
+    ///
+    /// # Returns
+    /// - New `AttitudeEstimator` object.
+    #[must_use]
+    pub fn new(algorithm: FusionAlgorithm) -> Self {
+        Self {
+            algorithm,
+            filter: new_filter(algorithm),
+            bias: GyroBiasEstimator::default(),
+            innovation: None,
+            inputs: FusionInputs::default(),
+        }
+    }
+
+    /// Set sensors fused into the attitude.
+    ///
+    /// # Parameters
+    /// - `inputs` - given sensors to fuse.
+    ///
+    /// # Returns
+    /// - Updated `AttitudeEstimator` object.
+    #[must_use]
+    pub const fn with_inputs(mut self, inputs: FusionInputs) -> Self {
+        self.inputs = inputs;
+        self
+    }
+
+    /// Change sensors fused into the attitude.
+    ///
+    /// Estimation continues from the current attitude, so that re-enabled
+    /// sensors correct the drift accumulated without them.
+    ///
+    /// # Parameters
+    /// - `inputs` - given sensors to fuse.
+    pub const fn set_inputs(&mut self, inputs: FusionInputs) {
+        self.inputs = inputs;
+    }
+
+    /// Get sensors fused into the attitude.
+    ///
+    /// # Returns
+    /// - Sensors enabled for fusion.
+    #[must_use]
+    pub const fn inputs(&self) -> FusionInputs {
+        self.inputs
+    }
+
+    /// Set gyroscope bias estimator.
+    ///
+    /// # Parameters
+    /// - `bias` - given gyroscope bias estimator to set.
+    ///
+    /// # Returns
+    /// - Updated `AttitudeEstimator` object.
+    #[must_use]
+    pub const fn with_gyro_bias(mut self, bias: GyroBiasEstimator) -> Self {
+        self.bias = bias;
+        self
+    }
+
+    /// Restart attitude estimation with another fusion algorithm.
+    ///
+    /// Estimated gyroscope bias is kept, since it does not depend on the
+    /// fusion algorithm.
+    ///
+    /// # Parameters
+    /// - `algorithm` - given fusion algorithm to set.
+    pub fn set_algorithm(&mut self, algorithm: FusionAlgorithm) {
+        self.algorithm = algorithm;
+        self.filter = new_filter(algorithm);
+        self.innovation = None;
+    }
+
+    /// Get selected fusion algorithm.
+    ///
+    /// # Returns
+    /// - Fusion algorithm of the estimator.
+    #[must_use]
+    pub const fn algorithm(&self) -> FusionAlgorithm {
+        self.algorithm
+    }
+
+    /// Continue estimation from the last known state of the previous session.
+    ///
+    /// # Parameters
+    /// - `state` - given last known attitude and gyroscope bias.
+    pub fn warm_start(&mut self, state: &WarmStart) {
+        self.filter.set_quaternion(state.attitude());
+
+        if let Some(bias) = state.gyro_bias() {
+            self.bias.set_bias(bias);
+        }
+    }
+
+    /// Get gyroscope bias estimation state.
+    ///
+    /// # Returns
+    /// - Bias estimation state - if bias was estimated.
+    /// - `None` - otherwise.
+    #[must_use]
+    pub fn gyro_bias(&self) -> Option<GyroBias> {
+        self.bias.state()
+    }
+
+    /// Get attitude uncertainty.
+    ///
+    /// Filters tracking covariance report its standard deviation, for the
+    /// others the accelerometer innovation is used: disagreement between
+    /// measured and estimated gravity directions grows when the solution is
+    /// degraded by vibration, sustained acceleration or gyroscope faults.
+    ///
+    /// # Returns
+    /// - Attitude uncertainty in degrees - if attitude was estimated.
+    /// - `None` - otherwise.
+    #[must_use]
+    pub fn uncertainty(&self) -> Option<f32> {
+        self.filter.uncertainty().or(self.innovation)
+    }
+
+    /// Update moving average of the accelerometer innovation.
+    ///
+    /// # Parameters
+    /// - `acc` - given vector of accelerometer readings.
+    fn update_innovation(&mut self, acc: Vector3<f32>) {
+        let q = self.filter.quaternion();
+        let gravity = q.inverse_transform_vector(&Vector3::z());
+        let angle = acc.angle(&gravity).to_degrees();
+
+        if !angle.is_finite() {
+            return;
+        }
+
+        self.innovation = Some(
+            self.innovation
+                .map_or(angle, |avg| avg + (angle - avg) * INNOVATION_WEIGHT),
+        );
+    }
+
+    /// Estimate attitude based on inertial sensors readings.
+    ///
+    /// # Parameters
+    /// - `acc` - given vector of accelerometer readings in g (g).
+    /// - `gyr` - given vector of gyroscope readings
+    ///   in degrees per second (deg/s).
+    /// - `dt` - given time step since last update in seconds (sec).
+    ///
+    /// # Returns
+    /// - Estimated attitude in quaternion representation.
+    pub fn estimate_imu(
+        &mut self,
+        acc: Vector3<f32>,
+        gyr: Vector3<f32>,
+        dt: f32,
+    ) -> Quat32 {
+        self.bias.update(acc, gyr, dt);
+        self.filter.update_imu(acc, self.bias.correct(gyr), dt);
+        self.update_innovation(acc);
+        self.filter.quaternion()
+    }
+
+    /// Estimate attitude based on inertial sensors readings.
+    ///
+    /// # Parameters
+    /// - `acc` - given vector of accelerometer readings in g (g).
+    /// - `gyr` - given vector of gyroscope readings
+    ///   in degrees per second (deg/s).
+    /// - `mag` - given vector of magnetometer readings in microteslas (µT).
+    /// - `dt` - given time step since last update in seconds (sec).
+    ///
+    /// # Returns
+    /// - Estimated attitude in quaternion representation.
+    pub fn estimate_marg(
+        &mut self,
+        acc: Vector3<f32>,
+        gyr: Vector3<f32>,
+        mag: Vector3<f32>,
+        dt: f32,
+    ) -> Quat32 {
+        self.bias.update(acc, gyr, dt);
+        self.filter
+            .update_marg(acc, self.bias.correct(gyr), mag, dt);
+        self.update_innovation(acc);
+        self.filter.quaternion()
+    }
+
+    /// Estimate attitude based on readings of the sensors enabled for fusion.
+    ///
+    /// # Parameters
+    /// - `acc` - given vector of accelerometer readings in g (g).
+    /// - `gyr` - given vector of gyroscope readings
+    ///   in degrees per second (deg/s).
+    /// - `mag` - given vector of magnetometer readings in microteslas (µT)
+    ///   (`None` - no magnetometer readings).
+    /// - `dt` - given time step since last update in seconds (sec).
+    ///
+    /// # Returns
+    /// - Estimated attitude in quaternion representation.
+    pub fn estimate_enabled(
+        &mut self,
+        acc: Vector3<f32>,
+        gyr: Vector3<f32>,
+        mag: Option<Vector3<f32>>,
+        dt: f32,
+    ) -> Quat32 {
+        let FusionInputs {
+            acc: is_acc,
+            gyr: is_gyr,
+            mag: is_mag,
+        } = self.inputs;
+        let mag = mag.filter(|_| is_mag);
+
+        match (is_acc, is_gyr, mag) {
+            (true, true, Some(mag)) => self.estimate_marg(acc, gyr, mag, dt),
+            (true, true, None) => self.estimate_imu(acc, gyr, dt),
+            // Without gyroscope filters converge to the reference directions
+            // as if the device was not rotating.
+            (true, false, mag) => {
+                let gyr = Vector3::zeros();
+
+                match mag {
+                    Some(mag) => self.filter.update_marg(acc, gyr, mag, dt),
+                    None => self.filter.update_imu(acc, gyr, dt),
+                }
+
+                self.update_innovation(acc);
+                self.filter.quaternion()
+            }
+            // Without accelerometer gyroscope bias is not estimated, since
+            // stationary state is not detected.
+            (false, true, mag) => {
+                self.filter.update_gyr(self.bias.correct(gyr), dt);
+
+                if let Some(mag) = mag {
+                    self.filter.update_mag(mag);
+                }
+
+                self.filter.quaternion()
+            }
+            (false, false, mag) => self.estimate_partial(None, mag),
+        }
+    }
+
+    /// Estimate attitude based on single sensor readings of low-power
+    /// payloads.
+    ///
+    /// # Parameters
+    /// - `acc` - given vector of accelerometer readings (`None` - no
+    ///   accelerometer readings).
+    /// - `mag` - given vector of magnetometer readings (`None` - no
+    ///   magnetometer readings).
+    ///
+    /// # Returns
+    /// - Estimated attitude in quaternion representation.
+    pub fn estimate_partial(
+        &mut self,
+        acc: Option<Vector3<f32>>,
+        mag: Option<Vector3<f32>>,
+    ) -> Quat32 {
+        if let Some(acc) = acc {
+            self.filter.update_acc(acc);
+            self.update_innovation(acc);
+        }
+
+        if let Some(mag) = mag {
+            self.filter.update_mag(mag);
+        }
+
+        self.filter.quaternion()
+    }
+}
+
+impl FusionInputs {
+    /// Get sensors fused into the attitude for the payload.
+    ///
+    /// # Parameters
+    /// - `payload` - given frame payload to handle.
+    ///
+    /// # Returns
+    /// - Sensors enabled for fusion with readings in the payload (`None` -
+    ///   attitude is not estimated from the payload).
+    #[must_use]
+    pub const fn used(self, payload: &StandardPayload) -> Option<Self> {
+        let is_estimated = matches!(
+            payload,
+            StandardPayload::Imu6(_)
+                | StandardPayload::Imu9(_)
+                | StandardPayload::Imu3Acc(_)
+                | StandardPayload::Imu3Mag(_)
+        );
+
+        if !is_estimated {
+            return None;
+        }
+
+        Some(Self {
+            acc: self.acc && payload.acc().is_some(),
+            gyr: self.gyr && payload.gyr().is_some(),
+            mag: self.mag && payload.mag().is_some(),
+        })
+    }
+}
+
+/// Construct fusion filter.
+///
+/// # Parameters
+/// - `algorithm` - given fusion algorithm.
+///
+/// # Returns
+/// - New fusion filter handler.
+fn new_filter(algorithm: FusionAlgorithm) -> Box<dyn AttitudeFilter> {
+    match algorithm {
+        FusionAlgorithm::Madgwick => Box::new(MadgwickFilter::default()),
+        FusionAlgorithm::Mahony => Box::new(MahonyFilter::default()),
+        FusionAlgorithm::Complementary => {
+            Box::new(ComplementaryFilter::default())
+        }
+        FusionAlgorithm::Ekf => Box::new(EkfFilter::default()),
+        FusionAlgorithm::Compass => Box::new(CompassFilter::default()),
+    }
+}
+
+/// Get magnetic field direction in Earth frame without declination.
+///
+/// # Parameters
+/// - `q` - given estimated attitude.
+/// - `mag` - given normalized magnetometer readings.
+///
+/// # Returns
+/// - Magnetic field direction in the north-vertical plane.
+fn magnetic_reference(q: &Quat32, mag: &Vector3<f32>) -> Vector3<f32> {
+    let field = q * mag;
+    Vector3::new(field.xy().norm(), 0.0, field.z)
+}
+
+/// Get linear (gravity-free) acceleration.
+///
+/// # Parameters
+/// - `q` - given estimated attitude.
+/// - `acc` - given vector of accelerometer readings in m/s².
+///
+/// # Returns
+/// - Acceleration without gravity in sensor frame in m/s².
+#[must_use]
+pub fn linear_acceleration(q: &Quat32, acc: Vector3<f32>) -> Vector3<f32> {
+    // Accelerometer at rest measures gravity reaction pointing up.
+    let gravity = q.inverse_transform_vector(&Vector3::z()) * STANDARD_GRAVITY;
+    acc - gravity
+}
+
+/// Estimate attitude based on IMU readings.
+///
+/// # Parameters
+/// - `estimator` - given attitude estimator.
+/// - `payload` - given frame payload to handle.
+/// - `dt` - given time step since last update in seconds (sec).
+///
+/// # Returns
+/// - Attitude in quaternion representation.
+pub fn estimate_attitude(
+    estimator: &mut AttitudeEstimator,
+    payload: Option<&StandardPayload>,
+    dt: f32,
+) -> Quat32 {
+    match payload {
+        // IMU 6-axis (Acc + Gyr).
+        Some(StandardPayload::Imu6(p)) => {
+            let (acc, gyr) = (p.acc, p.gyr);
+
+            let acc =
+                Vector3::new(acc.acc_x.get(), acc.acc_y.get(), acc.acc_z.get());
+
+            let gyr =
+                Vector3::new(gyr.gyr_x.get(), gyr.gyr_y.get(), gyr.gyr_z.get());
+
+            estimator.estimate_enabled(acc, gyr, None, dt)
+        }
+
+        // MARG 9-axis (Acc + Gyr + Mag).
+        Some(StandardPayload::Imu9(p)) => {
+            let (acc, gyr, mag) = (p.acc, p.gyr, p.mag);
+
+            let acc =
+                Vector3::new(acc.acc_x.get(), acc.acc_y.get(), acc.acc_z.get());
+
+            let gyr =
+                Vector3::new(gyr.gyr_x.get(), gyr.gyr_y.get(), gyr.gyr_z.get());
+
+            let mag =
+                Vector3::new(mag.mag_x.get(), mag.mag_y.get(), mag.mag_z.get());
+
+            estimator.estimate_enabled(acc, gyr, Some(mag), dt)
+        }
+
+        Some(StandardPayload::ImuQuat(p)) => Quat32::from_quaternion(
+            Quaternion::new(p.w.get(), p.x.get(), p.y.get(), p.z.get()),
+        ),
+
+        // Low-power payloads without gyroscope readings.
+        Some(
+            payload @ (StandardPayload::Imu3Acc(_)
+            | StandardPayload::Imu3Mag(_)),
+        ) => {
+            let inputs = estimator.inputs;
+
+            estimator.estimate_partial(
+                payload.acc().filter(|_| inputs.acc),
+                payload.mag().filter(|_| inputs.mag),
+            )
+        }
+
+        _ => estimator.filter.quaternion(),
+    }
+}
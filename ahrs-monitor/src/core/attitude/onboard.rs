@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Comparison of the onboard and local attitude estimation.
+//!
+//! Device may send its onboard fusion result (`ImuQuat`) together with the
+//! raw readings, on alternating frames or in batch. Local estimator keeps
+//! running on the raw readings, and every new attitude of either side is
+//! compared with the latest attitude of the other one.
+
+use tsilna_nav::math::Quat32;
+
+/// Latest onboard and local attitudes.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AttitudeComparison {
+    /// Latest attitude reported by the device.
+    onboard: Option<Quat32>,
+    /// Latest attitude estimated from the raw readings.
+    local: Option<Quat32>,
+}
+
+impl AttitudeComparison {
+    /// Update attitude reported by the device.
+    ///
+    /// # Parameters
+    /// - `attitude` - given onboard attitude.
+    ///
+    /// # Returns
+    /// - Angular error between onboard and local attitude in degrees - if
+    ///   local attitude is estimated.
+    /// - `None` - otherwise.
+    pub fn update_onboard(&mut self, attitude: Quat32) -> Option<f32> {
+        self.onboard = Some(attitude);
+        self.error()
+    }
+
+    /// Update attitude estimated from the raw readings.
+    ///
+    /// # Parameters
+    /// - `attitude` - given local attitude.
+    ///
+    /// # Returns
+    /// - Angular error between onboard and local attitude in degrees - if
+    ///   onboard attitude is reported.
+    /// - `None` - otherwise.
+    pub fn update_local(&mut self, attitude: Quat32) -> Option<f32> {
+        self.local = Some(attitude);
+        self.error()
+    }
+
+    /// Get angular error between onboard and local attitude.
+    ///
+    /// # Returns
+    /// - Rotation angle between attitudes in degrees - if both are known.
+    /// - `None` - otherwise.
+    #[must_use]
+    pub fn error(&self) -> Option<f32> {
+        let (onboard, local) = (self.onboard?, self.local?);
+        Some(onboard.angle_to(&local).to_degrees())
+    }
+}
@@ -0,0 +1,72 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Attitude representations derived from the quaternion.
+//!
+//! Euler angles (roll, pitch, yaw) are singular at ±90° pitch, where roll
+//! and yaw rotate around the same axis and only their difference (or sum)
+//! is defined. Small noise of the quaternion then swings roll and yaw wildly
+//! in opposite directions, so that near gimbal lock roll is held at zero and
+//! the whole rotation around the vertical axis is reported as yaw.
+
+use tsilna_nav::math::{Quat32, na::Vector3};
+
+/// Distance from ±90° pitch treated as gimbal lock in radians.
+const GIMBAL_LOCK_MARGIN: f32 = std::f32::consts::PI / 180.0;
+
+/// Get Euler angles guarded against gimbal lock.
+///
+/// # Parameters
+/// - `q` - given attitude.
+///
+/// # Returns
+/// - Roll, pitch and yaw in radians.
+#[must_use]
+pub fn euler_angles(q: &Quat32) -> (f32, f32, f32) {
+    let (roll, pitch, yaw) = q.euler_angles();
+
+    if pitch.abs() < std::f32::consts::FRAC_PI_2 - GIMBAL_LOCK_MARGIN {
+        return (roll, pitch, yaw);
+    }
+
+    // With zero roll, both ±90° pitch matrices reduce to the same yaw terms.
+    let m = q.to_rotation_matrix();
+    let m = m.matrix();
+
+    (0.0, pitch, (-m.m12).atan2(m.m22))
+}
+
+/// Get rotation matrix.
+///
+/// # Parameters
+/// - `q` - given attitude.
+///
+/// # Returns
+/// - Row-major 3x3 matrix rotating sensor frame vectors into Earth frame.
+#[must_use]
+pub fn rotation_matrix(q: &Quat32) -> [[f32; 3]; 3] {
+    let m = q.to_rotation_matrix();
+    let m = m.matrix();
+
+    [
+        [m.m11, m.m12, m.m13],
+        [m.m21, m.m22, m.m23],
+        [m.m31, m.m32, m.m33],
+    ]
+}
+
+/// Get axis-angle representation.
+///
+/// # Parameters
+/// - `q` - given attitude.
+///
+/// # Returns
+/// - Unit rotation axis and rotation angle in radians (zero axis for the
+///   identity rotation).
+#[must_use]
+pub fn axis_angle(q: &Quat32) -> ([f32; 3], f32) {
+    q.axis_angle().map_or(([0.0; 3], 0.0), |(axis, angle)| {
+        let axis: Vector3<f32> = axis.into_inner();
+        (axis.into(), angle)
+    })
+}
@@ -0,0 +1,203 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Frame arrival burst analysis.
+//!
+//! Evenly paced sender delivers frames with inter-arrival times close to the
+//! sample period. Network and OS buffering deliver them in chunks instead:
+//! many frames within microseconds of each other followed by long gaps. The
+//! burstiness `B = (σ - μ) / (σ + μ)` of the inter-arrival times tells them
+//! apart: -1 for strictly periodic arrival, 0 for random (Poisson) arrival
+//! and close to 1 for bursty arrival. Jitter of the sender itself widens the
+//! histogram around the period without producing bursts.
+
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Number of inter-arrival time histogram bins.
+pub const HISTOGRAM_BINS: usize = 10;
+
+/// Upper bounds of the histogram bins in microseconds (last bin is open).
+pub const BIN_EDGES_US: [u32; HISTOGRAM_BINS - 1] =
+    [100, 250, 500, 1_000, 2_500, 5_000, 10_000, 25_000, 50_000];
+
+/// Number of recent inter-arrival times analyzed.
+const WINDOW_SIZE: usize = 1000;
+
+/// Fraction of the sample period below which frames are considered clumped.
+const CLUMP_RATIO: f64 = 0.25;
+
+/// Min number of clumped frames in a row counted as a burst.
+const MIN_BURST_FRAMES: usize = 3;
+
+/// Statistics of the recent frame inter-arrival times.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ArrivalStats {
+    /// Number of inter-arrival times per histogram bin.
+    pub histogram: [usize; HISTOGRAM_BINS],
+    /// Mean inter-arrival time in microseconds.
+    pub mean_us: f64,
+    /// Standard deviation of the inter-arrival times in microseconds.
+    pub std_us: f64,
+    /// Burstiness in range [-1, 1] (`None` - not enough samples).
+    pub burstiness: Option<f64>,
+    /// Number of bursts since the start of the session.
+    pub bursts: usize,
+    /// Max number of frames in a single burst.
+    pub max_burst: usize,
+}
+
+/// Frame arrival burst detector.
+#[derive(Debug)]
+pub struct BurstDetector {
+    /// Inter-arrival time below which frames are clumped in microseconds.
+    clump_us: f64,
+    /// Host receive time of the last frame in Unix microseconds.
+    last_received_us: Option<i64>,
+    /// Recent inter-arrival times in microseconds.
+    window: VecDeque<u32>,
+    /// Sum of the recent inter-arrival times.
+    sum: f64,
+    /// Sum of squares of the recent inter-arrival times.
+    sum_sq: f64,
+    /// Number of clumped frames in the current run.
+    run: usize,
+    /// Current statistics.
+    stats: ArrivalStats,
+}
+
+impl BurstDetector {
+    /// Construct new `BurstDetector` object.
+    ///
+    /// # Parameters
+    /// - `sample_rate` - given nominal sample rate in Hz.
+    ///
+    /// # Returns
+    /// - New `BurstDetector` object.
+    #[must_use]
+    pub fn new(sample_rate: f32) -> Self {
+        Self {
+            clump_us: CLUMP_RATIO * 1_000_000.0 / f64::from(sample_rate),
+            last_received_us: None,
+            window: VecDeque::with_capacity(WINDOW_SIZE),
+            sum: 0.0,
+            sum_sq: 0.0,
+            run: 0,
+            stats: ArrivalStats::default(),
+        }
+    }
+
+    /// Handle frame arrival.
+    ///
+    /// # Parameters
+    /// - `received_us` - given host receive time in Unix microseconds.
+    ///
+    /// # Returns
+    /// - Updated arrival statistics.
+    pub fn update(&mut self, received_us: i64) -> ArrivalStats {
+        let Some(last) = self.last_received_us.replace(received_us) else {
+            return self.stats;
+        };
+
+        // Host clock stepped backward, interval is unknown.
+        let Ok(interval) = u32::try_from(received_us - last) else {
+            return self.stats;
+        };
+
+        self.push(interval);
+        self.update_bursts(interval);
+        self.update_moments();
+
+        self.stats
+    }
+
+    /// Forget arrival history (counters of bursts are kept).
+    pub fn reset(&mut self) {
+        self.last_received_us = None;
+        self.window.clear();
+        self.sum = 0.0;
+        self.sum_sq = 0.0;
+        self.run = 0;
+        self.stats = ArrivalStats {
+            bursts: self.stats.bursts,
+            max_burst: self.stats.max_burst,
+            ..ArrivalStats::default()
+        };
+    }
+
+    /// Append inter-arrival time to the window.
+    ///
+    /// # Parameters
+    /// - `interval` - given inter-arrival time in microseconds.
+    fn push(&mut self, interval: u32) {
+        if self.window.len() >= WINDOW_SIZE
+            && let Some(old) = self.window.pop_front()
+        {
+            self.sum -= f64::from(old);
+            self.sum_sq -= f64::from(old).powi(2);
+
+            if let Some(count) = self.stats.histogram.get_mut(bin(old)) {
+                *count -= 1;
+            }
+        }
+
+        self.window.push_back(interval);
+        self.sum += f64::from(interval);
+        self.sum_sq += f64::from(interval).powi(2);
+
+        if let Some(count) = self.stats.histogram.get_mut(bin(interval)) {
+            *count += 1;
+        }
+    }
+
+    /// Count runs of clumped frames as bursts.
+    ///
+    /// # Parameters
+    /// - `interval` - given inter-arrival time in microseconds.
+    fn update_bursts(&mut self, interval: u32) {
+        if f64::from(interval) >= self.clump_us {
+            self.run = 0;
+            return;
+        }
+
+        // The first frame of the run arrived after a gap.
+        self.run = self.run.max(1) + 1;
+
+        if self.run == MIN_BURST_FRAMES {
+            self.stats.bursts += 1;
+        }
+
+        if self.run >= MIN_BURST_FRAMES {
+            self.stats.max_burst = self.stats.max_burst.max(self.run);
+        }
+    }
+
+    /// Update mean, deviation and burstiness over the window.
+    #[allow(clippy::cast_precision_loss)]
+    fn update_moments(&mut self) {
+        let n = self.window.len() as f64;
+        let mean = self.sum / n;
+        // Rounding errors of the running sums may go slightly negative.
+        let std = (self.sum_sq / n - mean.powi(2)).max(0.0).sqrt();
+
+        self.stats.mean_us = mean;
+        self.stats.std_us = std;
+        self.stats.burstiness = (self.window.len() >= MIN_BURST_FRAMES
+            && std + mean > f64::EPSILON)
+            .then(|| (std - mean) / (std + mean));
+    }
+}
+
+/// Get histogram bin of the inter-arrival time.
+///
+/// # Parameters
+/// - `interval` - given inter-arrival time in microseconds.
+///
+/// # Returns
+/// - Histogram bin index.
+fn bin(interval: u32) -> usize {
+    BIN_EDGES_US
+        .iter()
+        .position(|&edge| interval < edge)
+        .unwrap_or(HISTOGRAM_BINS - 1)
+}
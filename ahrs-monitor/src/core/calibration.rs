@@ -0,0 +1,413 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Accelerometer six-position calibration.
+//!
+//! The IMU is placed still with each of its axes pointing up and down. Only
+//! gravity is measured in every pose, so that for each axis the mean of up
+//! and down readings is the offset and their half-difference is the gravity
+//! seen by the sensor, which gives the scale factor.
+
+use crate::core::StandardPayload;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use tsilna_nav::math::na::Vector3;
+
+/// Standard gravity in m/s².
+pub const STANDARD_GRAVITY: f32 = 9.806_65;
+
+/// Number of accelerometer readings averaged in each pose.
+pub const POSE_READINGS: usize = 200;
+
+/// Max standard deviation of readings magnitude of the still device in m/s².
+const MAX_POSE_STD: f32 = 0.3;
+
+/// Min fraction of the gravity measured along the axis pointing up or down.
+const MIN_AXIS_ALIGNMENT: f32 = 0.8;
+
+/// Accelerometer calibration: `calibrated = (raw - offset) * scale`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct AccCalibration {
+    /// Offset (x, y, z) in units of raw readings.
+    pub offset: [f32; 3],
+    /// Scale factor (x, y, z).
+    pub scale: [f32; 3],
+}
+
+impl Default for AccCalibration {
+    /// Get identity calibration.
+    ///
+    /// # Returns
+    /// - Calibration without offset and scaling.
+    fn default() -> Self {
+        Self {
+            offset: [0.0; 3],
+            scale: [1.0; 3],
+        }
+    }
+}
+
+impl AccCalibration {
+    /// Calibrate accelerometer readings.
+    ///
+    /// # Parameters
+    /// - `acc` - given vector of raw accelerometer readings.
+    ///
+    /// # Returns
+    /// - Calibrated accelerometer readings.
+    #[must_use]
+    pub fn apply(&self, acc: Vector3<f32>) -> Vector3<f32> {
+        (acc - Vector3::from(self.offset))
+            .component_mul(&Vector3::from(self.scale))
+    }
+
+    /// Calibrate accelerometer readings of the payload.
+    ///
+    /// # Parameters
+    /// - `payload` - given payload to calibrate.
+    pub fn apply_to(&self, payload: &mut StandardPayload) {
+        if *self != Self::default()
+            && let Some(acc) = payload.acc()
+        {
+            payload.set_acc(self.apply(acc));
+        }
+    }
+
+    /// Compose calibration estimated from readings already calibrated by
+    /// this calibration.
+    ///
+    /// # Parameters
+    /// - `next` - given calibration of the calibrated readings.
+    ///
+    /// # Returns
+    /// - Calibration of the raw readings equivalent to applying both.
+    #[must_use]
+    pub fn then(&self, next: &Self) -> Self {
+        let mut composed = *self;
+
+        for axis in 0..3 {
+            if let (Some(offset), Some(scale), Some(o2), Some(s2)) = (
+                composed.offset.get_mut(axis),
+                composed.scale.get_mut(axis),
+                next.offset.get(axis),
+                next.scale.get(axis),
+            ) {
+                *offset += o2 / *scale;
+                *scale *= s2;
+            }
+        }
+
+        composed
+    }
+}
+
+/// Orientation of the IMU during calibration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CalibrationPose {
+    /// X axis pointing up.
+    XUp,
+    /// X axis pointing down.
+    XDown,
+    /// Y axis pointing up.
+    YUp,
+    /// Y axis pointing down.
+    YDown,
+    /// Z axis pointing up (IMU lying flat).
+    ZUp,
+    /// Z axis pointing down (IMU upside down).
+    ZDown,
+}
+
+impl CalibrationPose {
+    /// All calibration poses in the order of the calibration flow.
+    pub const ALL: [Self; 6] = [
+        Self::ZUp,
+        Self::ZDown,
+        Self::XUp,
+        Self::XDown,
+        Self::YUp,
+        Self::YDown,
+    ];
+
+    /// Get pose name.
+    ///
+    /// # Returns
+    /// - Pose name string slice.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::XUp => "+X up",
+            Self::XDown => "-X up",
+            Self::YUp => "+Y up",
+            Self::YDown => "-Y up",
+            Self::ZUp => "+Z up",
+            Self::ZDown => "-Z up",
+        }
+    }
+
+    /// Get axis pointing up or down.
+    ///
+    /// # Returns
+    /// - Axis index (0 - X, 1 - Y, 2 - Z).
+    #[must_use]
+    pub const fn axis(self) -> usize {
+        match self {
+            Self::XUp | Self::XDown => 0,
+            Self::YUp | Self::YDown => 1,
+            Self::ZUp | Self::ZDown => 2,
+        }
+    }
+
+    /// Check whether positive direction of the axis points up.
+    ///
+    /// # Returns
+    /// - `true` - if axis points up.
+    /// - `false` - if axis points down.
+    #[must_use]
+    pub const fn is_up(self) -> bool {
+        matches!(self, Self::XUp | Self::YUp | Self::ZUp)
+    }
+
+    /// Get pose index in the calibration flow.
+    ///
+    /// # Returns
+    /// - Pose index in [`CalibrationPose::ALL`].
+    const fn index(self) -> usize {
+        match self {
+            Self::ZUp => 0,
+            Self::ZDown => 1,
+            Self::XUp => 2,
+            Self::XDown => 3,
+            Self::YUp => 4,
+            Self::YDown => 5,
+        }
+    }
+}
+
+/// Calibration error enumeration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CalibrationError {
+    /// Device was moving while measuring the pose.
+    Moving(CalibrationPose),
+    /// Gravity is not measured along the expected axis.
+    WrongPose(CalibrationPose),
+    /// Not all poses are measured.
+    Incomplete,
+    /// Up and down readings of the axis don't differ.
+    Degenerate(usize),
+}
+
+impl fmt::Display for CalibrationError {
+    /// Format calibration error.
+    ///
+    /// # Parameters
+    /// - `f` - given formatter.
+    ///
+    /// # Returns
+    /// - `Ok` - in case of success.
+    /// - `Err` - otherwise.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Moving(pose) => {
+                write!(f, "device was moving in pose {}", pose.name())
+            }
+            Self::WrongPose(pose) => {
+                write!(f, "device is not placed {}", pose.name())
+            }
+            Self::Incomplete => write!(f, "not all poses are measured"),
+            Self::Degenerate(axis) => {
+                write!(f, "up and down readings of axis {axis} are equal")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CalibrationError {}
+
+/// Readings of the pose being measured.
+#[derive(Debug, Clone, Copy)]
+struct Measurement {
+    /// Measured pose.
+    pose: CalibrationPose,
+    /// Number of readings.
+    count: usize,
+    /// Sum of the readings.
+    sum: Vector3<f32>,
+    /// Sum of the squared readings magnitudes.
+    norm_sq_sum: f32,
+    /// Sum of the readings magnitudes.
+    norm_sum: f32,
+}
+
+/// Six-position accelerometer calibration.
+#[derive(Debug, Clone, Default)]
+pub struct SixPositionCalibration {
+    /// Mean readings of each pose in the calibration flow order.
+    means: [Option<Vector3<f32>>; 6],
+    /// Pose being measured.
+    measurement: Option<Measurement>,
+}
+
+impl SixPositionCalibration {
+    /// Start measuring the pose.
+    ///
+    /// # Parameters
+    /// - `pose` - given pose the device is placed in.
+    pub const fn start(&mut self, pose: CalibrationPose) {
+        self.measurement = Some(Measurement {
+            pose,
+            count: 0,
+            sum: Vector3::new(0.0, 0.0, 0.0),
+            norm_sq_sum: 0.0,
+            norm_sum: 0.0,
+        });
+    }
+
+    /// Add accelerometer readings to the pose being measured.
+    ///
+    /// # Parameters
+    /// - `acc` - given vector of accelerometer readings.
+    ///
+    /// # Returns
+    /// - `Ok` - if readings are added or no pose is being measured.
+    /// - `Err` - if pose measurement failed.
+    ///
+    /// # Errors
+    /// - Device was moving or placed in the wrong pose.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn add(&mut self, acc: Vector3<f32>) -> Result<(), CalibrationError> {
+        let Some(measurement) = &mut self.measurement else {
+            return Ok(());
+        };
+
+        let norm = acc.norm();
+
+        measurement.count += 1;
+        measurement.sum += acc;
+        measurement.norm_sum += norm;
+        measurement.norm_sq_sum += norm * norm;
+
+        if measurement.count < POSE_READINGS {
+            return Ok(());
+        }
+
+        let Measurement {
+            pose,
+            count,
+            sum,
+            norm_sq_sum,
+            norm_sum,
+        } = *measurement;
+
+        self.measurement = None;
+
+        let count = count as f32;
+        let mean = sum / count;
+        let norm_mean = norm_sum / count;
+        let variance =
+            norm_mean.mul_add(-norm_mean, norm_sq_sum / count).max(0.0);
+
+        if variance.sqrt() > MAX_POSE_STD {
+            return Err(CalibrationError::Moving(pose));
+        }
+
+        let along_axis = mean.get(pose.axis()).copied().unwrap_or(0.0);
+        let expected = if pose.is_up() {
+            along_axis
+        } else {
+            -along_axis
+        };
+
+        if expected < MIN_AXIS_ALIGNMENT * mean.norm() {
+            return Err(CalibrationError::WrongPose(pose));
+        }
+
+        if let Some(slot) = self.means.get_mut(pose.index()) {
+            *slot = Some(mean);
+        }
+
+        Ok(())
+    }
+
+    /// Get progress of the pose measurement.
+    ///
+    /// # Returns
+    /// - Measured pose and fraction of collected readings - if pose is being
+    ///   measured.
+    /// - `None` - otherwise.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn progress(&self) -> Option<(CalibrationPose, f32)> {
+        self.measurement.map(|measurement| {
+            (
+                measurement.pose,
+                measurement.count as f32 / POSE_READINGS as f32,
+            )
+        })
+    }
+
+    /// Cancel measuring the pose.
+    pub const fn cancel(&mut self) {
+        self.measurement = None;
+    }
+
+    /// Check whether pose is measured.
+    ///
+    /// # Parameters
+    /// - `pose` - given pose to check.
+    ///
+    /// # Returns
+    /// - `true` - if pose is measured.
+    /// - `false` - otherwise.
+    #[must_use]
+    pub fn is_measured(&self, pose: CalibrationPose) -> bool {
+        self.means.get(pose.index()).is_some_and(Option::is_some)
+    }
+
+    /// Compute calibration of the measured readings.
+    ///
+    /// # Returns
+    /// - Calibration of the measured readings - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - Not all poses are measured.
+    /// - Up and down readings of some axis are equal.
+    pub fn compute(&self) -> Result<AccCalibration, CalibrationError> {
+        let mut calibration = AccCalibration::default();
+
+        for (up, down) in [
+            (CalibrationPose::XUp, CalibrationPose::XDown),
+            (CalibrationPose::YUp, CalibrationPose::YDown),
+            (CalibrationPose::ZUp, CalibrationPose::ZDown),
+        ] {
+            let axis = up.axis();
+            let reading = |pose: CalibrationPose| {
+                self.means
+                    .get(pose.index())
+                    .copied()
+                    .flatten()
+                    .and_then(|mean| mean.get(axis).copied())
+                    .ok_or(CalibrationError::Incomplete)
+            };
+
+            let (up, down) = (reading(up)?, reading(down)?);
+            let half_range = (up - down) / 2.0;
+
+            if half_range <= f32::EPSILON {
+                return Err(CalibrationError::Degenerate(axis));
+            }
+
+            if let (Some(offset), Some(scale)) = (
+                calibration.offset.get_mut(axis),
+                calibration.scale.get_mut(axis),
+            ) {
+                *offset = f32::midpoint(up, down);
+                *scale = STANDARD_GRAVITY / half_range;
+            }
+        }
+
+        Ok(calibration)
+    }
+}
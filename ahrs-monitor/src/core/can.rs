@@ -0,0 +1,287 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! `SocketCAN` frame source with CAN-FD multi-frame reassembly.
+//!
+//! IDTP frame is split into CAN (FD) frame segments sharing the same CAN
+//! identifier. Each segment starts with 1-byte segment index. The first
+//! segment (index 0) also carries 2-byte little-endian IDTP frame length,
+//! so that padding of the last CAN-FD frame is discarded.
+
+use indtp::MTU_SIZE;
+use std::{
+    ffi::CString,
+    io, mem,
+    os::fd::{AsRawFd, FromRawFd, OwnedFd},
+};
+use tokio::io::unix::AsyncFd;
+
+/// CAN frame header size (identifier, length, flags and reserved bytes).
+const CAN_HEADER_SIZE: usize = 8;
+
+/// Reassembler of IDTP frames from CAN frame segments.
+#[derive(Debug, Default)]
+struct Reassembler {
+    /// Reassembled IDTP frame bytes.
+    data: Vec<u8>,
+    /// Expected IDTP frame length.
+    expected_len: usize,
+    /// Expected index of the next segment.
+    next_index: Option<u8>,
+}
+
+impl Reassembler {
+    /// Append CAN frame segment.
+    ///
+    /// # Parameters
+    /// - `segment` - given CAN frame data to handle.
+    ///
+    /// # Returns
+    /// - Reassembled IDTP frame - if segment completes the frame.
+    /// - `None` - otherwise.
+    fn push(&mut self, segment: &[u8]) -> Option<&[u8]> {
+        let (&index, rest) = segment.split_first()?;
+
+        let data = if index == 0 {
+            let (len, data) = rest.split_first_chunk::<2>()?;
+            let expected_len = usize::from(u16::from_le_bytes(*len));
+
+            if expected_len > MTU_SIZE {
+                log::warn!("CAN: IDTP frame length {expected_len} exceeds MTU");
+                self.next_index = None;
+                return None;
+            }
+
+            self.data.clear();
+            self.expected_len = expected_len;
+            data
+        } else if self.next_index == Some(index) {
+            rest
+        } else {
+            if self.next_index.is_some() {
+                log::warn!("CAN: unexpected segment {index}, dropping frame");
+            }
+
+            self.next_index = None;
+            return None;
+        };
+
+        let remaining = self.expected_len.saturating_sub(self.data.len());
+        self.data.extend(data.iter().take(remaining));
+
+        if self.data.len() == self.expected_len {
+            self.next_index = None;
+            Some(&self.data)
+        } else {
+            self.next_index = index.checked_add(1);
+            None
+        }
+    }
+}
+
+/// `SocketCAN` raw socket receiving IDTP frame segments.
+pub struct CanSocket {
+    /// Non-blocking raw CAN socket.
+    fd: AsyncFd<OwnedFd>,
+    /// IDTP frames reassembler.
+    reassembler: Reassembler,
+}
+
+impl CanSocket {
+    /// Open raw CAN socket bound to the network interface.
+    ///
+    /// # Parameters
+    /// - `interface` - given CAN network interface name.
+    /// - `can_id` - given CAN identifier of IDTP frame segments.
+    ///
+    /// # Returns
+    /// - New `CanSocket` object - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - Unknown network interface.
+    /// - Socket creation, configuration or binding errors.
+    pub fn open(interface: &str, can_id: u32) -> io::Result<Self> {
+        let name = CString::new(interface)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        // SAFETY: `name` is a valid NUL-terminated string.
+        let ifindex = unsafe { libc::if_nametoindex(name.as_ptr()) };
+
+        if ifindex == 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        // SAFETY: plain system call without pointer arguments.
+        let raw_fd = unsafe {
+            libc::socket(
+                libc::PF_CAN,
+                libc::SOCK_RAW | libc::SOCK_NONBLOCK | libc::SOCK_CLOEXEC,
+                libc::CAN_RAW,
+            )
+        };
+
+        if raw_fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        // SAFETY: `raw_fd` is a valid descriptor owned by nobody else.
+        let fd = unsafe { OwnedFd::from_raw_fd(raw_fd) };
+
+        let enable: libc::c_int = 1;
+        set_option(&fd, libc::CAN_RAW_FD_FRAMES, &enable)?;
+        set_option(&fd, libc::CAN_RAW_FILTER, &can_filter(can_id))?;
+
+        // SAFETY: all-zero bit pattern is valid for `sockaddr_can`.
+        let mut addr: libc::sockaddr_can = unsafe { mem::zeroed() };
+        addr.can_ifindex = libc::c_int::try_from(ifindex)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        #[allow(clippy::cast_possible_truncation)]
+        {
+            addr.can_family = libc::AF_CAN as libc::sa_family_t;
+        }
+
+        // SAFETY: `addr` points to initialized `sockaddr_can` of given size.
+        let result = unsafe {
+            libc::bind(
+                fd.as_raw_fd(),
+                (&raw const addr).cast(),
+                socklen::<libc::sockaddr_can>(),
+            )
+        };
+
+        if result < 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        log::info!("Listening on {interface} (SocketCAN, ID {can_id:#X})");
+
+        Ok(Self {
+            fd: AsyncFd::new(fd)?,
+            reassembler: Reassembler::default(),
+        })
+    }
+
+    /// Receive single reassembled IDTP frame.
+    ///
+    /// # Returns
+    /// - Reassembled IDTP frame bytes - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - Socket reading errors.
+    pub async fn recv(&mut self) -> io::Result<&[u8]> {
+        let mut raw = [0u8; libc::CANFD_MTU];
+
+        loop {
+            let len = self.read_raw(&mut raw).await?;
+
+            // Both classic and FD frames store data length at byte 4.
+            let data = raw.get(4).and_then(|&data_len| {
+                let end = CAN_HEADER_SIZE + usize::from(data_len);
+                raw.get(CAN_HEADER_SIZE..end.min(len))
+            });
+
+            if let Some(data) = data
+                && self.reassembler.push(data).is_some()
+            {
+                return Ok(&self.reassembler.data);
+            }
+        }
+    }
+
+    /// Read single raw CAN frame.
+    ///
+    /// # Parameters
+    /// - `raw` - given buffer to store raw CAN frame.
+    ///
+    /// # Returns
+    /// - Number of read bytes - in case of success.
+    /// - `Err` - otherwise.
+    async fn read_raw(&self, raw: &mut [u8]) -> io::Result<usize> {
+        loop {
+            let mut guard = self.fd.readable().await?;
+
+            let result = guard.try_io(|fd| {
+                // SAFETY: `raw` is a valid writable buffer of given length.
+                let n = unsafe {
+                    libc::read(
+                        fd.as_raw_fd(),
+                        raw.as_mut_ptr().cast(),
+                        raw.len(),
+                    )
+                };
+
+                usize::try_from(n).map_err(|_| io::Error::last_os_error())
+            });
+
+            if let Ok(result) = result {
+                return result;
+            }
+        }
+    }
+}
+
+/// Construct CAN filter accepting frames with given identifier only.
+///
+/// # Parameters
+/// - `can_id` - given CAN identifier to accept.
+///
+/// # Returns
+/// - CAN filter.
+const fn can_filter(can_id: u32) -> libc::can_filter {
+    if can_id > libc::CAN_SFF_MASK {
+        libc::can_filter {
+            can_id: (can_id & libc::CAN_EFF_MASK) | libc::CAN_EFF_FLAG,
+            can_mask: libc::CAN_EFF_MASK
+                | libc::CAN_EFF_FLAG
+                | libc::CAN_RTR_FLAG,
+        }
+    } else {
+        libc::can_filter {
+            can_id,
+            can_mask: libc::CAN_SFF_MASK
+                | libc::CAN_EFF_FLAG
+                | libc::CAN_RTR_FLAG,
+        }
+    }
+}
+
+/// Set raw CAN socket option.
+///
+/// # Parameters
+/// - `fd` - given socket descriptor.
+/// - `name` - given option name.
+/// - `value` - given option value.
+///
+/// # Returns
+/// - `Ok` - in case of success.
+/// - `Err` - otherwise.
+fn set_option<T>(fd: &OwnedFd, name: libc::c_int, value: &T) -> io::Result<()> {
+    // SAFETY: `value` points to initialized `T` of given size.
+    let result = unsafe {
+        libc::setsockopt(
+            fd.as_raw_fd(),
+            libc::SOL_CAN_RAW,
+            name,
+            std::ptr::from_ref(value).cast(),
+            socklen::<T>(),
+        )
+    };
+
+    if result < 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    Ok(())
+}
+
+/// Get size of the type as socket length.
+///
+/// # Returns
+/// - Size of the type.
+#[allow(clippy::cast_possible_truncation)]
+const fn socklen<T>() -> libc::socklen_t {
+    mem::size_of::<T>() as libc::socklen_t
+}
@@ -0,0 +1,368 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Decoded readings channels metadata.
+//!
+//! Every decoded channel has a name, a measurement unit and a range of valid
+//! values, which are shared by plots, metrics panels, log headers and
+//! exporters. Custom decoders convert vendor-specific payloads into standard
+//! ones, so that standard channels describe plugin payloads as well. Plugins
+//! reporting readings in other units or ranges override the channel info.
+
+use crate::config::{AngleUnit, ImuConfig};
+use std::f32::consts::PI;
+
+/// Decoded readings channel info.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChannelInfo {
+    /// Channel key (log column and payload field name, e.g. `acc_x`).
+    pub key: &'static str,
+    /// Human-readable channel name.
+    pub name: &'static str,
+    /// Measurement unit (empty for dimensionless values).
+    pub unit: &'static str,
+    /// Min valid value.
+    pub min: f32,
+    /// Max valid value.
+    pub max: f32,
+}
+
+impl ChannelInfo {
+    /// Construct new `ChannelInfo` object.
+    ///
+    /// # Parameters
+    /// - `key` - given channel key.
+    /// - `name` - given human-readable channel name.
+    /// - `unit` - given measurement unit.
+    /// - `min` - given min valid value.
+    /// - `max` - given max valid value.
+    ///
+    /// # Returns
+    /// - New `ChannelInfo` object.
+    #[must_use]
+    pub const fn new(
+        key: &'static str,
+        name: &'static str,
+        unit: &'static str,
+        min: f32,
+        max: f32,
+    ) -> Self {
+        Self {
+            key,
+            name,
+            unit,
+            min,
+            max,
+        }
+    }
+
+    /// Get channel label with measurement unit.
+    ///
+    /// # Returns
+    /// - Label string (e.g. `Acc X (m/s²)`).
+    #[must_use]
+    pub fn label(&self) -> String {
+        if self.unit.is_empty() {
+            self.name.to_string()
+        } else {
+            format!("{} ({})", self.name, self.unit)
+        }
+    }
+
+    /// Check whether value is within the valid range.
+    ///
+    /// # Parameters
+    /// - `value` - given channel value to check.
+    ///
+    /// # Returns
+    /// - `true` - if value is valid.
+    /// - `false` - otherwise.
+    #[must_use]
+    pub fn is_valid(&self, value: f32) -> bool {
+        (self.min..=self.max).contains(&value)
+    }
+
+    /// Get channel info displayed in the angle unit.
+    ///
+    /// Angles in radians and angular rates in deg/s are converted, other
+    /// channels are left unchanged.
+    ///
+    /// # Parameters
+    /// - `unit` - given unit of the displayed angles.
+    ///
+    /// # Returns
+    /// - Displayed channel info and factor converting channel values into
+    ///   its unit.
+    #[must_use]
+    pub fn in_angle_unit(self, unit: AngleUnit) -> (Self, f32) {
+        let (symbol, factor) = match self.unit {
+            "rad" => (unit.angle_symbol(), unit.angle_factor()),
+            "deg/s" => (unit.rate_symbol(), unit.rate_factor()),
+            _ => return (self, 1.0),
+        };
+
+        let info = Self {
+            unit: symbol,
+            min: self.min * factor,
+            max: self.max * factor,
+            ..self
+        };
+
+        (info, factor)
+    }
+}
+
+/// Accelerometer X axis channel.
+pub const ACC_X: ChannelInfo =
+    ChannelInfo::new("acc_x", "Acc X", "m/s²", -160.0, 160.0);
+
+/// Accelerometer Y axis channel.
+pub const ACC_Y: ChannelInfo =
+    ChannelInfo::new("acc_y", "Acc Y", "m/s²", -160.0, 160.0);
+
+/// Accelerometer Z axis channel.
+pub const ACC_Z: ChannelInfo =
+    ChannelInfo::new("acc_z", "Acc Z", "m/s²", -160.0, 160.0);
+
+/// Gyroscope X axis channel.
+pub const GYR_X: ChannelInfo =
+    ChannelInfo::new("gyr_x", "Gyr X", "deg/s", -2000.0, 2000.0);
+
+/// Gyroscope Y axis channel.
+pub const GYR_Y: ChannelInfo =
+    ChannelInfo::new("gyr_y", "Gyr Y", "deg/s", -2000.0, 2000.0);
+
+/// Gyroscope Z axis channel.
+pub const GYR_Z: ChannelInfo =
+    ChannelInfo::new("gyr_z", "Gyr Z", "deg/s", -2000.0, 2000.0);
+
+/// Magnetometer X axis channel.
+pub const MAG_X: ChannelInfo =
+    ChannelInfo::new("mag_x", "Mag X", "µT", -4900.0, 4900.0);
+
+/// Magnetometer Y axis channel.
+pub const MAG_Y: ChannelInfo =
+    ChannelInfo::new("mag_y", "Mag Y", "µT", -4900.0, 4900.0);
+
+/// Magnetometer Z axis channel.
+pub const MAG_Z: ChannelInfo =
+    ChannelInfo::new("mag_z", "Mag Z", "µT", -4900.0, 4900.0);
+
+/// Barometer channel.
+pub const BARO: ChannelInfo =
+    ChannelInfo::new("baro", "Pressure", "Pa", 30_000.0, 110_000.0);
+
+/// Barometric altitude channel.
+pub const ALTITUDE: ChannelInfo =
+    ChannelInfo::new("altitude", "Altitude", "m", -1000.0, 12_000.0);
+
+/// Baro-aided altitude channel.
+pub const FUSED_ALTITUDE: ChannelInfo = ChannelInfo::new(
+    "fused_altitude",
+    "Fused Altitude",
+    "m",
+    -1000.0,
+    12_000.0,
+);
+
+/// Baro-aided climb rate channel.
+pub const CLIMB_RATE: ChannelInfo =
+    ChannelInfo::new("climb_rate", "Climb Rate", "m/s", -100.0, 100.0);
+
+/// Quaternion W component channel.
+pub const Q_W: ChannelInfo = ChannelInfo::new("q_w", "Quat W", "", -1.0, 1.0);
+
+/// Quaternion X component channel.
+pub const Q_X: ChannelInfo = ChannelInfo::new("q_x", "Quat X", "", -1.0, 1.0);
+
+/// Quaternion Y component channel.
+pub const Q_Y: ChannelInfo = ChannelInfo::new("q_y", "Quat Y", "", -1.0, 1.0);
+
+/// Quaternion Z component channel.
+pub const Q_Z: ChannelInfo = ChannelInfo::new("q_z", "Quat Z", "", -1.0, 1.0);
+
+/// Rotation around X axis channel.
+pub const ROLL: ChannelInfo = ChannelInfo::new("roll", "Roll", "rad", -PI, PI);
+
+/// Rotation around Y axis channel.
+pub const PITCH: ChannelInfo =
+    ChannelInfo::new("pitch", "Pitch", "rad", -PI, PI);
+
+/// Rotation around Z axis channel.
+pub const YAW: ChannelInfo = ChannelInfo::new("yaw", "Yaw", "rad", -PI, PI);
+
+/// Linear acceleration X axis channel.
+pub const LIN_ACC_X: ChannelInfo =
+    ChannelInfo::new("lin_acc_x", "Lin Acc X", "m/s²", -160.0, 160.0);
+
+/// Linear acceleration Y axis channel.
+pub const LIN_ACC_Y: ChannelInfo =
+    ChannelInfo::new("lin_acc_y", "Lin Acc Y", "m/s²", -160.0, 160.0);
+
+/// Linear acceleration Z axis channel.
+pub const LIN_ACC_Z: ChannelInfo =
+    ChannelInfo::new("lin_acc_z", "Lin Acc Z", "m/s²", -160.0, 160.0);
+
+/// Dead-reckoning velocity X axis channel.
+pub const VEL_X: ChannelInfo =
+    ChannelInfo::new("vel_x", "Vel X", "m/s", -1000.0, 1000.0);
+
+/// Dead-reckoning velocity Y axis channel.
+pub const VEL_Y: ChannelInfo =
+    ChannelInfo::new("vel_y", "Vel Y", "m/s", -1000.0, 1000.0);
+
+/// Dead-reckoning velocity Z axis channel.
+pub const VEL_Z: ChannelInfo =
+    ChannelInfo::new("vel_z", "Vel Z", "m/s", -1000.0, 1000.0);
+
+/// Dead-reckoning position X axis channel.
+pub const POS_X: ChannelInfo =
+    ChannelInfo::new("pos_x", "Pos X", "m", f32::NEG_INFINITY, f32::INFINITY);
+
+/// Dead-reckoning position Y axis channel.
+pub const POS_Y: ChannelInfo =
+    ChannelInfo::new("pos_y", "Pos Y", "m", f32::NEG_INFINITY, f32::INFINITY);
+
+/// Dead-reckoning position Z axis channel.
+pub const POS_Z: ChannelInfo =
+    ChannelInfo::new("pos_z", "Pos Z", "m", f32::NEG_INFINITY, f32::INFINITY);
+
+/// Channels of all standard payloads and estimated attitude.
+pub const STANDARD_CHANNELS: [ChannelInfo; 29] = [
+    ACC_X,
+    ACC_Y,
+    ACC_Z,
+    GYR_X,
+    GYR_Y,
+    GYR_Z,
+    MAG_X,
+    MAG_Y,
+    MAG_Z,
+    BARO,
+    ALTITUDE,
+    FUSED_ALTITUDE,
+    CLIMB_RATE,
+    Q_W,
+    Q_X,
+    Q_Y,
+    Q_Z,
+    ROLL,
+    PITCH,
+    YAW,
+    LIN_ACC_X,
+    LIN_ACC_Y,
+    LIN_ACC_Z,
+    VEL_X,
+    VEL_Y,
+    VEL_Z,
+    POS_X,
+    POS_Y,
+    POS_Z,
+];
+
+/// Registry of the decoded channels info.
+#[derive(Debug, Clone)]
+pub struct ChannelRegistry {
+    /// Registered channels.
+    channels: Vec<ChannelInfo>,
+}
+
+impl Default for ChannelRegistry {
+    /// Construct registry of the standard channels.
+    ///
+    /// # Returns
+    /// - New `ChannelRegistry` object.
+    fn default() -> Self {
+        Self {
+            channels: STANDARD_CHANNELS.to_vec(),
+        }
+    }
+}
+
+impl ChannelRegistry {
+    /// Register channel info.
+    ///
+    /// Registering channel with the same key again replaces the previous
+    /// info.
+    ///
+    /// # Parameters
+    /// - `info` - given channel info to register.
+    pub fn register(&mut self, info: ChannelInfo) {
+        match self.channels.iter_mut().find(|c| c.key == info.key) {
+            Some(channel) => *channel = info,
+            None => self.channels.push(info),
+        }
+    }
+
+    /// Limit valid ranges to the configured full-scale ranges.
+    ///
+    /// Full-scale ranges are given in units of the received readings and
+    /// are converted into the standard units of the channels.
+    ///
+    /// # Parameters
+    /// - `imu` - given IMU configurations.
+    ///
+    /// # Returns
+    /// - Updated `ChannelRegistry` object.
+    #[must_use]
+    pub fn with_full_scale(mut self, imu: &ImuConfig) -> Self {
+        for channel in &mut self.channels {
+            let units = &imu.units;
+            let range = match channel.key {
+                "acc_x" | "acc_y" | "acc_z" => {
+                    imu.acc_range * units.acc.factor()
+                }
+                "gyr_x" | "gyr_y" | "gyr_z" => {
+                    imu.gyr_range * units.gyr.factor()
+                }
+                "mag_x" | "mag_y" | "mag_z" => {
+                    imu.mag_range * units.mag.factor()
+                }
+                _ => continue,
+            };
+
+            // Zero range means it is not configured.
+            if range > 0.0 {
+                channel.min = -range;
+                channel.max = range;
+            }
+        }
+
+        self
+    }
+
+    /// Get channel info.
+    ///
+    /// # Parameters
+    /// - `key` - given channel key.
+    ///
+    /// # Returns
+    /// - Channel info - if channel is registered.
+    /// - `None` - otherwise.
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&ChannelInfo> {
+        self.channels.iter().find(|c| c.key == key)
+    }
+
+    /// Get channel info or info without unit and range limits.
+    ///
+    /// # Parameters
+    /// - `key` - given channel key.
+    ///
+    /// # Returns
+    /// - Registered channel info or info named after the key.
+    #[must_use]
+    pub fn info(&self, key: &'static str) -> ChannelInfo {
+        self.get(key).copied().unwrap_or_else(|| {
+            ChannelInfo::new(key, key, "", f32::NEG_INFINITY, f32::INFINITY)
+        })
+    }
+
+    /// Get registered channels.
+    ///
+    /// # Returns
+    /// - Iterator over registered channels info.
+    pub fn iter(&self) -> impl Iterator<Item = &ChannelInfo> {
+        self.channels.iter()
+    }
+}
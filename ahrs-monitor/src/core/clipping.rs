@@ -0,0 +1,180 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Sensor dynamic range (clipping) detection.
+
+use crate::{config::ImuConfig, core::StandardPayload};
+use serde::{Deserialize, Serialize};
+use tsilna_nav::math::na::Vector3;
+
+/// Sensors whose readings sit at or near their full-scale limits.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub struct Clipping {
+    /// Flag that shows whether accelerometer readings are clipped.
+    pub acc: bool,
+    /// Flag that shows whether gyroscope readings are clipped.
+    pub gyr: bool,
+    /// Flag that shows whether magnetometer readings are clipped.
+    pub mag: bool,
+}
+
+impl Clipping {
+    /// Check whether any sensor readings are clipped.
+    ///
+    /// # Returns
+    /// - `true` - if at least one sensor is clipped.
+    /// - `false` - otherwise.
+    #[must_use]
+    #[inline]
+    pub const fn any(&self) -> bool {
+        self.acc || self.gyr || self.mag
+    }
+}
+
+/// Single sensor clipping interval tracker.
+#[derive(Debug, Default)]
+struct SensorClipping {
+    /// Sensor name for logging.
+    name: &'static str,
+    /// Sensor full-scale range.
+    range: f32,
+    /// Sensor-local time of the current interval start in microseconds.
+    started_at: Option<u32>,
+    /// Number of clipped samples in the current interval.
+    samples: usize,
+}
+
+impl SensorClipping {
+    /// Construct new `SensorClipping` object.
+    ///
+    /// # Parameters
+    /// - `name` - given sensor name.
+    /// - `range` - given sensor full-scale range.
+    ///
+    /// # Returns
+    /// - New `SensorClipping` object.
+    const fn new(name: &'static str, range: f32) -> Self {
+        Self {
+            name,
+            range,
+            started_at: None,
+            samples: 0,
+        }
+    }
+
+    /// Update clipping interval state.
+    ///
+    /// # Parameters
+    /// - `readings` - given sensor readings to handle.
+    /// - `threshold` - given fraction of full-scale range treated as clipping.
+    /// - `timestamp` - given sensor-local time in microseconds.
+    ///
+    /// # Returns
+    /// - `true` - if readings are clipped.
+    /// - `false` - otherwise.
+    fn update(
+        &mut self,
+        readings: Option<Vector3<f32>>,
+        threshold: f32,
+        timestamp: u32,
+    ) -> bool {
+        let is_clipped = self.range > 0.0
+            && readings.is_some_and(|v| v.amax() >= self.range * threshold);
+
+        match (is_clipped, self.started_at) {
+            (true, None) => {
+                log::warn!(
+                    "{} clipping started: readings reached ±{} full-scale",
+                    self.name,
+                    self.range
+                );
+                self.started_at = Some(timestamp);
+                self.samples = 1;
+            }
+            (true, Some(_)) => self.samples += 1,
+            (false, Some(start)) => {
+                let duration_us = timestamp.wrapping_sub(start);
+
+                log::warn!(
+                    "{} clipping ended: {} samples over {} us",
+                    self.name,
+                    self.samples,
+                    duration_us
+                );
+                self.started_at = None;
+                self.samples = 0;
+            }
+            (false, None) => {}
+        }
+
+        is_clipped
+    }
+}
+
+/// Detector of sensor readings clipped by the full-scale range.
+#[derive(Debug, Default)]
+pub struct ClippingDetector {
+    /// Accelerometer clipping tracker.
+    acc: SensorClipping,
+    /// Gyroscope clipping tracker.
+    gyr: SensorClipping,
+    /// Magnetometer clipping tracker.
+    mag: SensorClipping,
+    /// Fraction of the full-scale range treated as clipping.
+    threshold: f32,
+}
+
+impl ClippingDetector {
+    /// Construct new `ClippingDetector` object.
+    ///
+    /// # Parameters
+    /// - `cfg` - given IMU configurations to handle.
+    ///
+    /// # Returns
+    /// - New `ClippingDetector` object.
+    #[must_use]
+    pub const fn new(cfg: &ImuConfig) -> Self {
+        Self {
+            acc: SensorClipping::new("Accelerometer", cfg.acc_range),
+            gyr: SensorClipping::new("Gyroscope", cfg.gyr_range),
+            mag: SensorClipping::new("Magnetometer", cfg.mag_range),
+            threshold: cfg.clipping_threshold,
+        }
+    }
+
+    /// Detect clipped sensor readings.
+    ///
+    /// # Parameters
+    /// - `payload` - given frame payload to handle.
+    /// - `timestamp` - given sensor-local time in microseconds.
+    ///
+    /// # Returns
+    /// - Sensors whose readings are clipped.
+    pub fn detect(
+        &mut self,
+        payload: Option<&StandardPayload>,
+        timestamp: u32,
+    ) -> Clipping {
+        let threshold = self.threshold;
+
+        Clipping {
+            acc: self.acc.update(
+                payload.and_then(StandardPayload::acc),
+                threshold,
+                timestamp,
+            ),
+            gyr: self.gyr.update(
+                payload.and_then(StandardPayload::gyr),
+                threshold,
+                timestamp,
+            ),
+            mag: self.mag.update(
+                payload.and_then(StandardPayload::mag),
+                threshold,
+                timestamp,
+            ),
+        }
+    }
+}
@@ -0,0 +1,515 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Comparison of two recorded sessions.
+
+use crate::{
+    core::channel::{self, ChannelInfo},
+    logger::{LogRecord, read_records},
+};
+use serde::Serialize;
+use std::{fmt::Write, path::Path};
+
+/// Single log record channel.
+#[derive(Debug, Clone, Copy)]
+pub struct Channel {
+    /// Channel info.
+    pub info: ChannelInfo,
+    /// Channel value getter.
+    pub value: fn(&LogRecord) -> Option<f32>,
+}
+
+/// Log record channels to compare.
+pub const CHANNELS: [Channel; 19] = [
+    Channel {
+        info: channel::ACC_X,
+        value: |r| r.acc_x,
+    },
+    Channel {
+        info: channel::ACC_Y,
+        value: |r| r.acc_y,
+    },
+    Channel {
+        info: channel::ACC_Z,
+        value: |r| r.acc_z,
+    },
+    Channel {
+        info: channel::GYR_X,
+        value: |r| r.gyr_x,
+    },
+    Channel {
+        info: channel::GYR_Y,
+        value: |r| r.gyr_y,
+    },
+    Channel {
+        info: channel::GYR_Z,
+        value: |r| r.gyr_z,
+    },
+    Channel {
+        info: channel::MAG_X,
+        value: |r| r.mag_x,
+    },
+    Channel {
+        info: channel::MAG_Y,
+        value: |r| r.mag_y,
+    },
+    Channel {
+        info: channel::MAG_Z,
+        value: |r| r.mag_z,
+    },
+    Channel {
+        info: channel::BARO,
+        value: |r| r.pressure,
+    },
+    Channel {
+        info: channel::ALTITUDE,
+        value: |r| r.altitude,
+    },
+    Channel {
+        info: channel::FUSED_ALTITUDE,
+        value: |r| r.fused_altitude,
+    },
+    Channel {
+        info: channel::CLIMB_RATE,
+        value: |r| r.climb_rate,
+    },
+    Channel {
+        info: channel::ROLL,
+        value: |r| Some(r.roll),
+    },
+    Channel {
+        info: channel::PITCH,
+        value: |r| Some(r.pitch),
+    },
+    Channel {
+        info: channel::YAW,
+        value: |r| Some(r.yaw),
+    },
+    Channel {
+        info: channel::LIN_ACC_X,
+        value: |r| r.lin_acc_x,
+    },
+    Channel {
+        info: channel::LIN_ACC_Y,
+        value: |r| r.lin_acc_y,
+    },
+    Channel {
+        info: channel::LIN_ACC_Z,
+        value: |r| r.lin_acc_z,
+    },
+];
+
+/// Recorded session.
+#[derive(Debug, Default)]
+pub struct Session {
+    /// Session name (log file name).
+    pub name: String,
+    /// Session log records.
+    pub records: Vec<LogRecord>,
+}
+
+impl Session {
+    /// Load recorded session from the log file.
+    ///
+    /// # Parameters
+    /// - `path` - given path to the log file.
+    ///
+    /// # Returns
+    /// - Loaded session - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - Log file reading errors.
+    pub fn load(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let records = read_records(path)?;
+        let name = path.file_name().map_or_else(
+            || path.to_string_lossy().into_owned(),
+            |name| name.to_string_lossy().into_owned(),
+        );
+
+        Ok(Self { name, records })
+    }
+
+    /// Get sensor-local time of the first record.
+    ///
+    /// # Returns
+    /// - First record timestamp in microseconds.
+    #[must_use]
+    pub fn start_timestamp(&self) -> u32 {
+        self.records.first().map_or(0, |r| r.timestamp)
+    }
+
+    /// Get session duration.
+    ///
+    /// # Returns
+    /// - Session duration in seconds.
+    #[must_use]
+    pub fn duration(&self) -> f64 {
+        self.records.last().map_or(0.0, |r| {
+            f64::from(r.timestamp.wrapping_sub(self.start_timestamp())) / 1e6
+        })
+    }
+
+    /// Get channel values along with the time since session start.
+    ///
+    /// # Parameters
+    /// - `channel` - given channel to get.
+    ///
+    /// # Returns
+    /// - List of (time in seconds, value) pairs.
+    #[must_use]
+    pub fn channel_points(&self, channel: &Channel) -> Vec<[f64; 2]> {
+        let start = self.start_timestamp();
+
+        self.records
+            .iter()
+            .filter_map(|r| {
+                let time = f64::from(r.timestamp.wrapping_sub(start)) / 1e6;
+                (channel.value)(r).map(|v| [time, f64::from(v)])
+            })
+            .collect()
+    }
+}
+
+/// Descriptive statistics of channel values.
+#[derive(Debug, Default, Clone, Copy, Serialize)]
+pub struct ChannelStats {
+    /// Number of values.
+    pub count: usize,
+    /// Mean value.
+    pub mean: f64,
+    /// Standard deviation.
+    pub std_dev: f64,
+    /// Minimum value.
+    pub min: f64,
+    /// Maximum value.
+    pub max: f64,
+}
+
+impl ChannelStats {
+    /// Calculate channel values statistics.
+    ///
+    /// # Parameters
+    /// - `values` - given channel values to handle.
+    ///
+    /// # Returns
+    /// - Channel values statistics - in case of success.
+    /// - `None` - if there are no values.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn new(values: impl Iterator<Item = f32>) -> Option<Self> {
+        let mut count: usize = 0;
+        let mut mean = 0.0;
+        let mut m2 = 0.0;
+        let mut min = f64::INFINITY;
+        let mut max = f64::NEG_INFINITY;
+
+        // Welford's online algorithm for numerically stable variance.
+        for value in values.map(f64::from) {
+            count += 1;
+            let delta = value - mean;
+            mean += delta / count as f64;
+            m2 += delta * (value - mean);
+            min = min.min(value);
+            max = max.max(value);
+        }
+
+        if count == 0 {
+            return None;
+        }
+
+        let variance = m2 / count as f64;
+
+        Some(Self {
+            count,
+            mean,
+            std_dev: variance.sqrt(),
+            min,
+            max,
+        })
+    }
+}
+
+/// Comparison of single channel between two sessions.
+#[derive(Debug, Clone, Copy)]
+pub struct ChannelComparison {
+    /// Compared channel.
+    pub channel: Channel,
+    /// Channel statistics of the first session.
+    pub a: Option<ChannelStats>,
+    /// Channel statistics of the second session.
+    pub b: Option<ChannelStats>,
+    /// Root mean square of differences between aligned samples.
+    pub rms_diff: Option<f64>,
+}
+
+impl ChannelComparison {
+    /// Get difference between the mean values (second - first).
+    ///
+    /// # Returns
+    /// - Mean values difference - in case of success.
+    /// - `None` - if channel is missing in one of the sessions.
+    #[must_use]
+    pub fn mean_delta(&self) -> Option<f64> {
+        Some(self.b?.mean - self.a?.mean)
+    }
+
+    /// Get difference between the standard deviations (second - first).
+    ///
+    /// # Returns
+    /// - Standard deviations difference - in case of success.
+    /// - `None` - if channel is missing in one of the sessions.
+    #[must_use]
+    pub fn std_dev_delta(&self) -> Option<f64> {
+        Some(self.b?.std_dev - self.a?.std_dev)
+    }
+}
+
+/// Attitude error between aligned samples of two sessions.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AttitudeError {
+    /// Mean angular error in degrees.
+    pub mean: f64,
+    /// Root mean square of angular error in degrees.
+    pub rms: f64,
+    /// Max angular error in degrees.
+    pub max: f64,
+}
+
+/// Comparison report of two recorded sessions.
+#[derive(Debug, Default)]
+pub struct SessionComparison {
+    /// Name of the first session.
+    pub a_name: String,
+    /// Name of the second session.
+    pub b_name: String,
+    /// Number of records in the first session.
+    pub a_records: usize,
+    /// Number of records in the second session.
+    pub b_records: usize,
+    /// Duration of the first session in seconds.
+    pub a_duration: f64,
+    /// Duration of the second session in seconds.
+    pub b_duration: f64,
+    /// Number of samples aligned by the sensor-local time.
+    pub aligned: usize,
+    /// Per-channel comparison.
+    pub channels: Vec<ChannelComparison>,
+    /// Attitude error (only if sessions are aligned).
+    pub attitude: Option<AttitudeError>,
+}
+
+impl SessionComparison {
+    /// Compare two recorded sessions.
+    ///
+    /// # Parameters
+    /// - `a` - given first (reference) session.
+    /// - `b` - given second session.
+    /// - `tolerance_us` - given max timestamps difference of aligned samples.
+    ///
+    /// # Returns
+    /// - Sessions comparison report.
+    #[must_use]
+    pub fn new(a: &Session, b: &Session, tolerance_us: u32) -> Self {
+        let pairs = align(&a.records, &b.records, tolerance_us);
+
+        let channels = CHANNELS
+            .iter()
+            .map(|channel| ChannelComparison {
+                channel: *channel,
+                a: ChannelStats::new(
+                    a.records.iter().filter_map(channel.value),
+                ),
+                b: ChannelStats::new(
+                    b.records.iter().filter_map(channel.value),
+                ),
+                rms_diff: rms(pairs.iter().filter_map(|(ra, rb)| {
+                    Some(f64::from((channel.value)(rb)? - (channel.value)(ra)?))
+                })),
+            })
+            .collect();
+
+        let errors: Vec<f64> = pairs
+            .iter()
+            .map(|(ra, rb)| attitude_error_deg(ra, rb))
+            .collect();
+
+        let attitude = rms(errors.iter().copied()).map(|rms| {
+            #[allow(clippy::cast_precision_loss)]
+            let mean = errors.iter().sum::<f64>() / errors.len() as f64;
+
+            AttitudeError {
+                mean,
+                rms,
+                max: errors.iter().copied().fold(0.0, f64::max),
+            }
+        });
+
+        Self {
+            a_name: a.name.clone(),
+            b_name: b.name.clone(),
+            a_records: a.records.len(),
+            b_records: b.records.len(),
+            a_duration: a.duration(),
+            b_duration: b.duration(),
+            aligned: pairs.len(),
+            channels,
+            attitude,
+        }
+    }
+
+    /// Render comparison report in Markdown.
+    ///
+    /// # Returns
+    /// - Comparison report in Markdown representation.
+    #[must_use]
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::new();
+        let fmt = |v: Option<f64>| {
+            v.map_or_else(|| "-".into(), |v| format!("{v:.4}"))
+        };
+
+        let _ = writeln!(out, "# Session comparison report\n");
+        let _ = writeln!(out, "| | Session A | Session B |");
+        let _ = writeln!(out, "|---|---|---|");
+        let _ = writeln!(out, "| File | {} | {} |", self.a_name, self.b_name);
+        let _ = writeln!(
+            out,
+            "| Records | {} | {} |",
+            self.a_records, self.b_records
+        );
+        let _ = writeln!(
+            out,
+            "| Duration, s | {:.3} | {:.3} |",
+            self.a_duration, self.b_duration
+        );
+        let _ = writeln!(out, "\nAligned samples: {}\n", self.aligned);
+
+        let _ = writeln!(out, "## Channels\n");
+        let _ = writeln!(
+            out,
+            "| Channel | Mean A | Mean B | Δ Mean | Std A | Std B | Δ Std | \
+             RMS diff |"
+        );
+        let _ = writeln!(out, "|---|---|---|---|---|---|---|---|");
+
+        for c in self
+            .channels
+            .iter()
+            .filter(|c| c.a.is_some() || c.b.is_some())
+        {
+            let _ = writeln!(
+                out,
+                "| {} ({}) | {} | {} | {} | {} | {} | {} | {} |",
+                c.channel.info.name,
+                c.channel.info.unit,
+                fmt(c.a.map(|s| s.mean)),
+                fmt(c.b.map(|s| s.mean)),
+                fmt(c.mean_delta()),
+                fmt(c.a.map(|s| s.std_dev)),
+                fmt(c.b.map(|s| s.std_dev)),
+                fmt(c.std_dev_delta()),
+                fmt(c.rms_diff),
+            );
+        }
+
+        let _ = writeln!(out, "\n## Attitude error\n");
+
+        if let Some(e) = self.attitude {
+            let _ = writeln!(out, "- Mean: {:.3}°", e.mean);
+            let _ = writeln!(out, "- RMS: {:.3}°", e.rms);
+            let _ = writeln!(out, "- Max: {:.3}°", e.max);
+        } else {
+            let _ = writeln!(out, "Sessions are not aligned in time.");
+        }
+
+        out
+    }
+}
+
+/// Get max timestamps difference of aligned samples.
+///
+/// # Parameters
+/// - `sample_rate` - given IMU sample rate in Hz.
+///
+/// # Returns
+/// - Half of the sample period in microseconds.
+#[must_use]
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn alignment_tolerance_us(sample_rate: f32) -> u32 {
+    (500_000.0 / sample_rate.max(1.0)) as u32
+}
+
+/// Pair samples of two sessions with the nearest sensor-local time.
+///
+/// # Parameters
+/// - `a` - given log records of the first session.
+/// - `b` - given log records of the second session.
+/// - `tolerance_us` - given max timestamps difference of aligned samples.
+///
+/// # Returns
+/// - List of aligned log record pairs.
+fn align<'a>(
+    a: &'a [LogRecord],
+    b: &'a [LogRecord],
+    tolerance_us: u32,
+) -> Vec<(&'a LogRecord, &'a LogRecord)> {
+    let mut sorted: Vec<&LogRecord> = b.iter().collect();
+    sorted.sort_by_key(|r| r.timestamp);
+
+    a.iter()
+        .filter_map(|ra| {
+            let pos = sorted.partition_point(|rb| rb.timestamp < ra.timestamp);
+
+            // Choosing the nearest of the two neighbouring records.
+            [pos.checked_sub(1), Some(pos)]
+                .into_iter()
+                .flatten()
+                .filter_map(|i| sorted.get(i).copied())
+                .min_by_key(|rb| rb.timestamp.abs_diff(ra.timestamp))
+                .filter(|rb| {
+                    rb.timestamp.abs_diff(ra.timestamp) <= tolerance_us
+                })
+                .map(|rb| (ra, rb))
+        })
+        .collect()
+}
+
+/// Calculate root mean square of values.
+///
+/// # Parameters
+/// - `values` - given values to handle.
+///
+/// # Returns
+/// - Root mean square - in case of success.
+/// - `None` - if there are no values.
+#[allow(clippy::cast_precision_loss)]
+fn rms(values: impl Iterator<Item = f64>) -> Option<f64> {
+    let (count, sum_sq) = values.fold((0usize, 0.0), |(count, sum), v| {
+        (count + 1, v.mul_add(v, sum))
+    });
+
+    (count > 0).then(|| (sum_sq / count as f64).sqrt())
+}
+
+/// Calculate angle between attitudes of two log records.
+///
+/// # Parameters
+/// - `a` - given first log record.
+/// - `b` - given second log record.
+///
+/// # Returns
+/// - Angular error in degrees.
+fn attitude_error_deg(a: &LogRecord, b: &LogRecord) -> f64 {
+    let dot = f64::from(a.q_w).mul_add(
+        f64::from(b.q_w),
+        f64::from(a.q_x).mul_add(
+            f64::from(b.q_x),
+            f64::from(a.q_y)
+                .mul_add(f64::from(b.q_y), f64::from(a.q_z) * f64::from(b.q_z)),
+        ),
+    );
+
+    // Quaternions q and -q represent the same attitude.
+    2.0 * dot.abs().min(1.0).acos().to_degrees()
+}
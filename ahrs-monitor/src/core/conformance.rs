@@ -0,0 +1,285 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! IDTP protocol conformance checker.
+//!
+//! Checker observes the stream of a connected device and verifies that it
+//! follows the IDTP specification: timestamps grow with sequence numbers,
+//! sequence numbers are continuous, frame sizes match declared payload
+//! lengths and integrity checks pass.
+
+use crate::{
+    core::length::LengthAnomalies,
+    model::{BadPackets, FrameContext},
+};
+use indtp::{Header, types::Packable};
+use std::fmt::Write;
+
+/// Min number of valid frames required for a meaningful report.
+const MIN_VALID_FRAMES: usize = 100;
+
+/// Max ratio of frames failed CRC or MAC verification.
+const MAX_INTEGRITY_FAILURE_RATE: f64 = 0.001;
+
+/// Max ratio of frames lost according to sequence gaps.
+const MAX_LOSS_RATE: f64 = 0.01;
+
+/// Result of a single conformance check.
+#[derive(Debug, Clone)]
+pub struct CheckResult {
+    /// Check name.
+    pub name: &'static str,
+    /// Flag whether the check passed.
+    pub passed: bool,
+    /// Measured values supporting the verdict.
+    pub details: String,
+}
+
+/// Conformance report of the device stream.
+#[derive(Debug, Clone, Default)]
+pub struct ConformanceReport {
+    /// Total number of received packets.
+    pub total_packets: usize,
+    /// Number of valid frames.
+    pub valid_frames: usize,
+    /// Results of all checks.
+    pub checks: Vec<CheckResult>,
+}
+
+impl ConformanceReport {
+    /// Check whether all checks passed.
+    ///
+    /// # Returns
+    /// - `true` - if stream conforms to the specification.
+    /// - `false` - otherwise.
+    #[must_use]
+    pub fn passed(&self) -> bool {
+        self.checks.iter().all(|check| check.passed)
+    }
+
+    /// Format report as Markdown.
+    ///
+    /// # Returns
+    /// - Report in Markdown format.
+    #[must_use]
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::new();
+        let verdict = if self.passed() { "PASS" } else { "FAIL" };
+
+        let _ = writeln!(out, "# IDTP conformance report\n");
+        let _ = writeln!(out, "- Verdict: **{verdict}**");
+        let _ = writeln!(out, "- Received packets: {}", self.total_packets);
+        let _ = writeln!(out, "- Valid frames: {}\n", self.valid_frames);
+        let _ = writeln!(out, "| Check | Result | Details |");
+        let _ = writeln!(out, "|---|---|---|");
+
+        for check in &self.checks {
+            let result = if check.passed { "pass" } else { "FAIL" };
+            let _ = writeln!(
+                out,
+                "| {} | {result} | {} |",
+                check.name, check.details
+            );
+        }
+
+        out
+    }
+}
+
+/// Observer of the device stream collecting conformance statistics.
+#[derive(Debug, Default)]
+pub struct ConformanceChecker {
+    /// Total number of received packets.
+    total_packets: usize,
+    /// Number of rejected packets by failure reason.
+    bad_packets: BadPackets,
+    /// Number of frame length anomalies by category.
+    length_anomalies: LengthAnomalies,
+    /// Number of valid frames.
+    valid_frames: usize,
+    /// Sequence number and timestamp of the newest valid frame.
+    last: Option<(u16, u32)>,
+    /// Number of timestamps not following sequence numbers order.
+    timestamp_violations: usize,
+    /// Number of frames lost according to sequence gaps.
+    lost: usize,
+    /// Number of frames received out of order.
+    reordered: usize,
+    /// Number of frames whose size does not match declared payload length.
+    size_mismatches: usize,
+    /// Number of connection losses.
+    disconnects: usize,
+}
+
+impl ConformanceChecker {
+    /// Handle received frame.
+    ///
+    /// # Parameters
+    /// - `frame_ctx` - given received frame context to handle.
+    pub fn update(&mut self, frame_ctx: &FrameContext) {
+        self.total_packets = frame_ctx.total_packets;
+        self.bad_packets = frame_ctx.bad_packets;
+        self.length_anomalies = frame_ctx.length_anomalies;
+
+        let Some(frame) = &frame_ctx.frame else {
+            return;
+        };
+
+        if !frame_ctx.is_valid {
+            return;
+        }
+
+        self.valid_frames += 1;
+
+        let declared_size = Header::len()
+            + usize::from(frame.header.payload_len.get())
+            + frame.trailer.len();
+
+        if frame.size != declared_size {
+            self.size_mismatches += 1;
+        }
+
+        let sequence = frame.header.sequence.get();
+        let timestamp = frame_ctx.timestamp;
+
+        let Some((last_sequence, last_timestamp)) = self.last else {
+            self.last = Some((sequence, timestamp));
+            return;
+        };
+
+        // Signed distances handle wrap-around of both counters.
+        #[allow(clippy::cast_possible_wrap)]
+        let sequence_delta = sequence.wrapping_sub(last_sequence) as i16;
+        #[allow(clippy::cast_possible_wrap)]
+        let timestamp_delta = timestamp.wrapping_sub(last_timestamp) as i32;
+
+        if sequence_delta > 0 {
+            self.lost += usize::from(sequence_delta.unsigned_abs() - 1);
+
+            if timestamp_delta <= 0 {
+                self.timestamp_violations += 1;
+            }
+
+            self.last = Some((sequence, timestamp));
+        } else {
+            // Late frame fills one of the counted gaps.
+            self.reordered += 1;
+            self.lost = self.lost.saturating_sub(1);
+
+            if timestamp_delta >= 0 {
+                self.timestamp_violations += 1;
+            }
+        }
+    }
+
+    /// Handle connection status update.
+    ///
+    /// # Parameters
+    /// - `connected` - given new connection status.
+    pub const fn update_connection_status(&mut self, connected: bool) {
+        if !connected {
+            self.disconnects += 1;
+        }
+
+        // Sequence numbering may restart after reconnect.
+        self.last = None;
+    }
+
+    /// Build conformance report.
+    ///
+    /// # Returns
+    /// - Conformance report of the observed stream.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn report(&self) -> ConformanceReport {
+        let bad = &self.bad_packets;
+        let rate = |count: usize, total: usize| {
+            if total == 0 {
+                0.0
+            } else {
+                count as f64 / total as f64
+            }
+        };
+
+        let integrity_rate = rate(bad.integrity, self.total_packets);
+        let loss_rate = rate(self.lost, self.valid_frames + self.lost);
+        let out_of_order = self.reordered + bad.duplicate + bad.stale;
+
+        let checks = vec![
+            CheckResult {
+                name: "Stream activity",
+                passed: self.valid_frames >= MIN_VALID_FRAMES,
+                details: format!(
+                    "{} valid frames (min {MIN_VALID_FRAMES}), {} disconnects",
+                    self.valid_frames, self.disconnects
+                ),
+            },
+            CheckResult {
+                name: "Header validity",
+                passed: bad.header == 0,
+                details: format!("{} malformed headers", bad.header),
+            },
+            CheckResult {
+                name: "Payload length",
+                passed: bad.length == 0
+                    && self.size_mismatches == 0
+                    && self.length_anomalies.payload == 0,
+                details: format!(
+                    "{} truncated, {} size mismatches, {} payload layout \
+                     mismatches",
+                    bad.length,
+                    self.size_mismatches,
+                    self.length_anomalies.payload
+                ),
+            },
+            CheckResult {
+                name: "CRC/MAC correctness",
+                passed: integrity_rate <= MAX_INTEGRITY_FAILURE_RATE,
+                details: format!(
+                    "{:.3}% correct ({} failures, max {:.1}%)",
+                    (1.0 - integrity_rate) * 100.0,
+                    bad.integrity,
+                    MAX_INTEGRITY_FAILURE_RATE * 100.0
+                ),
+            },
+            CheckResult {
+                name: "Decryption",
+                passed: bad.decryption == 0,
+                details: format!("{} failures", bad.decryption),
+            },
+            CheckResult {
+                name: "Payload decoding",
+                passed: bad.payload == 0,
+                details: format!("{} undecodable samples", bad.payload),
+            },
+            CheckResult {
+                name: "Sequence continuity",
+                passed: out_of_order == 0 && loss_rate <= MAX_LOSS_RATE,
+                details: format!(
+                    "{} lost ({:.2}%, max {:.1}%), {} reordered, {} duplicates, \
+                     {} stale",
+                    self.lost,
+                    loss_rate * 100.0,
+                    MAX_LOSS_RATE * 100.0,
+                    self.reordered,
+                    bad.duplicate,
+                    bad.stale
+                ),
+            },
+            CheckResult {
+                name: "Timestamp monotonicity",
+                passed: self.timestamp_violations == 0,
+                details: format!(
+                    "{} timestamps out of sequence order",
+                    self.timestamp_violations
+                ),
+            },
+        ];
+
+        ConformanceReport {
+            total_packets: self.total_packets,
+            valid_frames: self.valid_frames,
+            checks,
+        }
+    }
+}
@@ -0,0 +1,104 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Dead-reckoning velocity and position estimation.
+//!
+//! Linear acceleration is rotated into Earth frame by the estimated attitude
+//! and integrated into velocity and position. Integration errors grow
+//! quickly, so that velocity is reset to zero whenever the device is still
+//! for a while (zero-velocity update, ZUPT).
+
+use crate::config::DeadReckoningConfig;
+use serde::{Deserialize, Serialize};
+use tsilna_nav::math::{Quat32, na::Vector3};
+
+/// Dead-reckoning navigation state.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq)]
+pub struct NavState {
+    /// Velocity (x, y, z) in Earth frame in meters per second.
+    pub velocity: [f32; 3],
+    /// Position (x, y, z) in Earth frame relative to the start in meters.
+    pub position: [f32; 3],
+    /// Indicator whether velocity was reset by zero-velocity update.
+    pub is_still: bool,
+}
+
+/// Dead-reckoning velocity and position estimator.
+#[derive(Debug, Clone)]
+pub struct DeadReckoning {
+    /// Dead-reckoning configurations.
+    config: DeadReckoningConfig,
+    /// Velocity in Earth frame in m/s.
+    velocity: Vector3<f32>,
+    /// Position in Earth frame in m.
+    position: Vector3<f32>,
+    /// Duration of the current stillness interval in seconds.
+    still_time: f32,
+}
+
+impl DeadReckoning {
+    /// Construct new `DeadReckoning` object.
+    ///
+    /// # Parameters
+    /// - `config` - given dead-reckoning configurations.
+    ///
+    /// # Returns
+    /// - New `DeadReckoning` object.
+    #[must_use]
+    pub fn new(config: DeadReckoningConfig) -> Self {
+        Self {
+            config,
+            velocity: Vector3::zeros(),
+            position: Vector3::zeros(),
+            still_time: 0.0,
+        }
+    }
+
+    /// Integrate linear acceleration into velocity and position.
+    ///
+    /// # Parameters
+    /// - `q` - given estimated attitude.
+    /// - `linear_acc` - given linear acceleration in sensor frame in m/s².
+    /// - `gyr` - given vector of gyroscope readings in degrees per second.
+    /// - `dt` - given time step since last update in seconds.
+    ///
+    /// # Returns
+    /// - Updated navigation state.
+    pub fn update(
+        &mut self,
+        q: &Quat32,
+        linear_acc: Vector3<f32>,
+        gyr: Vector3<f32>,
+        dt: f32,
+    ) -> NavState {
+        let is_still = linear_acc.norm() <= self.config.acc_threshold
+            && gyr.norm() <= self.config.gyr_threshold;
+
+        self.still_time = if is_still { self.still_time + dt } else { 0.0 };
+
+        let is_zupt = self.still_time >= self.config.still_time;
+
+        if is_zupt {
+            self.velocity = Vector3::zeros();
+        } else {
+            let velocity = self.velocity + (q * linear_acc) * dt;
+
+            // Trapezoidal integration of the velocity.
+            self.position += (self.velocity + velocity) * (0.5 * dt);
+            self.velocity = velocity;
+        }
+
+        NavState {
+            velocity: self.velocity.into(),
+            position: self.position.into(),
+            is_still: is_zupt,
+        }
+    }
+
+    /// Reset velocity and position to zero.
+    pub fn reset(&mut self) {
+        self.velocity = Vector3::zeros();
+        self.position = Vector3::zeros();
+        self.still_time = 0.0;
+    }
+}
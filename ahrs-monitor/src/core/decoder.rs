@@ -0,0 +1,259 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Custom payload decoders for vendor-specific payload types.
+//!
+//! Payload type IDs not defined by the IDTP specification are reserved for
+//! vendor-specific payloads. Custom decoders convert such payloads into one
+//! of standard payloads, so that their readings are plotted and logged the
+//! same way as readings of standard sensors.
+//!
+//! Decoders receive the whole verified frame, so that vendor metadata or
+//! custom integrity schemes carried in the header and trailer can be
+//! interpreted as well.
+//!
+//! Plugins reporting readings in non-standard units or ranges register their
+//! channels info, which overrides the standard one.
+
+use crate::core::{
+    StandardPayload,
+    channel::{ChannelInfo, ChannelRegistry},
+};
+use indtp::{Header, payload::PayloadType};
+use std::collections::HashMap;
+
+/// Verified frame parts passed to decoders.
+#[derive(Debug, Clone, Copy)]
+pub struct RawFrame<'a> {
+    /// Frame header.
+    pub header: &'a Header,
+    /// Raw (decrypted) payload bytes without timestamp.
+    pub payload: &'a [u8],
+    /// Raw frame trailer bytes.
+    pub trailer: &'a [u8],
+}
+
+/// Decoder of vendor-specific payload.
+pub trait PayloadDecoder: Send + Sync {
+    /// Decode raw payload bytes.
+    ///
+    /// # Parameters
+    /// - `payload` - given raw payload bytes to decode.
+    ///
+    /// # Returns
+    /// - Standard payload - in case of success.
+    /// - `None` - otherwise.
+    fn decode(&self, payload: &[u8]) -> Option<StandardPayload>;
+
+    /// Decode payload using header and trailer of the frame.
+    ///
+    /// Decoders interpreting vendor metadata or custom integrity schemes
+    /// override this method. Default implementation decodes payload only.
+    ///
+    /// # Parameters
+    /// - `frame` - given verified frame parts to decode.
+    ///
+    /// # Returns
+    /// - Standard payload - in case of success.
+    /// - `None` - otherwise (frame is treated as undecodable).
+    fn decode_frame(&self, frame: &RawFrame) -> Option<StandardPayload> {
+        self.decode(frame.payload)
+    }
+}
+
+impl<F> PayloadDecoder for F
+where
+    F: Fn(&[u8]) -> Option<StandardPayload> + Send + Sync,
+{
+    /// Decode raw payload bytes by calling the closure.
+    ///
+    /// # Parameters
+    /// - `payload` - given raw payload bytes to decode.
+    ///
+    /// # Returns
+    /// - Standard payload - in case of success.
+    /// - `None` - otherwise.
+    fn decode(&self, payload: &[u8]) -> Option<StandardPayload> {
+        self(payload)
+    }
+}
+
+/// Decoder closure handling the whole frame.
+struct FrameDecoderFn<F>(F);
+
+impl<F> PayloadDecoder for FrameDecoderFn<F>
+where
+    F: Fn(&RawFrame) -> Option<StandardPayload> + Send + Sync,
+{
+    /// Decode raw payload bytes without frame parts.
+    ///
+    /// # Parameters
+    /// - `_payload` - given raw payload bytes to decode.
+    ///
+    /// # Returns
+    /// - `None` - closure requires the whole frame.
+    fn decode(&self, _payload: &[u8]) -> Option<StandardPayload> {
+        None
+    }
+
+    /// Decode frame by calling the closure.
+    ///
+    /// # Parameters
+    /// - `frame` - given verified frame parts to decode.
+    ///
+    /// # Returns
+    /// - Standard payload - in case of success.
+    /// - `None` - otherwise.
+    fn decode_frame(&self, frame: &RawFrame) -> Option<StandardPayload> {
+        (self.0)(frame)
+    }
+}
+
+/// Registry of custom decoders keyed by payload type ID.
+#[derive(Default)]
+pub struct DecoderRegistry {
+    /// Registered custom decoders.
+    decoders: HashMap<u8, Box<dyn PayloadDecoder>>,
+    /// Decoded channels info.
+    channels: ChannelRegistry,
+}
+
+impl DecoderRegistry {
+    /// Construct new empty `DecoderRegistry` object.
+    ///
+    /// # Returns
+    /// - New `DecoderRegistry` object.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register custom decoder for the reserved payload type.
+    ///
+    /// Registering decoder for the same payload type again replaces
+    /// the previous one.
+    ///
+    /// # Parameters
+    /// - `type_id` - given reserved payload type ID.
+    /// - `decoder` - given decoder (closure or trait object) to register.
+    ///
+    /// # Returns
+    /// - `Ok` - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - Payload type ID is defined by the IDTP specification.
+    pub fn register(
+        &mut self,
+        type_id: u8,
+        decoder: impl PayloadDecoder + 'static,
+    ) -> anyhow::Result<()> {
+        if PayloadType::from(type_id).is_standard() {
+            anyhow::bail!("payload type {type_id:#04X} is standard");
+        }
+
+        if self.decoders.insert(type_id, Box::new(decoder)).is_some() {
+            log::warn!("Replaced decoder for payload type {type_id:#04X}");
+        }
+
+        Ok(())
+    }
+
+    /// Register custom decoder closure handling the whole frame.
+    ///
+    /// # Parameters
+    /// - `type_id` - given reserved payload type ID.
+    /// - `decoder` - given closure receiving header, payload and trailer.
+    ///
+    /// # Returns
+    /// - `Ok` - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - Payload type ID is defined by the IDTP specification.
+    pub fn register_frame<F>(
+        &mut self,
+        type_id: u8,
+        decoder: F,
+    ) -> anyhow::Result<()>
+    where
+        F: Fn(&RawFrame) -> Option<StandardPayload> + Send + Sync + 'static,
+    {
+        self.register(type_id, FrameDecoderFn(decoder))
+    }
+
+    /// Register decoded channel info.
+    ///
+    /// Registering channel with the standard key overrides its standard info.
+    ///
+    /// # Parameters
+    /// - `info` - given channel info to register.
+    pub fn register_channel(&mut self, info: ChannelInfo) {
+        self.channels.register(info);
+    }
+
+    /// Get decoded channels info.
+    ///
+    /// # Returns
+    /// - Registry of the standard and registered channels.
+    #[must_use]
+    pub const fn channels(&self) -> &ChannelRegistry {
+        &self.channels
+    }
+
+    /// Check whether decoder for the payload type is registered.
+    ///
+    /// # Parameters
+    /// - `type_id` - given payload type ID to check.
+    ///
+    /// # Returns
+    /// - `true` - if decoder is registered.
+    /// - `false` - otherwise.
+    #[must_use]
+    pub fn contains(&self, type_id: u8) -> bool {
+        self.decoders.contains_key(&type_id)
+    }
+
+    /// Get payload types with registered decoders.
+    ///
+    /// # Returns
+    /// - List of reserved payload type IDs.
+    #[must_use]
+    pub fn payload_types(&self) -> Vec<u8> {
+        self.decoders.keys().copied().collect()
+    }
+
+    /// Decode payload of any type.
+    ///
+    /// # Parameters
+    /// - `frame` - given verified frame parts to decode.
+    ///
+    /// # Returns
+    /// - Standard payload - in case of success.
+    /// - `None` - if payload is malformed or there is no suitable decoder.
+    #[must_use]
+    pub fn decode(&self, frame: &RawFrame) -> Option<StandardPayload> {
+        match PayloadType::from(frame.header.payload_type) {
+            PayloadType::Reserved(type_id) => self
+                .decoders
+                .get(&type_id)
+                .and_then(|decoder| decoder.decode_frame(frame)),
+            payload_type => {
+                StandardPayload::try_from(frame.payload, payload_type)
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for DecoderRegistry {
+    /// Format list of registered payload types.
+    ///
+    /// # Parameters
+    /// - `f` - given formatter to write into.
+    ///
+    /// # Returns
+    /// - Formatting result.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.decoders.keys()).finish()
+    }
+}
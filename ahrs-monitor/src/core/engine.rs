@@ -0,0 +1,215 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Pluggable frame integrity and crypto engines.
+//!
+//! IDTP frames are verified (CRC, CMAC or HMAC) and decrypted by the indtp
+//! integrity and crypto engines. Software engines are used by default,
+//! platforms with crypto accelerators register their own implementation
+//! under a name and select it with `imu.engine`.
+
+use indtp::{
+    Frame,
+    engines::{
+        CryptoEngine, IntegrityEngine, SwCryptoEngine, SwIntegrityEngine,
+    },
+    types::CryptoKeys,
+};
+use std::{collections::HashMap, marker::PhantomData};
+
+/// Name of the default software engine.
+pub const SOFTWARE_ENGINE: &str = "software";
+
+/// Frame verification and decryption engine.
+pub trait FrameEngine: Send + Sync {
+    /// Parse frame and verify its integrity.
+    ///
+    /// # Parameters
+    /// - `bytes` - given raw frame bytes.
+    /// - `keys` - given cryptographic keys.
+    ///
+    /// # Returns
+    /// - Verified frame - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - Malformed frame or CRC/MAC verification failure.
+    fn parse<'a>(
+        &self,
+        bytes: &'a mut [u8],
+        keys: &CryptoKeys,
+    ) -> anyhow::Result<Frame<'a>>;
+
+    /// Decrypt frame payload in-place.
+    ///
+    /// # Parameters
+    /// - `frame` - given verified frame to decrypt.
+    /// - `keys` - given cryptographic keys.
+    ///
+    /// # Returns
+    /// - `Ok` - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - Payload decryption failure.
+    fn decrypt(
+        &self,
+        frame: &mut Frame<'_>,
+        keys: &CryptoKeys,
+    ) -> anyhow::Result<()>;
+}
+
+/// Frame engine backed by indtp integrity and crypto engines.
+pub struct IdtpEngine<I, C> {
+    /// Engines marker.
+    engines: PhantomData<fn() -> (I, C)>,
+}
+
+impl<I, C> Default for IdtpEngine<I, C> {
+    /// Construct new `IdtpEngine` object.
+    ///
+    /// # Returns
+    /// - New `IdtpEngine` object.
+    fn default() -> Self {
+        Self {
+            engines: PhantomData,
+        }
+    }
+}
+
+impl<I: IntegrityEngine, C: CryptoEngine> FrameEngine for IdtpEngine<I, C> {
+    /// Parse frame and verify its integrity.
+    ///
+    /// # Parameters
+    /// - `bytes` - given raw frame bytes.
+    /// - `keys` - given cryptographic keys.
+    ///
+    /// # Returns
+    /// - Verified frame - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - Malformed frame or CRC/MAC verification failure.
+    fn parse<'a>(
+        &self,
+        bytes: &'a mut [u8],
+        keys: &CryptoKeys,
+    ) -> anyhow::Result<Frame<'a>> {
+        Frame::parse::<I, C>(bytes, Some(keys))
+            .map_err(|e| anyhow::anyhow!("{e}"))
+    }
+
+    /// Decrypt frame payload in-place.
+    ///
+    /// # Parameters
+    /// - `frame` - given verified frame to decrypt.
+    /// - `keys` - given cryptographic keys.
+    ///
+    /// # Returns
+    /// - `Ok` - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - Payload decryption failure.
+    fn decrypt(
+        &self,
+        frame: &mut Frame<'_>,
+        keys: &CryptoKeys,
+    ) -> anyhow::Result<()> {
+        frame.decrypt::<C>(keys).map_err(|e| anyhow::anyhow!("{e}"))
+    }
+}
+
+/// Software integrity and crypto engines.
+pub type SoftwareEngine = IdtpEngine<SwIntegrityEngine, SwCryptoEngine>;
+
+/// Registry of frame engines keyed by name.
+pub struct EngineRegistry {
+    /// Registered frame engines.
+    engines: HashMap<String, Box<dyn FrameEngine>>,
+}
+
+impl Default for EngineRegistry {
+    /// Construct registry of the software engine.
+    ///
+    /// # Returns
+    /// - New `EngineRegistry` object.
+    fn default() -> Self {
+        let mut registry = Self {
+            engines: HashMap::new(),
+        };
+
+        registry.register(SOFTWARE_ENGINE, SoftwareEngine::default());
+        registry
+    }
+}
+
+impl EngineRegistry {
+    /// Construct new `EngineRegistry` object with the software engine.
+    ///
+    /// # Returns
+    /// - New `EngineRegistry` object.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register frame engine.
+    ///
+    /// Registering engine with the same name again replaces the previous
+    /// one.
+    ///
+    /// # Parameters
+    /// - `name` - given engine name used in `imu.engine`.
+    /// - `engine` - given frame engine to register.
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        engine: impl FrameEngine + 'static,
+    ) {
+        let name = name.into();
+
+        if self
+            .engines
+            .insert(name.clone(), Box::new(engine))
+            .is_some()
+        {
+            log::warn!("Replaced frame engine \"{name}\"");
+        }
+    }
+
+    /// Get names of the registered engines.
+    ///
+    /// # Returns
+    /// - List of engine names.
+    #[must_use]
+    pub fn names(&self) -> Vec<String> {
+        self.engines.keys().cloned().collect()
+    }
+
+    /// Take registered engine out of the registry.
+    ///
+    /// # Parameters
+    /// - `name` - given engine name.
+    ///
+    /// # Returns
+    /// - Frame engine - if engine is registered.
+    /// - `None` - otherwise.
+    #[must_use]
+    pub fn take(&mut self, name: &str) -> Option<Box<dyn FrameEngine>> {
+        self.engines.remove(name)
+    }
+}
+
+impl std::fmt::Debug for EngineRegistry {
+    /// Format list of registered engine names.
+    ///
+    /// # Parameters
+    /// - `f` - given formatter to write into.
+    ///
+    /// # Returns
+    /// - Formatting result.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.engines.keys()).finish()
+    }
+}
@@ -0,0 +1,140 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Fault injection into the received frames stream.
+//!
+//! Injector sits between the frame sources and frame processing, randomly
+//! dropping or delaying received frames, so that user interface and attitude
+//! estimator behavior under packet loss can be validated without touching
+//! the network.
+
+use crate::{config::DebugConfig, core::source::LinkSet};
+use std::{collections::VecDeque, io, time::Duration};
+use tokio::time::{Instant, sleep_until};
+
+/// Injector of received frames drops and delays.
+#[derive(Debug, Default)]
+pub struct FaultInjector {
+    /// Probability of dropping received frame.
+    drop_rate: f64,
+    /// Probability of delaying received frame.
+    delay_rate: f64,
+    /// Delay of the delayed frames.
+    delay: Duration,
+    /// Delayed frames with their release time.
+    delayed: VecDeque<(Instant, Vec<u8>)>,
+}
+
+impl FaultInjector {
+    /// Construct new `FaultInjector` object.
+    ///
+    /// # Parameters
+    /// - `cfg` - given debug configurations.
+    ///
+    /// # Returns
+    /// - New `FaultInjector` object.
+    #[must_use]
+    pub fn new(cfg: &DebugConfig) -> Self {
+        if cfg.is_fault_injection_enabled() {
+            log::warn!(
+                "Fault injection enabled: dropping {:.1}% and delaying {:.1}% \
+                 of frames by {} ms",
+                cfg.drop_rate * 100.0,
+                cfg.delay_rate * 100.0,
+                cfg.delay_ms
+            );
+        }
+
+        Self {
+            drop_rate: cfg.drop_rate.clamp(0.0, 1.0),
+            delay_rate: cfg.delay_rate.clamp(0.0, 1.0),
+            delay: Duration::from_millis(cfg.delay_ms),
+            delayed: VecDeque::new(),
+        }
+    }
+
+    /// Receive single raw frame surviving fault injection.
+    ///
+    /// # Parameters
+    /// - `source` - given frame sources to receive from.
+    /// - `buffer` - given buffer to store received frame.
+    ///
+    /// # Returns
+    /// - Number of received bytes - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - Frame sources receiving errors.
+    pub async fn recv(
+        &mut self,
+        source: &mut LinkSet,
+        buffer: &mut [u8],
+    ) -> io::Result<usize> {
+        loop {
+            let release_time = self.delayed.front().map(|(time, _)| *time);
+
+            tokio::select! {
+                recv = source.recv(buffer) => {
+                    let len = recv?;
+
+                    if rand::random_bool(self.drop_rate) {
+                        continue;
+                    }
+
+                    if rand::random_bool(self.delay_rate) {
+                        let frame = buffer.get(..len).unwrap_or_default();
+                        let release_time = Instant::now() + self.delay;
+
+                        self.delayed.push_back((release_time, frame.to_vec()));
+                        continue;
+                    }
+
+                    return Ok(len);
+                }
+                () = sleep_until_some(release_time) => {
+                    if let Some((_, frame)) = self.delayed.pop_front() {
+                        return copy_frame(&frame, buffer);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Forget delayed frames (e.g. after frame sources are reopened).
+    pub fn reset(&mut self) {
+        self.delayed.clear();
+    }
+}
+
+/// Wait until the given time.
+///
+/// # Parameters
+/// - `deadline` - given time to wait until (waits forever if not set).
+async fn sleep_until_some(deadline: Option<Instant>) {
+    match deadline {
+        Some(deadline) => sleep_until(deadline).await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Copy delayed frame into the receive buffer.
+///
+/// # Parameters
+/// - `frame` - given frame bytes to copy.
+/// - `buffer` - given buffer to store frame.
+///
+/// # Returns
+/// - Number of copied bytes - in case of success.
+/// - `Err` - if frame does not fit into buffer.
+fn copy_frame(frame: &[u8], buffer: &mut [u8]) -> io::Result<usize> {
+    let len = frame.len();
+    let dst = buffer.get_mut(..len).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("frame length {len} exceeds buffer size"),
+        )
+    })?;
+
+    dst.copy_from_slice(frame);
+    Ok(len)
+}
@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Forwarding of valid frames to downstream UDP endpoints.
+
+use std::io;
+use tokio::net::{UdpSocket, lookup_host};
+
+/// Relay re-emitting raw frames to downstream UDP endpoints.
+#[derive(Debug, Default)]
+pub struct Forwarder {
+    /// Sockets connected to each downstream endpoint.
+    sockets: Vec<UdpSocket>,
+}
+
+impl Forwarder {
+    /// Open sockets connected to downstream endpoints.
+    ///
+    /// # Parameters
+    /// - `endpoints` - given downstream endpoints (`host:port`).
+    ///
+    /// # Returns
+    /// - New `Forwarder` object - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - Endpoint address resolving errors.
+    /// - Socket binding or connecting errors.
+    pub async fn open(endpoints: &[String]) -> io::Result<Self> {
+        let mut sockets = Vec::with_capacity(endpoints.len());
+
+        for endpoint in endpoints {
+            let addr = lookup_host(endpoint.as_str())
+                .await?
+                .next()
+                .ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("unable to resolve endpoint: {endpoint}"),
+                    )
+                })?;
+
+            let local = if addr.is_ipv4() {
+                "0.0.0.0:0"
+            } else {
+                "[::]:0"
+            };
+            let socket = UdpSocket::bind(local).await?;
+            socket.connect(addr).await?;
+
+            log::info!("Forwarding valid frames to {addr}");
+            sockets.push(socket);
+        }
+
+        Ok(Self { sockets })
+    }
+
+    /// Check whether there are no downstream endpoints.
+    ///
+    /// # Returns
+    /// - `true` - if forwarding is disabled.
+    /// - `false` - otherwise.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.sockets.is_empty()
+    }
+
+    /// Send raw frame to all downstream endpoints.
+    ///
+    /// # Parameters
+    /// - `frame` - given raw frame bytes to forward.
+    pub async fn forward(&self, frame: &[u8]) {
+        for socket in &self.sockets {
+            // Downstream endpoint being unavailable must not stop ingesting.
+            if let Err(e) = socket.send(frame).await {
+                log::debug!("Frame forwarding failed: {e}");
+            }
+        }
+    }
+}
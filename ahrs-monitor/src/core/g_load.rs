@@ -0,0 +1,93 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! G-load monitoring.
+//!
+//! G-load is the total acceleration magnitude measured by the accelerometer
+//! in units of standard gravity, so that the device at rest reads 1 g. Max
+//! G-load is kept since the last reset, so that short peaks exceeding the
+//! structural limits are not missed between screen updates.
+
+use crate::{config::GLoadConfig, core::calibration::STANDARD_GRAVITY};
+use serde::{Deserialize, Serialize};
+use tsilna_nav::math::na::Vector3;
+
+/// G-load level relative to the configured limits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum GLoadLevel {
+    /// Below the warning threshold.
+    Normal,
+    /// Above the warning threshold.
+    Warning,
+    /// Above the limit.
+    Limit,
+}
+
+impl GLoadConfig {
+    /// Get G-load level.
+    ///
+    /// # Parameters
+    /// - `g_load` - given G-load in g.
+    ///
+    /// # Returns
+    /// - Level of the G-load relative to the configured limits.
+    #[must_use]
+    pub fn level(&self, g_load: f32) -> GLoadLevel {
+        if g_load > self.limit {
+            GLoadLevel::Limit
+        } else if g_load > self.warning {
+            GLoadLevel::Warning
+        } else {
+            GLoadLevel::Normal
+        }
+    }
+}
+
+/// Current and max G-load.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq)]
+pub struct GLoad {
+    /// Current G-load in g.
+    pub current: f32,
+    /// Max G-load since the last reset in g.
+    pub max: f32,
+}
+
+/// G-load monitor of the accelerometer readings.
+#[derive(Debug, Default)]
+pub struct GLoadMonitor {
+    /// Max G-load since the last reset in g.
+    max: f32,
+}
+
+impl GLoadMonitor {
+    /// Update monitor with the accelerometer readings.
+    ///
+    /// # Parameters
+    /// - `acc` - given accelerometer readings in m/s².
+    ///
+    /// # Returns
+    /// - Current and max G-load.
+    pub fn update(&mut self, acc: Vector3<f32>) -> GLoad {
+        let current = acc.norm() / STANDARD_GRAVITY;
+        self.max = self.max.max(current);
+
+        GLoad {
+            current,
+            max: self.max,
+        }
+    }
+
+    /// Get max G-load since the last reset.
+    ///
+    /// # Returns
+    /// - Max G-load in g.
+    #[must_use]
+    pub const fn max(&self) -> f32 {
+        self.max
+    }
+
+    /// Reset max G-load.
+    pub const fn reset(&mut self) {
+        self.max = 0.0;
+    }
+}
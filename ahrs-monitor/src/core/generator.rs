@@ -0,0 +1,302 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Built-in test-signal generator.
+//!
+//! Synthetic INDTP frames are built from the configured per-channel signals
+//! at the IMU sample rate and handled like received ones, so that plots,
+//! logging and alerting can be validated without a device or the separate
+//! IMU simulator. Channels without signals keep readings of the still IMU
+//! lying flat.
+
+use crate::{
+    config::{self, AppConfig, GeneratorChannel, SignalConfig, Waveform},
+    core::{StandardPayload, calibration::STANDARD_GRAVITY},
+};
+use indtp::{
+    Frame, MTU_SIZE, Mode,
+    engines::{SwCryptoEngine, SwIntegrityEngine},
+    payload::{
+        Imu3Acc, Imu3Gyr, Imu3Mag, Imu6, Imu9, Imu10, ImuQuat, PayloadType,
+    },
+    types::CryptoKeys,
+};
+use std::{f32::consts::TAU, io, time::Duration};
+use tokio::time::{Instant, Interval, MissedTickBehavior, interval};
+
+/// Number of generated readings channels.
+const CHANNELS_COUNT: usize = 14;
+
+/// Standard atmospheric pressure at sea level in Pa.
+const SEA_LEVEL_PRESSURE: f32 = 101_325.0;
+
+/// Readings of the still IMU lying flat.
+const STILL_READINGS: [f32; CHANNELS_COUNT] = [
+    0.0,
+    0.0,
+    STANDARD_GRAVITY,
+    0.0,
+    0.0,
+    0.0,
+    0.0,
+    0.0,
+    0.0,
+    SEA_LEVEL_PRESSURE,
+    1.0,
+    0.0,
+    0.0,
+    0.0,
+];
+
+/// Source of synthetic INDTP frames.
+pub struct SignalGenerator {
+    /// Generated signals.
+    signals: Vec<SignalConfig>,
+    /// Generated payload type.
+    payload_type: PayloadType,
+    /// Generated frames device identifier.
+    device_id: u8,
+    /// Generated frames INDTP protocol mode.
+    mode: Mode,
+    /// Container for cryptographic keys.
+    keys: CryptoKeys,
+    /// Sample period ticker.
+    ticker: Interval,
+    /// Generation start time.
+    start_time: Instant,
+    /// Next frame sequence number.
+    sequence: u16,
+    /// Buffer to build frames in.
+    buffer: Vec<u8>,
+}
+
+impl SignalGenerator {
+    /// Construct new `SignalGenerator` object.
+    ///
+    /// # Parameters
+    /// - `cfg` - given application's configurations.
+    ///
+    /// # Returns
+    /// - New `SignalGenerator` object - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - Vendor-specific payload type or invalid protocol mode.
+    pub fn new(cfg: &AppConfig) -> io::Result<Self> {
+        let payload_type = PayloadType::from(cfg.imu.payload_type);
+
+        if !payload_type.is_standard() {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "test-signal generator supports standard payloads only",
+            ));
+        }
+
+        let mode = Mode::try_from(cfg.imu.protocol_mode).map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid protocol mode: {}", cfg.imu.protocol_mode),
+            )
+        })?;
+
+        let mut ticker =
+            interval(Duration::from_secs_f32(1.0 / cfg.imu.sample_rate));
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        log::info!(
+            "Generating {} test signals at {} Hz",
+            cfg.debug.generator.len(),
+            cfg.imu.sample_rate
+        );
+
+        Ok(Self {
+            signals: cfg.debug.generator.clone(),
+            payload_type,
+            device_id: cfg.imu.device_id,
+            mode,
+            keys: CryptoKeys::new(*config::AES_KEY, *config::HMAC_KEY),
+            ticker,
+            start_time: Instant::now(),
+            sequence: 0,
+            buffer: vec![0u8; MTU_SIZE],
+        })
+    }
+
+    /// Wait for the next sample time and generate frame.
+    ///
+    /// # Returns
+    /// - Raw frame bytes - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - IDTP frame building errors.
+    pub async fn next_frame(&mut self) -> io::Result<Vec<u8>> {
+        self.ticker.tick().await;
+
+        let elapsed = self.start_time.elapsed();
+
+        // Sensor-local clock wraps around like the device one.
+        #[allow(clippy::cast_possible_truncation)]
+        let timestamp = elapsed.as_micros() as u32;
+
+        let readings = self.readings(elapsed.as_secs_f32());
+        let payload = build_payload(self.payload_type, &readings)
+            .ok_or_else(|| io::Error::from(io::ErrorKind::Unsupported))?;
+
+        let (device_id, payload_type) =
+            (self.device_id, self.payload_type.as_u8());
+
+        let mut frame = match self.mode {
+            Mode::Lite => {
+                Frame::new_lite(&mut self.buffer, device_id, payload_type)
+            }
+            Mode::Verified => {
+                Frame::new_verified(&mut self.buffer, device_id, payload_type)
+            }
+            Mode::Trusted => {
+                Frame::new_trusted(&mut self.buffer, device_id, payload_type)
+            }
+            Mode::Critical => {
+                Frame::new_critical(&mut self.buffer, device_id, payload_type)
+            }
+        }
+        .map_err(io::Error::other)?;
+
+        frame.set_sequence(self.sequence);
+        frame
+            .push_single_sample(timestamp, payload.to_bytes())
+            .map_err(io::Error::other)?;
+        frame
+            .pack::<SwIntegrityEngine, SwCryptoEngine>(Some(&self.keys))
+            .map_err(io::Error::other)?;
+
+        self.sequence = self.sequence.wrapping_add(1);
+        Ok(frame.frame().map_err(io::Error::other)?.to_vec())
+    }
+
+    /// Get generated readings of all channels.
+    ///
+    /// # Parameters
+    /// - `t` - given time since generation start in seconds.
+    ///
+    /// # Returns
+    /// - Readings indexed by [`GeneratorChannel`] (signals of the same
+    ///   channel are summed).
+    fn readings(&self, t: f32) -> [f32; CHANNELS_COUNT] {
+        let mut readings = STILL_READINGS;
+        let mut is_generated = [false; CHANNELS_COUNT];
+
+        for signal in &self.signals {
+            let value = signal.offset + waveform_value(&signal.waveform, t);
+            let idx = signal.channel as usize;
+
+            if let (Some(reading), Some(is_generated)) =
+                (readings.get_mut(idx), is_generated.get_mut(idx))
+            {
+                *reading = if *is_generated {
+                    *reading + value
+                } else {
+                    value
+                };
+                *is_generated = true;
+            }
+        }
+
+        readings
+    }
+}
+
+/// Get waveform value.
+///
+/// # Parameters
+/// - `waveform` - given waveform to evaluate.
+/// - `t` - given time since generation start in seconds.
+///
+/// # Returns
+/// - Waveform value at the given time.
+fn waveform_value(waveform: &Waveform, t: f32) -> f32 {
+    match *waveform {
+        Waveform::Sine {
+            amplitude,
+            frequency,
+        } => amplitude * (TAU * frequency * t).sin(),
+        Waveform::Step { amplitude, period } => {
+            if t % (2.0 * period) >= period {
+                amplitude
+            } else {
+                0.0
+            }
+        }
+        Waveform::Chirp {
+            amplitude,
+            start_frequency,
+            end_frequency,
+            duration,
+        } => {
+            let t = t % duration;
+            let rate = (end_frequency - start_frequency) / duration;
+            let phase = (rate * t / 2.0).mul_add(t, start_frequency * t);
+
+            amplitude * (TAU * phase).sin()
+        }
+    }
+}
+
+/// Build standard payload from generated readings.
+///
+/// # Parameters
+/// - `payload_type` - given payload type to build.
+/// - `readings` - given readings indexed by [`GeneratorChannel`].
+///
+/// # Returns
+/// - Standard payload - in case of success.
+/// - `None` - if payload type is not standard.
+fn build_payload(
+    payload_type: PayloadType,
+    readings: &[f32; CHANNELS_COUNT],
+) -> Option<StandardPayload> {
+    let get = |channel: GeneratorChannel| {
+        readings
+            .get(channel as usize)
+            .copied()
+            .unwrap_or(0.0)
+            .into()
+    };
+
+    let acc = Imu3Acc {
+        acc_x: get(GeneratorChannel::AccX),
+        acc_y: get(GeneratorChannel::AccY),
+        acc_z: get(GeneratorChannel::AccZ),
+    };
+    let gyr = Imu3Gyr {
+        gyr_x: get(GeneratorChannel::GyrX),
+        gyr_y: get(GeneratorChannel::GyrY),
+        gyr_z: get(GeneratorChannel::GyrZ),
+    };
+    let mag = Imu3Mag {
+        mag_x: get(GeneratorChannel::MagX),
+        mag_y: get(GeneratorChannel::MagY),
+        mag_z: get(GeneratorChannel::MagZ),
+    };
+
+    Some(match payload_type {
+        PayloadType::Imu3Acc => StandardPayload::Imu3Acc(acc),
+        PayloadType::Imu3Gyr => StandardPayload::Imu3Gyr(gyr),
+        PayloadType::Imu3Mag => StandardPayload::Imu3Mag(mag),
+        PayloadType::Imu6 => StandardPayload::Imu6(Imu6 { acc, gyr }),
+        PayloadType::Imu9 => StandardPayload::Imu9(Imu9 { acc, gyr, mag }),
+        PayloadType::Imu10 => StandardPayload::Imu10(Imu10 {
+            acc,
+            gyr,
+            mag,
+            baro: get(GeneratorChannel::Baro),
+        }),
+        PayloadType::ImuQuat => StandardPayload::ImuQuat(ImuQuat {
+            w: get(GeneratorChannel::QuatW),
+            x: get(GeneratorChannel::QuatX),
+            y: get(GeneratorChannel::QuatY),
+            z: get(GeneratorChannel::QuatZ),
+        }),
+        PayloadType::Reserved(_) => return None,
+    })
+}
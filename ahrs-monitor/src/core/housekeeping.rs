@@ -0,0 +1,319 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Log directory housekeeping.
+//!
+//! Long-running installations record a new log file per session and per
+//! device reboot, so that the log directory grows until the disk is full.
+//! Retention policy limits the age and total size of the recordings and
+//! keeps min free disk space. Recordings (`log_*.csv`) are pruned oldest
+//! first by modification time, the active recording is never pruned. Other
+//! files in the directory (journals, reports) are not touched.
+
+use crate::config::RetentionConfig;
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+/// Number of bytes in a megabyte.
+const MEGABYTE: u64 = 1024 * 1024;
+
+/// Number of seconds in a day.
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Reason to prune the recording.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PruneReason {
+    /// Recording is older than `max_age_days`.
+    Age,
+    /// Total size of the recordings exceeds `max_total_mb`.
+    TotalSize,
+    /// Free disk space is below `min_free_mb`.
+    FreeSpace,
+}
+
+impl fmt::Display for PruneReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason = match self {
+            Self::Age => "max age",
+            Self::TotalSize => "max total size",
+            Self::FreeSpace => "min free space",
+        };
+
+        f.write_str(reason)
+    }
+}
+
+/// Recording to prune.
+#[derive(Debug, Clone)]
+pub struct PruneCandidate {
+    /// Path to the recording.
+    pub path: PathBuf,
+    /// Size of the recording in bytes.
+    pub size: u64,
+    /// Last modification time of the recording.
+    pub modified: SystemTime,
+    /// Reason to prune the recording.
+    pub reason: PruneReason,
+}
+
+/// Recordings of the log directory.
+struct Recording {
+    /// Path to the recording.
+    path: PathBuf,
+    /// Size of the recording in bytes.
+    size: u64,
+    /// Last modification time of the recording.
+    modified: SystemTime,
+}
+
+/// Plan recordings to prune according to the retention policy.
+///
+/// # Parameters
+/// - `directory` - given log directory.
+/// - `cfg` - given retention policy.
+/// - `active` - given path to the active recording to keep.
+///
+/// # Returns
+/// - Recordings to prune, oldest first - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - Error to read log directory.
+pub fn plan(
+    directory: impl AsRef<Path>,
+    cfg: &RetentionConfig,
+    active: Option<&Path>,
+) -> io::Result<Vec<PruneCandidate>> {
+    let directory = directory.as_ref();
+
+    if !cfg.is_enabled() || !directory.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut recordings = recordings(directory, active)?;
+    recordings.sort_by_key(|recording| recording.modified);
+
+    let now = SystemTime::now();
+    let max_age = Duration::from_secs(cfg.max_age_days * SECONDS_PER_DAY);
+    let mut total: u64 = recordings.iter().map(|r| r.size).sum();
+    let mut free = if cfg.min_free_mb > 0 {
+        free_space(directory)
+    } else {
+        None
+    };
+
+    let mut candidates = Vec::new();
+
+    for recording in recordings {
+        let age = now.duration_since(recording.modified).unwrap_or_default();
+
+        let reason = if cfg.max_age_days > 0 && age > max_age {
+            PruneReason::Age
+        } else if cfg.max_total_mb > 0 && total > cfg.max_total_mb * MEGABYTE {
+            PruneReason::TotalSize
+        } else if free.is_some_and(|free| free < cfg.min_free_mb * MEGABYTE) {
+            PruneReason::FreeSpace
+        } else {
+            // Recordings are sorted, the rest are newer.
+            if cfg.max_age_days == 0 {
+                break;
+            }
+
+            continue;
+        };
+
+        total = total.saturating_sub(recording.size);
+        free = free.map(|free| free.saturating_add(recording.size));
+
+        candidates.push(PruneCandidate {
+            path: recording.path,
+            size: recording.size,
+            modified: recording.modified,
+            reason,
+        });
+    }
+
+    Ok(candidates)
+}
+
+/// Delete recordings.
+///
+/// # Parameters
+/// - `candidates` - given recordings to delete.
+///
+/// # Returns
+/// - Number of deleted recordings and number of freed bytes.
+pub fn prune(candidates: &[PruneCandidate]) -> (usize, u64) {
+    let mut deleted: usize = 0;
+    let mut freed: u64 = 0;
+
+    for candidate in candidates {
+        match fs::remove_file(&candidate.path) {
+            Ok(()) => {
+                log::info!(
+                    "Pruned recording {} ({})",
+                    candidate.path.display(),
+                    candidate.reason
+                );
+                deleted += 1;
+                freed += candidate.size;
+            }
+            Err(e) => log::error!(
+                "Failed to prune recording {}: {e}",
+                candidate.path.display()
+            ),
+        }
+    }
+
+    (deleted, freed)
+}
+
+/// Apply retention policy without confirmation.
+///
+/// Recordings are pruned if `auto_prune` is set, otherwise they are only
+/// reported.
+///
+/// # Parameters
+/// - `directory` - given log directory.
+/// - `cfg` - given retention policy.
+/// - `active` - given path to the active recording to keep.
+pub fn enforce(
+    directory: impl AsRef<Path>,
+    cfg: &RetentionConfig,
+    active: Option<&Path>,
+) {
+    let candidates = match plan(directory, cfg, active) {
+        Ok(candidates) if !candidates.is_empty() => candidates,
+        Ok(_) => return,
+        Err(e) => {
+            log::error!("Failed to check log directory retention: {e}");
+            return;
+        }
+    };
+
+    if cfg.auto_prune {
+        prune(&candidates);
+        return;
+    }
+
+    let size: u64 = candidates.iter().map(|c| c.size).sum();
+
+    log::warn!(
+        "{} recordings ({:.1} MB) exceed the retention policy, set \
+         `log.retention.auto_prune` to prune them",
+        candidates.len(),
+        megabytes(size)
+    );
+}
+
+/// Convert number of bytes to megabytes.
+///
+/// # Parameters
+/// - `bytes` - given number of bytes.
+///
+/// # Returns
+/// - Number of megabytes.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn megabytes(bytes: u64) -> f64 {
+    bytes as f64 / MEGABYTE as f64
+}
+
+/// Get recordings of the log directory.
+///
+/// # Parameters
+/// - `directory` - given log directory.
+/// - `active` - given path to the active recording to skip.
+///
+/// # Returns
+/// - Recordings in directory order - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - Error to read log directory.
+fn recordings(
+    directory: &Path,
+    active: Option<&Path>,
+) -> io::Result<Vec<Recording>> {
+    let active = active.and_then(Path::file_name);
+    let mut recordings = Vec::new();
+
+    for entry in fs::read_dir(directory)? {
+        let entry = entry?;
+        let path = entry.path();
+        let name = entry.file_name();
+
+        let is_recording = name.to_str().is_some_and(|name| {
+            name.starts_with("log_")
+                && Path::new(name)
+                    .extension()
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case("csv"))
+        });
+
+        if !is_recording || active == Some(name.as_os_str()) {
+            continue;
+        }
+
+        let metadata = entry.metadata()?;
+
+        if !metadata.is_file() {
+            continue;
+        }
+
+        recordings.push(Recording {
+            path,
+            size: metadata.len(),
+            modified: metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH),
+        });
+    }
+
+    Ok(recordings)
+}
+
+/// Get free disk space available to the user.
+///
+/// # Parameters
+/// - `directory` - given directory on the disk.
+///
+/// # Returns
+/// - Number of free bytes - in case of success.
+/// - `None` - otherwise.
+#[cfg(target_os = "linux")]
+fn free_space(directory: &Path) -> Option<u64> {
+    use std::{ffi::CString, os::unix::ffi::OsStrExt};
+
+    let path = CString::new(directory.as_os_str().as_bytes()).ok()?;
+    let mut stat = std::mem::MaybeUninit::<libc::statvfs>::uninit();
+
+    // SAFETY: path is NUL-terminated and stat is valid for writes.
+    let result = unsafe { libc::statvfs(path.as_ptr(), stat.as_mut_ptr()) };
+
+    if result != 0 {
+        log::warn!(
+            "Failed to get free disk space: {}",
+            io::Error::last_os_error()
+        );
+        return None;
+    }
+
+    // SAFETY: statvfs succeeded and initialized stat.
+    let stat = unsafe { stat.assume_init() };
+
+    #[allow(clippy::useless_conversion)]
+    Some(u64::from(stat.f_bavail).saturating_mul(u64::from(stat.f_frsize)))
+}
+
+/// Get free disk space available to the user.
+///
+/// # Parameters
+/// - `directory` - given directory on the disk (unused).
+///
+/// # Returns
+/// - `None` - free disk space is checked on Linux only.
+#[cfg(not(target_os = "linux"))]
+fn free_space(_: &Path) -> Option<u64> {
+    None
+}
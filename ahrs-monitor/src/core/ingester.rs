@@ -3,63 +3,208 @@
 
 //! IMU communication handler.
 
-use std::time::Duration;
-use crate::core::StandardPayload;
-use crate::model::FrameWrapper;
+use crate::model::{BadPackets, FrameWrapper, RejectReason, RejectedFrame};
 use crate::{
-    config::{self, AppConfig},
-    core::attitude::{AttitudeEstimator, estimate_attitude},
-    model::{AppEvent, FrameContext},
+    config::{self, AppConfig, ImuConfig, NetConfig, Transport},
+    core::{
+        StandardPayload,
+        alert::AlertEngine,
+        altitude::pressure_altitude,
+        anomaly::AnomalyDetector,
+        attitude::{
+            AttitudeComparison, AttitudeEstimator, GyroBiasEstimator,
+            estimate_attitude, linear_acceleration,
+        },
+        burst::BurstDetector,
+        clipping::ClippingDetector,
+        dead_reckoning::DeadReckoning,
+        decoder::{DecoderRegistry, RawFrame},
+        engine::{FrameEngine, SoftwareEngine},
+        faults::FaultInjector,
+        forward::Forwarder,
+        g_load::GLoadMonitor,
+        length::{self, LengthAnomalies, LengthAnomaly},
+        mode::{ModeTracker, ModeTransition, ProtocolMode},
+        motion::MotionClassifier,
+        reboot::RebootDetector,
+        replay::ReplayGuard,
+        shock::{ShockDetector, ShockEvent},
+        source::LinkSet,
+        stale_data::{StaleDataDetector, StaleTransition},
+        suspend::SuspendDetector,
+        timesync::TimeSync,
+        vertical::VerticalFilter,
+        vibration::VibrationMeter,
+        warm_start::WarmStartStore,
+    },
+    model::{
+        AppEvent, FrameContext, IngesterCommand,
+        channel::{CommandReceiver, EventSender},
+    },
 };
-use indtp::engines::{SwCryptoEngine, SwIntegrityEngine};
-use indtp::payload::PayloadType;
 use indtp::types::CryptoKeys;
 use indtp::utils::is_sequence_correct;
-use indtp::{Frame, MTU_SIZE};
-use tokio::{net::UdpSocket, sync::mpsc::Sender};
-use tokio::time::{interval_at, Instant};
-use tsilna_nav::math::Quat32;
+use indtp::{Header, MTU_SIZE, Mode, types::Packable};
+use std::{collections::HashMap, io, io::ErrorKind, time::Duration};
+use tokio::time::{Instant, MissedTickBehavior, interval_at};
+
+/// Time without received frames after which connection is considered lost.
+const CONNECTION_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Interval between packets per second updates.
+const PPS_INTERVAL: Duration = Duration::from_secs(1);
 
 /// Mediator between AHRS monitor and IMU.
 pub struct Ingester {
-    /// MPSC sender handle.
-    tx: Sender<AppEvent>,
+    /// Events channel sender handle.
+    tx: EventSender,
     /// Application's configurations.
     cfg: AppConfig,
-    /// Total number of invalid packets.
-    bad_packets: usize,
+    /// Number of invalid packets by failure reason.
+    bad_packets: BadPackets,
+    /// Number of frame length anomalies by category.
+    length_anomalies: LengthAnomalies,
+    /// Frame arrival burst detector.
+    arrival: BurstDetector,
+    /// Reason for rejecting the last packet (`None` - not rejected).
+    last_reject: Option<RejectReason>,
+    /// Accepted device identifier (`None` accepts all devices).
+    device_filter: Option<u8>,
     /// Previous frame sequence number.
     prev_sequence: Option<u16>,
-    /// Last timestamp in microseconds.
-    last_timestamp_us: Option<u32>,
-    /// Orientation estimator.
-    estimator: AttitudeEstimator,
+    /// Duplicated and stale frames detector.
+    replay: ReplayGuard,
+    /// Protocol mode transitions tracker.
+    modes: ModeTracker,
+    /// Device reboots detector.
+    reboot: RebootDetector,
+    /// Attitude estimators keyed by device identifier.
+    estimators: HashMap<u8, DeviceEstimator>,
+    /// Velocity and position estimator (`None` - disabled).
+    dead_reckoning: Option<DeadReckoning>,
+    /// Altitude and climb rate estimator (`None` - disabled).
+    vertical: Option<VerticalFilter>,
+    /// Last known states of the previous sessions (`None` - warm-start is
+    /// disabled).
+    warm_start: Option<WarmStartStore>,
+    /// Host/device clocks synchronization.
+    time_sync: TimeSync,
+    /// Sensor readings clipping detector.
+    clipping: ClippingDetector,
+    /// Sensor readings anomaly detector (`None` - disabled).
+    anomalies: Option<AnomalyDetector>,
+    /// Motion state classifier (`None` - disabled).
+    motion: Option<MotionClassifier>,
+    /// Accelerometer vibration meter (`None` - disabled).
+    vibration: Option<VibrationMeter>,
+    /// G-load monitor (`None` - disabled).
+    g_load: Option<GLoadMonitor>,
+    /// Repeated payloads detector (`None` - disabled).
+    stale_data: Option<StaleDataDetector>,
+    /// Shock detectors of the devices.
+    shocks: HashMap<u8, ShockDetector>,
+    /// Host suspend duration in seconds to report with the next frame
+    /// (`None` - host was not suspended).
+    resumed_after: Option<f32>,
+    /// Alerting rules evaluator.
+    alerts: AlertEngine,
     /// Container for cryptographic keys.
     keys: CryptoKeys,
+    /// Frame integrity and crypto engine.
+    engine: Box<dyn FrameEngine>,
+    /// Custom decoders for vendor-specific payload types.
+    decoders: DecoderRegistry,
+    /// Commands channel receiver handle.
+    commands: Option<CommandReceiver>,
 }
 
 impl Ingester {
     /// Construct new `Ingester` object.
     ///
     /// # Parameters
-    /// - `tx` - given MPSC sender handle.
+    /// - `tx` - given events channel sender handle.
     /// - `cfg` - given application's configurations.
     ///
     /// # Returns
     /// - New `Ingester` object.
     #[must_use]
-    pub fn new(tx: Sender<AppEvent>, cfg: AppConfig) -> Self {
+    pub fn new(tx: EventSender, cfg: AppConfig) -> Self {
+        let clipping = ClippingDetector::new(&cfg.imu);
+        let alerts = AlertEngine::new(&cfg.alerts);
+        let replay = ReplayGuard::new(cfg.imu.replay_window);
+
         Self {
             tx,
             cfg,
-            bad_packets: 0,
+            bad_packets: BadPackets::default(),
+            length_anomalies: LengthAnomalies::default(),
+            arrival: BurstDetector::new(cfg.imu.sample_rate),
+            last_reject: None,
+            device_filter: None,
             prev_sequence: None,
-            last_timestamp_us: None,
-            estimator: AttitudeEstimator::new(),
+            replay,
+            modes: ModeTracker::default(),
+            reboot: RebootDetector::default(),
+            estimators: HashMap::new(),
+            dead_reckoning: new_dead_reckoning(&cfg.imu),
+            vertical: new_vertical_filter(&cfg.imu),
+            warm_start: load_warm_start(&cfg.imu),
+            time_sync: TimeSync::default(),
+            clipping,
+            anomalies: new_anomaly_detector(&cfg.imu),
+            motion: new_motion_classifier(&cfg.imu),
+            vibration: new_vibration_meter(&cfg.imu),
+            g_load: cfg.imu.g_load.enabled.then(GLoadMonitor::default),
+            stale_data: new_stale_data_detector(&cfg.imu),
+            shocks: HashMap::new(),
+            resumed_after: None,
+            alerts,
             keys: CryptoKeys::new(*config::AES_KEY, *config::HMAC_KEY),
+            engine: Box::new(SoftwareEngine::default()),
+            decoders: DecoderRegistry::default(),
+            commands: None,
         }
     }
 
+    /// Set custom decoders for vendor-specific payload types.
+    ///
+    /// # Parameters
+    /// - `decoders` - given custom decoders registry.
+    ///
+    /// # Returns
+    /// - Updated `Ingester` object.
+    #[must_use]
+    pub fn with_decoders(mut self, decoders: DecoderRegistry) -> Self {
+        self.decoders = decoders;
+        self
+    }
+
+    /// Set frame integrity and crypto engine.
+    ///
+    /// # Parameters
+    /// - `engine` - given frame engine.
+    ///
+    /// # Returns
+    /// - Updated `Ingester` object.
+    #[must_use]
+    pub fn with_engine(mut self, engine: Box<dyn FrameEngine>) -> Self {
+        self.engine = engine;
+        self
+    }
+
+    /// Set commands channel for runtime control from the user interface.
+    ///
+    /// # Parameters
+    /// - `commands` - given commands channel receiver handle.
+    ///
+    /// # Returns
+    /// - Updated `Ingester` object.
+    #[must_use]
+    pub fn with_commands(mut self, commands: CommandReceiver) -> Self {
+        self.commands = Some(commands);
+        self
+    }
+
     /// Start communication with IMU.
     ///
     /// # Returns
@@ -67,138 +212,1008 @@ impl Ingester {
     /// - `Err` - otherwise.
     ///
     /// # Errors
-    /// - Error to sending data over MPSC.
+    /// - Error to sending data over events channel.
+    /// - Frame source opening error (without commands channel).
     /// - IDTP frame parsing error.
     /// - Receiving data over Wi-Fi error.
-    #[allow(clippy::indexing_slicing)]
+    #[allow(clippy::indexing_slicing, clippy::too_many_lines)]
     pub async fn run(&mut self) -> anyhow::Result<()> {
         log::info!("Running Ingester");
 
-        let pair = (self.cfg.net.ip_address.clone(), self.cfg.net.udp_port);
-        let bind_result = UdpSocket::bind(pair).await;
+        let open_result = LinkSet::open(&self.cfg).await;
 
-        // Sending UDP connection status.
+        // Sending frame source connection status.
         self.tx
-            .send(AppEvent::UpdateConnectionStatus(bind_result.is_ok()))
+            .send(AppEvent::UpdateConnectionStatus(open_result.is_ok()))
             .await?;
 
-        let socket = bind_result?;
+        let mut commands = self.commands.take();
+
+        // Frame source can be reopened later with the corrected settings.
+        let mut source = match open_result {
+            Ok(source) => Some(source),
+            Err(e) if commands.is_some() => {
+                log::error!("Failed to open frame source: {e}");
+                None
+            }
+            Err(e) => return Err(e.into()),
+        };
+
         let mut buffer = [0u8; MTU_SIZE];
 
+        let forwarder = Forwarder::open(&self.cfg.net.forward_endpoints)
+            .await
+            .unwrap_or_else(|e| {
+                log::error!("Frame forwarding disabled: {e}");
+                Forwarder::default()
+            });
+
+        let mut faults = FaultInjector::new(&self.cfg.debug);
+
         log::info!("Listening for IDTP frames...");
 
         let mut total_packets: usize = 0;
         let mut packets_in_last_second: usize = 0;
         let mut current_pps: usize = 0;
+        let mut bytes_in_last_second: usize = 0;
+        let mut current_bps: usize = 0;
+        let mut reported_dropped: usize = 0;
 
-        const CONNECTION_TIMEOUT: Duration = Duration::from_secs(3);
         let mut last_packet_time = Instant::now();
-        let mut connection_active = true;
-        let mut pps_interval = interval_at(Instant::now(), Duration::from_secs(1));
-        let mut timeout_check = interval_at(Instant::now() + CONNECTION_TIMEOUT, CONNECTION_TIMEOUT);
+        let mut connection_active = source.is_some();
+        let mut suspend = SuspendDetector::new(PPS_INTERVAL);
+        let mut pps_interval = interval_at(Instant::now(), PPS_INTERVAL);
+        // Ticks missed during system sleep must not fire in a burst.
+        pps_interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        let mut timeout_check = interval_at(
+            Instant::now() + CONNECTION_TIMEOUT,
+            CONNECTION_TIMEOUT,
+        );
 
         loop {
             tokio::select! {
-                recv = socket.recv_from(&mut buffer) => {
-                    let (len, _addr) = recv?;
+                recv = recv_frame(source.as_mut(), &mut faults, &mut buffer) => {
+                    let len = match recv {
+                        Ok(len) => len,
+                        Err(e) if e.kind() == ErrorKind::UnexpectedEof => {
+                            log::info!("Frame source reached end of stream");
+                            return Ok(());
+                        }
+                        Err(e) => return Err(e.into()),
+                    };
 
                     last_packet_time = Instant::now();
+                    bytes_in_last_second += len;
+
+                    if !self.is_accepted_device(&buffer[..len]) {
+                        continue;
+                    }
+
+                    let received_us = chrono::Utc::now().timestamp_micros();
+                    let active_link = source
+                        .as_ref()
+                        .filter(|links| links.is_redundant())
+                        .map(LinkSet::active);
+
+                    // Keeping original bytes, since decryption is in-place.
+                    let raw = buffer[..len].to_vec();
+
+                    let Some(mut frame_ctx) = self.handle_frame(
+                        &mut buffer[..len],
+                        received_us,
+                        active_link.is_some(),
+                    )?
+                    else {
+                        if let Some(links) = source.as_mut() {
+                            links.record_duplicate();
+                        }
+                        continue;
+                    };
+
                     total_packets += 1;
                     packets_in_last_second += 1;
 
-                    let mut frame_ctx = FrameContext::default();
-                    let result = Frame::parse::<SwIntegrityEngine, SwCryptoEngine>(&mut buffer[..len], Some(&self.keys));
+                    if frame_ctx.is_valid {
+                        forwarder.forward(&raw).await;
+                    } else if let Some(reason) = self.last_reject.take() {
+                        frame_ctx.rejected =
+                            Some(RejectedFrame { reason, bytes: raw });
+                    }
 
-                    match result {
-                        Ok(mut frame) => {
-                            let header = frame.header();
-                            let recv_seq = header.sequence.get();
+                    frame_ctx.total_packets = total_packets;
+                    frame_ctx.bad_packets = self.bad_packets;
+                    frame_ctx.length_anomalies = self.length_anomalies;
+                    frame_ctx.arrival = self.arrival.update(received_us);
+                    frame_ctx.pps = current_pps;
+                    frame_ctx.bytes_per_second = current_bps;
+                    frame_ctx.dropped_events = self.tx.dropped();
+                    frame_ctx.active_link = active_link;
+                    frame_ctx.link_stats = source
+                        .as_ref()
+                        .filter(|links| links.is_redundant())
+                        .map(LinkSet::stats);
 
-                            if is_sequence_correct(recv_seq, self.prev_sequence) {
-                                let payload_type = PayloadType::from(header.payload_type);
+                    for alert in self.alerts.evaluate(&frame_ctx) {
+                        let _ = self
+                            .tx
+                            .send(AppEvent::AlertTriggered(Box::new(alert)))
+                            .await;
+                    }
 
-                                if frame.is_encrypted() {
-                                    frame.decrypt::<SwCryptoEngine>(&self.keys)?;
-                                }
+                    let shock = self.detect_shock(&frame_ctx);
+                    frame_ctx.processing_time_us =
+                        u32::try_from(last_packet_time.elapsed().as_micros())
+                            .unwrap_or(u32::MAX);
 
-                                if let Ok((timestamp, payload)) = frame.read_single_sample() {
-                                    let payload = StandardPayload::try_from(payload, payload_type);
+                    let _ = self
+                        .tx
+                        .send(AppEvent::FrameReceived(Box::new(frame_ctx)))
+                        .await;
 
-                                    frame_ctx.quaternion = Some(self.estimate_attitude(timestamp, Option::from(&payload)));
-                                    self.prev_sequence = Some(recv_seq);
+                    if let Some(shock) = shock {
+                        log::info!(
+                            "Device {:#04X} {shock}",
+                            shock.device_id
+                        );
+                        let _ = self
+                            .tx
+                            .send(AppEvent::ShockDetected(shock))
+                            .await;
+                    }
+                }
+                command = recv_command(commands.as_mut()) => {
+                    let Some(command) = command else {
+                        // User interface is closed, no more commands.
+                        commands = None;
+                        continue;
+                    };
 
-                                    let frame_wrapper = FrameWrapper {
-                                        header: *frame.header(),
-                                        payload,
-                                        trailer: frame.trailer()?.to_vec(),
-                                        size: frame.size(),
-                                        flags: frame.flags(),
-                                    };
+                    match command {
+                        IngesterCommand::Reconnect(_)
+                        | IngesterCommand::Disconnect
+                            if self.cfg.net.transport == Transport::Stdin =>
+                        {
+                            log::warn!(
+                                "Standard input cannot be reopened, \
+                                 ignoring connection change"
+                            );
+                        }
+                        IngesterCommand::Reconnect(net) => {
+                            // Closing old source first to free its address.
+                            drop(source.take());
+                            faults.reset();
+                            source = self.reconnect(*net).await;
+                            connection_active = source.is_some();
+                            last_packet_time = Instant::now();
+                            packets_in_last_second = 0;
+                            current_pps = 0;
+                            bytes_in_last_second = 0;
+                            current_bps = 0;
+                        }
+                        IngesterCommand::Disconnect => {
+                            log::info!("Closing frame source");
+                            drop(source.take());
+                            faults.reset();
+                            self.reset();
+                            connection_active = false;
+                            packets_in_last_second = 0;
+                            current_pps = 0;
+                            bytes_in_last_second = 0;
+                            current_bps = 0;
 
-                                    frame_ctx.frame = Some(frame_wrapper);
-                                    frame_ctx.timestamp = timestamp;
-                                    frame_ctx.is_valid = true;
-                                }
+                            let _ = self
+                                .tx
+                                .send(AppEvent::UpdateConnectionStatus(false))
+                                .await;
+                        }
+                        IngesterCommand::SetFrameFilter {
+                            device_id,
+                            use_encryption,
+                        } => {
+                            log::info!(
+                                "Changing frame filter: device ID \
+                                 {device_id:02X?}, encryption {use_encryption}"
+                            );
+                            self.device_filter = device_id;
+                            self.cfg.net.use_encryption = use_encryption;
+                        }
+                        IngesterCommand::SetFusion(algorithm) => {
+                            log::info!(
+                                "Switching attitude fusion to {}",
+                                algorithm.name()
+                            );
+                            self.cfg.imu.fusion = algorithm;
+
+                            for device in self.estimators.values_mut() {
+                                device.estimator.set_algorithm(algorithm);
                             }
                         }
-                        Err(e) => {
-                            log::error!("Error: {e}");
-                            self.bad_packets += 1;
-                            frame_ctx.is_valid = false;
+                        IngesterCommand::SetAccCalibration(calibration) => {
+                            log::info!(
+                                "Applying accelerometer calibration: {calibration:?}"
+                            );
+                            self.cfg.imu.acc_calibration = calibration;
                         }
-                    }
+                        IngesterCommand::SetMagCalibration(calibration) => {
+                            log::info!(
+                                "Applying magnetometer calibration: {calibration:?}"
+                            );
+                            self.cfg.imu.mag_calibration = calibration;
+                        }
+                        IngesterCommand::ResetGLoad => {
+                            log::info!("Resetting max G-load");
 
-                    frame_ctx.total_packets = total_packets;
-                    frame_ctx.bad_packets = self.bad_packets;
-                    frame_ctx.pps = current_pps;
+                            if let Some(monitor) = &mut self.g_load {
+                                monitor.reset();
+                            }
+                        }
+                        IngesterCommand::SetFusionInputs(inputs) => {
+                            log::info!("Changing fusion inputs: {inputs:?}");
+                            self.cfg.imu.inputs = inputs;
+
+                            for device in self.estimators.values_mut() {
+                                device.estimator.set_inputs(inputs);
+                            }
+                        }
+                        IngesterCommand::ResetAttitude => {
+                            log::info!("Restarting attitude estimation");
+
+                            for device in self.estimators.values_mut() {
+                                let algorithm = device.estimator.algorithm();
+                                device.estimator.set_algorithm(algorithm);
+                            }
+                        }
+                        IngesterCommand::ResetNavigation => {
+                            log::info!("Resetting dead-reckoning state");
 
-                    let _ = self.tx.send(
-                        AppEvent::FrameReceived(Box::new(frame_ctx))
-                    ).await;
+                            if let Some(navigation) = &mut self.dead_reckoning {
+                                navigation.reset();
+                            }
+                        }
+                    }
                 }
                 _ = pps_interval.tick() => {
+                    if let Some(gap) = suspend.check() {
+                        log::warn!(
+                            "System resumed after ~{}s of sleep, \
+                             restarting frame handling",
+                            gap.as_secs()
+                        );
+
+                        // Sockets may be invalidated while interfaces are down.
+                        // Reader task of the standard input keeps running,
+                        // so that another reader would desynchronize frames.
+                        if source.is_some()
+                            && self.cfg.net.transport != Transport::Stdin
+                        {
+                            drop(source.take());
+                            faults.reset();
+                            source = self.reconnect(self.cfg.net.clone()).await;
+                            connection_active = source.is_some();
+                        } else {
+                            self.reset();
+                        }
+
+                        self.resumed_after = Some(gap.as_secs_f32());
+                        last_packet_time = Instant::now();
+                        packets_in_last_second = 0;
+                        current_pps = 0;
+                        bytes_in_last_second = 0;
+                        current_bps = 0;
+                        continue;
+                    }
+
                     current_pps = packets_in_last_second;
                     packets_in_last_second = 0;
-                }
+                    current_bps = bytes_in_last_second;
+                    bytes_in_last_second = 0;
+
+                    if let Some(links) = source.as_mut() {
+                        links.update_rates();
+                    }
 
+                    reported_dropped = self.report_dropped(reported_dropped);
+                }
                 _ = timeout_check.tick() => {
-                    if connection_active && last_packet_time.elapsed() >= CONNECTION_TIMEOUT {
-                        log::warn!("No data received for {:?}, marking connection lost", CONNECTION_TIMEOUT);
+                    let elapsed = last_packet_time.elapsed();
+
+                    if connection_active && elapsed >= CONNECTION_TIMEOUT {
+                        log::warn!(
+                            "No data received for {CONNECTION_TIMEOUT:?}, \
+                             marking connection lost"
+                        );
                         connection_active = false;
-                        total_packets = 0;
                         packets_in_last_second = 0;
                         current_pps = 0;
-                        self.prev_sequence = None;
-                        self.last_timestamp_us = None;
-                        self.estimator = AttitudeEstimator::new();
-                        self.bad_packets = 0;
+                        bytes_in_last_second = 0;
+                        current_bps = 0;
+                        self.reset();
 
-                        let _ = self.tx.send(AppEvent::UpdateConnectionStatus(false)).await;
-                    } else if !connection_active && last_packet_time.elapsed() < CONNECTION_TIMEOUT {
+                        let _ = self
+                            .tx
+                            .send(AppEvent::UpdateConnectionStatus(false))
+                            .await;
+                    } else if !connection_active
+                        && source.is_some()
+                        && elapsed < CONNECTION_TIMEOUT
+                    {
                         log::info!("Data flow resumed, marking connection active");
                         connection_active = true;
-                        let _ = self.tx.send(AppEvent::UpdateConnectionStatus(true)).await;
+
+                        let _ = self
+                            .tx
+                            .send(AppEvent::UpdateConnectionStatus(true))
+                            .await;
                     }
                 }
             }
         }
     }
 
-    /// Estimate IMU attitude.
+    /// Reopen frame sources with new networks configurations.
     ///
     /// # Parameters
-    /// - `timestamp` - given sensor-local time in microseconds to handle.
-    /// - `payload` - given frame payload to handle.
+    /// - `net` - given new networks configurations.
     ///
     /// # Returns
-    /// - Attitude in quaternion representation - in case of success.
+    /// - New set of frame sources - in case of success.
     /// - `None` - otherwise.
-    fn estimate_attitude(
+    async fn reconnect(&mut self, net: NetConfig) -> Option<LinkSet> {
+        log::info!("Reopening frame source with new networks configurations");
+
+        self.cfg.net = net;
+        self.reset();
+
+        let source = LinkSet::open(&self.cfg)
+            .await
+            .inspect_err(|e| log::error!("Failed to open frame source: {e}"))
+            .ok();
+
+        let _ = self
+            .tx
+            .send(AppEvent::UpdateConnectionStatus(source.is_some()))
+            .await;
+
+        source
+    }
+
+    /// Log frames dropped by the events channel since the last report.
+    ///
+    /// # Parameters
+    /// - `reported` - given number of already reported dropped frames.
+    ///
+    /// # Returns
+    /// - Total number of dropped frames.
+    fn report_dropped(&self, reported: usize) -> usize {
+        let dropped = self.tx.dropped();
+
+        if dropped > reported {
+            log::warn!(
+                "User interface lags behind: {} frames dropped ({dropped} total)",
+                dropped - reported
+            );
+        }
+
+        dropped
+    }
+
+    /// Reset frame handling state after connection loss.
+    fn reset(&mut self) {
+        self.prev_sequence = None;
+        self.replay.reset();
+        self.reboot.reset();
+        self.estimators.clear();
+        self.dead_reckoning = new_dead_reckoning(&self.cfg.imu);
+        self.vertical = new_vertical_filter(&self.cfg.imu);
+        self.time_sync.reset();
+        self.arrival.reset();
+        self.clipping = ClippingDetector::new(&self.cfg.imu);
+        self.anomalies = new_anomaly_detector(&self.cfg.imu);
+        self.motion = new_motion_classifier(&self.cfg.imu);
+        self.vibration = new_vibration_meter(&self.cfg.imu);
+        self.shocks.clear();
+        self.alerts.reset_loss();
+
+        if let Some(monitor) = &mut self.g_load {
+            monitor.reset();
+        }
+
+        if let Some(detector) = &mut self.stale_data {
+            detector.reset();
+        }
+    }
+
+    /// Reset frame handling state of the rebooted device.
+    ///
+    /// # Parameters
+    /// - `device_id` - given rebooted device identifier.
+    fn reset_device(&mut self, device_id: u8) {
+        self.prev_sequence = None;
+        self.replay.forget(device_id);
+        self.reboot.forget(device_id);
+        self.estimators.remove(&device_id);
+        self.shocks.remove(&device_id);
+        self.alerts.reset_device(device_id);
+
+        // Sensor clock restarts, so that the clock model no longer fits.
+        self.time_sync.reset();
+    }
+
+    /// Handle single received raw frame.
+    ///
+    /// # Parameters
+    /// - `bytes` - given raw frame bytes to handle.
+    /// - `received_us` - given host receive time in Unix microseconds.
+    /// - `is_redundant` - given flag whether frames are received over two
+    ///   links.
+    ///
+    /// # Returns
+    /// - Context data of the received frame - in case of success.
+    /// - `None` - if frame is a copy received over the other link.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - IDTP frame trailer reading error.
+    fn handle_frame(
         &mut self,
+        bytes: &mut [u8],
+        received_us: i64,
+        is_redundant: bool,
+    ) -> anyhow::Result<Option<FrameContext>> {
+        let mut frame_ctx = FrameContext::default();
+
+        if let Some(header) = bytes
+            .get(..Header::len())
+            .and_then(|bytes| Header::from_bytes(bytes).ok())
+            && let Some(anomaly) = length::audit(&header, bytes.len())
+        {
+            self.report_length(anomaly);
+        }
+
+        // Header and length are checked first, so that failed frame parsing
+        // indicates CRC or MAC verification failure.
+        if let Err(reason) = check_layout(bytes) {
+            self.reject(reason, "malformed frame layout");
+            return Ok(Some(frame_ctx));
+        }
+
+        let mut frame = match self.engine.parse(bytes, &self.keys) {
+            Ok(frame) => frame,
+            Err(e) => {
+                self.reject(RejectReason::Integrity, e);
+                return Ok(Some(frame_ctx));
+            }
+        };
+
+        let header = *frame.header();
+        let recv_seq = header.sequence.get();
+
+        if let Some(mode) = header.flags().mode()
+            && let Some(transition) =
+                self.modes.update(header.device_id, mode.into())
+        {
+            self.rearm(transition);
+            frame_ctx.mode_transition = Some(transition);
+        }
+
+        if let Err(message) = self.check_encryption(&header) {
+            self.reject(RejectReason::Decryption, message);
+            return Ok(Some(frame_ctx));
+        }
+
+        if frame.is_encrypted()
+            && let Err(e) = self.engine.decrypt(&mut frame, &self.keys)
+        {
+            self.reject(RejectReason::Decryption, e);
+            return Ok(Some(frame_ctx));
+        }
+
+        // Length is already checked, so that failure is caused by the
+        // payload contents.
+        let (timestamp, payload) = match frame.read_single_sample() {
+            Ok(sample) => sample,
+            Err(e) => {
+                self.reject(RejectReason::Payload, e);
+                return Ok(Some(frame_ctx));
+            }
+        };
+
+        match self.check_sequence(
+            &header,
+            timestamp,
+            is_redundant,
+            &mut frame_ctx,
+        ) {
+            Some(true) => {}
+            Some(false) => return Ok(Some(frame_ctx)),
+            None => return Ok(None),
+        }
+
+        self.detect_stale_data(&header, payload, &mut frame_ctx);
+
+        let trailer = frame.trailer()?;
+        let mut payload = self.decoders.decode(&RawFrame {
+            header: frame.header(),
+            payload,
+            trailer,
+        });
+
+        // Full-scale range is defined for raw readings.
+        frame_ctx.clipping = self.clipping.detect(payload.as_ref(), timestamp);
+
+        if let Some(payload) = &mut payload {
+            self.apply_corrections(payload);
+        }
+
+        self.analyze(header.device_id, payload.as_ref(), &mut frame_ctx);
+        self.estimate(
+            header.device_id,
+            timestamp,
+            payload.as_ref(),
+            &mut frame_ctx,
+        );
+        self.annotate(header.device_id, &mut frame_ctx);
+        self.prev_sequence = Some(recv_seq);
+
+        let frame_wrapper = FrameWrapper {
+            header: *frame.header(),
+            payload,
+            trailer: trailer.to_vec(),
+            size: frame.size(),
+            flags: frame.flags(),
+        };
+
+        frame_ctx.frame = Some(frame_wrapper);
+        frame_ctx.timestamp = timestamp;
+        frame_ctx.host_time_us =
+            Some(self.time_sync.update(timestamp, received_us));
+        frame_ctx.clock = self.time_sync.model();
+        frame_ctx.is_valid = true;
+
+        Ok(Some(frame_ctx))
+    }
+
+    /// Check sequence number of the authenticated frame.
+    ///
+    /// # Parameters
+    /// - `header` - given frame header.
+    /// - `timestamp` - given sensor-local time of the frame in microseconds.
+    /// - `is_redundant` - given flag whether frames are received over two
+    ///   links.
+    /// - `frame_ctx` - given frame context to fill.
+    ///
+    /// # Returns
+    /// - `Some(true)` - if frame is accepted.
+    /// - `Some(false)` - if frame is rejected.
+    /// - `None` - if frame is a copy received over the other link.
+    fn check_sequence(
+        &mut self,
+        header: &Header,
+        timestamp: u32,
+        is_redundant: bool,
+        frame_ctx: &mut FrameContext,
+    ) -> Option<bool> {
+        let device_id = header.device_id;
+        let sequence = header.sequence.get();
+
+        // Restarted sequence numbering must not be rejected as stale.
+        if self.reboot.is_reboot(device_id, sequence, timestamp) {
+            log::warn!(
+                "Device {device_id:#04X} reboot detected (sequence \
+                 {sequence}, timestamp {timestamp} us)"
+            );
+            self.reset_device(device_id);
+            frame_ctx.is_reboot = true;
+        }
+
+        // Sequence number is trusted only after MAC verification.
+        if self.replay.is_enabled() {
+            if let Err(reason) = self.replay.check(device_id, sequence) {
+                // Copies of the same frame are expected from redundant links.
+                if is_redundant && reason == RejectReason::Duplicate {
+                    return None;
+                }
+
+                self.reject(reason, format_args!("sequence {sequence}"));
+                return Some(false);
+            }
+        } else if !is_sequence_correct(sequence, self.prev_sequence) {
+            return Some(false);
+        }
+
+        self.reboot.update(device_id, sequence, timestamp);
+        Some(true)
+    }
+
+    /// Count frames repeating the previous payload of the device.
+    ///
+    /// Raw payload is compared, so that units and calibration do not mask
+    /// tiny changes of the readings.
+    ///
+    /// # Parameters
+    /// - `header` - given frame header.
+    /// - `payload` - given raw payload of the frame.
+    /// - `frame_ctx` - given frame context to fill.
+    fn detect_stale_data(
+        &mut self,
+        header: &Header,
+        payload: &[u8],
+        frame_ctx: &mut FrameContext,
+    ) {
+        if let Some(detector) = &mut self.stale_data {
+            let (repeated, transition) =
+                detector.update(header.device_id, header.payload_type, payload);
+
+            frame_ctx.repeated_frames = repeated;
+            frame_ctx.is_stale_data =
+                repeated >= self.cfg.imu.stale_data.frames;
+            frame_ctx.stale_transition = transition;
+        }
+    }
+
+    /// Convert readings to standard units and body axes.
+    ///
+    /// Calibration is defined for sensor axes in standard units, estimation
+    /// uses body axes.
+    ///
+    /// # Parameters
+    /// - `payload` - given decoded payload to correct.
+    fn apply_corrections(&self, payload: &mut StandardPayload) {
+        self.cfg.imu.units.apply_to(payload);
+        self.cfg.imu.acc_calibration.apply_to(payload);
+        self.cfg.imu.mag_calibration.apply_to(payload);
+        self.cfg.imu.mounting.apply_to(payload);
+    }
+
+    /// Detect anomalies and measure altitude, vibration and G-load.
+    ///
+    /// # Parameters
+    /// - `device_id` - given frame source device identifier.
+    /// - `payload` - given corrected payload of the frame.
+    /// - `frame_ctx` - given frame context to fill.
+    fn analyze(
+        &mut self,
+        device_id: u8,
+        payload: Option<&StandardPayload>,
+        frame_ctx: &mut FrameContext,
+    ) {
+        if let Some(detector) = &mut self.anomalies {
+            frame_ctx.anomalies = detector.detect(payload);
+        }
+
+        frame_ctx.altitude = payload.and_then(|payload| {
+            pressure_altitude(payload.baro()?, self.cfg.imu.reference_pressure)
+        });
+
+        if let Some(meter) = &mut self.vibration {
+            frame_ctx.vibration = payload
+                .and_then(StandardPayload::acc)
+                .map(|acc| meter.update(acc));
+        }
+
+        if let Some(monitor) = &mut self.g_load {
+            let limit = self.cfg.imu.g_load.limit;
+            let prev_max = monitor.max();
+
+            frame_ctx.g_load = payload
+                .and_then(StandardPayload::acc)
+                .map(|acc| monitor.update(acc));
+
+            if let Some(g_load) = frame_ctx.g_load
+                && g_load.max > limit
+                && prev_max <= limit
+            {
+                log::warn!(
+                    "Device {device_id:#04X} G-load {:.2} g exceeds limit \
+                     {limit} g",
+                    g_load.max
+                );
+            }
+        }
+    }
+
+    /// Estimate attitude and the derived navigation and motion state.
+    ///
+    /// # Parameters
+    /// - `device_id` - given frame source device identifier.
+    /// - `timestamp` - given sensor-local time of the frame in microseconds.
+    /// - `payload` - given corrected payload of the frame.
+    /// - `frame_ctx` - given frame context to fill.
+    fn estimate(
+        &mut self,
+        device_id: u8,
         timestamp: u32,
         payload: Option<&StandardPayload>,
-    ) -> Quat32 {
-        let default_dt = 1.0 / self.cfg.imu.sample_rate;
+        frame_ctx: &mut FrameContext,
+    ) {
+        // Devices sharing the socket are estimated independently.
+        let device = self.estimators.entry(device_id).or_insert_with(|| {
+            DeviceEstimator::new(
+                &self.cfg.imu,
+                self.warm_start.as_ref(),
+                device_id,
+            )
+        });
+
+        // Onboard attitude frames interleaved with the raw readings must not
+        // shorten the time step of the local estimator.
+        let is_onboard = matches!(payload, Some(StandardPayload::ImuQuat(_)));
+        let dt = if is_onboard {
+            0.0
+        } else {
+            device.time_step(timestamp, self.cfg.imu.sample_rate)
+        };
+
+        let quaternion = estimate_attitude(&mut device.estimator, payload, dt);
+
+        if is_onboard {
+            frame_ctx.attitude_error =
+                device.comparison.update_onboard(quaternion);
+        }
+
+        frame_ctx.quaternion = Some(quaternion);
+        frame_ctx.gyro_bias = device.estimator.gyro_bias();
+        frame_ctx.fusion_inputs =
+            payload.and_then(|payload| device.estimator.inputs().used(payload));
+
+        // Attitude is not estimated without gyroscope readings.
+        let Some((acc, gyr)) =
+            payload.and_then(|payload| Some((payload.acc()?, payload.gyr()?)))
+        else {
+            return;
+        };
+
+        let linear_acc = linear_acceleration(&quaternion, acc);
+
+        frame_ctx.attitude_error = device.comparison.update_local(quaternion);
+        frame_ctx.attitude_uncertainty = device.estimator.uncertainty();
+
+        frame_ctx.linear_acc = Some(linear_acc.into());
+        frame_ctx.navigation = self
+            .dead_reckoning
+            .as_mut()
+            .map(|nav| nav.update(&quaternion, linear_acc, gyr, dt));
+
+        if let (Some(filter), Some(altitude)) =
+            (&mut self.vertical, frame_ctx.altitude)
+        {
+            frame_ctx.vertical =
+                Some(filter.update(&quaternion, linear_acc, altitude, dt));
+        }
+
+        if let Some(classifier) = &mut self.motion {
+            frame_ctx.motion_transition = classifier.update(acc, gyr, dt);
+            frame_ctx.motion = classifier.state();
+        }
+    }
+
+    /// Log state transitions of the frame and annotate its log record.
+    ///
+    /// # Parameters
+    /// - `device_id` - given frame source device identifier.
+    /// - `frame_ctx` - given frame context to annotate.
+    fn annotate(&mut self, device_id: u8, frame_ctx: &mut FrameContext) {
+        let mut annotations: Vec<String> = self
+            .resumed_after
+            .map(|gap| format!("resumed after {gap:.0} s of system sleep"))
+            .into_iter()
+            .chain(frame_ctx.mode_transition.iter().map(ToString::to_string))
+            .collect();
+
+        frame_ctx.resumed_after = self.resumed_after.take();
+
+        if let Some(transition) = frame_ctx.stale_transition {
+            match transition {
+                StaleTransition::Started(_) => log::warn!(
+                    "Device {device_id:#04X} {transition} while sequence \
+                     numbers advance, sensor may be frozen"
+                ),
+                StaleTransition::Recovered(_) => {
+                    log::info!("Device {device_id:#04X} {transition}");
+                }
+            }
+
+            annotations.push(transition.to_string());
+        }
+
+        if self.anomalies.is_some() && self.cfg.imu.anomaly.annotate_log {
+            annotations
+                .extend(frame_ctx.anomalies.iter().map(ToString::to_string));
+        }
+
+        if let Some(transition) = frame_ctx.motion_transition {
+            log::info!("Device {device_id:#04X} {transition}");
+
+            if self.cfg.imu.motion.annotate_log {
+                annotations.push(transition.to_string());
+            }
+        }
+
+        if !annotations.is_empty() {
+            frame_ctx.annotation = Some(annotations.join("; "));
+        }
+    }
+
+    /// Detect shock of the frame device.
+    ///
+    /// # Parameters
+    /// - `frame_ctx` - given frame context with calibrated readings.
+    ///
+    /// # Returns
+    /// - Shock event - if shock ended at this frame.
+    /// - `None` - otherwise.
+    fn detect_shock(&mut self, frame_ctx: &FrameContext) -> Option<ShockEvent> {
+        let config = self.cfg.imu.shock;
+
+        if !config.enabled {
+            return None;
+        }
+
+        let frame = frame_ctx.frame.as_ref()?;
+        let acc = frame.payload.as_ref()?.acc()?;
+        let device_id = frame.header.device_id;
+
+        self.shocks
+            .entry(device_id)
+            .or_insert_with(|| ShockDetector::new(config))
+            .update(device_id, acc, frame_ctx.timestamp, frame_ctx.host_time_us)
+    }
+
+    /// Re-arm frame validation after protocol mode transition.
+    ///
+    /// Sequence numbers remembered in the previous mode are not covered by
+    /// the MAC of the new one, so that sequence checks start over with the
+    /// frames verified in the new mode.
+    ///
+    /// # Parameters
+    /// - `transition` - given protocol mode transition to handle.
+    fn rearm(&mut self, transition: ModeTransition) {
+        let configured = Mode::try_from(self.cfg.imu.protocol_mode)
+            .map(ProtocolMode::from)
+            .ok();
+
+        if transition.is_downgrade()
+            || configured.is_some_and(|mode| transition.to < mode)
+        {
+            log::warn!("Protocol mode downgraded: {transition}");
+        } else {
+            log::info!("Protocol mode changed: {transition}");
+        }
+
+        self.replay.forget(transition.device_id);
+        self.prev_sequence = None;
+    }
+
+    /// Count frame length anomaly.
+    ///
+    /// First anomaly of each category is logged as warning, since the same
+    /// packing bug usually affects every frame.
+    ///
+    /// # Parameters
+    /// - `anomaly` - given length anomaly to count.
+    fn report_length(&mut self, anomaly: LengthAnomaly) {
+        if self.length_anomalies.add(anomaly) {
+            log::warn!("Frame length anomaly: {anomaly}");
+        } else {
+            log::debug!("Frame length anomaly: {anomaly}");
+        }
+    }
+
+    /// Check whether frame is sent by the accepted device.
+    ///
+    /// Frames of other devices sharing the source are dropped before any
+    /// accounting, as if they were not received.
+    ///
+    /// # Parameters
+    /// - `bytes` - given received frame bytes.
+    ///
+    /// # Returns
+    /// - `true` - if frame is accepted or its header is malformed (to be
+    ///   rejected later).
+    /// - `false` - otherwise.
+    fn is_accepted_device(&self, bytes: &[u8]) -> bool {
+        let Some(device_filter) = self.device_filter else {
+            return true;
+        };
+
+        bytes
+            .get(..Header::len())
+            .and_then(|bytes| Header::from_bytes(bytes).ok())
+            .is_none_or(|header| header.device_id == device_filter)
+    }
+
+    /// Check whether frame encryption matches the configured one.
+    ///
+    /// Lite and Verified frames carry no key-based protection, so that
+    /// encryption is only required from Trusted and Critical frames.
+    ///
+    /// # Parameters
+    /// - `header` - given verified frame header.
+    ///
+    /// # Returns
+    /// - `Ok` - if frame encryption is expected.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - Unencrypted frame while encryption is required.
+    /// - Encrypted frame while encryption is disabled.
+    fn check_encryption(&self, header: &Header) -> Result<(), &'static str> {
+        let flags = header.flags();
+        let is_keyed =
+            matches!(flags.mode(), Some(Mode::Trusted | Mode::Critical));
+
+        match (self.cfg.net.use_encryption, flags.is_encrypted()) {
+            (true, false) if is_keyed => Err("frame is not encrypted"),
+            (false, true) => Err("frame is encrypted, encryption is disabled"),
+            _ => Ok(()),
+        }
+    }
+
+    /// Count rejected packet.
+    ///
+    /// # Parameters
+    /// - `reason` - given reason for rejecting the packet.
+    /// - `error` - given rejection error to log.
+    fn reject(&mut self, reason: RejectReason, error: impl std::fmt::Display) {
+        log::error!("Rejected packet ({reason:?}): {error}");
+        self.bad_packets.add(reason);
+        self.last_reject = Some(reason);
+    }
+}
+
+/// Attitude estimation state of a single device.
+struct DeviceEstimator {
+    /// Orientation estimator.
+    estimator: AttitudeEstimator,
+    /// Onboard and local attitudes comparison.
+    comparison: AttitudeComparison,
+    /// Last timestamp in microseconds.
+    last_timestamp_us: Option<u32>,
+}
+
+impl DeviceEstimator {
+    /// Construct new `DeviceEstimator` object.
+    ///
+    /// Estimation starts from the last known state of the device, if it is
+    /// stored.
+    ///
+    /// # Parameters
+    /// - `imu` - given IMU configurations.
+    /// - `warm_start` - given last known states of the previous sessions
+    ///   (`None` - warm-start is disabled).
+    /// - `device_id` - given device identifier.
+    ///
+    /// # Returns
+    /// - New `DeviceEstimator` object.
+    fn new(
+        imu: &ImuConfig,
+        warm_start: Option<&WarmStartStore>,
+        device_id: u8,
+    ) -> Self {
+        let mut estimator = new_estimator(imu);
+
+        if let Some(state) = warm_start.and_then(|store| store.get(device_id)) {
+            log::info!("Warm-starting attitude of device {device_id:#04X}");
+            estimator.warm_start(state);
+        }
+
+        Self {
+            estimator,
+            comparison: AttitudeComparison::default(),
+            last_timestamp_us: None,
+        }
+    }
+
+    /// Get time step since the previous sample of the device.
+    ///
+    /// # Parameters
+    /// - `timestamp` - given sensor-local time in microseconds to handle.
+    /// - `sample_rate` - given nominal sample rate in Hz.
+    ///
+    /// # Returns
+    /// - Time step in seconds (nominal sample period for the first sample).
+    fn time_step(&mut self, timestamp: u32, sample_rate: f32) -> f32 {
+        let default_dt = 1.0 / sample_rate;
         let current_timestamp_us = timestamp;
 
         let dt = self.last_timestamp_us.map_or(default_dt, |prev_us| {
@@ -214,6 +1229,180 @@ impl Ingester {
             }
         });
 
-        estimate_attitude(&mut self.estimator, payload, dt)
+        self.last_timestamp_us = Some(current_timestamp_us);
+        dt
     }
 }
+
+/// Receive single raw frame from the frame sources.
+///
+/// # Parameters
+/// - `source` - given frame sources (waits forever if they are not opened).
+/// - `faults` - given fault injector dropping or delaying received frames.
+/// - `buffer` - given buffer to store received frame.
+///
+/// # Returns
+/// - Number of received bytes - in case of success.
+/// - `Err` - otherwise.
+async fn recv_frame(
+    source: Option<&mut LinkSet>,
+    faults: &mut FaultInjector,
+    buffer: &mut [u8],
+) -> io::Result<usize> {
+    match source {
+        Some(source) => faults.recv(source, buffer).await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Construct attitude estimator.
+///
+/// # Parameters
+/// - `imu` - given IMU configurations.
+///
+/// # Returns
+/// - New attitude estimator with gyroscope bias estimation.
+fn new_estimator(imu: &ImuConfig) -> AttitudeEstimator {
+    AttitudeEstimator::new(imu.fusion)
+        .with_inputs(imu.inputs)
+        .with_gyro_bias(GyroBiasEstimator::new(imu.gyro_bias))
+}
+
+/// Load last known states of the previous sessions.
+///
+/// # Parameters
+/// - `imu` - given IMU configurations.
+///
+/// # Returns
+/// - Last known states - if warm-start is enabled.
+/// - `None` - otherwise.
+fn load_warm_start(imu: &ImuConfig) -> Option<WarmStartStore> {
+    if !imu.warm_start {
+        return None;
+    }
+
+    WarmStartStore::load(config::WARM_START_FILE_PATH)
+        .inspect_err(|e| log::error!("Failed to load warm-start states: {e}"))
+        .ok()
+}
+
+/// Construct dead-reckoning estimator.
+///
+/// # Parameters
+/// - `imu` - given IMU configurations.
+///
+/// # Returns
+/// - New dead-reckoning estimator - if it is enabled.
+/// - `None` - otherwise.
+fn new_dead_reckoning(imu: &ImuConfig) -> Option<DeadReckoning> {
+    let config = imu.dead_reckoning;
+    config.enabled.then(|| DeadReckoning::new(config))
+}
+
+/// Construct baro-aided altitude and climb rate estimator.
+///
+/// # Parameters
+/// - `imu` - given IMU configurations.
+///
+/// # Returns
+/// - New altitude and climb rate estimator - if it is enabled.
+/// - `None` - otherwise.
+fn new_vertical_filter(imu: &ImuConfig) -> Option<VerticalFilter> {
+    let config = imu.vertical;
+    config.enabled.then(|| VerticalFilter::new(config))
+}
+
+/// Construct sensor readings anomaly detector.
+///
+/// # Parameters
+/// - `imu` - given IMU configurations.
+///
+/// # Returns
+/// - New anomaly detector - if it is enabled.
+/// - `None` - otherwise.
+fn new_anomaly_detector(imu: &ImuConfig) -> Option<AnomalyDetector> {
+    let config = imu.anomaly;
+    config.enabled.then(|| AnomalyDetector::new(config))
+}
+
+/// Construct repeated payloads detector.
+///
+/// # Parameters
+/// - `imu` - given IMU configurations.
+///
+/// # Returns
+/// - Repeated payloads detector - if stale data detection is enabled.
+/// - `None` - otherwise.
+fn new_stale_data_detector(imu: &ImuConfig) -> Option<StaleDataDetector> {
+    let config = imu.stale_data;
+    config.enabled.then(|| StaleDataDetector::new(config))
+}
+
+/// Construct motion state classifier.
+///
+/// # Parameters
+/// - `imu` - given IMU configurations.
+///
+/// # Returns
+/// - Motion state classifier - if classification is enabled.
+/// - `None` - otherwise.
+fn new_motion_classifier(imu: &ImuConfig) -> Option<MotionClassifier> {
+    let config = imu.motion;
+    config.enabled.then(|| MotionClassifier::new(config))
+}
+
+/// Construct accelerometer vibration meter.
+///
+/// # Parameters
+/// - `imu` - given IMU configurations.
+///
+/// # Returns
+/// - Vibration meter - if vibration metrics are enabled.
+/// - `None` - otherwise.
+fn new_vibration_meter(imu: &ImuConfig) -> Option<VibrationMeter> {
+    let config = imu.vibration;
+    config
+        .enabled
+        .then(|| VibrationMeter::new(&config, imu.sample_rate))
+}
+
+/// Receive command from the user interface.
+///
+/// # Parameters
+/// - `commands` - given commands channel receiver handle (waits forever if
+///   it is not set).
+///
+/// # Returns
+/// - Received command - in case of success.
+/// - `None` - if the channel is closed.
+async fn recv_command(
+    commands: Option<&mut CommandReceiver>,
+) -> Option<IngesterCommand> {
+    match commands {
+        Some(commands) => commands.recv().await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Check that raw frame contains valid header and the whole payload.
+///
+/// # Parameters
+/// - `bytes` - given raw frame bytes to check.
+///
+/// # Returns
+/// - `Ok` - if frame layout is correct.
+/// - `Err` - with reason for rejecting the packet otherwise.
+fn check_layout(bytes: &[u8]) -> Result<(), RejectReason> {
+    let header_bytes =
+        bytes.get(..Header::len()).ok_or(RejectReason::Length)?;
+    let header =
+        Header::from_bytes(header_bytes).map_err(|_| RejectReason::Header)?;
+
+    let payload_len = usize::from(header.payload_len.get());
+
+    if bytes.len() < Header::len() + payload_len {
+        return Err(RejectReason::Length);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,345 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Decoded payloads layout export.
+//!
+//! Generates C and Python struct definitions matching the layout of payloads
+//! decoded by the monitor, so that firmware and analysis code stay in sync
+//! with it. All IDTP payload fields are little-endian IEEE 754 `float32`
+//! values without padding. Fields are annotated with the measurement units
+//! and valid ranges of the decoded channels.
+
+use crate::core::channel::ChannelRegistry;
+use indtp::payload::{
+    Imu3Acc, Imu3Gyr, Imu3Mag, Imu6, Imu9, Imu10, ImuQuat, Payload,
+};
+use std::fmt::Write;
+
+/// Size of a single payload field in bytes.
+const FIELD_SIZE: usize = 4;
+
+/// Target language of the exported struct definitions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StructLanguage {
+    /// C header with packed structs.
+    C,
+    /// Python module with `struct` based dataclasses.
+    Python,
+}
+
+impl StructLanguage {
+    /// Parse target language name.
+    ///
+    /// # Parameters
+    /// - `name` - given language name (`c` or `python`).
+    ///
+    /// # Returns
+    /// - Target language - in case of success.
+    /// - `None` - if language is not supported.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "c" => Some(Self::C),
+            "python" | "py" => Some(Self::Python),
+            _ => None,
+        }
+    }
+}
+
+/// Layout of a single payload type.
+#[derive(Debug, Clone, Copy)]
+pub struct PayloadLayout {
+    /// Payload name.
+    pub name: &'static str,
+    /// Payload type according to IDTP specification.
+    pub type_id: u8,
+    /// Payload description.
+    pub description: &'static str,
+    /// Field names in wire order.
+    pub fields: &'static [&'static str],
+}
+
+impl PayloadLayout {
+    /// Get payload size.
+    ///
+    /// # Returns
+    /// - Payload size in bytes.
+    #[must_use]
+    pub const fn size(&self) -> usize {
+        self.fields.len() * FIELD_SIZE
+    }
+}
+
+/// Accelerometer readings fields.
+const ACC: [&str; 3] = ["acc_x", "acc_y", "acc_z"];
+
+/// Gyroscope readings fields.
+const GYR: [&str; 3] = ["gyr_x", "gyr_y", "gyr_z"];
+
+/// Magnetometer readings fields.
+const MAG: [&str; 3] = ["mag_x", "mag_y", "mag_z"];
+
+/// Layouts of all standard payloads.
+pub const STANDARD_LAYOUTS: [PayloadLayout; 7] = [
+    PayloadLayout {
+        name: "Imu3Acc",
+        type_id: Imu3Acc::TYPE_ID,
+        description: "Accelerometer only (for 3-axis sensor)",
+        fields: &ACC,
+    },
+    PayloadLayout {
+        name: "Imu3Gyr",
+        type_id: Imu3Gyr::TYPE_ID,
+        description: "Gyroscope only (for 3-axis sensor)",
+        fields: &GYR,
+    },
+    PayloadLayout {
+        name: "Imu3Mag",
+        type_id: Imu3Mag::TYPE_ID,
+        description: "Magnetometer only (for 3-axis sensor)",
+        fields: &MAG,
+    },
+    PayloadLayout {
+        name: "Imu6",
+        type_id: Imu6::TYPE_ID,
+        description: "Accelerometer + Gyroscope readings",
+        fields: &["acc_x", "acc_y", "acc_z", "gyr_x", "gyr_y", "gyr_z"],
+    },
+    PayloadLayout {
+        name: "Imu9",
+        type_id: Imu9::TYPE_ID,
+        description: "Accelerometer + Gyroscope + Magnetometer readings",
+        fields: &[
+            "acc_x", "acc_y", "acc_z", "gyr_x", "gyr_y", "gyr_z", "mag_x",
+            "mag_y", "mag_z",
+        ],
+    },
+    PayloadLayout {
+        name: "Imu10",
+        type_id: Imu10::TYPE_ID,
+        description: "Accelerometer + Gyroscope + Magnetometer + Barometer \
+                      readings",
+        fields: &[
+            "acc_x", "acc_y", "acc_z", "gyr_x", "gyr_y", "gyr_z", "mag_x",
+            "mag_y", "mag_z", "baro",
+        ],
+    },
+    PayloadLayout {
+        name: "ImuQuat",
+        type_id: ImuQuat::TYPE_ID,
+        description: "Attitude. Hamiltonian Quaternion (w, x, y, z)",
+        fields: &["w", "x", "y", "z"],
+    },
+];
+
+/// Generate struct definitions of the decoded payloads.
+///
+/// # Parameters
+/// - `language` - given target language.
+/// - `custom_payload_types` - given payload types with custom decoders.
+/// - `channels` - given decoded channels info.
+///
+/// # Returns
+/// - Source code with struct definitions.
+#[must_use]
+pub fn export_structs(
+    language: StructLanguage,
+    custom_payload_types: &[u8],
+    channels: &ChannelRegistry,
+) -> String {
+    match language {
+        StructLanguage::C => export_c(custom_payload_types, channels),
+        StructLanguage::Python => export_python(custom_payload_types, channels),
+    }
+}
+
+/// Get payload field annotation.
+///
+/// # Parameters
+/// - `field` - given payload field name.
+/// - `channels` - given decoded channels info.
+///
+/// # Returns
+/// - Measurement unit and valid range (e.g. `m/s², [-160, 160]`) - if
+///   field channel is registered.
+/// - `None` - otherwise.
+fn field_annotation(field: &str, channels: &ChannelRegistry) -> Option<String> {
+    // Quaternion fields are named after components only.
+    let key = match field {
+        "w" | "x" | "y" | "z" => format!("q_{field}"),
+        _ => field.to_string(),
+    };
+    let info = channels.get(&key)?;
+    let range = format!("[{}, {}]", info.min, info.max);
+
+    Some(if info.unit.is_empty() {
+        range
+    } else {
+        format!("{}, {range}", info.unit)
+    })
+}
+
+/// Convert payload name into snake case (e.g. `Imu3Acc` -> `imu3_acc`).
+///
+/// # Parameters
+/// - `name` - given payload name.
+///
+/// # Returns
+/// - Snake case payload name.
+fn snake_case(name: &str) -> String {
+    let mut out = String::with_capacity(name.len() + 4);
+
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 {
+            out.push('_');
+        }
+
+        out.push(c.to_ascii_lowercase());
+    }
+
+    out
+}
+
+/// Generate C header with payload structs.
+///
+/// # Parameters
+/// - `custom_payload_types` - given payload types with custom decoders.
+/// - `channels` - given decoded channels info.
+///
+/// # Returns
+/// - C header source code.
+fn export_c(custom_payload_types: &[u8], channels: &ChannelRegistry) -> String {
+    let mut out = String::new();
+
+    let _ = writeln!(out, "/* Generated by ahrs-monitor. Do not edit. */");
+    let _ =
+        writeln!(out, "/* IDTP payload fields are little-endian float32 */");
+    let _ =
+        writeln!(out, "/* values, swap bytes on big-endian targets.    */\n");
+    let _ = writeln!(out, "#ifndef IDTP_PAYLOADS_H");
+    let _ = writeln!(out, "#define IDTP_PAYLOADS_H\n");
+    let _ = writeln!(out, "#include <assert.h>\n");
+    let _ = writeln!(out, "#pragma pack(push, 1)\n");
+
+    for layout in &STANDARD_LAYOUTS {
+        let snake = snake_case(layout.name);
+        let upper = snake.to_ascii_uppercase();
+
+        let _ = writeln!(out, "/* {} */", layout.description);
+        let _ = writeln!(
+            out,
+            "#define IDTP_PAYLOAD_TYPE_{upper} 0x{:02X}",
+            layout.type_id
+        );
+        let _ = writeln!(out, "typedef struct {{");
+
+        for field in layout.fields {
+            match field_annotation(field, channels) {
+                Some(note) => writeln!(out, "    float {field}; /* {note} */"),
+                None => writeln!(out, "    float {field};"),
+            }
+            .ok();
+        }
+
+        let _ = writeln!(out, "}} idtp_{snake}_t;");
+        let _ = writeln!(
+            out,
+            "static_assert(sizeof(idtp_{snake}_t) == {}, \"idtp_{snake}_t \
+             size\");\n",
+            layout.size()
+        );
+    }
+
+    let _ = writeln!(out, "#pragma pack(pop)\n");
+
+    if !custom_payload_types.is_empty() {
+        let _ = writeln!(out, "/* Vendor-specific payload types. */");
+
+        for type_id in custom_payload_types {
+            let _ = writeln!(
+                out,
+                "#define IDTP_PAYLOAD_TYPE_CUSTOM_{type_id:02X} 0x{type_id:02X}"
+            );
+        }
+
+        let _ = writeln!(out);
+    }
+
+    let _ = writeln!(out, "#endif /* IDTP_PAYLOADS_H */");
+    out
+}
+
+/// Generate Python module with payload dataclasses.
+///
+/// # Parameters
+/// - `custom_payload_types` - given payload types with custom decoders.
+/// - `channels` - given decoded channels info.
+///
+/// # Returns
+/// - Python module source code.
+fn export_python(
+    custom_payload_types: &[u8],
+    channels: &ChannelRegistry,
+) -> String {
+    let mut out = String::new();
+
+    let _ = writeln!(
+        out,
+        "\"\"\"IDTP payloads. Generated by ahrs-monitor.\"\"\"\n"
+    );
+    let _ = writeln!(out, "import struct");
+    let _ = writeln!(out, "from dataclasses import dataclass");
+    let _ = writeln!(out, "from typing import ClassVar\n");
+
+    for layout in &STANDARD_LAYOUTS {
+        let name = layout.name;
+
+        let _ = writeln!(out, "\n@dataclass");
+        let _ = writeln!(out, "class {name}:");
+        let _ = writeln!(out, "    \"\"\"{}.\"\"\"\n", layout.description);
+        let _ = writeln!(
+            out,
+            "    TYPE_ID: ClassVar[int] = 0x{:02X}",
+            layout.type_id
+        );
+        let _ = writeln!(
+            out,
+            "    STRUCT: ClassVar[struct.Struct] = struct.Struct(\"<{}f\")\n",
+            layout.fields.len()
+        );
+
+        for field in layout.fields {
+            match field_annotation(field, channels) {
+                Some(note) => writeln!(out, "    {field}: float  # {note}"),
+                None => writeln!(out, "    {field}: float"),
+            }
+            .ok();
+        }
+
+        let _ = writeln!(out, "\n    @classmethod");
+        let _ =
+            writeln!(out, "    def unpack(cls, data: bytes) -> \"{name}\":");
+        let _ = writeln!(out, "        return cls(*cls.STRUCT.unpack(data))");
+    }
+
+    let _ = writeln!(out, "\n\nPAYLOADS = {{");
+
+    for layout in &STANDARD_LAYOUTS {
+        let _ = writeln!(out, "    0x{:02X}: {},", layout.type_id, layout.name);
+    }
+
+    let _ = writeln!(out, "}}");
+
+    if !custom_payload_types.is_empty() {
+        let ids = custom_payload_types
+            .iter()
+            .map(|type_id| format!("0x{type_id:02X}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let _ = writeln!(out, "\n# Vendor-specific payload types.");
+        let _ = writeln!(out, "CUSTOM_PAYLOAD_TYPES = ({ids},)");
+    }
+
+    out
+}
@@ -0,0 +1,166 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Frame-size and payload-length consistency auditing.
+//!
+//! Header declares the payload length, protocol mode defines the trailer
+//! length and payload type defines the sample layout. Mismatches between
+//! them and the actual received datagram size are categorized separately,
+//! so that firmware packing bugs (wrong `payload_len`, padding, stale
+//! trailer size) are told apart from the transport truncation.
+
+use crate::core::{layout::STANDARD_LAYOUTS, mode::ProtocolMode};
+use indtp::Header;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Size of the sample timestamp in bytes.
+const TIMESTAMP_SIZE: usize = 4;
+
+/// Frame length inconsistency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LengthAnomaly {
+    /// Datagram is shorter than the header declares.
+    Truncated {
+        /// Expected frame size in bytes.
+        expected: usize,
+        /// Received datagram size in bytes.
+        actual: usize,
+    },
+    /// Datagram has bytes beyond the declared frame.
+    Oversized {
+        /// Expected frame size in bytes.
+        expected: usize,
+        /// Received datagram size in bytes.
+        actual: usize,
+    },
+    /// Declared payload length does not fit the standard payload layout.
+    PayloadLength {
+        /// Standard payload type ID.
+        payload_type: u8,
+        /// Expected payload length of a single sample in bytes.
+        expected: usize,
+        /// Declared payload length in bytes.
+        declared: usize,
+    },
+}
+
+impl fmt::Display for LengthAnomaly {
+    /// Format anomaly description.
+    ///
+    /// # Parameters
+    /// - `f` - given formatter to write into.
+    ///
+    /// # Returns
+    /// - Formatting result.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Truncated { expected, actual } => {
+                write!(f, "truncated frame: {actual} of {expected} bytes")
+            }
+            Self::Oversized { expected, actual } => write!(
+                f,
+                "oversized frame: {actual} bytes, {expected} declared"
+            ),
+            Self::PayloadLength {
+                payload_type,
+                expected,
+                declared,
+            } => write!(
+                f,
+                "payload {payload_type:#04X} length {declared} bytes is not \
+                 a multiple of {expected} bytes"
+            ),
+        }
+    }
+}
+
+/// Number of length anomalies by category.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub struct LengthAnomalies {
+    /// Number of truncated datagrams.
+    pub truncated: usize,
+    /// Number of datagrams with bytes beyond the declared frame.
+    pub oversized: usize,
+    /// Number of frames with payload length mismatching its layout.
+    pub payload: usize,
+}
+
+impl LengthAnomalies {
+    /// Count length anomaly.
+    ///
+    /// # Parameters
+    /// - `anomaly` - given anomaly to count.
+    ///
+    /// # Returns
+    /// - `true` - if it is the first anomaly of its category.
+    /// - `false` - otherwise.
+    pub const fn add(&mut self, anomaly: LengthAnomaly) -> bool {
+        let counter = match anomaly {
+            LengthAnomaly::Truncated { .. } => &mut self.truncated,
+            LengthAnomaly::Oversized { .. } => &mut self.oversized,
+            LengthAnomaly::PayloadLength { .. } => &mut self.payload,
+        };
+
+        *counter += 1;
+        *counter == 1
+    }
+
+    /// Get total number of length anomalies.
+    ///
+    /// # Returns
+    /// - Total number of length anomalies.
+    #[must_use]
+    pub const fn total(&self) -> usize {
+        self.truncated + self.oversized + self.payload
+    }
+}
+
+/// Cross-check declared lengths with the received datagram size.
+///
+/// # Parameters
+/// - `header` - given parsed frame header.
+/// - `size` - given received datagram size in bytes.
+///
+/// # Returns
+/// - Length anomaly - if lengths are inconsistent.
+/// - `None` - otherwise.
+#[must_use]
+pub fn audit(header: &Header, size: usize) -> Option<LengthAnomaly> {
+    let declared = usize::from(header.payload_len.get());
+    let trailer_len = header
+        .flags()
+        .mode()
+        .map_or(0, |mode| ProtocolMode::from(mode).trailer_len());
+    let expected = Header::len() + declared + trailer_len;
+
+    if size < expected {
+        return Some(LengthAnomaly::Truncated {
+            expected,
+            actual: size,
+        });
+    }
+
+    if size > expected {
+        return Some(LengthAnomaly::Oversized {
+            expected,
+            actual: size,
+        });
+    }
+
+    // Vendor-specific payloads have no known layout.
+    let layout = STANDARD_LAYOUTS
+        .iter()
+        .find(|l| l.type_id == header.payload_type)?;
+    let sample_len = TIMESTAMP_SIZE + layout.size();
+
+    (declared == 0 || declared % sample_len != 0).then_some(
+        LengthAnomaly::PayloadLength {
+            payload_type: header.payload_type,
+            expected: sample_len,
+            declared,
+        },
+    )
+}
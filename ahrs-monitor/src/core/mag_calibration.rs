@@ -0,0 +1,435 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Magnetometer hard/soft-iron calibration.
+//!
+//! Readings of the magnetometer rotated through all orientations lie on an
+//! ellipsoid: its center is the hard-iron offset and its shape is the
+//! soft-iron distortion. The ellipsoid is fitted to the collected readings by
+//! least squares and mapped back onto the sphere of the same volume, so that
+//! the field strength is preserved.
+
+use crate::core::StandardPayload;
+use serde::{Deserialize, Serialize};
+use std::{
+    f32::consts::{PI, TAU},
+    fmt,
+};
+use tsilna_nav::math::na::{Matrix3, SMatrix, SVector, Vector3};
+
+/// Min number of samples to fit the ellipsoid.
+pub const MIN_FIT_SAMPLES: usize = 50;
+
+/// Max number of collected samples.
+pub const MAX_SAMPLES: usize = 2000;
+
+/// Min distance between consecutive collected samples as a fraction of the
+/// reading magnitude (readings of the still device are skipped).
+const MIN_SAMPLE_SPACING: f32 = 0.05;
+
+/// Number of azimuth sectors of the coverage grid.
+const AZIMUTH_BINS: usize = 8;
+
+/// Number of equal-area elevation bands of the coverage grid.
+const ELEVATION_BINS: usize = 4;
+
+/// Magnetometer calibration: `calibrated = matrix * (raw - offset)`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct MagCalibration {
+    /// Hard-iron offset (x, y, z) in units of raw readings.
+    pub offset: [f32; 3],
+    /// Soft-iron correction matrix rows.
+    pub matrix: [[f32; 3]; 3],
+}
+
+impl Default for MagCalibration {
+    /// Get identity calibration.
+    ///
+    /// # Returns
+    /// - Calibration without offset and distortion correction.
+    fn default() -> Self {
+        Self {
+            offset: [0.0; 3],
+            matrix: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+        }
+    }
+}
+
+impl MagCalibration {
+    /// Construct calibration from the offset and correction matrix.
+    ///
+    /// # Parameters
+    /// - `offset` - given hard-iron offset.
+    /// - `matrix` - given soft-iron correction matrix.
+    ///
+    /// # Returns
+    /// - New `MagCalibration` object.
+    #[must_use]
+    pub fn new(offset: Vector3<f32>, matrix: &Matrix3<f32>) -> Self {
+        Self {
+            offset: [offset.x, offset.y, offset.z],
+            matrix: [
+                [matrix.m11, matrix.m12, matrix.m13],
+                [matrix.m21, matrix.m22, matrix.m23],
+                [matrix.m31, matrix.m32, matrix.m33],
+            ],
+        }
+    }
+
+    /// Get soft-iron correction matrix.
+    ///
+    /// # Returns
+    /// - Correction matrix.
+    #[must_use]
+    pub fn correction(&self) -> Matrix3<f32> {
+        let [[m11, m12, m13], [m21, m22, m23], [m31, m32, m33]] = self.matrix;
+        Matrix3::new(m11, m12, m13, m21, m22, m23, m31, m32, m33)
+    }
+
+    /// Calibrate magnetometer readings.
+    ///
+    /// # Parameters
+    /// - `mag` - given vector of raw magnetometer readings.
+    ///
+    /// # Returns
+    /// - Calibrated magnetometer readings.
+    #[must_use]
+    pub fn apply(&self, mag: Vector3<f32>) -> Vector3<f32> {
+        self.correction() * (mag - Vector3::from(self.offset))
+    }
+
+    /// Calibrate magnetometer readings of the payload.
+    ///
+    /// # Parameters
+    /// - `payload` - given payload to calibrate.
+    pub fn apply_to(&self, payload: &mut StandardPayload) {
+        if *self != Self::default()
+            && let Some(mag) = payload.mag()
+        {
+            payload.set_mag(self.apply(mag));
+        }
+    }
+
+    /// Compose calibration estimated from readings already calibrated by
+    /// this calibration.
+    ///
+    /// # Parameters
+    /// - `next` - given calibration of the calibrated readings.
+    ///
+    /// # Returns
+    /// - Calibration of the raw readings equivalent to applying both.
+    #[must_use]
+    pub fn then(&self, next: &Self) -> Self {
+        let correction = self.correction();
+        let inverse =
+            correction.try_inverse().unwrap_or_else(Matrix3::identity);
+        let offset =
+            Vector3::from(self.offset) + inverse * Vector3::from(next.offset);
+
+        Self::new(offset, &(next.correction() * correction))
+    }
+}
+
+/// Magnetometer calibration error enumeration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MagCalibrationError {
+    /// Not enough samples are collected.
+    TooFewSamples(usize),
+    /// Samples do not span all three dimensions.
+    Degenerate,
+    /// Fitted quadric surface is not an ellipsoid.
+    NotEllipsoid,
+}
+
+impl fmt::Display for MagCalibrationError {
+    /// Format calibration error.
+    ///
+    /// # Parameters
+    /// - `f` - given formatter.
+    ///
+    /// # Returns
+    /// - `Ok` - in case of success.
+    /// - `Err` - otherwise.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooFewSamples(count) => write!(
+                f,
+                "{count} samples collected, at least {MIN_FIT_SAMPLES} needed"
+            ),
+            Self::Degenerate => {
+                write!(f, "samples do not cover enough orientations")
+            }
+            Self::NotEllipsoid => {
+                write!(f, "samples do not lie on an ellipsoid")
+            }
+        }
+    }
+}
+
+impl std::error::Error for MagCalibrationError {}
+
+/// Ellipsoid fitted to the magnetometer samples.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EllipsoidFit {
+    /// Calibration mapping the ellipsoid onto the sphere.
+    pub calibration: MagCalibration,
+    /// Radius of the sphere (field strength) in units of the readings.
+    pub field: f32,
+    /// Semi-axes lengths of the ellipsoid in ascending order.
+    pub semi_axes: [f32; 3],
+    /// RMS deviation of the calibrated samples magnitude from the field
+    /// strength as a fraction of it.
+    pub residual: f32,
+}
+
+impl EllipsoidFit {
+    /// Get soft-iron distortion.
+    ///
+    /// # Returns
+    /// - Ratio of the longest semi-axis to the shortest one (1.0 - sphere).
+    #[must_use]
+    pub fn axes_ratio(&self) -> f32 {
+        let [min, _, max] = self.semi_axes;
+        max / min
+    }
+
+    /// Get point of the fitted ellipsoid surface.
+    ///
+    /// # Parameters
+    /// - `direction` - given unit vector on the calibrated sphere.
+    ///
+    /// # Returns
+    /// - Raw reading calibrated into the given direction.
+    #[must_use]
+    pub fn surface_point(&self, direction: Vector3<f32>) -> Vector3<f32> {
+        let inverse = self
+            .calibration
+            .correction()
+            .try_inverse()
+            .unwrap_or_else(Matrix3::identity);
+
+        Vector3::from(self.calibration.offset)
+            + inverse * (direction * self.field)
+    }
+}
+
+/// Magnetometer samples collected for calibration.
+#[derive(Debug, Clone, Default)]
+pub struct MagSampler {
+    /// Collected magnetometer readings.
+    samples: Vec<Vector3<f32>>,
+}
+
+impl MagSampler {
+    /// Add magnetometer readings.
+    ///
+    /// Readings close to the previous sample are skipped, so that holding
+    /// the device still does not bias the fit.
+    ///
+    /// # Parameters
+    /// - `mag` - given vector of magnetometer readings.
+    ///
+    /// # Returns
+    /// - `true` - if readings are added as a new sample.
+    /// - `false` - otherwise.
+    pub fn add(&mut self, mag: Vector3<f32>) -> bool {
+        if self.samples.len() >= MAX_SAMPLES
+            || !mag.iter().all(|v| v.is_finite())
+        {
+            return false;
+        }
+
+        if self.samples.last().is_some_and(|last| {
+            (mag - last).norm() < MIN_SAMPLE_SPACING * mag.norm()
+        }) {
+            return false;
+        }
+
+        self.samples.push(mag);
+        true
+    }
+
+    /// Get collected samples.
+    ///
+    /// # Returns
+    /// - Magnetometer readings in collection order.
+    #[must_use]
+    pub fn samples(&self) -> &[Vector3<f32>] {
+        &self.samples
+    }
+
+    /// Get number of collected samples.
+    ///
+    /// # Returns
+    /// - Number of samples.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Check whether no samples are collected.
+    ///
+    /// # Returns
+    /// - `true` - if there are no samples.
+    /// - `false` - otherwise.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Get orientations coverage of the collected samples.
+    ///
+    /// Directions from the center of the samples bounding box are counted
+    /// in the equal-area grid of azimuth sectors and elevation bands.
+    ///
+    /// # Returns
+    /// - Fraction of the covered grid cells (0.0 - 1.0).
+    #[must_use]
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    pub fn coverage(&self) -> f32 {
+        let Some(first) = self.samples.first() else {
+            return 0.0;
+        };
+
+        let (min, max) = self
+            .samples
+            .iter()
+            .fold((*first, *first), |(min, max), s| (min.inf(s), max.sup(s)));
+        let center = (min + max) / 2.0;
+
+        let mut cells = [false; AZIMUTH_BINS * ELEVATION_BINS];
+
+        for sample in &self.samples {
+            let direction = sample - center;
+            let norm = direction.norm();
+
+            if norm <= f32::EPSILON {
+                continue;
+            }
+
+            let azimuth = (direction.y.atan2(direction.x) + PI) / TAU;
+            let height = f32::midpoint(direction.z / norm, 1.0);
+            let azimuth_bin = ((azimuth * AZIMUTH_BINS as f32) as usize)
+                .min(AZIMUTH_BINS - 1);
+            let elevation_bin = ((height * ELEVATION_BINS as f32) as usize)
+                .min(ELEVATION_BINS - 1);
+
+            if let Some(cell) =
+                cells.get_mut(elevation_bin * AZIMUTH_BINS + azimuth_bin)
+            {
+                *cell = true;
+            }
+        }
+
+        let covered = cells.iter().filter(|&&cell| cell).count();
+        covered as f32 / cells.len() as f32
+    }
+
+    /// Drop collected samples.
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    /// Fit ellipsoid to the collected samples.
+    ///
+    /// General quadric `a·x² + b·y² + c·z² + 2d·xy + 2e·xz + 2f·yz + 2g·x +
+    /// 2h·y + 2i·z = 1` is fitted by least squares, its center is the
+    /// hard-iron offset and the square root of its normalized shape matrix
+    /// maps it onto the unit sphere.
+    ///
+    /// # Returns
+    /// - Fitted ellipsoid - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - Not enough samples are collected.
+    /// - Samples are degenerate or do not lie on an ellipsoid.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::many_single_char_names
+    )]
+    pub fn fit(&self) -> Result<EllipsoidFit, MagCalibrationError> {
+        if self.samples.len() < MIN_FIT_SAMPLES {
+            return Err(MagCalibrationError::TooFewSamples(self.samples.len()));
+        }
+
+        let mut normal = SMatrix::<f64, 9, 9>::zeros();
+        let mut rhs = SVector::<f64, 9>::zeros();
+
+        for sample in &self.samples {
+            let [x, y, z] = [sample.x, sample.y, sample.z].map(f64::from);
+            let row = SVector::<f64, 9>::from([
+                x * x,
+                y * y,
+                z * z,
+                2.0 * x * y,
+                2.0 * x * z,
+                2.0 * y * z,
+                2.0 * x,
+                2.0 * y,
+                2.0 * z,
+            ]);
+
+            normal += row * row.transpose();
+            rhs += row;
+        }
+
+        let solution = normal
+            .lu()
+            .solve(&rhs)
+            .ok_or(MagCalibrationError::Degenerate)?;
+        let [a, b, c, d, e, f, g, h, i]: [f64; 9] = solution.into();
+
+        let quadric = Matrix3::new(a, d, e, d, b, f, e, f, c);
+        let center = -(quadric
+            .try_inverse()
+            .ok_or(MagCalibrationError::Degenerate)?
+            * Vector3::new(g, h, i));
+        let scale = 1.0 + center.dot(&(quadric * center));
+        let eigen = (quadric / scale).symmetric_eigen();
+
+        if !eigen.eigenvalues.iter().all(|&l| l.is_finite() && l > 0.0) {
+            return Err(MagCalibrationError::NotEllipsoid);
+        }
+
+        let mut semi_axes: [f64; 3] =
+            eigen.eigenvalues.map(|l| l.sqrt().recip()).into();
+        semi_axes.sort_by(f64::total_cmp);
+
+        // Sphere of the same volume keeps the field strength.
+        let field = semi_axes.iter().product::<f64>().cbrt();
+        let root = eigen.eigenvectors
+            * Matrix3::from_diagonal(&eigen.eigenvalues.map(f64::sqrt))
+            * eigen.eigenvectors.transpose();
+
+        let calibration = MagCalibration::new(
+            center.cast::<f32>(),
+            &(root * field).cast::<f32>(),
+        );
+        let field = field as f32;
+
+        let residual = self
+            .samples
+            .iter()
+            .map(|&sample| {
+                let deviation = calibration.apply(sample).norm() / field - 1.0;
+                deviation * deviation
+            })
+            .sum::<f32>();
+
+        let residual = (residual / self.samples.len() as f32).sqrt();
+
+        Ok(EllipsoidFit {
+            calibration,
+            field,
+            semi_axes: semi_axes.map(|axis| axis as f32),
+            residual,
+        })
+    }
+}
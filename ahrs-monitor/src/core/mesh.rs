@@ -0,0 +1,292 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Vehicle 3D model loading.
+//!
+//! Binary and ASCII STL files and Wavefront OBJ files (vertices and faces,
+//! polygons are split into triangle fans) are supported. Normals, texture
+//! coordinates and materials are ignored: faces are shaded by the renderer
+//! from their geometry. Loaded model is centered and scaled to unit radius,
+//! so that models of any units fit the attitude widget.
+
+use crate::config::ModelConfig;
+use std::{fs, path::Path};
+use tsilna_nav::math::{Quat32, na::Vector3};
+
+/// Max number of model triangles (larger models are too slow to sort for
+/// every frame).
+pub const MAX_TRIANGLES: usize = 100_000;
+
+/// Size of the binary STL header in bytes.
+const STL_HEADER_SIZE: usize = 80;
+
+/// Size of the binary STL triangle record in bytes.
+const STL_TRIANGLE_SIZE: usize = 50;
+
+/// Model triangle vertices.
+pub type Triangle = [Vector3<f32>; 3];
+
+/// Triangle mesh of the vehicle model.
+#[derive(Debug, Clone, Default)]
+pub struct Mesh {
+    /// Triangles centered at the origin within unit radius.
+    pub triangles: Vec<Triangle>,
+}
+
+impl Mesh {
+    /// Load vehicle model.
+    ///
+    /// # Parameters
+    /// - `cfg` - given vehicle 3D model configurations.
+    ///
+    /// # Returns
+    /// - Loaded mesh rotated into the body frame - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - File reading errors.
+    /// - Unsupported file extension or malformed file contents.
+    /// - Model without triangles or with more than `MAX_TRIANGLES`.
+    pub fn load(cfg: &ModelConfig) -> anyhow::Result<Self> {
+        let path = &cfg.path;
+        let bytes = fs::read(path)?;
+
+        let extension = Path::new(path)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(str::to_ascii_lowercase);
+
+        let triangles = match extension.as_deref() {
+            Some("stl") => parse_stl(&bytes)?,
+            Some("obj") => parse_obj(&String::from_utf8_lossy(&bytes))?,
+            _ => anyhow::bail!("{path}: expected .stl or .obj model"),
+        };
+
+        anyhow::ensure!(!triangles.is_empty(), "{path}: model has no faces");
+        anyhow::ensure!(
+            triangles.len() <= MAX_TRIANGLES,
+            "{path}: model has {} triangles, max is {MAX_TRIANGLES}",
+            triangles.len()
+        );
+
+        let [roll, pitch, yaw] = cfg.orientation.map(f32::to_radians);
+        let rotation = Quat32::from_euler_angles(roll, pitch, yaw);
+
+        Ok(Self::normalized(triangles, &rotation))
+    }
+
+    /// Construct mesh centered at the origin within unit radius.
+    ///
+    /// # Parameters
+    /// - `triangles` - given model triangles in the model units.
+    /// - `rotation` - given rotation of the model into the body frame.
+    ///
+    /// # Returns
+    /// - Normalized `Mesh` object.
+    fn normalized(triangles: Vec<Triangle>, rotation: &Quat32) -> Self {
+        let vertices = || triangles.iter().flatten();
+
+        let (min, max) = vertices().fold(
+            (Vector3::repeat(f32::MAX), Vector3::repeat(f32::MIN)),
+            |(min, max), v| (min.inf(v), max.sup(v)),
+        );
+        let center = (min + max) * 0.5;
+        let radius = vertices()
+            .map(|v| (v - center).norm())
+            .fold(0.0, f32::max)
+            .max(f32::EPSILON);
+
+        let triangles = triangles
+            .iter()
+            .map(|triangle| {
+                triangle.map(|v| rotation * ((v - center) / radius))
+            })
+            .collect();
+
+        Self { triangles }
+    }
+}
+
+/// Parse STL file contents.
+///
+/// # Parameters
+/// - `bytes` - given file contents.
+///
+/// # Returns
+/// - Model triangles - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - Contents are neither binary nor ASCII STL.
+fn parse_stl(bytes: &[u8]) -> anyhow::Result<Vec<Triangle>> {
+    // ASCII files start with "solid" as well as some binary headers, so that
+    // the size of the binary file is checked first.
+    if let Some(triangles) = parse_binary_stl(bytes) {
+        return Ok(triangles);
+    }
+
+    anyhow::ensure!(bytes.starts_with(b"solid"), "malformed STL file");
+
+    let text = String::from_utf8_lossy(bytes);
+    let vertices = text
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("vertex"))
+        .map(parse_vector)
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    anyhow::ensure!(vertices.len() % 3 == 0, "incomplete STL facet");
+
+    Ok(vertices
+        .chunks_exact(3)
+        .filter_map(|facet| <[Vector3<f32>; 3]>::try_from(facet).ok())
+        .collect())
+}
+
+/// Parse binary STL file contents.
+///
+/// # Parameters
+/// - `bytes` - given file contents.
+///
+/// # Returns
+/// - Model triangles - if contents are binary STL.
+/// - `None` - otherwise.
+fn parse_binary_stl(bytes: &[u8]) -> Option<Vec<Triangle>> {
+    let count = bytes
+        .get(STL_HEADER_SIZE..STL_HEADER_SIZE + 4)
+        .and_then(|b| b.try_into().ok())
+        .map(u32::from_le_bytes)?;
+    let records = bytes.get(STL_HEADER_SIZE + 4..)?;
+
+    if records.len() != usize::try_from(count).ok()? * STL_TRIANGLE_SIZE {
+        return None;
+    }
+
+    let read_f32 = |record: &[u8], offset: usize| {
+        record
+            .get(offset..offset + 4)
+            .and_then(|b| b.try_into().ok())
+            .map(f32::from_le_bytes)
+    };
+
+    // Record: normal, 3 vertices and attribute byte count.
+    let read_vertex = |record: &[u8], index: usize| {
+        let offset = 12 * (index + 1);
+
+        Some(Vector3::new(
+            read_f32(record, offset)?,
+            read_f32(record, offset + 4)?,
+            read_f32(record, offset + 8)?,
+        ))
+    };
+
+    records
+        .chunks_exact(STL_TRIANGLE_SIZE)
+        .map(|record| {
+            Some([
+                read_vertex(record, 0)?,
+                read_vertex(record, 1)?,
+                read_vertex(record, 2)?,
+            ])
+        })
+        .collect()
+}
+
+/// Parse Wavefront OBJ file contents.
+///
+/// # Parameters
+/// - `text` - given file contents.
+///
+/// # Returns
+/// - Model triangles - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - Malformed vertex or face.
+/// - Face referring to unknown vertex.
+fn parse_obj(text: &str) -> anyhow::Result<Vec<Triangle>> {
+    let mut vertices = Vec::new();
+    let mut triangles = Vec::new();
+
+    for line in text.lines() {
+        let line = line.trim();
+
+        if let Some(coords) = line.strip_prefix("v ") {
+            vertices.push(parse_vector(coords)?);
+            continue;
+        }
+
+        let Some(face) = line.strip_prefix("f ") else {
+            continue;
+        };
+
+        let corners = face
+            .split_whitespace()
+            .map(|corner| obj_vertex(corner, &vertices))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        anyhow::ensure!(corners.len() >= 3, "face with less than 3 vertices");
+
+        if let Some((&first, rest)) = corners.split_first() {
+            for pair in rest.windows(2) {
+                if let &[second, third] = pair {
+                    triangles.push([first, second, third]);
+                }
+            }
+        }
+    }
+
+    Ok(triangles)
+}
+
+/// Get vertex of the OBJ face corner.
+///
+/// # Parameters
+/// - `corner` - given face corner (`v`, `v/vt`, `v//vn` or `v/vt/vn`).
+/// - `vertices` - given vertices defined so far.
+///
+/// # Returns
+/// - Corner vertex - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - Malformed or out of range vertex index.
+fn obj_vertex(
+    corner: &str,
+    vertices: &[Vector3<f32>],
+) -> anyhow::Result<Vector3<f32>> {
+    let index: isize = corner.split('/').next().unwrap_or_default().parse()?;
+
+    // Indices are 1-based, negative ones are relative to the end.
+    let position = if index < 0 {
+        vertices.len().checked_add_signed(index)
+    } else {
+        index.unsigned_abs().checked_sub(1)
+    };
+
+    position
+        .and_then(|i| vertices.get(i))
+        .copied()
+        .ok_or_else(|| anyhow::anyhow!("unknown vertex {index}"))
+}
+
+/// Parse whitespace-separated vector coordinates.
+///
+/// # Parameters
+/// - `text` - given coordinates to parse.
+///
+/// # Returns
+/// - Parsed vector - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - Less than 3 coordinates or malformed coordinate.
+fn parse_vector(text: &str) -> anyhow::Result<Vector3<f32>> {
+    let mut coords = text.split_whitespace().map(str::parse::<f32>);
+    let mut next = || {
+        coords
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("expected 3 coordinates: {text}"))
+    };
+
+    Ok(Vector3::new(next()??, next()??, next()??))
+}
@@ -4,8 +4,52 @@
 //! AHRS Monitor core main module.
 //! The core responsible for handling IDTP frames.
 
+pub mod alert;
+pub mod altitude;
+pub mod analysis;
+pub mod anomaly;
 pub mod attitude;
+pub mod burst;
+pub mod calibration;
+#[cfg(target_os = "linux")]
+pub mod can;
+pub mod channel;
+pub mod clipping;
+pub mod compare;
+pub mod conformance;
+pub mod dead_reckoning;
+pub mod decoder;
+pub mod engine;
+pub mod faults;
+pub mod forward;
+pub mod g_load;
+pub mod generator;
+pub mod housekeeping;
 mod ingester;
+pub mod layout;
+pub mod length;
+pub mod mag_calibration;
+pub mod mesh;
+pub mod mode;
+pub mod motion;
+pub mod mounting;
+pub mod player;
+pub mod reboot;
+pub mod replay;
+pub mod reprocess;
+pub mod shock;
+pub mod smoothing;
+pub mod source;
+pub mod spectrum;
+pub mod stale_data;
+pub mod summary;
+pub mod suspend;
+pub mod timesync;
+pub mod units;
+pub mod vertical;
+pub mod vibration;
+pub mod warm_start;
+mod worker;
 
 use indtp::payload::PayloadType;
 use indtp::{
@@ -13,6 +57,8 @@ use indtp::{
     types::Packable,
 };
 pub use ingester::Ingester;
+use tsilna_nav::math::na::Vector3;
+pub use worker::Worker;
 
 /// INDTP standard payload enumeration.
 #[derive(Debug)]
@@ -121,4 +167,138 @@ impl StandardPayload {
             PayloadType::Reserved(_) => 0,
         }
     }
+
+    /// Get accelerometer readings.
+    ///
+    /// # Returns
+    /// - Vector of accelerometer readings - if payload contains them.
+    /// - `None` - otherwise.
+    #[must_use]
+    pub const fn acc(&self) -> Option<Vector3<f32>> {
+        let acc = match self {
+            Self::Imu3Acc(p) => p,
+            Self::Imu6(p) => &p.acc,
+            Self::Imu9(p) => &p.acc,
+            Self::Imu10(p) => &p.acc,
+            _ => return None,
+        };
+
+        Some(Vector3::new(
+            acc.acc_x.get(),
+            acc.acc_y.get(),
+            acc.acc_z.get(),
+        ))
+    }
+
+    /// Set accelerometer readings.
+    ///
+    /// # Parameters
+    /// - `acc` - given vector of accelerometer readings to set (ignored if
+    ///   payload does not contain them).
+    pub fn set_acc(&mut self, acc: Vector3<f32>) {
+        let target = match self {
+            Self::Imu3Acc(p) => p,
+            Self::Imu6(p) => &mut p.acc,
+            Self::Imu9(p) => &mut p.acc,
+            Self::Imu10(p) => &mut p.acc,
+            _ => return,
+        };
+
+        target.acc_x = acc.x.into();
+        target.acc_y = acc.y.into();
+        target.acc_z = acc.z.into();
+    }
+
+    /// Get gyroscope readings.
+    ///
+    /// # Returns
+    /// - Vector of gyroscope readings - if payload contains them.
+    /// - `None` - otherwise.
+    #[must_use]
+    pub const fn gyr(&self) -> Option<Vector3<f32>> {
+        let gyr = match self {
+            Self::Imu3Gyr(p) => p,
+            Self::Imu6(p) => &p.gyr,
+            Self::Imu9(p) => &p.gyr,
+            Self::Imu10(p) => &p.gyr,
+            _ => return None,
+        };
+
+        Some(Vector3::new(
+            gyr.gyr_x.get(),
+            gyr.gyr_y.get(),
+            gyr.gyr_z.get(),
+        ))
+    }
+
+    /// Set gyroscope readings.
+    ///
+    /// # Parameters
+    /// - `gyr` - given vector of gyroscope readings to set (ignored if
+    ///   payload does not contain them).
+    pub fn set_gyr(&mut self, gyr: Vector3<f32>) {
+        let target = match self {
+            Self::Imu3Gyr(p) => p,
+            Self::Imu6(p) => &mut p.gyr,
+            Self::Imu9(p) => &mut p.gyr,
+            Self::Imu10(p) => &mut p.gyr,
+            _ => return,
+        };
+
+        target.gyr_x = gyr.x.into();
+        target.gyr_y = gyr.y.into();
+        target.gyr_z = gyr.z.into();
+    }
+
+    /// Get magnetometer readings.
+    ///
+    /// # Returns
+    /// - Vector of magnetometer readings - if payload contains them.
+    /// - `None` - otherwise.
+    #[must_use]
+    pub const fn mag(&self) -> Option<Vector3<f32>> {
+        let mag = match self {
+            Self::Imu3Mag(p) => p,
+            Self::Imu9(p) => &p.mag,
+            Self::Imu10(p) => &p.mag,
+            _ => return None,
+        };
+
+        Some(Vector3::new(
+            mag.mag_x.get(),
+            mag.mag_y.get(),
+            mag.mag_z.get(),
+        ))
+    }
+
+    /// Set magnetometer readings.
+    ///
+    /// # Parameters
+    /// - `mag` - given vector of magnetometer readings to set (ignored if
+    ///   payload does not contain them).
+    pub fn set_mag(&mut self, mag: Vector3<f32>) {
+        let target = match self {
+            Self::Imu3Mag(p) => p,
+            Self::Imu9(p) => &mut p.mag,
+            Self::Imu10(p) => &mut p.mag,
+            _ => return,
+        };
+
+        target.mag_x = mag.x.into();
+        target.mag_y = mag.y.into();
+        target.mag_z = mag.z.into();
+    }
+
+    /// Get barometer readings.
+    ///
+    /// # Returns
+    /// - Static pressure in Pa - if payload contains it.
+    /// - `None` - otherwise.
+    #[must_use]
+    pub const fn baro(&self) -> Option<f32> {
+        match self {
+            Self::Imu10(p) => Some(p.baro.get()),
+            _ => None,
+        }
+    }
 }
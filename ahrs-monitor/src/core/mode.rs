@@ -0,0 +1,160 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! IDTP protocol mode transitions tracking.
+//!
+//! Device may switch its protocol mode mid-session (e.g. from Lite to
+//! Trusted once the link is secured). Mode of every frame is taken from the
+//! header flags, which are covered by the frame CRC or MAC, so that the
+//! transition is only reported for frames that passed verification.
+
+use indtp::Mode;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fmt};
+
+/// IDTP protocol mode in order of protection strength.
+#[derive(
+    Serialize,
+    Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ProtocolMode {
+    /// No integrity check.
+    Lite,
+    /// CRC-32 integrity check.
+    Verified,
+    /// CMAC-AES-128 authentication.
+    Trusted,
+    /// HMAC-SHA256 authentication.
+    Critical,
+}
+
+impl ProtocolMode {
+    /// Get protocol mode name.
+    ///
+    /// # Returns
+    /// - Protocol mode name string slice.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Lite => "Lite",
+            Self::Verified => "Verified",
+            Self::Trusted => "Trusted",
+            Self::Critical => "Critical",
+        }
+    }
+
+    /// Get length of the frame trailer.
+    ///
+    /// # Returns
+    /// - Trailer length in bytes.
+    #[must_use]
+    pub const fn trailer_len(self) -> usize {
+        match self {
+            Self::Lite => 0,
+            Self::Verified => 4,
+            Self::Trusted => 16,
+            Self::Critical => 32,
+        }
+    }
+}
+
+impl From<Mode> for ProtocolMode {
+    /// Convert IDTP mode into protocol mode.
+    ///
+    /// # Parameters
+    /// - `mode` - given IDTP mode to convert.
+    ///
+    /// # Returns
+    /// - Corresponding protocol mode.
+    fn from(mode: Mode) -> Self {
+        match mode {
+            Mode::Lite => Self::Lite,
+            Mode::Verified => Self::Verified,
+            Mode::Trusted => Self::Trusted,
+            Mode::Critical => Self::Critical,
+        }
+    }
+}
+
+/// Protocol mode switch of a single device.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModeTransition {
+    /// Device identifier.
+    pub device_id: u8,
+    /// Protocol mode of the previous frames.
+    pub from: ProtocolMode,
+    /// Protocol mode of the current frame.
+    pub to: ProtocolMode,
+}
+
+impl ModeTransition {
+    /// Check whether protection of the frames got weaker.
+    ///
+    /// # Returns
+    /// - `true` - if new mode is weaker than the previous one.
+    /// - `false` - otherwise.
+    #[must_use]
+    pub fn is_downgrade(&self) -> bool {
+        self.to < self.from
+    }
+}
+
+impl fmt::Display for ModeTransition {
+    /// Format transition as log annotation.
+    ///
+    /// # Parameters
+    /// - `f` - given formatter to write into.
+    ///
+    /// # Returns
+    /// - Formatting result.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "device {:#04X} mode {} → {}",
+            self.device_id,
+            self.from.name(),
+            self.to.name()
+        )
+    }
+}
+
+/// Per-device protocol mode tracker.
+#[derive(Debug, Default)]
+pub struct ModeTracker {
+    /// Protocol mode of the latest frame by device identifier.
+    modes: HashMap<u8, ProtocolMode>,
+}
+
+impl ModeTracker {
+    /// Remember protocol mode of the verified frame.
+    ///
+    /// # Parameters
+    /// - `device_id` - given device identifier of the frame.
+    /// - `mode` - given protocol mode of the frame.
+    ///
+    /// # Returns
+    /// - Mode transition - if mode differs from the previous frame one.
+    /// - `None` - otherwise.
+    pub fn update(
+        &mut self,
+        device_id: u8,
+        mode: ProtocolMode,
+    ) -> Option<ModeTransition> {
+        let from = self.modes.insert(device_id, mode)?;
+
+        (from != mode).then_some(ModeTransition {
+            device_id,
+            from,
+            to: mode,
+        })
+    }
+}
@@ -0,0 +1,228 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Motion state classification.
+//!
+//! Magnitudes of accelerometer and gyroscope readings are averaged with
+//! the time constant of the configured window. Device is static when both
+//! barely vary, in slow motion when they stay moderate, dynamic otherwise
+//! and in freefall when the specific force nearly vanishes. New state is
+//! reported only after it holds for the configured time, so that single
+//! noisy readings do not produce transitions.
+
+use crate::config::MotionConfig;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use tsilna_nav::math::na::Vector3;
+
+/// Motion state of the device.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MotionState {
+    /// Device is not moving.
+    Static,
+    /// Device moves slowly (e.g. handled or turned by hand).
+    SlowMotion,
+    /// Device moves with high angular rates or accelerations.
+    Dynamic,
+    /// Device is falling freely.
+    Freefall,
+}
+
+impl MotionState {
+    /// Get motion state name.
+    ///
+    /// # Returns
+    /// - Motion state name string slice.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Static => "Static",
+            Self::SlowMotion => "Slow motion",
+            Self::Dynamic => "Dynamic",
+            Self::Freefall => "Freefall",
+        }
+    }
+}
+
+/// Motion state change.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MotionTransition {
+    /// Previous motion state.
+    pub from: MotionState,
+    /// New motion state.
+    pub to: MotionState,
+}
+
+impl fmt::Display for MotionTransition {
+    /// Format transition as log annotation.
+    ///
+    /// # Parameters
+    /// - `f` - given formatter to write into.
+    ///
+    /// # Returns
+    /// - Formatting result.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "motion {} → {}", self.from.name(), self.to.name())
+    }
+}
+
+/// Exponential moving mean and variance of a magnitude.
+#[derive(Debug, Default, Clone, Copy)]
+struct MovingStats {
+    /// Moving mean.
+    mean: f32,
+    /// Moving variance.
+    variance: f32,
+}
+
+impl MovingStats {
+    /// Add value to the statistics.
+    ///
+    /// # Parameters
+    /// - `value` - given value to add.
+    /// - `alpha` - given weight of the value.
+    fn add(&mut self, value: f32, alpha: f32) {
+        let delta = value - self.mean;
+        self.mean += alpha * delta;
+        self.variance = (1.0 - alpha) * (self.variance + alpha * delta * delta);
+    }
+
+    /// Get moving standard deviation.
+    ///
+    /// # Returns
+    /// - Standard deviation of the values.
+    fn std_dev(&self) -> f32 {
+        self.variance.max(0.0).sqrt()
+    }
+}
+
+/// Online motion state classifier.
+#[derive(Debug)]
+pub struct MotionClassifier {
+    /// Classification configurations.
+    config: MotionConfig,
+    /// Accelerometer readings magnitude statistics.
+    acc: Option<MovingStats>,
+    /// Gyroscope readings magnitude statistics.
+    gyr: MovingStats,
+    /// Reported motion state.
+    state: Option<MotionState>,
+    /// State differing from the reported one and its duration in seconds.
+    candidate: Option<(MotionState, f32)>,
+}
+
+impl MotionClassifier {
+    /// Construct new `MotionClassifier` object.
+    ///
+    /// # Parameters
+    /// - `config` - given classification configurations.
+    ///
+    /// # Returns
+    /// - New `MotionClassifier` object.
+    #[must_use]
+    pub fn new(config: MotionConfig) -> Self {
+        Self {
+            config,
+            acc: None,
+            gyr: MovingStats::default(),
+            state: None,
+            candidate: None,
+        }
+    }
+
+    /// Get reported motion state.
+    ///
+    /// # Returns
+    /// - Motion state - if enough readings were classified.
+    /// - `None` - otherwise.
+    #[must_use]
+    pub const fn state(&self) -> Option<MotionState> {
+        self.state
+    }
+
+    /// Classify inertial sensors readings.
+    ///
+    /// # Parameters
+    /// - `acc` - given vector of accelerometer readings in m/s².
+    /// - `gyr` - given vector of gyroscope readings in degrees per second.
+    /// - `dt` - given time step since last update in seconds.
+    ///
+    /// # Returns
+    /// - Motion transition - if reported state changed.
+    /// - `None` - otherwise.
+    pub fn update(
+        &mut self,
+        acc: Vector3<f32>,
+        gyr: Vector3<f32>,
+        dt: f32,
+    ) -> Option<MotionTransition> {
+        let acc_norm = acc.norm();
+        let alpha = dt / (self.config.window + dt);
+
+        // Statistics start from the first reading instead of zero.
+        let acc_stats = self.acc.get_or_insert(MovingStats {
+            mean: acc_norm,
+            variance: 0.0,
+        });
+
+        acc_stats.add(acc_norm, alpha);
+        self.gyr.add(gyr.norm(), alpha);
+
+        let acc_std = acc_stats.std_dev();
+        let classified = self.classify(acc_norm, acc_std);
+
+        let Some(state) = self.state else {
+            self.state = Some(classified);
+            return None;
+        };
+
+        if classified == state {
+            self.candidate = None;
+            return None;
+        }
+
+        let duration = match self.candidate {
+            Some((candidate, duration)) if candidate == classified => {
+                duration + dt
+            }
+            _ => dt,
+        };
+
+        if duration < self.config.hold_time {
+            self.candidate = Some((classified, duration));
+            return None;
+        }
+
+        self.state = Some(classified);
+        self.candidate = None;
+
+        Some(MotionTransition {
+            from: state,
+            to: classified,
+        })
+    }
+
+    /// Classify current readings statistics.
+    ///
+    /// # Parameters
+    /// - `acc_norm` - given magnitude of the latest accelerometer readings.
+    /// - `acc_std` - given moving standard deviation of the magnitude.
+    ///
+    /// # Returns
+    /// - Motion state of the readings.
+    fn classify(&self, acc_norm: f32, acc_std: f32) -> MotionState {
+        let cfg = &self.config;
+        let gyr = self.gyr.mean;
+
+        if acc_norm < cfg.freefall_acc {
+            MotionState::Freefall
+        } else if gyr < cfg.static_gyr && acc_std < cfg.static_acc {
+            MotionState::Static
+        } else if gyr < cfg.slow_gyr && acc_std < cfg.slow_acc {
+            MotionState::SlowMotion
+        } else {
+            MotionState::Dynamic
+        }
+    }
+}
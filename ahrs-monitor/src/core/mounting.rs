@@ -0,0 +1,165 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Sensor mounting orientation.
+//!
+//! IMU is not always mounted with its axes along the body axes of the
+//! vehicle. Readings of every sensor are rotated into the body frame before
+//! attitude estimation, so that plots, attitude and logs are in body axes.
+//! Rotation is an axis remap with sign flips, optionally followed by a fixed
+//! quaternion for mounting angles that are not multiples of 90°.
+
+use crate::core::StandardPayload;
+use serde::{Deserialize, Serialize};
+use tsilna_nav::math::{
+    Quat32,
+    na::{Matrix3, Quaternion, Vector3},
+};
+
+/// Signed sensor axis.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    /// Sensor X axis.
+    #[serde(rename = "+x", alias = "x")]
+    PosX,
+    /// Sensor X axis reversed.
+    #[serde(rename = "-x")]
+    NegX,
+    /// Sensor Y axis.
+    #[serde(rename = "+y", alias = "y")]
+    PosY,
+    /// Sensor Y axis reversed.
+    #[serde(rename = "-y")]
+    NegY,
+    /// Sensor Z axis.
+    #[serde(rename = "+z", alias = "z")]
+    PosZ,
+    /// Sensor Z axis reversed.
+    #[serde(rename = "-z")]
+    NegZ,
+}
+
+impl Axis {
+    /// Get index and sign of the sensor axis.
+    ///
+    /// # Returns
+    /// - Sensor axis index (x - 0, y - 1, z - 2) and sign (1 or -1).
+    #[must_use]
+    pub const fn index_and_sign(self) -> (usize, f32) {
+        match self {
+            Self::PosX => (0, 1.0),
+            Self::NegX => (0, -1.0),
+            Self::PosY => (1, 1.0),
+            Self::NegY => (1, -1.0),
+            Self::PosZ => (2, 1.0),
+            Self::NegZ => (2, -1.0),
+        }
+    }
+}
+
+/// Sensor mounting orientation: `body = rotation * remap(sensor)`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Mounting {
+    /// Sensor axes along the body axes (x, y, z).
+    pub axes: [Axis; 3],
+    /// Rotation (w, x, y, z) from the remapped sensor frame into the body
+    /// frame (`None` - no rotation).
+    pub quaternion: Option<[f32; 4]>,
+}
+
+impl Default for Mounting {
+    /// Get identity mounting.
+    ///
+    /// # Returns
+    /// - Mounting with sensor axes along the body axes.
+    fn default() -> Self {
+        Self {
+            axes: [Axis::PosX, Axis::PosY, Axis::PosZ],
+            quaternion: None,
+        }
+    }
+}
+
+impl Mounting {
+    /// Check whether every sensor axis is mapped exactly once.
+    ///
+    /// # Returns
+    /// - `true` - if axes are a permutation of sensor axes.
+    /// - `false` - otherwise.
+    #[must_use]
+    pub fn is_permutation(&self) -> bool {
+        let mut used = [false; 3];
+
+        for axis in self.axes {
+            let (index, _) = axis.index_and_sign();
+
+            match used.get_mut(index) {
+                Some(used) if !*used => *used = true,
+                _ => return false,
+            }
+        }
+
+        true
+    }
+
+    /// Get rotation from the sensor frame into the body frame.
+    ///
+    /// # Returns
+    /// - Rotation matrix.
+    #[must_use]
+    pub fn rotation(&self) -> Matrix3<f32> {
+        let mut remap = Matrix3::zeros();
+
+        for (row, axis) in self.axes.iter().enumerate() {
+            let (column, sign) = axis.index_and_sign();
+
+            if let Some(value) = remap.get_mut((row, column)) {
+                *value = sign;
+            }
+        }
+
+        self.quaternion.map_or(remap, |[w, x, y, z]| {
+            let rotation = Quat32::from_quaternion(Quaternion::new(w, x, y, z));
+            rotation.to_rotation_matrix().into_inner() * remap
+        })
+    }
+
+    /// Rotate readings from the body frame back into the sensor frame.
+    ///
+    /// # Parameters
+    /// - `v` - given readings in the body frame.
+    ///
+    /// # Returns
+    /// - Readings in the sensor frame.
+    #[must_use]
+    pub fn body_to_sensor(&self, v: Vector3<f32>) -> Vector3<f32> {
+        // Rotation is orthogonal, so that its inverse is the transpose.
+        self.rotation().transpose() * v
+    }
+
+    /// Rotate readings of the payload sensors into the body frame.
+    ///
+    /// # Parameters
+    /// - `payload` - given payload to rotate.
+    pub fn apply_to(&self, payload: &mut StandardPayload) {
+        if *self == Self::default() {
+            return;
+        }
+
+        let rotation = self.rotation();
+        let rotate = |v: Vector3<f32>| rotation * v;
+
+        if let Some(acc) = payload.acc() {
+            payload.set_acc(rotate(acc));
+        }
+
+        if let Some(gyr) = payload.gyr() {
+            payload.set_gyr(rotate(gyr));
+        }
+
+        if let Some(mag) = payload.mag() {
+            payload.set_mag(rotate(mag));
+        }
+    }
+}
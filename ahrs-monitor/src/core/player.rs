@@ -0,0 +1,208 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Recorded log player.
+//!
+//! Records of the CSV log or events journal are converted back into frame
+//! contexts and released according to their sensor-local timestamps, scaled
+//! by the playback speed, so that the user interface handles them like the
+//! live stream.
+
+use crate::{core::reprocess, logger::LogRecord, model::FrameContext};
+use std::path::Path;
+
+/// Max gap between consecutive records in microseconds.
+///
+/// Longer gaps (e.g. device reboot or sensor clock restart) are shortened,
+/// so that playback does not stall.
+const MAX_GAP_US: u64 = 1_000_000;
+
+/// Max number of records released per update.
+const MAX_RECORDS_PER_UPDATE: usize = 2000;
+
+/// Supported playback speeds.
+pub const PLAYBACK_SPEEDS: [f64; 7] = [0.1, 0.25, 0.5, 1.0, 2.0, 4.0, 8.0];
+
+/// Recorded log player.
+#[derive(Debug)]
+pub struct LogPlayer {
+    /// Records with readings or attitude in recording order.
+    records: Vec<LogRecord>,
+    /// Playback time of each record since the first one in microseconds.
+    offsets_us: Vec<u64>,
+    /// Index of the next record to release.
+    position: usize,
+    /// Current playback time since the first record in microseconds.
+    clock_us: f64,
+    /// Flag whether playback is running.
+    pub is_playing: bool,
+    /// Playback speed multiplier.
+    pub speed: f64,
+}
+
+impl LogPlayer {
+    /// Load recorded log.
+    ///
+    /// # Parameters
+    /// - `path` - given path to the log file (`.csv` - CSV log, events journal
+    ///   otherwise).
+    ///
+    /// # Returns
+    /// - New `LogPlayer` object - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - I/O errors.
+    /// - Malformed log record or journal entry.
+    /// - Log without readings.
+    pub fn load(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let records: Vec<LogRecord> = reprocess::read_log(path)?
+            .into_iter()
+            .filter(|record| !record.is_event())
+            .collect();
+
+        if records.is_empty() {
+            anyhow::bail!("log contains no readings");
+        }
+
+        let mut offsets_us = Vec::with_capacity(records.len());
+        let mut offset_us: u64 = 0;
+        let mut prev: Option<u32> = None;
+
+        for record in &records {
+            if let Some(prev) = prev {
+                // Sensor-local clock wraps around and restarts on reboot.
+                let gap = record.timestamp.wrapping_sub(prev);
+                offset_us += u64::from(gap).min(MAX_GAP_US);
+            }
+
+            offsets_us.push(offset_us);
+            prev = Some(record.timestamp);
+        }
+
+        Ok(Self {
+            records,
+            offsets_us,
+            position: 0,
+            clock_us: 0.0,
+            is_playing: false,
+            speed: 1.0,
+        })
+    }
+
+    /// Get number of records.
+    ///
+    /// # Returns
+    /// - Number of records with readings or attitude.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Check whether there are no records.
+    ///
+    /// # Returns
+    /// - `true` - if log contains no records.
+    /// - `false` - otherwise.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Get index of the next record to release.
+    ///
+    /// # Returns
+    /// - Playback position (number of records equals to the end).
+    #[must_use]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Check whether all records were released.
+    ///
+    /// # Returns
+    /// - `true` - if playback reached the end of the log.
+    /// - `false` - otherwise.
+    #[must_use]
+    pub const fn is_finished(&self) -> bool {
+        self.position >= self.records.len()
+    }
+
+    /// Get current playback time.
+    ///
+    /// # Returns
+    /// - Playback time since the first record in seconds.
+    #[must_use]
+    pub fn elapsed_secs(&self) -> f64 {
+        self.clock_us / 1e6
+    }
+
+    /// Get log duration.
+    ///
+    /// # Returns
+    /// - Playback time of the last record in seconds.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn duration_secs(&self) -> f64 {
+        self.offsets_us.last().copied().unwrap_or_default() as f64 / 1e6
+    }
+
+    /// Move playback position.
+    ///
+    /// # Parameters
+    /// - `position` - given index of the next record to release.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn seek(&mut self, position: usize) {
+        self.position = position.min(self.records.len());
+        self.clock_us = self
+            .offsets_us
+            .get(self.position)
+            .or_else(|| self.offsets_us.last())
+            .copied()
+            .unwrap_or_default() as f64;
+    }
+
+    /// Advance playback time and release records due by then.
+    ///
+    /// # Parameters
+    /// - `dt` - given wall-clock time since the previous update in seconds.
+    ///
+    /// # Returns
+    /// - Frame contexts of the released records in recording order.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn advance(&mut self, dt: f64) -> Vec<FrameContext> {
+        if !self.is_playing || self.is_finished() {
+            self.is_playing = false;
+            return Vec::new();
+        }
+
+        self.clock_us += dt * self.speed * 1e6;
+
+        let due = self
+            .offsets_us
+            .iter()
+            .skip(self.position)
+            .take(MAX_RECORDS_PER_UPDATE)
+            .take_while(|&&offset| offset as f64 <= self.clock_us)
+            .count();
+
+        let released = self
+            .records
+            .iter()
+            .skip(self.position)
+            .take(due)
+            .filter_map(LogRecord::to_frame_context)
+            .collect();
+
+        self.position += due;
+
+        // Playback time does not run ahead of the capped release.
+        if due == MAX_RECORDS_PER_UPDATE
+            && let Some(&offset) = self.offsets_us.get(self.position)
+        {
+            self.clock_us = self.clock_us.min(offset as f64);
+        }
+
+        released
+    }
+}
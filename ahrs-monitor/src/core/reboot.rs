@@ -0,0 +1,99 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Device reboot detection.
+//!
+//! Rebooted device restarts both sequence numbering and the sensor clock, so
+//! that reboot is detected by a frame whose sequence number is reset close to
+//! zero while its timestamp jumps backward. Late frames of the same boot keep
+//! the sensor clock, and sequence wrap-around keeps it moving forward, so
+//! neither of them is mistaken for reboot.
+
+use std::collections::HashMap;
+
+/// Max sequence number of the first frames after reboot.
+const MAX_REBOOT_SEQUENCE: u16 = 128;
+
+/// Min backward jump of the sensor clock after reboot in microseconds.
+const MIN_CLOCK_JUMP_US: u32 = 1_000_000;
+
+/// Newest accepted frame of the device.
+#[derive(Debug, Clone, Copy)]
+struct LastFrame {
+    /// Sequence number.
+    sequence: u16,
+    /// Sensor-local time in microseconds.
+    timestamp: u32,
+}
+
+/// Detector of device reboots.
+#[derive(Debug, Default)]
+pub struct RebootDetector {
+    /// Newest accepted frame by device identifier.
+    last: HashMap<u8, LastFrame>,
+}
+
+impl RebootDetector {
+    /// Check whether frame is the first one after device reboot.
+    ///
+    /// # Parameters
+    /// - `device_id` - given device identifier of the frame.
+    /// - `sequence` - given sequence number of the frame.
+    /// - `timestamp` - given sensor-local time of the frame in microseconds.
+    ///
+    /// # Returns
+    /// - `true` - if both sequence number and sensor clock are reset.
+    /// - `false` - otherwise.
+    #[must_use]
+    pub fn is_reboot(
+        &self,
+        device_id: u8,
+        sequence: u16,
+        timestamp: u32,
+    ) -> bool {
+        self.last.get(&device_id).is_some_and(|last| {
+            sequence < last.sequence
+                && sequence <= MAX_REBOOT_SEQUENCE
+                && timestamp < last.timestamp
+                && last.timestamp - timestamp >= MIN_CLOCK_JUMP_US
+        })
+    }
+
+    /// Remember accepted frame.
+    ///
+    /// # Parameters
+    /// - `device_id` - given device identifier of the frame.
+    /// - `sequence` - given sequence number of the frame.
+    /// - `timestamp` - given sensor-local time of the frame in microseconds.
+    pub fn update(&mut self, device_id: u8, sequence: u16, timestamp: u32) {
+        let is_newer = self.last.get(&device_id).is_none_or(|last| {
+            // Signed distance handles wrap-around of sequence numbers.
+            #[allow(clippy::cast_possible_wrap)]
+            let delta = sequence.wrapping_sub(last.sequence) as i16;
+            delta > 0
+        });
+
+        if is_newer {
+            self.last.insert(
+                device_id,
+                LastFrame {
+                    sequence,
+                    timestamp,
+                },
+            );
+        }
+    }
+
+    /// Forget accepted frames of the device.
+    ///
+    /// # Parameters
+    /// - `device_id` - given device identifier.
+    pub fn forget(&mut self, device_id: u8) {
+        self.last.remove(&device_id);
+    }
+
+    /// Forget accepted frames of all devices (e.g. after connection loss).
+    pub fn reset(&mut self) {
+        self.last.clear();
+    }
+}
@@ -0,0 +1,134 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Duplicated and replayed frames rejection.
+//!
+//! Every device has a sliding window of recently received sequence numbers.
+//! Frames ahead of the window advance it, frames inside the window are
+//! accepted once, and frames behind the window are rejected as stale. Since
+//! sequence numbers are covered by MAC, this also rejects record-and-replay
+//! of the encrypted link traffic.
+
+use crate::model::RejectReason;
+use std::collections::HashMap;
+
+/// Max number of tracked sequence numbers per device.
+pub const MAX_REPLAY_WINDOW: u16 = 128;
+
+/// Recently received sequence numbers of a single device.
+#[derive(Debug, Clone, Copy)]
+struct SequenceWindow {
+    /// Highest received sequence number.
+    highest: u16,
+    /// Bitmap of received sequence numbers (bit N - `highest - N`).
+    received: u128,
+}
+
+/// Per-device duplicated and stale frames detector.
+#[derive(Debug, Default)]
+pub struct ReplayGuard {
+    /// Number of tracked sequence numbers per device (0 - disabled).
+    size: u16,
+    /// Sequence windows by device identifier.
+    windows: HashMap<u8, SequenceWindow>,
+}
+
+impl ReplayGuard {
+    /// Construct new `ReplayGuard` object.
+    ///
+    /// # Parameters
+    /// - `size` - given number of tracked sequence numbers per device.
+    ///
+    /// # Returns
+    /// - New `ReplayGuard` object.
+    #[must_use]
+    pub fn new(size: u16) -> Self {
+        Self {
+            size: size.min(MAX_REPLAY_WINDOW),
+            windows: HashMap::new(),
+        }
+    }
+
+    /// Check whether duplicate rejection is enabled.
+    ///
+    /// # Returns
+    /// - `true` - if window size is not zero.
+    /// - `false` - otherwise.
+    #[must_use]
+    pub const fn is_enabled(&self) -> bool {
+        self.size > 0
+    }
+
+    /// Check frame sequence number and remember it.
+    ///
+    /// # Parameters
+    /// - `device_id` - given frame source device identifier.
+    /// - `sequence` - given frame sequence number.
+    ///
+    /// # Returns
+    /// - `Ok` - if sequence number was not received yet.
+    /// - `Err` - with reason for rejecting the packet otherwise.
+    ///
+    /// # Errors
+    /// - Sequence number was already received.
+    /// - Sequence number is older than the window.
+    pub fn check(
+        &mut self,
+        device_id: u8,
+        sequence: u16,
+    ) -> Result<(), RejectReason> {
+        let Some(window) = self.windows.get_mut(&device_id) else {
+            self.windows.insert(
+                device_id,
+                SequenceWindow {
+                    highest: sequence,
+                    received: 1,
+                },
+            );
+            return Ok(());
+        };
+
+        // Sequence numbers wrap around, so distance is signed.
+        #[allow(clippy::cast_possible_wrap)]
+        let distance = sequence.wrapping_sub(window.highest) as i16;
+
+        if distance > 0 {
+            let shift = u32::from(distance.unsigned_abs());
+
+            window.received =
+                window.received.checked_shl(shift).unwrap_or(0) | 1;
+            window.highest = sequence;
+            return Ok(());
+        }
+
+        let age = distance.unsigned_abs();
+
+        if age >= self.size {
+            return Err(RejectReason::Stale);
+        }
+
+        let bit = 1u128 << age;
+
+        if window.received & bit != 0 {
+            return Err(RejectReason::Duplicate);
+        }
+
+        window.received |= bit;
+        Ok(())
+    }
+
+    /// Forget received sequence numbers of the device.
+    ///
+    /// # Parameters
+    /// - `device_id` - given frame source device identifier.
+    pub fn forget(&mut self, device_id: u8) {
+        self.windows.remove(&device_id);
+    }
+
+    /// Forget received sequence numbers of all devices.
+    ///
+    /// IMU may restart its sequence numbering after reconnect.
+    pub fn reset(&mut self) {
+        self.windows.clear();
+    }
+}
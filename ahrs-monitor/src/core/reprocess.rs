@@ -0,0 +1,212 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Offline re-processing of the recorded logs.
+//!
+//! Recorded sensor readings are fed through the attitude estimator with the
+//! current fusion settings (`imu.fusion`, `[imu.gyro_bias]`), so that filter
+//! tuning changes are evaluated against old flights. Readings are logged
+//! after units conversion, calibration and mounting correction, so that they
+//! are estimated as is. Attitude, linear acceleration and uncertainty columns
+//! are recomputed, other columns are kept as recorded.
+//!
+//! Re-estimated attitude can be smoothed by the zero-phase filter of
+//! `core::smoothing` before it is written.
+//!
+//! Both CSV logs and events journals (`--record-journal`, raw frame bytes)
+//! are accepted. Journals keep readings with the original precision of the
+//! frame payload.
+
+use crate::{
+    config::{AppConfig, ImuConfig},
+    core::attitude::{
+        AttitudeEstimator, GyroBiasEstimator, linear_acceleration,
+    },
+    core::smoothing::smooth_attitude,
+    journal,
+    logger::{LogRecord, read_records, write_records},
+};
+use std::{collections::HashMap, path::Path};
+use tsilna_nav::math::na::Vector3;
+
+/// Min time step between samples in seconds.
+const MIN_TIME_STEP: f32 = 0.0001;
+
+/// Max time step between samples in seconds.
+const MAX_TIME_STEP: f32 = 0.1;
+
+/// Attitude estimation state of a single device.
+struct DeviceState {
+    /// Attitude estimator of the device.
+    estimator: AttitudeEstimator,
+    /// Sensor-local time of the previous sample in microseconds.
+    last_timestamp_us: Option<u32>,
+}
+
+/// Re-process recorded log and write the result into a new log.
+///
+/// # Parameters
+/// - `input` - given path to the CSV log or events journal to read.
+/// - `output` - given path to the CSV log to write.
+/// - `config` - given application's configurations with filter settings.
+/// - `smoothing` - given cutoff frequency of the attitude smoothing filter
+///   in Hz (`None` - attitude is not smoothed).
+///
+/// # Returns
+/// - Number of re-estimated records - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - Log files reading or writing errors.
+pub fn reprocess_log(
+    input: impl AsRef<Path>,
+    output: impl AsRef<Path>,
+    config: &AppConfig,
+    smoothing: Option<f32>,
+) -> anyhow::Result<usize> {
+    let mut records = read_log(input)?;
+    let estimated = reprocess(&mut records, &config.imu);
+
+    if let Some(cutoff) = smoothing {
+        smooth_attitude(&mut records, cutoff);
+    }
+
+    write_records(output, &records, &config.channels)?;
+    Ok(estimated)
+}
+
+/// Read log records from the CSV log or events journal.
+///
+/// # Parameters
+/// - `path` - given path to the log file (`.csv` - CSV log, events journal
+///   otherwise).
+///
+/// # Returns
+/// - Log records in recording order - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - I/O errors.
+/// - Malformed log record or journal entry.
+pub fn read_log(path: impl AsRef<Path>) -> anyhow::Result<Vec<LogRecord>> {
+    let path = path.as_ref();
+    let is_csv = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("csv"));
+
+    if is_csv {
+        return read_records(path);
+    }
+
+    let records = journal::read_frames(path)?
+        .iter()
+        .filter_map(LogRecord::from_frame_context)
+        .collect();
+
+    Ok(records)
+}
+
+/// Re-estimate attitude of the log records.
+///
+/// Devices are estimated independently. Records without accelerometer,
+/// gyroscope or magnetometer readings (e.g. onboard attitude) are kept.
+///
+/// # Parameters
+/// - `records` - given log records in recording order.
+/// - `imu` - given IMU configurations with filter settings.
+///
+/// # Returns
+/// - Number of re-estimated records.
+pub fn reprocess(records: &mut [LogRecord], imu: &ImuConfig) -> usize {
+    let mut devices: HashMap<u8, DeviceState> = HashMap::new();
+    let mut estimated: usize = 0;
+
+    for record in records {
+        let acc = vector(record.acc_x, record.acc_y, record.acc_z);
+        let gyr = vector(record.gyr_x, record.gyr_y, record.gyr_z);
+        let mag = vector(record.mag_x, record.mag_y, record.mag_z);
+
+        if acc.is_none() && gyr.is_none() && mag.is_none() {
+            continue;
+        }
+
+        let device =
+            devices
+                .entry(record.device_id)
+                .or_insert_with(|| DeviceState {
+                    estimator: AttitudeEstimator::new(imu.fusion)
+                        .with_gyro_bias(GyroBiasEstimator::new(imu.gyro_bias)),
+                    last_timestamp_us: None,
+                });
+
+        let dt = time_step(
+            device.last_timestamp_us.replace(record.timestamp),
+            record.timestamp,
+            imu.sample_rate,
+        );
+
+        let estimator = &mut device.estimator;
+        let quaternion = match (acc, gyr, mag) {
+            (Some(acc), Some(gyr), Some(mag)) => {
+                estimator.estimate_marg(acc, gyr, mag, dt)
+            }
+            (Some(acc), Some(gyr), None) => {
+                estimator.estimate_imu(acc, gyr, dt)
+            }
+            // Attitude is not observable from gyroscope readings alone.
+            (None, Some(_), None) => continue,
+            _ => estimator.estimate_partial(acc, mag),
+        };
+
+        record.set_attitude(&quaternion);
+        record.attitude_uncertainty = estimator.uncertainty();
+
+        if let (Some(acc), Some(_)) = (acc, gyr) {
+            let [x, y, z] = linear_acceleration(&quaternion, acc).into();
+
+            record.lin_acc_x = Some(x);
+            record.lin_acc_y = Some(y);
+            record.lin_acc_z = Some(z);
+        }
+
+        estimated += 1;
+    }
+
+    estimated
+}
+
+/// Get vector of the sensor readings.
+///
+/// # Parameters
+/// - `x` - given reading along the X-axis.
+/// - `y` - given reading along the Y-axis.
+/// - `z` - given reading along the Z-axis.
+///
+/// # Returns
+/// - Vector of readings - if all axes are recorded.
+/// - `None` - otherwise.
+fn vector(
+    x: Option<f32>,
+    y: Option<f32>,
+    z: Option<f32>,
+) -> Option<Vector3<f32>> {
+    Some(Vector3::new(x?, y?, z?))
+}
+
+/// Get time step between samples.
+///
+/// # Parameters
+/// - `prev` - given sensor-local time of the previous sample in
+///   microseconds (`None` - first sample).
+/// - `timestamp` - given sensor-local time of the sample in microseconds.
+/// - `sample_rate` - given nominal sample rate in Hz.
+///
+/// # Returns
+/// - Time step in seconds (nominal sample period for the first sample).
+#[allow(clippy::cast_precision_loss)]
+fn time_step(prev: Option<u32>, timestamp: u32, sample_rate: f32) -> f32 {
+    prev.map_or(1.0 / sample_rate, |prev| {
+        let diff = timestamp.wrapping_sub(prev);
+        (diff as f32 / 1_000_000.0).clamp(MIN_TIME_STEP, MAX_TIME_STEP)
+    })
+}
@@ -0,0 +1,151 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Shock (impact) detection.
+//!
+//! Shock starts when the acceleration magnitude exceeds the configured
+//! threshold and ends when it falls back below it. The event is reported at
+//! the end of the shock with its peak magnitude and duration, so that a
+//! single impact produces a single event. Ringing of the structure right
+//! after the impact is suppressed by the hold-off time.
+
+use crate::{config::ShockConfig, core::calibration::STANDARD_GRAVITY};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use tsilna_nav::math::na::Vector3;
+
+/// Detected shock event.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ShockEvent {
+    /// Vendor-specific unique IMU device identifier.
+    pub device_id: u8,
+    /// Sensor-local time of the peak in microseconds.
+    pub timestamp: u32,
+    /// Estimated host time of the peak in Unix microseconds.
+    pub host_time_us: Option<i64>,
+    /// Peak acceleration magnitude in g.
+    pub peak_g: f32,
+    /// Time above the threshold in microseconds.
+    pub duration_us: u32,
+}
+
+impl fmt::Display for ShockEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "shock {:.1} g over {:.1} ms",
+            self.peak_g,
+            f64::from(self.duration_us) / 1000.0
+        )
+    }
+}
+
+/// Shock in progress.
+#[derive(Debug, Clone, Copy)]
+struct Excursion {
+    /// Sensor-local time of the first sample above the threshold.
+    started_at: u32,
+    /// Peak of the shock.
+    peak: ShockEvent,
+}
+
+/// Shock detector of the accelerometer readings.
+#[derive(Debug)]
+pub struct ShockDetector {
+    /// Shock detection configurations.
+    cfg: ShockConfig,
+    /// Shock in progress (`None` - magnitude is below the threshold).
+    excursion: Option<Excursion>,
+    /// Sensor-local time of the last shock end in microseconds.
+    last_end: Option<u32>,
+}
+
+impl ShockDetector {
+    /// Construct new `ShockDetector` object.
+    ///
+    /// # Parameters
+    /// - `cfg` - given shock detection configurations.
+    ///
+    /// # Returns
+    /// - New `ShockDetector` object.
+    #[must_use]
+    pub const fn new(cfg: ShockConfig) -> Self {
+        Self {
+            cfg,
+            excursion: None,
+            last_end: None,
+        }
+    }
+
+    /// Update detector with the accelerometer readings.
+    ///
+    /// # Parameters
+    /// - `device_id` - given device identifier.
+    /// - `acc` - given accelerometer readings in m/s².
+    /// - `timestamp` - given sensor-local time in microseconds.
+    /// - `host_time_us` - given estimated host time in Unix microseconds.
+    ///
+    /// # Returns
+    /// - Shock event - if shock ended at this sample.
+    /// - `None` - otherwise.
+    pub fn update(
+        &mut self,
+        device_id: u8,
+        acc: Vector3<f32>,
+        timestamp: u32,
+        host_time_us: Option<i64>,
+    ) -> Option<ShockEvent> {
+        let magnitude = acc.norm() / STANDARD_GRAVITY;
+
+        if magnitude < self.cfg.threshold {
+            let excursion = self.excursion.take()?;
+            self.last_end = Some(timestamp);
+
+            return Some(ShockEvent {
+                duration_us: timestamp.wrapping_sub(excursion.started_at),
+                ..excursion.peak
+            });
+        }
+
+        let sample = ShockEvent {
+            device_id,
+            timestamp,
+            host_time_us,
+            peak_g: magnitude,
+            duration_us: 0,
+        };
+
+        match &mut self.excursion {
+            Some(excursion) => {
+                if magnitude > excursion.peak.peak_g {
+                    excursion.peak = sample;
+                }
+            }
+            None if !self.is_holding_off(timestamp) => {
+                self.excursion = Some(Excursion {
+                    started_at: timestamp,
+                    peak: sample,
+                });
+            }
+            None => {}
+        }
+
+        None
+    }
+
+    /// Check whether the sample is within hold-off time of the last shock.
+    ///
+    /// # Parameters
+    /// - `timestamp` - given sensor-local time in microseconds.
+    ///
+    /// # Returns
+    /// - `true` - if new shocks are suppressed.
+    /// - `false` - otherwise.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn is_holding_off(&self, timestamp: u32) -> bool {
+        let hold_off_us = (self.cfg.hold_off * 1_000_000.0) as u32;
+
+        self.last_end
+            .is_some_and(|end| timestamp.wrapping_sub(end) < hold_off_us)
+    }
+}
@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Zero-phase smoothing of the recorded attitude.
+//!
+//! Attitude track is passed through the first-order low-pass filter forward
+//! and then backward in time. Phase lag of the forward pass is cancelled by
+//! the backward one, so that smoothed attitude is not delayed relative to
+//! the other recorded channels. Quaternions are blended on the shorter path
+//! and normalized, Euler angles, rotation matrix and axis-angle columns are
+//! recomputed from the smoothed quaternions.
+
+use crate::logger::LogRecord;
+use std::f32::consts::PI;
+use tsilna_nav::math::{Quat32, na::Quaternion};
+
+/// Default cutoff frequency of the smoothing filter in Hz.
+pub const DEFAULT_CUTOFF: f32 = 5.0;
+
+/// Smooth attitude columns of the log records.
+///
+/// # Parameters
+/// - `records` - given log records of a single session in recording order.
+/// - `cutoff` - given cutoff frequency of the filter in Hz.
+pub fn smooth_attitude(records: &mut [LogRecord], cutoff: f32) {
+    let mut attitudes: Vec<Quaternion<f32>> = records
+        .iter()
+        .map(|r| Quaternion::new(r.q_w, r.q_x, r.q_y, r.q_z))
+        .collect();
+
+    // Filter weight of each sample relative to the previous one.
+    let weights: Vec<f32> = records
+        .windows(2)
+        .map(|pair| match pair {
+            [prev, next] => weight(prev.timestamp, next.timestamp, cutoff),
+            _ => 1.0,
+        })
+        .collect();
+
+    // Forward pass.
+    for (i, weight) in weights.iter().enumerate() {
+        if let (Some(&prev), Some(next)) =
+            (attitudes.get(i), attitudes.get_mut(i + 1))
+        {
+            *next = blend(prev, *next, *weight);
+        }
+    }
+
+    // Backward pass.
+    for (i, weight) in weights.iter().enumerate().rev() {
+        if let (Some(&prev), Some(next)) =
+            (attitudes.get(i + 1), attitudes.get_mut(i))
+        {
+            *next = blend(prev, *next, *weight);
+        }
+    }
+
+    for (record, attitude) in records.iter_mut().zip(attitudes) {
+        record.set_attitude(&Quat32::from_quaternion(attitude));
+    }
+}
+
+/// Get filter weight of the sample.
+///
+/// # Parameters
+/// - `prev` - given sensor-local time of the previous sample in
+///   microseconds.
+/// - `next` - given sensor-local time of the sample in microseconds.
+/// - `cutoff` - given cutoff frequency of the filter in Hz.
+///
+/// # Returns
+/// - Weight of the sample from 0 to 1 (1 - sample is kept as is).
+#[allow(clippy::cast_precision_loss)]
+fn weight(prev: u32, next: u32, cutoff: f32) -> f32 {
+    let dt = next.wrapping_sub(prev) as f32 / 1_000_000.0;
+    1.0 - (-2.0 * PI * cutoff * dt).exp()
+}
+
+/// Blend attitude with the filtered previous one.
+///
+/// # Parameters
+/// - `prev` - given filtered attitude of the previous sample.
+/// - `next` - given attitude of the sample.
+/// - `weight` - given weight of the sample.
+///
+/// # Returns
+/// - Filtered attitude of the sample.
+fn blend(
+    prev: Quaternion<f32>,
+    next: Quaternion<f32>,
+    weight: f32,
+) -> Quaternion<f32> {
+    // Opposite quaternions represent the same attitude.
+    let next = if prev.dot(&next) < 0.0 { -next } else { next };
+    prev.lerp(&next, weight).normalize()
+}
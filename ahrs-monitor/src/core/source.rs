@@ -0,0 +1,722 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Sources of raw INDTP frames.
+
+use crate::config::{AppConfig, NetConfig, Transport};
+#[cfg(target_os = "linux")]
+use crate::core::can::CanSocket;
+use crate::core::generator::SignalGenerator;
+use indtp::MTU_SIZE;
+use serde::{Deserialize, Serialize};
+use socket2::{Domain, Protocol, Socket, Type};
+#[cfg(target_os = "linux")]
+use std::os::fd::AsRawFd;
+use std::{io, net::SocketAddr, time::Duration};
+#[cfg(unix)]
+use tokio::net::UnixDatagram;
+use tokio::{
+    io::AsyncReadExt,
+    net::{UdpSocket, lookup_host},
+    sync::mpsc,
+    time::{Instant, Interval, MissedTickBehavior, interval},
+};
+
+/// Max number of frames read ahead from the byte stream.
+const STREAM_QUEUE_SIZE: usize = 64;
+
+/// Keepalive datagram content (dropped by devices as a malformed frame).
+const KEEPALIVE_MESSAGE: &[u8] = b"ahrs-monitor keepalive";
+
+/// Periodic keepalive datagrams sender (client-initiated mode).
+///
+/// Datagrams are sent from the listening socket, so that NAT and firewalls
+/// treat telemetry from the device as reply traffic.
+pub struct Keepalive {
+    /// Device UDP endpoint.
+    target: SocketAddr,
+    /// Keepalive interval ticker.
+    ticker: Interval,
+}
+
+impl Keepalive {
+    /// Construct new `Keepalive` object.
+    ///
+    /// # Parameters
+    /// - `device_address` - given device UDP endpoint (`host:port`).
+    /// - `interval_ms` - given keepalive interval in milliseconds.
+    ///
+    /// # Returns
+    /// - New `Keepalive` object - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - Device address resolution errors.
+    pub async fn new(
+        device_address: &str,
+        interval_ms: u64,
+    ) -> io::Result<Self> {
+        let target =
+            lookup_host(device_address).await?.next().ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "device address \"{device_address}\" is not resolved"
+                    ),
+                )
+            })?;
+
+        let mut ticker = interval(Duration::from_millis(interval_ms));
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        Ok(Self { target, ticker })
+    }
+
+    /// Wait for the keepalive time and send keepalive datagram.
+    ///
+    /// Sending errors are not fatal (e.g. network is temporarily down),
+    /// since the next keepalive may succeed.
+    ///
+    /// # Parameters
+    /// - `socket` - given listening socket to send from.
+    async fn send(&mut self, socket: &UdpSocket) {
+        self.ticker.tick().await;
+
+        if let Err(e) = socket.send_to(KEEPALIVE_MESSAGE, self.target).await {
+            log::warn!("Failed to send keepalive to {}: {e}", self.target);
+        }
+    }
+}
+
+/// Transport delivering raw INDTP frames to the ingester.
+pub enum FrameSource {
+    /// UDP socket receiving one frame per datagram with optional keepalive
+    /// datagrams sender.
+    Udp(UdpSocket, Option<Keepalive>),
+    /// Unix domain datagram socket receiving one frame per datagram.
+    #[cfg(unix)]
+    Unix(UnixDatagram),
+    /// Standard input stream of frames, each prefixed with 2-byte
+    /// little-endian frame length. Frames are read by a dedicated task,
+    /// since stream reads are not cancellation safe. The task can not be
+    /// stopped while it waits for input, so that standard input is opened
+    /// once and never reopened.
+    Stdin(mpsc::Receiver<io::Result<Vec<u8>>>),
+    /// `SocketCAN` raw socket receiving segmented frames.
+    #[cfg(target_os = "linux")]
+    Can(CanSocket),
+    /// Built-in test-signal generator producing frames at the sample rate.
+    Generator(Box<SignalGenerator>),
+}
+
+impl FrameSource {
+    /// Open frame source according to the networks configurations.
+    ///
+    /// # Parameters
+    /// - `app_cfg` - given application's configurations to handle.
+    ///
+    /// # Returns
+    /// - New frame source - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - Socket binding errors.
+    /// - Device address resolution errors.
+    /// - Unsupported transport on the current platform.
+    /// - Unsupported payload type of the test-signal generator.
+    pub async fn open(app_cfg: &AppConfig) -> io::Result<Self> {
+        let cfg = &app_cfg.net;
+
+        match cfg.transport {
+            Transport::Udp => {
+                let socket = Self::bind_udp(cfg).await?;
+
+                log::info!("Listening on {} (UDP)", socket.local_addr()?);
+
+                let keepalive = if cfg.keepalive_interval_ms > 0 {
+                    let keepalive = Keepalive::new(
+                        &cfg.device_address,
+                        cfg.keepalive_interval_ms,
+                    )
+                    .await?;
+
+                    log::info!(
+                        "Sending keepalive to {} every {} ms",
+                        keepalive.target,
+                        cfg.keepalive_interval_ms
+                    );
+                    Some(keepalive)
+                } else {
+                    None
+                };
+
+                Ok(Self::Udp(socket, keepalive))
+            }
+            Transport::Unix => Self::open_unix(&cfg.unix_socket_path),
+            Transport::Stdin => {
+                log::info!("Reading length-prefixed frames from stdin");
+                Ok(Self::Stdin(spawn_stream_reader(tokio::io::stdin())))
+            }
+            Transport::Can => Self::open_can(&cfg.can_interface, cfg.can_id),
+            Transport::Generator => {
+                Ok(Self::Generator(Box::new(SignalGenerator::new(app_cfg)?)))
+            }
+        }
+    }
+
+    /// Bind UDP socket with configured OS-level tuning options.
+    ///
+    /// # Parameters
+    /// - `cfg` - given networks configurations to handle.
+    ///
+    /// # Returns
+    /// - Bound UDP socket - in case of success.
+    /// - `Err` - otherwise.
+    async fn bind_udp(cfg: &NetConfig) -> io::Result<UdpSocket> {
+        let pair = (cfg.ip_address.as_str(), cfg.udp_port);
+        let addr = lookup_host(pair).await?.next().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::AddrNotAvailable,
+                format!("cannot resolve address: {}", cfg.ip_address),
+            )
+        })?;
+
+        let socket = Socket::new(
+            Domain::for_address(addr),
+            Type::DGRAM,
+            Some(Protocol::UDP),
+        )?;
+
+        if cfg.recv_buffer_size > 0 {
+            socket.set_recv_buffer_size(cfg.recv_buffer_size)?;
+
+            // OS silently caps the size (e.g. by net.core.rmem_max on Linux).
+            let size = socket.recv_buffer_size()?;
+
+            if size < cfg.recv_buffer_size {
+                log::warn!(
+                    "UDP receive buffer is limited to {size} bytes ({} bytes \
+                     requested)",
+                    cfg.recv_buffer_size
+                );
+            }
+        }
+
+        if cfg.dscp > 0 {
+            if addr.is_ipv4() {
+                // DSCP occupies 6 upper bits of the TOS field.
+                socket.set_tos_v4(u32::from(cfg.dscp) << 2)?;
+            } else {
+                log::warn!("DSCP marking is supported for IPv4 sockets only");
+            }
+        }
+
+        #[cfg(target_os = "linux")]
+        if cfg.busy_poll_us > 0 {
+            set_busy_poll(&socket, cfg.busy_poll_us)?;
+        }
+
+        socket.set_nonblocking(true)?;
+        socket.bind(&addr.into())?;
+
+        UdpSocket::from_std(socket.into())
+    }
+
+    /// Open `SocketCAN` frame source.
+    ///
+    /// # Parameters
+    /// - `interface` - given CAN network interface name.
+    /// - `can_id` - given CAN identifier of frame segments.
+    ///
+    /// # Returns
+    /// - New frame source - in case of success.
+    /// - `Err` - otherwise.
+    #[cfg(target_os = "linux")]
+    fn open_can(interface: &str, can_id: u32) -> io::Result<Self> {
+        Ok(Self::Can(CanSocket::open(interface, can_id)?))
+    }
+
+    /// Open `SocketCAN` frame source.
+    ///
+    /// # Parameters
+    /// - `interface` - given CAN network interface name.
+    /// - `can_id` - given CAN identifier of frame segments.
+    ///
+    /// # Returns
+    /// - `Err` - `SocketCAN` is not supported on this platform.
+    #[cfg(not(target_os = "linux"))]
+    fn open_can(_: &str, _: u32) -> io::Result<Self> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "SocketCAN is supported on Linux only",
+        ))
+    }
+
+    /// Open Unix domain datagram socket frame source.
+    ///
+    /// # Parameters
+    /// - `path` - given socket file path to bind.
+    ///
+    /// # Returns
+    /// - New frame source - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - Path exists and is not a socket.
+    /// - Socket binding errors.
+    #[cfg(unix)]
+    fn open_unix(path: &str) -> io::Result<Self> {
+        use std::os::unix::fs::FileTypeExt;
+
+        // Removing stale socket file left by the previous run, other files
+        // are never deleted.
+        match std::fs::symlink_metadata(path) {
+            Ok(metadata) if metadata.file_type().is_socket() => {
+                std::fs::remove_file(path)?;
+            }
+            Ok(_) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::AlreadyExists,
+                    format!("{path} exists and is not a socket"),
+                ));
+            }
+            Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+            Err(_) => {}
+        }
+
+        let socket = UnixDatagram::bind(path)?;
+
+        log::info!("Listening on {path} (Unix domain socket)");
+        Ok(Self::Unix(socket))
+    }
+
+    /// Open Unix domain datagram socket frame source.
+    ///
+    /// # Parameters
+    /// - `path` - given socket file path to bind.
+    ///
+    /// # Returns
+    /// - `Err` - Unix domain sockets are not supported on this platform.
+    #[cfg(not(unix))]
+    fn open_unix(_: &str) -> io::Result<Self> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "Unix domain sockets are not supported on this platform",
+        ))
+    }
+
+    /// Receive single raw frame.
+    ///
+    /// # Parameters
+    /// - `buffer` - given buffer to store received frame.
+    ///
+    /// # Returns
+    /// - Number of received bytes - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - Receiving data errors.
+    /// - End of the input stream (`UnexpectedEof`).
+    pub async fn recv(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+        match self {
+            Self::Udp(socket, None) => Ok(socket.recv_from(buffer).await?.0),
+            Self::Udp(socket, Some(keepalive)) => loop {
+                tokio::select! {
+                    recv = socket.recv_from(buffer) => return Ok(recv?.0),
+                    () = keepalive.send(socket) => {}
+                }
+            },
+            #[cfg(unix)]
+            Self::Unix(socket) => socket.recv(buffer).await,
+            Self::Stdin(rx) => match rx.recv().await {
+                Some(Ok(frame)) => copy_frame(&frame, buffer),
+                Some(Err(e)) => Err(e),
+                None => Err(io::ErrorKind::UnexpectedEof.into()),
+            },
+            #[cfg(target_os = "linux")]
+            Self::Can(socket) => copy_frame(socket.recv().await?, buffer),
+            Self::Generator(generator) => {
+                copy_frame(&generator.next_frame().await?, buffer)
+            }
+        }
+    }
+}
+
+/// Link delivering raw INDTP frames.
+#[derive(
+    Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq,
+)]
+pub enum Link {
+    /// Primary link.
+    #[default]
+    Primary,
+    /// Secondary (redundant) link.
+    Secondary,
+}
+
+/// Frame statistics of a single link.
+#[derive(
+    Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq,
+)]
+pub struct LinkStats {
+    /// Number of frames received over the link.
+    pub received: usize,
+    /// Number of frames dropped as copies of the ones already received over
+    /// the other link.
+    pub duplicates: usize,
+    /// Number of frames received over the link in the last second.
+    pub pps: usize,
+    /// Number of frames received since the last packets per second update.
+    #[serde(skip)]
+    window: usize,
+}
+
+impl LinkStats {
+    /// Get number of frames delivered first over the link.
+    ///
+    /// # Returns
+    /// - Number of received frames which are not duplicates.
+    #[must_use]
+    pub const fn unique(&self) -> usize {
+        self.received.saturating_sub(self.duplicates)
+    }
+}
+
+/// Frame statistics of redundant links.
+#[derive(
+    Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq,
+)]
+pub struct RedundancyStats {
+    /// Primary link statistics.
+    pub primary: LinkStats,
+    /// Secondary link statistics.
+    pub secondary: LinkStats,
+}
+
+impl RedundancyStats {
+    /// Get statistics of the link.
+    ///
+    /// # Parameters
+    /// - `link` - given link to get statistics of.
+    ///
+    /// # Returns
+    /// - Link statistics.
+    #[must_use]
+    pub const fn get(&self, link: Link) -> &LinkStats {
+        match link {
+            Link::Primary => &self.primary,
+            Link::Secondary => &self.secondary,
+        }
+    }
+
+    /// Get mutable statistics of the link.
+    ///
+    /// # Parameters
+    /// - `link` - given link to get statistics of.
+    ///
+    /// # Returns
+    /// - Mutable link statistics.
+    const fn get_mut(&mut self, link: Link) -> &mut LinkStats {
+        match link {
+            Link::Primary => &mut self.primary,
+            Link::Secondary => &mut self.secondary,
+        }
+    }
+}
+
+/// Primary frame source with optional redundant secondary one.
+pub struct LinkSet {
+    /// Primary link frame source.
+    primary: FrameSource,
+    /// Secondary link frame source.
+    secondary: Option<FrameSource>,
+    /// Buffer for frames received over the secondary link.
+    secondary_buffer: Vec<u8>,
+    /// Time without primary link frames before failover.
+    failover_timeout: Duration,
+    /// Time of the last frame received over the primary link.
+    last_primary_time: Instant,
+    /// Currently active link.
+    active: Link,
+    /// Link of the last received frame.
+    last: Link,
+    /// Frame statistics of each link.
+    stats: RedundancyStats,
+}
+
+impl LinkSet {
+    /// Open frame sources according to the networks configurations.
+    ///
+    /// Secondary link failure is not fatal, monitor keeps using the primary
+    /// link only.
+    ///
+    /// # Parameters
+    /// - `app_cfg` - given application's configurations to handle.
+    ///
+    /// # Returns
+    /// - New set of frame sources - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - Primary frame source opening errors.
+    pub async fn open(app_cfg: &AppConfig) -> io::Result<Self> {
+        let cfg = &app_cfg.net;
+        let primary = FrameSource::open(app_cfg).await?;
+
+        let secondary = match cfg.secondary_net() {
+            Some(net) => FrameSource::open(&AppConfig {
+                net,
+                ..app_cfg.clone()
+            })
+            .await
+            .inspect_err(|e| log::error!("Secondary link disabled: {e}"))
+            .ok(),
+            None => None,
+        };
+
+        let failover_timeout_ms =
+            cfg.secondary.as_ref().map_or(0, |s| s.failover_timeout_ms);
+
+        Ok(Self {
+            primary,
+            secondary,
+            secondary_buffer: vec![0u8; MTU_SIZE],
+            failover_timeout: Duration::from_millis(failover_timeout_ms),
+            last_primary_time: Instant::now(),
+            active: Link::Primary,
+            last: Link::Primary,
+            stats: RedundancyStats::default(),
+        })
+    }
+
+    /// Check whether secondary link is open.
+    ///
+    /// # Returns
+    /// - `true` - if frames are received over two links.
+    /// - `false` - otherwise.
+    #[must_use]
+    pub const fn is_redundant(&self) -> bool {
+        self.secondary.is_some()
+    }
+
+    /// Get currently active link.
+    ///
+    /// # Returns
+    /// - Primary link - while it delivers frames.
+    /// - Secondary link - after primary link is quiet for failover timeout.
+    #[must_use]
+    pub const fn active(&self) -> Link {
+        self.active
+    }
+
+    /// Get frame statistics of each link.
+    ///
+    /// # Returns
+    /// - Statistics of primary and secondary links.
+    #[must_use]
+    pub const fn stats(&self) -> RedundancyStats {
+        self.stats
+    }
+
+    /// Count the last received frame as a copy received over the other link.
+    pub const fn record_duplicate(&mut self) {
+        self.stats.get_mut(self.last).duplicates += 1;
+    }
+
+    /// Update packets per second of each link (called once per second).
+    pub fn update_rates(&mut self) {
+        for stats in [&mut self.stats.primary, &mut self.stats.secondary] {
+            stats.pps = stats.window;
+            stats.window = 0;
+        }
+    }
+
+    /// Receive single raw frame over any link.
+    ///
+    /// # Parameters
+    /// - `buffer` - given buffer to store received frame.
+    ///
+    /// # Returns
+    /// - Number of received bytes - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - Receiving data errors of the primary link.
+    /// - End of the primary input stream (`UnexpectedEof`).
+    pub async fn recv(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+        loop {
+            let Some(secondary) = &mut self.secondary else {
+                let len = self.primary.recv(buffer).await?;
+                self.update_active(Link::Primary);
+                return Ok(len);
+            };
+
+            tokio::select! {
+                recv = self.primary.recv(buffer) => {
+                    let len = recv?;
+                    self.update_active(Link::Primary);
+                    return Ok(len);
+                }
+                recv = secondary.recv(&mut self.secondary_buffer) => {
+                    let frame = recv.and_then(|len| {
+                        self.secondary_buffer
+                            .get(..len)
+                            .ok_or_else(|| io::ErrorKind::InvalidData.into())
+                    });
+
+                    match frame {
+                        Ok(frame) => {
+                            let len = copy_frame(frame, buffer)?;
+                            self.update_active(Link::Secondary);
+                            return Ok(len);
+                        }
+                        Err(e) => {
+                            log::error!("Secondary link failed, disabling: {e}");
+                            self.secondary = None;
+                            self.active = Link::Primary;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Update active link and statistics after receiving frame.
+    ///
+    /// # Parameters
+    /// - `link` - given link the frame is received over.
+    fn update_active(&mut self, link: Link) {
+        let stats = self.stats.get_mut(link);
+
+        stats.received += 1;
+        stats.window += 1;
+        self.last = link;
+
+        match link {
+            Link::Primary => {
+                self.last_primary_time = Instant::now();
+
+                if self.active == Link::Secondary {
+                    log::info!("Primary link resumed, switching back");
+                    self.active = Link::Primary;
+                }
+            }
+            Link::Secondary => {
+                let is_quiet =
+                    self.last_primary_time.elapsed() >= self.failover_timeout;
+
+                if self.active == Link::Primary && is_quiet {
+                    log::warn!(
+                        "Primary link is quiet for {:?}, failing over to \
+                         secondary link",
+                        self.failover_timeout
+                    );
+                    self.active = Link::Secondary;
+                }
+            }
+        }
+    }
+}
+
+/// Copy received frame into the buffer.
+///
+/// # Parameters
+/// - `frame` - given received frame bytes.
+/// - `buffer` - given buffer to store frame.
+///
+/// # Returns
+/// - Number of copied bytes - in case of success.
+/// - `Err` - if frame does not fit into buffer.
+fn copy_frame(frame: &[u8], buffer: &mut [u8]) -> io::Result<usize> {
+    let len = frame.len();
+    let dst = buffer.get_mut(..len).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("frame length {len} exceeds buffer size"),
+        )
+    })?;
+
+    dst.copy_from_slice(frame);
+    Ok(len)
+}
+
+/// Spawn task reading length-prefixed frames from the byte stream.
+///
+/// # Parameters
+/// - `stream` - given byte stream to read.
+///
+/// # Returns
+/// - Receiver of frames read from the stream.
+fn spawn_stream_reader<R>(mut stream: R) -> mpsc::Receiver<io::Result<Vec<u8>>>
+where
+    R: AsyncReadExt + Unpin + Send + 'static,
+{
+    let (tx, rx) = mpsc::channel(STREAM_QUEUE_SIZE);
+
+    tokio::spawn(async move {
+        loop {
+            let result = recv_length_prefixed(&mut stream).await;
+            let is_err = result.is_err();
+
+            if tx.send(result).await.is_err() || is_err {
+                break;
+            }
+        }
+    });
+
+    rx
+}
+
+/// Receive single length-prefixed frame from the byte stream.
+///
+/// # Parameters
+/// - `stream` - given byte stream to read.
+///
+/// # Returns
+/// - Received frame bytes - in case of success.
+/// - `Err` - otherwise.
+async fn recv_length_prefixed<R: AsyncReadExt + Unpin>(
+    stream: &mut R,
+) -> io::Result<Vec<u8>> {
+    let mut prefix = [0u8; 2];
+    stream.read_exact(&mut prefix).await?;
+
+    let mut frame = vec![0u8; usize::from(u16::from_le_bytes(prefix))];
+    stream.read_exact(&mut frame).await?;
+
+    Ok(frame)
+}
+
+/// Enable busy polling of the socket receive queue.
+///
+/// # Parameters
+/// - `socket` - given socket to handle.
+/// - `busy_poll_us` - given busy polling time in microseconds.
+///
+/// # Returns
+/// - `Ok` - in case of success.
+/// - `Err` - otherwise.
+#[cfg(target_os = "linux")]
+#[allow(clippy::cast_possible_truncation)]
+fn set_busy_poll(socket: &Socket, busy_poll_us: u32) -> io::Result<()> {
+    let value = libc::c_int::try_from(busy_poll_us).map_err(|_| {
+        io::Error::new(io::ErrorKind::InvalidInput, "busy polling is too long")
+    })?;
+
+    // SAFETY: `value` points to initialized `c_int` of given size.
+    let result = unsafe {
+        libc::setsockopt(
+            socket.as_raw_fd(),
+            libc::SOL_SOCKET,
+            libc::SO_BUSY_POLL,
+            std::ptr::from_ref(&value).cast(),
+            size_of::<libc::c_int>() as libc::socklen_t,
+        )
+    };
+
+    if result < 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,394 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Frequency spectrum of sensor readings.
+//!
+//! Latest readings of each accelerometer and gyroscope axis are kept in a
+//! sliding buffer. Spectrum is computed by the radix-2 FFT of the buffer
+//! multiplied by the Hann window, which reduces leakage of strong vibration
+//! peaks (e.g. propeller blade pass frequency) into the neighbouring bins.
+//!
+//! Spectra of the overlapping buffers are kept in a spectrogram, so that
+//! intermittent vibration can be located in time.
+
+use crate::core::StandardPayload;
+use std::{collections::VecDeque, f64::consts::PI};
+use tsilna_nav::math::na::{Complex, Vector3};
+
+/// Supported FFT sizes in samples.
+pub const FFT_SIZES: [usize; 5] = [256, 512, 1024, 2048, 4096];
+
+/// Default FFT size in samples.
+pub const DEFAULT_FFT_SIZE: usize = 1024;
+
+/// Number of analyzed channels (accelerometer and gyroscope axes).
+pub const CHANNELS: usize = 6;
+
+/// Max number of spectra kept in the spectrogram.
+pub const SPECTROGRAM_LENGTH: usize = 300;
+
+/// Amplitude spectrum of a single channel.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Spectrum {
+    /// Frequency resolution (bin width) in Hz.
+    pub resolution: f64,
+    /// Amplitudes of the frequency bins from DC to Nyquist frequency in
+    /// units of the readings.
+    pub amplitudes: Vec<f64>,
+}
+
+impl Spectrum {
+    /// Get frequency and amplitude of the bins.
+    ///
+    /// # Returns
+    /// - Iterator over frequency (Hz) and amplitude pairs.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn bins(&self) -> impl Iterator<Item = (f64, f64)> + '_ {
+        self.amplitudes
+            .iter()
+            .enumerate()
+            .map(|(i, &amplitude)| (i as f64 * self.resolution, amplitude))
+    }
+
+    /// Get the strongest vibration peak.
+    ///
+    /// # Returns
+    /// - Frequency (Hz) and amplitude of the highest bin excluding DC - if
+    ///   spectrum is not empty.
+    /// - `None` - otherwise.
+    #[must_use]
+    pub fn peak(&self) -> Option<(f64, f64)> {
+        self.bins().skip(1).max_by(|a, b| a.1.total_cmp(&b.1))
+    }
+}
+
+/// Sliding buffer spectrum analyzer.
+#[derive(Debug)]
+pub struct SpectrumAnalyzer {
+    /// FFT size in samples.
+    size: usize,
+    /// Latest readings by channel (accelerometer, gyroscope axes).
+    samples: [VecDeque<f64>; CHANNELS],
+    /// Sensor-local times of the latest readings in microseconds.
+    timestamps: VecDeque<u32>,
+}
+
+impl Default for SpectrumAnalyzer {
+    /// Construct analyzer with default FFT size.
+    ///
+    /// # Returns
+    /// - New `SpectrumAnalyzer` object.
+    fn default() -> Self {
+        Self::new(DEFAULT_FFT_SIZE)
+    }
+}
+
+impl SpectrumAnalyzer {
+    /// Construct new `SpectrumAnalyzer` object.
+    ///
+    /// # Parameters
+    /// - `size` - given FFT size in samples (rounded up to power of two).
+    ///
+    /// # Returns
+    /// - New `SpectrumAnalyzer` object.
+    #[must_use]
+    pub fn new(size: usize) -> Self {
+        Self {
+            size: size.max(2).next_power_of_two(),
+            samples: Default::default(),
+            timestamps: VecDeque::new(),
+        }
+    }
+
+    /// Get FFT size.
+    ///
+    /// # Returns
+    /// - FFT size in samples.
+    #[must_use]
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Get fill level of the sliding buffer.
+    ///
+    /// # Returns
+    /// - Number of buffered readings.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.timestamps.len()
+    }
+
+    /// Check whether sliding buffer is empty.
+    ///
+    /// # Returns
+    /// - `true` - if there are no buffered readings.
+    /// - `false` - otherwise.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.timestamps.is_empty()
+    }
+
+    /// Add readings to the sliding buffer.
+    ///
+    /// # Parameters
+    /// - `payload` - given frame payload to handle.
+    /// - `timestamp` - given sensor-local time in microseconds.
+    pub fn add(&mut self, payload: &StandardPayload, timestamp: u32) {
+        let readings = [payload.acc(), payload.gyr()];
+
+        if readings.iter().all(Option::is_none) {
+            return;
+        }
+
+        for (samples, readings) in self.samples.chunks_mut(3).zip(readings) {
+            // Absent sensor keeps buffers of all channels aligned.
+            let values = readings.unwrap_or_else(Vector3::zeros);
+
+            for (samples, value) in samples.iter_mut().zip(values.iter()) {
+                samples.push_back(f64::from(*value));
+
+                if samples.len() > self.size {
+                    samples.pop_front();
+                }
+            }
+        }
+
+        self.timestamps.push_back(timestamp);
+
+        if self.timestamps.len() > self.size {
+            self.timestamps.pop_front();
+        }
+    }
+
+    /// Get sample rate of the buffered readings.
+    ///
+    /// # Returns
+    /// - Mean sample rate in Hz - if at least two readings are buffered.
+    /// - `None` - otherwise.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn sample_rate(&self) -> Option<f64> {
+        let (first, last) = (self.timestamps.front()?, self.timestamps.back()?);
+        let intervals = self.timestamps.len().checked_sub(1)?;
+        let elapsed_us = last.wrapping_sub(*first);
+
+        (intervals > 0 && elapsed_us > 0)
+            .then(|| intervals as f64 * 1e6 / f64::from(elapsed_us))
+    }
+
+    /// Compute amplitude spectrum of the channel.
+    ///
+    /// # Parameters
+    /// - `channel` - given channel index (accelerometer x, y, z, gyroscope
+    ///   x, y, z).
+    ///
+    /// # Returns
+    /// - Amplitude spectrum - if sliding buffer is full.
+    /// - `None` - otherwise.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn compute(&self, channel: usize) -> Option<Spectrum> {
+        let samples = self.samples.get(channel)?;
+
+        if samples.len() < self.size {
+            return None;
+        }
+
+        let sample_rate = self.sample_rate()?;
+        let mean = samples.iter().sum::<f64>() / self.size as f64;
+        let window = hann_window(self.size);
+
+        // Mean is removed, so that gravity does not mask vibration peaks.
+        let mut bins: Vec<Complex<f64>> = samples
+            .iter()
+            .zip(&window)
+            .map(|(v, w)| Complex::new((v - mean) * w, 0.0))
+            .collect();
+
+        fft(&mut bins);
+
+        // Single-sided amplitude corrected by the window coherent gain.
+        let gain = window.iter().sum::<f64>();
+        let amplitudes = bins
+            .iter()
+            .take(self.size / 2 + 1)
+            .enumerate()
+            .map(|(i, bin)| {
+                let scale = if i == 0 { 1.0 } else { 2.0 };
+                scale * bin.norm() / gain
+            })
+            .collect();
+
+        Some(Spectrum {
+            resolution: sample_rate / self.size as f64,
+            amplitudes,
+        })
+    }
+}
+
+/// Scrolling history of the single channel spectra.
+#[derive(Debug, Default, Clone)]
+pub struct Spectrogram {
+    /// Spectra with their times since the first one in seconds, oldest
+    /// first.
+    columns: VecDeque<(f64, Spectrum)>,
+    /// Sensor-local time of the latest spectrum in microseconds.
+    last_timestamp: Option<u32>,
+    /// Time of the latest spectrum since the first one in seconds.
+    elapsed: f64,
+}
+
+impl Spectrogram {
+    /// Add spectrum of the latest readings.
+    ///
+    /// # Parameters
+    /// - `spectrum` - given spectrum to add.
+    /// - `timestamp` - given sensor-local time of the latest readings in
+    ///   microseconds.
+    pub fn push(&mut self, spectrum: Spectrum, timestamp: u32) {
+        // Spectra of different FFT sizes do not share the frequency axis.
+        if self.latest().is_some_and(|latest| {
+            latest.amplitudes.len() != spectrum.amplitudes.len()
+        }) {
+            self.clear();
+        }
+
+        if let Some(last) = self.last_timestamp {
+            // Sensor-local clock wraps around.
+            self.elapsed += f64::from(timestamp.wrapping_sub(last)) / 1e6;
+        }
+
+        self.last_timestamp = Some(timestamp);
+        self.columns.push_back((self.elapsed, spectrum));
+
+        if self.columns.len() > SPECTROGRAM_LENGTH {
+            self.columns.pop_front();
+        }
+    }
+
+    /// Drop all spectra.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Get number of spectra.
+    ///
+    /// # Returns
+    /// - Number of kept spectra.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Check whether there are no spectra.
+    ///
+    /// # Returns
+    /// - `true` - if spectrogram is empty.
+    /// - `false` - otherwise.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.columns.is_empty()
+    }
+
+    /// Get spectra.
+    ///
+    /// # Returns
+    /// - Iterator over time since the first spectrum (s) and spectrum pairs,
+    ///   oldest first.
+    pub fn columns(&self) -> impl Iterator<Item = &(f64, Spectrum)> + '_ {
+        self.columns.iter()
+    }
+
+    /// Get the latest spectrum.
+    ///
+    /// # Returns
+    /// - The latest spectrum - if spectrogram is not empty.
+    /// - `None` - otherwise.
+    #[must_use]
+    pub fn latest(&self) -> Option<&Spectrum> {
+        self.columns.back().map(|(_, spectrum)| spectrum)
+    }
+
+    /// Get time span of the spectra.
+    ///
+    /// # Returns
+    /// - Time between the oldest and the latest spectra in seconds.
+    #[must_use]
+    pub fn duration(&self) -> f64 {
+        match (self.columns.front(), self.columns.back()) {
+            (Some((first, _)), Some((last, _))) => last - first,
+            _ => 0.0,
+        }
+    }
+
+    /// Get the highest amplitude excluding DC.
+    ///
+    /// # Returns
+    /// - The highest amplitude of all spectra in units of the readings.
+    #[must_use]
+    pub fn max_amplitude(&self) -> f64 {
+        self.columns
+            .iter()
+            .filter_map(|(_, spectrum)| spectrum.peak())
+            .map(|(_, amplitude)| amplitude)
+            .fold(0.0, f64::max)
+    }
+}
+
+/// Get Hann window coefficients.
+///
+/// # Parameters
+/// - `size` - given window size in samples.
+///
+/// # Returns
+/// - Window coefficients.
+#[allow(clippy::cast_precision_loss)]
+fn hann_window(size: usize) -> Vec<f64> {
+    (0..size)
+        .map(|i| 0.5 - 0.5 * (2.0 * PI * i as f64 / size as f64).cos())
+        .collect()
+}
+
+/// Compute in-place iterative radix-2 FFT.
+///
+/// # Parameters
+/// - `data` - given complex samples (length must be power of two).
+#[allow(clippy::cast_precision_loss)]
+fn fft(data: &mut [Complex<f64>]) {
+    let n = data.len();
+
+    if n < 2 || !n.is_power_of_two() {
+        return;
+    }
+
+    // Bit-reversed order of the samples.
+    let bits = n.trailing_zeros();
+
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+
+        if j > i {
+            data.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+
+    while len <= n {
+        let angle = -2.0 * PI / len as f64;
+
+        for chunk in data.chunks_mut(len) {
+            let (even, odd) = chunk.split_at_mut(len / 2);
+
+            for (k, (a, b)) in even.iter_mut().zip(odd.iter_mut()).enumerate() {
+                let twiddle = Complex::from_polar(1.0, angle * k as f64);
+                let t = *b * twiddle;
+
+                *b = *a - t;
+                *a += t;
+            }
+        }
+
+        len *= 2;
+    }
+}
@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Stale data (frozen sensor) detection.
+//!
+//! Real sensor readings always carry some noise, so that identical payloads
+//! over many consecutive frames mean that the sensor is frozen or firmware
+//! resends a stale buffer, even though sequence numbers and timestamps keep
+//! advancing. Payloads are compared per device and payload type, so that
+//! interleaved onboard attitude frames do not hide the repeats.
+
+use crate::config::StaleDataConfig;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fmt};
+
+/// Stale data state change.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StaleTransition {
+    /// Payload repeated for the given number of frames.
+    Started(usize),
+    /// Payload changed after repeating for the given number of frames.
+    Recovered(usize),
+}
+
+impl fmt::Display for StaleTransition {
+    /// Format transition as log annotation.
+    ///
+    /// # Parameters
+    /// - `f` - given formatter to write into.
+    ///
+    /// # Returns
+    /// - Formatting result.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Started(frames) => {
+                write!(f, "stale data: payload unchanged for {frames} frames")
+            }
+            Self::Recovered(frames) => {
+                write!(f, "stale data recovered after {frames} frames")
+            }
+        }
+    }
+}
+
+/// Last payload of the device and payload type.
+#[derive(Debug, Default)]
+struct LastPayload {
+    /// Raw payload bytes.
+    bytes: Vec<u8>,
+    /// Number of consecutive frames repeating the payload.
+    repeats: usize,
+}
+
+/// Detector of the payloads repeated across consecutive frames.
+#[derive(Debug)]
+pub struct StaleDataDetector {
+    /// Stale data detection configurations.
+    config: StaleDataConfig,
+    /// Last payloads keyed by device identifier and payload type.
+    last: HashMap<(u8, u8), LastPayload>,
+}
+
+impl StaleDataDetector {
+    /// Construct new `StaleDataDetector` object.
+    ///
+    /// # Parameters
+    /// - `config` - given stale data detection configurations.
+    ///
+    /// # Returns
+    /// - New `StaleDataDetector` object.
+    #[must_use]
+    pub fn new(config: StaleDataConfig) -> Self {
+        Self {
+            config,
+            last: HashMap::new(),
+        }
+    }
+
+    /// Update detector with the frame payload.
+    ///
+    /// # Parameters
+    /// - `device_id` - given device identifier.
+    /// - `payload_type` - given payload type.
+    /// - `payload` - given raw payload bytes without timestamp.
+    ///
+    /// # Returns
+    /// - Number of consecutive frames repeating the previous payload and
+    ///   stale data state change (`None` - state is unchanged).
+    pub fn update(
+        &mut self,
+        device_id: u8,
+        payload_type: u8,
+        payload: &[u8],
+    ) -> (usize, Option<StaleTransition>) {
+        let threshold = self.config.frames;
+        let last = self.last.entry((device_id, payload_type)).or_default();
+
+        if last.bytes == payload {
+            last.repeats += 1;
+
+            let transition = (last.repeats == threshold)
+                .then_some(StaleTransition::Started(threshold));
+
+            return (last.repeats, transition);
+        }
+
+        let transition = (last.repeats >= threshold)
+            .then_some(StaleTransition::Recovered(last.repeats));
+
+        last.bytes.clear();
+        last.bytes.extend_from_slice(payload);
+        last.repeats = 0;
+
+        (0, transition)
+    }
+
+    /// Reset detector state.
+    pub fn reset(&mut self) {
+        self.last.clear();
+    }
+}
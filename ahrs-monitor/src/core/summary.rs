@@ -0,0 +1,274 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Summary statistics of the recorded logs.
+//!
+//! Logs carry no sequence numbers, so that lost samples are estimated from
+//! the gaps of the sensor-local time: interval longer than 1.5 of the median
+//! sample interval of the device is counted as the number of whole periods
+//! missing in it. Event rows (e.g. shocks) are counted separately and do not
+//! take part in the statistics.
+
+use crate::{
+    core::compare::{CHANNELS, ChannelStats},
+    logger::LogRecord,
+};
+use serde::Serialize;
+use std::{collections::BTreeMap, fmt::Write};
+
+/// Interval relative to the median one counted as a gap.
+const GAP_RATIO: f64 = 1.5;
+
+/// Summary statistics of single channel.
+#[derive(Debug, Clone, Serialize)]
+pub struct ChannelSummary {
+    /// Channel key (log column name).
+    pub channel: &'static str,
+    /// Measurement unit.
+    pub unit: &'static str,
+    /// Channel values statistics.
+    #[serde(flatten)]
+    pub stats: ChannelStats,
+}
+
+/// Range of the attitude angles in degrees.
+#[derive(Debug, Default, Clone, Copy, Serialize)]
+pub struct AttitudeRange {
+    /// Min and max roll.
+    pub roll: [f64; 2],
+    /// Min and max pitch.
+    pub pitch: [f64; 2],
+    /// Min and max yaw.
+    pub yaw: [f64; 2],
+}
+
+/// Summary statistics of the recorded log.
+#[derive(Debug, Clone, Serialize)]
+pub struct LogSummary {
+    /// Log name (file name).
+    pub name: String,
+    /// Number of sample records.
+    pub records: usize,
+    /// Number of event rows.
+    pub events: usize,
+    /// Identifiers of the recorded devices.
+    pub devices: Vec<u8>,
+    /// Duration by the sensor-local time in seconds.
+    pub duration: f64,
+    /// Median sample interval in microseconds (`None` - single sample).
+    pub sample_interval_us: Option<f64>,
+    /// Estimated number of lost samples.
+    pub lost: usize,
+    /// Estimated share of lost samples in percents.
+    pub loss_percent: f64,
+    /// Statistics of the recorded channels.
+    pub channels: Vec<ChannelSummary>,
+    /// Range of the attitude angles (`None` - no samples).
+    pub attitude: Option<AttitudeRange>,
+}
+
+impl LogSummary {
+    /// Summarize log records.
+    ///
+    /// # Parameters
+    /// - `name` - given log name.
+    /// - `records` - given log records in recording order.
+    ///
+    /// # Returns
+    /// - Summary statistics of the log.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn new(name: &str, records: &[LogRecord]) -> Self {
+        let samples: Vec<&LogRecord> =
+            records.iter().filter(|r| !r.is_event()).collect();
+
+        let mut timestamps: BTreeMap<u8, Vec<u32>> = BTreeMap::new();
+
+        for record in &samples {
+            timestamps
+                .entry(record.device_id)
+                .or_default()
+                .push(record.timestamp);
+        }
+
+        let mut duration: f64 = 0.0;
+        let mut lost: usize = 0;
+        let mut intervals_us = Vec::new();
+
+        for device in timestamps.values() {
+            let intervals: Vec<u32> = device
+                .windows(2)
+                .filter_map(|pair| match pair {
+                    [prev, next] => Some(next.wrapping_sub(*prev)),
+                    _ => None,
+                })
+                .collect();
+
+            let total: u64 = intervals.iter().copied().map(u64::from).sum();
+            duration = duration.max(total as f64 / 1e6);
+            lost += lost_samples(&intervals);
+            intervals_us.extend(intervals);
+        }
+
+        let sample_interval_us = median(&mut intervals_us);
+        let expected = samples.len() + lost;
+        let loss_percent = if expected == 0 {
+            0.0
+        } else {
+            lost as f64 * 100.0 / expected as f64
+        };
+
+        let channels = CHANNELS
+            .iter()
+            .filter_map(|channel| {
+                let values = samples.iter().filter_map(|&r| (channel.value)(r));
+
+                Some(ChannelSummary {
+                    channel: channel.info.key,
+                    unit: channel.info.unit,
+                    stats: ChannelStats::new(values)?,
+                })
+            })
+            .collect();
+
+        let range = |value: fn(&LogRecord) -> f32| {
+            let stats = ChannelStats::new(samples.iter().map(|&r| value(r)))?;
+            Some([stats.min.to_degrees(), stats.max.to_degrees()])
+        };
+
+        let attitude = range(|r| r.roll).and_then(|roll| {
+            Some(AttitudeRange {
+                roll,
+                pitch: range(|r| r.pitch)?,
+                yaw: range(|r| r.yaw)?,
+            })
+        });
+
+        Self {
+            name: name.to_string(),
+            records: samples.len(),
+            events: records.len() - samples.len(),
+            devices: timestamps.keys().copied().collect(),
+            duration,
+            sample_interval_us,
+            lost,
+            loss_percent,
+            channels,
+            attitude,
+        }
+    }
+
+    /// Render summary as plain text.
+    ///
+    /// # Returns
+    /// - Summary in text representation.
+    #[must_use]
+    pub fn to_text(&self) -> String {
+        let mut out = String::new();
+        let devices: Vec<String> =
+            self.devices.iter().map(|id| format!("{id:#04X}")).collect();
+
+        let _ = writeln!(out, "Log:        {}", self.name);
+        let _ = writeln!(out, "Records:    {}", self.records);
+        let _ = writeln!(out, "Events:     {}", self.events);
+        let _ = writeln!(out, "Devices:    {}", devices.join(", "));
+        let _ = writeln!(out, "Duration:   {:.3} s", self.duration);
+
+        if let Some(interval) = self.sample_interval_us {
+            let _ = writeln!(
+                out,
+                "Interval:   {interval:.0} us ({:.1} Hz)",
+                1e6 / interval
+            );
+        }
+
+        let _ = writeln!(
+            out,
+            "Lost:       {} ({:.2}%)",
+            self.lost, self.loss_percent
+        );
+
+        if let Some(a) = self.attitude {
+            let _ = writeln!(out, "\nAttitude range, deg:");
+
+            for (name, [min, max]) in
+                [("Roll", a.roll), ("Pitch", a.pitch), ("Yaw", a.yaw)]
+            {
+                let _ = writeln!(out, "  {name:<6} {min:>9.2} .. {max:>9.2}");
+            }
+        }
+
+        let _ = writeln!(
+            out,
+            "\n{:<12} {:>8} {:>12} {:>12} {:>12} {:>12}  Unit",
+            "Channel", "Count", "Min", "Max", "Mean", "Std"
+        );
+
+        for c in &self.channels {
+            let _ = writeln!(
+                out,
+                "{:<12} {:>8} {:>12.4} {:>12.4} {:>12.4} {:>12.4}  {}",
+                c.channel,
+                c.stats.count,
+                c.stats.min,
+                c.stats.max,
+                c.stats.mean,
+                c.stats.std_dev,
+                c.unit
+            );
+        }
+
+        out
+    }
+}
+
+/// Estimate number of lost samples from the sample intervals.
+///
+/// # Parameters
+/// - `intervals` - given intervals between consecutive samples.
+///
+/// # Returns
+/// - Number of whole sample periods missing in the gaps.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+fn lost_samples(intervals: &[u32]) -> usize {
+    let mut sorted = intervals.to_vec();
+
+    let Some(period) = median(&mut sorted).filter(|&p| p > 0.0) else {
+        return 0;
+    };
+
+    intervals
+        .iter()
+        .map(|&interval| f64::from(interval))
+        .filter(|&interval| interval > period * GAP_RATIO)
+        .map(|interval| {
+            ((interval / period).round() as usize).saturating_sub(1)
+        })
+        .sum()
+}
+
+/// Get median of the values.
+///
+/// # Parameters
+/// - `values` - given values to handle (sorted in place).
+///
+/// # Returns
+/// - Median value - if values are not empty.
+/// - `None` - otherwise.
+fn median(values: &mut [u32]) -> Option<f64> {
+    values.sort_unstable();
+
+    let mid = values.len() / 2;
+    let upper = f64::from(*values.get(mid)?);
+
+    if values.len() % 2 == 0 {
+        let lower = f64::from(*values.get(mid - 1)?);
+        Some(f64::midpoint(lower, upper))
+    } else {
+        Some(upper)
+    }
+}
@@ -0,0 +1,206 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Host suspend/resume detection.
+//!
+//! Suspend is detected by the periodic check firing late, while the time
+//! spent in sleep is measured by the pair of clocks available on the
+//! platform:
+//! - Linux - `CLOCK_BOOTTIME` keeps running during sleep and
+//!   `CLOCK_MONOTONIC` does not, both are immune to wall clock steps.
+//! - macOS - wall clock keeps running during sleep and the monotonic clock
+//!   does not. Wall clock steps backward are ignored, while a large forward
+//!   step during a late check is reported as sleep.
+//! - Other platforms (e.g. Windows) - the monotonic clock keeps running
+//!   during sleep, so that the whole delay of the check beyond its interval
+//!   is reported as sleep, including long stalls of the checking task.
+//!
+//! Sockets, time steps and rates measured across the gap are not meaningful
+//! after resume.
+
+use std::time::Duration;
+
+/// Min time spent in sleep considered as system suspend.
+const MIN_SUSPEND_DURATION: Duration = Duration::from_secs(5);
+
+/// Detector of host suspend/resume.
+#[derive(Debug)]
+pub struct SuspendDetector {
+    /// Expected time between two checks.
+    interval: Duration,
+    /// Clock readings of the last check.
+    last: Option<Reading>,
+}
+
+impl SuspendDetector {
+    /// Construct new `SuspendDetector` object.
+    ///
+    /// # Parameters
+    /// - `interval` - given expected time between two checks.
+    ///
+    /// # Returns
+    /// - New `SuspendDetector` object.
+    #[must_use]
+    pub const fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            last: None,
+        }
+    }
+
+    /// Check whether host was suspended since the last check.
+    ///
+    /// # Returns
+    /// - Approximate suspend duration - if host was suspended.
+    /// - `None` - otherwise.
+    pub fn check(&mut self) -> Option<Duration> {
+        let now = Reading::now();
+        let last = self.last.replace(now)?;
+        let (total, awake) = now.elapsed_since(&last);
+        // Without clock stopped during sleep the check is assumed to stay
+        // awake for its own interval only.
+        let awake = awake.unwrap_or(self.interval);
+
+        // Forward jump only, since suspend never moves the clocks backward.
+        let gap = total.checked_sub(awake)?;
+        let stall = total.saturating_sub(self.interval);
+
+        (gap >= MIN_SUSPEND_DURATION && stall >= MIN_SUSPEND_DURATION)
+            .then_some(gap)
+    }
+}
+
+/// Clock readings of a single check.
+#[cfg(target_os = "linux")]
+#[derive(Debug, Clone, Copy)]
+struct Reading {
+    /// Time since boot including sleep.
+    boot: Duration,
+    /// Time since boot excluding sleep.
+    monotonic: Duration,
+}
+
+#[cfg(target_os = "linux")]
+impl Reading {
+    /// Read current clocks.
+    ///
+    /// # Returns
+    /// - Current clock readings.
+    fn now() -> Self {
+        Self {
+            boot: clock_time(libc::CLOCK_BOOTTIME),
+            monotonic: clock_time(libc::CLOCK_MONOTONIC),
+        }
+    }
+
+    /// Get time elapsed since the previous readings.
+    ///
+    /// # Parameters
+    /// - `last` - given previous clock readings.
+    ///
+    /// # Returns
+    /// - Elapsed time including and excluding sleep (if measurable).
+    const fn elapsed_since(&self, last: &Self) -> (Duration, Option<Duration>) {
+        (
+            self.boot.saturating_sub(last.boot),
+            Some(self.monotonic.saturating_sub(last.monotonic)),
+        )
+    }
+}
+
+/// Clock readings of a single check.
+#[cfg(target_os = "macos")]
+#[derive(Debug, Clone, Copy)]
+struct Reading {
+    /// Wall clock time running during sleep.
+    wall: std::time::SystemTime,
+    /// Monotonic time stopped during sleep.
+    monotonic: std::time::Instant,
+}
+
+#[cfg(target_os = "macos")]
+impl Reading {
+    /// Read current clocks.
+    ///
+    /// # Returns
+    /// - Current clock readings.
+    fn now() -> Self {
+        Self {
+            wall: std::time::SystemTime::now(),
+            monotonic: std::time::Instant::now(),
+        }
+    }
+
+    /// Get time elapsed since the previous readings.
+    ///
+    /// # Parameters
+    /// - `last` - given previous clock readings.
+    ///
+    /// # Returns
+    /// - Elapsed time including and excluding sleep (if measurable).
+    fn elapsed_since(&self, last: &Self) -> (Duration, Option<Duration>) {
+        // Wall clock moved backward is adjusted, not suspended.
+        let wall = self.wall.duration_since(last.wall).unwrap_or_default();
+
+        (wall, Some(self.monotonic.duration_since(last.monotonic)))
+    }
+}
+
+/// Clock readings of a single check.
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+#[derive(Debug, Clone, Copy)]
+struct Reading {
+    /// Monotonic time running during sleep.
+    monotonic: std::time::Instant,
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+impl Reading {
+    /// Read current clocks.
+    ///
+    /// # Returns
+    /// - Current clock readings.
+    fn now() -> Self {
+        Self {
+            monotonic: std::time::Instant::now(),
+        }
+    }
+
+    /// Get time elapsed since the previous readings.
+    ///
+    /// # Parameters
+    /// - `last` - given previous clock readings.
+    ///
+    /// # Returns
+    /// - Elapsed time including sleep (there is no clock excluding it).
+    fn elapsed_since(&self, last: &Self) -> (Duration, Option<Duration>) {
+        (self.monotonic.duration_since(last.monotonic), None)
+    }
+}
+
+/// Read clock time.
+///
+/// # Parameters
+/// - `clock` - given clock identifier.
+///
+/// # Returns
+/// - Clock time (zero if clock is not available).
+#[cfg(target_os = "linux")]
+fn clock_time(clock: libc::clockid_t) -> Duration {
+    let mut time = libc::timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+    };
+
+    // SAFETY: time is valid for writes.
+    let result = unsafe { libc::clock_gettime(clock, &raw mut time) };
+
+    if result != 0 {
+        return Duration::ZERO;
+    }
+
+    Duration::new(
+        u64::try_from(time.tv_sec).unwrap_or_default(),
+        u32::try_from(time.tv_nsec).unwrap_or_default(),
+    )
+}
@@ -0,0 +1,197 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Host/device time synchronization.
+//!
+//! Sensor timestamps come from the free-running microsecond clock of the IMU,
+//! which has unknown offset from the host clock and drifts relative to it.
+//! Host receive time of every sample is noisy due to transport jitter, so that
+//! host time is estimated by linear regression of the receive times over
+//! the unwrapped sensor timestamps.
+
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Max number of samples used for regression.
+const SYNC_WINDOW_SIZE: usize = 600;
+
+/// Min sensor time interval between regression samples in microseconds.
+const SYNC_SAMPLE_INTERVAL_US: u64 = 100_000;
+
+/// Min number of samples required to fit the clock model.
+const MIN_SYNC_SAMPLES: usize = 10;
+
+/// Linear model mapping sensor time onto host time.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ClockModel {
+    /// Reference unwrapped sensor time in microseconds.
+    device_ref_us: u64,
+    /// Host time at the reference sensor time in Unix microseconds.
+    host_ref_us: f64,
+    /// Host clock microseconds per sensor clock microsecond.
+    rate: f64,
+}
+
+impl ClockModel {
+    /// Get offset between host and sensor clocks.
+    ///
+    /// # Returns
+    /// - Host time minus sensor time at the reference point in microseconds.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn offset_us(&self) -> f64 {
+        self.host_ref_us - self.device_ref_us as f64
+    }
+
+    /// Get sensor clock drift relative to host clock.
+    ///
+    /// # Returns
+    /// - Drift in parts per million (positive - sensor clock is slow).
+    #[must_use]
+    pub fn drift_ppm(&self) -> f64 {
+        (self.rate - 1.0) * 1_000_000.0
+    }
+
+    /// Convert unwrapped sensor time into host time.
+    ///
+    /// # Parameters
+    /// - `device_us` - given unwrapped sensor time in microseconds.
+    ///
+    /// # Returns
+    /// - Host time in Unix microseconds.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+    fn to_host(self, device_us: u64) -> i64 {
+        let dt = device_us as f64 - self.device_ref_us as f64;
+        dt.mul_add(self.rate, self.host_ref_us).round() as i64
+    }
+}
+
+/// Estimator of host time of sensor samples.
+#[derive(Debug, Default)]
+pub struct TimeSync {
+    /// Regression samples (unwrapped sensor time, host time) in microseconds.
+    samples: VecDeque<(u64, i64)>,
+    /// Last raw sensor timestamp.
+    last_timestamp_us: Option<u32>,
+    /// Last unwrapped sensor time in microseconds.
+    last_device_us: u64,
+    /// Current clock model.
+    model: Option<ClockModel>,
+}
+
+impl TimeSync {
+    /// Add sample and estimate its host time.
+    ///
+    /// # Parameters
+    /// - `timestamp_us` - given raw sensor timestamp in microseconds.
+    /// - `host_us` - given host receive time in Unix microseconds.
+    ///
+    /// # Returns
+    /// - Estimated host time in Unix microseconds (receive time until
+    ///   the clock model is fitted).
+    pub fn update(&mut self, timestamp_us: u32, host_us: i64) -> i64 {
+        let device_us = self.unwrap(timestamp_us);
+
+        let is_due = self.samples.back().is_none_or(|&(last, _)| {
+            device_us >= last.saturating_add(SYNC_SAMPLE_INTERVAL_US)
+        });
+
+        if is_due {
+            if self.samples.len() >= SYNC_WINDOW_SIZE {
+                self.samples.pop_front();
+            }
+
+            self.samples.push_back((device_us, host_us));
+            self.model = self.fit();
+        }
+
+        self.model.map_or(host_us, |model| model.to_host(device_us))
+    }
+
+    /// Get current clock model.
+    ///
+    /// # Returns
+    /// - Clock model - if enough samples are collected.
+    /// - `None` - otherwise.
+    #[must_use]
+    pub const fn model(&self) -> Option<ClockModel> {
+        self.model
+    }
+
+    /// Forget collected samples (e.g. after IMU restart).
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Extend raw sensor timestamp beyond 32-bit wrap-around.
+    ///
+    /// # Parameters
+    /// - `timestamp_us` - given raw sensor timestamp in microseconds.
+    ///
+    /// # Returns
+    /// - Unwrapped sensor time in microseconds.
+    fn unwrap(&mut self, timestamp_us: u32) -> u64 {
+        let Some(last) = self.last_timestamp_us else {
+            self.last_timestamp_us = Some(timestamp_us);
+            self.last_device_us = u64::from(timestamp_us);
+            return self.last_device_us;
+        };
+
+        // Signed distance handles both wrap-around and late samples.
+        #[allow(clippy::cast_possible_wrap)]
+        let delta = timestamp_us.wrapping_sub(last) as i32;
+        let device_us =
+            self.last_device_us.saturating_add_signed(i64::from(delta));
+
+        if delta > 0 {
+            self.last_timestamp_us = Some(timestamp_us);
+            self.last_device_us = device_us;
+        }
+
+        device_us
+    }
+
+    /// Fit clock model by least squares.
+    ///
+    /// # Returns
+    /// - Clock model - if enough samples are collected.
+    /// - `None` - otherwise.
+    #[allow(clippy::cast_precision_loss)]
+    fn fit(&self) -> Option<ClockModel> {
+        if self.samples.len() < MIN_SYNC_SAMPLES {
+            return None;
+        }
+
+        let &(device_ref_us, host_ref_us) = self.samples.front()?;
+        let n = self.samples.len() as f64;
+
+        // Relative values keep precision of microsecond timestamps.
+        let points = self.samples.iter().map(|&(device, host)| {
+            ((device - device_ref_us) as f64, (host - host_ref_us) as f64)
+        });
+
+        let (sum_x, sum_y) = points
+            .clone()
+            .fold((0.0, 0.0), |(sx, sy), (x, y)| (sx + x, sy + y));
+        let (mean_x, mean_y) = (sum_x / n, sum_y / n);
+
+        let (cov, var) = points.fold((0.0, 0.0), |(cov, var), (x, y)| {
+            let dx = x - mean_x;
+            (dx.mul_add(y - mean_y, cov), dx.mul_add(dx, var))
+        });
+
+        if var <= f64::EPSILON {
+            return None;
+        }
+
+        let rate = cov / var;
+        let intercept = rate.mul_add(-mean_x, mean_y);
+
+        Some(ClockModel {
+            device_ref_us,
+            host_ref_us: host_ref_us as f64 + intercept,
+            rate,
+        })
+    }
+}
@@ -0,0 +1,165 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Measurement units normalization.
+//!
+//! Devices send accelerometer readings in g or m/s², gyroscope readings in
+//! deg/s or rad/s and magnetometer readings in µT, nT or gauss. Received
+//! readings are scaled per axis and converted into the standard units
+//! (m/s², deg/s, µT) before calibration, estimation and display, so that the
+//! rest of the pipeline, plots and logs do not depend on the device.
+
+use crate::core::{StandardPayload, calibration::STANDARD_GRAVITY};
+use serde::{Deserialize, Serialize};
+use tsilna_nav::math::na::Vector3;
+
+/// Accelerometer readings unit.
+#[derive(
+    Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq,
+)]
+pub enum AccUnit {
+    /// Meters per second squared (standard).
+    #[default]
+    #[serde(rename = "m/s²", alias = "m/s2")]
+    MetersPerSecondSquared,
+    /// Standard gravity.
+    #[serde(rename = "g")]
+    Gravity,
+}
+
+impl AccUnit {
+    /// Get factor converting readings into m/s².
+    ///
+    /// # Returns
+    /// - Conversion factor.
+    #[must_use]
+    pub const fn factor(self) -> f32 {
+        match self {
+            Self::MetersPerSecondSquared => 1.0,
+            Self::Gravity => STANDARD_GRAVITY,
+        }
+    }
+}
+
+/// Gyroscope readings unit.
+#[derive(
+    Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq,
+)]
+pub enum GyrUnit {
+    /// Degrees per second (standard).
+    #[default]
+    #[serde(rename = "deg/s")]
+    DegreesPerSecond,
+    /// Radians per second.
+    #[serde(rename = "rad/s")]
+    RadiansPerSecond,
+}
+
+impl GyrUnit {
+    /// Get factor converting readings into deg/s.
+    ///
+    /// # Returns
+    /// - Conversion factor.
+    #[must_use]
+    pub const fn factor(self) -> f32 {
+        match self {
+            Self::DegreesPerSecond => 1.0,
+            Self::RadiansPerSecond => 180.0 / std::f32::consts::PI,
+        }
+    }
+}
+
+/// Magnetometer readings unit.
+#[derive(
+    Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq,
+)]
+pub enum MagUnit {
+    /// Microtesla (standard).
+    #[default]
+    #[serde(rename = "µT", alias = "uT")]
+    Microtesla,
+    /// Nanotesla.
+    #[serde(rename = "nT")]
+    Nanotesla,
+    /// Gauss.
+    #[serde(rename = "gauss", alias = "G")]
+    Gauss,
+}
+
+impl MagUnit {
+    /// Get factor converting readings into µT.
+    ///
+    /// # Returns
+    /// - Conversion factor.
+    #[must_use]
+    pub const fn factor(self) -> f32 {
+        match self {
+            Self::Microtesla => 1.0,
+            Self::Nanotesla => 0.001,
+            Self::Gauss => 100.0,
+        }
+    }
+}
+
+/// Units and per-axis scale of the received sensor readings.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Units {
+    /// Accelerometer readings unit.
+    pub acc: AccUnit,
+    /// Gyroscope readings unit.
+    pub gyr: GyrUnit,
+    /// Magnetometer readings unit.
+    pub mag: MagUnit,
+    /// Accelerometer per-axis scale applied before unit conversion.
+    pub acc_scale: [f32; 3],
+    /// Gyroscope per-axis scale applied before unit conversion.
+    pub gyr_scale: [f32; 3],
+    /// Magnetometer per-axis scale applied before unit conversion.
+    pub mag_scale: [f32; 3],
+}
+
+impl Default for Units {
+    /// Get standard units.
+    ///
+    /// # Returns
+    /// - Units which leave readings unchanged.
+    fn default() -> Self {
+        Self {
+            acc: AccUnit::default(),
+            gyr: GyrUnit::default(),
+            mag: MagUnit::default(),
+            acc_scale: [1.0; 3],
+            gyr_scale: [1.0; 3],
+            mag_scale: [1.0; 3],
+        }
+    }
+}
+
+impl Units {
+    /// Convert readings of the payload sensors into the standard units.
+    ///
+    /// # Parameters
+    /// - `payload` - given payload to convert.
+    pub fn apply_to(&self, payload: &mut StandardPayload) {
+        if *self == Self::default() {
+            return;
+        }
+
+        let convert = |v: Vector3<f32>, scale: [f32; 3], factor: f32| {
+            v.component_mul(&Vector3::from(scale)) * factor
+        };
+
+        if let Some(acc) = payload.acc() {
+            payload.set_acc(convert(acc, self.acc_scale, self.acc.factor()));
+        }
+
+        if let Some(gyr) = payload.gyr() {
+            payload.set_gyr(convert(gyr, self.gyr_scale, self.gyr.factor()));
+        }
+
+        if let Some(mag) = payload.mag() {
+            payload.set_mag(convert(mag, self.mag_scale, self.mag.factor()));
+        }
+    }
+}
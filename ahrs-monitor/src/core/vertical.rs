@@ -0,0 +1,142 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Baro-aided vertical channel estimation.
+//!
+//! Altitude and climb rate are estimated by the two-state Kalman filter:
+//! vertical linear acceleration in Earth frame drives the prediction and
+//! barometric altitude corrects it. Accelerometer gives responsive climb rate
+//! while barometer keeps altitude from drifting away, so that the result is
+//! both smoother than barometric altitude and stable unlike integration of
+//! the acceleration alone.
+
+use crate::config::VerticalConfig;
+use serde::{Deserialize, Serialize};
+use tsilna_nav::math::{Quat32, na::Vector3};
+
+/// Initial climb rate variance in (m/s)².
+const INITIAL_CLIMB_RATE_VARIANCE: f32 = 1.0;
+
+/// Estimated vertical channel state.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq)]
+pub struct VerticalState {
+    /// Smoothed altitude above the reference pressure level in meters.
+    pub altitude: f32,
+    /// Climb rate in meters per second (positive - ascending).
+    pub climb_rate: f32,
+}
+
+/// Altitude and climb rate Kalman filter.
+#[derive(Debug, Clone)]
+pub struct VerticalFilter {
+    /// Vertical channel estimation configurations.
+    config: VerticalConfig,
+    /// Estimated state (`None` - no barometer readings yet).
+    state: Option<VerticalState>,
+    /// State covariance matrix (altitude, climb rate).
+    p: [[f32; 2]; 2],
+}
+
+impl VerticalFilter {
+    /// Construct new `VerticalFilter` object.
+    ///
+    /// # Parameters
+    /// - `config` - given vertical channel estimation configurations.
+    ///
+    /// # Returns
+    /// - New `VerticalFilter` object.
+    #[must_use]
+    pub const fn new(config: VerticalConfig) -> Self {
+        Self {
+            config,
+            state: None,
+            p: [[0.0; 2]; 2],
+        }
+    }
+
+    /// Update estimation with the new readings.
+    ///
+    /// # Parameters
+    /// - `q` - given estimated attitude.
+    /// - `linear_acc` - given linear acceleration in sensor frame in m/s².
+    /// - `altitude` - given barometric altitude in meters.
+    /// - `dt` - given time step since last update in seconds.
+    ///
+    /// # Returns
+    /// - Updated vertical channel state.
+    pub fn update(
+        &mut self,
+        q: &Quat32,
+        linear_acc: Vector3<f32>,
+        altitude: f32,
+        dt: f32,
+    ) -> VerticalState {
+        let baro_var = self.config.baro_noise * self.config.baro_noise;
+
+        let Some(mut state) = self.state else {
+            let state = VerticalState {
+                altitude,
+                climb_rate: 0.0,
+            };
+
+            self.state = Some(state);
+            self.p = [[baro_var, 0.0], [0.0, INITIAL_CLIMB_RATE_VARIANCE]];
+            return state;
+        };
+
+        // Earth frame Z axis points up.
+        let acc = (q * linear_acc).z;
+
+        self.predict(&mut state, acc, dt);
+
+        // Correction by the barometric altitude.
+        let [[p00, p01], [p10, p11]] = self.p;
+        let innovation = altitude - state.altitude;
+        let s = p00 + baro_var;
+
+        if s > f32::EPSILON {
+            let (k0, k1) = (p00 / s, p10 / s);
+
+            state.altitude = k0.mul_add(innovation, state.altitude);
+            state.climb_rate = k1.mul_add(innovation, state.climb_rate);
+
+            self.p = [
+                [(1.0 - k0) * p00, (1.0 - k0) * p01],
+                [k1.mul_add(-p00, p10), k1.mul_add(-p01, p11)],
+            ];
+        }
+
+        self.state = Some(state);
+        state
+    }
+
+    /// Propagate state and covariance by vertical acceleration.
+    ///
+    /// # Parameters
+    /// - `state` - given state to propagate.
+    /// - `acc` - given vertical acceleration in Earth frame in m/s².
+    /// - `dt` - given time step since last update in seconds.
+    fn predict(&mut self, state: &mut VerticalState, acc: f32, dt: f32) {
+        state.altitude = (0.5 * acc)
+            .mul_add(dt, state.climb_rate)
+            .mul_add(dt, state.altitude);
+        state.climb_rate = acc.mul_add(dt, state.climb_rate);
+
+        // P = F * P * F' + Q with F = [[1, dt], [0, 1]] and acceleration
+        // treated as white noise.
+        let [[p00, p01], [p10, p11]] = self.p;
+        let q = self.config.acc_noise * self.config.acc_noise;
+        let dt2 = dt * dt;
+        let q00 = 0.25 * q * dt2 * dt2;
+        let q01 = 0.5 * q * dt2 * dt;
+        let q11 = q * dt2;
+
+        self.p = [
+            [
+                dt.mul_add(dt.mul_add(p11, p01 + p10), p00) + q00,
+                dt.mul_add(p11, p01) + q01,
+            ],
+            [dt.mul_add(p11, p10) + q01, p11 + q11],
+        ];
+    }
+}
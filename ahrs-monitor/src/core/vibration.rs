@@ -0,0 +1,166 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Vibration metrics of the accelerometer readings.
+//!
+//! Vibration level of each accelerometer axis is measured over a sliding
+//! window of the recent readings: RMS of the readings around the window mean
+//! (so that gravity and slow maneuvers are excluded) and peak-to-peak range.
+//! Min and max of the window are tracked with monotonic queues, so that the
+//! metrics are updated in constant time at any window length.
+
+use crate::config::VibrationConfig;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use tsilna_nav::math::na::Vector3;
+
+/// Vibration metrics per accelerometer axis.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct VibrationMetrics {
+    /// RMS of the readings around the window mean in m/s².
+    pub rms: [f32; 3],
+    /// Peak-to-peak range of the readings in m/s².
+    pub peak_to_peak: [f32; 3],
+}
+
+/// Sliding window of a single axis readings.
+#[derive(Debug, Default)]
+struct AxisWindow {
+    /// Recent readings.
+    readings: VecDeque<f64>,
+    /// Sum of the recent readings.
+    sum: f64,
+    /// Sum of squares of the recent readings.
+    sum_sq: f64,
+    /// Candidates for the window min (index, reading), increasing.
+    min: VecDeque<(u64, f64)>,
+    /// Candidates for the window max (index, reading), decreasing.
+    max: VecDeque<(u64, f64)>,
+}
+
+impl AxisWindow {
+    /// Append reading to the window.
+    ///
+    /// # Parameters
+    /// - `index` - given index of the reading since the start.
+    /// - `value` - given reading.
+    /// - `size` - given window size.
+    fn push(&mut self, index: u64, value: f64, size: usize) {
+        if self.readings.len() >= size
+            && let Some(old) = self.readings.pop_front()
+        {
+            self.sum -= old;
+            self.sum_sq -= old * old;
+        }
+
+        self.readings.push_back(value);
+        self.sum += value;
+        self.sum_sq += value * value;
+
+        // Readings before this index left the window.
+        let first = index.saturating_sub(self.readings.len() as u64 - 1);
+
+        while self.min.back().is_some_and(|&(_, v)| v >= value) {
+            self.min.pop_back();
+        }
+        self.min.push_back((index, value));
+
+        while self.min.front().is_some_and(|&(i, _)| i < first) {
+            self.min.pop_front();
+        }
+
+        while self.max.back().is_some_and(|&(_, v)| v <= value) {
+            self.max.pop_back();
+        }
+        self.max.push_back((index, value));
+
+        while self.max.front().is_some_and(|&(i, _)| i < first) {
+            self.max.pop_front();
+        }
+    }
+
+    /// Get RMS of the readings around the window mean.
+    ///
+    /// # Returns
+    /// - RMS of the readings.
+    #[allow(clippy::cast_precision_loss)]
+    fn rms(&self) -> f64 {
+        let n = self.readings.len() as f64;
+        let mean = self.sum / n;
+
+        // Rounding errors of the running sums may go slightly negative.
+        (self.sum_sq / n - mean * mean).max(0.0).sqrt()
+    }
+
+    /// Get peak-to-peak range of the window.
+    ///
+    /// # Returns
+    /// - Difference between max and min readings.
+    fn peak_to_peak(&self) -> f64 {
+        match (self.min.front(), self.max.front()) {
+            (Some(&(_, min)), Some(&(_, max))) => max - min,
+            _ => 0.0,
+        }
+    }
+}
+
+/// Windowed vibration meter of the accelerometer readings.
+#[derive(Debug)]
+pub struct VibrationMeter {
+    /// Number of readings in the window.
+    size: usize,
+    /// Windows of the X, Y and Z axes.
+    axes: [AxisWindow; 3],
+    /// Number of readings since the start.
+    count: u64,
+}
+
+impl VibrationMeter {
+    /// Construct new `VibrationMeter` object.
+    ///
+    /// # Parameters
+    /// - `cfg` - given vibration metrics configurations.
+    /// - `sample_rate` - given nominal sample rate in Hz.
+    ///
+    /// # Returns
+    /// - New `VibrationMeter` object.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn new(cfg: &VibrationConfig, sample_rate: f32) -> Self {
+        let size = (cfg.window * sample_rate).round().max(2.0) as usize;
+
+        Self {
+            size,
+            axes: Default::default(),
+            count: 0,
+        }
+    }
+
+    /// Update metrics with the accelerometer readings.
+    ///
+    /// # Parameters
+    /// - `acc` - given accelerometer readings in m/s².
+    ///
+    /// # Returns
+    /// - Vibration metrics over the window.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn update(&mut self, acc: Vector3<f32>) -> VibrationMetrics {
+        let mut metrics = VibrationMetrics::default();
+
+        for (i, axis) in self.axes.iter_mut().enumerate() {
+            let value = acc.get(i).copied().map_or(0.0, f64::from);
+            axis.push(self.count, value, self.size);
+
+            if let Some(rms) = metrics.rms.get_mut(i) {
+                *rms = axis.rms() as f32;
+            }
+
+            if let Some(peak_to_peak) = metrics.peak_to_peak.get_mut(i) {
+                *peak_to_peak = axis.peak_to_peak() as f32;
+            }
+        }
+
+        self.count += 1;
+        metrics
+    }
+}
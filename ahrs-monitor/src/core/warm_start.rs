@@ -0,0 +1,173 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Attitude estimation warm-start from the last session.
+//!
+//! Last known attitude and gyroscope bias of each device are stored at
+//! shutdown, so that estimation of the next session of the same device
+//! starts close to the actual state instead of converging from scratch.
+
+use crate::model::FrameContext;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fs, io::ErrorKind};
+use tsilna_nav::math::{
+    Quat32,
+    na::{Quaternion, Vector3},
+};
+
+/// Last known estimation state of the device.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq)]
+pub struct WarmStart {
+    /// Attitude quaternion (w, x, y, z).
+    pub quaternion: [f32; 4],
+    /// Gyroscope bias (x, y, z) in degrees per second (`None` - bias was
+    /// not estimated).
+    pub gyro_bias: Option<[f32; 3]>,
+}
+
+impl WarmStart {
+    /// Get last known state from frame context.
+    ///
+    /// # Parameters
+    /// - `frame_ctx` - given context of the last received frame.
+    ///
+    /// # Returns
+    /// - Device identifier and its state - if attitude was estimated.
+    /// - `None` - otherwise.
+    #[must_use]
+    pub fn from_frame_context(frame_ctx: &FrameContext) -> Option<(u8, Self)> {
+        let device_id = frame_ctx.frame.as_ref()?.header.device_id;
+        let q = frame_ctx.quaternion?;
+
+        let state = Self {
+            quaternion: [q.w, q.i, q.j, q.k],
+            gyro_bias: frame_ctx.gyro_bias.map(|bias| bias.bias),
+        };
+
+        Some((device_id, state))
+    }
+
+    /// Get stored attitude.
+    ///
+    /// # Returns
+    /// - Attitude in quaternion representation.
+    #[must_use]
+    pub fn attitude(&self) -> Quat32 {
+        let [w, x, y, z] = self.quaternion;
+        Quat32::from_quaternion(Quaternion::new(w, x, y, z))
+    }
+
+    /// Get stored gyroscope bias.
+    ///
+    /// # Returns
+    /// - Gyroscope bias in degrees per second - if it was estimated.
+    /// - `None` - otherwise.
+    #[must_use]
+    pub fn gyro_bias(&self) -> Option<Vector3<f32>> {
+        self.gyro_bias.map(Vector3::from)
+    }
+}
+
+/// Last known estimation states by device identifier.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+#[serde(transparent)]
+pub struct WarmStartStore {
+    /// Last known states by device identifier.
+    devices: BTreeMap<u8, WarmStart>,
+}
+
+impl WarmStartStore {
+    /// Load stored states from the file.
+    ///
+    /// # Parameters
+    /// - `path` - given store file path.
+    ///
+    /// # Returns
+    /// - Stored states (empty if file does not exist) - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - File reading or parsing errors.
+    pub fn load(path: &str) -> anyhow::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(content) => Ok(serde_json::from_str(&content)?),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Save states to the file.
+    ///
+    /// # Parameters
+    /// - `path` - given store file path.
+    ///
+    /// # Returns
+    /// - `Ok` - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - Serialization or file writing errors.
+    pub fn save(&self, path: &str) -> anyhow::Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Get last known state of the device.
+    ///
+    /// # Parameters
+    /// - `device_id` - given device identifier.
+    ///
+    /// # Returns
+    /// - Last known state - if device was stored.
+    /// - `None` - otherwise.
+    #[must_use]
+    pub fn get(&self, device_id: u8) -> Option<&WarmStart> {
+        self.devices.get(&device_id)
+    }
+
+    /// Store last known state of the device.
+    ///
+    /// # Parameters
+    /// - `device_id` - given device identifier.
+    /// - `state` - given last known state to store.
+    pub fn insert(&mut self, device_id: u8, state: WarmStart) {
+        self.devices.insert(device_id, state);
+    }
+}
+
+/// Store last known states of the devices into the file.
+///
+/// States of other devices are preserved.
+///
+/// # Parameters
+/// - `path` - given store file path.
+/// - `frames` - given contexts of the last received frames in receive order
+///   (the newest frame of each device is stored).
+///
+/// # Returns
+/// - `Ok` - in case of success (nothing is stored without attitude).
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - Store file reading, parsing or writing errors.
+pub fn save_last_states<'a>(
+    path: &str,
+    frames: impl IntoIterator<Item = &'a FrameContext>,
+) -> anyhow::Result<()> {
+    let mut states = frames
+        .into_iter()
+        .filter_map(WarmStart::from_frame_context)
+        .peekable();
+
+    if states.peek().is_none() {
+        return Ok(());
+    }
+
+    let mut store = WarmStartStore::load(path)?;
+
+    for (device_id, state) in states {
+        store.insert(device_id, state);
+    }
+
+    store.save(path)
+}
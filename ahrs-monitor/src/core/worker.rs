@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Stream statistics worker.
+//!
+//! The user interface drains its events once per repaint, so that at 1-2 kHz
+//! ingest rates frames may be dropped by the events channel whenever egui
+//! repaints late (e.g. the window is hidden). The worker sits between the
+//! ingester and the user interface with its own queue and accumulates
+//! session statistics of every frame, so that counters and sequence gaps do
+//! not depend on the repaint timing. The user interface samples the latest
+//! statistics and receives frames for the plots according to the
+//! back-pressure policy.
+
+use crate::model::{
+    AppEvent,
+    channel::{EventReceiver, EventSender},
+    stats::SharedStats,
+};
+use std::time::{Duration, Instant};
+
+/// Interval between reports of frames dropped by the user interface.
+const REPORT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Stream statistics worker.
+pub struct Worker {
+    /// Events channel receiver handle of the ingester.
+    rx: EventReceiver,
+    /// Events channel sender handle of the user interface.
+    tx: EventSender,
+    /// Session statistics sampled by the user interface.
+    stats: SharedStats,
+    /// Number of dropped frames reported last time.
+    reported_dropped: usize,
+    /// Time of the last dropped frames report.
+    last_report: Instant,
+}
+
+impl Worker {
+    /// Construct new `Worker` object.
+    ///
+    /// # Parameters
+    /// - `rx` - given events channel receiver handle of the ingester.
+    /// - `tx` - given events channel sender handle of the user interface.
+    ///
+    /// # Returns
+    /// - New `Worker` object.
+    #[must_use]
+    pub fn new(rx: EventReceiver, tx: EventSender) -> Self {
+        Self {
+            rx,
+            tx,
+            stats: SharedStats::default(),
+            reported_dropped: 0,
+            last_report: Instant::now(),
+        }
+    }
+
+    /// Get session statistics accumulated by the worker.
+    ///
+    /// # Returns
+    /// - Shared session statistics handle.
+    #[must_use]
+    pub fn stats(&self) -> SharedStats {
+        self.stats.clone()
+    }
+
+    /// Handle events until any of the channels is closed.
+    pub async fn run(mut self) {
+        while let Some(mut event) = self.rx.recv().await {
+            self.handle_event(&mut event);
+
+            if self.tx.send(event).await.is_err() {
+                break;
+            }
+
+            self.report_dropped();
+        }
+    }
+
+    /// Accumulate statistics of the event.
+    ///
+    /// # Parameters
+    /// - `event` - given application event to handle.
+    fn handle_event(&self, event: &mut AppEvent) {
+        let mut stats = self.stats.lock();
+
+        match event {
+            AppEvent::UpdateConnectionStatus(_) => stats.reset_sequence(),
+            AppEvent::FrameReceived(frame_ctx) => {
+                // Sequence gap across device reboot or system sleep is not
+                // packet loss.
+                if frame_ctx.is_reboot || frame_ctx.resumed_after.is_some() {
+                    stats.reset_sequence();
+                }
+
+                stats.update(frame_ctx);
+
+                // Showing session-wide counters instead of the ingester ones.
+                frame_ctx.total_packets = stats.total_packets;
+                frame_ctx.bad_packets = stats.bad_packets;
+                frame_ctx.dropped_events = self.tx.dropped();
+            }
+            AppEvent::AlertTriggered(_) | AppEvent::ShockDetected(_) => {}
+        }
+    }
+
+    /// Log frames dropped by the user interface since the last report.
+    fn report_dropped(&mut self) {
+        if self.last_report.elapsed() < REPORT_INTERVAL {
+            return;
+        }
+
+        let dropped = self.tx.dropped();
+
+        if dropped > self.reported_dropped {
+            log::warn!(
+                "User interface lags behind: {} frames dropped ({dropped} total)",
+                dropped - self.reported_dropped
+            );
+        }
+
+        self.reported_dropped = dropped;
+        self.last_report = Instant::now();
+    }
+}
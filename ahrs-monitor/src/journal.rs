@@ -0,0 +1,449 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Application events journal.
+//!
+//! Journal is a JSON lines file with every application event received by
+//! the user interface and the time it was received at. Replaying the journal
+//! feeds the same events with the same timing into the user interface, which
+//! makes UI issues reproducible without the IMU.
+
+use crate::{
+    core::{StandardPayload, alert::Alert, shock::ShockEvent},
+    model::{AppEvent, FrameContext, FrameWrapper, channel::EventSender},
+};
+use indtp::{Header, payload::PayloadType, types::Packable};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    io::{BufRead, BufReader, BufWriter, Write},
+    path::Path,
+    time::{Duration, Instant},
+};
+use tokio::io::AsyncBufReadExt;
+
+/// Max time between flushes of the journal file.
+const FLUSH_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Raw IDTP frame parts.
+#[derive(Debug, Serialize, Deserialize)]
+struct JournalFrame {
+    /// Raw frame header bytes.
+    header: Vec<u8>,
+    /// Type of the decoded standard payload.
+    payload_type: Option<u8>,
+    /// Raw decoded payload bytes.
+    payload: Vec<u8>,
+    /// Raw frame trailer bytes.
+    trailer: Vec<u8>,
+    /// Frame size in bytes.
+    size: usize,
+}
+
+/// Application event to journal.
+///
+/// Frame context is borrowed while recording and owned while replaying.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum JournalEvent<F> {
+    /// IMU connection status update.
+    ConnectionStatus {
+        /// IMU connection status.
+        connected: bool,
+    },
+    /// Received frame.
+    Frame(F),
+    /// Triggered alert.
+    Alert {
+        /// Triggered alert info.
+        alert: Alert,
+        /// Flag whether to show a modal window.
+        show_modal: bool,
+    },
+    /// Detected shock.
+    Shock(ShockEvent),
+}
+
+/// Journal entry.
+#[derive(Debug, Serialize, Deserialize)]
+struct JournalEntry<F> {
+    /// Time since the start of the recording in microseconds.
+    elapsed_us: u64,
+    /// Journaled application event.
+    event: JournalEvent<F>,
+}
+
+/// Journal entry read from the file.
+type ReadEntry = JournalEntry<Box<FrameContext>>;
+
+impl From<&FrameWrapper> for JournalFrame {
+    /// Convert frame into raw frame parts.
+    ///
+    /// # Parameters
+    /// - `frame` - given frame to convert.
+    ///
+    /// # Returns
+    /// - Raw frame parts.
+    fn from(frame: &FrameWrapper) -> Self {
+        Self {
+            header: frame.header.to_bytes().to_vec(),
+            payload_type: frame
+                .payload
+                .as_ref()
+                .map(StandardPayload::payload_type),
+            payload: frame
+                .payload
+                .as_ref()
+                .map(|p| p.to_bytes().to_vec())
+                .unwrap_or_default(),
+            trailer: frame.trailer.clone(),
+            size: frame.size,
+        }
+    }
+}
+
+impl JournalFrame {
+    /// Convert raw frame parts back into frame.
+    ///
+    /// # Returns
+    /// - Frame - in case of success.
+    /// - `None` - if header is malformed.
+    fn into_frame(self) -> Option<FrameWrapper> {
+        let header = Header::from_bytes(&self.header).ok()?;
+        let payload = self.payload_type.and_then(|payload_type| {
+            StandardPayload::try_from(
+                &self.payload,
+                PayloadType::from(payload_type),
+            )
+        });
+
+        Some(FrameWrapper {
+            flags: header.flags(),
+            header,
+            payload,
+            trailer: self.trailer,
+            size: self.size,
+        })
+    }
+}
+
+impl<'a> From<&'a AppEvent> for JournalEvent<&'a FrameContext> {
+    /// Convert application event into journaled one.
+    ///
+    /// # Parameters
+    /// - `event` - given application event to convert.
+    ///
+    /// # Returns
+    /// - Journaled application event.
+    fn from(event: &'a AppEvent) -> Self {
+        match event {
+            AppEvent::UpdateConnectionStatus(connected) => {
+                Self::ConnectionStatus {
+                    connected: *connected,
+                }
+            }
+            AppEvent::FrameReceived(ctx) => Self::Frame(ctx.as_ref()),
+            AppEvent::AlertTriggered(alert) => Self::Alert {
+                alert: alert.as_ref().clone(),
+                show_modal: alert.show_modal,
+            },
+            AppEvent::ShockDetected(shock) => Self::Shock(*shock),
+        }
+    }
+}
+
+impl From<JournalEvent<Box<FrameContext>>> for AppEvent {
+    /// Convert journaled application event back into application event.
+    ///
+    /// # Parameters
+    /// - `event` - given journaled application event to convert.
+    ///
+    /// # Returns
+    /// - Application event.
+    fn from(event: JournalEvent<Box<FrameContext>>) -> Self {
+        match event {
+            JournalEvent::ConnectionStatus { connected } => {
+                Self::UpdateConnectionStatus(connected)
+            }
+            JournalEvent::Frame(ctx) => Self::FrameReceived(ctx),
+            JournalEvent::Alert {
+                mut alert,
+                show_modal,
+            } => {
+                alert.show_modal = show_modal;
+                Self::AlertTriggered(Box::new(alert))
+            }
+            JournalEvent::Shock(shock) => Self::ShockDetected(shock),
+        }
+    }
+}
+
+/// Application events journal writer.
+pub struct JournalWriter {
+    /// Buffered journal file.
+    file: BufWriter<fs::File>,
+    /// Path to the journal file.
+    path: String,
+    /// Recording start timestamp.
+    start_time: Instant,
+    /// Timestamp of the last flush.
+    last_flush: Instant,
+}
+
+impl JournalWriter {
+    /// Create new journal file.
+    ///
+    /// # Parameters
+    /// - `path` - given path to the journal file.
+    ///
+    /// # Returns
+    /// - New `JournalWriter` object - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - Error to create journal file.
+    pub fn create(path: &str) -> std::io::Result<Self> {
+        if let Some(dir) = Path::new(path).parent()
+            && !dir.as_os_str().is_empty()
+        {
+            fs::create_dir_all(dir)?;
+        }
+
+        Ok(Self {
+            file: BufWriter::new(fs::File::create(path)?),
+            path: path.to_string(),
+            start_time: Instant::now(),
+            last_flush: Instant::now(),
+        })
+    }
+
+    /// Get journal file path.
+    ///
+    /// # Returns
+    /// - Journal file path.
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Append application event to the journal.
+    ///
+    /// # Parameters
+    /// - `event` - given application event to journal.
+    ///
+    /// # Returns
+    /// - `Ok` - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - I/O errors.
+    /// - JSON serialization errors.
+    pub fn record(&mut self, event: &AppEvent) -> anyhow::Result<()> {
+        let elapsed = self.start_time.elapsed();
+        let entry = JournalEntry {
+            elapsed_us: u64::try_from(elapsed.as_micros()).unwrap_or(u64::MAX),
+            event: JournalEvent::from(event),
+        };
+
+        let mut line = serde_json::to_vec(&entry)?;
+        line.push(b'\n');
+
+        self.file.write_all(&line)?;
+
+        // Journal is recorded at the frame rate, so that it is flushed
+        // periodically and at most the last interval is lost on crash.
+        if self.last_flush.elapsed() >= FLUSH_INTERVAL {
+            self.file.flush()?;
+            self.last_flush = Instant::now();
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for JournalWriter {
+    /// Flush buffered events into the journal file.
+    fn drop(&mut self) {
+        if let Err(e) = self.file.flush() {
+            log::error!("Events journal flushing failed: {e}");
+        }
+    }
+}
+
+/// Replay journaled application events with the original timing.
+///
+/// # Parameters
+/// - `path` - given path to the journal file.
+/// - `tx` - given events channel sender handle.
+///
+/// # Returns
+/// - `Ok` - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - I/O errors.
+/// - Malformed journal entry.
+pub async fn replay(path: &str, tx: EventSender) -> anyhow::Result<()> {
+    let file = tokio::fs::File::open(path).await?;
+    let mut lines = tokio::io::BufReader::new(file).lines();
+    let mut line_number: usize = 0;
+    let start_time = tokio::time::Instant::now();
+    let mut events: usize = 0;
+
+    log::info!("Replaying events journal: {path}");
+
+    while let Some(line) = lines.next_line().await? {
+        line_number += 1;
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let entry: ReadEntry = serde_json::from_str(&line)
+            .map_err(|e| anyhow::anyhow!("{path}:{line_number}: {e}"))?;
+
+        let deadline = start_time + Duration::from_micros(entry.elapsed_us);
+        tokio::time::sleep_until(deadline).await;
+
+        if tx.send(AppEvent::from(entry.event)).await.is_err() {
+            break;
+        }
+
+        events += 1;
+    }
+
+    log::info!("Replayed {events} events from: {path}");
+    Ok(())
+}
+
+/// Read frames of the journal without replaying its timing.
+///
+/// # Parameters
+/// - `path` - given path to the journal file.
+///
+/// # Returns
+/// - Journaled frame contexts in recording order - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - I/O errors.
+/// - Malformed journal entry.
+pub fn read_frames(
+    path: impl AsRef<Path>,
+) -> anyhow::Result<Vec<FrameContext>> {
+    let path = path.as_ref();
+    let reader = BufReader::new(fs::File::open(path)?);
+    let mut frames = Vec::new();
+
+    for (line_number, line) in reader.lines().enumerate() {
+        let line = line?;
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let entry: ReadEntry = serde_json::from_str(&line).map_err(|e| {
+            anyhow::anyhow!("{}:{}: {e}", path.display(), line_number + 1)
+        })?;
+
+        if let JournalEvent::Frame(ctx) = entry.event {
+            frames.push(*ctx);
+        }
+    }
+
+    Ok(frames)
+}
+
+/// Journaling of the frame as raw frame parts.
+pub(crate) mod raw_frame {
+    use super::JournalFrame;
+    use crate::model::FrameWrapper;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serialize frame as raw frame parts.
+    ///
+    /// # Parameters
+    /// - `frame` - given frame to serialize.
+    /// - `serializer` - given serializer to handle.
+    ///
+    /// # Returns
+    /// - Serializer output - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - Serializer errors.
+    #[allow(clippy::ref_option)]
+    pub fn serialize<S: Serializer>(
+        frame: &Option<FrameWrapper>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        frame.as_ref().map(JournalFrame::from).serialize(serializer)
+    }
+
+    /// Deserialize frame from raw frame parts.
+    ///
+    /// # Parameters
+    /// - `deserializer` - given deserializer to handle.
+    ///
+    /// # Returns
+    /// - Frame (`None` if header is malformed) - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - Deserializer errors.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<FrameWrapper>, D::Error> {
+        let frame = Option::<JournalFrame>::deserialize(deserializer)?;
+        Ok(frame.and_then(JournalFrame::into_frame))
+    }
+}
+
+/// Journaling of the attitude quaternion as (w, x, y, z) array.
+pub(crate) mod quaternion {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use tsilna_nav::math::{Quat32, na::Quaternion};
+
+    /// Serialize attitude quaternion.
+    ///
+    /// # Parameters
+    /// - `quaternion` - given attitude quaternion to serialize.
+    /// - `serializer` - given serializer to handle.
+    ///
+    /// # Returns
+    /// - Serializer output - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - Serializer errors.
+    #[allow(clippy::ref_option)]
+    pub fn serialize<S: Serializer>(
+        quaternion: &Option<Quat32>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        quaternion
+            .map(|q| [q.w, q.i, q.j, q.k])
+            .serialize(serializer)
+    }
+
+    /// Deserialize attitude quaternion.
+    ///
+    /// # Parameters
+    /// - `deserializer` - given deserializer to handle.
+    ///
+    /// # Returns
+    /// - Attitude quaternion - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - Deserializer errors.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Quat32>, D::Error> {
+        let quaternion = Option::<[f32; 4]>::deserialize(deserializer)?;
+
+        Ok(quaternion.map(|[w, x, y, z]| {
+            Quat32::from_quaternion(Quaternion::new(w, x, y, z))
+        }))
+    }
+}
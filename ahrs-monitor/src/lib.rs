@@ -19,17 +19,36 @@ pub mod macros;
 pub mod app;
 pub mod config;
 pub mod core;
+pub mod journal;
 pub mod logger;
 pub mod model;
+pub mod report;
 pub mod ui;
 
-use crate::{app::App, config::AppConfig, core::Ingester, model::AppEvent};
+use crate::{
+    app::App,
+    config::{
+        AppConfig, BackpressurePolicy, PlotRenderer, PlotsConfig, Transport,
+    },
+    core::{
+        Ingester, Worker,
+        conformance::{ConformanceChecker, ConformanceReport},
+        decoder::DecoderRegistry,
+        engine::{EngineRegistry, FrameEngine},
+        housekeeping,
+    },
+    journal::JournalWriter,
+    logger::{LogRecord, Logger},
+    model::{
+        AppEvent,
+        channel::{self, EventReceiver},
+    },
+};
 use chrono::Local;
 use eframe::{HardwareAcceleration, egui};
 use env_logger::Builder;
 use log::LevelFilter;
-use std::{env, io::Write, sync::Once};
-use tokio::sync::mpsc;
+use std::{env, io::Write, path::Path, sync::Once, time::Duration};
 
 /// Used in order to ensure that the initialization code runs only once.
 static INIT: Once = Once::new();
@@ -48,8 +67,11 @@ pub fn init_logging(filter: LevelFilter) {
             let target = record.target();
             let level = record.level();
             let args = record.args();
+            let line = format!("[{timestamp}][{level}][{target}] {args}");
 
-            writeln!(buf, "[{timestamp}][{level}][{target}] {args}")
+            writeln!(buf, "{line}")?;
+            report::record_log(line);
+            Ok(())
         });
 
         builder.init();
@@ -57,20 +79,253 @@ pub fn init_logging(filter: LevelFilter) {
 }
 
 /// Initialize AHRS monitor.
-fn init() -> anyhow::Result<AppConfig> {
+///
+/// # Parameters
+/// - `args` - given command line arguments to handle.
+/// - `decoders` - given custom decoders registry.
+///
+/// # Returns
+/// - Application's configurations - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - Config file reading or parsing errors.
+/// - Invalid configuration values.
+fn init(
+    args: &[String],
+    decoders: &DecoderRegistry,
+) -> anyhow::Result<AppConfig> {
     init_logging(LevelFilter::Info);
     log::info!("Initialized AHRS monitor");
 
-    let args: Vec<String> = env::args().collect();
+    // Built-in test-signal generator takes precedence over standard input.
+    let transport = if args.iter().any(|arg| arg == "--generator") {
+        Some(Transport::Generator)
+    } else if args.iter().any(|arg| arg == "--stdin") {
+        Some(Transport::Stdin)
+    } else {
+        None
+    };
 
-    let config_path = args
-        .iter()
-        .position(|arg| arg == "--config")
-        .and_then(|pos| args.get(pos + 1))
-        .map_or(config::CONFIG_FILE_PATH, |s| s.as_str());
+    load_app_config(config_path(args), transport, decoders)
+}
 
+/// Load and validate application's configurations.
+///
+/// # Parameters
+/// - `config_path` - given config file path.
+/// - `transport` - given transport overriding the configured one.
+/// - `decoders` - given custom decoders registry.
+///
+/// # Returns
+/// - Application's configurations - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - Config file reading or parsing errors.
+/// - Invalid configuration values.
+pub fn load_app_config(
+    config_path: &str,
+    transport: Option<Transport>,
+    decoders: &DecoderRegistry,
+) -> anyhow::Result<AppConfig> {
     log::info!("Loading configurations from: {config_path}");
-    Ok(config::load_config(config_path)?)
+    let mut config = config::load_config(config_path)?;
+
+    if let Some(transport) = transport {
+        config.net.transport = transport;
+    }
+
+    config.validate(&decoders.payload_types())?;
+    config.channels = decoders.channels().clone().with_full_scale(&config.imu);
+
+    Ok(config)
+}
+
+/// Get path to the config file.
+///
+/// # Parameters
+/// - `args` - given command line arguments.
+///
+/// # Returns
+/// - Path specified with `--config` option or default config file path.
+fn config_path(args: &[String]) -> &str {
+    option_value(args, "--config").unwrap_or(config::CONFIG_FILE_PATH)
+}
+
+/// Get value of the command line option.
+///
+/// # Parameters
+/// - `args` - given command line arguments to handle.
+/// - `name` - given option name.
+///
+/// # Returns
+/// - Option value - if option is specified.
+/// - `None` - otherwise.
+fn option_value<'a>(args: &'a [String], name: &str) -> Option<&'a str> {
+    args.iter()
+        .position(|arg| arg == name)
+        .and_then(|pos| args.get(pos + 1))
+        .map(String::as_str)
+}
+
+/// Write received frames into the log file without user interface.
+///
+/// # Parameters
+/// - `config` - given application's configurations.
+/// - `rx` - given events channel receiver handle.
+///
+/// # Returns
+/// - `Ok` - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - Error to create log file.
+/// - Error to write log record.
+async fn run_headless(
+    config: &AppConfig,
+    mut rx: EventReceiver,
+) -> anyhow::Result<()> {
+    let mut logger = Logger::new(config)?;
+    let mut records: usize = 0;
+
+    log::info!("Running headless, logging to: {}", logger.path());
+    housekeeping::enforce(
+        &config.log.directory,
+        &config.log.retention,
+        Some(Path::new(logger.path())),
+    );
+
+    // Channel is closed once the ingester finishes.
+    while let Some(event) = rx.recv().await {
+        if let AppEvent::ShockDetected(shock) = event {
+            logger.write(&LogRecord::from_shock(&shock))?;
+        } else if let AppEvent::FrameReceived(frame_ctx) = event
+            && let Some(record) = LogRecord::from_frame_context(&frame_ctx)
+        {
+            if frame_ctx.is_reboot {
+                logger.next_segment()?;
+                log::info!("Device rebooted, logging to: {}", logger.path());
+
+                housekeeping::enforce(
+                    &config.log.directory,
+                    &config.log.retention,
+                    Some(Path::new(logger.path())),
+                );
+            }
+
+            logger.write(&record)?;
+            records += 1;
+        }
+    }
+
+    log::info!("Written {records} records to: {}", logger.path());
+    Ok(())
+}
+
+/// Check conformance of the device stream to the IDTP specification.
+///
+/// # Parameters
+/// - `config` - given application's configurations.
+/// - `decoders` - given custom decoders registry.
+/// - `engines` - given frame integrity and crypto engines registry.
+/// - `duration` - given stream observation duration.
+///
+/// # Returns
+/// - Conformance report of the observed stream - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - Configured frame engine is not registered.
+pub async fn check_conformance(
+    config: AppConfig,
+    decoders: DecoderRegistry,
+    mut engines: EngineRegistry,
+    duration: Duration,
+) -> anyhow::Result<ConformanceReport> {
+    let engine = take_engine(&mut engines, &config)?;
+    let (tx, mut rx) = channel::channel(
+        config::MPSC_CHANNEL_BUFFER_SIZE,
+        BackpressurePolicy::Block,
+    );
+
+    tokio::spawn(async move {
+        let mut ingester = Ingester::new(tx, config)
+            .with_decoders(decoders)
+            .with_engine(engine);
+
+        if let Err(e) = ingester.run().await {
+            log::error!("Core service failed: {e:?}");
+        }
+    });
+
+    let mut checker = ConformanceChecker::default();
+    let deadline = tokio::time::Instant::now() + duration;
+
+    log::info!("Checking stream conformance for {duration:?}");
+
+    // Channel is closed once the ingester finishes (e.g. end of stdin).
+    while let Ok(Some(event)) =
+        tokio::time::timeout_at(deadline, rx.recv()).await
+    {
+        match event {
+            AppEvent::UpdateConnectionStatus(connected) => {
+                checker.update_connection_status(connected);
+            }
+            AppEvent::FrameReceived(frame_ctx) => checker.update(&frame_ctx),
+            AppEvent::AlertTriggered(_) | AppEvent::ShockDetected(_) => {}
+        }
+    }
+
+    Ok(checker.report())
+}
+
+/// Take configured frame engine out of the registry.
+///
+/// # Parameters
+/// - `engines` - given frame integrity and crypto engines registry.
+/// - `config` - given application's configurations.
+///
+/// # Returns
+/// - Configured frame engine - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - Configured frame engine is not registered.
+fn take_engine(
+    engines: &mut EngineRegistry,
+    config: &AppConfig,
+) -> anyhow::Result<Box<dyn FrameEngine>> {
+    let engine_name = &config.imu.engine;
+    let Some(engine) = engines.take(engine_name) else {
+        anyhow::bail!(
+            "imu.engine: \"{engine_name}\" is not registered (available: \
+             {:?})",
+            engines.names()
+        );
+    };
+
+    log::info!("Using \"{engine_name}\" frame engine");
+    Ok(engine)
+}
+
+/// Select rendering backend of the native window.
+///
+/// # Parameters
+/// - `plot` - given plots configurations.
+///
+/// # Returns
+/// - Rendering backend supporting configured plots renderer.
+const fn window_renderer(plot: &PlotsConfig) -> eframe::Renderer {
+    match plot.renderer {
+        PlotRenderer::Cpu => eframe::Renderer::Glow,
+        // Paint callbacks of the GPU plots require wgpu backend.
+        #[cfg(feature = "gpu-plots")]
+        PlotRenderer::Gpu => eframe::Renderer::Wgpu,
+        // Rejected by configurations validation.
+        #[cfg(not(feature = "gpu-plots"))]
+        PlotRenderer::Gpu => eframe::Renderer::Glow,
+    }
 }
 
 /// Run AHRS monitor.
@@ -81,20 +336,120 @@ fn init() -> anyhow::Result<AppConfig> {
 ///
 /// # Errors
 /// - Eframe errors.
+/// - Log file handling errors in headless mode.
 pub fn run() -> anyhow::Result<()> {
-    let app_config = init()?;
-    let (tx, rx) = mpsc::channel::<AppEvent>(config::MPSC_CHANNEL_BUFFER_SIZE);
+    run_with_decoders(DecoderRegistry::default())
+}
+
+/// Run AHRS monitor with custom decoders for vendor-specific payloads.
+///
+/// # Parameters
+/// - `decoders` - given custom decoders registry.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - Eframe errors.
+/// - Log file handling errors in headless mode.
+/// - Error to create events journal file.
+/// - Invalid configuration values.
+pub fn run_with_decoders(decoders: DecoderRegistry) -> anyhow::Result<()> {
+    run_with_engines(decoders, EngineRegistry::default())
+}
+
+/// Run AHRS monitor with custom decoders and frame engines.
+///
+/// # Parameters
+/// - `decoders` - given custom decoders registry.
+/// - `engines` - given frame integrity and crypto engines registry.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - Eframe errors.
+/// - Log file handling errors in headless mode.
+/// - Error to create events journal file.
+/// - Invalid configuration values.
+/// - Configured frame engine is not registered.
+pub fn run_with_engines(
+    decoders: DecoderRegistry,
+    mut engines: EngineRegistry,
+) -> anyhow::Result<()> {
+    let args: Vec<String> = env::args().collect();
+    let app_config = init(&args, &decoders)?;
+
+    let engine = take_engine(&mut engines, &app_config)?;
+    let is_headless = args.iter().any(|arg| arg == "--headless");
+    let config_path = config_path(&args).to_string();
+    let replay_path = option_value(&args, "--replay-journal").map(String::from);
+    let journal = option_value(&args, "--record-journal")
+        .map(JournalWriter::create)
+        .transpose()?;
+
+    if let Some(journal) = &journal {
+        log::info!("Recording events journal to: {}", journal.path());
+    }
+
+    // Headless logging and journal replay must not lose frames.
+    let policy = if is_headless || replay_path.is_some() {
+        BackpressurePolicy::Block
+    } else {
+        app_config.channel.policy
+    };
+
+    // User interface receives frames through the stats worker, so that the
+    // ingester waits for the worker only, not for the repaint.
+    let (tx, rx) = if is_headless {
+        channel::channel(config::MPSC_CHANNEL_BUFFER_SIZE, policy)
+    } else {
+        channel::channel(config::WORKER_QUEUE_SIZE, BackpressurePolicy::Block)
+    };
     let app_config_clone = app_config.clone();
 
+    // Runtime connection changes are available for the live interface only.
+    let (command_tx, command_rx) = (!is_headless && replay_path.is_none())
+        .then(channel::command_channel)
+        .unzip();
+
     // Spawning a new asynchronous task for handling INDTP frames.
     tokio::spawn(async move {
-        let mut ingester = Ingester::new(tx, app_config_clone);
+        let result = if let Some(path) = replay_path {
+            journal::replay(&path, tx).await
+        } else {
+            let mut ingester = Ingester::new(tx, app_config_clone)
+                .with_decoders(decoders)
+                .with_engine(engine);
 
-        if let Err(e) = ingester.run().await {
+            if let Some(commands) = command_rx {
+                ingester = ingester.with_commands(commands);
+            }
+
+            ingester.run().await
+        };
+
+        if let Err(e) = result {
             log::error!("Core service failed: {e:?}");
         }
     });
 
+    if is_headless {
+        return tokio::task::block_in_place(|| {
+            tokio::runtime::Handle::current()
+                .block_on(run_headless(&app_config, rx))
+        });
+    }
+
+    let (ui_tx, ui_rx) =
+        channel::channel(config::MPSC_CHANNEL_BUFFER_SIZE, policy);
+    let worker = Worker::new(rx, ui_tx);
+    let stats = worker.stats();
+
+    tokio::spawn(worker.run());
+
     // Setting options controlling the behavior of a native window.
     let options = eframe::NativeOptions {
         viewport: egui::ViewportBuilder::default()
@@ -102,6 +457,7 @@ pub fn run() -> anyhow::Result<()> {
             .with_maximize_button(false)
             .with_inner_size(config::APP_WINDOW_SIZE),
         hardware_acceleration: HardwareAcceleration::Required,
+        renderer: window_renderer(&app_config.display.plot),
         ..Default::default()
     };
 
@@ -109,7 +465,25 @@ pub fn run() -> anyhow::Result<()> {
     let _ = eframe::run_native(
         config::APP_WINDOW_TITLE,
         options,
-        Box::new(|_| Ok(Box::new(App::new(app_config, rx)))),
+        Box::new(|cc| {
+            #[cfg(feature = "gpu-plots")]
+            let app_config = ui::gpu_plot::init(cc, app_config);
+            #[cfg(not(feature = "gpu-plots"))]
+            let _ = cc;
+
+            let mut app = App::new(app_config, ui_rx)
+                .with_stats(stats)
+                .with_config_path(config_path);
+
+            if let Some(commands) = command_tx {
+                app = app.with_commands(commands);
+            }
+
+            Ok(Box::new(match journal {
+                Some(journal) => app.with_journal(journal),
+                None => app,
+            }))
+        }),
     );
 
     Ok(())
@@ -3,11 +3,81 @@
 
 //! IMU data logger implementation.
 
-use crate::config::AppConfig;
+use crate::{
+    config::AppConfig,
+    core::{
+        StandardPayload, attitude::representation, channel::ChannelRegistry,
+        clipping::Clipping, dead_reckoning::NavState, motion::MotionState,
+        shock::ShockEvent, vertical::VerticalState,
+    },
+    model::{FrameContext, FrameWrapper},
+};
 use chrono::Local;
+use indtp::Header;
 use indtp::payload::{Imu3Acc, Imu3Gyr, Imu3Mag, Imu6, Imu9, Imu10, ImuQuat};
 use serde::{Deserialize, Serialize};
-use std::{fs, path::PathBuf};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+use tsilna_nav::math::{Quat32, na::Quaternion};
+
+/// Log file columns in the order of [`LogRecord`] fields.
+const LOG_COLUMNS: [&str; 52] = [
+    "timestamp",
+    "device_id",
+    "acc_x",
+    "acc_y",
+    "acc_z",
+    "gyr_x",
+    "gyr_y",
+    "gyr_z",
+    "mag_x",
+    "mag_y",
+    "mag_z",
+    "pressure",
+    "q_w",
+    "q_x",
+    "q_y",
+    "q_z",
+    "roll",
+    "pitch",
+    "yaw",
+    "acc_clipped",
+    "gyr_clipped",
+    "mag_clipped",
+    "host_time_us",
+    "lin_acc_x",
+    "lin_acc_y",
+    "lin_acc_z",
+    "vel_x",
+    "vel_y",
+    "vel_z",
+    "pos_x",
+    "pos_y",
+    "pos_z",
+    "annotation",
+    "altitude",
+    "attitude_error",
+    "attitude_uncertainty",
+    "motion",
+    "r_11",
+    "r_12",
+    "r_13",
+    "r_21",
+    "r_22",
+    "r_23",
+    "r_31",
+    "r_32",
+    "r_33",
+    "axis_x",
+    "axis_y",
+    "axis_z",
+    "axis_angle",
+    "fused_altitude",
+    "climb_rate",
+];
 
 #[derive(Debug, Default, Serialize, Deserialize)]
 /// IMU data log record.
@@ -50,6 +120,374 @@ pub struct LogRecord {
     pub pitch: f32,
     /// Rotation around Z-axis.
     pub yaw: f32,
+    /// Flag that shows whether accelerometer readings are clipped.
+    #[serde(default)]
+    pub acc_clipped: bool,
+    /// Flag that shows whether gyroscope readings are clipped.
+    #[serde(default)]
+    pub gyr_clipped: bool,
+    /// Flag that shows whether magnetometer readings are clipped.
+    #[serde(default)]
+    pub mag_clipped: bool,
+    /// Estimated host time of the sample in Unix microseconds.
+    #[serde(default)]
+    pub host_time_us: Option<i64>,
+    /// Linear (gravity-free) acceleration along X-axis.
+    #[serde(default)]
+    pub lin_acc_x: Option<f32>,
+    /// Linear (gravity-free) acceleration along Y-axis.
+    #[serde(default)]
+    pub lin_acc_y: Option<f32>,
+    /// Linear (gravity-free) acceleration along Z-axis.
+    #[serde(default)]
+    pub lin_acc_z: Option<f32>,
+    /// Dead-reckoning velocity along X-axis of Earth frame.
+    #[serde(default)]
+    pub vel_x: Option<f32>,
+    /// Dead-reckoning velocity along Y-axis of Earth frame.
+    #[serde(default)]
+    pub vel_y: Option<f32>,
+    /// Dead-reckoning velocity along Z-axis of Earth frame.
+    #[serde(default)]
+    pub vel_z: Option<f32>,
+    /// Dead-reckoning position along X-axis of Earth frame.
+    #[serde(default)]
+    pub pos_x: Option<f32>,
+    /// Dead-reckoning position along Y-axis of Earth frame.
+    #[serde(default)]
+    pub pos_y: Option<f32>,
+    /// Dead-reckoning position along Z-axis of Earth frame.
+    #[serde(default)]
+    pub pos_z: Option<f32>,
+    /// Annotation of the record (e.g. detected anomalies).
+    #[serde(default)]
+    pub annotation: Option<String>,
+    /// Barometric altitude.
+    #[serde(default)]
+    pub altitude: Option<f32>,
+    /// Angular error between onboard and local attitude.
+    #[serde(default)]
+    pub attitude_error: Option<f32>,
+    /// Attitude uncertainty.
+    #[serde(default)]
+    pub attitude_uncertainty: Option<f32>,
+    /// Classified motion state.
+    #[serde(default)]
+    pub motion: Option<MotionState>,
+    /// Rotation matrix element of row 1, column 1.
+    #[serde(default)]
+    pub r_11: Option<f32>,
+    /// Rotation matrix element of row 1, column 2.
+    #[serde(default)]
+    pub r_12: Option<f32>,
+    /// Rotation matrix element of row 1, column 3.
+    #[serde(default)]
+    pub r_13: Option<f32>,
+    /// Rotation matrix element of row 2, column 1.
+    #[serde(default)]
+    pub r_21: Option<f32>,
+    /// Rotation matrix element of row 2, column 2.
+    #[serde(default)]
+    pub r_22: Option<f32>,
+    /// Rotation matrix element of row 2, column 3.
+    #[serde(default)]
+    pub r_23: Option<f32>,
+    /// Rotation matrix element of row 3, column 1.
+    #[serde(default)]
+    pub r_31: Option<f32>,
+    /// Rotation matrix element of row 3, column 2.
+    #[serde(default)]
+    pub r_32: Option<f32>,
+    /// Rotation matrix element of row 3, column 3.
+    #[serde(default)]
+    pub r_33: Option<f32>,
+    /// Rotation axis X component.
+    #[serde(default)]
+    pub axis_x: Option<f32>,
+    /// Rotation axis Y component.
+    #[serde(default)]
+    pub axis_y: Option<f32>,
+    /// Rotation axis Z component.
+    #[serde(default)]
+    pub axis_z: Option<f32>,
+    /// Rotation angle around the axis.
+    #[serde(default)]
+    pub axis_angle: Option<f32>,
+    /// Baro-aided altitude.
+    #[serde(default)]
+    pub fused_altitude: Option<f32>,
+    /// Baro-aided climb rate.
+    #[serde(default)]
+    pub climb_rate: Option<f32>,
+}
+
+impl LogRecord {
+    /// Construct IMU data log record from frame context.
+    ///
+    /// # Parameters
+    /// - `frame_ctx` - given frame context to handle.
+    ///
+    /// # Returns
+    /// - IMU data log record - if frame context contains frame.
+    /// - `None` - otherwise.
+    #[must_use]
+    pub fn from_frame_context(frame_ctx: &FrameContext) -> Option<Self> {
+        let frame = frame_ctx.frame.as_ref()?;
+        let header = frame.header;
+
+        let mut record = Self {
+            timestamp: frame_ctx.timestamp,
+            device_id: header.device_id,
+            q_w: 1.0,
+            acc_clipped: frame_ctx.clipping.acc,
+            gyr_clipped: frame_ctx.clipping.gyr,
+            mag_clipped: frame_ctx.clipping.mag,
+            host_time_us: frame_ctx.host_time_us,
+            annotation: frame_ctx.annotation.clone(),
+            altitude: frame_ctx.altitude,
+            attitude_error: frame_ctx.attitude_error,
+            attitude_uncertainty: frame_ctx.attitude_uncertainty,
+            motion: frame_ctx.motion,
+            fused_altitude: frame_ctx.vertical.map(|v| v.altitude),
+            climb_rate: frame_ctx.vertical.map(|v| v.climb_rate),
+            ..Self::default()
+        };
+
+        if let Some([x, y, z]) = frame_ctx.linear_acc {
+            record.lin_acc_x = Some(x);
+            record.lin_acc_y = Some(y);
+            record.lin_acc_z = Some(z);
+        }
+
+        if let Some(quat) = &frame_ctx.quaternion {
+            record.set_attitude(quat);
+        }
+
+        if let Some(nav) = frame_ctx.navigation {
+            let [vel_x, vel_y, vel_z] = nav.velocity.map(Some);
+            let [pos_x, pos_y, pos_z] = nav.position.map(Some);
+
+            record.vel_x = vel_x;
+            record.vel_y = vel_y;
+            record.vel_z = vel_z;
+            record.pos_x = pos_x;
+            record.pos_y = pos_y;
+            record.pos_z = pos_z;
+        }
+
+        if let Some(payload) = &frame.payload {
+            match payload {
+                StandardPayload::Imu3Acc(p) => p.fill_record(&mut record),
+                StandardPayload::Imu3Gyr(p) => p.fill_record(&mut record),
+                StandardPayload::Imu3Mag(p) => p.fill_record(&mut record),
+                StandardPayload::Imu6(p) => p.fill_record(&mut record),
+                StandardPayload::Imu9(p) => p.fill_record(&mut record),
+                StandardPayload::Imu10(p) => p.fill_record(&mut record),
+                StandardPayload::ImuQuat(p) => p.fill_record(&mut record),
+            }
+        }
+
+        Some(record)
+    }
+
+    /// Convert IMU data log record back into frame context.
+    ///
+    /// Frame carries the logged readings as the standard payload of the
+    /// richest type they fit into. Values not logged (e.g. counters and raw
+    /// frame bytes) are left default.
+    ///
+    /// # Returns
+    /// - Frame context - if record carries readings or attitude.
+    /// - `None` - if record is an event row.
+    #[must_use]
+    pub fn to_frame_context(&self) -> Option<FrameContext> {
+        if self.is_event() {
+            return None;
+        }
+
+        let payload = self.payload();
+        let header = Header {
+            device_id: self.device_id,
+            payload_type: payload.payload_type(),
+            ..Header::default()
+        };
+
+        let quaternion = self.axis_angle.map(|_| {
+            Quat32::from_quaternion(Quaternion::new(
+                self.q_w, self.q_x, self.q_y, self.q_z,
+            ))
+        });
+
+        let navigation = triple(self.vel_x, self.vel_y, self.vel_z)
+            .zip(triple(self.pos_x, self.pos_y, self.pos_z))
+            .map(|(velocity, position)| NavState {
+                velocity,
+                position,
+                is_still: false,
+            });
+
+        let vertical = self.fused_altitude.zip(self.climb_rate).map(
+            |(altitude, climb_rate)| VerticalState {
+                altitude,
+                climb_rate,
+            },
+        );
+
+        Some(FrameContext {
+            frame: Some(FrameWrapper {
+                flags: header.flags(),
+                header,
+                payload: Some(payload),
+                ..FrameWrapper::default()
+            }),
+            timestamp: self.timestamp,
+            host_time_us: self.host_time_us,
+            is_valid: true,
+            quaternion,
+            attitude_error: self.attitude_error,
+            attitude_uncertainty: self.attitude_uncertainty,
+            linear_acc: triple(self.lin_acc_x, self.lin_acc_y, self.lin_acc_z),
+            navigation,
+            altitude: self.altitude,
+            vertical,
+            clipping: Clipping {
+                acc: self.acc_clipped,
+                gyr: self.gyr_clipped,
+                mag: self.mag_clipped,
+            },
+            annotation: self.annotation.clone(),
+            motion: self.motion,
+            ..FrameContext::default()
+        })
+    }
+
+    /// Build standard payload of the logged readings.
+    ///
+    /// # Returns
+    /// - Payload of the richest type the logged readings fit into (attitude
+    ///   quaternion if there are no readings).
+    fn payload(&self) -> StandardPayload {
+        let acc =
+            triple(self.acc_x, self.acc_y, self.acc_z).map(|[x, y, z]| {
+                Imu3Acc {
+                    acc_x: x.into(),
+                    acc_y: y.into(),
+                    acc_z: z.into(),
+                }
+            });
+        let gyr =
+            triple(self.gyr_x, self.gyr_y, self.gyr_z).map(|[x, y, z]| {
+                Imu3Gyr {
+                    gyr_x: x.into(),
+                    gyr_y: y.into(),
+                    gyr_z: z.into(),
+                }
+            });
+        let mag =
+            triple(self.mag_x, self.mag_y, self.mag_z).map(|[x, y, z]| {
+                Imu3Mag {
+                    mag_x: x.into(),
+                    mag_y: y.into(),
+                    mag_z: z.into(),
+                }
+            });
+
+        match (acc, gyr, mag, self.pressure) {
+            (Some(acc), Some(gyr), Some(mag), Some(baro)) => {
+                StandardPayload::Imu10(Imu10 {
+                    acc,
+                    gyr,
+                    mag,
+                    baro: baro.into(),
+                })
+            }
+            (Some(acc), Some(gyr), Some(mag), None) => {
+                StandardPayload::Imu9(Imu9 { acc, gyr, mag })
+            }
+            (Some(acc), Some(gyr), _, _) => {
+                StandardPayload::Imu6(Imu6 { acc, gyr })
+            }
+            (Some(acc), _, _, _) => StandardPayload::Imu3Acc(acc),
+            (None, Some(gyr), _, _) => StandardPayload::Imu3Gyr(gyr),
+            (None, None, Some(mag), _) => StandardPayload::Imu3Mag(mag),
+            (None, None, None, _) => StandardPayload::ImuQuat(ImuQuat {
+                w: self.q_w.into(),
+                x: self.q_x.into(),
+                y: self.q_y.into(),
+                z: self.q_z.into(),
+            }),
+        }
+    }
+
+    /// Construct event log record of the detected shock.
+    ///
+    /// Event rows carry only time and annotation columns, so that they are
+    /// found by the annotation without affecting readings columns.
+    ///
+    /// # Parameters
+    /// - `shock` - given detected shock event.
+    ///
+    /// # Returns
+    /// - Shock event log record.
+    #[must_use]
+    pub fn from_shock(shock: &ShockEvent) -> Self {
+        Self {
+            timestamp: shock.timestamp,
+            device_id: shock.device_id,
+            q_w: 1.0,
+            host_time_us: shock.host_time_us,
+            annotation: Some(shock.to_string()),
+            ..Self::default()
+        }
+    }
+
+    /// Check whether record is an event row.
+    ///
+    /// # Returns
+    /// - `true` - if record carries neither readings nor attitude (e.g.
+    ///   shock event).
+    /// - `false` - otherwise.
+    #[must_use]
+    pub const fn is_event(&self) -> bool {
+        self.acc_x.is_none()
+            && self.gyr_x.is_none()
+            && self.mag_x.is_none()
+            && self.pressure.is_none()
+            && self.axis_angle.is_none()
+    }
+
+    /// Set attitude columns of all representations.
+    ///
+    /// # Parameters
+    /// - `quat` - given attitude to set.
+    pub fn set_attitude(&mut self, quat: &Quat32) {
+        let (roll, pitch, yaw) = representation::euler_angles(quat);
+        let [[r_11, r_12, r_13], [r_21, r_22, r_23], [r_31, r_32, r_33]] =
+            representation::rotation_matrix(quat).map(|row| row.map(Some));
+        let ([axis_x, axis_y, axis_z], angle) =
+            representation::axis_angle(quat);
+
+        self.q_w = quat.w;
+        self.q_x = quat.i;
+        self.q_y = quat.j;
+        self.q_z = quat.k;
+        self.roll = roll;
+        self.pitch = pitch;
+        self.yaw = yaw;
+        self.r_11 = r_11;
+        self.r_12 = r_12;
+        self.r_13 = r_13;
+        self.r_21 = r_21;
+        self.r_22 = r_22;
+        self.r_23 = r_23;
+        self.r_31 = r_31;
+        self.r_32 = r_32;
+        self.r_33 = r_33;
+        self.axis_x = Some(axis_x);
+        self.axis_y = Some(axis_y);
+        self.axis_z = Some(axis_z);
+        self.axis_angle = Some(angle);
+    }
 }
 
 /// Trait for logging IDTP frame payload data.
@@ -138,8 +576,14 @@ impl ToLog for ImuQuat {
 pub struct Logger {
     /// CSV file writer.
     writer: csv::Writer<fs::File>,
+    /// Log file header with measurement units.
+    header: Vec<String>,
     /// Path to log file.
     path: String,
+    /// Path to the first log file without extension.
+    base_path: PathBuf,
+    /// Session segment number (incremented on device reboot).
+    segment: usize,
     /// Recording start timestamp.
     start_time: std::time::Instant,
 }
@@ -161,21 +605,60 @@ impl Logger {
         fs::create_dir_all(&cfg.log.directory)?;
 
         let now = Local::now();
-        let filename = format!("log_{}.csv", now.format("%d-%m-%Y_%H-%M-%S"));
+        let filename = format!("log_{}", now.format("%d-%m-%Y_%H-%M-%S"));
 
-        let mut path = PathBuf::from(&cfg.log.directory);
-        path.push(filename);
+        let mut base_path = PathBuf::from(&cfg.log.directory);
+        base_path.push(filename);
 
+        let path = base_path.with_extension("csv");
         let path_str = path.to_string_lossy().into_owned();
-        let file = fs::File::create(&path)?;
+        let header = log_header(&cfg.channels);
 
         Ok(Self {
-            writer: csv::Writer::from_writer(file),
+            writer: create_writer(&path, &header)?,
+            header,
             path: path_str,
+            base_path,
+            segment: 1,
             start_time: std::time::Instant::now(),
         })
     }
 
+    /// Continue recording in the new log file (e.g. after device reboot).
+    ///
+    /// New file is named after the first one with the segment number suffix
+    /// (e.g. `log_<time>_seg2.csv`).
+    ///
+    /// # Returns
+    /// - `Ok` - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - Error to create log file.
+    pub fn next_segment(&mut self) -> std::io::Result<()> {
+        let segment = self.segment + 1;
+
+        let mut filename = self.base_path.as_os_str().to_owned();
+        filename.push(format!("_seg{segment}.csv"));
+
+        let path = PathBuf::from(filename);
+
+        self.writer = create_writer(&path, &self.header)?;
+        self.path = path.to_string_lossy().into_owned();
+        self.segment = segment;
+
+        Ok(())
+    }
+
+    /// Get session segment number.
+    ///
+    /// # Returns
+    /// - Number of the current log file starting from 1.
+    #[must_use]
+    pub const fn segment(&self) -> usize {
+        self.segment
+    }
+
     /// Get log file path.
     ///
     /// # Returns
@@ -185,6 +668,18 @@ impl Logger {
         &self.path
     }
 
+    /// Get log file size.
+    ///
+    /// # Returns
+    /// - Number of bytes written into the current log file.
+    #[must_use]
+    pub fn size(&self) -> u64 {
+        self.writer
+            .get_ref()
+            .metadata()
+            .map_or(0, |metadata| metadata.len())
+    }
+
     /// Write record into the log file.
     ///
     /// # Parameters
@@ -219,3 +714,162 @@ impl Logger {
         format!("{hours:02}:{minutes:02}:{seconds:02}")
     }
 }
+
+/// Combine optional axis values.
+///
+/// # Parameters
+/// - `x` - given X-axis value.
+/// - `y` - given Y-axis value.
+/// - `z` - given Z-axis value.
+///
+/// # Returns
+/// - Values of all axes - if all of them are present.
+/// - `None` - otherwise.
+const fn triple(
+    x: Option<f32>,
+    y: Option<f32>,
+    z: Option<f32>,
+) -> Option<[f32; 3]> {
+    match (x, y, z) {
+        (Some(x), Some(y), Some(z)) => Some([x, y, z]),
+        _ => None,
+    }
+}
+
+/// Reveal file in the OS file manager.
+///
+/// # Parameters
+/// - `path` - given path to the file to reveal.
+///
+/// # Returns
+/// - `Ok` - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - Error to launch the file manager.
+pub fn reveal_in_file_manager(path: &Path) -> std::io::Result<()> {
+    let mut cmd = if cfg!(target_os = "windows") {
+        let mut cmd = Command::new("explorer");
+        cmd.arg(format!("/select,{}", path.display()));
+        cmd
+    } else if cfg!(target_os = "macos") {
+        let mut cmd = Command::new("open");
+        cmd.arg("-R").arg(path);
+        cmd
+    } else {
+        // Generic file managers are unable to select file, opening its
+        // directory instead.
+        let mut cmd = Command::new("xdg-open");
+        cmd.arg(path.parent().unwrap_or(path));
+        cmd
+    };
+
+    cmd.spawn().map(drop)
+}
+
+/// Get log file header.
+///
+/// # Parameters
+/// - `channels` - given decoded channels info.
+///
+/// # Returns
+/// - Column names with measurement units (e.g. `acc_x [m/s²]`).
+fn log_header(channels: &ChannelRegistry) -> Vec<String> {
+    LOG_COLUMNS
+        .iter()
+        .map(|&column| {
+            let key = if column == "pressure" { "baro" } else { column };
+
+            match channels.get(key) {
+                Some(info) if !info.unit.is_empty() => {
+                    format!("{column} [{}]", info.unit)
+                }
+                _ => column.to_string(),
+            }
+        })
+        .collect()
+}
+
+/// Create log file and write its header.
+///
+/// # Parameters
+/// - `path` - given path to the log file.
+/// - `header` - given log file header.
+///
+/// # Returns
+/// - CSV file writer - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - Error to create log file.
+fn create_writer(
+    path: &Path,
+    header: &[String],
+) -> std::io::Result<csv::Writer<fs::File>> {
+    let file = fs::File::create(path)?;
+    let mut writer = csv::WriterBuilder::new()
+        .has_headers(false)
+        .from_writer(file);
+
+    writer.write_record(header)?;
+    Ok(writer)
+}
+
+/// Write IMU data log records into the new log file.
+///
+/// # Parameters
+/// - `path` - given path to the log file.
+/// - `records` - given log records to write.
+/// - `channels` - given decoded channels info.
+///
+/// # Returns
+/// - `Ok` - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - Error to create log file.
+/// - CSV file handling errors.
+pub fn write_records(
+    path: impl AsRef<Path>,
+    records: &[LogRecord],
+    channels: &ChannelRegistry,
+) -> anyhow::Result<()> {
+    let mut writer = create_writer(path.as_ref(), &log_header(channels))?;
+
+    for record in records {
+        writer.serialize(record)?;
+    }
+
+    writer.flush()?;
+    Ok(())
+}
+
+/// Read IMU data log records from the log file.
+///
+/// Measurement units are stripped from the column names, so that logs
+/// written with and without units are read the same way.
+///
+/// # Parameters
+/// - `path` - given path to the log file.
+///
+/// # Returns
+/// - List of log records - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - I/O errors.
+/// - CSV file handling errors.
+pub fn read_records(path: impl AsRef<Path>) -> anyhow::Result<Vec<LogRecord>> {
+    let mut reader = csv::Reader::from_path(path)?;
+
+    let header: csv::StringRecord = reader
+        .headers()?
+        .iter()
+        .map(|column| column.split_once(" [").map_or(column, |(key, _)| key))
+        .collect();
+    reader.set_headers(header);
+
+    let records = reader.deserialize().collect::<Result<Vec<_>, _>>()?;
+
+    Ok(records)
+}
@@ -0,0 +1,303 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Bounded application events channel with back-pressure policy.
+
+use crate::{
+    config::BackpressurePolicy,
+    model::{AppEvent, IngesterCommand},
+};
+use std::{
+    collections::VecDeque,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+    },
+};
+use tokio::sync::{Notify, mpsc};
+
+/// State shared between the sender and the receiver.
+struct Shared {
+    /// Queued events.
+    queue: Mutex<VecDeque<AppEvent>>,
+    /// Max number of queued events.
+    capacity: usize,
+    /// Policy applied when the queue is full.
+    policy: BackpressurePolicy,
+    /// Total number of dropped frame events.
+    dropped: AtomicUsize,
+    /// Flag whether the sender is dropped.
+    is_sender_closed: AtomicBool,
+    /// Flag whether the receiver is dropped.
+    is_receiver_closed: AtomicBool,
+    /// Notification about queued event.
+    event_queued: Notify,
+    /// Notification about freed queue slot.
+    slot_freed: Notify,
+}
+
+impl Shared {
+    /// Lock events queue.
+    ///
+    /// # Returns
+    /// - Events queue guard.
+    fn lock(&self) -> std::sync::MutexGuard<'_, VecDeque<AppEvent>> {
+        // Queue stays consistent even if other thread panicked.
+        self.queue
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Queue event according to the back-pressure policy.
+    ///
+    /// # Parameters
+    /// - `event` - given application event to queue.
+    ///
+    /// # Returns
+    /// - `Ok` - if event is queued or dropped by the policy.
+    /// - `Err` - with rejected event if the sender has to wait.
+    fn try_push(&self, event: AppEvent) -> Result<(), AppEvent> {
+        let mut queue = self.lock();
+
+        if queue.len() < self.capacity {
+            queue.push_back(event);
+            return Ok(());
+        }
+
+        // Other events are never dropped, queued frame gives way to them
+        // unless frames must not be lost either.
+        if !is_frame(&event) {
+            let pos = queue.iter().position(is_frame);
+
+            match pos {
+                Some(pos) if self.policy != BackpressurePolicy::Block => {
+                    queue.remove(pos);
+                    queue.push_back(event);
+                }
+                _ => return Err(event),
+            }
+
+            drop(queue);
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+            return Ok(());
+        }
+
+        match self.policy {
+            BackpressurePolicy::DropOldest => {
+                if let Some(pos) = queue.iter().position(is_frame) {
+                    queue.remove(pos);
+                }
+
+                queue.push_back(event);
+            }
+            BackpressurePolicy::DropNewest => {}
+            BackpressurePolicy::Block => return Err(event),
+        }
+
+        drop(queue);
+        self.dropped.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+/// Error of sending event to the closed channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChannelClosed;
+
+impl std::fmt::Display for ChannelClosed {
+    /// Format error message.
+    ///
+    /// # Parameters
+    /// - `f` - given formatter to write into.
+    ///
+    /// # Returns
+    /// - Formatting result.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "events channel is closed")
+    }
+}
+
+impl std::error::Error for ChannelClosed {}
+
+/// Sending half of the ingester commands channel.
+pub type CommandSender = mpsc::UnboundedSender<IngesterCommand>;
+
+/// Receiving half of the ingester commands channel.
+pub type CommandReceiver = mpsc::UnboundedReceiver<IngesterCommand>;
+
+/// Sending half of the events channel.
+pub struct EventSender {
+    /// Shared channel state.
+    shared: Arc<Shared>,
+}
+
+/// Receiving half of the events channel.
+pub struct EventReceiver {
+    /// Shared channel state.
+    shared: Arc<Shared>,
+}
+
+/// Create bounded application events channel.
+///
+/// Back-pressure policy is applied to frame events only, so that connection
+/// status and alert events are never lost: queued frame is dropped in their
+/// favour, or the sender waits if there is none or the policy blocks.
+///
+/// # Parameters
+/// - `capacity` - given max number of queued events.
+/// - `policy` - given policy applied when the queue is full.
+///
+/// # Returns
+/// - Sending and receiving halves of the channel.
+#[must_use]
+pub fn channel(
+    capacity: usize,
+    policy: BackpressurePolicy,
+) -> (EventSender, EventReceiver) {
+    let shared = Arc::new(Shared {
+        queue: Mutex::new(VecDeque::with_capacity(capacity)),
+        capacity: capacity.max(1),
+        policy,
+        dropped: AtomicUsize::new(0),
+        is_sender_closed: AtomicBool::new(false),
+        is_receiver_closed: AtomicBool::new(false),
+        event_queued: Notify::new(),
+        slot_freed: Notify::new(),
+    });
+
+    (
+        EventSender {
+            shared: Arc::clone(&shared),
+        },
+        EventReceiver { shared },
+    )
+}
+
+/// Create ingester commands channel.
+///
+/// Commands are rare and sent from the user interface thread, so that the
+/// channel is unbounded and sending never blocks.
+///
+/// # Returns
+/// - Sending and receiving halves of the channel.
+#[must_use]
+pub fn command_channel() -> (CommandSender, CommandReceiver) {
+    mpsc::unbounded_channel()
+}
+
+impl EventSender {
+    /// Send event according to the back-pressure policy.
+    ///
+    /// # Parameters
+    /// - `event` - given application event to send.
+    ///
+    /// # Returns
+    /// - `Ok` - if event is queued or dropped by the policy.
+    /// - `Err` - if the receiver is dropped.
+    ///
+    /// # Errors
+    /// - Receiver is dropped.
+    pub async fn send(&self, mut event: AppEvent) -> Result<(), ChannelClosed> {
+        loop {
+            if self.shared.is_receiver_closed.load(Ordering::Acquire) {
+                return Err(ChannelClosed);
+            }
+
+            match self.shared.try_push(event) {
+                Ok(()) => break,
+                Err(rejected) => event = rejected,
+            }
+
+            // Waiting for the receiver to free a queue slot.
+            self.shared.slot_freed.notified().await;
+        }
+
+        self.shared.event_queued.notify_one();
+        Ok(())
+    }
+
+    /// Get total number of dropped frame events.
+    ///
+    /// # Returns
+    /// - Number of dropped frame events.
+    #[must_use]
+    pub fn dropped(&self) -> usize {
+        self.shared.dropped.load(Ordering::Relaxed)
+    }
+}
+
+impl Drop for EventSender {
+    /// Close the channel and wake up the receiver.
+    fn drop(&mut self) {
+        self.shared.is_sender_closed.store(true, Ordering::Release);
+        self.shared.event_queued.notify_one();
+    }
+}
+
+impl EventReceiver {
+    /// Receive queued event without waiting.
+    ///
+    /// # Returns
+    /// - Queued event - if there is any.
+    /// - `None` - otherwise.
+    pub fn try_recv(&mut self) -> Option<AppEvent> {
+        let event = self.shared.lock().pop_front();
+
+        if event.is_some() {
+            self.shared.slot_freed.notify_one();
+        }
+
+        event
+    }
+
+    /// Get number of queued events.
+    ///
+    /// # Returns
+    /// - Number of events waiting to be received.
+    #[must_use]
+    pub fn queued(&self) -> usize {
+        self.shared.lock().len()
+    }
+
+    /// Wait for the next event.
+    ///
+    /// # Returns
+    /// - Received event - in case of success.
+    /// - `None` - if the sender is dropped and the queue is empty.
+    pub async fn recv(&mut self) -> Option<AppEvent> {
+        loop {
+            if let Some(event) = self.try_recv() {
+                return Some(event);
+            }
+
+            if self.shared.is_sender_closed.load(Ordering::Acquire) {
+                // Draining events queued right before the sender is dropped.
+                return self.try_recv();
+            }
+
+            self.shared.event_queued.notified().await;
+        }
+    }
+}
+
+impl Drop for EventReceiver {
+    /// Close the channel and wake up the sender.
+    fn drop(&mut self) {
+        self.shared
+            .is_receiver_closed
+            .store(true, Ordering::Release);
+        self.shared.slot_freed.notify_one();
+    }
+}
+
+/// Check whether event is a frame event.
+///
+/// # Parameters
+/// - `event` - given application event to check.
+///
+/// # Returns
+/// - `true` - if event is a frame event.
+/// - `false` - otherwise.
+const fn is_frame(event: &AppEvent) -> bool {
+    matches!(event, AppEvent::FrameReceived(_))
+}
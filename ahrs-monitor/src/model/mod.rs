@@ -3,8 +3,36 @@
 
 //! Application state module.
 
-use crate::core::StandardPayload;
+pub mod channel;
+pub mod session;
+pub mod stats;
+
+use crate::{
+    config::{FusionInputs, NetConfig},
+    core::{
+        StandardPayload,
+        alert::Alert,
+        anomaly::Anomaly,
+        attitude::{FusionAlgorithm, GyroBias, representation},
+        burst::ArrivalStats,
+        calibration::AccCalibration,
+        clipping::Clipping,
+        dead_reckoning::NavState,
+        g_load::GLoad,
+        length::LengthAnomalies,
+        mag_calibration::MagCalibration,
+        mode::ModeTransition,
+        motion::{MotionState, MotionTransition},
+        shock::ShockEvent,
+        source::{Link, RedundancyStats},
+        stale_data::StaleTransition,
+        timesync::ClockModel,
+        vertical::VerticalState,
+        vibration::VibrationMetrics,
+    },
+};
 use indtp::{Flags, Header};
+use serde::{Deserialize, Serialize};
 use tsilna_nav::math::Quat32;
 
 /// TODO:
@@ -22,23 +50,215 @@ pub struct FrameWrapper {
     pub flags: Flags,
 }
 
+/// Reasons for rejecting the received packet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RejectReason {
+    /// Malformed frame header.
+    Header,
+    /// Packet length does not match the header.
+    Length,
+    /// CRC or MAC verification failure.
+    Integrity,
+    /// Payload decryption failure.
+    Decryption,
+    /// Payload sample decoding failure.
+    Payload,
+    /// Sequence number was already received.
+    Duplicate,
+    /// Sequence number is older than the replay window.
+    Stale,
+}
+
+/// Number of rejected packets by failure reason.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub struct BadPackets {
+    /// Number of packets with malformed header.
+    pub header: usize,
+    /// Number of packets with length mismatching the header.
+    pub length: usize,
+    /// Number of packets failed CRC or MAC verification.
+    pub integrity: usize,
+    /// Number of packets failed payload decryption.
+    pub decryption: usize,
+    /// Number of packets failed payload sample decoding.
+    #[serde(default)]
+    pub payload: usize,
+    /// Number of duplicated or replayed packets.
+    #[serde(default)]
+    pub duplicate: usize,
+    /// Number of packets older than the replay window.
+    #[serde(default)]
+    pub stale: usize,
+}
+
+impl BadPackets {
+    /// Count rejected packet.
+    ///
+    /// # Parameters
+    /// - `reason` - given reason for rejecting the packet.
+    pub const fn add(&mut self, reason: RejectReason) {
+        let counter = match reason {
+            RejectReason::Header => &mut self.header,
+            RejectReason::Length => &mut self.length,
+            RejectReason::Integrity => &mut self.integrity,
+            RejectReason::Decryption => &mut self.decryption,
+            RejectReason::Payload => &mut self.payload,
+            RejectReason::Duplicate => &mut self.duplicate,
+            RejectReason::Stale => &mut self.stale,
+        };
+
+        *counter += 1;
+    }
+
+    /// Get total number of rejected packets.
+    ///
+    /// # Returns
+    /// - Total number of rejected packets.
+    #[must_use]
+    pub const fn total(&self) -> usize {
+        self.header
+            + self.length
+            + self.integrity
+            + self.decryption
+            + self.payload
+            + self.duplicate
+            + self.stale
+    }
+}
+
+/// Received packet rejected by the ingester.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RejectedFrame {
+    /// Reason for rejecting the packet.
+    pub reason: RejectReason,
+    /// Raw packet bytes as received.
+    pub bytes: Vec<u8>,
+}
+
 /// Context data after receiving the frame.
-#[derive(Default, Debug)]
+///
+/// Context is journaled as is, missing fields of older journals are set to
+/// their defaults.
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default)]
 pub struct FrameContext {
     /// INDTP frame.
+    #[serde(with = "crate::journal::raw_frame")]
     pub frame: Option<FrameWrapper>,
     /// Sensor-local time in microseconds.
     pub timestamp: u32,
+    /// Estimated host time of the sample in Unix microseconds.
+    pub host_time_us: Option<i64>,
+    /// Sensor clock model relative to host clock.
+    pub clock: Option<ClockModel>,
     /// Indicator whether current frame is valid.
     pub is_valid: bool,
     /// Total number of packets.
     pub total_packets: usize,
-    /// Number of broken packets.
-    pub bad_packets: usize,
+    /// Number of broken packets by failure reason.
+    pub bad_packets: BadPackets,
+    /// Number of frame length anomalies by category.
+    pub length_anomalies: LengthAnomalies,
+    /// Statistics of the recent frame inter-arrival times.
+    pub arrival: ArrivalStats,
     /// Number of packets per second.
     pub pps: usize,
+    /// Number of received bytes per second (including rejected and
+    /// duplicated datagrams).
+    pub bytes_per_second: usize,
+    /// Ingester processing time of the frame in microseconds (from datagram
+    /// receipt to sending the frame event).
+    #[serde(skip)]
+    pub processing_time_us: u32,
     /// Unit for representation of rotation in space.
+    #[serde(with = "crate::journal::quaternion")]
     pub quaternion: Option<Quat32>,
+    /// Estimated gyroscope bias (`None` - not estimated yet).
+    pub gyro_bias: Option<GyroBias>,
+    /// Sensors fused into the attitude (`None` - attitude is not estimated
+    /// from the frame readings).
+    pub fusion_inputs: Option<FusionInputs>,
+    /// Angular error between onboard and local attitude in degrees (`None` -
+    /// device does not report its attitude).
+    pub attitude_error: Option<f32>,
+    /// Attitude uncertainty in degrees (`None` - attitude is not estimated
+    /// from the raw readings).
+    pub attitude_uncertainty: Option<f32>,
+    /// Linear (gravity-free) acceleration (x, y, z) in sensor frame in m/s²
+    /// (`None` - no accelerometer readings).
+    pub linear_acc: Option<[f32; 3]>,
+    /// Dead-reckoning velocity and position (`None` - disabled).
+    pub navigation: Option<NavState>,
+    /// Barometric altitude in meters (`None` - no barometer readings).
+    pub altitude: Option<f32>,
+    /// Baro-aided altitude and climb rate (`None` - disabled or no barometer
+    /// readings).
+    pub vertical: Option<VerticalState>,
+    /// Vibration metrics of the accelerometer readings (`None` - disabled or
+    /// no accelerometer readings).
+    pub vibration: Option<VibrationMetrics>,
+    /// Current and max G-load (`None` - disabled or no accelerometer
+    /// readings).
+    pub g_load: Option<GLoad>,
+    /// Sensors whose readings are clipped by the full-scale range.
+    pub clipping: Clipping,
+    /// Anomalous readings detected on sensor channels.
+    pub anomalies: Vec<Anomaly>,
+    /// Annotation of the log record (`None` - no annotation).
+    pub annotation: Option<String>,
+    /// Number of frames dropped by the events channel.
+    pub dropped_events: usize,
+    /// Active link of redundant frame sources (`None` - single link).
+    pub active_link: Option<Link>,
+    /// Frame statistics of redundant links (`None` - single link).
+    pub link_stats: Option<RedundancyStats>,
+    /// Protocol mode switch at this frame (`None` - mode is unchanged).
+    pub mode_transition: Option<ModeTransition>,
+    /// Classified motion state (`None` - not classified).
+    pub motion: Option<MotionState>,
+    /// Motion state change at this frame (`None` - state is unchanged).
+    pub motion_transition: Option<MotionTransition>,
+    /// Number of consecutive frames repeating the previous payload.
+    pub repeated_frames: usize,
+    /// Indicator whether payload is unchanged for the configured number of
+    /// frames (frozen sensor or stale buffer).
+    pub is_stale_data: bool,
+    /// Stale data state change at this frame (`None` - state is unchanged).
+    pub stale_transition: Option<StaleTransition>,
+    /// Indicator whether device reboot was detected at this frame.
+    pub is_reboot: bool,
+    /// Host suspend duration in seconds before this frame (`None` - host was
+    /// not suspended).
+    pub resumed_after: Option<f32>,
+    /// Rejected packet (`None` - packet is accepted).
+    pub rejected: Option<RejectedFrame>,
+}
+
+impl FrameContext {
+    /// Get estimated attitude as rotation matrix.
+    ///
+    /// # Returns
+    /// - Row-major rotation matrix - if attitude is estimated.
+    /// - `None` - otherwise.
+    #[must_use]
+    pub fn rotation_matrix(&self) -> Option<[[f32; 3]; 3]> {
+        self.quaternion
+            .as_ref()
+            .map(representation::rotation_matrix)
+    }
+
+    /// Get estimated attitude as rotation axis and angle.
+    ///
+    /// # Returns
+    /// - Unit rotation axis and angle in radians - if attitude is estimated.
+    /// - `None` - otherwise.
+    #[must_use]
+    pub fn axis_angle(&self) -> Option<([f32; 3], f32)> {
+        self.quaternion.as_ref().map(representation::axis_angle)
+    }
 }
 
 /// Application events enumeration.
@@ -47,4 +267,38 @@ pub enum AppEvent {
     UpdateConnectionStatus(bool),
     /// Event for handling received frame.
     FrameReceived(Box<FrameContext>),
+    /// Event for handling triggered or cleared alert.
+    AlertTriggered(Box<Alert>),
+    /// Event for handling detected shock.
+    ShockDetected(ShockEvent),
+}
+
+/// Commands sent from the user interface to the ingester.
+#[derive(Debug, Clone)]
+pub enum IngesterCommand {
+    /// Close frame source and open it with new networks configurations.
+    Reconnect(Box<NetConfig>),
+    /// Close frame source until the next reconnect.
+    Disconnect,
+    /// Change frames accepted by the ingester.
+    SetFrameFilter {
+        /// Accepted device identifier (`None` accepts all devices).
+        device_id: Option<u8>,
+        /// Flag whether frames are expected to be encrypted.
+        use_encryption: bool,
+    },
+    /// Restart attitude estimation with another fusion algorithm.
+    SetFusion(FusionAlgorithm),
+    /// Apply another accelerometer calibration to received readings.
+    SetAccCalibration(AccCalibration),
+    /// Apply another magnetometer calibration to received readings.
+    SetMagCalibration(MagCalibration),
+    /// Reset dead-reckoning velocity and position to zero.
+    ResetNavigation,
+    /// Restart attitude estimation of all devices.
+    ResetAttitude,
+    /// Reset max G-load.
+    ResetGLoad,
+    /// Change sensors fused into the attitude.
+    SetFusionInputs(FusionInputs),
 }
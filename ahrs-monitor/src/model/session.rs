@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Archived device sessions.
+//!
+//! Switching to another device or disconnecting from the UI closes the
+//! current session. Its frames history and counters are archived, so that
+//! they can be reviewed later in the session browser.
+
+use crate::model::{FrameContext, stats::SessionStats};
+use chrono::{DateTime, Local};
+use std::{sync::Arc, time::Duration};
+
+/// Closed device session.
+#[derive(Debug)]
+pub struct ArchivedSession {
+    /// Frame source description (e.g. `udp://127.0.0.1:10000`).
+    pub source: String,
+    /// Session start time.
+    pub started: DateTime<Local>,
+    /// Session end time.
+    pub ended: DateTime<Local>,
+    /// Stream statistics accumulated over the session.
+    pub stats: SessionStats,
+    /// Last frame contexts of the session.
+    pub history: Vec<Arc<FrameContext>>,
+}
+
+impl ArchivedSession {
+    /// Get session duration.
+    ///
+    /// # Returns
+    /// - Time between session start and end.
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        (self.ended - self.started).to_std().unwrap_or_default()
+    }
+
+    /// Check whether session received any packets.
+    ///
+    /// # Returns
+    /// - `true` - if session has no packets and history.
+    /// - `false` - otherwise.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.stats.total_packets == 0 && self.history.is_empty()
+    }
+}
@@ -0,0 +1,233 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Session-wide stream statistics.
+//!
+//! Ingester counters restart from zero whenever the ingester is restarted.
+//! Session statistics accumulate their increments instead, so that counters
+//! survive restarts and reconnects until they are reset explicitly.
+
+use crate::model::{BadPackets, FrameContext};
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+
+/// Max number of packets per second samples in history.
+const PPS_HISTORY_SIZE: usize = 3600;
+
+/// Interval between packets per second samples.
+const PPS_SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Packets per second summary.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct PpsSummary {
+    /// Min number of packets per second.
+    pub min: usize,
+    /// Average number of packets per second.
+    pub avg: f64,
+    /// Max number of packets per second.
+    pub max: usize,
+}
+
+/// Stream statistics accumulated over the whole session.
+#[derive(Debug, Default)]
+pub struct SessionStats {
+    /// Total number of packets.
+    pub total_packets: usize,
+    /// Number of broken packets by failure reason.
+    pub bad_packets: BadPackets,
+    /// Number of packets lost according to sequence gaps.
+    pub lost_packets: usize,
+    /// History of packets per second samples.
+    pps_history: VecDeque<usize>,
+    /// Time of the last packets per second sample.
+    last_pps_sample: Option<Instant>,
+    /// Last total number of packets reported by ingester.
+    last_total_packets: usize,
+    /// Last number of broken packets reported by ingester.
+    last_bad_packets: BadPackets,
+    /// Sequence number of the last valid frame.
+    last_sequence: Option<u16>,
+}
+
+/// Session statistics shared between the stats worker and the user
+/// interface.
+#[derive(Debug, Clone, Default)]
+pub struct SharedStats(Arc<Mutex<SessionStats>>);
+
+impl SharedStats {
+    /// Lock session statistics.
+    ///
+    /// # Returns
+    /// - Session statistics guard.
+    pub fn lock(&self) -> MutexGuard<'_, SessionStats> {
+        // Counters stay consistent even if other thread panicked.
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl SessionStats {
+    /// Accumulate counters of the received frame.
+    ///
+    /// # Parameters
+    /// - `frame_ctx` - given received frame context to handle.
+    pub fn update(&mut self, frame_ctx: &FrameContext) {
+        let is_restarted = frame_ctx.total_packets < self.last_total_packets;
+
+        if is_restarted {
+            // Sequence numbers of the new stream are not related to the old.
+            self.last_sequence = None;
+        }
+
+        self.total_packets +=
+            delta(frame_ctx.total_packets, self.last_total_packets);
+
+        let bad = &frame_ctx.bad_packets;
+        let last = &self.last_bad_packets;
+
+        self.bad_packets.header += delta(bad.header, last.header);
+        self.bad_packets.length += delta(bad.length, last.length);
+        self.bad_packets.integrity += delta(bad.integrity, last.integrity);
+        self.bad_packets.decryption += delta(bad.decryption, last.decryption);
+        self.bad_packets.payload += delta(bad.payload, last.payload);
+        self.bad_packets.duplicate += delta(bad.duplicate, last.duplicate);
+        self.bad_packets.stale += delta(bad.stale, last.stale);
+
+        self.last_total_packets = frame_ctx.total_packets;
+        self.last_bad_packets = frame_ctx.bad_packets;
+
+        if let Some(frame) = &frame_ctx.frame
+            && frame_ctx.is_valid
+        {
+            self.update_sequence(frame.header.sequence.get());
+        }
+
+        self.sample_pps(frame_ctx.pps);
+    }
+
+    /// Forget last sequence number after reconnect.
+    ///
+    /// IMU may restart its sequence numbering, so that the gap between
+    /// streams is not counted as loss.
+    pub const fn reset_sequence(&mut self) {
+        self.last_sequence = None;
+    }
+
+    /// Reset session counters and history.
+    pub fn reset(&mut self) {
+        self.total_packets = 0;
+        self.bad_packets = BadPackets::default();
+        self.lost_packets = 0;
+        self.pps_history.clear();
+        self.last_pps_sample = None;
+    }
+
+    /// Get ratio of lost packets.
+    ///
+    /// # Returns
+    /// - Ratio of lost packets to expected ones (0.0 - 1.0).
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn loss_ratio(&self) -> f64 {
+        let expected = self.total_packets + self.lost_packets;
+
+        if expected == 0 {
+            0.0
+        } else {
+            self.lost_packets as f64 / expected as f64
+        }
+    }
+
+    /// Get packets per second summary over the history.
+    ///
+    /// # Returns
+    /// - Packets per second summary - if history is not empty.
+    /// - `None` - otherwise.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn pps_summary(&self) -> Option<PpsSummary> {
+        let min = *self.pps_history.iter().min()?;
+        let max = *self.pps_history.iter().max()?;
+        let sum: usize = self.pps_history.iter().sum();
+        let avg = sum as f64 / self.pps_history.len() as f64;
+
+        Some(PpsSummary { min, avg, max })
+    }
+
+    /// Get packets per second history.
+    ///
+    /// # Returns
+    /// - Iterator over packets per second samples (one per second).
+    pub fn pps_history(&self) -> impl Iterator<Item = usize> + '_ {
+        self.pps_history.iter().copied()
+    }
+
+    /// Get the latest packets per second samples.
+    ///
+    /// # Parameters
+    /// - `count` - given max number of samples.
+    ///
+    /// # Returns
+    /// - Iterator over the latest samples from the oldest one.
+    pub fn recent_pps(&self, count: usize) -> impl Iterator<Item = usize> + '_ {
+        let skipped = self.pps_history.len().saturating_sub(count);
+        self.pps_history.iter().skip(skipped).copied()
+    }
+
+    /// Count packets lost between consecutive valid frames.
+    ///
+    /// # Parameters
+    /// - `sequence` - given sequence number of the valid frame.
+    fn update_sequence(&mut self, sequence: u16) {
+        if let Some(prev) = self.last_sequence {
+            let gap = sequence.wrapping_sub(prev).wrapping_sub(1);
+
+            // Huge gaps are caused by reordering or IMU restart, not loss.
+            if gap < u16::MAX / 2 {
+                self.lost_packets += usize::from(gap);
+            }
+        }
+
+        self.last_sequence = Some(sequence);
+    }
+
+    /// Append packets per second sample to the history.
+    ///
+    /// # Parameters
+    /// - `pps` - given current number of packets per second.
+    fn sample_pps(&mut self, pps: usize) {
+        let now = Instant::now();
+
+        if self
+            .last_pps_sample
+            .is_some_and(|last| now.duration_since(last) < PPS_SAMPLE_INTERVAL)
+        {
+            return;
+        }
+
+        if self.pps_history.len() >= PPS_HISTORY_SIZE {
+            self.pps_history.pop_front();
+        }
+
+        self.pps_history.push_back(pps);
+        self.last_pps_sample = Some(now);
+    }
+}
+
+/// Get counter increment since the last report.
+///
+/// # Parameters
+/// - `current` - given current counter value.
+/// - `last` - given last reported counter value.
+///
+/// # Returns
+/// - Counter increment (whole value if counter is restarted).
+const fn delta(current: usize, last: usize) -> usize {
+    if current >= last {
+        current - last
+    } else {
+        current
+    }
+}
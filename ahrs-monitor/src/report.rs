@@ -0,0 +1,225 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Diagnostic bundle for problem reports.
+//!
+//! Bundle is a single zip file with everything needed to investigate an
+//! issue without access to the user's setup: config file with secrets
+//! redacted, recent application logs, packet counters, a sample of rejected
+//! frames and a screenshot of the interface.
+
+use crate::model::{BadPackets, RejectedFrame};
+use egui::ColorImage;
+use serde::Serialize;
+use std::{
+    collections::VecDeque, fmt::Write as _, fs, io::Write, path::Path,
+    sync::Mutex,
+};
+use toml_edit::{DocumentMut, TableLike, value};
+use zip::{CompressionMethod, ZipWriter, write::SimpleFileOptions};
+
+/// Max number of recent application log lines kept for the bundle.
+const MAX_LOG_LINES: usize = 2000;
+
+/// Substrings of the config keys whose values are redacted.
+const SECRET_KEYS: [&str; 4] = ["key", "secret", "password", "token"];
+
+/// Replacement of the redacted config values.
+const REDACTED: &str = "<redacted>";
+
+/// Recent application log lines.
+static RECENT_LOGS: Mutex<VecDeque<String>> = Mutex::new(VecDeque::new());
+
+/// Keep application log line for the diagnostic bundle.
+///
+/// # Parameters
+/// - `line` - given formatted log line.
+pub fn record_log(line: String) {
+    if let Ok(mut logs) = RECENT_LOGS.lock() {
+        logs.push_back(line);
+
+        if logs.len() > MAX_LOG_LINES {
+            logs.pop_front();
+        }
+    }
+}
+
+/// Get recent application log lines.
+///
+/// # Returns
+/// - Recent log lines in chronological order.
+#[must_use]
+pub fn recent_logs() -> Vec<String> {
+    RECENT_LOGS
+        .lock()
+        .map(|logs| logs.iter().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// Redact secret values of the config file.
+///
+/// # Parameters
+/// - `content` - given config file content.
+///
+/// # Returns
+/// - Config file content with secret values replaced - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - Config file parsing errors.
+pub fn redact_config(content: &str) -> anyhow::Result<String> {
+    let mut document: DocumentMut = content.parse()?;
+    redact_table(document.as_table_mut());
+    Ok(document.to_string())
+}
+
+/// Redact secret values of the config table and its subtables.
+///
+/// # Parameters
+/// - `table` - given config table to handle.
+fn redact_table(table: &mut dyn TableLike) {
+    for (key, item) in table.iter_mut() {
+        let key = key.get().to_lowercase();
+
+        if item.is_value() && SECRET_KEYS.iter().any(|s| key.contains(s)) {
+            *item = value(REDACTED);
+        } else if let Some(table) = item.as_table_like_mut() {
+            redact_table(table);
+        } else if let Some(tables) = item.as_array_of_tables_mut() {
+            for table in tables.iter_mut() {
+                redact_table(table);
+            }
+        }
+    }
+}
+
+/// Packet counters at the moment of the report.
+#[derive(Serialize, Debug, Default, Clone, Copy)]
+pub struct ReportCounters {
+    /// Indicator whether IMU is connected.
+    pub connected: bool,
+    /// Total number of packets.
+    pub total_packets: usize,
+    /// Number of broken packets by failure reason.
+    pub bad_packets: BadPackets,
+    /// Number of packets lost according to sequence gaps.
+    pub lost_packets: usize,
+    /// Number of packets per second.
+    pub pps: usize,
+    /// Number of frames dropped by the events channel.
+    pub dropped_events: usize,
+}
+
+/// Diagnostic bundle contents.
+#[derive(Debug, Default)]
+pub struct DiagnosticReport<'a> {
+    /// Config file content with secrets redacted.
+    pub config: String,
+    /// Packet counters.
+    pub counters: ReportCounters,
+    /// Sample of the recently rejected frames.
+    pub quarantine: Vec<&'a RejectedFrame>,
+    /// Screenshot of the interface (`None` - screenshot is unavailable).
+    pub screenshot: Option<&'a ColorImage>,
+}
+
+impl DiagnosticReport<'_> {
+    /// Write diagnostic bundle to the zip file.
+    ///
+    /// # Parameters
+    /// - `path` - given bundle file path.
+    ///
+    /// # Returns
+    /// - `Ok` - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - Serialization, image encoding or file writing errors.
+    pub fn write(&self, path: &Path) -> anyhow::Result<()> {
+        if let Some(dir) = path.parent()
+            && !dir.as_os_str().is_empty()
+        {
+            fs::create_dir_all(dir)?;
+        }
+
+        let mut zip = ZipWriter::new(fs::File::create(path)?);
+        let options = SimpleFileOptions::default()
+            .compression_method(CompressionMethod::Deflated);
+
+        let mut logs = recent_logs().join("\n");
+        logs.push('\n');
+
+        let mut entries = vec![
+            ("config.toml", self.config.clone().into_bytes()),
+            ("app.log", logs.into_bytes()),
+            ("counters.json", serde_json::to_vec_pretty(&self.counters)?),
+            ("quarantine.txt", self.quarantine_dump().into_bytes()),
+        ];
+
+        if let Some(image) = self.screenshot {
+            entries.push(("screenshot.png", encode_png(image)?));
+        }
+
+        for (name, bytes) in entries {
+            zip.start_file(name, options)?;
+            zip.write_all(&bytes)?;
+        }
+
+        zip.finish()?;
+        Ok(())
+    }
+
+    /// Get hex dump of the rejected frames.
+    ///
+    /// # Returns
+    /// - Rejection reason and hex bytes of each rejected frame.
+    fn quarantine_dump(&self) -> String {
+        let mut dump = String::new();
+
+        for (index, frame) in self.quarantine.iter().enumerate() {
+            let _ = writeln!(
+                dump,
+                "#{index} {:?} ({} bytes)",
+                frame.reason,
+                frame.bytes.len()
+            );
+
+            for chunk in frame.bytes.chunks(16) {
+                for b in chunk {
+                    let _ = write!(dump, "{b:02x} ");
+                }
+                dump.push('\n');
+            }
+            dump.push('\n');
+        }
+
+        dump
+    }
+}
+
+/// Encode image into PNG format.
+///
+/// # Parameters
+/// - `image` - given RGBA image to encode.
+///
+/// # Returns
+/// - PNG file bytes - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - Image encoding errors.
+pub fn encode_png(image: &ColorImage) -> anyhow::Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    let width = u32::try_from(image.width())?;
+    let height = u32::try_from(image.height())?;
+
+    let mut encoder = png::Encoder::new(&mut bytes, width, height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(image.as_raw())?;
+    writer.finish()?;
+
+    Ok(bytes)
+}
@@ -0,0 +1,215 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Alerts panel user interface implementation.
+
+use crate::{
+    config,
+    core::alert::{self, Alert},
+};
+use eframe::epaint::Color32;
+use egui::{Context, Grid, RichText};
+use std::{collections::VecDeque, time::Duration};
+
+/// Blinking period of the active alerts indicator.
+const BLINK_PERIOD: f64 = 1.0;
+
+/// Alert shown in the alerts panel.
+#[derive(Debug, Clone)]
+struct AlertEntry {
+    /// Triggered alert info.
+    alert: Alert,
+    /// Host local time of clearing the alert (`None` - alert is active).
+    cleared: Option<String>,
+    /// Flag whether alert is acknowledged by the user.
+    is_acknowledged: bool,
+}
+
+/// Alerts panel handler.
+#[derive(Debug, Default)]
+pub struct AlertPanel {
+    /// Flag whether the window is open.
+    is_open: bool,
+    /// Flag whether new alerts play the system sound.
+    is_muted: bool,
+    /// Triggered alerts, newest first.
+    entries: VecDeque<AlertEntry>,
+}
+
+impl AlertPanel {
+    /// Add triggered or cleared alert.
+    ///
+    /// # Parameters
+    /// - `alert` - given alert to handle.
+    pub fn add(&mut self, alert: Alert) {
+        if alert.is_cleared {
+            if let Some(entry) = self.entries.iter_mut().find(|entry| {
+                entry.cleared.is_none() && entry.alert.rule == alert.rule
+            }) {
+                entry.cleared = Some(alert.time);
+            }
+            return;
+        }
+
+        if !self.is_muted {
+            alert::play_sound(None);
+        }
+
+        self.entries.push_front(AlertEntry {
+            alert,
+            cleared: None,
+            is_acknowledged: false,
+        });
+        self.entries.truncate(config::ALERT_HISTORY_MAX_SIZE);
+    }
+
+    /// Display active alerts indicator.
+    ///
+    /// Indicator blinks while there are unacknowledged active alerts and
+    /// opens the alerts window when clicked.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    pub fn display_indicator(&mut self, ui: &mut egui::Ui) {
+        let active = self.active_count();
+        let is_pending = self
+            .entries
+            .iter()
+            .any(|entry| entry.cleared.is_none() && !entry.is_acknowledged);
+
+        let time = ui.input(|i| i.time);
+        let is_lit = is_pending && time % BLINK_PERIOD < BLINK_PERIOD / 2.0;
+
+        let (text, fill) = if active == 0 {
+            ("🔔 No alerts".to_string(), Color32::from_gray(60))
+        } else if is_lit {
+            (format!("⚠ {active} active"), Color32::RED)
+        } else {
+            (format!("⚠ {active} active"), Color32::from_rgb(120, 0, 0))
+        };
+
+        if is_pending {
+            ui.ctx().request_repaint_after(Duration::from_secs_f64(
+                BLINK_PERIOD / 2.0,
+            ));
+        }
+
+        let mute_icon = if self.is_muted { "🔇" } else { "🔊" };
+
+        if ui
+            .selectable_label(self.is_muted, mute_icon)
+            .on_hover_text("Mute sound of new alerts")
+            .clicked()
+        {
+            self.is_muted = !self.is_muted;
+        }
+
+        if ui
+            .add(egui::Button::new(RichText::new(text).strong()).fill(fill))
+            .on_hover_text("Show active and past alerts")
+            .clicked()
+        {
+            self.is_open = !self.is_open;
+        }
+    }
+
+    /// Display alerts window.
+    ///
+    /// # Parameters
+    /// - `ctx` - given egui context to handle.
+    pub fn show(&mut self, ctx: &Context) {
+        let mut is_open = self.is_open;
+
+        egui::Window::new("⚠ Alerts")
+            .open(&mut is_open)
+            .collapsible(false)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    if ui
+                        .button("✔ Acknowledge all")
+                        .on_hover_text("Stop blinking of the alerts indicator")
+                        .clicked()
+                    {
+                        for entry in &mut self.entries {
+                            entry.is_acknowledged = true;
+                        }
+                    }
+
+                    if ui
+                        .button("🗑 Clear history")
+                        .on_hover_text("Remove cleared alerts")
+                        .clicked()
+                    {
+                        self.entries.retain(|entry| entry.cleared.is_none());
+                    }
+                });
+
+                ui.separator();
+
+                if self.entries.is_empty() {
+                    ui.label("No alerts triggered");
+                } else {
+                    self.display_entries(ui);
+                }
+            });
+
+        self.is_open = is_open;
+    }
+
+    /// Get number of active alerts.
+    ///
+    /// # Returns
+    /// - Number of alerts whose rules are still breached.
+    fn active_count(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| entry.cleared.is_none())
+            .count()
+    }
+
+    /// Display list of alerts.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    fn display_entries(&self, ui: &mut egui::Ui) {
+        egui::ScrollArea::vertical()
+            .max_height(300.0)
+            .show(ui, |ui| {
+                Grid::new("alert_entries")
+                    .num_columns(6)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for header in [
+                            "",
+                            "Rule",
+                            "Value",
+                            "Threshold",
+                            "Time",
+                            "Cleared",
+                        ] {
+                            ui.label(RichText::new(header).strong());
+                        }
+                        ui.end_row();
+
+                        for entry in &self.entries {
+                            let alert = &entry.alert;
+                            let (icon, color) = if entry.cleared.is_some() {
+                                ("✔", Color32::GRAY)
+                            } else {
+                                ("⚠", Color32::RED)
+                            };
+
+                            ui.label(RichText::new(icon).color(color));
+
+                            ui.label(RichText::new(&alert.rule).color(color))
+                                .on_hover_text(format!("{:?}", alert.metric));
+                            ui.label(format!("{:.3}", alert.value));
+                            ui.label(format!("{:.3}", alert.threshold));
+                            ui.label(&alert.time);
+                            ui.label(entry.cleared.as_deref().unwrap_or("-"));
+                            ui.end_row();
+                        }
+                    });
+            });
+    }
+}
@@ -0,0 +1,389 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Sensor noise analysis tab user interface implementation.
+
+use crate::{
+    config::AppConfig,
+    core::{
+        analysis::{AllanDeviation, CHANNELS},
+        compare::Session,
+    },
+    model::{FrameContext, FrameWrapper},
+    ui::TabViewer,
+};
+use eframe::epaint::Color32;
+use egui::{Grid, RichText};
+use egui_plot::{Corner, GridMark, Legend, Line, Plot, PlotPoints};
+use std::ops::RangeInclusive;
+
+/// Max number of recorded readings per channel (over 1 hour at 200 Hz).
+const MAX_SAMPLES: usize = 1_000_000;
+
+/// Colors of the analyzed axes (x, y, z).
+const AXIS_COLORS: [Color32; 3] = [
+    Color32::LIGHT_RED,
+    Color32::LIGHT_GREEN,
+    Color32::LIGHT_BLUE,
+];
+
+/// Source of the analyzed readings.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Source {
+    /// Readings recorded from the connected device.
+    #[default]
+    Live,
+    /// Readings of the recorded session log file.
+    Log,
+}
+
+/// Sensor noise analysis tab handler.
+#[derive(Debug, Default)]
+pub struct AnalysisTab {
+    /// Source of the analyzed readings.
+    source: Source,
+    /// Indicator whether live readings are recorded.
+    is_recording: bool,
+    /// Recorded live readings by channel of [`CHANNELS`].
+    samples: [Vec<f64>; CHANNELS.len()],
+    /// Sensor-local time of the last recorded frame in microseconds.
+    last_timestamp: Option<u32>,
+    /// Duration of the live recording in microseconds.
+    elapsed_us: u64,
+    /// Path to the session log file to analyze.
+    log_path: String,
+    /// Allan deviation by channel of [`CHANNELS`] (`None` - no readings).
+    results: Vec<Option<AllanDeviation>>,
+    /// Status message of the last operation.
+    status: Option<(String, Color32)>,
+}
+
+impl AnalysisTab {
+    /// Add new frame readings to the live recording.
+    ///
+    /// # Parameters
+    /// - `frame` - given new frame to handle.
+    /// - `timestamp` - given sensor-local time in microseconds.
+    pub fn add_data(&mut self, frame: &FrameWrapper, timestamp: u32) {
+        if !self.is_recording {
+            return;
+        }
+
+        let Some(payload) = &frame.payload else {
+            return;
+        };
+
+        if self
+            .samples
+            .iter()
+            .any(|samples| samples.len() >= MAX_SAMPLES)
+        {
+            self.is_recording = false;
+            self.status =
+                Some(("Recording limit is reached".to_string(), Color32::GRAY));
+            return;
+        }
+
+        if let Some(last) = self.last_timestamp {
+            self.elapsed_us += u64::from(timestamp.wrapping_sub(last));
+        }
+
+        self.last_timestamp = Some(timestamp);
+
+        // Channels are ordered as accelerometer and gyroscope axes.
+        for (samples, readings) in self
+            .samples
+            .chunks_mut(3)
+            .zip([payload.acc(), payload.gyr()])
+        {
+            if let Some(readings) = readings {
+                for (samples, value) in samples.iter_mut().zip(readings.iter())
+                {
+                    samples.push(f64::from(*value));
+                }
+            }
+        }
+    }
+
+    /// Clear live recording.
+    fn clear(&mut self) {
+        self.samples = Default::default();
+        self.last_timestamp = None;
+        self.elapsed_us = 0;
+    }
+
+    /// Compute Allan deviation of the selected readings.
+    #[allow(clippy::cast_precision_loss)]
+    fn compute(&mut self) {
+        self.results = match self.source {
+            Source::Live => {
+                let duration = self.elapsed_us as f64 / 1e6;
+
+                self.samples
+                    .iter()
+                    .map(|samples| {
+                        let intervals = samples.len().checked_sub(1)?;
+                        (intervals > 0).then(|| {
+                            AllanDeviation::compute(
+                                samples,
+                                duration / intervals as f64,
+                            )
+                        })
+                    })
+                    .collect()
+            }
+            Source::Log => match Session::load(self.log_path.trim()) {
+                Ok(session) => CHANNELS
+                    .iter()
+                    .map(|channel| {
+                        AllanDeviation::from_session(&session, channel)
+                    })
+                    .collect(),
+                Err(e) => {
+                    self.status =
+                        Some((format!("Error: {e}"), Color32::LIGHT_RED));
+                    return;
+                }
+            },
+        };
+
+        self.status = if self.results.iter().any(Option::is_some) {
+            None
+        } else {
+            Some((
+                "No accelerometer or gyroscope readings".to_string(),
+                Color32::LIGHT_RED,
+            ))
+        };
+    }
+
+    /// Display readings source selection.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    #[allow(clippy::cast_precision_loss)]
+    fn display_source(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.radio_value(&mut self.source, Source::Live, "Live");
+            ui.radio_value(&mut self.source, Source::Log, "Log file");
+        });
+
+        match self.source {
+            Source::Live => {
+                ui.label("Keep the device still while recording.");
+
+                ui.horizontal(|ui| {
+                    let label = if self.is_recording {
+                        "⏹ Stop"
+                    } else {
+                        "⏺ Record"
+                    };
+
+                    if ui.button(label).clicked() {
+                        self.is_recording = !self.is_recording;
+                        self.last_timestamp = None;
+                    }
+
+                    if ui.button("⟲ Clear").clicked() {
+                        self.clear();
+                    }
+
+                    let count = self.samples.iter().map(Vec::len).max();
+
+                    ui.label(format!(
+                        "Recorded: {} samples ({:.1} sec)",
+                        count.unwrap_or_default(),
+                        self.elapsed_us as f64 / 1e6
+                    ));
+                });
+            }
+            Source::Log => {
+                ui.horizontal(|ui| {
+                    ui.label("Session:");
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.log_path)
+                            .hint_text("path/to/log.csv")
+                            .desired_width(480.0),
+                    );
+                });
+            }
+        }
+
+        ui.horizontal(|ui| {
+            if ui.button("📈 Compute").clicked() {
+                self.compute();
+            }
+
+            if let Some((status, color)) = &self.status {
+                ui.label(RichText::new(status).color(*color));
+            }
+        });
+    }
+
+    /// Display noise parameters of the analyzed channels.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `app_cfg` - given global config to handle.
+    fn display_parameters(&self, ui: &mut egui::Ui, app_cfg: &AppConfig) {
+        Grid::new("analysis_parameters")
+            .striped(true)
+            .num_columns(4)
+            .show(ui, |ui| {
+                for header in [
+                    "Channel",
+                    "Random Walk (τ = 1 s)",
+                    "Bias Instability",
+                    "τ of Bias Instability",
+                ] {
+                    ui.label(RichText::new(header).strong());
+                }
+                ui.end_row();
+
+                for (channel, result) in CHANNELS.iter().zip(&self.results) {
+                    let Some(result) = result else {
+                        continue;
+                    };
+
+                    let unit = app_cfg.channels.info(channel.info.key).unit;
+
+                    ui.label(channel.info.name);
+
+                    match result.random_walk() {
+                        Some(n) => ui.label(format!("{n:.3e} {unit}·√s")),
+                        None => ui.label("-"),
+                    };
+
+                    match result.bias_instability() {
+                        Some(b) => {
+                            ui.label(format!("{:.3e} {unit}", b.deviation));
+                            ui.label(format!("{:.2} s", b.tau));
+                        }
+                        None => {
+                            ui.label("-");
+                            ui.label("-");
+                        }
+                    }
+                    ui.end_row();
+                }
+            });
+    }
+
+    /// Display Allan deviation curves on the log-log scale.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `id` - given plot identifier.
+    /// - `title` - given plot title.
+    /// - `offset` - given index of the first plotted channel.
+    fn display_plot(
+        &self,
+        ui: &mut egui::Ui,
+        id: &str,
+        title: &str,
+        offset: usize,
+    ) {
+        ui.label(RichText::new(title).strong());
+
+        Plot::new(id)
+            .height(220.0)
+            .show_grid(true)
+            .legend(Legend::default().position(Corner::RightTop))
+            .allow_double_click_reset(true)
+            .x_axis_label("τ, (sec)")
+            .y_axis_label("Allan deviation")
+            .x_axis_formatter(log_axis_label)
+            .y_axis_formatter(log_axis_label)
+            .show(ui, |plot_ui| {
+                let curves = CHANNELS
+                    .iter()
+                    .zip(&self.results)
+                    .skip(offset)
+                    .take(AXIS_COLORS.len())
+                    .zip(AXIS_COLORS);
+
+                for ((channel, result), color) in curves {
+                    let Some(result) = result else {
+                        continue;
+                    };
+
+                    let points: PlotPoints = result
+                        .points
+                        .iter()
+                        .map(|p| [p.tau.log10(), p.deviation.log10()])
+                        .collect();
+
+                    plot_ui.line(
+                        Line::new(channel.info.name, points)
+                            .color(color)
+                            .width(0.8),
+                    );
+                }
+            });
+    }
+
+    /// Display tab regardless of the payload type.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `app_cfg` - given global config to handle.
+    pub fn show(&mut self, ui: &mut egui::Ui, app_cfg: &AppConfig) {
+        ui.label(RichText::new("Allan Deviation Analysis").strong());
+        ui.add_space(10.0);
+
+        self.display_source(ui);
+
+        if self.results.is_empty() {
+            return;
+        }
+
+        ui.separator();
+        self.display_parameters(ui, app_cfg);
+        ui.add_space(10.0);
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            self.display_plot(ui, "allan_acc_p", "Accelerometer", 0);
+            self.display_plot(ui, "allan_gyr_p", "Gyroscope", 3);
+        });
+    }
+}
+
+impl TabViewer for AnalysisTab {
+    /// Get tab title.
+    ///
+    /// # Returns
+    /// - Tab title string slice.
+    fn title(&self) -> &'static str {
+        "Analysis"
+    }
+
+    /// Get tab icon.
+    ///
+    /// # Returns
+    /// - Tab icon string slice.
+    fn icon(&self) -> &'static str {
+        "📉"
+    }
+
+    /// Display tab.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `frame_ctx` - given current frame context to handle.
+    /// - `app_cfg` - given global config to handle.
+    fn ui(&mut self, ui: &mut egui::Ui, _: &FrameContext, app_cfg: &AppConfig) {
+        self.show(ui, app_cfg);
+    }
+}
+
+/// Format axis mark of the logarithmic scale.
+///
+/// # Parameters
+/// - `mark` - given axis mark with decimal logarithm of the value.
+/// - `range` - given axis range (unused).
+///
+/// # Returns
+/// - Axis mark label.
+fn log_axis_label(mark: GridMark, _: &RangeInclusive<f64>) -> String {
+    format!("{:.1e}", 10.0_f64.powf(mark.value))
+}
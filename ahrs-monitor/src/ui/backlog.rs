@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Backlog of the frames for the tabs that are not visible.
+//!
+//! Frames are buffered as shared frame contexts once for all tabs, each tab
+//! keeps only the sequence number of the next frame it has not seen yet. Tab
+//! is fed with the missed frames when it becomes active, so that plots of the
+//! hidden tabs cost nothing at high frame rates.
+
+use crate::model::FrameContext;
+use std::{
+    collections::{HashMap, VecDeque, vec_deque},
+    sync::Arc,
+};
+
+/// Backlog of the frames to feed into the tabs.
+#[derive(Debug, Default)]
+pub struct FrameBacklog {
+    /// Recent frames, oldest first.
+    frames: VecDeque<Arc<FrameContext>>,
+    /// Sequence number of the next appended frame.
+    next_seq: u64,
+    /// Sequence number of the next frame to feed by tab index.
+    fed: HashMap<usize, u64>,
+}
+
+impl FrameBacklog {
+    /// Append frame to the backlog.
+    ///
+    /// # Parameters
+    /// - `frame_ctx` - given frame context to append.
+    /// - `max_frames` - given max number of frames in the backlog.
+    pub fn push(&mut self, frame_ctx: Arc<FrameContext>, max_frames: usize) {
+        self.frames.push_back(frame_ctx);
+        self.next_seq += 1;
+
+        let excess = self.frames.len().saturating_sub(max_frames);
+        self.frames.drain(..excess);
+    }
+
+    /// Take frames not fed into the tab yet.
+    ///
+    /// Frames dropped from the backlog before the tab became active are
+    /// skipped, they would be out of the plot history anyway.
+    ///
+    /// # Parameters
+    /// - `tab` - given tab index.
+    ///
+    /// # Returns
+    /// - Frames to feed into the tab, oldest first.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn take(
+        &mut self,
+        tab: usize,
+    ) -> vec_deque::Iter<'_, Arc<FrameContext>> {
+        let first_seq = self.next_seq - self.frames.len() as u64;
+        let next = self.fed.insert(tab, self.next_seq).unwrap_or_default();
+        let skip = next.saturating_sub(first_seq) as usize;
+
+        self.frames.range(skip.min(self.frames.len())..)
+    }
+
+    /// Drop buffered frames.
+    pub fn clear(&mut self) {
+        self.frames.clear();
+        self.fed.clear();
+    }
+}
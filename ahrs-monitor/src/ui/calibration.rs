@@ -0,0 +1,374 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Sensors calibration tab user interface implementation.
+
+use crate::{
+    config::AppConfig,
+    core::{
+        StandardPayload,
+        calibration::{
+            AccCalibration, CalibrationPose, SixPositionCalibration,
+        },
+        mag_calibration::MagCalibration,
+        motion::MotionState,
+        mounting::Mounting,
+    },
+    model::{FrameContext, FrameWrapper},
+    ui::{TabViewer, mag_calibration::MagCalibrationPanel},
+};
+use eframe::epaint::Color32;
+use egui::{Button, Grid, ProgressBar, RichText};
+
+/// Calibrated sensor enumeration.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum CalibrationSensor {
+    /// Accelerometer six-position calibration.
+    #[default]
+    Accelerometer,
+    /// Magnetometer ellipsoid calibration.
+    Magnetometer,
+}
+
+/// Calibration applied by the user.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AppliedCalibration {
+    /// Accelerometer calibration of the raw readings.
+    Acc(AccCalibration),
+    /// Magnetometer calibration of the raw readings.
+    Mag(MagCalibration),
+}
+
+/// Sensors calibration tab handler.
+#[derive(Debug, Default)]
+pub struct CalibrationTab {
+    /// Selected sensor to calibrate.
+    sensor: CalibrationSensor,
+    /// Magnetometer calibration panel.
+    mag: MagCalibrationPanel,
+    /// Calibration measurements.
+    calibration: SixPositionCalibration,
+    /// Index of the selected pose in [`CalibrationPose::ALL`].
+    pose_idx: usize,
+    /// Latest accelerometer readings (x, y, z).
+    acc: Option<[f32; 3]>,
+    /// Latest classified motion state (`None` - not classified).
+    motion: Option<MotionState>,
+    /// Calibration computed from all poses.
+    result: Option<AccCalibration>,
+    /// Status message of the last operation.
+    status: Option<(String, Color32)>,
+}
+
+impl CalibrationTab {
+    /// Add new frame data to the pose being measured and to the collected
+    /// magnetometer samples.
+    ///
+    /// # Parameters
+    /// - `frame` - given new frame to handle.
+    /// - `mounting` - given sensor mounting orientation of the readings.
+    /// - `motion` - given classified motion state of the device.
+    pub fn add_data(
+        &mut self,
+        frame: &FrameWrapper,
+        mounting: &Mounting,
+        motion: Option<MotionState>,
+    ) {
+        self.motion = motion;
+
+        // Calibrations are defined for sensor axes.
+        if let Some(mag) = frame.payload.as_ref().and_then(StandardPayload::mag)
+        {
+            self.mag.add_data(mounting.body_to_sensor(mag));
+        }
+
+        let Some(acc) = frame.payload.as_ref().and_then(StandardPayload::acc)
+        else {
+            return;
+        };
+
+        let acc = mounting.body_to_sensor(acc);
+
+        self.acc = Some(acc.into());
+
+        let was_measuring = self.calibration.progress().is_some();
+
+        if let Err(e) = self.calibration.add(acc) {
+            self.status = Some((format!("Error: {e}"), Color32::LIGHT_RED));
+            return;
+        }
+
+        if was_measuring && self.calibration.progress().is_none() {
+            self.on_pose_measured();
+        }
+    }
+
+    /// Drop accelerometer measurements.
+    fn restart(&mut self) {
+        self.calibration = SixPositionCalibration::default();
+        self.pose_idx = 0;
+        self.result = None;
+        self.status = None;
+    }
+
+    /// Select next pose to measure or compute calibration.
+    fn on_pose_measured(&mut self) {
+        let next = CalibrationPose::ALL
+            .iter()
+            .position(|pose| !self.calibration.is_measured(*pose));
+
+        if let Some(idx) = next {
+            self.pose_idx = idx;
+            self.status = None;
+            return;
+        }
+
+        self.status = Some(match self.calibration.compute() {
+            Ok(result) => {
+                self.result = Some(result);
+                (
+                    "Calibration is computed, review and apply it".to_string(),
+                    Color32::LIGHT_GREEN,
+                )
+            }
+            Err(e) => (format!("Error: {e}"), Color32::LIGHT_RED),
+        });
+    }
+
+    /// Display poses list.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    fn display_poses(&mut self, ui: &mut egui::Ui) {
+        let is_measuring = self.calibration.progress().is_some();
+
+        Grid::new("calibration_poses")
+            .striped(true)
+            .num_columns(2)
+            .show(ui, |ui| {
+                for (i, pose) in CalibrationPose::ALL.iter().enumerate() {
+                    ui.add_enabled_ui(!is_measuring, |ui| {
+                        ui.radio_value(&mut self.pose_idx, i, pose.name());
+                    });
+
+                    if self.calibration.is_measured(*pose) {
+                        ui.label(
+                            RichText::new("✔ Measured")
+                                .color(Color32::LIGHT_GREEN),
+                        );
+                    } else {
+                        ui.label(RichText::new("Pending").color(Color32::GRAY));
+                    }
+                    ui.end_row();
+                }
+            });
+    }
+
+    /// Display measurement controls of the selected pose.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    fn display_measurement(&mut self, ui: &mut egui::Ui) {
+        let Some(pose) = CalibrationPose::ALL.get(self.pose_idx).copied()
+        else {
+            return;
+        };
+
+        ui.label(format!(
+            "Place the device still with {} and press Measure.",
+            pose.name()
+        ));
+
+        if let Some([x, y, z]) = self.acc {
+            ui.label(format!("Accelerometer: x={x:.3} y={y:.3} z={z:.3}"));
+        }
+
+        // Measurement is started only when the device is known to be still.
+        let is_static = self.motion.is_none_or(|m| m == MotionState::Static);
+
+        if let Some(motion) = self.motion {
+            let (text, color) = if is_static {
+                (motion.name().to_string(), Color32::LIGHT_GREEN)
+            } else {
+                (
+                    format!("{}, hold the device still", motion.name()),
+                    Color32::ORANGE,
+                )
+            };
+
+            ui.label(RichText::new(format!("Motion: {text}")).color(color));
+        }
+
+        ui.horizontal(|ui| {
+            if let Some((pose, progress)) = self.calibration.progress() {
+                ui.add(
+                    ProgressBar::new(progress)
+                        .desired_width(240.0)
+                        .text(format!("Measuring {}", pose.name())),
+                );
+
+                if ui.button("✖ Cancel").clicked() {
+                    self.calibration.cancel();
+                }
+            } else {
+                if ui
+                    .add_enabled(is_static, Button::new("📐 Measure"))
+                    .clicked()
+                {
+                    self.calibration.start(pose);
+                    self.status = None;
+                }
+
+                if ui.button("⟲ Restart").clicked() {
+                    self.restart();
+                }
+            }
+        });
+    }
+
+    /// Display calibration values.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `id` - given grid identifier.
+    /// - `calibration` - given calibration to display.
+    fn display_values(
+        ui: &mut egui::Ui,
+        id: &str,
+        calibration: &AccCalibration,
+    ) {
+        Grid::new(id).num_columns(4).show(ui, |ui| {
+            for header in ["", "X", "Y", "Z"] {
+                ui.label(RichText::new(header).strong());
+            }
+            ui.end_row();
+
+            for (name, values) in
+                [("Offset", calibration.offset), ("Scale", calibration.scale)]
+            {
+                ui.label(name);
+
+                for v in values {
+                    ui.label(format!("{v:.4}"));
+                }
+                ui.end_row();
+            }
+        });
+    }
+
+    /// Display accelerometer calibration.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `current` - given accelerometer calibration currently applied.
+    ///
+    /// # Returns
+    /// - Calibration of the raw readings to apply - if user applied it.
+    /// - `None` - otherwise.
+    fn display_acc(
+        &mut self,
+        ui: &mut egui::Ui,
+        current: &AccCalibration,
+    ) -> Option<AccCalibration> {
+        let mut applied = None;
+
+        ui.label(
+            RichText::new("Accelerometer Six-Position Calibration").strong(),
+        );
+        ui.add_space(10.0);
+
+        self.display_poses(ui);
+        ui.add_space(10.0);
+        self.display_measurement(ui);
+
+        if let Some((status, color)) = &self.status {
+            ui.label(RichText::new(status).color(*color));
+        }
+
+        ui.separator();
+        ui.label(RichText::new("Current Calibration").strong());
+        Self::display_values(ui, "calibration_current", current);
+
+        // Measured readings are already calibrated by the current calibration.
+        if let Some(result) = self.result.map(|r| current.then(&r)) {
+            ui.add_space(10.0);
+            ui.label(RichText::new("New Calibration").strong());
+            Self::display_values(ui, "calibration_new", &result);
+
+            if ui.button("💾 Apply & Save").clicked() {
+                applied = Some(result);
+                self.restart();
+            }
+        }
+
+        applied
+    }
+
+    /// Display tab regardless of the payload type.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `app_cfg` - given global config to handle.
+    ///
+    /// # Returns
+    /// - Calibration of the raw readings to apply - if user applied it.
+    /// - `None` - otherwise.
+    pub fn show(
+        &mut self,
+        ui: &mut egui::Ui,
+        app_cfg: &AppConfig,
+    ) -> Option<AppliedCalibration> {
+        ui.horizontal(|ui| {
+            ui.label("Sensor:");
+            ui.radio_value(
+                &mut self.sensor,
+                CalibrationSensor::Accelerometer,
+                "Accelerometer",
+            );
+            ui.radio_value(
+                &mut self.sensor,
+                CalibrationSensor::Magnetometer,
+                "Magnetometer",
+            );
+        });
+        ui.separator();
+
+        match self.sensor {
+            CalibrationSensor::Accelerometer => self
+                .display_acc(ui, &app_cfg.imu.acc_calibration)
+                .map(AppliedCalibration::Acc),
+            CalibrationSensor::Magnetometer => self
+                .mag
+                .show(ui, &app_cfg.imu.mag_calibration)
+                .map(AppliedCalibration::Mag),
+        }
+    }
+}
+
+impl TabViewer for CalibrationTab {
+    /// Get tab title.
+    ///
+    /// # Returns
+    /// - Tab title string slice.
+    fn title(&self) -> &'static str {
+        "Calibration"
+    }
+
+    /// Get tab icon.
+    ///
+    /// # Returns
+    /// - Tab icon string slice.
+    fn icon(&self) -> &'static str {
+        "📐"
+    }
+
+    /// Display tab.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `frame_ctx` - given current frame context to handle.
+    /// - `app_cfg` - given global config to handle.
+    fn ui(&mut self, ui: &mut egui::Ui, _: &FrameContext, app_cfg: &AppConfig) {
+        let _ = self.show(ui, app_cfg);
+    }
+}
@@ -0,0 +1,148 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Copying attitude and channel values to the clipboard.
+//!
+//! Values are copied with full precision (not rounded as displayed) as CSV
+//! with a header line or as JSON object, so that they can be pasted directly
+//! into notebooks and spreadsheets. Angles are in radians, as in the logs.
+
+use crate::{
+    core::attitude::representation, logger::LogRecord, model::FrameContext,
+};
+use serde::Serialize;
+use tsilna_nav::math::Quat32;
+
+/// Quaternion components.
+#[derive(Serialize)]
+struct Quaternion {
+    /// Scalar part.
+    w: f32,
+    /// X component of the vector part.
+    x: f32,
+    /// Y component of the vector part.
+    y: f32,
+    /// Z component of the vector part.
+    z: f32,
+}
+
+/// Euler angles in radians.
+#[derive(Serialize)]
+struct EulerAngles {
+    /// Rotation around X axis.
+    roll: f32,
+    /// Rotation around Y axis.
+    pitch: f32,
+    /// Rotation around Z axis.
+    yaw: f32,
+}
+
+/// Clipboard text format.
+#[derive(Debug, Clone, Copy)]
+enum Format {
+    /// Header line followed by values line.
+    Csv,
+    /// JSON object.
+    Json,
+}
+
+impl Format {
+    /// All formats in the order of menu entries.
+    const ALL: [Self; 2] = [Self::Csv, Self::Json];
+
+    /// Get format name.
+    ///
+    /// # Returns
+    /// - Format name in string representation.
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Csv => "CSV",
+            Self::Json => "JSON",
+        }
+    }
+
+    /// Serialize value.
+    ///
+    /// # Parameters
+    /// - `value` - given value to serialize.
+    ///
+    /// # Returns
+    /// - Value text - in case of success.
+    /// - `None` - otherwise.
+    fn serialize<T: Serialize>(self, value: &T) -> Option<String> {
+        match self {
+            Self::Csv => {
+                let mut writer = csv::Writer::from_writer(Vec::new());
+                writer.serialize(value).ok()?;
+                let bytes = writer.into_inner().ok()?;
+
+                String::from_utf8(bytes).ok()
+            }
+            Self::Json => serde_json::to_string_pretty(value).ok(),
+        }
+    }
+}
+
+/// Display copy actions of the attitude metrics context menu.
+///
+/// # Parameters
+/// - `ui` - given screen UI handler.
+/// - `quaternion` - given displayed attitude.
+/// - `frame_ctx` - given current frame context to handle.
+pub fn attitude_menu(
+    ui: &mut egui::Ui,
+    quaternion: &Quat32,
+    frame_ctx: &FrameContext,
+) {
+    let (roll, pitch, yaw) = representation::euler_angles(quaternion);
+    let quaternion = Quaternion {
+        w: quaternion.w,
+        x: quaternion.i,
+        y: quaternion.j,
+        z: quaternion.k,
+    };
+    let euler = EulerAngles { roll, pitch, yaw };
+    let snapshot = LogRecord::from_frame_context(frame_ctx);
+
+    for format in Format::ALL {
+        let name = format.name();
+
+        if ui.button(format!("Copy quaternion as {name}")).clicked() {
+            copy(ui, format.serialize(&quaternion));
+        }
+
+        if ui.button(format!("Copy Euler angles as {name}")).clicked() {
+            copy(ui, format.serialize(&euler));
+        }
+    }
+
+    ui.separator();
+
+    ui.add_enabled_ui(snapshot.is_some(), |ui| {
+        for format in Format::ALL {
+            let label = format!("Copy all channels as {}", format.name());
+
+            if ui
+                .button(label)
+                .on_hover_text("Timestamped snapshot of the current frame")
+                .clicked()
+            {
+                copy(ui, snapshot.as_ref().and_then(|s| format.serialize(s)));
+            }
+        }
+    });
+}
+
+/// Copy text to the clipboard and close the menu.
+///
+/// # Parameters
+/// - `ui` - given screen UI handler.
+/// - `text` - given text to copy.
+fn copy(ui: &egui::Ui, text: Option<String>) {
+    match text {
+        Some(text) => ui.ctx().copy_text(text),
+        None => log::warn!("Failed to serialize values for the clipboard"),
+    }
+
+    ui.close();
+}
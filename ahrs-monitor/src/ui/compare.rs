@@ -0,0 +1,317 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Session comparison tab user interface implementation.
+
+use crate::{
+    config::AppConfig,
+    core::compare::{
+        CHANNELS, Session, SessionComparison, alignment_tolerance_us,
+    },
+    model::FrameContext,
+    ui::TabViewer,
+};
+use chrono::Local;
+use eframe::epaint::Color32;
+use egui::{ComboBox, Grid, RichText};
+use egui_plot::{Corner, Legend, Line, Plot, PlotPoint, PlotPoints};
+use std::{fs, path::PathBuf};
+
+/// Colors of the compared sessions.
+const SESSION_COLORS: [Color32; 2] = [Color32::LIGHT_BLUE, Color32::LIGHT_RED];
+
+/// Session comparison tab handler.
+#[derive(Debug, Default)]
+pub struct CompareTab {
+    /// Path to the first (reference) session log file.
+    a_path: String,
+    /// Path to the second session log file.
+    b_path: String,
+    /// Loaded sessions.
+    sessions: Option<[Session; 2]>,
+    /// Sessions comparison report.
+    report: Option<SessionComparison>,
+    /// Index of the channel to plot.
+    channel_idx: usize,
+    /// Plot points of the selected channel for each session.
+    points: [Vec<PlotPoint>; 2],
+    /// Status message of the last operation.
+    status: Option<(String, Color32)>,
+}
+
+impl CompareTab {
+    /// Load sessions and compare them.
+    ///
+    /// # Parameters
+    /// - `app_cfg` - given global config to handle.
+    fn compare(&mut self, app_cfg: &AppConfig) {
+        let sessions = Session::load(self.a_path.trim())
+            .and_then(|a| Ok([a, Session::load(self.b_path.trim())?]));
+
+        match sessions {
+            Ok(sessions) => {
+                let [a, b] = &sessions;
+                let report = SessionComparison::new(
+                    a,
+                    b,
+                    alignment_tolerance_us(app_cfg.imu.sample_rate),
+                );
+
+                self.status = Some((
+                    format!("Aligned samples: {}", report.aligned),
+                    Color32::GRAY,
+                ));
+                self.report = Some(report);
+                self.sessions = Some(sessions);
+                self.update_points();
+            }
+            Err(e) => {
+                self.status = Some((format!("Error: {e}"), Color32::LIGHT_RED));
+            }
+        }
+    }
+
+    /// Update plot points of the selected channel.
+    fn update_points(&mut self) {
+        let Some(sessions) = &self.sessions else {
+            return;
+        };
+
+        if let Some(channel) = CHANNELS.get(self.channel_idx) {
+            for (points, session) in self.points.iter_mut().zip(sessions) {
+                *points = session
+                    .channel_points(channel)
+                    .into_iter()
+                    .map(PlotPoint::from)
+                    .collect();
+            }
+        }
+    }
+
+    /// Export comparison report into the log directory.
+    ///
+    /// # Parameters
+    /// - `app_cfg` - given global config to handle.
+    fn export_report(&mut self, app_cfg: &AppConfig) {
+        let Some(report) = &self.report else {
+            return;
+        };
+
+        let filename =
+            format!("compare_{}.md", Local::now().format("%d-%m-%Y_%H-%M-%S"));
+        let mut path = PathBuf::from(&app_cfg.log.directory);
+        path.push(filename);
+
+        let result = fs::create_dir_all(&app_cfg.log.directory)
+            .and_then(|()| fs::write(&path, report.to_markdown()));
+
+        self.status = Some(match result {
+            Ok(()) => (
+                format!("Report saved: {}", path.display()),
+                Color32::LIGHT_GREEN,
+            ),
+            Err(e) => (format!("Error: {e}"), Color32::LIGHT_RED),
+        });
+    }
+
+    /// Display sessions selection panel.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `app_cfg` - given global config to handle.
+    fn display_selection(&mut self, ui: &mut egui::Ui, app_cfg: &AppConfig) {
+        Grid::new("compare_paths").num_columns(2).show(ui, |ui| {
+            ui.label("Session A:");
+            ui.add(
+                egui::TextEdit::singleline(&mut self.a_path)
+                    .hint_text("path/to/log.csv")
+                    .desired_width(480.0),
+            );
+            ui.end_row();
+
+            ui.label("Session B:");
+            ui.add(
+                egui::TextEdit::singleline(&mut self.b_path)
+                    .hint_text("path/to/log.csv")
+                    .desired_width(480.0),
+            );
+            ui.end_row();
+        });
+
+        ui.horizontal(|ui| {
+            if ui.button("⚖ Compare").clicked() {
+                self.compare(app_cfg);
+            }
+
+            if ui
+                .add_enabled(
+                    self.report.is_some(),
+                    egui::Button::new("💾 Export Report"),
+                )
+                .clicked()
+            {
+                self.export_report(app_cfg);
+            }
+
+            if let Some((status, color)) = &self.status {
+                ui.label(RichText::new(status).color(*color));
+            }
+        });
+    }
+
+    /// Display overlaid plot of the selected channel.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    fn display_plot(&mut self, ui: &mut egui::Ui) {
+        let selected =
+            CHANNELS.get(self.channel_idx).map_or("", |c| c.info.name);
+        let mut channel_idx = self.channel_idx;
+
+        ComboBox::from_label("Channel")
+            .selected_text(selected)
+            .show_ui(ui, |ui| {
+                for (i, channel) in CHANNELS.iter().enumerate() {
+                    ui.selectable_value(&mut channel_idx, i, channel.info.name);
+                }
+            });
+
+        if channel_idx != self.channel_idx {
+            self.channel_idx = channel_idx;
+            self.update_points();
+        }
+
+        let names = self
+            .report
+            .as_ref()
+            .map(|r| [r.a_name.as_str(), r.b_name.as_str()])
+            .unwrap_or_default();
+
+        Plot::new("compare_p")
+            .height(256.0)
+            .show_grid(true)
+            .legend(Legend::default().position(Corner::RightTop))
+            .allow_double_click_reset(true)
+            .x_axis_label("Time since session start, (sec)")
+            .show(ui, |plot_ui| {
+                for ((points, name), color) in
+                    self.points.iter().zip(names).zip(SESSION_COLORS)
+                {
+                    plot_ui.line(
+                        Line::new(name, PlotPoints::Borrowed(points))
+                            .color(color)
+                            .width(0.8),
+                    );
+                }
+            });
+
+        ui.add_space(10.0);
+    }
+
+    /// Display comparison statistics.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `report` - given sessions comparison report.
+    fn display_stats(ui: &mut egui::Ui, report: &SessionComparison) {
+        let fmt = |v: Option<f64>| {
+            v.map_or_else(|| "-".to_string(), |v| format!("{v:.4}"))
+        };
+
+        ui.label(RichText::new("Delta Statistics (B - A)").strong());
+
+        Grid::new("compare_stats")
+            .striped(true)
+            .num_columns(8)
+            .show(ui, |ui| {
+                for header in [
+                    "Channel", "Mean A", "Mean B", "Δ Mean", "Std A", "Std B",
+                    "Δ Std", "RMS diff",
+                ] {
+                    ui.label(RichText::new(header).strong());
+                }
+                ui.end_row();
+
+                for c in report
+                    .channels
+                    .iter()
+                    .filter(|c| c.a.is_some() || c.b.is_some())
+                {
+                    ui.label(format!(
+                        "{} ({})",
+                        c.channel.info.name, c.channel.info.unit
+                    ));
+                    ui.label(fmt(c.a.map(|s| s.mean)));
+                    ui.label(fmt(c.b.map(|s| s.mean)));
+                    ui.label(fmt(c.mean_delta()));
+                    ui.label(fmt(c.a.map(|s| s.std_dev)));
+                    ui.label(fmt(c.b.map(|s| s.std_dev)));
+                    ui.label(fmt(c.std_dev_delta()));
+                    ui.label(fmt(c.rms_diff));
+                    ui.end_row();
+                }
+            });
+
+        ui.add_space(10.0);
+        ui.label(RichText::new("Attitude Error").strong());
+
+        if let Some(e) = report.attitude {
+            ui.label(format!(
+                "Mean: {:.3}°   RMS: {:.3}°   Max: {:.3}°",
+                e.mean, e.rms, e.max
+            ));
+        } else {
+            ui.label("Sessions are not aligned in time.");
+        }
+    }
+
+    /// Display tab regardless of the IMU connection.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `app_cfg` - given global config to handle.
+    pub fn show(&mut self, ui: &mut egui::Ui, app_cfg: &AppConfig) {
+        self.display_selection(ui, app_cfg);
+        ui.separator();
+
+        if self.report.is_none() {
+            return;
+        }
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            self.display_plot(ui);
+
+            if let Some(report) = &self.report {
+                Self::display_stats(ui, report);
+            }
+        });
+    }
+}
+
+impl TabViewer for CompareTab {
+    /// Get tab title.
+    ///
+    /// # Returns
+    /// - Tab title string slice.
+    fn title(&self) -> &'static str {
+        "Compare"
+    }
+
+    /// Get tab icon.
+    ///
+    /// # Returns
+    /// - Tab icon string slice.
+    fn icon(&self) -> &'static str {
+        "⚖"
+    }
+
+    /// Display tab.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `frame_ctx` - given current frame context to handle.
+    /// - `app_cfg` - given global config to handle.
+    fn ui(&mut self, ui: &mut egui::Ui, _: &FrameContext, app_cfg: &AppConfig) {
+        self.show(ui, app_cfg);
+    }
+}
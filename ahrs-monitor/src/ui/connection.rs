@@ -0,0 +1,287 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Connection settings window user interface implementation.
+
+use crate::config::{NetConfig, Transport};
+use eframe::epaint::Color32;
+use egui::{Context, Grid, RichText};
+
+/// Connection change requested by the user.
+#[derive(Debug, Clone)]
+pub enum ConnectionAction {
+    /// Close current frame source and open it with new settings and
+    /// accepted device identifier (`None` accepts all devices).
+    Connect(Box<NetConfig>, Option<u8>),
+    /// Close current frame source.
+    Disconnect,
+}
+
+/// Connection settings window handler.
+#[derive(Debug, Default)]
+pub struct ConnectionPanel {
+    /// Flag whether the window is open.
+    is_open: bool,
+    /// Edited ingester's IP address.
+    ip_address: String,
+    /// Edited ingester's UDP port.
+    udp_port: String,
+    /// Edited ingester's Unix domain socket path.
+    unix_socket_path: String,
+    /// Edited accepted device identifier (empty accepts all devices).
+    device_id: String,
+    /// Edited flag whether frames are expected to be encrypted.
+    use_encryption: bool,
+    /// Status message of the last operation.
+    status: Option<(String, Color32)>,
+}
+
+impl ConnectionPanel {
+    /// Open window with current networks configurations.
+    ///
+    /// # Parameters
+    /// - `net` - given current networks configurations.
+    /// - `device_id` - given current accepted device identifier.
+    pub fn open(&mut self, net: &NetConfig, device_id: Option<u8>) {
+        self.is_open = true;
+        self.ip_address.clone_from(&net.ip_address);
+        self.udp_port = net.udp_port.to_string();
+        self.unix_socket_path.clone_from(&net.unix_socket_path);
+        self.device_id = device_id
+            .map(|device_id| format!("{device_id:#04X}"))
+            .unwrap_or_default();
+        self.use_encryption = net.use_encryption;
+        self.status = None;
+    }
+
+    /// Display connection settings window.
+    ///
+    /// # Parameters
+    /// - `ctx` - given egui context to handle.
+    /// - `net` - given current networks configurations.
+    ///
+    /// # Returns
+    /// - Connection change - if user requested it.
+    /// - `None` - otherwise.
+    pub fn show(
+        &mut self,
+        ctx: &Context,
+        net: &NetConfig,
+    ) -> Option<ConnectionAction> {
+        let mut is_open = self.is_open;
+        let mut applied = None;
+
+        egui::Window::new("🔌 Connection Settings")
+            .open(&mut is_open)
+            .collapsible(false)
+            .resizable(false)
+            .show(ctx, |ui| {
+                self.display_fields(ui, net.transport);
+
+                ui.horizontal(|ui| {
+                    if ui
+                        .button("✔ Connect")
+                        .on_hover_text("Archive current session and connect")
+                        .clicked()
+                    {
+                        applied = self.apply(net).map(|(net, device_id)| {
+                            ConnectionAction::Connect(Box::new(net), device_id)
+                        });
+                    }
+
+                    if ui
+                        .button("⏏ Disconnect")
+                        .on_hover_text("Archive current session and disconnect")
+                        .clicked()
+                    {
+                        applied = self.disconnect(net);
+                    }
+
+                    if let Some((status, color)) = &self.status {
+                        ui.label(RichText::new(status).color(*color));
+                    }
+                });
+            });
+
+        self.is_open = is_open;
+        applied
+    }
+
+    /// Display editable settings of the configured transport.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `transport` - given configured transport.
+    fn display_fields(&mut self, ui: &mut egui::Ui, transport: Transport) {
+        Grid::new("connection_settings")
+            .num_columns(2)
+            .show(ui, |ui| {
+                ui.label("Transport:");
+                ui.label(format!("{transport:?}"));
+                ui.end_row();
+
+                match transport {
+                    Transport::Udp => {
+                        ui.label("IP address:");
+                        ui.add(
+                            egui::TextEdit::singleline(&mut self.ip_address)
+                                .hint_text("127.0.0.1")
+                                .desired_width(160.0),
+                        );
+                        ui.end_row();
+
+                        ui.label("UDP port:");
+                        ui.add(
+                            egui::TextEdit::singleline(&mut self.udp_port)
+                                .hint_text("5000")
+                                .desired_width(160.0),
+                        );
+                        ui.end_row();
+                    }
+                    Transport::Unix => {
+                        ui.label("Socket path:");
+                        ui.add(
+                            egui::TextEdit::singleline(
+                                &mut self.unix_socket_path,
+                            )
+                            .hint_text("/tmp/ahrs-monitor.sock")
+                            .desired_width(240.0),
+                        );
+                        ui.end_row();
+                    }
+                    Transport::Stdin
+                    | Transport::Can
+                    | Transport::Generator => {}
+                }
+
+                ui.label("Device ID:");
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.device_id)
+                        .hint_text("any")
+                        .desired_width(160.0),
+                )
+                .on_hover_text(
+                    "Hexadecimal (0x..) or decimal identifier, \
+                     empty to accept all devices",
+                );
+                ui.end_row();
+
+                ui.label("Encryption:");
+                ui.checkbox(&mut self.use_encryption, "")
+                    .on_hover_text("Reject frames not matching this setting");
+                ui.end_row();
+            });
+    }
+
+    /// Build new networks configurations from the edited settings.
+    ///
+    /// # Parameters
+    /// - `net` - given current networks configurations.
+    ///
+    /// # Returns
+    /// - New networks configurations and accepted device identifier - if
+    ///   edited settings are valid.
+    /// - `None` - otherwise.
+    fn apply(&mut self, net: &NetConfig) -> Option<(NetConfig, Option<u8>)> {
+        let mut new_net = net.clone();
+
+        let Some(device_id) = parse_device_id(&self.device_id) else {
+            self.set_error("Device ID must be in range [0x00, 0xFF]");
+            return None;
+        };
+
+        match net.transport {
+            Transport::Udp => {
+                let ip_address = self.ip_address.trim();
+                let udp_port = self.udp_port.trim().parse::<u16>();
+
+                let Ok(udp_port @ 1..) = udp_port else {
+                    self.set_error("UDP port must be in range [1, 65535]");
+                    return None;
+                };
+
+                if ip_address.is_empty() {
+                    self.set_error("IP address must not be empty");
+                    return None;
+                }
+
+                new_net.ip_address = ip_address.to_string();
+                new_net.udp_port = udp_port;
+            }
+            Transport::Unix => {
+                let path = self.unix_socket_path.trim();
+
+                if path.is_empty() {
+                    self.set_error("Socket path must not be empty");
+                    return None;
+                }
+
+                new_net.unix_socket_path = path.to_string();
+            }
+            // Standard input is still owned by the running stream reader.
+            Transport::Stdin => {
+                self.set_error("standard input cannot be reopened");
+                return None;
+            }
+            Transport::Can | Transport::Generator => {}
+        }
+
+        new_net.use_encryption = self.use_encryption;
+
+        self.status =
+            Some(("Reconnecting...".to_string(), Color32::LIGHT_GREEN));
+        Some((new_net, device_id))
+    }
+
+    /// Request closing current frame source.
+    ///
+    /// # Parameters
+    /// - `net` - given current networks configurations.
+    ///
+    /// # Returns
+    /// - Disconnect action - if frame source can be reopened later.
+    /// - `None` - otherwise.
+    fn disconnect(&mut self, net: &NetConfig) -> Option<ConnectionAction> {
+        // Standard input is still owned by the running stream reader.
+        if net.transport == Transport::Stdin {
+            self.set_error("standard input cannot be reopened");
+            return None;
+        }
+
+        self.status = Some(("Disconnected".to_string(), Color32::GRAY));
+        Some(ConnectionAction::Disconnect)
+    }
+
+    /// Set error status message.
+    ///
+    /// # Parameters
+    /// - `message` - given error description.
+    fn set_error(&mut self, message: &str) {
+        self.status = Some((format!("Error: {message}"), Color32::LIGHT_RED));
+    }
+}
+
+/// Parse accepted device identifier.
+///
+/// # Parameters
+/// - `text` - given hexadecimal (`0x` prefixed) or decimal identifier.
+///
+/// # Returns
+/// - `Some(None)` - if text is empty (all devices are accepted).
+/// - `Some(Some)` - if text is a valid identifier.
+/// - `None` - otherwise.
+fn parse_device_id(text: &str) -> Option<Option<u8>> {
+    let text = text.trim();
+
+    if text.is_empty() {
+        return Some(None);
+    }
+
+    let device_id =
+        match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+            Some(hex) => u8::from_str_radix(hex, 16).ok(),
+            None => text.parse().ok(),
+        };
+
+    device_id.map(Some)
+}
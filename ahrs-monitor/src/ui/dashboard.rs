@@ -3,15 +3,34 @@
 
 //! Dashboard tab user interface implementation.
 
-use crate::ui::utils::Metric;
+use crate::ui::{
+    clipboard, instruments, interpolation::AttitudeInterpolator, utils::Metric,
+};
 use crate::{
-    config::AppConfig,
+    config::{
+        AngleUnit, AppConfig, GLoadConfig, GRID_COLUMNS, Instrument,
+        InstrumentTile, MAX_TILE_ROWS, Smoothing,
+    },
+    core::{
+        attitude::representation,
+        g_load::{GLoad, GLoadLevel},
+        mesh::Mesh,
+    },
     model::FrameContext,
     ui::{TabViewer, utils::Plotter},
 };
-use eframe::epaint::Stroke;
-use egui::{Align2, Color32, FontId, Pos2, Sense, vec2};
-use tsilna_nav::math::{Quat32, euler::Euler32, na::Vector3};
+use eframe::epaint::{Stroke, StrokeKind};
+use egui::{
+    Align2, Button, Color32, ComboBox, FontId, Pos2, RichText, ScrollArea,
+    Sense, UiBuilder, vec2,
+};
+use std::{
+    collections::BTreeMap,
+    f32::consts::{PI, TAU},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tsilna_nav::math::{Quat32, na::Vector3};
 
 /// Roll angle color.
 const ROLL_COLOR: Color32 = Color32::LIGHT_RED;
@@ -22,17 +41,97 @@ const PITCH_COLOR: Color32 = Color32::LIGHT_GREEN;
 /// Yaw angle color.
 const YAW_COLOR: Color32 = Color32::LIGHT_BLUE;
 
+/// Onboard attitude error color.
+const ERROR_COLOR: Color32 = Color32::GOLD;
+
+/// Attitude uncertainty treated as degraded solution in degrees.
+const DEGRADED_UNCERTAINTY: f32 = 5.0;
+
 /// Number of metrics in history.
 const HISTORY_ENTRIES: usize = 3;
 
 /// Max number of points in history per each metric.
 const MAX_POINTS: usize = 1000;
 
+/// Angle between smoothed and received attitude treated as settled.
+const SETTLED_ANGLE: f32 = 1e-4;
+
+/// Height of the instrument grid row in pixels.
+const ROW_HEIGHT: f32 = 110.0;
+
+/// Spacing between the instrument grid cells in pixels.
+const GRID_SPACING: f32 = 6.0;
+
+/// Inner margin of the instrument tile in pixels.
+const TILE_MARGIN: f32 = 6.0;
+
+/// Max number of device columns in a row of the multi-device view.
+const MAX_DEVICE_COLUMNS: usize = 4;
+
+/// Height of the device attitude widget in pixels.
+const DEVICE_WIDGET_HEIGHT: f32 = 220.0;
+
+/// Time without frames after which device is shown as silent.
+const DEVICE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Instrument tile change requested by user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TileEdit {
+    /// Move tile one position back in placement order.
+    MoveBack,
+    /// Move tile one position forward in placement order.
+    MoveForward,
+    /// Span one grid column less.
+    Narrow,
+    /// Span one grid column more.
+    Widen,
+    /// Span one grid row less.
+    Shorten,
+    /// Span one grid row more.
+    Heighten,
+    /// Remove tile from the grid.
+    Remove,
+}
+
 /// Dashboard tab handler.
 #[derive(Debug, Default)]
 pub struct DashboardTab {
     /// Metrics plotter.
     plotter: Plotter<HISTORY_ENTRIES, MAX_POINTS>,
+    /// Onboard attitude error plotter.
+    error_plotter: Plotter<1, MAX_POINTS>,
+    /// Indicator whether device reports its onboard attitude.
+    has_onboard: bool,
+    /// Smoothing selected by user (overrides configured one).
+    smoothing: Option<Smoothing>,
+    /// Smoothed attitude displayed by the widget and readouts.
+    smoothed: Option<Quat32>,
+    /// Interpolation selected by user (overrides configured one).
+    interpolation: Option<bool>,
+    /// Attitude interpolator between received samples.
+    interpolator: AttitudeInterpolator,
+    /// Heading reference selected by user (overrides configured one).
+    true_heading: Option<bool>,
+    /// Indicator whether user requested max G-load reset.
+    is_g_load_reset: bool,
+    /// Instrument grid in placement order.
+    instruments: Vec<InstrumentTile>,
+    /// Instrument selected to add into the grid.
+    new_instrument: Instrument,
+    /// Indicator whether instrument grid is being edited.
+    is_editing: bool,
+    /// Indicator whether user changed instrument grid.
+    is_layout_changed: bool,
+    /// Indicator whether user requested instrument grid saving.
+    is_layout_saved: bool,
+    /// Vehicle 3D model of the attitude widget (`None` - wireframe cube).
+    model: Option<Arc<Mesh>>,
+    /// Latest frame with estimated attitude and its arrival time by device.
+    devices: BTreeMap<u8, (Arc<FrameContext>, Instant)>,
+    /// Indicator whether devices are shown side by side.
+    is_multi_device: bool,
+    /// Unit of the plotted Euler angles.
+    angle_unit: AngleUnit,
 }
 
 impl TabViewer for DashboardTab {
@@ -62,42 +161,64 @@ impl TabViewer for DashboardTab {
         &mut self,
         ui: &mut egui::Ui,
         frame_ctx: &FrameContext,
-        _: &AppConfig,
+        app_cfg: &AppConfig,
     ) {
-        if let Some(quaternion) = frame_ctx.quaternion {
-            ui.vertical(|ui| {
-                let plot_height = ui.available_height() * 0.45;
-                self.plotter.set_plot_height(Some(plot_height));
-
-                ui.scope(|ui| {
-                    ui.set_height(plot_height);
-                    self.display_attitude_plot(ui);
+        self.set_angle_unit(app_cfg.display.angle_unit);
+
+        if let Some(received) = frame_ctx.quaternion {
+            let is_min_spec = app_cfg.display.is_min_spec;
+
+            // Smoothing animation is disabled in min-spec mode.
+            let smoothing = if is_min_spec {
+                Smoothing::None
+            } else {
+                self.smoothing.unwrap_or(app_cfg.display.smoothing)
+            };
+            let is_interpolated = !is_min_spec
+                && self.interpolation.unwrap_or(app_cfg.display.interpolation);
+            let (dt, now) = ui.input(|i| (i.stable_dt, i.time));
+
+            let target = if is_interpolated {
+                self.interpolator.update(received, frame_ctx.timestamp, now)
+            } else {
+                self.interpolator.reset();
+                received
+            };
+            let quaternion = self.smooth_attitude(target, smoothing, dt);
+
+            // Keep animating until smoothed attitude catches up.
+            if quaternion.angle_to(&received) > SETTLED_ANGLE {
+                ui.ctx().request_repaint();
+            }
+
+            ui.horizontal(|ui| {
+                ui.add_enabled_ui(!is_min_spec, |ui| {
+                    self.display_smoothing_selector(ui, smoothing);
+                    self.display_interpolation_toggle(ui, is_interpolated);
                 });
-            });
+                ui.separator();
+                display_fusion_inputs(ui, frame_ctx, app_cfg);
+                ui.separator();
+                self.display_layout_toolbar(ui);
 
-            ui.add_space(8.0);
-            ui.separator();
-            ui.add_space(8.0);
-
-            ui.columns(2, |cols| {
-                if let Some(col) = cols.get_mut(0) {
-                    col.vertical(|ui| {
-                        // Displaying attitude widget.
-                        ui.group(|ui| {
-                            ui.set_height(ui.available_height() * 0.90);
-                            ui.set_width(ui.available_width());
-                            ui.label(egui::RichText::new("Attitude"));
-                            ui.separator();
-
-                            display_attitude_widget(ui, &quaternion);
-                        });
-                    });
+                if self.devices.len() > 1 {
+                    ui.separator();
+                    ui.toggle_value(&mut self.is_multi_device, "⧉ Devices")
+                        .on_hover_text(
+                            "Show attitude of each device side by side",
+                        );
                 }
+            });
+            ui.separator();
 
-                if let Some(col) = cols.get_mut(1) {
-                    col.vertical(|ui| {
-                        self.display_attitude_metrics(ui, &quaternion);
-                    });
+            let is_multi_device =
+                self.is_multi_device && self.devices.len() > 1;
+
+            ScrollArea::vertical().auto_shrink(false).show(ui, |ui| {
+                if is_multi_device {
+                    self.display_devices(ui, app_cfg);
+                } else {
+                    self.display_grid(ui, &quaternion, frame_ctx, app_cfg);
                 }
             });
         }
@@ -105,116 +226,1087 @@ impl TabViewer for DashboardTab {
 }
 
 impl DashboardTab {
+    /// Set vehicle 3D model of the attitude widget.
+    ///
+    /// # Parameters
+    /// - `model` - given vehicle model (`None` - wireframe cube).
+    ///
+    /// # Returns
+    /// - Updated `DashboardTab` object.
+    #[must_use]
+    pub fn with_model(mut self, model: Option<Arc<Mesh>>) -> Self {
+        self.model = model;
+        self
+    }
+
+    /// Take max G-load reset request.
+    ///
+    /// # Returns
+    /// - `true` - if user requested reset since the last call.
+    /// - `false` - otherwise.
+    pub const fn take_g_load_reset(&mut self) -> bool {
+        let is_reset = self.is_g_load_reset;
+        self.is_g_load_reset = false;
+        is_reset
+    }
+
+    /// Set instrument grid of the active layout preset.
+    ///
+    /// # Parameters
+    /// - `instruments` - given instrument tiles in placement order.
+    pub fn set_instruments(&mut self, instruments: &[InstrumentTile]) {
+        if self.instruments != instruments {
+            self.instruments = instruments.to_vec();
+        }
+    }
+
+    /// Take instrument grid changed by user.
+    ///
+    /// # Returns
+    /// - Changed instrument grid - if user changed it since the last call.
+    /// - `None` - otherwise.
+    pub fn take_instruments(&mut self) -> Option<Vec<InstrumentTile>> {
+        let is_changed = self.is_layout_changed;
+        self.is_layout_changed = false;
+        is_changed.then(|| self.instruments.clone())
+    }
+
+    /// Take instrument grid saving request.
+    ///
+    /// # Returns
+    /// - `true` - if user requested saving since the last call.
+    /// - `false` - otherwise.
+    pub const fn take_layout_save(&mut self) -> bool {
+        let is_saved = self.is_layout_saved;
+        self.is_layout_saved = false;
+        is_saved
+    }
+
     /// Append attitude metrics to the points history.
     ///
     /// # Parameters
     /// - `quaternion` - given quaternion to handle.
+    /// - `attitude_error` - given angular error between onboard and local
+    ///   attitude in degrees.
+    /// - `attitude_uncertainty` - given attitude uncertainty in degrees.
     /// - `timestamp` - given timestamp in microseconds.
-    pub fn add_data(&mut self, quaternion: &Option<Quat32>, timestamp: u32) {
+    pub fn add_data(
+        &mut self,
+        quaternion: &Option<Quat32>,
+        attitude_error: Option<f32>,
+        attitude_uncertainty: Option<f32>,
+        timestamp: u32,
+    ) {
         if let Some(q) = quaternion {
-            let attitude = Euler32::from_quaternion(*q);
-
+            let factor = self.angle_unit.angle_factor();
+            let (roll, pitch, yaw) = representation::euler_angles(q);
             let data: [f32; HISTORY_ENTRIES] =
-                [attitude.roll, attitude.pitch, attitude.yaw];
+                [roll, pitch, yaw].map(|angle| angle * factor);
 
             self.plotter.add_data(data, u64::from(timestamp));
+
+            if let Some(uncertainty) = attitude_uncertainty {
+                self.plotter.set_band(uncertainty.to_radians() * factor);
+            }
+        }
+
+        if let Some(error) = attitude_error {
+            self.has_onboard = true;
+            self.error_plotter.add_data([error], u64::from(timestamp));
+        }
+    }
+
+    /// Set unit of the plotted Euler angles.
+    ///
+    /// # Parameters
+    /// - `unit` - given angle unit to set.
+    fn set_angle_unit(&mut self, unit: AngleUnit) {
+        if self.angle_unit == unit {
+            return;
+        }
+
+        let factor = unit.angle_factor() / self.angle_unit.angle_factor();
+        self.plotter.rescale(&[0, 1, 2], factor);
+        self.angle_unit = unit;
+    }
+
+    /// Store the latest frame of the device for the multi-device view.
+    ///
+    /// # Parameters
+    /// - `frame_ctx` - given received frame context to handle.
+    pub fn add_device_frame(&mut self, frame_ctx: &Arc<FrameContext>) {
+        if let Some(frame) = &frame_ctx.frame
+            && frame_ctx.quaternion.is_some()
+        {
+            self.devices.insert(
+                frame.header.device_id,
+                (Arc::clone(frame_ctx), Instant::now()),
+            );
         }
     }
 
+    /// Set playback cursor of the plots.
+    ///
+    /// # Parameters
+    /// - `timestamp` - given timestamp at the cursor in microseconds (`None`
+    ///   - hide cursor).
+    pub fn set_cursor(&mut self, timestamp: Option<u32>) {
+        let timestamp = timestamp.map(u64::from);
+
+        self.plotter.set_cursor(timestamp);
+        self.error_plotter.set_cursor(timestamp);
+    }
+
     /// Display plot of attitude changing over the time.
     ///
     /// # Parameters
     /// - `ui` - given screen UI handler.
-    /// - `attitude` - given attitude in Euler angles representation.
     fn display_attitude_plot(&mut self, ui: &mut egui::Ui) {
+        self.plotter.set_plot_height(Some(ui.available_height()));
         self.plotter.render_plot(
             ui,
             "attitude_p",
-            "Attitude (Euler Angles)",
+            &format!(
+                "Attitude (Euler Angles, {})",
+                self.angle_unit.angle_symbol()
+            ),
             &[0, 1, 2],
             &["Roll (X)", "Pitch (Y)", "Yaw (Z)"],
             &[ROLL_COLOR, PITCH_COLOR, YAW_COLOR],
         );
     }
 
-    /// Display attitude metrics.
+    /// Display plot of the onboard attitude error changing over the time.
     ///
     /// # Parameters
     /// - `ui` - given screen UI handler.
-    /// - `attitude` - given attitude in Euler angles representation.
-    /// - `quaternion` - given quaternion to handle.
-    fn display_attitude_metrics(&self, ui: &mut egui::Ui, quaternion: &Quat32) {
-        ui.group(|ui| {
-            ui.set_height(ui.available_height() * 0.90);
-            ui.set_width(ui.available_width());
-            ui.vertical_centered(|ui| {
-                ui.label(egui::RichText::new("EULER ANGLES").strong());
+    fn display_error_plot(&mut self, ui: &mut egui::Ui) {
+        if !self.has_onboard {
+            ui.label("Device does not report its onboard attitude");
+            return;
+        }
+
+        self.error_plotter
+            .set_plot_height(Some(ui.available_height()));
+        self.error_plotter.render_plot(
+            ui,
+            "attitude_error_p",
+            "Onboard vs Local Attitude Error (deg)",
+            &[0],
+            &["Error"],
+            &[ERROR_COLOR],
+        );
+    }
+
+    /// Smooth attitude displayed by the widget and readouts.
+    ///
+    /// # Parameters
+    /// - `received` - given received attitude.
+    /// - `smoothing` - given smoothing amount.
+    /// - `dt` - given time since the previous UI frame in seconds.
+    ///
+    /// # Returns
+    /// - Smoothed attitude.
+    fn smooth_attitude(
+        &mut self,
+        received: Quat32,
+        smoothing: Smoothing,
+        dt: f32,
+    ) -> Quat32 {
+        let time_constant = smoothing.time_constant();
+
+        let smoothed = match self.smoothed {
+            Some(prev) if time_constant > 0.0 => {
+                // Exponential smoothing weight for the elapsed frame time.
+                let weight = 1.0 - (-dt / time_constant).exp();
+
+                // Opposite attitudes have no unique interpolation path.
+                prev.try_slerp(&received, weight, f32::EPSILON)
+                    .unwrap_or(received)
+            }
+            _ => received,
+        };
+
+        self.smoothed = Some(smoothed);
+        smoothed
+    }
+
+    /// Display attitude smoothing selector.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `smoothing` - given current smoothing amount.
+    fn display_smoothing_selector(
+        &mut self,
+        ui: &mut egui::Ui,
+        smoothing: Smoothing,
+    ) {
+        let mut selected = smoothing;
+
+        ComboBox::from_label("Smoothing")
+            .selected_text(selected.name())
+            .show_ui(ui, |ui| {
+                for value in Smoothing::ALL {
+                    ui.selectable_value(&mut selected, value, value.name());
+                }
             });
-            ui.separator();
 
-            ui.group(|ui| {
-                ui.vertical(|ui| {
-                    if let Some(data) = self.plotter.last_data() {
-                        let (roll, pitch, yaw) = (data[0], data[1], data[2]);
-                        let r = &format!("{roll:.2}");
-                        let p = &format!("{pitch:.2}");
-                        let y = &format!("{yaw:.2}");
-
-                        let metrics_args: Vec<Metric> = vec![
-                            Metric::new(
-                                "Roll:",
-                                r,
-                                Some("rad"),
-                                Some(ROLL_COLOR),
-                            ),
-                            Metric::new(
-                                "Pitch:",
-                                p,
-                                Some("rad"),
-                                Some(PITCH_COLOR),
-                            ),
-                            Metric::new(
-                                "Yaw:",
-                                y,
-                                Some("rad"),
-                                Some(YAW_COLOR),
-                            ),
-                        ];
-
-                        for m in &metrics_args {
-                            m.display(ui);
-                        }
+        if selected != smoothing {
+            self.smoothing = Some(selected);
+        }
+    }
+
+    /// Display attitude interpolation toggle.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `is_interpolated` - given indicator whether interpolation is enabled.
+    fn display_interpolation_toggle(
+        &mut self,
+        ui: &mut egui::Ui,
+        is_interpolated: bool,
+    ) {
+        let mut is_checked = is_interpolated;
+
+        if ui
+            .checkbox(&mut is_checked, "Interpolate")
+            .on_hover_text(
+                "Animate attitude between received samples at display frame \
+                 rate",
+            )
+            .changed()
+        {
+            self.interpolation = Some(is_checked);
+        }
+    }
+
+    /// Display instrument grid editing controls.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    fn display_layout_toolbar(&mut self, ui: &mut egui::Ui) {
+        ui.toggle_value(&mut self.is_editing, "✏ Edit layout")
+            .on_hover_text("Add, remove, move and resize instruments");
+
+        if !self.is_editing {
+            return;
+        }
+
+        // Each instrument is shown once, so that plot identifiers are unique.
+        let available: Vec<Instrument> = Instrument::ALL
+            .into_iter()
+            .filter(|instrument| {
+                !self.instruments.iter().any(|t| t.instrument == *instrument)
+            })
+            .collect();
+
+        if !available.contains(&self.new_instrument)
+            && let Some(&first) = available.first()
+        {
+            self.new_instrument = first;
+        }
+
+        ui.add_enabled_ui(!available.is_empty(), |ui| {
+            ComboBox::from_id_salt("dashboard_new_instrument")
+                .selected_text(self.new_instrument.name())
+                .show_ui(ui, |ui| {
+                    for instrument in available {
+                        ui.selectable_value(
+                            &mut self.new_instrument,
+                            instrument,
+                            instrument.name(),
+                        );
                     }
                 });
-            });
 
-            // Displaying quaternion data.
-            ui.vertical_centered(|ui| {
-                ui.label(egui::RichText::new("QUATERNION").strong());
-            });
-            ui.separator();
+            if ui.button("➕ Add").clicked() {
+                self.instruments.push(InstrumentTile::new(
+                    self.new_instrument,
+                    2,
+                    1,
+                ));
+                self.is_layout_changed = true;
+            }
+        });
+
+        if ui
+            .button("💾 Save to preset")
+            .on_hover_text(
+                "Save instrument grid of the layout preset to config",
+            )
+            .clicked()
+        {
+            self.is_layout_saved = true;
+        }
+    }
+
+    /// Display instrument grid.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `quaternion` - given displayed quaternion to handle.
+    /// - `frame_ctx` - given current frame context to handle.
+    /// - `app_cfg` - given global config to handle.
+    #[allow(clippy::cast_precision_loss)]
+    fn display_grid(
+        &mut self,
+        ui: &mut egui::Ui,
+        quaternion: &Quat32,
+        frame_ctx: &FrameContext,
+        app_cfg: &AppConfig,
+    ) {
+        let placements = instruments::pack(&self.instruments);
+        let rows = placements
+            .iter()
+            .map(|placement| placement.row + placement.rows)
+            .max()
+            .unwrap_or_default();
+
+        if rows == 0 {
+            ui.label("No instruments, use ✏ Edit layout to add them");
+            return;
+        }
 
-            ui.group(|ui| {
-                ui.vertical(|ui| {
-                    let w = &format!("{:.6}", quaternion.w);
-                    let x = &format!("{:.6}", quaternion.i);
-                    let y = &format!("{:.6}", quaternion.j);
-                    let z = &format!("{:.6}", quaternion.k);
-
-                    let metrics_args: Vec<Metric> = vec![
-                        Metric::new("w:", w, None, Some(Color32::LIGHT_YELLOW)),
-                        Metric::new("x:", x, None, Some(Color32::LIGHT_RED)),
-                        Metric::new("y:", y, None, Some(Color32::LIGHT_GREEN)),
-                        Metric::new("z:", z, None, Some(Color32::LIGHT_BLUE)),
-                    ];
-
-                    for m in &metrics_args {
-                        m.display(ui);
+        let width = ui.available_width();
+        let columns = f32::from(GRID_COLUMNS);
+        let cell = vec2(
+            (columns - 1.0).mul_add(-GRID_SPACING, width) / columns,
+            ROW_HEIGHT,
+        );
+        let height =
+            (rows as f32).mul_add(ROW_HEIGHT + GRID_SPACING, -GRID_SPACING);
+        let (grid_rect, _) =
+            ui.allocate_exact_size(vec2(width, height), Sense::hover());
+
+        let tiles = self.instruments.clone();
+        let last = tiles.len().saturating_sub(1);
+        let mut edit = None;
+
+        for (index, (tile, placement)) in
+            tiles.iter().zip(&placements).enumerate()
+        {
+            let rect = placement.rect(grid_rect.min, cell, GRID_SPACING);
+            let content = rect.shrink(TILE_MARGIN);
+
+            ui.painter().rect_stroke(
+                rect,
+                4.0,
+                ui.visuals().widgets.noninteractive.bg_stroke,
+                StrokeKind::Inside,
+            );
+
+            let builder = UiBuilder::new()
+                .max_rect(content)
+                .id_salt(("dashboard_tile", index));
+
+            ui.scope_builder(builder, |ui| {
+                ui.set_clip_rect(content.intersect(ui.clip_rect()));
+
+                if self.is_editing {
+                    let is_first = index == 0;
+                    let is_last = index == last;
+
+                    if let Some(action) =
+                        display_tile_header(ui, tile, is_first, is_last)
+                    {
+                        edit = Some((index, action));
                     }
+                } else if !is_plot(tile.instrument) {
+                    ui.vertical_centered(|ui| {
+                        let title = tile.instrument.name().to_uppercase();
+                        ui.label(RichText::new(title).strong());
+                    });
+                    ui.separator();
+                }
+
+                self.display_instrument(
+                    ui,
+                    tile.instrument,
+                    quaternion,
+                    frame_ctx,
+                    app_cfg,
+                );
+            });
+
+            // Readouts are labels, so that the whole tile is made clickable
+            // for the context menu.
+            if !self.is_editing && has_copy_menu(tile.instrument) {
+                let id = ui.id().with(("dashboard_copy_menu", index));
+
+                ui.interact(rect, id, Sense::click()).context_menu(|ui| {
+                    clipboard::attitude_menu(ui, quaternion, frame_ctx);
                 });
+            }
+        }
+
+        if let Some((index, action)) = edit {
+            self.edit_tile(index, action);
+        }
+    }
+
+    /// Display attitude and readouts of each device side by side.
+    ///
+    /// Devices are compared with the first one (lowest identifier), e.g. to
+    /// check agreement of a redundant IMU pair. Attitudes are shown as
+    /// received, without smoothing.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `app_cfg` - given global config to handle.
+    fn display_devices(&self, ui: &mut egui::Ui, app_cfg: &AppConfig) {
+        let devices: Vec<_> = self
+            .devices
+            .iter()
+            .filter_map(|(&id, (frame_ctx, received))| {
+                let quaternion = frame_ctx.quaternion?;
+                Some((id, quaternion, frame_ctx, received.elapsed()))
+            })
+            .collect();
+
+        let Some(&(reference_id, reference, ..)) = devices.first() else {
+            return;
+        };
+
+        // Sorting model faces is too slow for min-spec hardware.
+        let model = self
+            .model
+            .as_deref()
+            .filter(|_| !app_cfg.display.is_min_spec);
+        let [r, g, b] = app_cfg.display.model.color;
+        let model_color = Color32::from_rgb(r, g, b);
+
+        for row in devices.chunks(MAX_DEVICE_COLUMNS) {
+            ui.columns(row.len(), |columns| {
+                for (ui, (id, quaternion, frame_ctx, elapsed)) in
+                    columns.iter_mut().zip(row)
+                {
+                    ui.push_id(("dashboard_device", id), |ui| {
+                        display_device_header(ui, *id, frame_ctx, *elapsed);
+
+                        ui.allocate_ui(
+                            vec2(ui.available_width(), DEVICE_WIDGET_HEIGHT),
+                            |ui| {
+                                display_attitude_widget(
+                                    ui,
+                                    quaternion,
+                                    model,
+                                    model_color,
+                                );
+                            },
+                        );
+
+                        ui.separator();
+                        display_euler(ui, quaternion, frame_ctx, app_cfg);
+
+                        if *id != reference_id {
+                            display_device_difference(
+                                ui,
+                                quaternion,
+                                &reference,
+                                reference_id,
+                            );
+                        }
+
+                        ui.separator();
+                        display_gyro_bias(ui, frame_ctx, app_cfg);
+                    });
+                }
             });
+
+            ui.separator();
+        }
+    }
+
+    /// Display instrument.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `instrument` - given instrument to display.
+    /// - `quaternion` - given displayed quaternion to handle.
+    /// - `frame_ctx` - given current frame context to handle.
+    /// - `app_cfg` - given global config to handle.
+    fn display_instrument(
+        &mut self,
+        ui: &mut egui::Ui,
+        instrument: Instrument,
+        quaternion: &Quat32,
+        frame_ctx: &FrameContext,
+        app_cfg: &AppConfig,
+    ) {
+        let (roll, pitch, yaw) = representation::euler_angles(quaternion);
+
+        match instrument {
+            Instrument::Attitude => {
+                // Sorting model faces is too slow for min-spec hardware.
+                let model = self
+                    .model
+                    .as_deref()
+                    .filter(|_| !app_cfg.display.is_min_spec);
+                let [r, g, b] = app_cfg.display.model.color;
+
+                display_attitude_widget(
+                    ui,
+                    quaternion,
+                    model,
+                    Color32::from_rgb(r, g, b),
+                );
+            }
+            Instrument::Horizon => {
+                instruments::display_horizon(ui, roll, pitch)
+            }
+            Instrument::Compass => self.display_compass(ui, yaw, app_cfg),
+            Instrument::Euler => {
+                display_euler(ui, quaternion, frame_ctx, app_cfg)
+            }
+            Instrument::Quaternion => {
+                display_quaternion(ui, quaternion, app_cfg)
+            }
+            Instrument::GyroBias => display_gyro_bias(ui, frame_ctx, app_cfg),
+            Instrument::Vertical => display_vertical(ui, frame_ctx, app_cfg),
+            Instrument::GLoad => {
+                if let Some(g_load) = frame_ctx.g_load {
+                    self.is_g_load_reset |=
+                        display_g_load(ui, g_load, &app_cfg.imu.g_load);
+                } else {
+                    ui.label("G-load monitoring is disabled");
+                }
+            }
+            Instrument::OnboardFusion => {
+                display_onboard_fusion(ui, frame_ctx, app_cfg);
+            }
+            Instrument::AttitudePlot => self.display_attitude_plot(ui),
+            Instrument::ErrorPlot => self.display_error_plot(ui),
+        }
+    }
+
+    /// Display heading indicator with magnetic/true heading toggle.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `yaw` - given displayed yaw angle in radians.
+    /// - `app_cfg` - given global config to handle.
+    fn display_compass(
+        &mut self,
+        ui: &mut egui::Ui,
+        yaw: f32,
+        app_cfg: &AppConfig,
+    ) {
+        let cfg = &app_cfg.display.compass;
+        let mut is_true = self.true_heading.unwrap_or(cfg.true_heading);
+
+        ui.horizontal(|ui| {
+            let magnetic = ui.selectable_value(&mut is_true, false, "Magnetic");
+            let true_north = ui
+                .selectable_value(&mut is_true, true, "True")
+                .on_hover_text(format!(
+                    "Magnetic declination {:+.1}°",
+                    cfg.declination
+                ));
+
+            if magnetic.changed() || true_north.changed() {
+                self.true_heading = Some(is_true);
+            }
         });
+
+        let reference = if is_true { "TRUE" } else { "MAG" };
+        instruments::display_compass(ui, cfg.heading(yaw, is_true), reference);
+    }
+
+    /// Apply instrument tile change requested by user.
+    ///
+    /// # Parameters
+    /// - `index` - given tile index in placement order.
+    /// - `action` - given tile change to apply.
+    fn edit_tile(&mut self, index: usize, action: TileEdit) {
+        let len = self.instruments.len();
+
+        match action {
+            TileEdit::MoveBack if index > 0 && index < len => {
+                self.instruments.swap(index - 1, index);
+            }
+            TileEdit::MoveForward if index + 1 < len => {
+                self.instruments.swap(index, index + 1);
+            }
+            TileEdit::Remove if index < len => {
+                self.instruments.remove(index);
+            }
+            _ => {
+                let Some(tile) = self.instruments.get_mut(index) else {
+                    return;
+                };
+
+                match action {
+                    TileEdit::Narrow => {
+                        tile.columns = tile.columns.saturating_sub(1).max(1);
+                    }
+                    TileEdit::Widen => {
+                        tile.columns = (tile.columns + 1).min(GRID_COLUMNS);
+                    }
+                    TileEdit::Shorten => {
+                        tile.rows = tile.rows.saturating_sub(1).max(1);
+                    }
+                    TileEdit::Heighten => {
+                        tile.rows = (tile.rows + 1).min(MAX_TILE_ROWS);
+                    }
+                    _ => return,
+                }
+            }
+        }
+
+        self.is_layout_changed = true;
+    }
+}
+
+/// Display sensors fused into the attitude.
+///
+/// # Parameters
+/// - `ui` - given screen UI handler.
+/// - `frame_ctx` - given current frame context to handle.
+/// - `app_cfg` - given global config to handle.
+fn display_fusion_inputs(
+    ui: &mut egui::Ui,
+    frame_ctx: &FrameContext,
+    app_cfg: &AppConfig,
+) {
+    let Some(used) = frame_ctx.fusion_inputs else {
+        ui.label("Inputs: onboard attitude");
+        return;
+    };
+
+    let enabled = app_cfg.imu.inputs;
+
+    ui.label("Inputs:");
+
+    for (is_used, is_enabled, label) in [
+        (used.acc, enabled.acc, "ACC"),
+        (used.gyr, enabled.gyr, "GYR"),
+        (used.mag, enabled.mag, "MAG"),
+    ] {
+        let (text, hint) = match (is_used, is_enabled) {
+            (true, _) => {
+                (RichText::new(label).color(Color32::LIGHT_GREEN), "Fused")
+            }
+            (false, false) => (
+                RichText::new(label).color(Color32::ORANGE).strikethrough(),
+                "Excluded from fusion",
+            ),
+            (false, true) => (
+                RichText::new(label).color(Color32::DARK_GRAY),
+                "No readings in the payload",
+            ),
+        };
+
+        ui.label(text).on_hover_text(hint);
+    }
+}
+
+/// Check whether instrument is a plot.
+///
+/// # Parameters
+/// - `instrument` - given instrument to check.
+///
+/// # Returns
+/// - `true` - if instrument is a plot with its own title.
+/// - `false` - otherwise.
+const fn is_plot(instrument: Instrument) -> bool {
+    matches!(instrument, Instrument::AttitudePlot | Instrument::ErrorPlot)
+}
+
+/// Check whether instrument tile has attitude copy context menu.
+///
+/// # Parameters
+/// - `instrument` - given instrument to check.
+///
+/// # Returns
+/// - `true` - if instrument consists of readouts only.
+/// - `false` - otherwise.
+const fn has_copy_menu(instrument: Instrument) -> bool {
+    matches!(
+        instrument,
+        Instrument::Euler
+            | Instrument::Quaternion
+            | Instrument::GyroBias
+            | Instrument::Vertical
+            | Instrument::OnboardFusion
+    )
+}
+
+/// Display instrument tile editing header.
+///
+/// # Parameters
+/// - `ui` - given screen UI handler.
+/// - `tile` - given instrument tile to edit.
+/// - `is_first` - given indicator whether tile is placed first.
+/// - `is_last` - given indicator whether tile is placed last.
+///
+/// # Returns
+/// - Tile change requested by user (`None` - tile is unchanged).
+fn display_tile_header(
+    ui: &mut egui::Ui,
+    tile: &InstrumentTile,
+    is_first: bool,
+    is_last: bool,
+) -> Option<TileEdit> {
+    let mut action = None;
+
+    ui.horizontal_wrapped(|ui| {
+        ui.label(RichText::new(tile.instrument.name()).strong());
+        ui.label(format!("{}×{}", tile.columns, tile.rows));
+
+        let buttons = [
+            ("◀", "Move backward", TileEdit::MoveBack, !is_first),
+            ("▶", "Move forward", TileEdit::MoveForward, !is_last),
+            ("W−", "Narrow", TileEdit::Narrow, tile.columns > 1),
+            ("W+", "Widen", TileEdit::Widen, tile.columns < GRID_COLUMNS),
+            ("H−", "Shorten", TileEdit::Shorten, tile.rows > 1),
+            (
+                "H+",
+                "Heighten",
+                TileEdit::Heighten,
+                tile.rows < MAX_TILE_ROWS,
+            ),
+            ("✖", "Remove", TileEdit::Remove, true),
+        ];
+
+        for (text, hint, edit, is_enabled) in buttons {
+            if ui
+                .add_enabled(is_enabled, Button::new(text).small())
+                .on_hover_text(hint)
+                .clicked()
+            {
+                action = Some(edit);
+            }
+        }
+    });
+    ui.separator();
+
+    action
+}
+
+/// Display device identifier and stream state.
+///
+/// # Parameters
+/// - `ui` - given screen UI handler.
+/// - `device_id` - given device identifier.
+/// - `frame_ctx` - given latest frame context of the device.
+/// - `elapsed` - given time since the latest frame of the device.
+fn display_device_header(
+    ui: &mut egui::Ui,
+    device_id: u8,
+    frame_ctx: &FrameContext,
+    elapsed: Duration,
+) {
+    let (state, color) = if elapsed > DEVICE_TIMEOUT {
+        (
+            format!("silent for {:.0} s", elapsed.as_secs_f32()),
+            Color32::ORANGE,
+        )
+    } else {
+        let sequence = frame_ctx
+            .frame
+            .as_ref()
+            .map_or(0, |frame| frame.header.sequence.get());
+
+        (format!("live, seq {sequence}"), Color32::LIGHT_GREEN)
+    };
+
+    ui.vertical_centered(|ui| {
+        ui.label(RichText::new(format!("DEVICE {device_id:#04X}")).strong());
+        ui.label(RichText::new(state).color(color).small());
+    });
+    ui.separator();
+}
+
+/// Display attitude difference between the device and the reference one.
+///
+/// # Parameters
+/// - `ui` - given screen UI handler.
+/// - `quaternion` - given attitude of the device.
+/// - `reference` - given attitude of the reference device.
+/// - `reference_id` - given reference device identifier.
+fn display_device_difference(
+    ui: &mut egui::Ui,
+    quaternion: &Quat32,
+    reference: &Quat32,
+    reference_id: u8,
+) {
+    let angle = quaternion.angle_to(reference).to_degrees();
+    let (roll, pitch, yaw) = representation::euler_angles(quaternion);
+    let (ref_roll, ref_pitch, ref_yaw) =
+        representation::euler_angles(reference);
+
+    let name = &format!("Δ vs {reference_id:#04X}:");
+    let value = &format!("{angle:.2}");
+    let deltas = &format!(
+        "roll {:+.2}, pitch {:+.2}, yaw {:+.2}",
+        wrap_angle(roll - ref_roll).to_degrees(),
+        wrap_angle(pitch - ref_pitch).to_degrees(),
+        wrap_angle(yaw - ref_yaw).to_degrees(),
+    );
+
+    Metric::new(name, value, Some("deg"), Some(ERROR_COLOR))
+        .display(ui)
+        .on_hover_text(format!("Euler angle differences in degrees: {deltas}"));
+}
+
+/// Wrap angle into the [-π, π] range.
+///
+/// # Parameters
+/// - `angle` - given angle in radians.
+///
+/// # Returns
+/// - Wrapped angle in radians.
+fn wrap_angle(angle: f32) -> f32 {
+    (angle + PI).rem_euclid(TAU) - PI
+}
+
+/// Display Euler angles readouts.
+///
+/// # Parameters
+/// - `ui` - given screen UI handler.
+/// - `quaternion` - given displayed quaternion to handle.
+/// - `frame_ctx` - given current frame context to handle.
+/// - `app_cfg` - given global config to handle.
+fn display_euler(
+    ui: &mut egui::Ui,
+    quaternion: &Quat32,
+    frame_ctx: &FrameContext,
+    app_cfg: &AppConfig,
+) {
+    let formats = &app_cfg.display.format;
+    let ch = &app_cfg.channels;
+    let angle_unit = app_cfg.display.angle_unit;
+
+    let [(roll_ch, factor), (pitch_ch, _), (yaw_ch, _)] =
+        ["roll", "pitch", "yaw"].map(|k| ch.info(k).in_angle_unit(angle_unit));
+
+    let (roll, pitch, yaw) = representation::euler_angles(quaternion);
+    let r = &formats.euler.format(roll * factor);
+    let p = &formats.euler.format(pitch * factor);
+    let y = &formats.euler.format(yaw * factor);
+
+    let [r_unit, p_unit, y_unit] =
+        [roll_ch, pitch_ch, yaw_ch].map(|c| Some(c.unit));
+
+    let metrics_args: Vec<Metric> = vec![
+        Metric::new("Roll:", r, r_unit, Some(ROLL_COLOR)),
+        Metric::new("Pitch:", p, p_unit, Some(PITCH_COLOR)),
+        Metric::new("Yaw:", y, y_unit, Some(YAW_COLOR)),
+    ];
+
+    for m in &metrics_args {
+        m.display(ui);
+    }
+
+    if let Some(uncertainty) = frame_ctx.attitude_uncertainty {
+        let value = &format!(
+            "±{}",
+            formats.euler.format(uncertainty.to_radians() * factor)
+        );
+        let color = if uncertainty > DEGRADED_UNCERTAINTY {
+            Color32::ORANGE
+        } else {
+            Color32::GRAY
+        };
+
+        Metric::new("Uncertainty:", value, r_unit, Some(color)).display(ui);
+    }
+}
+
+/// Display quaternion components readouts.
+///
+/// # Parameters
+/// - `ui` - given screen UI handler.
+/// - `quaternion` - given displayed quaternion to handle.
+/// - `app_cfg` - given global config to handle.
+fn display_quaternion(
+    ui: &mut egui::Ui,
+    quaternion: &Quat32,
+    app_cfg: &AppConfig,
+) {
+    let formats = &app_cfg.display.format;
+
+    let w = &formats.quat.format(quaternion.w);
+    let x = &formats.quat.format(quaternion.i);
+    let y = &formats.quat.format(quaternion.j);
+    let z = &formats.quat.format(quaternion.k);
+
+    let metrics_args: Vec<Metric> = vec![
+        Metric::new("w:", w, None, Some(Color32::LIGHT_YELLOW)),
+        Metric::new("x:", x, None, Some(Color32::LIGHT_RED)),
+        Metric::new("y:", y, None, Some(Color32::LIGHT_GREEN)),
+        Metric::new("z:", z, None, Some(Color32::LIGHT_BLUE)),
+    ];
+
+    for m in &metrics_args {
+        m.display(ui);
+    }
+}
+
+/// Display estimated gyroscope bias readouts.
+///
+/// # Parameters
+/// - `ui` - given screen UI handler.
+/// - `frame_ctx` - given current frame context to handle.
+/// - `app_cfg` - given global config to handle.
+fn display_gyro_bias(
+    ui: &mut egui::Ui,
+    frame_ctx: &FrameContext,
+    app_cfg: &AppConfig,
+) {
+    let Some(gyro_bias) = frame_ctx.gyro_bias else {
+        ui.label("Not estimated yet, keep the device still");
+        return;
+    };
+
+    let formats = &app_cfg.display.format;
+    let ch = &app_cfg.channels;
+    let angle_unit = app_cfg.display.angle_unit;
+
+    let [(x_ch, factor), (y_ch, _), (z_ch, _)] = ["gyr_x", "gyr_y", "gyr_z"]
+        .map(|k| ch.info(k).in_angle_unit(angle_unit));
+
+    let [x, y, z] = gyro_bias.bias.map(|v| formats.gyr.format(v * factor));
+    let (state, color) = if gyro_bias.is_still {
+        ("Stationary", Color32::LIGHT_GREEN)
+    } else {
+        ("Moving", Color32::GRAY)
+    };
+
+    let [x_unit, y_unit, z_unit] = [x_ch, y_ch, z_ch].map(|c| Some(c.unit));
+
+    let metrics_args: Vec<Metric> = vec![
+        Metric::new("x:", &x, x_unit, Some(ROLL_COLOR)),
+        Metric::new("y:", &y, y_unit, Some(PITCH_COLOR)),
+        Metric::new("z:", &z, z_unit, Some(YAW_COLOR)),
+        Metric::new("Device:", state, None, Some(color)),
+    ];
+
+    for m in &metrics_args {
+        m.display(ui);
+    }
+}
+
+/// Display baro-aided altitude and climb rate readouts.
+///
+/// # Parameters
+/// - `ui` - given screen UI handler.
+/// - `frame_ctx` - given current frame context to handle.
+/// - `app_cfg` - given global config to handle.
+fn display_vertical(
+    ui: &mut egui::Ui,
+    frame_ctx: &FrameContext,
+    app_cfg: &AppConfig,
+) {
+    let Some(vertical) = frame_ctx.vertical else {
+        ui.label("No barometer readings");
+        return;
+    };
+
+    let ch = &app_cfg.channels;
+    let altitude = &format!("{:.2}", vertical.altitude);
+    let climb_rate = &format!("{:+.2}", vertical.climb_rate);
+
+    let [altitude_unit, climb_rate_unit] =
+        ["fused_altitude", "climb_rate"].map(|k| Some(ch.info(k).unit));
+
+    let metrics_args: Vec<Metric> = vec![
+        Metric::new(
+            "Altitude:",
+            altitude,
+            altitude_unit,
+            Some(Color32::LIGHT_BLUE),
+        ),
+        Metric::new(
+            "Climb rate:",
+            climb_rate,
+            climb_rate_unit,
+            Some(Color32::LIGHT_GREEN),
+        ),
+    ];
+
+    for m in &metrics_args {
+        m.display(ui);
+    }
+}
+
+/// Display onboard vs local attitude error readout.
+///
+/// # Parameters
+/// - `ui` - given screen UI handler.
+/// - `frame_ctx` - given current frame context to handle.
+/// - `app_cfg` - given global config to handle.
+fn display_onboard_fusion(
+    ui: &mut egui::Ui,
+    frame_ctx: &FrameContext,
+    app_cfg: &AppConfig,
+) {
+    let Some(error) = frame_ctx.attitude_error else {
+        ui.label("Device does not report its onboard attitude");
+        return;
+    };
+
+    let error = &app_cfg.display.format.euler.format(error);
+    let unit = Some(app_cfg.channels.info("roll").unit);
+
+    Metric::new("Error:", error, unit, Some(ERROR_COLOR)).display(ui);
+}
+
+/// Display current and max G-load colored by the configured limits.
+///
+/// # Parameters
+/// - `ui` - given screen UI handler.
+/// - `g_load` - given current and max G-load.
+/// - `cfg` - given G-load limits.
+///
+/// # Returns
+/// - `true` - if user requested max G-load reset.
+/// - `false` - otherwise.
+fn display_g_load(ui: &mut egui::Ui, g_load: GLoad, cfg: &GLoadConfig) -> bool {
+    let color = |value: f32| match cfg.level(value) {
+        GLoadLevel::Normal => Color32::LIGHT_GREEN,
+        GLoadLevel::Warning => Color32::ORANGE,
+        GLoadLevel::Limit => Color32::RED,
+    };
+
+    let current = &format!("{:.2}", g_load.current);
+    let max = &format!("{:.2}", g_load.max);
+
+    let metrics_args: Vec<Metric> = vec![
+        Metric::new(
+            "Current:",
+            current,
+            Some("g"),
+            Some(color(g_load.current)),
+        ),
+        Metric::new("Max:", max, Some("g"), Some(color(g_load.max))),
+    ];
+
+    for m in &metrics_args {
+        m.display(ui);
     }
+
+    let mut is_reset = false;
+
+    ui.horizontal(|ui| {
+        is_reset = ui
+            .button("⟲ Reset max")
+            .on_hover_text("Reset max G-load since the last reset")
+            .clicked();
+
+        if cfg.level(g_load.max) == GLoadLevel::Limit {
+            ui.label(
+                egui::RichText::new(format!(
+                    "⚠ LIMIT {:.1} g EXCEEDED",
+                    cfg.limit
+                ))
+                .color(Color32::RED)
+                .strong(),
+            );
+        }
+    });
+
+    is_reset
 }
 
 /// Cube vertices size.
@@ -253,12 +1345,19 @@ const CUBE_EDGES: [(usize, usize); 12] = [
 /// # Parameters
 /// - `ui` - given screen UI handler.
 /// - `rotation` - given quaternion to handle.
-fn display_attitude_widget(ui: &mut egui::Ui, rotation: &Quat32) {
+/// - `model` - given vehicle model (`None` - wireframe cube).
+/// - `model_color` - given vehicle model color.
+fn display_attitude_widget(
+    ui: &mut egui::Ui,
+    rotation: &Quat32,
+    model: Option<&Mesh>,
+    model_color: Color32,
+) {
     let (rect, _) = ui.allocate_at_least(ui.available_size(), Sense::hover());
     let center = rect.center();
     let scale = rect.width().min(rect.height()) * 0.2;
 
-    let painter = ui.painter();
+    let painter = ui.painter().with_clip_rect(rect);
 
     let project = |v: Vector3<f32>| -> Pos2 {
         let rotated = rotation * v;
@@ -266,14 +1365,19 @@ fn display_attitude_widget(ui: &mut egui::Ui, rotation: &Quat32) {
         center + vec2(rotated.x, -rotated.y) * scale
     };
 
-    // Rendering the cube.
-    let cube_stroke = Stroke::new(1.0, Color32::from_gray(100));
+    // Rendering the vehicle model or the cube.
+    if let Some(mesh) = model {
+        instruments::paint_model(&painter, rect, mesh, rotation, model_color);
+    } else {
+        let cube_stroke = Stroke::new(1.0, Color32::from_gray(100));
 
-    for &(i, j) in &CUBE_EDGES {
-        if let Some(v_i) = CUBE_VERTICES.get(i)
-            && let Some(v_j) = CUBE_VERTICES.get(j)
-        {
-            painter.line_segment([project(*v_i), project(*v_j)], cube_stroke);
+        for &(i, j) in &CUBE_EDGES {
+            if let Some(v_i) = CUBE_VERTICES.get(i)
+                && let Some(v_j) = CUBE_VERTICES.get(j)
+            {
+                painter
+                    .line_segment([project(*v_i), project(*v_j)], cube_stroke);
+            }
         }
     }
 
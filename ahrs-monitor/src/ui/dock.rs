@@ -0,0 +1,205 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Docking of the application tabs.
+//!
+//! Central area is split into resizable panes shown side by side, each one
+//! showing a single tab, and tabs can be popped out into separate windows.
+//! Every tab is shown at most once, since tabs keep their own state (plots
+//! history, scroll positions). Layout is stored on exit and restored at the
+//! next start.
+
+use crate::config::TabKind;
+use serde::{Deserialize, Serialize};
+use std::{fs, io::ErrorKind};
+
+/// Docking layout of the application tabs.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(default)]
+pub struct DockLayout {
+    /// Tabs shown side by side in the central area, left to right.
+    pub panes: Vec<TabKind>,
+    /// Tabs popped out into separate windows.
+    pub windows: Vec<TabKind>,
+    /// Index of the pane whose tab is switched by the tab bar.
+    pub focused: usize,
+}
+
+impl Default for DockLayout {
+    /// Get default docking layout.
+    ///
+    /// # Returns
+    /// - Single pane with the Dashboard tab.
+    fn default() -> Self {
+        Self {
+            panes: vec![TabKind::Dashboard],
+            windows: Vec::new(),
+            focused: 0,
+        }
+    }
+}
+
+/// Change of the docking layout requested by user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DockAction {
+    /// Focus the pane.
+    Focus(usize),
+    /// Show another tab in a new pane to the right of the pane.
+    Split(usize),
+    /// Move tab of the pane into a separate window.
+    PopOut(usize),
+    /// Close the pane.
+    Close(usize),
+    /// Move tab from the window back into a new pane.
+    Dock(TabKind),
+    /// Close the window.
+    CloseWindow(TabKind),
+}
+
+impl DockLayout {
+    /// Load docking layout from the file.
+    ///
+    /// # Parameters
+    /// - `path` - given layout file path.
+    ///
+    /// # Returns
+    /// - Stored layout (default if file does not exist) - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - File reading or parsing errors.
+    pub fn load(path: &str) -> anyhow::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(content) => Ok(serde_json::from_str(&content)?),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Save docking layout to the file.
+    ///
+    /// # Parameters
+    /// - `path` - given layout file path.
+    ///
+    /// # Returns
+    /// - `Ok` - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - Serialization or file writing errors.
+    pub fn save(&self, path: &str) -> anyhow::Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Check whether tab is shown in a pane or a window.
+    ///
+    /// # Parameters
+    /// - `kind` - given tab to check.
+    ///
+    /// # Returns
+    /// - `true` - if tab is shown.
+    /// - `false` - otherwise.
+    #[must_use]
+    pub fn contains(&self, kind: TabKind) -> bool {
+        self.panes.contains(&kind) || self.windows.contains(&kind)
+    }
+
+    /// Show tab selected in the tab bar.
+    ///
+    /// Pane showing the tab is focused, otherwise the tab replaces the tab of
+    /// the focused pane. Popped out tabs stay in their windows.
+    ///
+    /// # Parameters
+    /// - `kind` - given selected tab.
+    pub fn select(&mut self, kind: TabKind) {
+        if let Some(pane) = self.panes.iter().position(|&k| k == kind) {
+            self.focused = pane;
+        } else if !self.windows.contains(&kind) {
+            match self.panes.get_mut(self.focused) {
+                Some(focused) => *focused = kind,
+                None => self.panes.push(kind),
+            }
+        }
+    }
+
+    /// Apply change of the docking layout.
+    ///
+    /// # Parameters
+    /// - `action` - given change requested by user.
+    /// - `available` - given tabs allowed by the layout preset in tab bar
+    ///   order.
+    pub fn apply(&mut self, action: DockAction, available: &[TabKind]) {
+        match action {
+            DockAction::Focus(pane) => self.focused = pane,
+            DockAction::Split(pane) => {
+                if let Some(kind) = self.next_hidden(available) {
+                    let index = (pane + 1).min(self.panes.len());
+                    self.panes.insert(index, kind);
+                    self.focused = index;
+                }
+            }
+            DockAction::PopOut(pane) => {
+                if let Some(&kind) = self.panes.get(pane) {
+                    self.panes.remove(pane);
+                    self.windows.push(kind);
+                }
+            }
+            DockAction::Close(pane) => {
+                if pane < self.panes.len() {
+                    self.panes.remove(pane);
+                }
+            }
+            DockAction::Dock(kind) => {
+                self.windows.retain(|&k| k != kind);
+                self.panes.push(kind);
+                self.focused = self.panes.len() - 1;
+            }
+            DockAction::CloseWindow(kind) => {
+                self.windows.retain(|&k| k != kind);
+            }
+        }
+
+        self.retain(available);
+    }
+
+    /// Remove tabs hidden by the layout preset.
+    ///
+    /// Central area always shows at least one tab.
+    ///
+    /// # Parameters
+    /// - `available` - given tabs allowed by the layout preset in tab bar
+    ///   order.
+    pub fn retain(&mut self, available: &[TabKind]) {
+        let mut shown = Vec::new();
+        let mut is_new = |kind: &TabKind| {
+            let is_kept = available.contains(kind) && !shown.contains(kind);
+            shown.push(*kind);
+            is_kept
+        };
+
+        self.panes.retain(&mut is_new);
+        self.windows.retain(is_new);
+
+        if self.panes.is_empty()
+            && let Some(kind) = self.next_hidden(available)
+        {
+            self.panes.push(kind);
+        }
+
+        self.focused = self.focused.min(self.panes.len().saturating_sub(1));
+    }
+
+    /// Get first tab which is not shown.
+    ///
+    /// # Parameters
+    /// - `available` - given tabs allowed by the layout preset in tab bar
+    ///   order.
+    ///
+    /// # Returns
+    /// - Tab to show - if there are tabs which are not shown.
+    /// - `None` - otherwise.
+    fn next_hidden(&self, available: &[TabKind]) -> Option<TabKind> {
+        available.iter().copied().find(|&kind| !self.contains(kind))
+    }
+}
@@ -0,0 +1,591 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! GPU accelerated plots rendering.
+//!
+//! Plotted points are decimated to the first, min, max and last point of
+//! each pixel column (which preserves the rendered shape of the line) and
+//! drawn as line strips by a custom wgpu paint callback. Therefore rendering
+//! cost depends on the plot width rather than on the number of visible
+//! points.
+
+use crate::config::{AppConfig, PlotRenderer};
+use eframe::{
+    CreationContext,
+    egui_wgpu::{
+        self, CallbackResources, CallbackTrait, RenderState, ScreenDescriptor,
+    },
+    wgpu,
+};
+use egui::{Color32, Id, Rgba, Shape, Ui};
+use egui_plot::{
+    PlotBounds, PlotGeometry, PlotItem, PlotItemBase, PlotPoint, PlotTransform,
+};
+use std::{
+    collections::{HashMap, VecDeque},
+    ops::RangeInclusive,
+};
+
+/// Line strip shader. Vertices are already in normalized device coordinates
+/// of the plot frame.
+const SHADER: &str = r"
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+};
+
+@vertex
+fn vs_main(
+    @location(0) position: vec2<f32>,
+    @location(1) color: vec4<f32>,
+) -> VertexOutput {
+    var out: VertexOutput;
+    out.position = vec4<f32>(position, 0.0, 1.0);
+    out.color = color;
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    return in.color;
+}
+";
+
+/// Number of `f32` values per vertex (position and RGBA color).
+const VERTEX_FLOATS: usize = 6;
+
+/// Vertex size in bytes.
+const VERTEX_SIZE: usize = VERTEX_FLOATS * size_of::<f32>();
+
+/// Vertex buffer of the single line strip.
+struct LineStripBuffer {
+    /// GPU vertex buffer.
+    buffer: wgpu::Buffer,
+    /// Number of vertices to draw.
+    vertices: u32,
+}
+
+/// GPU plots resources stored in the wgpu renderer.
+struct GpuPlotResources {
+    /// Line strip render pipeline.
+    pipeline: wgpu::RenderPipeline,
+    /// Whether render target expects linear colors.
+    is_srgb: bool,
+    /// Vertex buffers by line identifier.
+    strips: HashMap<Id, LineStripBuffer>,
+}
+
+/// Register GPU plots resources in the wgpu renderer.
+///
+/// Plots fall back to the CPU renderer if wgpu is not available (e.g. the
+/// native window was created with another rendering backend).
+///
+/// # Parameters
+/// - `cc` - given native app creation context.
+/// - `config` - given application's configurations.
+///
+/// # Returns
+/// - Application's configurations with available plots renderer.
+#[must_use]
+pub fn init(cc: &CreationContext<'_>, mut config: AppConfig) -> AppConfig {
+    if config.display.plot.renderer != PlotRenderer::Gpu {
+        return config;
+    }
+
+    if let Some(render_state) = &cc.wgpu_render_state {
+        register(render_state);
+        log::info!("Plots are rendered by the GPU");
+    } else {
+        log::warn!("wgpu is not available, plots are rendered by the CPU");
+        config.display.plot.renderer = PlotRenderer::Cpu;
+    }
+
+    config
+}
+
+/// Create line strip render pipeline and store it in the wgpu renderer.
+///
+/// # Parameters
+/// - `render_state` - given wgpu render state.
+fn register(render_state: &RenderState) {
+    let device = &render_state.device;
+
+    let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+        label: Some("gpu_plot_shader"),
+        source: wgpu::ShaderSource::Wgsl(SHADER.into()),
+    });
+
+    let pipeline =
+        device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("gpu_plot_pipeline"),
+            layout: None,
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                compilation_options: wgpu::PipelineCompilationOptions::default(
+                ),
+                buffers: &[wgpu::VertexBufferLayout {
+                    array_stride: VERTEX_SIZE as wgpu::BufferAddress,
+                    step_mode: wgpu::VertexStepMode::Vertex,
+                    attributes: &wgpu::vertex_attr_array![
+                        0 => Float32x2,
+                        1 => Float32x4,
+                    ],
+                }],
+            },
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::LineStrip,
+                ..Default::default()
+            },
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                compilation_options: wgpu::PipelineCompilationOptions::default(
+                ),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: render_state.target_format,
+                    blend: Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+            }),
+            multiview: None,
+            cache: None,
+        });
+
+    render_state
+        .renderer
+        .write()
+        .callback_resources
+        .insert(GpuPlotResources {
+            pipeline,
+            is_srgb: render_state.target_format.is_srgb(),
+            strips: HashMap::new(),
+        });
+}
+
+/// Paint callback drawing a single line strip.
+struct LineStripCallback {
+    /// Line identifier.
+    id: Id,
+    /// Line vertices in normalized device coordinates of the plot frame.
+    vertices: Vec<[f32; 2]>,
+    /// Line color.
+    color: Color32,
+}
+
+impl CallbackTrait for LineStripCallback {
+    /// Upload line vertices to the GPU.
+    ///
+    /// # Parameters
+    /// - `device` - given wgpu device.
+    /// - `queue` - given wgpu queue.
+    /// - `callback_resources` - given resources stored in the renderer.
+    ///
+    /// # Returns
+    /// - Additional command buffers (none).
+    fn prepare(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        _screen_descriptor: &ScreenDescriptor,
+        _egui_encoder: &mut wgpu::CommandEncoder,
+        callback_resources: &mut CallbackResources,
+    ) -> Vec<wgpu::CommandBuffer> {
+        let Some(resources) = callback_resources.get_mut::<GpuPlotResources>()
+        else {
+            return Vec::new();
+        };
+
+        let color = if resources.is_srgb {
+            Rgba::from(self.color).to_array()
+        } else {
+            self.color.to_normalized_gamma_f32()
+        };
+
+        let data: Vec<u8> = self
+            .vertices
+            .iter()
+            .flat_map(|&[x, y]| [x, y].into_iter().chain(color))
+            .flat_map(f32::to_ne_bytes)
+            .collect();
+
+        let size = data.len() as wgpu::BufferAddress;
+        let strip = resources.strips.entry(self.id).or_insert_with(|| {
+            LineStripBuffer {
+                buffer: create_vertex_buffer(device, size),
+                vertices: 0,
+            }
+        });
+
+        if strip.buffer.size() < size {
+            strip.buffer = create_vertex_buffer(device, size);
+        }
+
+        queue.write_buffer(&strip.buffer, 0, &data);
+        strip.vertices = u32::try_from(self.vertices.len()).unwrap_or(0);
+
+        Vec::new()
+    }
+
+    /// Draw line strip.
+    ///
+    /// # Parameters
+    /// - `render_pass` - given egui render pass.
+    /// - `callback_resources` - given resources stored in the renderer.
+    fn paint(
+        &self,
+        _info: egui::PaintCallbackInfo,
+        render_pass: &mut wgpu::RenderPass<'static>,
+        callback_resources: &CallbackResources,
+    ) {
+        let Some(resources) = callback_resources.get::<GpuPlotResources>()
+        else {
+            return;
+        };
+
+        let Some(strip) = resources.strips.get(&self.id) else {
+            return;
+        };
+
+        if strip.vertices < 2 {
+            return;
+        }
+
+        render_pass.set_pipeline(&resources.pipeline);
+        render_pass.set_vertex_buffer(0, strip.buffer.slice(..));
+        render_pass.draw(0..strip.vertices, 0..1);
+    }
+}
+
+/// Create vertex buffer.
+///
+/// # Parameters
+/// - `device` - given wgpu device.
+/// - `size` - given min buffer size in bytes.
+///
+/// # Returns
+/// - New vertex buffer with spare capacity for the growing plots.
+fn create_vertex_buffer(
+    device: &wgpu::Device,
+    size: wgpu::BufferAddress,
+) -> wgpu::Buffer {
+    device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("gpu_plot_vertices"),
+        size: size
+            .max(VERTEX_SIZE as wgpu::BufferAddress)
+            .next_power_of_two(),
+        usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+        mapped_at_creation: false,
+    })
+}
+
+/// Plotted point with its index in history.
+#[derive(Debug, Clone, Copy)]
+struct IndexedPoint {
+    /// Point index in history.
+    index: usize,
+    /// Point X coordinate.
+    x: f64,
+    /// Point Y coordinate.
+    y: f64,
+}
+
+/// Points falling into the same pixel column.
+#[derive(Debug, Clone, Copy)]
+struct Column {
+    /// Pixel column index.
+    index: i64,
+    /// First point in column.
+    first: IndexedPoint,
+    /// Point with min value.
+    min: IndexedPoint,
+    /// Point with max value.
+    max: IndexedPoint,
+    /// Last point in column.
+    last: IndexedPoint,
+}
+
+impl Column {
+    /// Construct new pixel column.
+    ///
+    /// # Parameters
+    /// - `index` - given pixel column index.
+    /// - `point` - given first point in column.
+    ///
+    /// # Returns
+    /// - New `Column` object.
+    const fn new(index: i64, point: IndexedPoint) -> Self {
+        Self {
+            index,
+            first: point,
+            min: point,
+            max: point,
+            last: point,
+        }
+    }
+
+    /// Add point to the pixel column.
+    ///
+    /// # Parameters
+    /// - `point` - given point to add.
+    fn add(&mut self, point: IndexedPoint) {
+        if point.y < self.min.y {
+            self.min = point;
+        }
+
+        if point.y > self.max.y {
+            self.max = point;
+        }
+
+        self.last = point;
+    }
+
+    /// Get distinct column points in history order.
+    ///
+    /// # Returns
+    /// - Up to four points representing the column.
+    fn points(&self) -> impl Iterator<Item = IndexedPoint> {
+        let (lower, upper) = if self.min.index <= self.max.index {
+            (self.min, self.max)
+        } else {
+            (self.max, self.min)
+        };
+
+        let mut previous = None;
+
+        [self.first, lower, upper, self.last]
+            .into_iter()
+            .filter(move |point| {
+                let is_new = previous != Some(point.index);
+                previous = Some(point.index);
+                is_new
+            })
+    }
+}
+
+/// Decimate plotted points to the pixel columns of the plot frame.
+///
+/// # Parameters
+/// - `timestamps` - given points X coordinates (sorted).
+/// - `values` - given points Y coordinates.
+/// - `transform` - given plot coordinates transform.
+/// - `columns` - given number of pixel columns of the plot frame.
+///
+/// # Returns
+/// - Line vertices in normalized device coordinates of the plot frame.
+#[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+fn decimate(
+    timestamps: &VecDeque<f64>,
+    values: &VecDeque<f64>,
+    transform: &PlotTransform,
+    columns: usize,
+) -> Vec<[f32; 2]> {
+    let frame = *transform.frame();
+    let [x_min, _] = transform.bounds().min();
+    let [x_max, _] = transform.bounds().max();
+    let scale = columns as f64 / (x_max - x_min);
+
+    // Keeping one point on each side of the visible range, so that lines
+    // reach the frame edges.
+    let start = timestamps
+        .partition_point(|&ts| ts < x_min)
+        .saturating_sub(1);
+    let end = (timestamps.partition_point(|&ts| ts <= x_max) + 1)
+        .min(timestamps.len());
+
+    let to_ndc = |point: IndexedPoint| {
+        let pos =
+            transform.position_from_point(&PlotPoint::new(point.x, point.y));
+        let rel = (pos - frame.min) / frame.size();
+
+        [rel.x.mul_add(2.0, -1.0), rel.y.mul_add(-2.0, 1.0)]
+    };
+
+    let count = end.saturating_sub(start);
+    let mut vertices = Vec::with_capacity(columns.min(count) * 4);
+    let mut column: Option<Column> = None;
+
+    let points = timestamps
+        .iter()
+        .zip(values)
+        .enumerate()
+        .skip(start)
+        .take(count);
+
+    for (index, (&x, &y)) in points {
+        let point = IndexedPoint { index, x, y };
+
+        // Points outside of the visible range share the edge columns.
+        let column_index =
+            ((x - x_min) * scale).floor().clamp(-1.0, columns as f64) as i64;
+
+        match &mut column {
+            Some(current) if current.index == column_index => {
+                current.add(point);
+            }
+            _ => {
+                if let Some(previous) =
+                    column.replace(Column::new(column_index, point))
+                {
+                    vertices.extend(previous.points().map(to_ndc));
+                }
+            }
+        }
+    }
+
+    if let Some(last) = column {
+        vertices.extend(last.points().map(to_ndc));
+    }
+
+    vertices
+}
+
+/// Plot line rendered by the GPU.
+pub struct GpuLine<'a> {
+    /// Plot item base.
+    base: PlotItemBase,
+    /// Line identifier unique across plots.
+    id: Id,
+    /// Points X coordinates (sorted).
+    timestamps: &'a VecDeque<f64>,
+    /// Points Y coordinates.
+    values: &'a VecDeque<f64>,
+    /// Line color.
+    color: Color32,
+}
+
+impl<'a> GpuLine<'a> {
+    /// Construct new GPU plot line.
+    ///
+    /// # Parameters
+    /// - `plot_id` - given plot identifier.
+    /// - `name` - given line name displayed in the legend.
+    /// - `timestamps` - given points X coordinates (sorted).
+    /// - `values` - given points Y coordinates.
+    ///
+    /// # Returns
+    /// - New `GpuLine` object.
+    #[must_use]
+    pub fn new(
+        plot_id: &str,
+        name: &str,
+        timestamps: &'a VecDeque<f64>,
+        values: &'a VecDeque<f64>,
+    ) -> Self {
+        Self {
+            base: PlotItemBase::new(name.to_string()),
+            id: Id::new(plot_id).with(name),
+            timestamps,
+            values,
+            color: Color32::WHITE,
+        }
+    }
+
+    /// Set line color.
+    ///
+    /// # Parameters
+    /// - `color` - given line color to set.
+    ///
+    /// # Returns
+    /// - Updated `GpuLine` object.
+    #[must_use]
+    pub const fn color(mut self, color: Color32) -> Self {
+        self.color = color;
+        self
+    }
+}
+
+impl PlotItem for GpuLine<'_> {
+    /// Add line strip paint callback.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `transform` - given plot coordinates transform.
+    /// - `shapes` - given plot shapes to extend.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn shapes(
+        &self,
+        ui: &Ui,
+        transform: &PlotTransform,
+        shapes: &mut Vec<Shape>,
+    ) {
+        let frame = *transform.frame();
+        let columns = (frame.width() * ui.ctx().pixels_per_point())
+            .ceil()
+            .max(1.0) as usize;
+
+        let callback = LineStripCallback {
+            id: self.id,
+            vertices: decimate(
+                self.timestamps,
+                self.values,
+                transform,
+                columns,
+            ),
+            color: self.color,
+        };
+
+        shapes.push(Shape::Callback(egui_wgpu::Callback::new_paint_callback(
+            frame, callback,
+        )));
+    }
+
+    /// Initialize plot item (nothing to generate).
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    /// Get line color.
+    ///
+    /// # Returns
+    /// - Line color.
+    fn color(&self) -> Color32 {
+        self.color
+    }
+
+    /// Check whether line can be hovered.
+    ///
+    /// # Returns
+    /// - `false` - points under the cursor are not available after decimation.
+    fn allow_hover(&self) -> bool {
+        false
+    }
+
+    /// Get line geometry.
+    ///
+    /// # Returns
+    /// - No geometry, points are not hoverable.
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::None
+    }
+
+    /// Get line bounds.
+    ///
+    /// # Returns
+    /// - Bounds of all plotted points.
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+
+        for (&x, &y) in self.timestamps.iter().zip(self.values) {
+            bounds.extend_with(&PlotPoint::new(x, y));
+        }
+
+        bounds
+    }
+
+    /// Get plot item base.
+    ///
+    /// # Returns
+    /// - Plot item base reference.
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    /// Get mutable plot item base.
+    ///
+    /// # Returns
+    /// - Plot item base mutable reference.
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+}
@@ -0,0 +1,309 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Log directory housekeeping window user interface implementation.
+
+use crate::{
+    config::{self, RetentionConfig},
+    core::housekeeping::{self, PruneCandidate},
+};
+use eframe::epaint::Color32;
+use egui::{Context, Grid, RichText};
+use std::{
+    path::Path,
+    time::{Duration, Instant, SystemTime},
+};
+
+/// Log directory housekeeping window handler.
+#[derive(Debug, Default)]
+pub struct HousekeepingPanel {
+    /// Flag whether the window is open.
+    is_open: bool,
+    /// Recordings to prune with the selection flag.
+    candidates: Vec<(PruneCandidate, bool)>,
+    /// Time of the last retention check (`None` - not checked yet).
+    last_check: Option<Instant>,
+    /// Status message of the last operation.
+    status: Option<(String, Color32)>,
+}
+
+impl HousekeepingPanel {
+    /// Check retention policy of the log directory.
+    ///
+    /// Recordings are pruned without confirmation if `auto_prune` is set,
+    /// otherwise the window is opened to preview them.
+    ///
+    /// # Parameters
+    /// - `directory` - given log directory.
+    /// - `cfg` - given retention policy.
+    /// - `active` - given path to the active recording to keep.
+    /// - `force` - given flag whether to check before the next interval.
+    pub fn check(
+        &mut self,
+        directory: &str,
+        cfg: &RetentionConfig,
+        active: Option<&Path>,
+        force: bool,
+    ) {
+        let interval = Duration::from_secs(config::HOUSEKEEPING_INTERVAL);
+
+        if !cfg.is_enabled()
+            || self.is_open
+            || (!force
+                && self.last_check.is_some_and(|t| t.elapsed() < interval))
+        {
+            return;
+        }
+
+        self.last_check = Some(Instant::now());
+
+        if cfg.auto_prune {
+            housekeeping::enforce(directory, cfg, active);
+            return;
+        }
+
+        self.plan(directory, cfg, active);
+
+        if !self.candidates.is_empty() {
+            self.is_open = true;
+        }
+    }
+
+    /// Open window with recordings to prune.
+    ///
+    /// # Parameters
+    /// - `directory` - given log directory.
+    /// - `cfg` - given retention policy.
+    /// - `active` - given path to the active recording to keep.
+    pub fn open(
+        &mut self,
+        directory: &str,
+        cfg: &RetentionConfig,
+        active: Option<&Path>,
+    ) {
+        self.plan(directory, cfg, active);
+        self.is_open = true;
+    }
+
+    /// Display log directory housekeeping window.
+    ///
+    /// # Parameters
+    /// - `ctx` - given egui context to handle.
+    /// - `cfg` - given retention policy.
+    pub fn show(&mut self, ctx: &Context, cfg: &RetentionConfig) {
+        let mut is_open = self.is_open;
+        let mut close = false;
+
+        egui::Window::new("🗑 Log Housekeeping")
+            .open(&mut is_open)
+            .collapsible(false)
+            .show(ctx, |ui| {
+                display_policy(ui, cfg);
+                ui.separator();
+
+                if self.candidates.is_empty() {
+                    ui.label("No recordings exceed the retention policy");
+                } else {
+                    self.display_candidates(ui);
+                }
+
+                ui.separator();
+                ui.horizontal(|ui| {
+                    let selected = self.selected();
+
+                    if ui
+                        .add_enabled(
+                            !selected.is_empty(),
+                            egui::Button::new(format!(
+                                "🗑 Delete {} recordings",
+                                selected.len()
+                            )),
+                        )
+                        .on_hover_text("Delete selected recordings permanently")
+                        .clicked()
+                    {
+                        self.delete(&selected);
+                    }
+
+                    if ui.button("Later").clicked() {
+                        close = true;
+                    }
+
+                    if let Some((status, color)) = &self.status {
+                        ui.label(RichText::new(status).color(*color));
+                    }
+                });
+            });
+
+        self.is_open = is_open && !close;
+    }
+
+    /// Plan recordings to prune.
+    ///
+    /// # Parameters
+    /// - `directory` - given log directory.
+    /// - `cfg` - given retention policy.
+    /// - `active` - given path to the active recording to keep.
+    fn plan(
+        &mut self,
+        directory: &str,
+        cfg: &RetentionConfig,
+        active: Option<&Path>,
+    ) {
+        self.status = None;
+
+        match housekeeping::plan(directory, cfg, active) {
+            Ok(candidates) => {
+                self.candidates =
+                    candidates.into_iter().map(|c| (c, true)).collect();
+            }
+            Err(e) => {
+                self.candidates.clear();
+                self.status = Some((format!("Error: {e}"), Color32::LIGHT_RED));
+            }
+        }
+    }
+
+    /// Display recordings to prune with selection checkboxes.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    fn display_candidates(&mut self, ui: &mut egui::Ui) {
+        let now = SystemTime::now();
+
+        egui::ScrollArea::vertical()
+            .max_height(300.0)
+            .show(ui, |ui| {
+                Grid::new("housekeeping_candidates")
+                    .num_columns(5)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        ui.label("");
+                        ui.label(RichText::new("Recording").strong());
+                        ui.label(RichText::new("Size").strong());
+                        ui.label(RichText::new("Age").strong());
+                        ui.label(RichText::new("Reason").strong());
+                        ui.end_row();
+
+                        for (candidate, selected) in &mut self.candidates {
+                            let age = now
+                                .duration_since(candidate.modified)
+                                .unwrap_or_default();
+                            let name = candidate.path.file_name().map_or_else(
+                                || candidate.path.display().to_string(),
+                                |name| name.to_string_lossy().into_owned(),
+                            );
+
+                            ui.checkbox(selected, "");
+                            ui.label(name);
+                            ui.label(format!(
+                                "{:.1} MB",
+                                housekeeping::megabytes(candidate.size)
+                            ));
+                            ui.label(format_age(age));
+                            ui.label(candidate.reason.to_string());
+                            ui.end_row();
+                        }
+                    });
+            });
+
+        let (count, size) = self
+            .candidates
+            .iter()
+            .filter(|(_, selected)| *selected)
+            .fold((0, 0), |(count, size), (c, _)| (count + 1, size + c.size));
+
+        ui.label(format!(
+            "Selected {count} of {} recordings ({:.1} MB)",
+            self.candidates.len(),
+            housekeeping::megabytes(size)
+        ));
+    }
+
+    /// Get selected recordings.
+    ///
+    /// # Returns
+    /// - Recordings selected to delete.
+    fn selected(&self) -> Vec<PruneCandidate> {
+        self.candidates
+            .iter()
+            .filter(|(_, selected)| *selected)
+            .map(|(candidate, _)| candidate.clone())
+            .collect()
+    }
+
+    /// Delete selected recordings.
+    ///
+    /// # Parameters
+    /// - `selected` - given recordings to delete.
+    fn delete(&mut self, selected: &[PruneCandidate]) {
+        let (deleted, freed) = housekeeping::prune(selected);
+
+        self.candidates
+            .retain(|(candidate, _)| candidate.path.exists());
+
+        let status = format!(
+            "Deleted {deleted} recordings ({:.1} MB)",
+            housekeeping::megabytes(freed)
+        );
+        let color = if deleted == selected.len() {
+            Color32::LIGHT_GREEN
+        } else {
+            Color32::LIGHT_RED
+        };
+
+        self.status = Some((status, color));
+    }
+}
+
+/// Display configured retention policy.
+///
+/// # Parameters
+/// - `ui` - given screen UI handler.
+/// - `cfg` - given retention policy.
+fn display_policy(ui: &mut egui::Ui, cfg: &RetentionConfig) {
+    let limit = |value: u64, unit: &str| {
+        if value == 0 {
+            "unlimited".to_string()
+        } else {
+            format!("{value} {unit}")
+        }
+    };
+
+    Grid::new("housekeeping_policy")
+        .num_columns(2)
+        .show(ui, |ui| {
+            ui.label("Max total size:");
+            ui.label(limit(cfg.max_total_mb, "MB"));
+            ui.end_row();
+
+            ui.label("Max age:");
+            ui.label(limit(cfg.max_age_days, "days"));
+            ui.end_row();
+
+            ui.label("Min free space:");
+            ui.label(if cfg.min_free_mb == 0 {
+                "not checked".to_string()
+            } else {
+                format!("{} MB", cfg.min_free_mb)
+            });
+            ui.end_row();
+        });
+}
+
+/// Format age of the recording.
+///
+/// # Parameters
+/// - `age` - given age of the recording.
+///
+/// # Returns
+/// - Age in days or hours in string representation.
+fn format_age(age: Duration) -> String {
+    let hours = age.as_secs() / 3600;
+
+    if hours >= 24 {
+        format!("{} d", hours / 24)
+    } else {
+        format!("{hours} h")
+    }
+}
@@ -5,25 +5,228 @@
 
 use crate::ui::utils::display_metric_group;
 use crate::{
-    config::AppConfig,
-    core::StandardPayload,
-    model::{FrameContext, FrameWrapper},
+    config::{AppConfig, INSPECTOR_MAX_FRAMES},
+    core::{
+        StandardPayload,
+        burst::{ArrivalStats, BIN_EDGES_US, HISTOGRAM_BINS},
+        length::LengthAnomalies,
+    },
+    model::{BadPackets, FrameContext, FrameWrapper},
     ui::{
         TabViewer,
-        utils::{Metric, extract_readings},
+        utils::{Metric, extract_readings, format_host_time},
     },
 };
 use eframe::epaint::Color32;
-use egui::{Layout, RichText};
+use egui::{
+    ComboBox, Layout, RichText, ScrollArea,
+    text::{LayoutJob, TextFormat},
+};
+use egui_plot::{Bar, BarChart, GridMark, Plot};
 use indtp::{
-    Mode,
+    Flags, Header, Mode,
     payload::{Imu6, PayloadType},
     types::Packable,
 };
-use std::fmt::Write;
+use std::{
+    collections::{BTreeSet, VecDeque},
+    mem::offset_of,
+    ops::{Range, RangeInclusive},
+    sync::Arc,
+};
+
+/// Burstiness above which frame delivery is considered bursty.
+const BURSTY_THRESHOLD: f64 = 0.3;
+
+/// Width of the frame list in pixels.
+const FRAME_LIST_WIDTH: f32 = 240.0;
 
 /// Packet inspector tab handler.
-pub struct InspectorTab;
+#[derive(Debug, Default)]
+pub struct InspectorTab {
+    /// Last received frames, oldest first.
+    frames: VecDeque<Arc<FrameContext>>,
+    /// Frame selected by user (`None` - follow the latest frame).
+    selected: Option<Arc<FrameContext>>,
+    /// Frame field hovered in the header panel.
+    hovered_field: Option<FrameField>,
+    /// Frame list filter.
+    filter: FrameFilter,
+}
+
+/// Filter of the inspector frame list (`None` - any value).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct FrameFilter {
+    /// Sender device ID.
+    device_id: Option<u8>,
+    /// Payload type ID.
+    payload_type: Option<u8>,
+    /// Frame validity.
+    is_valid: Option<bool>,
+}
+
+impl FrameFilter {
+    /// Check whether any filter is set.
+    ///
+    /// # Returns
+    /// - `true` - if frames are filtered.
+    /// - `false` - otherwise.
+    fn is_active(self) -> bool {
+        *self != Self::default()
+    }
+
+    /// Check whether frame passes the filter.
+    ///
+    /// # Parameters
+    /// - `frame_ctx` - given frame context to check.
+    ///
+    /// # Returns
+    /// - `true` - if frame passes the filter.
+    /// - `false` - otherwise.
+    fn matches(self, frame_ctx: &FrameContext) -> bool {
+        let Some(frame) = &frame_ctx.frame else {
+            return false;
+        };
+
+        self.device_id.is_none_or(|id| id == frame.header.device_id)
+            && self
+                .payload_type
+                .is_none_or(|t| t == frame.header.payload_type)
+            && self.is_valid.is_none_or(|v| v == frame_ctx.is_valid)
+    }
+}
+
+/// Frame field highlighted in the hex dump.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FrameField {
+    /// Header preamble.
+    Preamble,
+    /// Protocol version.
+    Version,
+    /// Header flags.
+    Flags,
+    /// Frame sequence number.
+    Sequence,
+    /// Sender device ID.
+    DeviceId,
+    /// Payload type ID.
+    PayloadType,
+    /// Declared payload length.
+    PayloadLen,
+    /// Header CRC.
+    Crc,
+    /// Frame payload.
+    Payload,
+    /// Frame trailer (MAC or vendor data).
+    Trailer,
+}
+
+impl FrameField {
+    /// Frame fields in the hex dump legend order.
+    const ALL: [Self; 10] = [
+        Self::Preamble,
+        Self::Version,
+        Self::Flags,
+        Self::Sequence,
+        Self::DeviceId,
+        Self::PayloadType,
+        Self::PayloadLen,
+        Self::Crc,
+        Self::Payload,
+        Self::Trailer,
+    ];
+
+    /// Get field label.
+    ///
+    /// # Returns
+    /// - Field label string slice.
+    const fn label(self) -> &'static str {
+        match self {
+            Self::Preamble => "Preamble",
+            Self::Version => "Version",
+            Self::Flags => "Flags",
+            Self::Sequence => "Sequence",
+            Self::DeviceId => "Device ID",
+            Self::PayloadType => "Payload Type",
+            Self::PayloadLen => "Payload Length",
+            Self::Crc => "CRC",
+            Self::Payload => "Payload",
+            Self::Trailer => "Trailer",
+        }
+    }
+
+    /// Get field bytes color.
+    ///
+    /// # Returns
+    /// - Field bytes color.
+    const fn color(self) -> Color32 {
+        match self {
+            Self::Preamble => Color32::GOLD,
+            Self::Version => Color32::LIGHT_BLUE,
+            Self::Flags => Color32::LIGHT_GREEN,
+            Self::Sequence => Color32::KHAKI,
+            Self::DeviceId => Color32::ORANGE,
+            Self::PayloadType => Color32::LIGHT_RED,
+            Self::PayloadLen => Color32::from_rgb(200, 150, 255),
+            Self::Crc => Color32::from_rgb(255, 130, 200),
+            Self::Payload => Color32::LIGHT_GRAY,
+            Self::Trailer => Color32::from_rgb(0, 200, 200),
+        }
+    }
+
+    /// Get byte range of the field within the raw frame.
+    ///
+    /// # Parameters
+    /// - `header` - given frame header.
+    /// - `payload_len` - given number of payload bytes.
+    /// - `frame_len` - given number of frame bytes.
+    ///
+    /// # Returns
+    /// - Field byte range.
+    fn range(
+        self,
+        header: &Header,
+        payload_len: usize,
+        frame_len: usize,
+    ) -> Range<usize> {
+        let span = |offset: usize, size: usize| offset..offset + size;
+        let header_len = header.to_bytes().len();
+
+        match self {
+            Self::Preamble => span(
+                offset_of!(Header, preamble),
+                size_of_val(&header.preamble),
+            ),
+            Self::Version => {
+                span(offset_of!(Header, version), size_of_val(&header.version))
+            }
+            Self::Flags => {
+                span(offset_of!(Header, flags), size_of_val(&header.flags))
+            }
+            Self::Sequence => span(
+                offset_of!(Header, sequence),
+                size_of_val(&header.sequence),
+            ),
+            Self::DeviceId => span(
+                offset_of!(Header, device_id),
+                size_of_val(&header.device_id),
+            ),
+            Self::PayloadType => span(
+                offset_of!(Header, payload_type),
+                size_of_val(&header.payload_type),
+            ),
+            Self::PayloadLen => span(
+                offset_of!(Header, payload_len),
+                size_of_val(&header.payload_len),
+            ),
+            Self::Crc => {
+                span(offset_of!(Header, crc), size_of_val(&header.crc))
+            }
+            Self::Payload => span(header_len, payload_len),
+            Self::Trailer => header_len + payload_len..frame_len,
+        }
+    }
+}
 
 impl TabViewer for InspectorTab {
     /// Get tab title.
@@ -54,39 +257,316 @@ impl TabViewer for InspectorTab {
         frame_ctx: &FrameContext,
         app_cfg: &AppConfig,
     ) {
-        if let Some(frame) = &frame_ctx.frame {
-            ui.horizontal_top(|ui| {
-                let mut col_height: f32 = 0.0;
-
-                let desired_size = egui::vec2(512.0, ui.available_height());
-                ui.allocate_ui(desired_size, |ui| {
-                    if let Some(frame) = &frame_ctx.frame {
-                        col_height = display_hex_dump_column(
-                            ui,
-                            frame,
-                            frame_ctx.is_valid,
-                        );
+        ui.horizontal_top(|ui| {
+            let desired_size =
+                egui::vec2(FRAME_LIST_WIDTH, ui.available_height());
+            ui.allocate_ui(desired_size, |ui| self.display_frame_list(ui));
+
+            ui.separator();
+
+            // Selected frame stays displayed after it leaves the list, while
+            // following the stream the latest frame passing the filter is
+            // displayed.
+            let latest = if self.filter.is_active() {
+                self.frames.iter().rev().find(|f| self.filter.matches(f))
+            } else {
+                None
+            };
+
+            let frame_ctx = match (&self.selected, latest) {
+                (Some(selected), _) => selected.as_ref(),
+                (None, Some(latest)) => latest.as_ref(),
+                (None, None) if self.filter.is_active() => {
+                    ui.label(
+                        RichText::new("No frames match the filter").weak(),
+                    );
+                    return;
+                }
+                (None, None) => frame_ctx,
+            };
+
+            display_frame_details(
+                ui,
+                frame_ctx,
+                app_cfg,
+                &mut self.hovered_field,
+            );
+        });
+    }
+}
+
+impl InspectorTab {
+    /// Append received frame to the frame list.
+    ///
+    /// # Parameters
+    /// - `frame_ctx` - given received frame context.
+    pub fn add_frame(&mut self, frame_ctx: Arc<FrameContext>) {
+        self.frames.push_back(frame_ctx);
+
+        if self.frames.len() > INSPECTOR_MAX_FRAMES {
+            self.frames.pop_front();
+        }
+    }
+
+    /// Display list of the last received frames.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    fn display_frame_list(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Frames").strong());
+
+            if ui
+                .add_enabled(
+                    self.selected.is_some(),
+                    egui::Button::new("⏭ Latest"),
+                )
+                .on_hover_text("Follow the latest received frame")
+                .clicked()
+            {
+                self.selected = None;
+            }
+        });
+
+        self.display_frame_filter(ui);
+        ui.separator();
+
+        ui.label(
+            RichText::new(format!(
+                "{:>6} {:>4} {:>3} {:>5}",
+                "SEQ", "DEV", "OK", "SIZE"
+            ))
+            .monospace()
+            .weak(),
+        );
+        ui.separator();
+
+        let row_height = ui.text_style_height(&egui::TextStyle::Monospace);
+        let mut clicked = None;
+
+        // Newest frames first.
+        let visible: Vec<_> = self
+            .frames
+            .iter()
+            .rev()
+            .filter(|frame_ctx| self.filter.matches(frame_ctx))
+            .collect();
+
+        ScrollArea::vertical()
+            .id_salt("inspector_frames")
+            .auto_shrink(false)
+            .show_rows(ui, row_height, visible.len(), |ui, rows| {
+                for &frame_ctx in
+                    visible.iter().skip(rows.start).take(rows.len())
+                {
+                    let Some(frame) = &frame_ctx.frame else {
+                        continue;
+                    };
+
+                    let is_selected = self
+                        .selected
+                        .as_ref()
+                        .is_some_and(|s| Arc::ptr_eq(s, frame_ctx));
+                    let (validity, color) = if frame_ctx.is_valid {
+                        ("✔", Color32::GREEN)
+                    } else {
+                        ("✖", Color32::RED)
+                    };
+                    let header = frame.header;
+                    let (sequence, device_id) =
+                        (header.sequence.get(), header.device_id);
+                    let row = format!(
+                        "{sequence:>6} {device_id:>#4X} {validity:>3} {:>5}",
+                        frame.size
+                    );
+
+                    let response = ui.selectable_label(
+                        is_selected,
+                        RichText::new(row).monospace().color(color),
+                    );
+
+                    if response.clicked() {
+                        clicked = Some(Arc::clone(frame_ctx));
                     }
-                });
+                }
+            });
 
-                ui.add_space(8.0);
+        if clicked.is_some() {
+            self.selected = clicked;
+        }
+    }
 
-                let desired_size =
-                    egui::vec2(ui.available_width(), ui.available_height());
-                ui.allocate_ui(desired_size, |ui| {
-                    display_payload_column(ui, frame, col_height, app_cfg);
+    /// Display frame list filter controls.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    fn display_frame_filter(&mut self, ui: &mut egui::Ui) {
+        let header_values = |value: fn(&Header) -> u8| -> BTreeSet<u8> {
+            self.frames
+                .iter()
+                .filter_map(|f| {
+                    f.frame.as_ref().map(|frame| value(&frame.header))
+                })
+                .collect()
+        };
+
+        let devices = header_values(|header| header.device_id);
+        let payload_types = header_values(|header| header.payload_type);
+        let any_or_hex = |value: Option<u8>| {
+            value.map_or_else(|| "Any".to_owned(), |v| format!("{v:#04X}"))
+        };
+
+        egui::Grid::new("inspector_filter")
+            .num_columns(2)
+            .show(ui, |ui| {
+                let filter = &mut self.filter;
+
+                ui.label("Device:");
+                ComboBox::from_id_salt("inspector_filter_device")
+                    .selected_text(any_or_hex(filter.device_id))
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut filter.device_id, None, "Any");
+
+                        for id in devices {
+                            ui.selectable_value(
+                                &mut filter.device_id,
+                                Some(id),
+                                format!("{id:#04X}"),
+                            );
+                        }
+                    });
+                ui.end_row();
+
+                ui.label("Payload:");
+                ComboBox::from_id_salt("inspector_filter_payload")
+                    .selected_text(any_or_hex(filter.payload_type))
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(
+                            &mut filter.payload_type,
+                            None,
+                            "Any",
+                        );
+
+                        for t in payload_types {
+                            ui.selectable_value(
+                                &mut filter.payload_type,
+                                Some(t),
+                                format!("{t:#04X}"),
+                            );
+                        }
+                    });
+                ui.end_row();
+
+                ui.label("Validity:");
+                ui.horizontal(|ui| {
+                    ui.selectable_value(&mut filter.is_valid, None, "All");
+                    ui.selectable_value(&mut filter.is_valid, Some(true), "✔");
+                    ui.selectable_value(&mut filter.is_valid, Some(false), "✖");
                 });
+                ui.end_row();
             });
+
+        if self.filter.is_active()
+            && ui.small_button("✖ Clear filter").clicked()
+        {
+            self.filter = FrameFilter::default();
         }
     }
 }
 
-/// Display hex dump column user interface.
+/// Display details of the frame.
 ///
 /// # Parameters
 /// - `ui` - given screen UI handler.
 /// - `frame_ctx` - given frame context to handle.
+/// - `app_cfg` - given global config to handle.
+/// - `hovered` - given frame field hovered in the header panel.
+fn display_frame_details(
+    ui: &mut egui::Ui,
+    frame_ctx: &FrameContext,
+    app_cfg: &AppConfig,
+    hovered: &mut Option<FrameField>,
+) {
+    if let Some(frame) = &frame_ctx.frame {
+        ui.horizontal_top(|ui| {
+            let mut col_height: f32 = 0.0;
+
+            let desired_size = egui::vec2(512.0, ui.available_height());
+            ui.allocate_ui(desired_size, |ui| {
+                if let Some(frame) = &frame_ctx.frame {
+                    col_height = display_hex_dump_column(
+                        ui,
+                        frame,
+                        frame_ctx.is_valid,
+                        hovered,
+                    );
+                }
+            });
+
+            ui.add_space(8.0);
+
+            let desired_size =
+                egui::vec2(ui.available_width(), ui.available_height());
+            ui.allocate_ui(desired_size, |ui| {
+                display_payload_column(ui, frame, col_height, app_cfg);
+                display_rejected_packets(ui, &frame_ctx.bad_packets);
+                display_length_anomalies(ui, &frame_ctx.length_anomalies);
+                display_arrival_timing(ui, &frame_ctx.arrival);
+                display_clock_sync(ui, frame_ctx);
+            });
+        });
+    }
+}
+
+/// Row of the frame header panel.
+#[derive(Debug)]
+struct HeaderRow {
+    /// Row name.
+    name: &'static str,
+    /// Formatted field value.
+    value: String,
+    /// Measurement unit.
+    unit: Option<&'static str>,
+    /// Value color.
+    color: Option<Color32>,
+    /// Frame field highlighted in the hex dump on hover.
+    field: Option<FrameField>,
+}
+
+impl HeaderRow {
+    /// Construct new `HeaderRow` object without measurement unit.
+    ///
+    /// # Parameters
+    /// - `name` - given row name.
+    /// - `value` - given formatted field value.
+    /// - `color` - given value color.
+    /// - `field` - given frame field of the row.
+    ///
+    /// # Returns
+    /// - New `HeaderRow` object.
+    fn new(
+        name: &'static str,
+        value: impl Into<String>,
+        color: Option<Color32>,
+        field: Option<FrameField>,
+    ) -> Self {
+        Self {
+            name,
+            value: value.into(),
+            unit: None,
+            color,
+            field,
+        }
+    }
+}
+
+/// Display hex dump column user interface.
+///
+/// # Parameters
+/// - `ui` - given screen UI handler.
 /// - `frame` - given IDTP frame to handle.
+/// - `is_valid` - given flag whether frame is valid.
+/// - `hovered` - given frame field hovered in the header panel.
 ///
 /// # Returns
 /// - Column height.
@@ -94,21 +574,168 @@ fn display_hex_dump_column(
     ui: &mut egui::Ui,
     frame: &FrameWrapper,
     is_valid: bool,
+    hovered: &mut Option<FrameField>,
 ) -> f32 {
-    let header = frame.header;
+    let col1_rect = ui.with_layout(Layout::top_down(egui::Align::LEFT), |ui| {
+        ui.group(|ui| display_frame_bytes(ui, frame, hovered));
+        ui.add_space(16.0);
+
+        // Displaying protocol header info.
+        ui.group(|ui| {
+            display_header_rows(
+                ui,
+                &header_rows(&frame.header, is_valid),
+                hovered,
+            );
+        });
+    });
+
+    col1_rect.response.rect.height()
+}
+
+/// Display hex dump of the frame bytes colored by frame fields.
+///
+/// # Parameters
+/// - `ui` - given screen UI handler.
+/// - `frame` - given IDTP frame to handle.
+/// - `hovered` - given frame field hovered in the header panel.
+fn display_frame_bytes(
+    ui: &mut egui::Ui,
+    frame: &FrameWrapper,
+    hovered: &mut Option<FrameField>,
+) {
+    let mut raw_frame = Vec::with_capacity(frame.size);
+    let payload = frame.payload.as_ref();
+    let default_payload = StandardPayload::Imu6(Imu6::default());
+    let payload = payload.unwrap_or(&default_payload);
+
+    raw_frame.extend_from_slice(frame.header.to_bytes());
+    raw_frame.extend_from_slice(payload.to_bytes());
+    raw_frame.extend_from_slice(&frame.trailer);
+
+    let payload_len = payload.to_bytes().len();
+    let fields = FrameField::ALL.map(|field| {
+        (
+            field,
+            field.range(&frame.header, payload_len, raw_frame.len()),
+        )
+    });
+
+    display_hex_dump(ui, &raw_frame, &fields, *hovered);
+    ui.separator();
+
+    // Hovered field is updated after the hex dump is drawn, so that it is
+    // highlighted at the next frame.
+    *hovered = display_hex_legend(ui);
+}
+
+/// Display rows of the frame header panel.
+///
+/// # Parameters
+/// - `ui` - given screen UI handler.
+/// - `rows` - given rows to display.
+/// - `hovered` - given frame field hovered in the header panel to update.
+fn display_header_rows(
+    ui: &mut egui::Ui,
+    rows: &[HeaderRow],
+    hovered: &mut Option<FrameField>,
+) {
+    for row in rows {
+        let metric = Metric::new(row.name, &row.value, row.unit, row.color);
+        let response = metric.display(ui);
+
+        if row.field.is_some() && response.contains_pointer() {
+            *hovered = row.field;
+        }
+    }
+}
+
+/// Build rows of the frame header panel.
+///
+/// # Parameters
+/// - `header` - given frame header.
+/// - `is_valid` - given flag whether frame is valid.
+///
+/// # Returns
+/// - Header panel rows.
+fn header_rows(header: &Header, is_valid: bool) -> Vec<HeaderRow> {
     let preamble = header.preamble.to_bytes();
     let preamble = std::str::from_utf8(&preamble).unwrap_or("Unknown");
-    let version = header.version;
-    let version_major = (version >> 4) & 0x0F;
-    let version_minor = version & 0x0F;
+    let version_major = (header.version >> 4) & 0x0F;
+    let version_minor = header.version & 0x0F;
     let flags = header.flags();
-    let sequence = header.sequence;
-    let device_id = header.device_id;
-    let payload_len = header.payload_len;
-    let version = format!("v{version_major}.{version_minor}");
-    let payload_type = header.payload_type;
-    let crc = header.crc;
 
+    let (valid_label, valid_color) = if is_valid {
+        ("VALID", Some(Color32::GREEN))
+    } else {
+        ("INVALID", Some(Color32::RED))
+    };
+
+    let mut rows = vec![
+        HeaderRow::new("Frame: is", valid_label, valid_color, None),
+        HeaderRow::new("Preamble:", preamble, None, Some(FrameField::Preamble)),
+        HeaderRow::new(
+            "Version:",
+            format!("v{version_major}.{version_minor}"),
+            None,
+            Some(FrameField::Version),
+        ),
+        HeaderRow::new(
+            "Flags:",
+            format!("{:#02X}", flags.bits()),
+            None,
+            Some(FrameField::Flags),
+        ),
+    ];
+
+    rows.extend(flag_rows(flags));
+    rows.extend([
+        HeaderRow::new(
+            "Device ID:",
+            format!("{:#02X}", header.device_id),
+            None,
+            Some(FrameField::DeviceId),
+        ),
+        HeaderRow::new(
+            "Payload Type:",
+            format!("{:#02X}", header.payload_type),
+            None,
+            Some(FrameField::PayloadType),
+        ),
+        HeaderRow::new(
+            "Sequence:",
+            header.sequence.to_string(),
+            None,
+            Some(FrameField::Sequence),
+        ),
+        HeaderRow {
+            unit: Some("bytes"),
+            ..HeaderRow::new(
+                "Payload Length:",
+                header.payload_len.to_string(),
+                None,
+                Some(FrameField::PayloadLen),
+            )
+        },
+        HeaderRow::new(
+            "CRC:",
+            format!("{:#04X}", header.crc),
+            None,
+            Some(FrameField::Crc),
+        ),
+    ]);
+
+    rows
+}
+
+/// Build header panel rows decoding the frame flags.
+///
+/// # Parameters
+/// - `flags` - given frame flags.
+///
+/// # Returns
+/// - Header panel rows of the flags.
+fn flag_rows(flags: Flags) -> [HeaderRow; 4] {
     let (mode_label, mode_color) = {
         flags
             .mode()
@@ -126,13 +753,6 @@ fn display_hex_dump_column(
             })
     };
 
-    let flags_label = &format!("{:#02X}", &flags.bits());
-    let device_id_label = &format!("{device_id:#02X}");
-    let payload_type_label = &format!("{payload_type:#02X}");
-    let sequence_label = &sequence.to_string();
-    let payload_len = &payload_len.to_string();
-    let crc_label = &format!("{crc:#04X}");
-
     let (batch_label, batch_color) = if flags.is_batch() {
         ("Data aggregation mode is enabled", Some(Color32::GREEN))
     } else {
@@ -151,54 +771,14 @@ fn display_hex_dump_column(
         ("Frame has low priority", Some(Color32::GRAY))
     };
 
-    let (valid_label, valid_color) = if is_valid {
-        ("VALID", Some(Color32::GREEN))
-    } else {
-        ("INVALID", Some(Color32::RED))
-    };
-
-    let col1_rect = ui.with_layout(Layout::top_down(egui::Align::LEFT), |ui| {
-        // Displaying hex dump of the frame bytes.
-        ui.group(|ui| {
-            let mut raw_frame = Vec::with_capacity(frame.size);
-            let payload = frame.payload.as_ref();
-            let default_payload = StandardPayload::Imu6(Imu6::default());
-            let payload = payload.unwrap_or(&default_payload);
-
-            raw_frame.extend_from_slice(frame.header.to_bytes());
-            raw_frame.extend_from_slice(payload.to_bytes());
-            raw_frame.extend_from_slice(&frame.trailer);
-
-            display_hex_dump(ui, &raw_frame);
-        });
-
-        ui.add_space(16.0);
-
-        let metrics_args: Vec<Metric> = vec![
-            Metric::new("Frame: is", valid_label, None, valid_color),
-            Metric::new("Preamble:", preamble, None, None),
-            Metric::new("Version:", &version, None, None),
-            Metric::new("Flags:", flags_label, None, None),
-            Metric::new("Protocol Mode:", mode_label, None, mode_color),
-            Metric::new("Batch:", batch_label, None, batch_color),
-            Metric::new("Encryption:", encrypt_label, None, encrypt_color),
-            Metric::new("Priority:", priority_label, None, priority_color),
-            Metric::new("Device ID:", device_id_label, None, None),
-            Metric::new("Payload Type:", payload_type_label, None, None),
-            Metric::new("Sequence:", sequence_label, None, None),
-            Metric::new("Payload Length:", payload_len, Some("bytes"), None),
-            Metric::new("CRC:", crc_label, None, None),
-        ];
+    let field = Some(FrameField::Flags);
 
-        // Displaying protocol header info.
-        ui.group(|ui| {
-            for m in &metrics_args {
-                m.display(ui);
-            }
-        });
-    });
-
-    col1_rect.response.rect.height()
+    [
+        HeaderRow::new("Protocol Mode:", mode_label, mode_color, field),
+        HeaderRow::new("Batch:", batch_label, batch_color, field),
+        HeaderRow::new("Encryption:", encrypt_label, encrypt_color, field),
+        HeaderRow::new("Priority:", priority_label, priority_color, field),
+    ]
 }
 
 /// Display payload metrics column user interface.
@@ -218,6 +798,9 @@ fn display_payload_column(
     let data = extract_readings(frame);
     let pt = app_cfg.imu.payload_type;
     let imu = app_cfg.imu.metrics;
+    let formats = &app_cfg.display.format;
+    let ch = &app_cfg.channels;
+    let angle_unit = app_cfg.display.angle_unit;
 
     ui.with_layout(Layout::top_down(egui::Align::LEFT), |ui| {
         ui.group(|ui| {
@@ -236,7 +819,8 @@ fn display_payload_column(
                             ui,
                             "ACC",
                             &data[0..3],
-                            Some("m/s^2"),
+                            &["acc_x", "acc_y", "acc_z"].map(|k| ch.info(k)),
+                            formats.acc,
                         );
                     }
 
@@ -246,11 +830,20 @@ fn display_payload_column(
                         } else {
                             3
                         };
+                        let [(x, factor), (y, _), (z, _)] =
+                            ["gyr_x", "gyr_y", "gyr_z"]
+                                .map(|k| ch.info(k).in_angle_unit(angle_unit));
+                        let values: Vec<f32> = data[start..start + 3]
+                            .iter()
+                            .map(|value| value * factor)
+                            .collect();
+
                         display_metric_group(
                             ui,
                             "GYR",
-                            &data[start..start + 3],
-                            Some("rad/s"),
+                            &values,
+                            &[x, y, z],
+                            formats.gyr,
                         );
                     }
 
@@ -264,18 +857,28 @@ fn display_payload_column(
                             ui,
                             "MAG",
                             &data[start..start + 3],
-                            Some("μT"),
+                            &["mag_x", "mag_y", "mag_z"].map(|k| ch.info(k)),
+                            formats.mag,
                         );
                     }
 
                     if imu.baro {
-                        let val = format!("{:.6}", data[9]);
-                        Metric::new("BARO:", &val, Some("Pa"), None)
+                        let baro = ch.info("baro");
+                        let val = formats.baro.format(data[9]);
+                        let color = (!baro.is_valid(data[9]))
+                            .then_some(Color32::LIGHT_RED);
+                        Metric::new("BARO:", &val, Some(baro.unit), color)
                             .display(ui);
                     }
 
                     if imu.quat {
-                        display_metric_group(ui, "QUAT", &data[0..4], None);
+                        display_metric_group(
+                            ui,
+                            "QUAT",
+                            &data[0..4],
+                            &["q_w", "q_x", "q_y", "q_z"].map(|k| ch.info(k)),
+                            formats.quat,
+                        );
                     }
                 },
             );
@@ -283,6 +886,225 @@ fn display_payload_column(
     });
 }
 
+/// Display rejected packets breakdown user interface.
+///
+/// # Parameters
+/// - `ui` - given screen UI handler.
+/// - `bad_packets` - given number of rejected packets by failure reason.
+fn display_rejected_packets(ui: &mut egui::Ui, bad_packets: &BadPackets) {
+    let total = &bad_packets.total().to_string();
+    let header = &bad_packets.header.to_string();
+    let length = &bad_packets.length.to_string();
+    let integrity = &bad_packets.integrity.to_string();
+    let decryption = &bad_packets.decryption.to_string();
+    let payload = &bad_packets.payload.to_string();
+    let duplicate = &bad_packets.duplicate.to_string();
+    let stale = &bad_packets.stale.to_string();
+
+    let total_color = (bad_packets.total() > 0).then_some(Color32::YELLOW);
+
+    let metrics_args: Vec<Metric> = vec![
+        Metric::new("Total:", total, None, total_color),
+        Metric::new("Header Errors:", header, None, None),
+        Metric::new("Length Mismatches:", length, None, None),
+        Metric::new("CRC/MAC Failures:", integrity, None, None),
+        Metric::new("Decryption Failures:", decryption, None, None),
+        Metric::new("Payload Decoding Failures:", payload, None, None),
+        Metric::new("Duplicates:", duplicate, None, None),
+        Metric::new("Stale Retransmissions:", stale, None, None),
+    ];
+
+    ui.group(|ui| {
+        ui.set_width(ui.available_width());
+        ui.label(RichText::new("Rejected Packets").strong());
+        ui.separator();
+
+        for m in &metrics_args {
+            m.display(ui);
+        }
+    });
+}
+
+/// Display frame length anomalies breakdown user interface.
+///
+/// # Parameters
+/// - `ui` - given screen UI handler.
+/// - `anomalies` - given number of length anomalies by category.
+fn display_length_anomalies(ui: &mut egui::Ui, anomalies: &LengthAnomalies) {
+    let total = &anomalies.total().to_string();
+    let truncated = &anomalies.truncated.to_string();
+    let oversized = &anomalies.oversized.to_string();
+    let payload = &anomalies.payload.to_string();
+
+    let total_color = (anomalies.total() > 0).then_some(Color32::ORANGE);
+
+    let metrics_args: Vec<Metric> = vec![
+        Metric::new("Total:", total, None, total_color),
+        Metric::new("Truncated Frames:", truncated, None, None),
+        Metric::new("Oversized Frames:", oversized, None, None),
+        Metric::new("Payload Length Mismatches:", payload, None, None),
+    ];
+
+    ui.group(|ui| {
+        ui.set_width(ui.available_width());
+        ui.label(RichText::new("Length Anomalies").strong());
+        ui.separator();
+
+        for m in &metrics_args {
+            m.display(ui);
+        }
+    });
+}
+
+/// Display frame arrival timing user interface.
+///
+/// # Parameters
+/// - `ui` - given screen UI handler.
+/// - `arrival` - given statistics of the recent frame inter-arrival times.
+#[allow(clippy::cast_precision_loss)]
+fn display_arrival_timing(ui: &mut egui::Ui, arrival: &ArrivalStats) {
+    let mean = &format!("{:.3}", arrival.mean_us / 1000.0);
+    let jitter = &format!("{:.3}", arrival.std_us / 1000.0);
+    let bursts = &arrival.bursts.to_string();
+    let max_burst = &arrival.max_burst.to_string();
+
+    let (burstiness, burstiness_color) = arrival.burstiness.map_or_else(
+        || ("-".to_string(), None),
+        |b| {
+            let color = (b > BURSTY_THRESHOLD).then_some(Color32::ORANGE);
+            (format!("{b:+.2}"), color)
+        },
+    );
+
+    let metrics_args: Vec<Metric> = vec![
+        Metric::new("Mean Interval:", mean, Some("ms"), None),
+        Metric::new("Jitter:", jitter, Some("ms"), None),
+        Metric::new("Burstiness:", &burstiness, None, burstiness_color),
+        Metric::new("Bursts:", bursts, None, None),
+        Metric::new("Longest Burst:", max_burst, Some("frames"), None),
+    ];
+
+    let bars = arrival
+        .histogram
+        .iter()
+        .enumerate()
+        .map(|(i, &count)| {
+            Bar::new(i as f64, count as f64)
+                .width(0.9)
+                .name(bin_label(i))
+        })
+        .collect();
+
+    ui.group(|ui| {
+        ui.set_width(ui.available_width());
+        ui.label(RichText::new("Arrival Timing").strong())
+            .on_hover_text(
+                "Burstiness of inter-arrival times: -1 - evenly paced, 0 - \
+                 random, 1 - frames delivered in chunks (network or OS \
+                 buffering)",
+            );
+        ui.separator();
+
+        for m in &metrics_args {
+            m.display(ui);
+        }
+
+        Plot::new("arrival_histogram")
+            .height(120.0)
+            .allow_drag(false)
+            .allow_zoom(false)
+            .allow_scroll(false)
+            .y_axis_label("Frames")
+            .x_axis_formatter(bin_axis_label)
+            .show(ui, |plot_ui| {
+                plot_ui.bar_chart(
+                    BarChart::new("Inter-arrival time", bars)
+                        .color(Color32::LIGHT_BLUE),
+                );
+            });
+    });
+}
+
+/// Get inter-arrival time histogram bin label.
+///
+/// # Parameters
+/// - `bin` - given histogram bin index.
+///
+/// # Returns
+/// - Bin bounds in milliseconds.
+fn bin_label(bin: usize) -> String {
+    let to_ms = |us: u32| f64::from(us) / 1000.0;
+
+    match (bin.checked_sub(1), BIN_EDGES_US.get(bin)) {
+        (None, Some(&upper)) => format!("< {} ms", to_ms(upper)),
+        (Some(prev), Some(&upper)) => BIN_EDGES_US
+            .get(prev)
+            .map(|&lower| format!("{}-{} ms", to_ms(lower), to_ms(upper)))
+            .unwrap_or_default(),
+        (_, None) => BIN_EDGES_US
+            .last()
+            .map(|&lower| format!(">= {} ms", to_ms(lower)))
+            .unwrap_or_default(),
+    }
+}
+
+/// Format histogram axis mark as the bin bounds.
+///
+/// # Parameters
+/// - `mark` - given axis grid mark.
+/// - `_` - given axis range (unused).
+///
+/// # Returns
+/// - Axis mark label.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn bin_axis_label(mark: GridMark, _: &RangeInclusive<f64>) -> String {
+    let is_bin = mark.value.fract().abs() < f64::EPSILON
+        && (0.0..HISTOGRAM_BINS as f64).contains(&mark.value);
+
+    if is_bin {
+        bin_label(mark.value as usize)
+    } else {
+        String::new()
+    }
+}
+
+/// Display host/device clocks synchronization user interface.
+///
+/// # Parameters
+/// - `ui` - given screen UI handler.
+/// - `frame_ctx` - given frame context to handle.
+fn display_clock_sync(ui: &mut egui::Ui, frame_ctx: &FrameContext) {
+    let host_time = &frame_ctx
+        .host_time_us
+        .map_or_else(|| "-".to_string(), format_host_time);
+
+    let (offset, drift) = frame_ctx.clock.map_or_else(
+        || ("Synchronizing...".to_string(), "-".to_string()),
+        |clock| {
+            (
+                format!("{:.3}", clock.offset_us() / 1_000_000.0),
+                format!("{:+.1}", clock.drift_ppm()),
+            )
+        },
+    );
+
+    let metrics_args: Vec<Metric> = vec![
+        Metric::new("Host Time:", host_time, None, None),
+        Metric::new("Clock Offset:", &offset, Some("s"), None),
+        Metric::new("Clock Drift:", &drift, Some("ppm"), None),
+    ];
+
+    ui.group(|ui| {
+        ui.set_width(ui.available_width());
+        ui.label(RichText::new("Clock Sync").strong());
+        ui.separator();
+
+        for m in &metrics_args {
+            m.display(ui);
+        }
+    });
+}
+
 /// Convert byte to ASCII.
 ///
 /// # Parameters
@@ -304,41 +1126,109 @@ const fn to_print(ch: u8) -> char {
 /// # Parameters
 /// - `ui` - given screen UI handler.
 /// - `bytes` - given raw bytes to display
-fn display_hex_dump(ui: &mut egui::Ui, bytes: &[u8]) {
+/// - `fields` - given frame fields with their byte ranges.
+/// - `hovered` - given frame field to highlight.
+fn display_hex_dump(
+    ui: &mut egui::Ui,
+    bytes: &[u8],
+    fields: &[(FrameField, Range<usize>)],
+    hovered: Option<FrameField>,
+) {
     ui.style_mut().override_text_style = Some(egui::TextStyle::Monospace);
+    let font_id = egui::TextStyle::Monospace.resolve(ui.style());
     let bytes_per_line = 16;
 
+    let field_at = |offset: usize| {
+        fields
+            .iter()
+            .find(|(_, range)| range.contains(&offset))
+            .map(|(field, _)| *field)
+    };
+
+    // Bytes of the hovered field are inverted.
+    let format = |field: Option<FrameField>| {
+        let color = field.map_or(Color32::GRAY, FrameField::color);
+
+        if field.is_some() && field == hovered {
+            TextFormat {
+                background: color,
+                ..TextFormat::simple(font_id.clone(), Color32::BLACK)
+            }
+        } else {
+            TextFormat::simple(font_id.clone(), color)
+        }
+    };
+
     for (row_idx, chunk) in bytes.chunks(bytes_per_line).enumerate() {
-        let mut hex_line = String::with_capacity(64);
-        let mut ascii_line = String::with_capacity(32);
-        let offset_label = &format!("<{:08x}>  ", row_idx * bytes_per_line);
+        let row_offset = row_idx * bytes_per_line;
+        let mut hex_line = LayoutJob::default();
+        let mut ascii_line = LayoutJob::default();
+        let offset_label = &format!("<{row_offset:08x}>  ");
+        let plain = TextFormat::simple(font_id.clone(), Color32::WHITE);
 
         // Hex data representation.
         for i in 0..bytes_per_line {
             if let Some(b) = chunk.get(i) {
-                let _ = write!(hex_line, "{b:02x} ");
+                let field = field_at(row_offset + i);
+                hex_line.append(&format!("{b:02x}"), 0.0, format(field));
+
+                // Gap between the bytes of the same field is highlighted too.
+                let is_joined = i + 1 < chunk.len()
+                    && field_at(row_offset + i + 1) == field;
+                let gap = if is_joined {
+                    format(field)
+                } else {
+                    plain.clone()
+                };
+                hex_line.append(" ", 0.0, gap);
             } else {
-                hex_line.push_str("   ");
+                hex_line.append("   ", 0.0, plain.clone());
             }
 
             if (i + 1) % 8 == 0 {
-                hex_line.push(' ');
+                hex_line.append(" ", 0.0, plain.clone());
             }
         }
 
         // ASCII data representation.
-        ascii_line.push('|');
+        ascii_line.append("|", 0.0, plain.clone());
 
-        for &b in chunk {
-            ascii_line.push(to_print(b));
+        for (i, &b) in chunk.iter().enumerate() {
+            let field = field_at(row_offset + i);
+            ascii_line.append(&to_print(b).to_string(), 0.0, format(field));
         }
 
-        ascii_line.push('|');
+        ascii_line.append("|", 0.0, plain);
 
         ui.horizontal(|ui| {
             ui.label(RichText::new(offset_label).color(Color32::WHITE));
             ui.label(hex_line);
-            ui.label(RichText::new(ascii_line).color(Color32::WHITE));
+            ui.label(ascii_line);
         });
     }
 }
+
+/// Display legend of the hex dump colors.
+///
+/// # Parameters
+/// - `ui` - given screen UI handler.
+///
+/// # Returns
+/// - Frame field hovered in the legend.
+fn display_hex_legend(ui: &mut egui::Ui) -> Option<FrameField> {
+    let mut hovered = None;
+
+    ui.horizontal_wrapped(|ui| {
+        for field in FrameField::ALL {
+            let label = RichText::new(format!("■ {}", field.label()))
+                .color(field.color())
+                .small();
+
+            if ui.label(label).contains_pointer() {
+                hovered = Some(field);
+            }
+        }
+    });
+
+    hovered
+}
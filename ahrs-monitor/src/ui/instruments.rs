@@ -0,0 +1,463 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Dashboard instrument grid and flight instruments painting.
+//!
+//! Tiles are packed in placement order into the first free cell of the grid
+//! wide enough for them, so that removing or resizing the tile lets the
+//! following tiles flow into the freed space.
+
+use crate::{
+    config::{GRID_COLUMNS, InstrumentTile},
+    core::mesh::Mesh,
+};
+use eframe::epaint::{self, Color32, Shape, Stroke};
+use egui::{Align2, FontId, Painter, Pos2, Rect, Sense, Vec2, vec2};
+use tsilna_nav::math::{Quat32, na::Vector3};
+
+/// Sky color of the artificial horizon.
+const SKY_COLOR: Color32 = Color32::from_rgb(40, 110, 190);
+
+/// Ground color of the artificial horizon.
+const GROUND_COLOR: Color32 = Color32::from_rgb(130, 85, 40);
+
+/// Aircraft symbol color.
+const SYMBOL_COLOR: Color32 = Color32::YELLOW;
+
+/// Pitch range visible from the center to the edge of the horizon in degrees.
+const HORIZON_PITCH_RANGE: f32 = 40.0;
+
+/// Pitch ladder step in degrees.
+const PITCH_LADDER_STEP: i32 = 10;
+
+/// Compass dial tick step in degrees.
+const COMPASS_TICK_STEP: i32 = 5;
+
+/// Compass dial label step in degrees.
+const COMPASS_LABEL_STEP: i32 = 30;
+
+/// Compass cardinal and intercardinal marks with their bearings in degrees.
+const COMPASS_CARDINALS: [(i32, &str); 8] = [
+    (0, "N"),
+    (45, "NE"),
+    (90, "E"),
+    (135, "SE"),
+    (180, "S"),
+    (225, "SW"),
+    (270, "W"),
+    (315, "NW"),
+];
+
+/// Vehicle model radius relative to the smaller side of the widget.
+const MODEL_SCALE: f32 = 0.3;
+
+/// Distance from the camera to the model center in model radii.
+const CAMERA_DISTANCE: f32 = 4.0;
+
+/// Ambient light intensity of the model faces.
+const AMBIENT_LIGHT: f32 = 0.3;
+
+/// Placement of the tile in the instrument grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TilePlacement {
+    /// First grid column of the tile.
+    pub column: usize,
+    /// First grid row of the tile.
+    pub row: usize,
+    /// Number of grid columns spanned by the tile.
+    pub columns: usize,
+    /// Number of grid rows spanned by the tile.
+    pub rows: usize,
+}
+
+impl TilePlacement {
+    /// Get tile area on the screen.
+    ///
+    /// # Parameters
+    /// - `origin` - given top left corner of the grid.
+    /// - `cell` - given grid cell size.
+    /// - `spacing` - given spacing between the cells.
+    ///
+    /// # Returns
+    /// - Screen rectangle of the tile.
+    #[allow(clippy::cast_precision_loss)]
+    #[must_use]
+    pub fn rect(&self, origin: Pos2, cell: Vec2, spacing: f32) -> Rect {
+        let span = |count: usize, size: f32| {
+            (count as f32).mul_add(size + spacing, -spacing)
+        };
+
+        let min = origin
+            + vec2(
+                self.column as f32 * (cell.x + spacing),
+                self.row as f32 * (cell.y + spacing),
+            );
+
+        Rect::from_min_size(
+            min,
+            vec2(span(self.columns, cell.x), span(self.rows, cell.y)),
+        )
+    }
+}
+
+/// Pack tiles into the instrument grid.
+///
+/// # Parameters
+/// - `tiles` - given instrument tiles in placement order.
+///
+/// # Returns
+/// - Placements of the tiles in the same order.
+#[must_use]
+pub fn pack(tiles: &[InstrumentTile]) -> Vec<TilePlacement> {
+    let width = usize::from(GRID_COLUMNS);
+    let mut occupied: Vec<Vec<bool>> = Vec::new();
+    let mut placements = Vec::with_capacity(tiles.len());
+
+    for tile in tiles {
+        let columns = usize::from(tile.columns).clamp(1, width);
+        let rows = usize::from(tile.rows).max(1);
+
+        let is_free = |occupied: &[Vec<bool>], row: usize, column: usize| {
+            (row..row + rows).all(|r| {
+                occupied.get(r).is_none_or(|cells| {
+                    cells
+                        .iter()
+                        .skip(column)
+                        .take(columns)
+                        .all(|&is_taken| !is_taken)
+                })
+            })
+        };
+
+        // Row after the last occupied one is always free.
+        let placement = (0..=occupied.len())
+            .flat_map(|row| (0..=width - columns).map(move |col| (row, col)))
+            .find(|&(row, column)| is_free(&occupied, row, column))
+            .map(|(row, column)| TilePlacement {
+                column,
+                row,
+                columns,
+                rows,
+            })
+            .unwrap_or(TilePlacement {
+                column: 0,
+                row: occupied.len(),
+                columns,
+                rows,
+            });
+
+        if occupied.len() < placement.row + rows {
+            occupied.resize(placement.row + rows, vec![false; width]);
+        }
+
+        for cells in occupied.iter_mut().skip(placement.row).take(rows) {
+            for is_taken in
+                cells.iter_mut().skip(placement.column).take(columns)
+            {
+                *is_taken = true;
+            }
+        }
+
+        placements.push(placement);
+    }
+
+    placements
+}
+
+/// Display artificial horizon.
+///
+/// # Parameters
+/// - `ui` - given screen UI handler.
+/// - `roll` - given roll angle in radians.
+/// - `pitch` - given pitch angle in radians.
+pub fn display_horizon(ui: &mut egui::Ui, roll: f32, pitch: f32) {
+    let (rect, _) = ui.allocate_at_least(ui.available_size(), Sense::hover());
+    let painter = ui.painter().with_clip_rect(rect);
+    let center = rect.center();
+    let radius = rect.width().min(rect.height()) * 0.5;
+    let px_per_degree = radius / HORIZON_PITCH_RANGE;
+
+    // Horizon direction and normal pointing to the ground on the screen, where
+    // Y-axis points downwards and right wing down raises right end of the
+    // horizon.
+    let dir = vec2(roll.cos(), -roll.sin());
+    let down = vec2(roll.sin(), roll.cos());
+    let horizon = center + down * pitch.to_degrees() * px_per_degree;
+    let extent = rect.size().length();
+
+    painter.rect_filled(rect, 0.0, SKY_COLOR);
+    painter.add(Shape::convex_polygon(
+        vec![
+            horizon - dir * extent,
+            horizon + dir * extent,
+            horizon + dir * extent + down * extent,
+            horizon - dir * extent + down * extent,
+        ],
+        GROUND_COLOR,
+        Stroke::NONE,
+    ));
+
+    // Pitch ladder.
+    let ladder_stroke = Stroke::new(1.0, Color32::WHITE);
+    let max_step = 90 / PITCH_LADDER_STEP;
+
+    for step in -max_step..=max_step {
+        let degrees = step * PITCH_LADDER_STEP;
+
+        #[allow(clippy::cast_precision_loss)]
+        let offset = degrees as f32 * px_per_degree;
+        let mid = horizon - down * offset;
+        let half_width = if degrees == 0 { radius } else { radius * 0.25 };
+
+        painter.line_segment(
+            [mid - dir * half_width, mid + dir * half_width],
+            ladder_stroke,
+        );
+
+        if degrees != 0 {
+            painter.text(
+                mid + dir * (half_width + 12.0),
+                Align2::CENTER_CENTER,
+                degrees.to_string(),
+                FontId::proportional(10.0),
+                Color32::WHITE,
+            );
+        }
+    }
+
+    // Fixed aircraft symbol.
+    let symbol_stroke = Stroke::new(3.0, SYMBOL_COLOR);
+    let wing = radius * 0.35;
+    let gap = radius * 0.1;
+
+    painter.line_segment(
+        [center - vec2(wing, 0.0), center - vec2(gap, 0.0)],
+        symbol_stroke,
+    );
+    painter.line_segment(
+        [center + vec2(gap, 0.0), center + vec2(wing, 0.0)],
+        symbol_stroke,
+    );
+    painter.circle_filled(center, 3.0, SYMBOL_COLOR);
+
+    painter.text(
+        rect.left_bottom() + vec2(6.0, -6.0),
+        Align2::LEFT_BOTTOM,
+        format!(
+            "R {:+.1}°  P {:+.1}°",
+            roll.to_degrees(),
+            pitch.to_degrees()
+        ),
+        FontId::monospace(12.0),
+        Color32::WHITE,
+    );
+}
+
+/// Display heading indicator.
+///
+/// Compass rose rotates under the fixed lubber line at the top, so that the
+/// current heading is read at the top of the dial like on the aircraft
+/// heading indicator.
+///
+/// # Parameters
+/// - `ui` - given screen UI handler.
+/// - `heading` - given heading in degrees in range [0, 360).
+/// - `reference` - given heading reference label (e.g. "MAG" or "TRUE").
+pub fn display_compass(ui: &mut egui::Ui, heading: f32, reference: &str) {
+    let (rect, _) = ui.allocate_at_least(ui.available_size(), Sense::hover());
+    let painter = ui.painter().with_clip_rect(rect);
+    let center = rect.center() + vec2(0.0, 8.0);
+    let radius = (rect.width().min(rect.height() - 16.0) * 0.45).max(1.0);
+
+    let dial_stroke = Stroke::new(1.5, Color32::GRAY);
+    painter.circle_stroke(center, radius, dial_stroke);
+
+    // Screen direction of the bearing on the heading-up dial.
+    let direction = |bearing: f32| {
+        let angle = (bearing - heading).to_radians();
+        vec2(angle.sin(), -angle.cos())
+    };
+
+    for step in 0..360 / COMPASS_TICK_STEP {
+        let degrees = step * COMPASS_TICK_STEP;
+
+        #[allow(clippy::cast_precision_loss)]
+        let dir = direction(degrees as f32);
+        let length = if degrees % COMPASS_LABEL_STEP == 0 {
+            0.15
+        } else if degrees % (2 * COMPASS_TICK_STEP) == 0 {
+            0.1
+        } else {
+            0.05
+        };
+
+        painter.line_segment(
+            [
+                center + dir * radius * (1.0 - length),
+                center + dir * radius,
+            ],
+            dial_stroke,
+        );
+    }
+
+    // Degrees between the cardinal marks.
+    for step in 0..360 / COMPASS_LABEL_STEP {
+        let degrees = step * COMPASS_LABEL_STEP;
+
+        if degrees % 90 != 0 {
+            #[allow(clippy::cast_precision_loss)]
+            let dir = direction(degrees as f32);
+
+            painter.text(
+                center + dir * radius * 0.7,
+                Align2::CENTER_CENTER,
+                degrees.to_string(),
+                FontId::proportional(10.0),
+                Color32::GRAY,
+            );
+        }
+    }
+
+    // Intercardinal marks are placed inside, so that they do not overlap
+    // the degrees.
+    for (bearing, name) in COMPASS_CARDINALS {
+        let is_cardinal = bearing % 90 == 0;
+        let (distance, size, color) = match (is_cardinal, bearing) {
+            (true, 0) => (0.7, 16.0, Color32::LIGHT_RED),
+            (true, _) => (0.7, 16.0, Color32::WHITE),
+            (false, _) => (0.5, 11.0, Color32::LIGHT_GRAY),
+        };
+
+        #[allow(clippy::cast_precision_loss)]
+        let dir = direction(bearing as f32);
+
+        painter.text(
+            center + dir * radius * distance,
+            Align2::CENTER_CENTER,
+            name,
+            FontId::proportional(size),
+            color,
+        );
+    }
+
+    // Fixed lubber line and aircraft symbol.
+    let top = center - vec2(0.0, radius);
+
+    painter.add(Shape::convex_polygon(
+        vec![top, top + vec2(-6.0, -10.0), top + vec2(6.0, -10.0)],
+        SYMBOL_COLOR,
+        Stroke::NONE,
+    ));
+
+    let symbol_stroke = Stroke::new(3.0, SYMBOL_COLOR);
+    let span = radius * 0.3;
+
+    painter.line_segment(
+        [center - vec2(0.0, span), center + vec2(0.0, span)],
+        symbol_stroke,
+    );
+    painter.line_segment(
+        [
+            center - vec2(span * 0.8, 0.0),
+            center + vec2(span * 0.8, 0.0),
+        ],
+        symbol_stroke,
+    );
+    painter.line_segment(
+        [
+            center + vec2(-span * 0.4, span),
+            center + vec2(span * 0.4, span),
+        ],
+        symbol_stroke,
+    );
+
+    painter.text(
+        rect.left_top() + vec2(6.0, 4.0),
+        Align2::LEFT_TOP,
+        format!("{heading:05.1}°"),
+        FontId::monospace(14.0),
+        Color32::WHITE,
+    );
+    painter.text(
+        rect.right_top() + vec2(-6.0, 4.0),
+        Align2::RIGHT_TOP,
+        reference,
+        FontId::monospace(12.0),
+        Color32::LIGHT_GRAY,
+    );
+}
+
+/// Paint shaded vehicle model.
+///
+/// Faces are drawn back to front (painter's algorithm) with perspective
+/// projection and lit from both sides, since winding of the model files is
+/// not reliable.
+///
+/// # Parameters
+/// - `painter` - given painter of the widget.
+/// - `rect` - given widget area.
+/// - `mesh` - given vehicle model.
+/// - `rotation` - given attitude of the vehicle.
+/// - `color` - given vehicle model color.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+pub fn paint_model(
+    painter: &Painter,
+    rect: Rect,
+    mesh: &Mesh,
+    rotation: &Quat32,
+    color: Color32,
+) {
+    let center = rect.center();
+    let scale = rect.width().min(rect.height()) * MODEL_SCALE;
+    let light = Vector3::new(0.3, 0.5, 0.8).normalize();
+
+    // Camera looks along negative Z-axis, in egui Y-axis points downwards.
+    let project = |v: &Vector3<f32>| {
+        let perspective = CAMERA_DISTANCE / (CAMERA_DISTANCE - v.z);
+        center + vec2(v.x, -v.y) * scale * perspective
+    };
+
+    let mut faces: Vec<(f32, [Vector3<f32>; 3])> = mesh
+        .triangles
+        .iter()
+        .map(|triangle| {
+            let [a, b, c] = triangle.map(|v| rotation * v);
+            (a.z + b.z + c.z, [a, b, c])
+        })
+        .collect();
+
+    // Farthest faces first.
+    faces.sort_unstable_by(|(lhs, _), (rhs, _)| lhs.total_cmp(rhs));
+
+    let mut shape = epaint::Mesh::default();
+
+    for (_, [a, b, c]) in &faces {
+        let Some(normal) = (b - a).cross(&(c - a)).try_normalize(f32::EPSILON)
+        else {
+            continue;
+        };
+
+        let intensity = (1.0 - AMBIENT_LIGHT)
+            .mul_add(normal.dot(&light).abs(), AMBIENT_LIGHT);
+        let shade = |channel: u8| (f32::from(channel) * intensity) as u8;
+        let shaded = Color32::from_rgb(
+            shade(color.r()),
+            shade(color.g()),
+            shade(color.b()),
+        );
+
+        let first = shape.vertices.len() as u32;
+
+        for vertex in [a, b, c] {
+            shape.colored_vertex(project(vertex), shaded);
+        }
+
+        shape.add_triangle(first, first + 1, first + 2);
+    }
+
+    painter.add(Shape::mesh(shape));
+}
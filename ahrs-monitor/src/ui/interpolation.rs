@@ -0,0 +1,127 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Attitude interpolation between received samples for rendering.
+//!
+//! Each new sample starts slerp from the currently displayed attitude to the
+//! received one over the expected sample interval, so that the attitude
+//! widgets move at display frame rate instead of jumping at packet rate.
+//! Interval is averaged over the sample arrivals seen by the user interface,
+//! so that bursty delivery does not make the motion jerky. Only rendering is
+//! affected, received and logged values stay raw.
+
+use tsilna_nav::math::Quat32;
+
+/// Weight of the latest arrival interval in the averaged interval.
+const INTERVAL_WEIGHT: f32 = 0.1;
+
+/// Min interpolation duration in seconds.
+const MIN_DURATION: f32 = 0.004;
+
+/// Max interpolation duration in seconds (longer gaps are not animated).
+const MAX_DURATION: f32 = 0.25;
+
+/// Interpolation from the displayed attitude to the latest sample.
+#[derive(Debug, Clone, Copy)]
+struct Segment {
+    /// Attitude displayed when the sample was received.
+    from: Quat32,
+    /// Received attitude.
+    to: Quat32,
+    /// Time of the sample arrival in seconds.
+    start: f64,
+    /// Interpolation duration in seconds.
+    duration: f32,
+}
+
+impl Segment {
+    /// Get interpolation progress.
+    ///
+    /// # Parameters
+    /// - `now` - given current time in seconds.
+    ///
+    /// # Returns
+    /// - Progress in range [0, 1].
+    #[allow(clippy::cast_possible_truncation)]
+    fn progress(&self, now: f64) -> f32 {
+        ((now - self.start) as f32 / self.duration).clamp(0.0, 1.0)
+    }
+
+    /// Get interpolated attitude.
+    ///
+    /// # Parameters
+    /// - `now` - given current time in seconds.
+    ///
+    /// # Returns
+    /// - Attitude between displayed and received ones.
+    fn sample(&self, now: f64) -> Quat32 {
+        // Opposite attitudes have no unique interpolation path.
+        self.from
+            .try_slerp(&self.to, self.progress(now), f32::EPSILON)
+            .unwrap_or(self.to)
+    }
+}
+
+/// Interpolator of the attitude between received samples.
+#[derive(Debug, Default)]
+pub struct AttitudeInterpolator {
+    /// Current interpolation (`None` - no samples yet).
+    segment: Option<Segment>,
+    /// Sensor timestamp of the latest sample in microseconds.
+    last_timestamp: Option<u32>,
+    /// Arrival time of the latest sample in seconds.
+    last_arrival: Option<f64>,
+    /// Averaged interval between sample arrivals in seconds.
+    interval: Option<f32>,
+}
+
+impl AttitudeInterpolator {
+    /// Update interpolator with the latest received sample.
+    ///
+    /// # Parameters
+    /// - `received` - given latest received attitude.
+    /// - `timestamp` - given sensor timestamp of the sample in microseconds.
+    /// - `now` - given current time in seconds.
+    ///
+    /// # Returns
+    /// - Attitude to display.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn update(
+        &mut self,
+        received: Quat32,
+        timestamp: u32,
+        now: f64,
+    ) -> Quat32 {
+        if self.last_timestamp != Some(timestamp) {
+            let displayed = self.segment.map_or(received, |s| s.sample(now));
+
+            if let Some(last) = self.last_arrival {
+                let elapsed = (now - last) as f32;
+
+                self.interval =
+                    Some(self.interval.map_or(elapsed, |interval| {
+                        INTERVAL_WEIGHT.mul_add(elapsed - interval, interval)
+                    }));
+            }
+
+            self.segment = Some(Segment {
+                from: displayed,
+                to: received,
+                start: now,
+                duration: self
+                    .interval
+                    .unwrap_or(MIN_DURATION)
+                    .clamp(MIN_DURATION, MAX_DURATION),
+            });
+            self.last_timestamp = Some(timestamp);
+            self.last_arrival = Some(now);
+        }
+
+        self.segment.map_or(received, |s| s.sample(now))
+    }
+
+    /// Reset interpolator state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
@@ -0,0 +1,112 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Plot legend state.
+//!
+//! Traces are hidden and shown by clicking their plot legend entries. Hidden
+//! traces of every plot are shared by all plotters through the egui context,
+//! stored on exit and restored at the next start.
+
+use egui::{Context, Id};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    io::ErrorKind,
+};
+
+/// Identifier of the hidden traces in the egui context memory.
+const HIDDEN_TRACES_ID: &str = "hidden_traces";
+
+/// Labels of the hidden traces by plot identifier.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct HiddenTraces(BTreeMap<String, BTreeSet<String>>);
+
+impl HiddenTraces {
+    /// Load hidden traces from the file.
+    ///
+    /// # Parameters
+    /// - `path` - given hidden traces file path.
+    ///
+    /// # Returns
+    /// - Stored hidden traces (none if file does not exist) - in case of
+    ///   success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - File reading or parsing errors.
+    pub fn load(path: &str) -> anyhow::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(content) => Ok(serde_json::from_str(&content)?),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Save hidden traces to the file.
+    ///
+    /// # Parameters
+    /// - `path` - given hidden traces file path.
+    ///
+    /// # Returns
+    /// - `Ok` - in case of success.
+    /// - `Err` - otherwise.
+    ///
+    /// # Errors
+    /// - Serialization or file writing errors.
+    pub fn save(&self, path: &str) -> anyhow::Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Synchronize with the hidden traces shared with the plots.
+    ///
+    /// Hidden traces are shared on the first call and taken back from the
+    /// plots on the next ones.
+    ///
+    /// # Parameters
+    /// - `ctx` - given egui context to handle.
+    pub fn sync(&mut self, ctx: &Context) {
+        let id = Id::new(HIDDEN_TRACES_ID);
+
+        match ctx.data(|d| d.get_temp::<Self>(id)) {
+            Some(shared) => *self = shared,
+            None => ctx.data_mut(|d| d.insert_temp(id, self.clone())),
+        }
+    }
+
+    /// Get hidden traces of the plot.
+    ///
+    /// # Parameters
+    /// - `ctx` - given egui context to handle.
+    /// - `plot_id` - given plot identifier.
+    ///
+    /// # Returns
+    /// - Labels of the hidden traces.
+    #[must_use]
+    pub fn of_plot(ctx: &Context, plot_id: &str) -> BTreeSet<String> {
+        ctx.data(|d| d.get_temp::<Self>(Id::new(HIDDEN_TRACES_ID)))
+            .and_then(|traces| traces.0.get(plot_id).cloned())
+            .unwrap_or_default()
+    }
+
+    /// Set hidden traces of the plot.
+    ///
+    /// # Parameters
+    /// - `ctx` - given egui context to handle.
+    /// - `plot_id` - given plot identifier.
+    /// - `hidden` - given labels of the hidden traces.
+    pub fn set_plot(ctx: &Context, plot_id: &str, hidden: BTreeSet<String>) {
+        ctx.data_mut(|d| {
+            let traces =
+                d.get_temp_mut_or_default::<Self>(Id::new(HIDDEN_TRACES_ID));
+
+            if hidden.is_empty() {
+                traces.0.remove(plot_id);
+            } else {
+                traces.0.insert(plot_id.to_owned(), hidden);
+            }
+        });
+    }
+}
@@ -0,0 +1,554 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Magnetometer ellipsoid calibration panel user interface implementation.
+//!
+//! Collected readings are painted as the 3D point cloud rotated by dragging,
+//! together with the wireframe of the fitted ellipsoid, so that the user sees
+//! which orientations are still missing.
+
+use crate::core::mag_calibration::{
+    EllipsoidFit, MAX_SAMPLES, MagCalibration, MagSampler,
+};
+use eframe::epaint::{Color32, Stroke};
+use egui::{
+    Align2, Button, FontId, Grid, Pos2, ProgressBar, Rect, RichText, Sense,
+    vec2,
+};
+use tsilna_nav::math::na::Vector3;
+
+/// Number of new samples after which the ellipsoid is fitted again.
+const REFIT_INTERVAL: usize = 25;
+
+/// Min coverage of the orientations for the good calibration.
+const GOOD_COVERAGE: f32 = 0.75;
+
+/// Max residual of the calibrated samples for the good calibration.
+const GOOD_RESIDUAL: f32 = 0.02;
+
+/// Initial view azimuth in radians.
+const VIEW_YAW: f32 = 0.6;
+
+/// Initial view elevation in radians.
+const VIEW_PITCH: f32 = 0.4;
+
+/// View rotation per dragged pixel in radians.
+const DRAG_SENSITIVITY: f32 = 0.01;
+
+/// Min height of the point cloud view in pixels.
+const MIN_VIEW_SIZE: f32 = 240.0;
+
+/// Radius of the sample point in pixels.
+const POINT_RADIUS: f32 = 2.0;
+
+/// Number of segments of each wireframe circle.
+const WIREFRAME_SEGMENTS: usize = 48;
+
+/// Angular step between wireframe circles in degrees.
+const WIREFRAME_STEP: f32 = 30.0;
+
+/// Wireframe color.
+const WIREFRAME_COLOR: Color32 = Color32::from_rgb(90, 90, 110);
+
+/// Axes colors (x, y, z).
+const AXES_COLORS: [Color32; 3] = [
+    Color32::from_rgb(220, 80, 80),
+    Color32::from_rgb(80, 200, 80),
+    Color32::from_rgb(80, 140, 240),
+];
+
+/// Magnetometer ellipsoid calibration panel handler.
+#[derive(Debug)]
+pub struct MagCalibrationPanel {
+    /// Collected magnetometer samples.
+    sampler: MagSampler,
+    /// Flag whether received readings are collected.
+    is_collecting: bool,
+    /// Latest magnetometer readings (x, y, z).
+    mag: Option<[f32; 3]>,
+    /// Ellipsoid fitted to the collected samples.
+    fit: Option<EllipsoidFit>,
+    /// Status message of the last operation.
+    status: Option<(String, Color32)>,
+    /// View azimuth in radians.
+    yaw: f32,
+    /// View elevation in radians.
+    pitch: f32,
+    /// Flag whether samples are shown calibrated by the fitted ellipsoid.
+    is_calibrated_shown: bool,
+}
+
+impl Default for MagCalibrationPanel {
+    /// Get panel without collected samples.
+    ///
+    /// # Returns
+    /// - New `MagCalibrationPanel` object.
+    fn default() -> Self {
+        Self {
+            sampler: MagSampler::default(),
+            is_collecting: false,
+            mag: None,
+            fit: None,
+            status: None,
+            yaw: VIEW_YAW,
+            pitch: VIEW_PITCH,
+            is_calibrated_shown: false,
+        }
+    }
+}
+
+impl MagCalibrationPanel {
+    /// Add new magnetometer readings.
+    ///
+    /// # Parameters
+    /// - `mag` - given vector of magnetometer readings in sensor axes.
+    pub fn add_data(&mut self, mag: Vector3<f32>) {
+        self.mag = Some(mag.into());
+
+        if !self.is_collecting || !self.sampler.add(mag) {
+            return;
+        }
+
+        if self.sampler.len() >= MAX_SAMPLES {
+            self.is_collecting = false;
+            self.refit();
+        } else if self.sampler.len() % REFIT_INTERVAL == 0 {
+            self.refit();
+        }
+    }
+
+    /// Fit ellipsoid to the collected samples.
+    fn refit(&mut self) {
+        match self.sampler.fit() {
+            Ok(fit) => {
+                self.fit = Some(fit);
+                self.status = None;
+            }
+            Err(e) => {
+                self.fit = None;
+                self.status = Some((format!("Error: {e}"), Color32::ORANGE));
+            }
+        }
+    }
+
+    /// Display collection controls.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    fn display_controls(&mut self, ui: &mut egui::Ui) {
+        ui.label(
+            "Rotate the device slowly through all orientations away from \
+             magnetic disturbances while collecting.",
+        );
+
+        if let Some([x, y, z]) = self.mag {
+            ui.label(format!("Magnetometer: x={x:.3} y={y:.3} z={z:.3}"));
+        } else {
+            ui.label(
+                RichText::new("Payload contains no magnetometer readings")
+                    .color(Color32::GRAY),
+            );
+        }
+
+        ui.horizontal(|ui| {
+            if self.is_collecting {
+                if ui.button("⏹ Stop").clicked() {
+                    self.is_collecting = false;
+                    self.refit();
+                }
+            } else if ui
+                .add_enabled(self.mag.is_some(), Button::new("🧲 Collect"))
+                .clicked()
+            {
+                self.is_collecting = true;
+                self.status = None;
+            }
+
+            if ui.button("⟲ Restart").clicked() {
+                *self = Self {
+                    mag: self.mag,
+                    ..Self::default()
+                };
+            }
+
+            ui.add_enabled(
+                self.fit.is_some(),
+                egui::Checkbox::new(
+                    &mut self.is_calibrated_shown,
+                    "Show calibrated",
+                ),
+            )
+            .on_hover_text("Show samples mapped onto the sphere by the fit");
+        });
+
+        if let Some((status, color)) = &self.status {
+            ui.label(RichText::new(status).color(*color));
+        }
+    }
+
+    /// Display fit quality metrics.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    fn display_metrics(&self, ui: &mut egui::Ui) {
+        let coverage = self.sampler.coverage();
+        let quality = |is_good: bool| {
+            if is_good {
+                Color32::LIGHT_GREEN
+            } else {
+                Color32::ORANGE
+            }
+        };
+
+        Grid::new("mag_calibration_metrics")
+            .num_columns(2)
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("Samples");
+                ui.label(format!("{} / {MAX_SAMPLES}", self.sampler.len()));
+                ui.end_row();
+
+                ui.label("Coverage");
+                ui.add(
+                    ProgressBar::new(coverage)
+                        .desired_width(160.0)
+                        .fill(
+                            quality(coverage >= GOOD_COVERAGE)
+                                .gamma_multiply(0.5),
+                        )
+                        .text(format!("{:.0} %", coverage * 100.0)),
+                );
+                ui.end_row();
+
+                let Some(fit) = &self.fit else {
+                    return;
+                };
+
+                ui.label("Field strength");
+                ui.label(format!("{:.3}", fit.field));
+                ui.end_row();
+
+                ui.label("Residual");
+                ui.label(
+                    RichText::new(format!("{:.2} %", fit.residual * 100.0))
+                        .color(quality(fit.residual <= GOOD_RESIDUAL)),
+                )
+                .on_hover_text(
+                    "RMS deviation of the calibrated samples magnitude from \
+                     the field strength",
+                );
+                ui.end_row();
+
+                ui.label("Axes ratio");
+                ui.label(format!("{:.3}", fit.axes_ratio())).on_hover_text(
+                    "Longest to shortest semi-axis of the ellipsoid \
+                         (1.0 - no soft-iron distortion)",
+                );
+                ui.end_row();
+            });
+    }
+
+    /// Display calibration values.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `id` - given grid identifier.
+    /// - `calibration` - given calibration to display.
+    fn display_values(
+        ui: &mut egui::Ui,
+        id: &str,
+        calibration: &MagCalibration,
+    ) {
+        Grid::new(id).num_columns(4).show(ui, |ui| {
+            for header in ["", "X", "Y", "Z"] {
+                ui.label(RichText::new(header).strong());
+            }
+            ui.end_row();
+
+            ui.label("Offset");
+            for v in calibration.offset {
+                ui.label(format!("{v:.4}"));
+            }
+            ui.end_row();
+
+            for (i, row) in calibration.matrix.iter().enumerate() {
+                ui.label(if i == 0 { "Matrix" } else { "" });
+
+                for v in row {
+                    ui.label(format!("{v:.4}"));
+                }
+                ui.end_row();
+            }
+        });
+    }
+
+    /// Display point cloud of the samples and the fitted ellipsoid.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    #[allow(clippy::cast_precision_loss)]
+    fn display_scatter(&mut self, ui: &mut egui::Ui) {
+        let size = ui.available_size().max(vec2(MIN_VIEW_SIZE, MIN_VIEW_SIZE));
+        let (rect, response) = ui.allocate_exact_size(size, Sense::drag());
+
+        if response.dragged() {
+            let delta = response.drag_delta() * DRAG_SENSITIVITY;
+            self.yaw += delta.x;
+            self.pitch = (self.pitch + delta.y).clamp(
+                -std::f32::consts::FRAC_PI_2,
+                std::f32::consts::FRAC_PI_2,
+            );
+        }
+
+        let painter = ui.painter().with_clip_rect(rect);
+        painter.rect_filled(rect, 4.0, ui.visuals().extreme_bg_color);
+
+        let fit = self.fit.filter(|_| self.is_calibrated_shown);
+        let samples: Vec<Vector3<f32>> = self
+            .sampler
+            .samples()
+            .iter()
+            .map(|s| fit.map_or(*s, |fit| fit.calibration.apply(*s)))
+            .collect();
+
+        let Some(view) = View::new(rect, self.yaw, self.pitch, &samples) else {
+            painter.text(
+                rect.center(),
+                Align2::CENTER_CENTER,
+                "No samples collected",
+                FontId::proportional(14.0),
+                Color32::GRAY,
+            );
+            return;
+        };
+
+        self.display_wireframe(&painter, &view);
+
+        let axes = [Vector3::x(), Vector3::y(), Vector3::z()];
+        let (start, _) = view.project(view.center);
+
+        for ((axis, name), color) in
+            axes.iter().zip(["X", "Y", "Z"]).zip(AXES_COLORS)
+        {
+            let (end, _) = view.project(view.center + axis * view.extent);
+
+            painter.line_segment([start, end], Stroke::new(1.5, color));
+            painter.text(
+                end,
+                Align2::CENTER_CENTER,
+                name,
+                FontId::monospace(12.0),
+                color,
+            );
+        }
+
+        // Far points are painted first and dimmed.
+        let mut points: Vec<(Pos2, f32)> =
+            samples.iter().map(|s| view.project(*s)).collect();
+        points.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+        for (pos, depth) in points {
+            let color = Color32::LIGHT_BLUE.gamma_multiply(0.35 + 0.65 * depth);
+            painter.circle_filled(pos, POINT_RADIUS, color);
+        }
+
+        painter.text(
+            rect.left_bottom() + vec2(6.0, -6.0),
+            Align2::LEFT_BOTTOM,
+            "Drag to rotate",
+            FontId::proportional(11.0),
+            Color32::GRAY,
+        );
+    }
+
+    /// Display wireframe of the fitted ellipsoid.
+    ///
+    /// Calibrated samples are shown with the sphere of the field strength.
+    ///
+    /// # Parameters
+    /// - `painter` - given painter of the point cloud view.
+    /// - `view` - given point cloud view projection.
+    #[allow(clippy::cast_precision_loss)]
+    fn display_wireframe(&self, painter: &egui::Painter, view: &View) {
+        let Some(fit) = &self.fit else {
+            return;
+        };
+
+        let surface = |latitude: f32, longitude: f32| {
+            let direction = Vector3::new(
+                latitude.cos() * longitude.cos(),
+                latitude.cos() * longitude.sin(),
+                latitude.sin(),
+            );
+
+            let point = if self.is_calibrated_shown {
+                direction * fit.field
+            } else {
+                fit.surface_point(direction)
+            };
+
+            view.project(point).0
+        };
+
+        let step = WIREFRAME_STEP.to_radians();
+        let segment = std::f32::consts::TAU / WIREFRAME_SEGMENTS as f32;
+        let stroke = Stroke::new(1.0, WIREFRAME_COLOR);
+
+        let mut latitude = -std::f32::consts::FRAC_PI_2 + step;
+        while latitude < std::f32::consts::FRAC_PI_2 - step / 2.0 {
+            let circle = (0..=WIREFRAME_SEGMENTS)
+                .map(|i| surface(latitude, i as f32 * segment))
+                .collect();
+            painter.add(egui::Shape::line(circle, stroke));
+            latitude += step;
+        }
+
+        let mut longitude = 0.0;
+        while longitude < std::f32::consts::PI - step / 2.0 {
+            let circle = (0..=WIREFRAME_SEGMENTS)
+                .map(|i| surface(i as f32 * segment, longitude))
+                .collect();
+            painter.add(egui::Shape::line(circle, stroke));
+            longitude += step;
+        }
+    }
+
+    /// Display panel.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `current` - given magnetometer calibration currently applied.
+    ///
+    /// # Returns
+    /// - Calibration of the raw readings to apply - if user applied it.
+    /// - `None` - otherwise.
+    pub fn show(
+        &mut self,
+        ui: &mut egui::Ui,
+        current: &MagCalibration,
+    ) -> Option<MagCalibration> {
+        let mut applied = None;
+
+        ui.label(RichText::new("Magnetometer Ellipsoid Calibration").strong());
+        ui.add_space(10.0);
+
+        self.display_controls(ui);
+        ui.add_space(10.0);
+
+        ui.horizontal_top(|ui| {
+            ui.vertical(|ui| {
+                self.display_metrics(ui);
+
+                ui.separator();
+                ui.label(RichText::new("Current Calibration").strong());
+                Self::display_values(ui, "mag_calibration_current", current);
+
+                // Collected readings are already calibrated by the current
+                // calibration.
+                if let Some(result) =
+                    self.fit.map(|fit| current.then(&fit.calibration))
+                {
+                    ui.add_space(10.0);
+                    ui.label(RichText::new("New Calibration").strong());
+                    Self::display_values(ui, "mag_calibration_new", &result);
+
+                    if ui
+                        .add_enabled(
+                            !self.is_collecting,
+                            Button::new("💾 Apply & Save"),
+                        )
+                        .clicked()
+                    {
+                        applied = Some(result);
+                    }
+                }
+            });
+
+            self.display_scatter(ui);
+        });
+
+        if applied.is_some() {
+            *self = Self::default();
+        }
+
+        applied
+    }
+}
+
+/// Orthographic projection of the point cloud view.
+struct View {
+    /// Screen rectangle of the view.
+    rect: Rect,
+    /// Point projected onto the view center.
+    center: Vector3<f32>,
+    /// Half of the largest extent of the samples.
+    extent: f32,
+    /// Screen pixels per unit of readings.
+    scale: f32,
+    /// Sine and cosine of the view azimuth.
+    yaw: (f32, f32),
+    /// Sine and cosine of the view elevation.
+    pitch: (f32, f32),
+}
+
+impl View {
+    /// Construct new `View` object fitting samples into the view.
+    ///
+    /// # Parameters
+    /// - `rect` - given screen rectangle of the view.
+    /// - `yaw` - given view azimuth in radians.
+    /// - `pitch` - given view elevation in radians.
+    /// - `samples` - given samples to fit.
+    ///
+    /// # Returns
+    /// - New `View` object - in case of success.
+    /// - `None` - if there are no samples.
+    fn new(
+        rect: Rect,
+        yaw: f32,
+        pitch: f32,
+        samples: &[Vector3<f32>],
+    ) -> Option<Self> {
+        let first = samples.first()?;
+        let (min, max) = samples
+            .iter()
+            .fold((*first, *first), |(min, max), s| (min.inf(s), max.sup(s)));
+
+        let extent = ((max - min).max() / 2.0).max(f32::EPSILON);
+        // Rotated bounding box fits into the view at any angle.
+        let scale = rect.width().min(rect.height()) / 2.0 / (extent * 1.8);
+
+        Some(Self {
+            rect,
+            center: (min + max) / 2.0,
+            extent,
+            scale,
+            yaw: yaw.sin_cos(),
+            pitch: pitch.sin_cos(),
+        })
+    }
+
+    /// Project point onto the screen.
+    ///
+    /// # Parameters
+    /// - `point` - given point in units of readings.
+    ///
+    /// # Returns
+    /// - Screen position and depth (0.0 - far, 1.0 - near).
+    fn project(&self, point: Vector3<f32>) -> (Pos2, f32) {
+        let p = (point - self.center) / self.extent;
+        let (sin_yaw, cos_yaw) = self.yaw;
+        let (sin_pitch, cos_pitch) = self.pitch;
+
+        // Rotation about Z-axis, then about the screen horizontal axis.
+        let x = p.x * cos_yaw - p.y * sin_yaw;
+        let y = p.x * sin_yaw + p.y * cos_yaw;
+        let up = p.z * cos_pitch - y * sin_pitch;
+        let depth = -(p.z * sin_pitch + y * cos_pitch);
+
+        let pos = self.rect.center() + vec2(x, -up) * self.extent * self.scale;
+        let depth = ((depth / 3.0_f32.sqrt()).clamp(-1.0, 1.0) + 1.0) / 2.0;
+
+        (pos, depth)
+    }
+}
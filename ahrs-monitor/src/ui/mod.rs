@@ -3,24 +3,127 @@
 
 //! The core responsible for AHRS Monitor user interface.
 
-use crate::{config::AppConfig, model::FrameContext};
+use crate::{
+    config::{AppConfig, TabKind},
+    model::FrameContext,
+};
+pub use alerts::AlertPanel;
+pub use analysis::AnalysisTab;
+pub use backlog::FrameBacklog;
+pub use calibration::{AppliedCalibration, CalibrationTab};
+pub use compare::CompareTab;
+pub use connection::{ConnectionAction, ConnectionPanel};
 pub use dashboard::DashboardTab;
+pub use dock::{DockAction, DockLayout};
+pub use housekeeping::HousekeepingPanel;
 pub use inspector::InspectorTab;
+pub use legend::HiddenTraces;
+pub use perf::PerfOverlay;
+pub use playback::Playback;
+pub use replay::ReplayTab;
+pub use sessions::SessionsTab;
+pub use settings::{SettingsAction, SettingsTab};
+pub use spectrum::SpectrumTab;
 pub use telemetry::TelemetryTab;
+pub use trajectory::TrajectoryTab;
 
+mod alerts;
+mod analysis;
+mod backlog;
+mod calibration;
+mod clipboard;
+mod compare;
+mod connection;
 mod dashboard;
+mod dock;
+#[cfg(feature = "gpu-plots")]
+pub mod gpu_plot;
+mod housekeeping;
 mod inspector;
+mod instruments;
+mod interpolation;
+mod legend;
+mod mag_calibration;
+mod perf;
+mod playback;
+mod replay;
+mod sessions;
+mod settings;
+mod spectrum;
 mod telemetry;
+mod trajectory;
 pub mod utils;
 
 /// Application tabs enumeration.
 pub enum AppTab {
     /// Tab for displaying 3D model.
-    Dashboard(DashboardTab),
+    Dashboard(Box<DashboardTab>),
     /// Sensor readings plots.
     Telemetry(Box<TelemetryTab>),
     /// Tab for displaying raw packet inspector.
-    Inspector(InspectorTab),
+    Inspector(Box<InspectorTab>),
+    /// Recorded sessions comparison.
+    Compare(Box<CompareTab>),
+    /// Accelerometer and magnetometer calibration wizards.
+    Calibration(Box<CalibrationTab>),
+    /// Archived device sessions browser.
+    Sessions(Box<SessionsTab>),
+    /// Recorded log replay.
+    Replay(Box<ReplayTab>),
+    /// Dead-reckoning trajectory.
+    Trajectory(Box<TrajectoryTab>),
+    /// Sensor noise analysis.
+    Analysis(Box<AnalysisTab>),
+    /// Frequency spectrum of sensor readings.
+    Spectrum(Box<SpectrumTab>),
+    /// Settings tab.
+    Settings(Box<SettingsTab>),
+}
+
+impl AppTab {
+    /// Get tab kind.
+    ///
+    /// # Returns
+    /// - Kind of the application tab.
+    #[must_use]
+    pub const fn kind(&self) -> TabKind {
+        match self {
+            Self::Dashboard(_) => TabKind::Dashboard,
+            Self::Telemetry(_) => TabKind::Telemetry,
+            Self::Inspector(_) => TabKind::Inspector,
+            Self::Compare(_) => TabKind::Compare,
+            Self::Calibration(_) => TabKind::Calibration,
+            Self::Sessions(_) => TabKind::Sessions,
+            Self::Replay(_) => TabKind::Replay,
+            Self::Trajectory(_) => TabKind::Trajectory,
+            Self::Analysis(_) => TabKind::Analysis,
+            Self::Spectrum(_) => TabKind::Spectrum,
+            Self::Settings(_) => TabKind::Settings,
+        }
+    }
+
+    /// Get tab label with icon.
+    ///
+    /// # Returns
+    /// - Tab label string.
+    #[must_use]
+    pub fn label(&self) -> String {
+        let (icon, title) = match self {
+            Self::Dashboard(tab) => (tab.icon(), tab.title()),
+            Self::Telemetry(tab) => (tab.icon(), tab.title()),
+            Self::Inspector(tab) => (tab.icon(), tab.title()),
+            Self::Compare(tab) => (tab.icon(), tab.title()),
+            Self::Calibration(tab) => (tab.icon(), tab.title()),
+            Self::Sessions(tab) => (tab.icon(), tab.title()),
+            Self::Replay(tab) => (tab.icon(), tab.title()),
+            Self::Trajectory(tab) => (tab.icon(), tab.title()),
+            Self::Analysis(tab) => (tab.icon(), tab.title()),
+            Self::Spectrum(tab) => (tab.icon(), tab.title()),
+            Self::Settings(tab) => (tab.icon(), tab.title()),
+        };
+
+        format!("{icon} {title}")
+    }
 }
 
 /// Application tab trait.
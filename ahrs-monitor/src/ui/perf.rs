@@ -0,0 +1,225 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Performance overlay user interface implementation.
+//!
+//! Overlay compares time spent by the user interface on each frame with the
+//! depth of the events queue and the ingester processing time of received
+//! frames: a growing queue with short UI frames points to the ingestion,
+//! long UI frames point to the rendering.
+
+use eframe::epaint::Color32;
+use egui::{Align2, Context, Grid, RichText, Window};
+use egui_plot::{Corner, Legend, Line, Plot, PlotPoints};
+use std::collections::VecDeque;
+
+/// Number of UI frames in the performance history.
+const PERF_HISTORY_SIZE: usize = 300;
+
+/// Height of the performance plots in pixels.
+const PERF_PLOT_HEIGHT: f32 = 80.0;
+
+/// Performance metrics of a single UI frame.
+#[derive(Debug, Default, Clone, Copy)]
+struct PerfSample {
+    /// Interval since the previous UI frame in milliseconds.
+    frame_interval: f64,
+    /// CPU time spent by the user interface on the previous frame in
+    /// milliseconds (`None` - not reported by the backend).
+    cpu_time: Option<f64>,
+    /// Number of events queued at the start of the frame.
+    queue_depth: usize,
+    /// Max ingester processing time of the frames received during the UI
+    /// frame in milliseconds.
+    processing_time: f64,
+}
+
+/// Performance overlay handler.
+#[derive(Debug, Default)]
+pub struct PerfOverlay {
+    /// Flag whether the overlay is shown.
+    pub is_open: bool,
+    /// Performance metrics of the recent UI frames.
+    samples: VecDeque<PerfSample>,
+    /// Max ingester processing time of the frames received since the last
+    /// UI frame in milliseconds.
+    processing_time: f64,
+}
+
+impl PerfOverlay {
+    /// Construct new `PerfOverlay` object.
+    ///
+    /// # Parameters
+    /// - `is_open` - given indicator whether the overlay is shown.
+    ///
+    /// # Returns
+    /// - New `PerfOverlay` object.
+    #[must_use]
+    pub fn new(is_open: bool) -> Self {
+        Self {
+            is_open,
+            ..Self::default()
+        }
+    }
+
+    /// Record ingester processing time of the received frame.
+    ///
+    /// # Parameters
+    /// - `time_us` - given processing time in microseconds.
+    pub fn record_processing(&mut self, time_us: u32) {
+        self.processing_time =
+            self.processing_time.max(f64::from(time_us) / 1000.0);
+    }
+
+    /// Record performance metrics of the UI frame.
+    ///
+    /// # Parameters
+    /// - `frame_interval` - given interval since the previous UI frame in
+    ///   seconds.
+    /// - `cpu_time` - given CPU time of the previous UI frame in seconds.
+    /// - `queue_depth` - given number of queued events.
+    pub fn record_frame(
+        &mut self,
+        frame_interval: f32,
+        cpu_time: Option<f32>,
+        queue_depth: usize,
+    ) {
+        self.samples.push_back(PerfSample {
+            frame_interval: f64::from(frame_interval) * 1000.0,
+            cpu_time: cpu_time.map(|time| f64::from(time) * 1000.0),
+            queue_depth,
+            processing_time: self.processing_time,
+        });
+        self.processing_time = 0.0;
+
+        if self.samples.len() > PERF_HISTORY_SIZE {
+            self.samples.pop_front();
+        }
+    }
+
+    /// Display performance overlay.
+    ///
+    /// # Parameters
+    /// - `ctx` - given egui context to handle.
+    pub fn show(&mut self, ctx: &Context) {
+        let mut is_open = self.is_open;
+
+        Window::new("⏱ Performance")
+            .open(&mut is_open)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(Align2::RIGHT_BOTTOM, [-10.0, -40.0])
+            .default_width(320.0)
+            .show(ctx, |ui| {
+                self.display_summary(ui);
+                ui.separator();
+                self.display_plots(ui);
+            });
+
+        self.is_open = is_open;
+    }
+
+    /// Display latest and max values of the performance metrics.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    #[allow(clippy::cast_precision_loss)]
+    fn display_summary(&self, ui: &mut egui::Ui) {
+        let rows: [(&str, fn(&PerfSample) -> Option<f64>, &str); 4] = [
+            ("Frame interval", |s| Some(s.frame_interval), "ms"),
+            ("UI CPU time", |s| s.cpu_time, "ms"),
+            ("Event queue", |s| Some(s.queue_depth as f64), ""),
+            ("Ingester time", |s| Some(s.processing_time), "ms"),
+        ];
+
+        Grid::new("perf_summary")
+            .num_columns(3)
+            .spacing([24.0, 4.0])
+            .show(ui, |ui| {
+                ui.label("");
+                ui.label(RichText::new("Last").strong());
+                ui.label(RichText::new("Max").strong());
+                ui.end_row();
+
+                for (name, value, unit) in rows {
+                    let last = self.samples.back().and_then(value);
+                    let max =
+                        self.samples.iter().filter_map(value).reduce(f64::max);
+
+                    ui.label(name);
+
+                    for value in [last, max] {
+                        ui.label(value.map_or_else(
+                            || "-".to_string(),
+                            |value| format!("{value:.2} {unit}"),
+                        ));
+                    }
+                    ui.end_row();
+                }
+            });
+    }
+
+    /// Display history plots of the performance metrics.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    #[allow(clippy::cast_precision_loss)]
+    fn display_plots(&self, ui: &mut egui::Ui) {
+        let series =
+            |value: fn(&PerfSample) -> Option<f64>| -> PlotPoints<'static> {
+                self.samples
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, s)| value(s).map(|v| [i as f64, v]))
+                    .collect()
+            };
+
+        Plot::new("perf_time_p")
+            .height(PERF_PLOT_HEIGHT)
+            .show_grid(true)
+            .allow_drag(false)
+            .allow_zoom(false)
+            .allow_scroll(false)
+            .show_x(false)
+            .include_y(0.0)
+            .y_axis_label("ms")
+            .legend(Legend::default().position(Corner::LeftTop))
+            .show(ui, |plot_ui| {
+                let lines = [
+                    ("Frame interval", series(|s| Some(s.frame_interval))),
+                    ("UI CPU time", series(|s| s.cpu_time)),
+                    ("Ingester time", series(|s| Some(s.processing_time))),
+                ];
+                let colors = [
+                    Color32::LIGHT_BLUE,
+                    Color32::LIGHT_GREEN,
+                    Color32::ORANGE,
+                ];
+
+                for ((name, points), color) in lines.into_iter().zip(colors) {
+                    plot_ui
+                        .line(Line::new(name, points).color(color).width(0.8));
+                }
+            });
+
+        Plot::new("perf_queue_p")
+            .height(PERF_PLOT_HEIGHT)
+            .show_grid(true)
+            .allow_drag(false)
+            .allow_zoom(false)
+            .allow_scroll(false)
+            .show_x(false)
+            .include_y(0.0)
+            .y_axis_label("Events")
+            .legend(Legend::default().position(Corner::LeftTop))
+            .show(ui, |plot_ui| {
+                let points = series(|s| Some(s.queue_depth as f64));
+
+                plot_ui.line(
+                    Line::new("Event queue", points)
+                        .color(Color32::LIGHT_RED)
+                        .width(0.8),
+                );
+            });
+    }
+}
@@ -0,0 +1,169 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Playback scrubber user interface implementation.
+//!
+//! Frames shown on the plots are kept in the playback buffer. While the
+//! stream is paused (e.g. at the end of a replayed journal), scrub position
+//! selects one of them for the attitude widget, plots cursor and inspector.
+
+use crate::model::FrameContext;
+use egui::{RichText, Slider};
+use std::{collections::VecDeque, sync::Arc};
+
+/// Time step of the coarse stepping buttons in microseconds.
+const TIME_STEP_US: u32 = 1_000_000;
+
+/// Width reserved for the stepping buttons and position label in pixels.
+const CONTROLS_WIDTH: f32 = 420.0;
+
+/// Playback scrubber handler.
+#[derive(Debug, Default)]
+pub struct Playback {
+    /// Frames shown on the plots, oldest first.
+    frames: VecDeque<Arc<FrameContext>>,
+    /// Index of the selected frame (`None` - latest frame).
+    position: Option<usize>,
+}
+
+impl Playback {
+    /// Append frame shown on the plots.
+    ///
+    /// # Parameters
+    /// - `frame_ctx` - given frame context to append.
+    /// - `max_frames` - given max number of frames in the buffer.
+    pub fn push(&mut self, frame_ctx: Arc<FrameContext>, max_frames: usize) {
+        self.frames.push_back(frame_ctx);
+
+        let excess = self.frames.len().saturating_sub(max_frames);
+        self.frames.drain(..excess);
+    }
+
+    /// Drop buffered frames and scrub position.
+    pub fn clear(&mut self) {
+        self.frames.clear();
+        self.position = None;
+    }
+
+    /// Return scrub position to the latest frame.
+    pub const fn stop(&mut self) {
+        self.position = None;
+    }
+
+    /// Check whether there are no frames to scrub.
+    ///
+    /// # Returns
+    /// - `true` - if buffer is empty.
+    /// - `false` - otherwise.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Display scrubber with stepping buttons.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    ///
+    /// # Returns
+    /// - Frame at the new scrub position - if position was changed.
+    /// - `None` - otherwise.
+    pub fn show(&mut self, ui: &mut egui::Ui) -> Option<Arc<FrameContext>> {
+        let last = self.frames.len().checked_sub(1)?;
+        let current = self.position.unwrap_or(last).min(last);
+        let mut selected = current;
+
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Playback").strong());
+
+            if ui
+                .button("⏪ 1 s")
+                .on_hover_text("Step back 1 second")
+                .clicked()
+            {
+                selected = self.step_back_time(current);
+            }
+
+            if ui.button("◀").on_hover_text("Previous sample").clicked() {
+                selected = current.saturating_sub(1);
+            }
+
+            ui.spacing_mut().slider_width =
+                (ui.available_width() - CONTROLS_WIDTH).max(100.0);
+            ui.add(Slider::new(&mut selected, 0..=last).show_value(false));
+
+            if ui.button("▶").on_hover_text("Next sample").clicked() {
+                selected = (current + 1).min(last);
+            }
+
+            if ui
+                .button("1 s ⏩")
+                .on_hover_text("Step forward 1 second")
+                .clicked()
+            {
+                selected = self.step_forward_time(current);
+            }
+
+            if let Some(frame_ctx) = self.frames.get(selected) {
+                ui.label(format!(
+                    "Sample {}/{} at {:.6} s",
+                    selected + 1,
+                    last + 1,
+                    f64::from(frame_ctx.timestamp) / 1_000_000.0
+                ));
+            }
+        });
+
+        if selected == current && self.position.is_some() {
+            return None;
+        }
+
+        self.position = Some(selected);
+        self.frames.get(selected).cloned()
+    }
+
+    /// Find the latest frame at least 1 second before the current one.
+    ///
+    /// # Parameters
+    /// - `current` - given index of the current frame.
+    ///
+    /// # Returns
+    /// - Index of the found frame (first frame if there is none).
+    fn step_back_time(&self, current: usize) -> usize {
+        let Some(origin) = self.frames.get(current).map(|f| f.timestamp) else {
+            return current;
+        };
+
+        (0..current)
+            .rev()
+            .find(|&i| {
+                self.frames.get(i).is_some_and(|frame_ctx| {
+                    origin.wrapping_sub(frame_ctx.timestamp) >= TIME_STEP_US
+                })
+            })
+            .unwrap_or(0)
+    }
+
+    /// Find the earliest frame at least 1 second after the current one.
+    ///
+    /// # Parameters
+    /// - `current` - given index of the current frame.
+    ///
+    /// # Returns
+    /// - Index of the found frame (last frame if there is none).
+    fn step_forward_time(&self, current: usize) -> usize {
+        let Some(origin) = self.frames.get(current).map(|f| f.timestamp) else {
+            return current;
+        };
+
+        let last = self.frames.len().saturating_sub(1);
+
+        (current + 1..self.frames.len())
+            .find(|&i| {
+                self.frames.get(i).is_some_and(|frame_ctx| {
+                    frame_ctx.timestamp.wrapping_sub(origin) >= TIME_STEP_US
+                })
+            })
+            .unwrap_or(last)
+    }
+}
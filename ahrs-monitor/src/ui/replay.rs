@@ -0,0 +1,290 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Recorded log replay tab user interface implementation.
+
+use crate::{
+    config::AppConfig,
+    core::player::{LogPlayer, PLAYBACK_SPEEDS},
+    model::FrameContext,
+    ui::TabViewer,
+};
+use eframe::epaint::Color32;
+use egui::{ComboBox, RichText, Slider};
+use std::{
+    sync::mpsc::{self, Receiver, TryRecvError},
+    thread,
+};
+
+/// Width reserved for the playback controls next to the slider in pixels.
+const CONTROLS_WIDTH: f32 = 360.0;
+
+/// Recorded log replay tab handler.
+#[derive(Debug, Default)]
+pub struct ReplayTab {
+    /// Path to the log file to replay.
+    log_path: String,
+    /// Player of the loaded log (`None` - no log is loaded).
+    player: Option<LogPlayer>,
+    /// Status message of the last operation.
+    status: Option<(String, Color32)>,
+    /// Receiver of the log being loaded in the background (`None` - no log
+    /// is being loaded).
+    loading: Option<Receiver<anyhow::Result<LogPlayer>>>,
+}
+
+impl ReplayTab {
+    /// Check whether recorded log is loaded.
+    ///
+    /// # Returns
+    /// - `true` - if replayed frames are shown instead of the live ones.
+    /// - `false` - otherwise.
+    #[must_use]
+    pub const fn is_active(&self) -> bool {
+        self.player.is_some()
+    }
+
+    /// Advance playback and release due frames.
+    ///
+    /// # Parameters
+    /// - `dt` - given wall-clock time since the previous update in seconds.
+    ///
+    /// # Returns
+    /// - Frame contexts of the released records in recording order.
+    pub fn advance(&mut self, dt: f32) -> Vec<FrameContext> {
+        self.player
+            .as_mut()
+            .map(|player| player.advance(f64::from(dt)))
+            .unwrap_or_default()
+    }
+
+    /// Start loading log file in the background.
+    ///
+    /// Large logs take seconds to parse, so that the UI thread is not
+    /// blocked.
+    fn load(&mut self) {
+        let path = self.log_path.trim().to_string();
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            tx.send(LogPlayer::load(path)).ok();
+        });
+
+        self.loading = Some(rx);
+        self.status = Some(("Loading...".to_string(), Color32::GRAY));
+    }
+
+    /// Take the player of the log loaded in the background.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    ///
+    /// # Returns
+    /// - `true` - if log is loaded.
+    /// - `false` - otherwise.
+    fn poll_loading(&mut self, ui: &egui::Ui) -> bool {
+        let Some(rx) = &self.loading else {
+            return false;
+        };
+
+        let result = match rx.try_recv() {
+            Ok(result) => result,
+            Err(TryRecvError::Empty) => {
+                ui.ctx().request_repaint();
+                return false;
+            }
+            Err(TryRecvError::Disconnected) => {
+                Err(anyhow::anyhow!("log loading was interrupted"))
+            }
+        };
+
+        self.loading = None;
+
+        match result {
+            Ok(player) => {
+                self.status = Some((
+                    format!("Loaded {} records", player.len()),
+                    Color32::GRAY,
+                ));
+                self.player = Some(player);
+                true
+            }
+            Err(e) => {
+                self.status = Some((format!("Error: {e}"), Color32::LIGHT_RED));
+                false
+            }
+        }
+    }
+
+    /// Display log file selection.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    ///
+    /// # Returns
+    /// - `true` - if shown frames must be discarded.
+    /// - `false` - otherwise.
+    fn display_source(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut is_restarted = self.poll_loading(ui);
+
+        ui.horizontal(|ui| {
+            ui.label("Log:");
+            ui.add(
+                egui::TextEdit::singleline(&mut self.log_path)
+                    .hint_text("path/to/log.csv or journal")
+                    .desired_width(480.0),
+            );
+
+            if ui
+                .add_enabled(
+                    self.loading.is_none(),
+                    egui::Button::new("📂 Load"),
+                )
+                .on_hover_text("Replay log instead of the live stream")
+                .clicked()
+            {
+                self.load();
+            }
+
+            if self.player.is_some()
+                && ui
+                    .button("✖ Close")
+                    .on_hover_text("Return to the live stream")
+                    .clicked()
+            {
+                self.player = None;
+                self.status = None;
+                is_restarted = true;
+            }
+
+            if let Some((status, color)) = &self.status {
+                ui.label(RichText::new(status).color(*color));
+            }
+        });
+
+        is_restarted
+    }
+
+    /// Display play/pause, seek and speed controls.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    ///
+    /// # Returns
+    /// - `true` - if shown frames must be discarded.
+    /// - `false` - otherwise.
+    fn display_controls(&mut self, ui: &mut egui::Ui) -> bool {
+        let Some(player) = &mut self.player else {
+            return false;
+        };
+
+        let mut is_restarted = false;
+        let last = player.len();
+        let mut position = player.position();
+
+        ui.horizontal(|ui| {
+            let label = if player.is_playing {
+                "⏸ Pause"
+            } else {
+                "▶ Play"
+            };
+
+            if ui.button(label).clicked() {
+                if player.is_finished() {
+                    player.seek(0);
+                    is_restarted = true;
+                }
+
+                player.is_playing = !player.is_playing;
+            }
+
+            ui.spacing_mut().slider_width =
+                (ui.available_width() - CONTROLS_WIDTH).max(100.0);
+
+            if ui
+                .add(Slider::new(&mut position, 0..=last).show_value(false))
+                .changed()
+            {
+                player.seek(position);
+                is_restarted = true;
+            }
+
+            ComboBox::from_id_salt("replay_speed")
+                .width(70.0)
+                .selected_text(format!("{}x", player.speed))
+                .show_ui(ui, |ui| {
+                    for speed in PLAYBACK_SPEEDS {
+                        ui.selectable_value(
+                            &mut player.speed,
+                            speed,
+                            format!("{speed}x"),
+                        );
+                    }
+                });
+
+            ui.label(format!(
+                "{:.1} / {:.1} s ({}/{last})",
+                player.elapsed_secs(),
+                player.duration_secs(),
+                player.position()
+            ));
+        });
+
+        is_restarted
+    }
+
+    /// Display tab regardless of the connection status.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    ///
+    /// # Returns
+    /// - `true` - if shown frames must be discarded (log is loaded, closed
+    ///   or playback position is changed).
+    /// - `false` - otherwise.
+    pub fn show(&mut self, ui: &mut egui::Ui) -> bool {
+        ui.label(RichText::new("Log Replay").strong());
+        ui.add_space(10.0);
+
+        let is_restarted = self.display_source(ui);
+        let is_seeked = self.display_controls(ui);
+
+        if self.player.is_some() {
+            ui.add_space(10.0);
+            ui.label(
+                "Replayed frames are shown in the other tabs instead of the \
+                 live stream until the log is closed.",
+            );
+        }
+
+        is_restarted || is_seeked
+    }
+}
+
+impl TabViewer for ReplayTab {
+    /// Get tab title.
+    ///
+    /// # Returns
+    /// - Tab title string slice.
+    fn title(&self) -> &'static str {
+        "Replay"
+    }
+
+    /// Get tab icon.
+    ///
+    /// # Returns
+    /// - Tab icon string slice.
+    fn icon(&self) -> &'static str {
+        "🎞"
+    }
+
+    /// Display tab.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `frame_ctx` - given current frame context to handle.
+    /// - `app_cfg` - given global config to handle.
+    fn ui(&mut self, ui: &mut egui::Ui, _: &FrameContext, _: &AppConfig) {
+        self.show(ui);
+    }
+}
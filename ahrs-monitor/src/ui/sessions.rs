@@ -0,0 +1,247 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Session browser tab user interface implementation.
+
+use crate::{
+    config::{self, AppConfig},
+    model::{FrameContext, session::ArchivedSession},
+    ui::TabViewer,
+};
+use eframe::epaint::Color32;
+use egui::{Grid, RichText};
+use egui_plot::{Line, Plot, PlotPoints};
+use std::{collections::VecDeque, sync::Arc};
+
+/// Archived sessions browser tab handler.
+#[derive(Debug, Default)]
+pub struct SessionsTab {
+    /// Archived sessions (the latest one is the first).
+    sessions: VecDeque<ArchivedSession>,
+    /// Index of the selected session.
+    selected: Option<usize>,
+}
+
+impl SessionsTab {
+    /// Archive closed session.
+    ///
+    /// The oldest session is discarded once the archive is full.
+    ///
+    /// # Parameters
+    /// - `session` - given closed session to archive.
+    pub fn archive(&mut self, session: ArchivedSession) {
+        log::info!(
+            "Archived session {} ({} packets)",
+            session.source,
+            session.stats.total_packets
+        );
+
+        self.sessions.push_front(session);
+        self.sessions.truncate(config::MAX_ARCHIVED_SESSIONS);
+        self.selected = Some(0);
+    }
+
+    /// Display archived sessions list.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    fn display_list(&mut self, ui: &mut egui::Ui) {
+        Grid::new("sessions_list")
+            .striped(true)
+            .num_columns(6)
+            .show(ui, |ui| {
+                for header in
+                    ["Source", "Started", "Duration", "Packets", "Bad", "Lost"]
+                {
+                    ui.label(RichText::new(header).strong());
+                }
+                ui.end_row();
+
+                for (i, session) in self.sessions.iter().enumerate() {
+                    let stats = &session.stats;
+                    let is_selected = self.selected == Some(i);
+
+                    if ui
+                        .selectable_label(is_selected, &session.source)
+                        .clicked()
+                    {
+                        self.selected = Some(i);
+                    }
+
+                    ui.label(session.started.format("%H:%M:%S").to_string());
+                    ui.label(format!("{}s", session.duration().as_secs()));
+                    ui.label(stats.total_packets.to_string());
+                    ui.label(stats.bad_packets.total().to_string());
+                    ui.label(stats.lost_packets.to_string());
+                    ui.end_row();
+                }
+            });
+    }
+
+    /// Display counters and history of the session.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `session` - given archived session to display.
+    fn display_details(ui: &mut egui::Ui, session: &ArchivedSession) {
+        let stats = &session.stats;
+        let bad = stats.bad_packets;
+
+        ui.label(
+            RichText::new(format!(
+                "{} ({} - {})",
+                session.source,
+                session.started.format("%d-%m-%Y %H:%M:%S"),
+                session.ended.format("%H:%M:%S")
+            ))
+            .strong(),
+        );
+
+        ui.label(format!(
+            "Bad packets: header {}, length {}, CRC/MAC {}, decryption {}, \
+             payload {}, duplicate {}, stale {}",
+            bad.header,
+            bad.length,
+            bad.integrity,
+            bad.decryption,
+            bad.payload,
+            bad.duplicate,
+            bad.stale
+        ));
+        ui.label(format!(
+            "Lost: {} ({:.1}%)",
+            stats.lost_packets,
+            stats.loss_ratio() * 100.0
+        ));
+
+        if let Some(pps) = stats.pps_summary() {
+            ui.label(format!(
+                "Stream min/avg/max: {}/{:.1}/{} packets/sec",
+                pps.min, pps.avg, pps.max
+            ));
+        }
+
+        #[allow(clippy::cast_precision_loss)]
+        let points: PlotPoints = stats
+            .pps_history()
+            .enumerate()
+            .map(|(i, pps)| [i as f64, pps as f64])
+            .collect();
+
+        Plot::new("session_pps_p")
+            .height(160.0)
+            .show_grid(true)
+            .allow_double_click_reset(true)
+            .x_axis_label("Time since session start, (sec)")
+            .y_axis_label("Packets/sec")
+            .show(ui, |plot_ui| {
+                plot_ui.line(
+                    Line::new("Packets/sec", points)
+                        .color(Color32::LIGHT_BLUE)
+                        .width(0.8),
+                );
+            });
+
+        ui.add_space(10.0);
+        ui.label(RichText::new("Last Frames").strong());
+        Self::display_history(ui, &session.history);
+    }
+
+    /// Display last frames of the session.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `history` - given last frame contexts of the session.
+    fn display_history(ui: &mut egui::Ui, history: &[Arc<FrameContext>]) {
+        Grid::new("session_history")
+            .striped(true)
+            .num_columns(4)
+            .show(ui, |ui| {
+                for header in ["Timestamp", "Sequence", "Payload Type", "Frame"]
+                {
+                    ui.label(RichText::new(header).strong());
+                }
+                ui.end_row();
+
+                for frame_ctx in history.iter().rev() {
+                    let Some(frame) = &frame_ctx.frame else {
+                        continue;
+                    };
+
+                    let (status, color) = if frame_ctx.is_valid {
+                        ("Valid", Color32::LIGHT_GREEN)
+                    } else {
+                        ("Invalid", Color32::LIGHT_RED)
+                    };
+
+                    ui.label(frame_ctx.timestamp.to_string());
+                    ui.label(frame.header.sequence.get().to_string());
+                    ui.label(format!("{:#04X}", frame.header.payload_type));
+                    ui.label(RichText::new(status).color(color));
+                    ui.end_row();
+                }
+            });
+    }
+
+    /// Display tab regardless of the IMU connection.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    pub fn show(&mut self, ui: &mut egui::Ui) {
+        if self.sessions.is_empty() {
+            ui.label(
+                "No archived sessions yet. Sessions are archived when \
+                 switching devices or disconnecting in 🔌 Connection.",
+            );
+            return;
+        }
+
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Archived Sessions").strong());
+
+            if ui.button("🗑 Clear").clicked() {
+                self.sessions.clear();
+                self.selected = None;
+            }
+        });
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            self.display_list(ui);
+            ui.separator();
+
+            if let Some(session) =
+                self.selected.and_then(|i| self.sessions.get(i))
+            {
+                Self::display_details(ui, session);
+            }
+        });
+    }
+}
+
+impl TabViewer for SessionsTab {
+    /// Get tab title.
+    ///
+    /// # Returns
+    /// - Tab title string slice.
+    fn title(&self) -> &'static str {
+        "Sessions"
+    }
+
+    /// Get tab icon.
+    ///
+    /// # Returns
+    /// - Tab icon string slice.
+    fn icon(&self) -> &'static str {
+        "🗄"
+    }
+
+    /// Display tab.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `frame_ctx` - given current frame context to handle.
+    /// - `app_cfg` - given global config to handle.
+    fn ui(&mut self, ui: &mut egui::Ui, _: &FrameContext, _: &AppConfig) {
+        self.show(ui);
+    }
+}
@@ -0,0 +1,536 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Settings tab user interface implementation.
+//!
+//! Configurations are edited in a draft copy, which is applied to the running
+//! monitor and optionally written back to the config file on user request.
+
+use crate::{
+    config::{
+        AngleUnit, AppConfig, DisplayConfig, ImuConfig, MAX_PLOT_HISTORY,
+        NetConfig, RetentionConfig, Transport, is_modified,
+    },
+    core::attitude::FusionAlgorithm,
+    model::FrameContext,
+    ui::TabViewer,
+};
+use eframe::epaint::Color32;
+use egui::{
+    Button, CollapsingHeader, ComboBox, DragValue, Grid, RichText, ScrollArea,
+    emath::Numeric,
+};
+use std::ops::RangeInclusive;
+
+/// Selectable transports with their labels.
+const TRANSPORTS: [(Transport, &str); 5] = [
+    (Transport::Udp, "UDP"),
+    (Transport::Unix, "Unix socket"),
+    (Transport::Stdin, "Standard input"),
+    (Transport::Can, "SocketCAN"),
+    (Transport::Generator, "Test-signal generator"),
+];
+
+/// Max extended (29-bit) CAN identifier.
+const MAX_CAN_ID: u32 = 0x1FFF_FFFF;
+
+/// Settings change requested by the user.
+#[derive(Debug, Clone)]
+pub enum SettingsAction {
+    /// Apply edited configurations to the running monitor.
+    Apply(Box<AppConfig>),
+    /// Apply edited configurations and write them to the config file.
+    Save(Box<AppConfig>),
+}
+
+/// Settings tab handler.
+#[derive(Debug, Default)]
+pub struct SettingsTab {
+    /// Edited copy of the configurations (`None` - not edited yet).
+    draft: Option<Box<AppConfig>>,
+    /// Configurations the draft was copied from.
+    base: Option<Box<AppConfig>>,
+    /// Status message of the last operation.
+    status: Option<(String, Color32)>,
+}
+
+impl SettingsTab {
+    /// Set result of the last requested settings change.
+    ///
+    /// # Parameters
+    /// - `result` - given status message or error of the settings change.
+    pub fn set_status(&mut self, result: anyhow::Result<String>) {
+        self.status = Some(match result {
+            Ok(message) => {
+                // Applied draft is the new base of the edits.
+                self.base.clone_from(&self.draft);
+                (message, Color32::LIGHT_GREEN)
+            }
+            Err(e) => (format!("Error: {e}"), Color32::LIGHT_RED),
+        });
+    }
+
+    /// Display tab regardless of the connection status.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `app_cfg` - given global config to handle.
+    ///
+    /// # Returns
+    /// - Settings change - if user requested it.
+    /// - `None` - otherwise.
+    pub fn show(
+        &mut self,
+        ui: &mut egui::Ui,
+        app_cfg: &AppConfig,
+    ) -> Option<SettingsAction> {
+        // Unmodified draft follows changes made outside the tab (e.g. fusion
+        // selector of the toolbar).
+        let is_outdated = self
+            .base
+            .as_deref()
+            .is_none_or(|base| is_modified(base, app_cfg));
+        let is_draft_modified = match (&self.draft, &self.base) {
+            (Some(draft), Some(base)) => is_modified(draft, base),
+            _ => false,
+        };
+
+        if is_outdated && !is_draft_modified {
+            self.draft = Some(Box::new(app_cfg.clone()));
+            self.base = Some(Box::new(app_cfg.clone()));
+        }
+
+        let draft = self.draft.get_or_insert_with(|| Box::new(app_cfg.clone()));
+        let is_changed = is_modified(draft, app_cfg);
+        let mut action = None;
+        let mut is_reverted = false;
+
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Settings").strong());
+
+            if is_changed {
+                ui.label(RichText::new("● modified").color(Color32::YELLOW));
+            }
+        });
+
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(is_changed, Button::new("✔ Apply"))
+                .on_hover_text("Apply changes to the running monitor")
+                .clicked()
+            {
+                action = Some(SettingsAction::Apply(draft.clone()));
+            }
+
+            if ui
+                .button("💾 Save")
+                .on_hover_text(
+                    "Apply changes and write them to the config file",
+                )
+                .clicked()
+            {
+                action = Some(SettingsAction::Save(draft.clone()));
+            }
+
+            if ui
+                .add_enabled(is_changed, Button::new("↺ Revert"))
+                .on_hover_text("Discard changes")
+                .clicked()
+            {
+                is_reverted = true;
+            }
+        });
+
+        if let Some((status, color)) = &self.status {
+            ui.label(RichText::new(status).color(*color));
+        }
+
+        ui.separator();
+
+        ScrollArea::vertical()
+            .id_salt("settings_scroll")
+            .show(ui, |ui| {
+                display_network(ui, &mut draft.net);
+                display_imu(ui, &mut draft.imu);
+                display_filter(ui, &mut draft.imu);
+                display_logging(
+                    ui,
+                    &mut draft.log.directory,
+                    &mut draft.log.retention,
+                );
+                display_display(ui, &mut draft.display);
+            });
+
+        if is_reverted {
+            self.draft = None;
+            self.base = None;
+            self.status = None;
+        }
+
+        action
+    }
+}
+
+impl TabViewer for SettingsTab {
+    /// Get tab title.
+    ///
+    /// # Returns
+    /// - Tab title string slice.
+    fn title(&self) -> &'static str {
+        "Settings"
+    }
+
+    /// Get tab icon.
+    ///
+    /// # Returns
+    /// - Tab icon string slice.
+    fn icon(&self) -> &'static str {
+        "⚙"
+    }
+
+    /// Display tab.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `frame_ctx` - given current frame context to handle.
+    /// - `app_cfg` - given global config to handle.
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        _frame_ctx: &FrameContext,
+        app_cfg: &AppConfig,
+    ) {
+        let _ = self.show(ui, app_cfg);
+    }
+}
+
+/// Display settings section.
+///
+/// # Parameters
+/// - `ui` - given screen UI handler.
+/// - `title` - given section title.
+/// - `note` - given note about applying changes of the section.
+/// - `add_rows` - given closure adding rows of the section grid.
+fn display_section(
+    ui: &mut egui::Ui,
+    title: &str,
+    note: &str,
+    add_rows: impl FnOnce(&mut egui::Ui),
+) {
+    CollapsingHeader::new(RichText::new(title).strong())
+        .default_open(true)
+        .show(ui, |ui| {
+            ui.label(RichText::new(note).weak().small());
+
+            Grid::new(title)
+                .num_columns(2)
+                .spacing([20.0, 4.0])
+                .striped(true)
+                .show(ui, add_rows);
+        });
+}
+
+/// Display network settings.
+///
+/// # Parameters
+/// - `ui` - given screen UI handler.
+/// - `net` - given networks configurations to edit.
+fn display_network(ui: &mut egui::Ui, net: &mut NetConfig) {
+    let note = "Applied by reconnecting (current session is archived).";
+
+    display_section(ui, "🔌 Network", note, |ui| {
+        let selected = TRANSPORTS
+            .iter()
+            .find(|(transport, _)| *transport == net.transport)
+            .map_or("Unknown", |(_, label)| label);
+
+        ui.label("Transport");
+        ComboBox::from_id_salt("settings_transport")
+            .selected_text(selected)
+            .show_ui(ui, |ui| {
+                for (transport, label) in TRANSPORTS {
+                    ui.selectable_value(&mut net.transport, transport, label);
+                }
+            });
+        ui.end_row();
+
+        edit_text(ui, "IP address", &mut net.ip_address);
+        edit_number(ui, "UDP port", &mut net.udp_port, 1..=u16::MAX, 1.0, "");
+        edit_text(ui, "Unix socket path", &mut net.unix_socket_path);
+        edit_text(ui, "CAN interface", &mut net.can_interface);
+        edit_number(ui, "CAN ID", &mut net.can_id, 0..=MAX_CAN_ID, 1.0, "");
+        edit_flag(ui, "Encryption", &mut net.use_encryption);
+    });
+}
+
+/// Display IMU settings.
+///
+/// # Parameters
+/// - `ui` - given screen UI handler.
+/// - `imu` - given IMU configurations to edit.
+fn display_imu(ui: &mut egui::Ui, imu: &mut ImuConfig) {
+    let note = "Fusion algorithm and inputs are applied immediately, other \
+                values after restart.";
+
+    display_section(ui, "📡 IMU", note, |ui| {
+        ui.label("Fusion");
+        ComboBox::from_id_salt("settings_fusion")
+            .selected_text(imu.fusion.name())
+            .show_ui(ui, |ui| {
+                for value in FusionAlgorithm::ALL {
+                    ui.selectable_value(&mut imu.fusion, value, value.name());
+                }
+            });
+        ui.end_row();
+
+        ui.label("Fused sensors");
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut imu.inputs.acc, "ACC");
+            ui.checkbox(&mut imu.inputs.gyr, "GYR");
+            ui.checkbox(&mut imu.inputs.mag, "MAG");
+        });
+        ui.end_row();
+
+        edit_number(
+            ui,
+            "Sample rate",
+            &mut imu.sample_rate,
+            1.0..=10_000.0,
+            1.0,
+            " Hz",
+        );
+        edit_number(
+            ui,
+            "Reference pressure",
+            &mut imu.reference_pressure,
+            1.0..=200_000.0,
+            10.0,
+            " Pa",
+        );
+        edit_number(
+            ui,
+            "ACC full-scale range",
+            &mut imu.acc_range,
+            0.0..=f32::MAX,
+            0.1,
+            "",
+        );
+        edit_number(
+            ui,
+            "GYR full-scale range",
+            &mut imu.gyr_range,
+            0.0..=f32::MAX,
+            1.0,
+            "",
+        );
+        edit_number(
+            ui,
+            "MAG full-scale range",
+            &mut imu.mag_range,
+            0.0..=f32::MAX,
+            1.0,
+            "",
+        );
+        edit_number(
+            ui,
+            "Clipping threshold",
+            &mut imu.clipping_threshold,
+            0.0..=1.0,
+            0.01,
+            "",
+        );
+        edit_flag(ui, "Warm start", &mut imu.warm_start);
+    });
+}
+
+/// Display attitude filter settings.
+///
+/// # Parameters
+/// - `ui` - given screen UI handler.
+/// - `imu` - given IMU configurations to edit.
+fn display_filter(ui: &mut egui::Ui, imu: &mut ImuConfig) {
+    let note = "Applied after restart.";
+
+    display_section(ui, "🧭 Filter", note, |ui| {
+        let bias = &mut imu.gyro_bias;
+        edit_flag(ui, "Gyro bias estimation", &mut bias.enabled);
+        edit_number(
+            ui,
+            "Stationary ACC std",
+            &mut bias.acc_std,
+            0.0..=f32::MAX,
+            0.001,
+            " m/s²",
+        );
+        edit_number(
+            ui,
+            "Stationary GYR std",
+            &mut bias.gyr_std,
+            0.0..=f32::MAX,
+            0.01,
+            " deg/s",
+        );
+        edit_number(
+            ui,
+            "Max gyro bias",
+            &mut bias.max_bias,
+            0.0..=f32::MAX,
+            0.1,
+            " deg/s",
+        );
+
+        let vertical = &mut imu.vertical;
+        edit_flag(ui, "Vertical channel", &mut vertical.enabled);
+        edit_number(
+            ui,
+            "ACC noise",
+            &mut vertical.acc_noise,
+            0.0..=f32::MAX,
+            0.01,
+            "",
+        );
+        edit_number(
+            ui,
+            "Baro noise",
+            &mut vertical.baro_noise,
+            0.0..=f32::MAX,
+            0.01,
+            "",
+        );
+    });
+}
+
+/// Display logging settings.
+///
+/// # Parameters
+/// - `ui` - given screen UI handler.
+/// - `directory` - given log directory to edit.
+/// - `retention` - given log directory retention policy to edit.
+fn display_logging(
+    ui: &mut egui::Ui,
+    directory: &mut String,
+    retention: &mut RetentionConfig,
+) {
+    let note = "Log directory is applied at the next recording.";
+
+    display_section(ui, "📝 Logging", note, |ui| {
+        edit_text(ui, "Directory", directory);
+        edit_number(
+            ui,
+            "Max total size",
+            &mut retention.max_total_mb,
+            0..=u64::MAX,
+            1.0,
+            " MB",
+        );
+        edit_number(
+            ui,
+            "Max age",
+            &mut retention.max_age_days,
+            0..=u64::MAX,
+            1.0,
+            " days",
+        );
+        edit_number(
+            ui,
+            "Min free space",
+            &mut retention.min_free_mb,
+            0..=u64::MAX,
+            1.0,
+            " MB",
+        );
+        edit_flag(ui, "Auto prune", &mut retention.auto_prune);
+    });
+}
+
+/// Display user interface settings.
+///
+/// # Parameters
+/// - `ui` - given screen UI handler.
+/// - `display` - given display configurations to edit.
+fn display_display(ui: &mut egui::Ui, display: &mut DisplayConfig) {
+    let note = "Applied immediately.";
+
+    display_section(ui, "🖥 Display", note, |ui| {
+        edit_number(
+            ui,
+            "Plot history",
+            &mut display.plot.history,
+            2..=MAX_PLOT_HISTORY,
+            10.0,
+            " points",
+        );
+        edit_flag(ui, "Plot magnitudes", &mut display.plot.magnitudes);
+        edit_flag(ui, "Interpolation", &mut display.interpolation);
+
+        ui.label("Angle unit");
+        ComboBox::from_id_salt("settings_angle_unit")
+            .selected_text(display.angle_unit.angle_symbol())
+            .show_ui(ui, |ui| {
+                for unit in AngleUnit::ALL {
+                    ui.selectable_value(
+                        &mut display.angle_unit,
+                        unit,
+                        unit.angle_symbol(),
+                    );
+                }
+            });
+        ui.end_row();
+
+        edit_number(
+            ui,
+            "Magnetic declination",
+            &mut display.compass.declination,
+            -180.0..=180.0,
+            0.1,
+            "°",
+        );
+        edit_flag(ui, "True heading", &mut display.compass.true_heading);
+    });
+}
+
+/// Display text value row.
+///
+/// # Parameters
+/// - `ui` - given screen UI handler.
+/// - `label` - given value label.
+/// - `value` - given text value to edit.
+fn edit_text(ui: &mut egui::Ui, label: &str, value: &mut String) {
+    ui.label(label);
+    ui.text_edit_singleline(value);
+    ui.end_row();
+}
+
+/// Display flag value row.
+///
+/// # Parameters
+/// - `ui` - given screen UI handler.
+/// - `label` - given value label.
+/// - `value` - given flag value to edit.
+fn edit_flag(ui: &mut egui::Ui, label: &str, value: &mut bool) {
+    ui.label(label);
+    ui.checkbox(value, "");
+    ui.end_row();
+}
+
+/// Display numeric value row.
+///
+/// # Parameters
+/// - `ui` - given screen UI handler.
+/// - `label` - given value label.
+/// - `value` - given numeric value to edit.
+/// - `range` - given range of valid values.
+/// - `speed` - given value change per dragged pixel.
+/// - `unit` - given measurement unit suffix.
+fn edit_number<N: Numeric>(
+    ui: &mut egui::Ui,
+    label: &str,
+    value: &mut N,
+    range: RangeInclusive<N>,
+    speed: f64,
+    unit: &str,
+) {
+    ui.label(label);
+    ui.add(DragValue::new(value).range(range).speed(speed).suffix(unit));
+    ui.end_row();
+}
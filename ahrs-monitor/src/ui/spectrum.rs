@@ -0,0 +1,544 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Frequency spectrum tab user interface implementation.
+
+use crate::{
+    config::AppConfig,
+    core::spectrum::{FFT_SIZES, Spectrogram, Spectrum, SpectrumAnalyzer},
+    model::{FrameContext, FrameWrapper},
+    ui::TabViewer,
+};
+use eframe::epaint::{Color32, ColorImage, TextureHandle};
+use egui::{ComboBox, Grid, RichText, TextureOptions, vec2};
+use egui_plot::{Corner, Legend, Line, Plot, PlotImage, PlotPoint, PlotPoints};
+use std::fmt;
+
+/// Colors of the analyzed axes (x, y, z).
+const AXIS_COLORS: [Color32; 3] = [
+    Color32::LIGHT_RED,
+    Color32::LIGHT_GREEN,
+    Color32::LIGHT_BLUE,
+];
+
+/// Names of the analyzed axes.
+const AXIS_NAMES: [&str; 3] = ["X", "Y", "Z"];
+
+/// Number of overlapping spectrogram columns per FFT buffer.
+const SPECTROGRAM_OVERLAP: usize = 4;
+
+/// Range of the spectrogram colors below the strongest bin in dB.
+const DYNAMIC_RANGE_DB: f64 = 60.0;
+
+/// Floor of the amplitudes converted to decibels.
+const MIN_AMPLITUDE: f64 = 1e-9;
+
+/// Spectrogram color map from the weakest to the strongest amplitude.
+const HEAT_COLORS: [(u8, u8, u8); 5] = [
+    (0, 0, 4),
+    (87, 16, 110),
+    (188, 55, 84),
+    (249, 142, 9),
+    (252, 255, 164),
+];
+
+/// Displayed view.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum SpectrumView {
+    /// Instantaneous amplitude versus frequency of each axis.
+    #[default]
+    Spectrum,
+    /// Time versus frequency heatmap of the selected axis.
+    Spectrogram,
+}
+
+/// Spectrogram heatmap texture.
+#[derive(Default)]
+struct HeatmapTexture {
+    /// Texture handle (`None` - not uploaded yet).
+    handle: Option<TextureHandle>,
+    /// Indicator whether texture does not match the spectrogram.
+    is_outdated: bool,
+}
+
+impl fmt::Debug for HeatmapTexture {
+    /// Format texture state.
+    ///
+    /// # Parameters
+    /// - `f` - given formatter.
+    ///
+    /// # Returns
+    /// - Formatting result.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HeatmapTexture")
+            .field("is_uploaded", &self.handle.is_some())
+            .field("is_outdated", &self.is_outdated)
+            .finish()
+    }
+}
+
+/// Analyzed sensor.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Sensor {
+    /// Accelerometer axes.
+    #[default]
+    Acc,
+    /// Gyroscope axes.
+    Gyr,
+}
+
+impl Sensor {
+    /// Get index of the first sensor channel in the analyzer.
+    ///
+    /// # Returns
+    /// - Channel offset.
+    const fn offset(self) -> usize {
+        match self {
+            Self::Acc => 0,
+            Self::Gyr => 3,
+        }
+    }
+
+    /// Get key of the sensor x-axis channel.
+    ///
+    /// # Returns
+    /// - Channel key string slice.
+    const fn key(self) -> &'static str {
+        match self {
+            Self::Acc => "acc_x",
+            Self::Gyr => "gyr_x",
+        }
+    }
+}
+
+/// Frequency spectrum tab handler.
+#[derive(Debug, Default)]
+pub struct SpectrumTab {
+    /// Sliding buffer spectrum analyzer.
+    analyzer: SpectrumAnalyzer,
+    /// Analyzed sensor.
+    sensor: Sensor,
+    /// Indicator whether displayed spectrum is frozen.
+    is_frozen: bool,
+    /// Indicator whether amplitudes are plotted in decibels.
+    is_db: bool,
+    /// Spectrum of each axis of the analyzed sensor.
+    spectra: Vec<Option<Spectrum>>,
+    /// Displayed view.
+    view: SpectrumView,
+    /// Index of the spectrogram axis of the analyzed sensor.
+    axis: usize,
+    /// Spectra history of the spectrogram axis.
+    spectrogram: Spectrogram,
+    /// Number of readings added since the latest spectrogram column.
+    pending: usize,
+    /// Spectrogram heatmap texture.
+    heatmap: HeatmapTexture,
+}
+
+impl SpectrumTab {
+    /// Add new frame readings to the sliding buffer.
+    ///
+    /// # Parameters
+    /// - `frame` - given new frame to handle.
+    /// - `timestamp` - given sensor-local time in microseconds.
+    pub fn add_data(&mut self, frame: &FrameWrapper, timestamp: u32) {
+        let Some(payload) = &frame.payload else {
+            return;
+        };
+
+        if payload.acc().is_none() && payload.gyr().is_none() {
+            return;
+        }
+
+        self.analyzer.add(payload, timestamp);
+        self.pending += 1;
+
+        if self.pending < self.analyzer.size() / SPECTROGRAM_OVERLAP {
+            return;
+        }
+
+        self.pending = 0;
+
+        if self.is_frozen {
+            return;
+        }
+
+        let channel = self.sensor.offset() + self.axis;
+
+        if let Some(spectrum) = self.analyzer.compute(channel) {
+            self.spectrogram.push(spectrum, timestamp);
+            self.heatmap.is_outdated = true;
+        }
+    }
+
+    /// Drop spectrogram history.
+    fn clear_spectrogram(&mut self) {
+        self.spectrogram.clear();
+        self.pending = 0;
+        self.heatmap = HeatmapTexture::default();
+    }
+
+    /// Display analyzer controls.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    fn display_controls(&mut self, ui: &mut egui::Ui) {
+        let channel = (self.sensor, self.axis);
+
+        ui.horizontal(|ui| {
+            ui.radio_value(&mut self.view, SpectrumView::Spectrum, "Spectrum");
+            ui.radio_value(
+                &mut self.view,
+                SpectrumView::Spectrogram,
+                "Spectrogram",
+            );
+            ui.separator();
+            ui.radio_value(&mut self.sensor, Sensor::Acc, "Accelerometer");
+            ui.radio_value(&mut self.sensor, Sensor::Gyr, "Gyroscope");
+            ui.separator();
+
+            let mut size = self.analyzer.size();
+
+            ComboBox::from_label("FFT size")
+                .selected_text(size.to_string())
+                .show_ui(ui, |ui| {
+                    for option in FFT_SIZES {
+                        ui.selectable_value(
+                            &mut size,
+                            option,
+                            option.to_string(),
+                        );
+                    }
+                });
+
+            if size != self.analyzer.size() {
+                self.analyzer = SpectrumAnalyzer::new(size);
+                self.clear_spectrogram();
+            }
+
+            if self.view == SpectrumView::Spectrogram {
+                ComboBox::from_label("Axis")
+                    .selected_text(
+                        AXIS_NAMES.get(self.axis).copied().unwrap_or_default(),
+                    )
+                    .show_ui(ui, |ui| {
+                        for (i, name) in AXIS_NAMES.iter().enumerate() {
+                            ui.selectable_value(&mut self.axis, i, *name);
+                        }
+                    });
+            }
+
+            ui.checkbox(&mut self.is_db, "dB");
+
+            let label = if self.is_frozen {
+                "▶ Resume"
+            } else {
+                "⏸ Freeze"
+            };
+
+            if ui.button(label).clicked() {
+                self.is_frozen = !self.is_frozen;
+            }
+        });
+
+        // Spectrogram keeps history of the single channel.
+        if (self.sensor, self.axis) != channel {
+            self.clear_spectrogram();
+        }
+
+        let rate = self.analyzer.sample_rate().unwrap_or_default();
+
+        ui.label(format!(
+            "Buffered: {}/{} samples, sample rate: {rate:.1} Hz",
+            self.analyzer.len(),
+            self.analyzer.size()
+        ));
+    }
+
+    /// Display the strongest peak of each axis.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `unit` - given measurement unit of the readings.
+    fn display_peaks(&self, ui: &mut egui::Ui, unit: &str) {
+        Grid::new("spectrum_peaks")
+            .striped(true)
+            .num_columns(3)
+            .show(ui, |ui| {
+                for header in ["Axis", "Peak Frequency", "Amplitude"] {
+                    ui.label(RichText::new(header).strong());
+                }
+                ui.end_row();
+
+                for (name, spectrum) in AXIS_NAMES.iter().zip(&self.spectra) {
+                    ui.label(*name);
+
+                    match spectrum.as_ref().and_then(Spectrum::peak) {
+                        Some((frequency, amplitude)) => {
+                            ui.label(format!("{frequency:.1} Hz"));
+                            ui.label(format!("{amplitude:.4} {unit}"));
+                        }
+                        None => {
+                            ui.label("-");
+                            ui.label("-");
+                        }
+                    }
+                    ui.end_row();
+                }
+            });
+    }
+
+    /// Display amplitude versus frequency plot.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `unit` - given measurement unit of the readings.
+    fn display_plot(&self, ui: &mut egui::Ui, unit: &str) {
+        let y_label = if self.is_db {
+            format!("Amplitude, (dB re 1 {unit})")
+        } else {
+            format!("Amplitude, ({unit})")
+        };
+
+        Plot::new("spectrum_p")
+            .show_grid(true)
+            .legend(Legend::default().position(Corner::RightTop))
+            .allow_double_click_reset(true)
+            .x_axis_label("Frequency, (Hz)")
+            .y_axis_label(y_label)
+            .show(ui, |plot_ui| {
+                let curves =
+                    self.spectra.iter().zip(AXIS_NAMES).zip(AXIS_COLORS);
+
+                for ((spectrum, name), color) in curves {
+                    let Some(spectrum) = spectrum else {
+                        continue;
+                    };
+
+                    let points: PlotPoints = spectrum
+                        .bins()
+                        .map(|(frequency, amplitude)| {
+                            if self.is_db {
+                                [frequency, decibels(amplitude)]
+                            } else {
+                                [frequency, amplitude]
+                            }
+                        })
+                        .collect();
+
+                    plot_ui
+                        .line(Line::new(name, points).color(color).width(0.8));
+                }
+            });
+    }
+
+    /// Display time versus frequency heatmap of the spectrogram axis.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `unit` - given measurement unit of the readings.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    fn display_spectrogram(&mut self, ui: &mut egui::Ui, unit: &str) {
+        let Some(latest) = self.spectrogram.latest() else {
+            ui.label("Waiting for the spectra...");
+            return;
+        };
+
+        if self.spectrogram.len() < 2 {
+            ui.label("Waiting for the spectra...");
+            return;
+        }
+
+        let bins = latest.amplitudes.len() as f64;
+        let resolution = latest.resolution;
+
+        if self.heatmap.is_outdated || self.heatmap.handle.is_none() {
+            if let Some(image) = heatmap_image(&self.spectrogram) {
+                match &mut self.heatmap.handle {
+                    Some(handle) => handle.set(image, TextureOptions::LINEAR),
+                    None => {
+                        self.heatmap.handle = Some(ui.ctx().load_texture(
+                            "spectrogram",
+                            image,
+                            TextureOptions::LINEAR,
+                        ));
+                    }
+                }
+            }
+
+            self.heatmap.is_outdated = false;
+        }
+
+        let Some(texture) = &self.heatmap.handle else {
+            return;
+        };
+
+        ui.label(format!(
+            "Color scale: {DYNAMIC_RANGE_DB} dB below the strongest bin \
+             ({:.4} {unit}), latest spectrum at 0 s.",
+            self.spectrogram.max_amplitude()
+        ));
+
+        // Latest spectrum is at the right edge, bins are centered on their
+        // frequencies.
+        let duration = self.spectrogram.duration();
+        let center =
+            PlotPoint::new(-duration / 2.0, (bins - 1.0) * resolution / 2.0);
+        let size = vec2(duration as f32, (bins * resolution) as f32);
+
+        Plot::new("spectrogram_p")
+            .allow_double_click_reset(true)
+            .x_axis_label("Time, (s)")
+            .y_axis_label("Frequency, (Hz)")
+            .show(ui, |plot_ui| {
+                plot_ui.image(PlotImage::new(
+                    "Spectrogram",
+                    texture.id(),
+                    center,
+                    size,
+                ));
+            });
+    }
+
+    /// Display tab regardless of the payload type.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `app_cfg` - given global config to handle.
+    pub fn show(&mut self, ui: &mut egui::Ui, app_cfg: &AppConfig) {
+        ui.label(RichText::new("Frequency Spectrum").strong());
+        ui.add_space(10.0);
+
+        self.display_controls(ui);
+
+        if !self.is_frozen {
+            let offset = self.sensor.offset();
+
+            self.spectra = (offset..offset + AXIS_NAMES.len())
+                .map(|channel| self.analyzer.compute(channel))
+                .collect();
+        }
+
+        if self.spectra.iter().all(Option::is_none) {
+            ui.label("Waiting for the buffer to fill...");
+            return;
+        }
+
+        let unit = app_cfg.channels.info(self.sensor.key()).unit;
+
+        ui.separator();
+        self.display_peaks(ui, unit);
+        ui.add_space(10.0);
+
+        match self.view {
+            SpectrumView::Spectrum => self.display_plot(ui, unit),
+            SpectrumView::Spectrogram => self.display_spectrogram(ui, unit),
+        }
+    }
+}
+
+/// Convert amplitude to decibels.
+///
+/// # Parameters
+/// - `amplitude` - given amplitude in units of the readings.
+///
+/// # Returns
+/// - Amplitude in dB re 1 unit of the readings.
+fn decibels(amplitude: f64) -> f64 {
+    // Floor keeps empty bins out of -infinity.
+    20.0 * amplitude.max(MIN_AMPLITUDE).log10()
+}
+
+/// Get color of the spectrogram heatmap.
+///
+/// # Parameters
+/// - `level` - given relative amplitude (0.0 - weakest, 1.0 - strongest).
+///
+/// # Returns
+/// - Color interpolated between the color map stops.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss
+)]
+fn heat_color(level: f32) -> Color32 {
+    let scaled = level.clamp(0.0, 1.0) * (HEAT_COLORS.len() - 1) as f32;
+    let idx = (scaled as usize).min(HEAT_COLORS.len() - 2);
+    let fraction = scaled - idx as f32;
+
+    let (Some(low), Some(high)) =
+        (HEAT_COLORS.get(idx), HEAT_COLORS.get(idx + 1))
+    else {
+        return Color32::BLACK;
+    };
+
+    let mix = |a: u8, b: u8| {
+        (f32::from(a) + (f32::from(b) - f32::from(a)) * fraction).round() as u8
+    };
+
+    Color32::from_rgb(
+        mix(low.0, high.0),
+        mix(low.1, high.1),
+        mix(low.2, high.2),
+    )
+}
+
+/// Render spectrogram into the heatmap image.
+///
+/// # Parameters
+/// - `spectrogram` - given spectrogram to render.
+///
+/// # Returns
+/// - Image with a column per spectrum and the highest frequency at the top
+///   row - if spectrogram is not empty.
+/// - `None` - otherwise.
+#[allow(clippy::cast_possible_truncation)]
+fn heatmap_image(spectrogram: &Spectrogram) -> Option<ColorImage> {
+    let height = spectrogram.latest()?.amplitudes.len();
+    let width = spectrogram.len();
+    let max_db = decibels(spectrogram.max_amplitude());
+    let mut rgba = vec![0; width * height * 4];
+
+    for (column, (_, spectrum)) in spectrogram.columns().enumerate() {
+        for (bin, amplitude) in spectrum.amplitudes.iter().enumerate() {
+            let level =
+                (decibels(*amplitude) - max_db) / DYNAMIC_RANGE_DB + 1.0;
+            let pixel = (height.saturating_sub(bin + 1) * width + column) * 4;
+
+            if let Some(target) = rgba.get_mut(pixel..pixel + 4) {
+                target.copy_from_slice(&heat_color(level as f32).to_array());
+            }
+        }
+    }
+
+    Some(ColorImage::from_rgba_unmultiplied([width, height], &rgba))
+}
+
+impl TabViewer for SpectrumTab {
+    /// Get tab title.
+    ///
+    /// # Returns
+    /// - Tab title string slice.
+    fn title(&self) -> &'static str {
+        "Spectrum"
+    }
+
+    /// Get tab icon.
+    ///
+    /// # Returns
+    /// - Tab icon string slice.
+    fn icon(&self) -> &'static str {
+        "〰"
+    }
+
+    /// Display tab.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `frame_ctx` - given current frame context to handle.
+    /// - `app_cfg` - given global config to handle.
+    fn ui(&mut self, ui: &mut egui::Ui, _: &FrameContext, app_cfg: &AppConfig) {
+        self.show(ui, app_cfg);
+    }
+}
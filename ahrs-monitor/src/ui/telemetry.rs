@@ -5,20 +5,46 @@
 
 use crate::model::FrameWrapper;
 use crate::{
-    config::AppConfig,
+    config::{AngleUnit, AppConfig, MAX_PLOT_HISTORY, PlotGroup},
+    core::{
+        StandardPayload,
+        anomaly::Anomaly,
+        channel::{ChannelInfo, ChannelRegistry},
+        clipping::Clipping,
+        mode::ModeTransition,
+        motion::MotionTransition,
+        vibration::VibrationMetrics,
+    },
     model::FrameContext,
     ui::{
         TabViewer,
-        utils::{Plotter, extract_readings},
+        utils::{Plotter, TimeAxis, extract_readings},
     },
 };
 use eframe::epaint::Color32;
+use egui::{DragValue, Grid, RichText};
 use indtp::payload::PayloadType;
 
 /// Number of metrics in history.
-const HISTORY_ENTRIES: usize = 10;
+const HISTORY_ENTRIES: usize = 20;
+
+/// Indices of linear acceleration metrics in history.
+const LINEAR_ACC_INDICES: [usize; 3] = [10, 11, 12];
+
+/// Index of barometric altitude in history.
+const ALTITUDE_INDEX: usize = 13;
+
+/// Indices of accelerometer vibration RMS metrics in history.
+const VIBRATION_INDICES: [usize; 3] = [14, 15, 16];
+
+/// Indices of accelerometer, gyroscope and magnetometer vector magnitudes in
+/// history.
+const MAGNITUDE_INDICES: [usize; 3] = [17, 18, 19];
 
-/// Max number of points in history per each metric.
+/// Vector magnitude trace color.
+const MAGNITUDE_COLOR: Color32 = Color32::WHITE;
+
+/// Default max number of points in history per each metric.
 const MAX_POINTS: usize = 1000;
 
 const GROUP_COLORS: [Color32; 3] = [
@@ -32,6 +58,18 @@ const GROUP_COLORS: [Color32; 3] = [
 pub struct TelemetryTab {
     /// Metrics plotter.
     plotter: Plotter<HISTORY_ENTRIES, MAX_POINTS>,
+    /// Time axis of the plots.
+    time_axis: TimeAxis,
+    /// Plot groups hidden by the layout preset.
+    hidden_plots: Vec<PlotGroup>,
+    /// Number of plotted points per metric set at runtime (`None` -
+    /// configured plot history).
+    history: Option<usize>,
+    /// Indicator whether vector magnitudes are plotted set at runtime
+    /// (`None` - configured indicator).
+    magnitudes: Option<bool>,
+    /// Unit of the plotted angular rates.
+    angle_unit: AngleUnit,
 }
 
 impl TelemetryTab {
@@ -40,10 +78,359 @@ impl TelemetryTab {
     /// # Parameters
     /// - `frame` - given IDTP frame to handle.
     /// - `timestamp` - given timestamp in microseconds.
-    pub fn add_data(&mut self, frame: &FrameWrapper, timestamp: u32) {
-        let data = extract_readings(frame);
-        self.plotter.add_data(data, u64::from(timestamp));
+    /// - `host_time_us` - given estimated host time in Unix microseconds.
+    /// - `clipping` - given sensors whose readings are clipped.
+    /// - `linear_acc` - given linear (gravity-free) acceleration.
+    /// - `altitude` - given barometric altitude.
+    /// - `vibration` - given vibration metrics of the accelerometer readings.
+    /// - `anomalies` - given anomalous readings to mark.
+    /// - `mode_transition` - given protocol mode transition to mark.
+    /// - `motion_transition` - given motion state transition to mark.
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_data(
+        &mut self,
+        frame: &FrameWrapper,
+        timestamp: u32,
+        host_time_us: Option<i64>,
+        clipping: Clipping,
+        linear_acc: Option<[f32; 3]>,
+        altitude: Option<f32>,
+        vibration: Option<VibrationMetrics>,
+        anomalies: &[Anomaly],
+        mode_transition: Option<ModeTransition>,
+        motion_transition: Option<MotionTransition>,
+    ) {
+        let timestamp = match self.time_axis {
+            TimeAxis::Sensor => u64::from(timestamp),
+            TimeAxis::WallClock => {
+                match host_time_us.and_then(|t| u64::try_from(t).ok()) {
+                    Some(host_time_us) => host_time_us,
+                    None => return,
+                }
+            }
+        };
+
+        let mut readings = extract_readings(frame);
+        let offsets = sensor_offsets(frame);
+        let [_, gyr_offset, _] = offsets;
+
+        // Angular rates are plotted in the displayed angle unit.
+        if let Some(gyr) =
+            gyr_offset.and_then(|offset| readings.get_mut(offset..offset + 3))
+        {
+            let factor = self.angle_unit.rate_factor();
+            gyr.iter_mut().for_each(|value| *value *= factor);
+        }
+
+        let linear_acc = linear_acc.unwrap_or_default();
+        let mut data = [0.0; HISTORY_ENTRIES];
+
+        for (entry, &value) in
+            data.iter_mut().zip(readings.iter().chain(&linear_acc))
+        {
+            *entry = value;
+        }
+
+        if let Some(entry) = data.get_mut(ALTITUDE_INDEX) {
+            *entry = altitude.unwrap_or_default();
+        }
+
+        let rms = vibration.unwrap_or_default().rms;
+
+        for (&index, value) in VIBRATION_INDICES.iter().zip(rms) {
+            if let Some(entry) = data.get_mut(index) {
+                *entry = value;
+            }
+        }
+
+        for (&index, offset) in MAGNITUDE_INDICES.iter().zip(offsets) {
+            if let Some(offset) = offset
+                && let Some(axes) = readings.get(offset..offset + 3)
+                && let Some(entry) = data.get_mut(index)
+            {
+                *entry = axes.iter().map(|v| v * v).sum::<f32>().sqrt();
+            }
+        }
+
+        let flags = clipping_flags(frame, clipping);
+        self.plotter.add_flagged_data(data, flags, timestamp);
+
+        for anomaly in anomalies {
+            let sensor = anomaly.channel / 3;
+            let axis = anomaly.channel % 3;
+
+            if let Some(offset) = offsets.get(sensor).copied().flatten() {
+                self.plotter.add_marker(offset + axis);
+            }
+        }
+
+        if let Some(transition) = mode_transition {
+            self.plotter.add_event(transition.to_string());
+        }
+
+        if let Some(transition) = motion_transition {
+            self.plotter.add_event(transition.to_string());
+        }
+    }
+
+    /// Set playback cursor of the plots.
+    ///
+    /// # Parameters
+    /// - `frame_ctx` - given frame context at the cursor (`None` - hide
+    ///   cursor).
+    pub fn set_cursor(&mut self, frame_ctx: Option<&FrameContext>) {
+        let timestamp = frame_ctx.and_then(|frame_ctx| match self.time_axis {
+            TimeAxis::Sensor => Some(u64::from(frame_ctx.timestamp)),
+            TimeAxis::WallClock => {
+                frame_ctx.host_time_us.and_then(|t| u64::try_from(t).ok())
+            }
+        });
+
+        self.plotter.set_cursor(timestamp);
+    }
+
+    /// Mark the latest samples with the timeline event.
+    ///
+    /// # Parameters
+    /// - `label` - given event label.
+    pub fn add_event(&mut self, label: String) {
+        self.plotter.add_event(label);
     }
+
+    /// Set plot groups visible in the layout preset.
+    ///
+    /// # Parameters
+    /// - `visible` - given visible plot groups.
+    pub fn set_visible_plots(&mut self, visible: &[PlotGroup]) {
+        self.hidden_plots = PlotGroup::ALL
+            .into_iter()
+            .filter(|group| !visible.contains(group))
+            .collect();
+    }
+
+    /// Check whether plot group is visible.
+    ///
+    /// # Parameters
+    /// - `group` - given plot group to check.
+    ///
+    /// # Returns
+    /// - `true` - if plot group is not hidden by the layout preset.
+    /// - `false` - otherwise.
+    fn is_visible(&self, group: PlotGroup) -> bool {
+        !self.hidden_plots.contains(&group)
+    }
+
+    /// Display quaternion components plots.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    fn display_quat_plots(&mut self, ui: &mut egui::Ui) {
+        self.plotter.render_plot(
+            ui,
+            "quat_w_p",
+            "Attitude (Quaternion W)",
+            &[0],
+            &["W"],
+            &[Color32::WHITE],
+        );
+
+        self.plotter.render_plot(
+            ui,
+            "quat_x_p",
+            "Attitude (Quaternion X)",
+            &[1],
+            &["X"],
+            &[Color32::LIGHT_RED],
+        );
+
+        self.plotter.render_plot(
+            ui,
+            "quat_y_p",
+            "Attitude (Quaternion Y)",
+            &[2],
+            &["Y"],
+            &[Color32::LIGHT_GREEN],
+        );
+
+        self.plotter.render_plot(
+            ui,
+            "quat_z_p",
+            "Attitude (Quaternion Z)",
+            &[3],
+            &["Z"],
+            &[Color32::LIGHT_BLUE],
+        );
+    }
+
+    /// Set unit of the plotted angular rates.
+    ///
+    /// # Parameters
+    /// - `unit` - given angle unit to set.
+    /// - `payload_type` - given payload type of the plotted readings.
+    fn set_angle_unit(&mut self, unit: AngleUnit, payload_type: u8) {
+        if self.angle_unit == unit {
+            return;
+        }
+
+        let offset = if payload_type == PayloadType::Imu3Gyr.as_u8() {
+            0
+        } else {
+            3
+        };
+        let entries = [offset, offset + 1, offset + 2, MAGNITUDE_INDICES[1]];
+
+        let factor = unit.rate_factor() / self.angle_unit.rate_factor();
+        self.plotter.rescale(&entries, factor);
+        self.angle_unit = unit;
+    }
+
+    /// Display time axis selector.
+    ///
+    /// Plotted history is cleared on change, since timestamps of different
+    /// time axes are not comparable.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    fn display_time_axis_selector(&mut self, ui: &mut egui::Ui) {
+        let mut time_axis = self.time_axis;
+
+        ui.horizontal(|ui| {
+            ui.label("Time axis:");
+            ui.selectable_value(&mut time_axis, TimeAxis::Sensor, "Sensor");
+            ui.selectable_value(
+                &mut time_axis,
+                TimeAxis::WallClock,
+                "Wall clock",
+            )
+            .on_hover_text("Host time estimated by clock synchronization");
+        });
+
+        if time_axis != self.time_axis {
+            self.time_axis = time_axis;
+            self.plotter = Plotter::default();
+            self.plotter.set_time_axis(time_axis);
+        }
+    }
+
+    /// Display plot history length selector.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `app_cfg` - given global config to handle.
+    fn display_history_selector(
+        &mut self,
+        ui: &mut egui::Ui,
+        app_cfg: &AppConfig,
+    ) {
+        let configured = app_cfg.display.plot_history();
+        let mut history = self.history.unwrap_or(configured);
+
+        ui.horizontal(|ui| {
+            ui.label("History:");
+
+            let response = ui
+                .add(
+                    DragValue::new(&mut history)
+                        .range(2..=MAX_PLOT_HISTORY)
+                        .speed(10.0)
+                        .suffix(" points"),
+                )
+                .on_hover_text("Number of plotted points per metric");
+
+            if response.changed() {
+                self.history = Some(history);
+            }
+
+            let sample_rate = app_cfg.imu.sample_rate;
+
+            #[allow(clippy::cast_precision_loss)]
+            let duration = history as f32 / sample_rate;
+            ui.weak(format!("≈ {duration:.1} s at {sample_rate} Hz"));
+
+            if self.history.is_some()
+                && ui
+                    .small_button("↺")
+                    .on_hover_text(format!("Reset to configured {configured}"))
+                    .clicked()
+            {
+                self.history = None;
+            }
+        });
+
+        self.plotter
+            .set_max_points(self.history.unwrap_or(configured));
+    }
+
+    /// Display vector magnitudes toggle.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `app_cfg` - given global config to handle.
+    fn display_magnitudes_toggle(
+        &mut self,
+        ui: &mut egui::Ui,
+        app_cfg: &AppConfig,
+    ) {
+        let mut is_shown =
+            self.magnitudes.unwrap_or(app_cfg.display.plot.magnitudes);
+
+        if ui
+            .checkbox(&mut is_shown, "Magnitudes")
+            .on_hover_text("Plot |acc|, |gyr| and |mag| next to the axes")
+            .changed()
+        {
+            self.magnitudes = Some(is_shown);
+        }
+    }
+}
+
+/// Get offsets of sensor readings in the padded IMU readings.
+///
+/// # Parameters
+/// - `frame` - given IDTP frame to handle.
+///
+/// # Returns
+/// - Offsets of accelerometer, gyroscope and magnetometer readings
+///   (`None` - sensor is absent in the payload).
+const fn sensor_offsets(frame: &FrameWrapper) -> [Option<usize>; 3] {
+    match frame.payload {
+        Some(StandardPayload::Imu3Gyr(_)) => [None, Some(0), None],
+        Some(StandardPayload::Imu3Mag(_)) => [None, None, Some(0)],
+        Some(StandardPayload::ImuQuat(_)) | None => [None, None, None],
+        Some(_) => [Some(0), Some(3), Some(6)],
+    }
+}
+
+/// Map clipped sensors onto IMU readings history entries.
+///
+/// # Parameters
+/// - `frame` - given IDTP frame to handle.
+/// - `clipping` - given sensors whose readings are clipped.
+///
+/// # Returns
+/// - Clipping flag for each IMU readings history entry.
+fn clipping_flags(
+    frame: &FrameWrapper,
+    clipping: Clipping,
+) -> [bool; HISTORY_ENTRIES] {
+    let [acc, gyr, mag] = sensor_offsets(frame);
+    let mut flags = [false; HISTORY_ENTRIES];
+
+    for (offset, is_clipped) in [
+        (acc, clipping.acc),
+        (gyr, clipping.gyr),
+        (mag, clipping.mag),
+    ] {
+        if let Some(offset) = offset
+            && is_clipped
+        {
+            for flag in flags.iter_mut().skip(offset).take(3) {
+                *flag = true;
+            }
+        }
+    }
+
+    flags
 }
 
 impl TabViewer for TelemetryTab {
@@ -69,10 +456,27 @@ impl TabViewer for TelemetryTab {
     /// - `ui` - given screen UI handler.
     /// - `frame_ctx` - given current frame context to handle.
     /// - `app_cfg` - given global config to handle.
-    fn ui(&mut self, ui: &mut egui::Ui, _: &FrameContext, app_cfg: &AppConfig) {
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        frame_ctx: &FrameContext,
+        app_cfg: &AppConfig,
+    ) {
+        self.set_angle_unit(
+            app_cfg.display.angle_unit,
+            app_cfg.imu.payload_type,
+        );
+
         ui.vertical(|ui| {
+            self.display_time_axis_selector(ui);
+            self.display_history_selector(ui, app_cfg);
+            self.display_magnitudes_toggle(ui, app_cfg);
             self.plotter.set_plot_height(Some(200.0));
 
+            let show_magnitudes =
+                self.magnitudes.unwrap_or(app_cfg.display.plot.magnitudes);
+            self.plotter.set_renderer(app_cfg.display.plot.renderer);
+
             let imu_metrics = app_cfg.imu.metrics;
             let payload_type = app_cfg.imu.payload_type;
 
@@ -96,89 +500,237 @@ impl TabViewer for TelemetryTab {
 
             let baro_indices = &[9];
 
+            let ch = &app_cfg.channels;
+            let acc = channels(ch, ["acc_x", "acc_y", "acc_z"]);
+            let gyr = channels(ch, ["gyr_x", "gyr_y", "gyr_z"])
+                .map(|c| c.in_angle_unit(self.angle_unit).0);
+            let mag = channels(ch, ["mag_x", "mag_y", "mag_z"]);
+            let baro = channels(ch, ["baro"]);
+            let altitude = channels(ch, ["altitude"]);
+            let lin_acc = channels(ch, ["lin_acc_x", "lin_acc_y", "lin_acc_z"]);
+
             egui::ScrollArea::vertical().show(ui, |ui| {
-                if imu_metrics.acc {
+                if imu_metrics.acc && self.is_visible(PlotGroup::Acc) {
+                    let (indices, labels, colors) = with_magnitude(
+                        acc_indices,
+                        acc.map(|c| c.name),
+                        (MAGNITUDE_INDICES[0], "|acc|"),
+                        show_magnitudes,
+                    );
+
                     self.plotter.render_plot(
                         ui,
                         "acc_p",
-                        "Accelerometer (m/s²)",
-                        acc_indices,
-                        &["Acc X", "Acc Y", "Acc Z"],
-                        &GROUP_COLORS,
+                        &group_title("Accelerometer", &acc),
+                        &indices,
+                        &labels,
+                        &colors,
                     );
                 }
 
-                if imu_metrics.gyr {
+                // Gravity is removed using attitude estimated with gyroscope.
+                if imu_metrics.acc
+                    && imu_metrics.gyr
+                    && self.is_visible(PlotGroup::LinAcc)
+                {
                     self.plotter.render_plot(
                         ui,
-                        "gyr_p",
-                        "Gyroscope (rad/s)",
-                        gyr_indices,
-                        &["Gyr X", "Gyr Y", "Gyr Z"],
+                        "lin_acc_p",
+                        &group_title("Linear acceleration", &lin_acc),
+                        &LINEAR_ACC_INDICES,
+                        &lin_acc.map(|c| c.name),
                         &GROUP_COLORS,
                     );
                 }
 
-                if imu_metrics.mag {
+                if imu_metrics.acc
+                    && app_cfg.imu.vibration.enabled
+                    && self.is_visible(PlotGroup::Vibration)
+                {
+                    display_vibration_metrics(ui, frame_ctx, app_cfg);
+
                     self.plotter.render_plot(
                         ui,
-                        "mag_p",
-                        "Magnetometer (µT)",
-                        mag_indices,
-                        &["Mag X", "Mag Y", "Mag Z"],
+                        "vibration_p",
+                        &group_title("Vibration RMS", &acc),
+                        &VIBRATION_INDICES,
+                        &acc.map(|c| c.name),
                         &GROUP_COLORS,
                     );
                 }
 
-                if imu_metrics.baro {
+                if imu_metrics.gyr && self.is_visible(PlotGroup::Gyr) {
+                    let (indices, labels, colors) = with_magnitude(
+                        gyr_indices,
+                        gyr.map(|c| c.name),
+                        (MAGNITUDE_INDICES[1], "|gyr|"),
+                        show_magnitudes,
+                    );
+
                     self.plotter.render_plot(
                         ui,
-                        "baro_p",
-                        "Pressure (Pa)",
-                        baro_indices,
-                        &["Baro"],
-                        &[Color32::LIGHT_BLUE],
+                        "gyr_p",
+                        &group_title("Gyroscope", &gyr),
+                        &indices,
+                        &labels,
+                        &colors,
                     );
                 }
 
-                if imu_metrics.quat {
-                    self.plotter.render_plot(
-                        ui,
-                        "quat_w_p",
-                        "Attitude (Quaternion W)",
-                        &[0],
-                        &["W"],
-                        &[Color32::WHITE],
+                if imu_metrics.mag && self.is_visible(PlotGroup::Mag) {
+                    let (indices, labels, colors) = with_magnitude(
+                        mag_indices,
+                        mag.map(|c| c.name),
+                        (MAGNITUDE_INDICES[2], "|mag|"),
+                        show_magnitudes,
                     );
 
                     self.plotter.render_plot(
                         ui,
-                        "quat_x_p",
-                        "Attitude (Quaternion X)",
-                        &[1],
-                        &["X"],
-                        &[Color32::LIGHT_RED],
+                        "mag_p",
+                        &group_title("Magnetometer", &mag),
+                        &indices,
+                        &labels,
+                        &colors,
                     );
+                }
 
+                if imu_metrics.baro && self.is_visible(PlotGroup::Baro) {
                     self.plotter.render_plot(
                         ui,
-                        "quat_y_p",
-                        "Attitude (Quaternion Y)",
-                        &[2],
-                        &["Y"],
-                        &[Color32::LIGHT_GREEN],
+                        "baro_p",
+                        &group_title("Pressure", &baro),
+                        baro_indices,
+                        &baro.map(|c| c.name),
+                        &[Color32::LIGHT_BLUE],
                     );
 
                     self.plotter.render_plot(
                         ui,
-                        "quat_z_p",
-                        "Attitude (Quaternion Z)",
-                        &[3],
-                        &["Z"],
-                        &[Color32::LIGHT_BLUE],
+                        "altitude_p",
+                        &group_title("Barometric altitude", &altitude),
+                        &[ALTITUDE_INDEX],
+                        &altitude.map(|c| c.name),
+                        &[Color32::LIGHT_GREEN],
                     );
                 }
+
+                if imu_metrics.quat && self.is_visible(PlotGroup::Quat) {
+                    self.display_quat_plots(ui);
+                }
             });
         });
     }
 }
+
+/// Display current vibration metrics of the accelerometer axes.
+///
+/// # Parameters
+/// - `ui` - given screen UI handler.
+/// - `frame_ctx` - given current frame context to handle.
+/// - `app_cfg` - given global config to handle.
+fn display_vibration_metrics(
+    ui: &mut egui::Ui,
+    frame_ctx: &FrameContext,
+    app_cfg: &AppConfig,
+) {
+    let format = &app_cfg.display.format.acc;
+    let ch = &app_cfg.channels;
+    let acc = channels(ch, ["acc_x", "acc_y", "acc_z"]);
+
+    Grid::new("vibration_metrics")
+        .num_columns(4)
+        .spacing([24.0, 4.0])
+        .show(ui, |ui| {
+            ui.label(RichText::new("Vibration").strong());
+
+            for (channel, color) in acc.iter().zip(GROUP_COLORS) {
+                ui.label(RichText::new(channel.name).color(color));
+            }
+            ui.end_row();
+
+            let rows: [(&str, fn(&VibrationMetrics) -> [f32; 3]); 2] =
+                [("RMS", |m| m.rms), ("Peak-to-peak", |m| m.peak_to_peak)];
+
+            for (name, values) in rows {
+                ui.label(name);
+
+                for (i, channel) in acc.iter().enumerate() {
+                    let value = frame_ctx
+                        .vibration
+                        .as_ref()
+                        .and_then(|m| values(m).get(i).copied())
+                        .map_or_else(
+                            || "-".to_string(),
+                            |v| {
+                                format!("{} {}", format.format(v), channel.unit)
+                            },
+                        );
+
+                    ui.label(value);
+                }
+                ui.end_row();
+            }
+        });
+}
+
+/// Get channels info.
+///
+/// # Parameters
+/// - `registry` - given decoded channels info.
+/// - `keys` - given channel keys.
+///
+/// # Returns
+/// - Channels info in the order of the keys.
+fn channels<const N: usize>(
+    registry: &ChannelRegistry,
+    keys: [&'static str; N],
+) -> [ChannelInfo; N] {
+    keys.map(|key| registry.info(key))
+}
+
+/// Get traces of the sensor axes with optional vector magnitude trace.
+///
+/// # Parameters
+/// - `indices` - given indices of the sensor axes in history.
+/// - `labels` - given labels of the sensor axes.
+/// - `magnitude` - given index in history and label of the vector magnitude.
+/// - `is_shown` - given indicator whether vector magnitude is plotted.
+///
+/// # Returns
+/// - Indices, labels and colors of the traces to plot.
+fn with_magnitude(
+    indices: &[usize; 3],
+    labels: [&'static str; 3],
+    magnitude: (usize, &'static str),
+    is_shown: bool,
+) -> (Vec<usize>, Vec<&'static str>, Vec<Color32>) {
+    let mut indices = indices.to_vec();
+    let mut labels = labels.to_vec();
+    let mut colors = GROUP_COLORS.to_vec();
+
+    if is_shown {
+        indices.push(magnitude.0);
+        labels.push(magnitude.1);
+        colors.push(MAGNITUDE_COLOR);
+    }
+
+    (indices, labels, colors)
+}
+
+/// Get plot title of the channels group.
+///
+/// # Parameters
+/// - `group` - given channels group name.
+/// - `channels` - given channels of the group.
+///
+/// # Returns
+/// - Plot title with measurement unit (e.g. `Accelerometer (m/s²)`).
+fn group_title(group: &str, channels: &[ChannelInfo]) -> String {
+    match channels.first() {
+        Some(channel) if !channel.unit.is_empty() => {
+            format!("{group} ({})", channel.unit)
+        }
+        _ => group.to_string(),
+    }
+}
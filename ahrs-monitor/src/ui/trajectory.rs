@@ -0,0 +1,178 @@
+// SPDX-License-Identifier: Apache-2.0.
+// Copyright (C) 2026-present ahrs-monitor project and contributors.
+
+//! Dead-reckoning trajectory tab user interface implementation.
+
+use crate::{
+    config::AppConfig, core::dead_reckoning::NavState, model::FrameContext,
+    ui::TabViewer,
+};
+use eframe::epaint::Color32;
+use egui::{Grid, RichText};
+use egui_plot::{Line, Plot, PlotPoints, Points};
+use std::collections::VecDeque;
+
+/// Max number of displayed trajectory points.
+const MAX_POINTS: usize = 20_000;
+
+/// Dead-reckoning trajectory tab handler.
+#[derive(Debug, Default)]
+pub struct TrajectoryTab {
+    /// Horizontal (x, y) positions of the trajectory in meters.
+    points: VecDeque<[f64; 2]>,
+    /// Latest navigation state.
+    state: Option<NavState>,
+}
+
+impl TrajectoryTab {
+    /// Add new navigation state to the trajectory.
+    ///
+    /// # Parameters
+    /// - `state` - given dead-reckoning navigation state.
+    pub fn add_data(&mut self, state: NavState) {
+        let [x, y, _] = state.position;
+
+        self.points.push_back([f64::from(x), f64::from(y)]);
+
+        if self.points.len() > MAX_POINTS {
+            self.points.pop_front();
+        }
+
+        self.state = Some(state);
+    }
+
+    /// Display navigation state values.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `state` - given navigation state to display.
+    fn display_values(ui: &mut egui::Ui, state: &NavState) {
+        Grid::new("trajectory_values")
+            .num_columns(4)
+            .show(ui, |ui| {
+                for header in ["", "X", "Y", "Z"] {
+                    ui.label(RichText::new(header).strong());
+                }
+                ui.end_row();
+
+                for (name, values) in [
+                    ("Velocity, (m/s)", state.velocity),
+                    ("Position, (m)", state.position),
+                ] {
+                    ui.label(name);
+
+                    for v in values {
+                        ui.label(format!("{v:.3}"));
+                    }
+                    ui.end_row();
+                }
+            });
+
+        if state.is_still {
+            ui.label(
+                RichText::new("Still: velocity is reset")
+                    .color(Color32::LIGHT_GREEN),
+            );
+        } else {
+            ui.label(RichText::new("Moving").color(Color32::GRAY));
+        }
+    }
+
+    /// Display tab regardless of the payload type.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `app_cfg` - given global config to handle.
+    ///
+    /// # Returns
+    /// - `true` - if user requested navigation reset.
+    /// - `false` - otherwise.
+    pub fn show(&mut self, ui: &mut egui::Ui, app_cfg: &AppConfig) -> bool {
+        if !app_cfg.imu.dead_reckoning.enabled {
+            ui.label(
+                "Dead reckoning is disabled, enable it with \
+                 imu.dead_reckoning.enabled option.",
+            );
+            return false;
+        }
+
+        let mut is_reset = false;
+
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Dead-Reckoning Trajectory").strong());
+
+            if ui
+                .button("⟲ Reset")
+                .on_hover_text("Reset velocity and position to zero")
+                .clicked()
+            {
+                is_reset = true;
+            }
+        });
+        ui.add_space(10.0);
+
+        if let Some(state) = &self.state {
+            Self::display_values(ui, state);
+        }
+
+        ui.add_space(10.0);
+
+        let path: PlotPoints = self.points.iter().copied().collect();
+
+        Plot::new("trajectory_p")
+            .data_aspect(1.0)
+            .show_grid(true)
+            .allow_double_click_reset(true)
+            .x_axis_label("X, (m)")
+            .y_axis_label("Y, (m)")
+            .show(ui, |plot_ui| {
+                plot_ui.line(
+                    Line::new("Trajectory", path)
+                        .color(Color32::LIGHT_BLUE)
+                        .width(0.8),
+                );
+
+                if let Some(&position) = self.points.back() {
+                    plot_ui.points(
+                        Points::new("Position", vec![position])
+                            .color(Color32::LIGHT_RED)
+                            .radius(4.0),
+                    );
+                }
+            });
+
+        if is_reset {
+            *self = Self::default();
+        }
+
+        is_reset
+    }
+}
+
+impl TabViewer for TrajectoryTab {
+    /// Get tab title.
+    ///
+    /// # Returns
+    /// - Tab title string slice.
+    fn title(&self) -> &'static str {
+        "Trajectory"
+    }
+
+    /// Get tab icon.
+    ///
+    /// # Returns
+    /// - Tab icon string slice.
+    fn icon(&self) -> &'static str {
+        "🧭"
+    }
+
+    /// Display tab.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `frame_ctx` - given current frame context to handle.
+    /// - `app_cfg` - given global config to handle.
+    fn ui(&mut self, ui: &mut egui::Ui, _: &FrameContext, app_cfg: &AppConfig) {
+        let _ = self.show(ui, app_cfg);
+    }
+}
@@ -3,12 +3,32 @@
 
 //! Utils for AHRS Monitor user interface.
 
-use crate::{core::StandardPayload, model::FrameWrapper};
-use eframe::epaint::Color32;
-use egui::RichText;
-use egui_plot::{Corner, GridMark, Legend, Line, Plot, PlotPoints};
+#[cfg(feature = "gpu-plots")]
+use crate::ui::gpu_plot::GpuLine;
+use crate::{
+    config::{MetricFormat, PLOT_EXPORT_DIRECTORY, PlotRenderer},
+    core::{StandardPayload, channel::ChannelInfo},
+    model::FrameWrapper,
+    report,
+    ui::HiddenTraces,
+};
+use chrono::{DateTime, Local};
+use eframe::epaint::{Color32, ColorImage, Stroke};
+use egui::{
+    Align, DragValue, Event, Id, Layout, Rect, RichText, UserData,
+    ViewportCommand,
+};
+use egui_plot::{
+    Corner, GridMark, HLine, Legend, Line, Plot, PlotBounds, PlotPoints,
+    PlotResponse, PlotUi, Points, Polygon, VLine,
+};
 use indtp::types::F32;
-use std::collections::VecDeque;
+use std::{
+    collections::{BTreeSet, HashMap, VecDeque},
+    fs,
+    path::PathBuf,
+    sync::Arc,
+};
 
 /// Custom metric struct.
 pub struct Metric<'a> {
@@ -53,8 +73,11 @@ impl<'a> Metric<'a> {
     ///
     /// # Parameters
     /// - `ui` - given screen UI handler.
-    pub fn display(&self, ui: &mut egui::Ui) {
-        ui.horizontal(|ui| {
+    ///
+    /// # Returns
+    /// - Response of the metric row.
+    pub fn display(&self, ui: &mut egui::Ui) -> egui::Response {
+        let response = ui.horizontal(|ui| {
             let color = self.color.unwrap_or(Color32::WHITE);
 
             ui.label(self.name);
@@ -66,6 +89,7 @@ impl<'a> Metric<'a> {
         });
 
         ui.separator();
+        response.response
     }
 }
 
@@ -75,31 +99,226 @@ impl<'a> Metric<'a> {
 /// - `ui` - given screen UI handler.
 /// - `label` - given metric group label.
 /// - `values` - given metric values.
-/// - `unit` - given metric measurement unit.
+/// - `channels` - given metric values channels info.
+/// - `format` - given metric values format.
 pub fn display_metric_group(
     ui: &mut egui::Ui,
     label: &str,
     values: &[f32],
-    unit: Option<&str>,
+    channels: &[ChannelInfo],
+    format: MetricFormat,
 ) {
     let axes = ["X", "Y", "Z", "W"];
 
     for (i, &val) in values.iter().enumerate() {
         let name = format!("{} {}:", label, axes.get(i).unwrap_or(&"?"));
-        let value_str = format!("{val:.6}");
-        Metric::new(&name, &value_str, unit, None).display(ui);
+        let value_str = format.format(val);
+        let channel = channels.get(i);
+        let unit = channel.map(|c| c.unit).filter(|unit| !unit.is_empty());
+
+        // Highlight values out of the channel valid range.
+        let color = channel
+            .filter(|c| !c.is_valid(val))
+            .map(|_| Color32::LIGHT_RED);
+
+        Metric::new(&name, &value_str, unit, color).display(ui);
+    }
+}
+
+/// Width of the sparklines in pixels.
+const SPARKLINE_WIDTH: f32 = 60.0;
+
+/// Fill color of flagged (e.g. clipped) plot intervals.
+const FLAGGED_INTERVAL_COLOR: Color32 =
+    Color32::from_rgba_premultiplied(90, 20, 20, 60);
+
+/// Legend label of flagged (e.g. clipped) plot intervals.
+const FLAGGED_INTERVAL_LABEL: &str = "Clipping";
+
+/// Color of marked (e.g. anomalous) plot points.
+const MARKER_COLOR: Color32 = Color32::YELLOW;
+
+/// Legend label of marked (e.g. anomalous) plot points.
+const MARKER_LABEL: &str = "Anomaly";
+
+/// Color of the timeline events (e.g. protocol mode transitions).
+const EVENT_COLOR: Color32 = Color32::from_rgb(255, 165, 0);
+
+/// Color of the playback cursor.
+const CURSOR_COLOR: Color32 = Color32::WHITE;
+
+/// Legend label of the playback cursor.
+const CURSOR_LABEL: &str = "Playback";
+
+/// Color of the hover crosshair.
+const CROSSHAIR_COLOR: Color32 = Color32::from_gray(160);
+
+/// Legend label of the hover crosshair.
+const CROSSHAIR_LABEL: &str = "Crosshair";
+
+/// Legend label of the uncertainty bands.
+const BAND_LABEL: &str = "Uncertainty";
+
+/// Max number of segments of the uncertainty band.
+const MAX_BAND_SEGMENTS: usize = 200;
+
+/// Time axis of the plots.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TimeAxis {
+    /// Sensor-local time.
+    #[default]
+    Sensor,
+    /// Estimated host wall-clock time.
+    WallClock,
+}
+
+/// Format host time as local wall-clock time.
+///
+/// # Parameters
+/// - `host_us` - given host time in Unix microseconds.
+///
+/// # Returns
+/// - Local time string (`HH:MM:SS.mmm`).
+#[must_use]
+pub fn format_host_time(host_us: i64) -> String {
+    DateTime::from_timestamp_micros(host_us).map_or_else(
+        || "-".to_string(),
+        |time| {
+            time.with_timezone(&Local)
+                .format("%H:%M:%S%.3f")
+                .to_string()
+        },
+    )
+}
+
+/// Format data throughput.
+///
+/// # Parameters
+/// - `bytes_per_second` - given number of bytes per second.
+///
+/// # Returns
+/// - Throughput string in B/s, kB/s or MB/s (decimal prefixes).
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn format_throughput(bytes_per_second: usize) -> String {
+    let value = bytes_per_second as f64;
+
+    if value >= 1e6 {
+        format!("{:.2} MB/s", value / 1e6)
+    } else if value >= 1e3 {
+        format!("{:.1} kB/s", value / 1e3)
+    } else {
+        format!("{bytes_per_second} B/s")
+    }
+}
+
+/// Display sparkline of the recent values.
+///
+/// Values are scaled from zero to their max, so that drops and bursts are
+/// visible regardless of the absolute level.
+///
+/// # Parameters
+/// - `ui` - given screen UI handler.
+/// - `values` - given values from the oldest to the latest.
+/// - `capacity` - given number of values fitting the sparkline width.
+/// - `color` - given line color.
+///
+/// # Returns
+/// - Sparkline response.
+#[allow(clippy::cast_precision_loss)]
+pub fn display_sparkline(
+    ui: &mut egui::Ui,
+    values: &[usize],
+    capacity: usize,
+    color: Color32,
+) -> egui::Response {
+    let height = ui.spacing().interact_size.y;
+    let (rect, response) = ui.allocate_exact_size(
+        egui::vec2(SPARKLINE_WIDTH, height),
+        egui::Sense::hover(),
+    );
+
+    let max = values.iter().copied().max().unwrap_or_default().max(1);
+    let step = rect.width() / capacity.saturating_sub(1).max(1) as f32;
+    // Latest value is aligned to the right edge.
+    let start = rect.right() - step * values.len().saturating_sub(1) as f32;
+
+    let points: Vec<egui::Pos2> = values
+        .iter()
+        .enumerate()
+        .map(|(i, &value)| {
+            let ratio = value as f32 / max as f32;
+            egui::pos2(
+                step.mul_add(i as f32, start),
+                rect.bottom() - ratio * rect.height(),
+            )
+        })
+        .collect();
+
+    if let Some(&last) = points.last() {
+        ui.painter()
+            .add(egui::Shape::line(points, Stroke::new(1.0, color)));
+        ui.painter().circle_filled(last, 1.5, color);
     }
+
+    response
 }
 
 /// Metrics plotter struct.
+///
+/// `POINTS` is the default number of points in history per each metric,
+/// which can be changed at runtime with [`Plotter::set_max_points`].
 #[derive(Debug)]
 pub struct Plotter<const ENTRIES: usize, const POINTS: usize> {
     /// Metrics history.
     history: [VecDeque<f64>; ENTRIES],
+    /// Metrics flags history (e.g. clipped samples).
+    flags: [VecDeque<bool>; ENTRIES],
     /// Timestamps history.
     timestamps: VecDeque<f64>,
+    /// Half-width of the uncertainty band history (0 - no band).
+    bands: VecDeque<f64>,
+    /// Marked points (metric entry, timestamp, value), e.g. anomalies.
+    markers: VecDeque<(usize, f64, f64)>,
+    /// Timeline events (timestamp, label), e.g. protocol mode transitions.
+    events: VecDeque<(f64, String)>,
+    /// Playback cursor timestamp in seconds (`None` - no cursor).
+    cursor: Option<f64>,
     /// Plot height in pixels.
     plot_height: Option<f32>,
+    /// Time axis of the timestamps.
+    time_axis: TimeAxis,
+    /// Max number of points in history per each metric.
+    max_points: usize,
+    /// Rendering backend of the plots.
+    #[cfg_attr(not(feature = "gpu-plots"), allow(dead_code))]
+    renderer: PlotRenderer,
+    /// Plot waiting for the screenshot to export (`None` - no export).
+    export: Option<PlotExport>,
+    /// Result of the last plot export.
+    export_status: Option<String>,
+    /// View settings of each plot by plot identifier.
+    views: HashMap<String, PlotView<ENTRIES, POINTS>>,
+}
+
+/// View settings of a single plot.
+#[derive(Debug, Default)]
+struct PlotView<const ENTRIES: usize, const POINTS: usize> {
+    /// Copy of the data at the moment of pause (`None` - live data).
+    frozen: Option<Box<Plotter<ENTRIES, POINTS>>>,
+    /// Indicator whether plot is rescaled to the data on every frame.
+    autoscale: bool,
+    /// Locked Y axis range [min, max] (`None` - not locked).
+    y_range: Option<[f64; 2]>,
+}
+
+/// Plot view export waiting for the screenshot.
+#[derive(Debug, Clone)]
+struct PlotExport {
+    /// Exported plot identifier.
+    id: String,
+    /// Plot area on the screen.
+    rect: Rect,
 }
 
 impl<const ENTRIES: usize, const POINTS: usize> Plotter<ENTRIES, POINTS> {
@@ -108,23 +327,197 @@ impl<const ENTRIES: usize, const POINTS: usize> Plotter<ENTRIES, POINTS> {
     /// # Parameters
     /// - `data` - given data to append.
     /// - `timestamp` - given timestamp in microseconds.
+    #[inline]
     pub fn add_data(&mut self, data: [f32; ENTRIES], timestamp: u64) {
+        self.add_flagged_data(data, [false; ENTRIES], timestamp);
+    }
+
+    /// Append flagged data to the points history.
+    ///
+    /// # Parameters
+    /// - `data` - given data to append.
+    /// - `flags` - given flags of each metric entry (e.g. clipped reading).
+    /// - `timestamp` - given timestamp in microseconds.
+    pub fn add_flagged_data(
+        &mut self,
+        data: [f32; ENTRIES],
+        flags: [bool; ENTRIES],
+        timestamp: u64,
+    ) {
         #[allow(clippy::cast_precision_loss)]
         let timestamp = timestamp as f64 / 1_000_000.0;
 
-        if self.timestamps.len() >= POINTS {
-            self.timestamps.pop_front();
-        }
         self.timestamps.push_back(timestamp);
+        self.bands.push_back(0.0);
 
         for (i, &val) in data.iter().enumerate() {
             if let Some(sequence) = self.history.get_mut(i) {
-                if sequence.len() >= POINTS {
-                    sequence.pop_front();
-                }
                 sequence.push_back(f64::from(val));
             }
         }
+
+        for (i, &flag) in flags.iter().enumerate() {
+            if let Some(sequence) = self.flags.get_mut(i) {
+                sequence.push_back(flag);
+            }
+        }
+
+        self.truncate_history();
+    }
+
+    /// Mark the most recent point of the metric entry.
+    ///
+    /// # Parameters
+    /// - `entry` - given index of the metric in history.
+    pub fn add_marker(&mut self, entry: usize) {
+        if let Some(&ts) = self.timestamps.back()
+            && let Some(&val) =
+                self.history.get(entry).and_then(|sequence| sequence.back())
+        {
+            self.markers.push_back((entry, ts, val));
+        }
+    }
+
+    /// Set uncertainty band of the most recent point of every metric.
+    ///
+    /// # Parameters
+    /// - `half_width` - given band half-width in units of the metrics.
+    pub fn set_band(&mut self, half_width: f32) {
+        if let Some(band) = self.bands.back_mut() {
+            *band = f64::from(half_width);
+        }
+    }
+
+    /// Mark the most recent timestamp with the timeline event.
+    ///
+    /// # Parameters
+    /// - `label` - given event label.
+    pub fn add_event(&mut self, label: String) {
+        if let Some(&ts) = self.timestamps.back() {
+            self.events.push_back((ts, label));
+        }
+    }
+
+    /// Set playback cursor.
+    ///
+    /// # Parameters
+    /// - `timestamp` - given cursor timestamp in microseconds (`None` - hide
+    ///   cursor).
+    pub fn set_cursor(&mut self, timestamp: Option<u64>) {
+        #[allow(clippy::cast_precision_loss)]
+        {
+            self.cursor = timestamp.map(|ts| ts as f64 / 1_000_000.0);
+        }
+    }
+
+    /// Convert metrics history into another unit.
+    ///
+    /// Uncertainty band is converted as well, paused plots keep their data
+    /// consistent with the live one.
+    ///
+    /// # Parameters
+    /// - `entries` - given indices of metrics in history to convert.
+    /// - `factor` - given factor converting values into the new unit.
+    pub fn rescale(&mut self, entries: &[usize], factor: f32) {
+        let scale = f64::from(factor);
+
+        for &entry in entries {
+            if let Some(sequence) = self.history.get_mut(entry) {
+                sequence.iter_mut().for_each(|value| *value *= scale);
+            }
+        }
+
+        self.bands.iter_mut().for_each(|band| *band *= scale);
+
+        for (entry, _, value) in &mut self.markers {
+            if entries.contains(entry) {
+                *value *= scale;
+            }
+        }
+
+        for view in self.views.values_mut() {
+            if let Some(frozen) = &mut view.frozen {
+                frozen.rescale(entries, factor);
+            }
+        }
+    }
+
+    /// Drop the oldest points exceeding max number of points in history.
+    fn truncate_history(&mut self) {
+        let max_points = self.max_points;
+        let excess = |len: usize| len.saturating_sub(max_points);
+
+        self.timestamps.drain(..excess(self.timestamps.len()));
+        self.bands.drain(..excess(self.bands.len()));
+
+        for sequence in &mut self.history {
+            sequence.drain(..excess(sequence.len()));
+        }
+
+        for sequence in &mut self.flags {
+            sequence.drain(..excess(sequence.len()));
+        }
+
+        if let Some(&start) = self.timestamps.front() {
+            self.markers.retain(|&(_, ts, _)| ts >= start);
+            self.events.retain(|(ts, _)| *ts >= start);
+        }
+    }
+
+    /// Get time intervals where any of specified metrics is flagged.
+    ///
+    /// # Parameters
+    /// - `indices` - given indices of specific metrics in history.
+    ///
+    /// # Returns
+    /// - List of flagged intervals (start, end) in seconds.
+    fn flagged_intervals(&self, indices: &[usize]) -> Vec<(f64, f64)> {
+        let mut intervals: Vec<(f64, f64)> = Vec::new();
+        let mut is_open = false;
+
+        for (i, &ts) in self.timestamps.iter().enumerate() {
+            let is_flagged = indices.iter().any(|&idx| {
+                self.flags
+                    .get(idx)
+                    .and_then(|sequence| sequence.get(i))
+                    .copied()
+                    .unwrap_or(false)
+            });
+
+            // Flagged sample lasts until the next sample arrives.
+            let end = self.timestamps.get(i + 1).copied().unwrap_or(ts);
+
+            match (is_flagged, is_open, intervals.last_mut()) {
+                (true, true, Some(last)) => last.1 = end,
+                (true, _, _) => intervals.push((ts, end)),
+                _ => {}
+            }
+
+            is_open = is_flagged;
+        }
+
+        intervals
+    }
+
+    /// Get range of values of specified metrics.
+    ///
+    /// # Parameters
+    /// - `indices` - given indices of specific metrics in history.
+    ///
+    /// # Returns
+    /// - Range of values (min, max) - in case of success.
+    /// - `None` - if there is no data.
+    fn value_range(&self, indices: &[usize]) -> Option<(f64, f64)> {
+        indices
+            .iter()
+            .filter_map(|&idx| self.history.get(idx))
+            .flatten()
+            .fold(None, |range, &val| match range {
+                Some((min, max)) => {
+                    Some((f64::min(min, val), f64::max(max, val)))
+                }
+                None => Some((val, val)),
+            })
     }
 
     /// Get most recent value for each metric entry.
@@ -160,8 +553,57 @@ impl<const ENTRIES: usize, const POINTS: usize> Plotter<ENTRIES, POINTS> {
         self.plot_height = height;
     }
 
+    /// Set max number of points in history per each metric.
+    ///
+    /// History buffers are reallocated for the new number of points, so that
+    /// shortened history releases its memory.
+    ///
+    /// # Parameters
+    /// - `max_points` - given max number of points to set.
+    pub fn set_max_points(&mut self, max_points: usize) {
+        if max_points == self.max_points {
+            return;
+        }
+
+        self.max_points = max_points;
+        self.truncate_history();
+
+        for sequence in &mut self.history {
+            resize_buffer(sequence, max_points);
+        }
+
+        for sequence in &mut self.flags {
+            resize_buffer(sequence, max_points);
+        }
+
+        resize_buffer(&mut self.timestamps, max_points);
+        resize_buffer(&mut self.bands, max_points);
+    }
+
+    /// Set rendering backend of the plots.
+    ///
+    /// # Parameters
+    /// - `renderer` - given rendering backend to set.
+    #[inline]
+    pub const fn set_renderer(&mut self, renderer: PlotRenderer) {
+        self.renderer = renderer;
+    }
+
+    /// Set time axis of the timestamps.
+    ///
+    /// # Parameters
+    /// - `time_axis` - given time axis to set.
+    #[inline]
+    pub const fn set_time_axis(&mut self, time_axis: TimeAxis) {
+        self.time_axis = time_axis;
+    }
+
     /// Render metrics plot.
     ///
+    /// Each plot has its own pause, autoscale and Y axis range lock, so that
+    /// a single trace can be frozen while the others keep updating. Traces
+    /// hidden from the legend stay hidden after restart.
+    ///
     /// # Parameters
     /// - `ui` - given screen UI handler.
     /// - `id` - given plot identifier.
@@ -169,7 +611,6 @@ impl<const ENTRIES: usize, const POINTS: usize> Plotter<ENTRIES, POINTS> {
     /// - `indices` - given indices of specific metrics in history.
     /// - `labels` - given labels for each metric to plot.
     /// - `colors` - given colors for each metric to plot.
-    #[allow(clippy::cast_precision_loss)]
     pub fn render_plot(
         &mut self,
         ui: &mut egui::Ui,
@@ -179,8 +620,158 @@ impl<const ENTRIES: usize, const POINTS: usize> Plotter<ENTRIES, POINTS> {
         labels: &[&str],
         colors: &[Color32],
     ) {
-        ui.label(RichText::new(title).strong());
+        self.handle_export(ui, id);
+        let mut view = self.views.remove(id).unwrap_or_default();
+        let mut hidden = HiddenTraces::of_plot(ui.ctx(), id);
+        let previous = hidden.clone();
+
+        ui.horizontal(|ui| {
+            ui.label(RichText::new(title).strong());
+
+            if let Some(status) = &self.export_status {
+                ui.weak(status);
+            }
+
+            ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                self.display_view_controls(ui, &mut view, indices);
+            });
+        });
+
+        match view.frozen.take() {
+            Some(mut frozen) => {
+                frozen.show_plot(
+                    ui,
+                    id,
+                    indices,
+                    labels,
+                    colors,
+                    &view,
+                    &mut hidden,
+                );
+
+                // Export is handled by the live plotter.
+                if let Some(export) = frozen.export.take() {
+                    self.export = Some(export);
+                }
+
+                view.frozen = Some(frozen);
+            }
+            None => self.show_plot(
+                ui,
+                id,
+                indices,
+                labels,
+                colors,
+                &view,
+                &mut hidden,
+            ),
+        }
+
+        if hidden != previous {
+            HiddenTraces::set_plot(ui.ctx(), id, hidden);
+        }
+
+        self.views.insert(id.to_owned(), view);
+        ui.add_space(10.0);
+    }
+
+    /// Display plot view controls.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `view` - given plot view settings to update.
+    /// - `indices` - given indices of specific metrics in history.
+    fn display_view_controls(
+        &self,
+        ui: &mut egui::Ui,
+        view: &mut PlotView<ENTRIES, POINTS>,
+        indices: &[usize],
+    ) {
+        let is_paused = view.frozen.is_some();
+        let (icon, hint) = if is_paused {
+            ("▶", "Resume plot")
+        } else {
+            ("⏸", "Pause plot")
+        };
+
+        if ui.small_button(icon).on_hover_text(hint).clicked() {
+            view.frozen = (!is_paused).then(|| Box::new(self.snapshot()));
+        }
+
+        ui.toggle_value(&mut view.autoscale, "↕ Auto")
+            .on_hover_text("Rescale plot to the data on every frame");
 
+        let mut is_locked = view.y_range.is_some();
+
+        if ui
+            .toggle_value(&mut is_locked, "🔒 Y")
+            .on_hover_text("Lock Y axis to the manual range")
+            .changed()
+        {
+            view.y_range = is_locked.then(|| {
+                let (min, max) = self.value_range(indices).unwrap_or_default();
+
+                if max - min < f64::EPSILON {
+                    [min - 1.0, max + 1.0]
+                } else {
+                    [min, max]
+                }
+            });
+        }
+
+        if let Some([min, max]) = &mut view.y_range {
+            ui.add(DragValue::new(max).speed(0.1).range(*min..=f64::MAX))
+                .on_hover_text("Y axis max");
+            ui.add(DragValue::new(min).speed(0.1).range(f64::MIN..=*max))
+                .on_hover_text("Y axis min");
+        }
+    }
+
+    /// Copy data of the plotter for the paused plot.
+    ///
+    /// # Returns
+    /// - Plotter with the copy of the current data.
+    fn snapshot(&self) -> Self {
+        Self {
+            history: self.history.clone(),
+            flags: self.flags.clone(),
+            timestamps: self.timestamps.clone(),
+            bands: self.bands.clone(),
+            markers: self.markers.clone(),
+            events: self.events.clone(),
+            cursor: self.cursor,
+            plot_height: self.plot_height,
+            time_axis: self.time_axis,
+            max_points: self.max_points,
+            renderer: self.renderer,
+            export: None,
+            export_status: None,
+            views: HashMap::new(),
+        }
+    }
+
+    /// Show metrics plot.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `id` - given plot identifier.
+    /// - `indices` - given indices of specific metrics in history.
+    /// - `labels` - given labels for each metric to plot.
+    /// - `colors` - given colors for each metric to plot.
+    /// - `view` - given plot view settings.
+    /// - `hidden` - given labels of the traces hidden from the legend to
+    ///   update.
+    #[allow(clippy::too_many_arguments)]
+    fn show_plot(
+        &mut self,
+        ui: &mut egui::Ui,
+        id: &str,
+        indices: &[usize],
+        labels: &[&str],
+        colors: &[Color32],
+        view: &PlotView<ENTRIES, POINTS>,
+        hidden: &mut BTreeSet<String>,
+    ) {
         let plot_height = self.plot_height.unwrap_or(256.0);
 
         let (x_min, x_max) = if let (Some(&min_ts), Some(&max_ts)) =
@@ -191,60 +782,357 @@ impl<const ENTRIES: usize, const POINTS: usize> Plotter<ENTRIES, POINTS> {
             (0.0, 1.0)
         };
 
-        let base_ts = 0.0;
+        let time_axis = self.time_axis;
 
         let plot = Plot::new(id)
             .height(plot_height)
             .show_grid(true)
-            .legend(Legend::default().position(Corner::RightTop))
+            .legend(
+                Legend::default()
+                    .position(Corner::RightTop)
+                    .hidden_items(hidden.iter().map(Id::new)),
+            )
             .include_x(x_min)
             .include_x(x_max)
             .allow_double_click_reset(true)
+            .show_x(false)
+            .show_y(false)
             .x_axis_formatter(move |mark: GridMark, _| {
-                let rel_s = mark.value - base_ts;
-
-                #[allow(clippy::cast_possible_truncation)]
-                let total_ms = (rel_s * 1000.0).round() as i64;
-                let ms = total_ms.abs() % 1000;
-                let total_sec = total_ms / 1000;
-                let sec = total_sec.abs() % 60;
-                let min = total_sec.abs() / 60 % 60;
-                let hours = total_sec.abs() / 3600;
-
-                let sign = if rel_s < 0.0 { "-" } else { "" };
-
-                if hours > 0 {
-                    format!("{sign}{hours}:{min:02}:{sec:02}.{ms:03}")
-                } else if min > 0 {
-                    format!("{sign}{min:02}:{sec:02}.{ms:03}")
-                } else {
-                    format!("{sign}{sec}.{ms:03}")
-                }
+                format_time_mark(mark.value, time_axis)
             })
-            .x_axis_label("Time, (sec)");
+            .x_axis_label(match time_axis {
+                TimeAxis::Sensor => "Time, (sec)",
+                TimeAxis::WallClock => "Host time",
+            });
+
+        let flagged_intervals = self.flagged_intervals(indices);
+        let value_range = self.value_range(indices);
+
+        let response = plot.show(ui, |plot_ui| {
+            apply_view(plot_ui, view, x_min, x_max);
+
+            if let Some(range) = value_range {
+                render_flagged_intervals(plot_ui, &flagged_intervals, range);
+            }
 
-        plot.show(ui, |plot_ui| {
+            self.render_traces(plot_ui, id, indices, labels, colors, hidden);
+            self.render_overlays(plot_ui, indices);
+            self.render_crosshair(plot_ui)
+        });
+
+        *hidden = hidden_labels(labels, &response);
+
+        if let Some(sample) = response.inner {
+            response.response.clone().on_hover_ui_at_pointer(|ui| {
+                self.display_readout(ui, sample, indices, labels, colors);
+            });
+        }
+
+        self.display_export_menu(&response.response, id);
+    }
+
+    /// Render uncertainty bands and lines of the plotted metrics.
+    ///
+    /// # Parameters
+    /// - `plot_ui` - given plot UI handler.
+    /// - `id` - given plot identifier.
+    /// - `indices` - given indices of specific metrics in history.
+    /// - `labels` - given labels for each metric to plot.
+    /// - `colors` - given colors for each metric to plot.
+    /// - `hidden` - given labels of the traces hidden from the legend.
+    #[cfg_attr(not(feature = "gpu-plots"), allow(unused_variables))]
+    fn render_traces(
+        &self,
+        plot_ui: &mut PlotUi<'_>,
+        id: &str,
+        indices: &[usize],
+        labels: &[&str],
+        colors: &[Color32],
+        hidden: &BTreeSet<String>,
+    ) {
+        // Shading uncertainty bands around plotted values.
+        if self.bands.iter().any(|&band| band > 0.0) {
             for (i, history_idx) in indices.iter().enumerate() {
-                if let Some(sequence) = self.history.get(*history_idx) {
-                    let points: PlotPoints = self
-                        .timestamps
-                        .iter()
-                        .zip(sequence.iter())
-                        .map(|(&ts, &val)| [ts, val])
-                        .collect();
-
-                    if let Some(label) = labels.get(i)
-                        && let Some(color) = colors.get(i)
-                    {
-                        plot_ui.line(
-                            Line::new(*label, points).color(*color).width(0.8),
-                        );
-                    }
+                if let Some(sequence) = self.history.get(*history_idx)
+                    && let Some(color) = colors.get(i)
+                    && labels.get(i).is_none_or(|l| !hidden.contains(*l))
+                {
+                    self.render_band(plot_ui, sequence, *color);
                 }
             }
+        }
+
+        for (i, history_idx) in indices.iter().enumerate() {
+            if let Some(sequence) = self.history.get(*history_idx)
+                && let Some(label) = labels.get(i)
+                && let Some(color) = colors.get(i)
+            {
+                #[cfg(feature = "gpu-plots")]
+                if self.renderer == PlotRenderer::Gpu {
+                    plot_ui.add(
+                        GpuLine::new(id, label, &self.timestamps, sequence)
+                            .color(*color),
+                    );
+                    continue;
+                }
+
+                let points: PlotPoints = self
+                    .timestamps
+                    .iter()
+                    .zip(sequence.iter())
+                    .map(|(&ts, &val)| [ts, val])
+                    .collect();
+
+                plot_ui
+                    .line(Line::new(*label, points).color(*color).width(0.8));
+            }
+        }
+    }
+
+    /// Render markers, event lines and playback cursor.
+    ///
+    /// # Parameters
+    /// - `plot_ui` - given plot UI handler.
+    /// - `indices` - given indices of specific metrics in history.
+    fn render_overlays(&self, plot_ui: &mut PlotUi<'_>, indices: &[usize]) {
+        let markers: Vec<[f64; 2]> = self
+            .markers
+            .iter()
+            .filter(|(entry, _, _)| indices.contains(entry))
+            .map(|&(_, ts, val)| [ts, val])
+            .collect();
+
+        if !markers.is_empty() {
+            plot_ui.points(
+                Points::new(MARKER_LABEL, markers)
+                    .color(MARKER_COLOR)
+                    .radius(3.0),
+            );
+        }
+
+        for (ts, label) in &self.events {
+            plot_ui.vline(
+                VLine::new(label.as_str(), *ts)
+                    .color(EVENT_COLOR)
+                    .width(1.0),
+            );
+        }
+
+        if let Some(ts) = self.cursor {
+            plot_ui.vline(
+                VLine::new(CURSOR_LABEL, ts).color(CURSOR_COLOR).width(1.5),
+            );
+        }
+    }
+
+    /// Render crosshair snapped to the sample nearest to the pointer.
+    ///
+    /// # Parameters
+    /// - `plot_ui` - given plot UI handler.
+    ///
+    /// # Returns
+    /// - Index of the sample under the crosshair - if plot is hovered.
+    /// - `None` - otherwise.
+    fn render_crosshair(&self, plot_ui: &mut PlotUi<'_>) -> Option<usize> {
+        let pointer = plot_ui.pointer_coordinate()?;
+        let sample = self.nearest_sample(pointer.x)?;
+        let ts = self.timestamps.get(sample).copied()?;
+
+        plot_ui.vline(
+            VLine::new(CROSSHAIR_LABEL, ts)
+                .color(CROSSHAIR_COLOR)
+                .width(0.5),
+        );
+        plot_ui.hline(
+            HLine::new(CROSSHAIR_LABEL, pointer.y)
+                .color(CROSSHAIR_COLOR)
+                .width(0.5),
+        );
+
+        Some(sample)
+    }
+
+    /// Display plot context menu with the PNG export.
+    ///
+    /// # Parameters
+    /// - `response` - given plot response.
+    /// - `id` - given plot identifier.
+    fn display_export_menu(&mut self, response: &egui::Response, id: &str) {
+        let rect = response.rect;
+
+        response.context_menu(|ui| {
+            if ui
+                .button("🖼 Export PNG")
+                .on_hover_text(format!(
+                    "Save current plot view into {PLOT_EXPORT_DIRECTORY}"
+                ))
+                .clicked()
+            {
+                ui.ctx().send_viewport_cmd(ViewportCommand::Screenshot(
+                    UserData::new(id.to_string()),
+                ));
+                self.export = Some(PlotExport {
+                    id: id.to_string(),
+                    rect,
+                });
+                ui.close();
+            }
         });
+    }
 
-        ui.add_space(10.0);
+    /// Get index of the sample nearest to the time.
+    ///
+    /// # Parameters
+    /// - `ts` - given time in seconds.
+    ///
+    /// # Returns
+    /// - Sample index - if there is data.
+    /// - `None` - otherwise.
+    fn nearest_sample(&self, ts: f64) -> Option<usize> {
+        let next = self.timestamps.partition_point(|&t| t < ts);
+        let distance =
+            |i: usize| self.timestamps.get(i).map(|&t| (t - ts).abs());
+
+        match (next.checked_sub(1).and_then(distance), distance(next)) {
+            (Some(before), Some(after)) if before <= after => Some(next - 1),
+            (_, Some(_)) => Some(next),
+            (Some(_), None) => Some(next - 1),
+            (None, None) => None,
+        }
+    }
+
+    /// Display values of the metrics at the sample.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `sample` - given sample index.
+    /// - `indices` - given indices of specific metrics in history.
+    /// - `labels` - given labels for each metric.
+    /// - `colors` - given colors for each metric.
+    fn display_readout(
+        &self,
+        ui: &mut egui::Ui,
+        sample: usize,
+        indices: &[usize],
+        labels: &[&str],
+        colors: &[Color32],
+    ) {
+        let Some(&ts) = self.timestamps.get(sample) else {
+            return;
+        };
+
+        #[allow(clippy::cast_possible_truncation)]
+        let time = match self.time_axis {
+            TimeAxis::Sensor => format!("{ts:.3} s"),
+            TimeAxis::WallClock => format_host_time((ts * 1e6).round() as i64),
+        };
+
+        ui.label(RichText::new(time).monospace().strong());
+
+        for ((&idx, label), color) in indices.iter().zip(labels).zip(colors) {
+            if let Some(&val) = self
+                .history
+                .get(idx)
+                .and_then(|sequence| sequence.get(sample))
+            {
+                ui.label(
+                    RichText::new(format!("{label}: {val:.4}"))
+                        .monospace()
+                        .color(*color),
+                );
+            }
+        }
+    }
+
+    /// Save requested plot export once the screenshot is taken.
+    ///
+    /// # Parameters
+    /// - `ui` - given screen UI handler.
+    /// - `id` - given plot identifier.
+    fn handle_export(&mut self, ui: &egui::Ui, id: &str) {
+        let Some(export) = self.export.as_ref().filter(|e| e.id == id) else {
+            return;
+        };
+
+        let screenshot = ui.input(|i| {
+            i.events.iter().find_map(|event| match event {
+                Event::Screenshot {
+                    image, user_data, ..
+                } if user_data
+                    .data
+                    .as_ref()
+                    .and_then(|data| data.downcast_ref::<String>())
+                    == Some(&export.id) =>
+                {
+                    Some(Arc::clone(image))
+                }
+                _ => None,
+            })
+        });
+
+        let Some(screenshot) = screenshot else {
+            return;
+        };
+
+        let image =
+            screenshot.region(&export.rect, Some(ui.pixels_per_point()));
+        let name = id.trim_end_matches("_p");
+        self.export = None;
+
+        self.export_status = match save_plot_image(&image, name) {
+            Ok(path) => {
+                log::info!("Plot exported to: {}", path.display());
+                Some(format!("Saved to: {}", path.display()))
+            }
+            Err(e) => {
+                log::error!("Failed to export plot: {e}");
+                Some(format!("Export failed: {e}"))
+            }
+        };
+    }
+
+    /// Render uncertainty band around the metric values.
+    ///
+    /// Band is split into quadrilaterals between consecutive points, since
+    /// plot polygons are filled as convex shapes.
+    ///
+    /// # Parameters
+    /// - `plot_ui` - given plot UI handler.
+    /// - `sequence` - given metric values history.
+    /// - `color` - given metric color.
+    fn render_band(
+        &self,
+        plot_ui: &mut PlotUi<'_>,
+        sequence: &VecDeque<f64>,
+        color: Color32,
+    ) {
+        let step = (self.timestamps.len() / MAX_BAND_SEGMENTS).max(1);
+        let points: Vec<(f64, f64, f64)> = self
+            .timestamps
+            .iter()
+            .zip(sequence)
+            .zip(&self.bands)
+            .step_by(step)
+            .map(|((&ts, &val), &band)| (ts, val - band, val + band))
+            .collect();
+
+        for pair in points.windows(2) {
+            let [(t0, lo0, hi0), (t1, lo1, hi1)] = pair else {
+                continue;
+            };
+
+            if *hi0 <= *lo0 && *hi1 <= *lo1 {
+                continue;
+            }
+
+            let quad = vec![[*t0, *lo0], [*t1, *lo1], [*t1, *hi1], [*t0, *hi0]];
+
+            plot_ui.polygon(
+                Polygon::new(BAND_LABEL, quad)
+                    .fill_color(color.gamma_multiply(0.15))
+                    .stroke(Stroke::NONE),
+            );
+        }
     }
 }
 
@@ -257,14 +1145,166 @@ impl<const ENTRIES: usize, const POINTS: usize> Default
     /// - New `Plotter` object.
     fn default() -> Self {
         let history = std::array::from_fn(|_| VecDeque::with_capacity(POINTS));
+        let flags = std::array::from_fn(|_| VecDeque::with_capacity(POINTS));
         Self {
             history,
+            flags,
             timestamps: VecDeque::default(),
+            bands: VecDeque::default(),
+            markers: VecDeque::default(),
+            events: VecDeque::default(),
+            cursor: None,
             plot_height: None,
+            time_axis: TimeAxis::default(),
+            max_points: POINTS,
+            renderer: PlotRenderer::default(),
+            export: None,
+            export_status: None,
+            views: HashMap::new(),
         }
     }
 }
 
+/// Format time axis grid mark.
+///
+/// # Parameters
+/// - `value` - given grid mark value in seconds.
+/// - `time_axis` - given time axis of the timestamps.
+///
+/// # Returns
+/// - Host time for the wall-clock axis, relative time otherwise.
+fn format_time_mark(value: f64, time_axis: TimeAxis) -> String {
+    if time_axis == TimeAxis::WallClock {
+        #[allow(clippy::cast_possible_truncation)]
+        return format_host_time((value * 1e6).round() as i64);
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    let total_ms = (value * 1000.0).round() as i64;
+    let ms = total_ms.abs() % 1000;
+    let total_sec = total_ms / 1000;
+    let sec = total_sec.abs() % 60;
+    let min = total_sec.abs() / 60 % 60;
+    let hours = total_sec.abs() / 3600;
+
+    let sign = if value < 0.0 { "-" } else { "" };
+
+    if hours > 0 {
+        format!("{sign}{hours}:{min:02}:{sec:02}.{ms:03}")
+    } else if min > 0 {
+        format!("{sign}{min:02}:{sec:02}.{ms:03}")
+    } else {
+        format!("{sign}{sec}.{ms:03}")
+    }
+}
+
+/// Apply autoscale and locked Y axis range of the plot view.
+///
+/// # Parameters
+/// - `plot_ui` - given plot UI handler.
+/// - `view` - given plot view settings.
+/// - `x_min` - given time of the oldest sample.
+/// - `x_max` - given time of the newest sample.
+fn apply_view<const ENTRIES: usize, const POINTS: usize>(
+    plot_ui: &mut PlotUi<'_>,
+    view: &PlotView<ENTRIES, POINTS>,
+    x_min: f64,
+    x_max: f64,
+) {
+    if view.autoscale {
+        plot_ui.set_auto_bounds(true);
+    }
+
+    // Locked Y axis range keeps the time axis following the data.
+    if let Some([y_min, y_max]) = view.y_range
+        && y_max > y_min
+    {
+        plot_ui.set_plot_bounds(PlotBounds::from_min_max(
+            [x_min, y_min],
+            [x_max.max(x_min + 1e-3), y_max],
+        ));
+    }
+}
+
+/// Shade flagged intervals within the range of plotted values.
+///
+/// # Parameters
+/// - `plot_ui` - given plot UI handler.
+/// - `intervals` - given flagged time intervals.
+/// - `range` - given range of plotted values.
+fn render_flagged_intervals(
+    plot_ui: &mut PlotUi<'_>,
+    intervals: &[(f64, f64)],
+    (y_min, y_max): (f64, f64),
+) {
+    for &(start, end) in intervals {
+        let rect =
+            vec![[start, y_min], [end, y_min], [end, y_max], [start, y_max]];
+
+        plot_ui.polygon(
+            Polygon::new(FLAGGED_INTERVAL_LABEL, rect)
+                .fill_color(FLAGGED_INTERVAL_COLOR)
+                .stroke(Stroke::NONE),
+        );
+    }
+}
+
+/// Get labels of the traces hidden from the plot legend.
+///
+/// Legend entries are identified by the names of the plot items.
+///
+/// # Parameters
+/// - `labels` - given labels of the plotted metrics.
+/// - `response` - given plot response.
+///
+/// # Returns
+/// - Labels of the hidden traces.
+fn hidden_labels<R>(
+    labels: &[&str],
+    response: &PlotResponse<R>,
+) -> BTreeSet<String> {
+    labels
+        .iter()
+        .filter(|label| response.hidden_items.contains(&Id::new(**label)))
+        .map(ToString::to_string)
+        .collect()
+}
+
+/// Reallocate history buffer for the number of points.
+///
+/// # Parameters
+/// - `buffer` - given history buffer to reallocate.
+/// - `max_points` - given max number of points in history.
+fn resize_buffer<T>(buffer: &mut VecDeque<T>, max_points: usize) {
+    buffer.shrink_to(max_points);
+    buffer.reserve(max_points.saturating_sub(buffer.len()));
+}
+
+/// Save plot image into the exports directory.
+///
+/// # Parameters
+/// - `image` - given plot image to save.
+/// - `name` - given plot name.
+///
+/// # Returns
+/// - Path to the saved image - in case of success.
+/// - `Err` - otherwise.
+///
+/// # Errors
+/// - Directory creation, image encoding or file writing errors.
+fn save_plot_image(image: &ColorImage, name: &str) -> anyhow::Result<PathBuf> {
+    fs::create_dir_all(PLOT_EXPORT_DIRECTORY)?;
+
+    let mut path = PathBuf::from(PLOT_EXPORT_DIRECTORY);
+    path.push(format!(
+        "{name}_{}.png",
+        Local::now().format("%d-%m-%Y_%H-%M-%S")
+    ));
+
+    fs::write(&path, report::encode_png(image)?)?;
+    Ok(path)
+}
+
 /// Extract IMU reading from payload.
 ///
 /// # Parameters